@@ -23,6 +23,7 @@ pub mod horizontal_flatten;
 #[cfg(feature = "approx_unique")]
 pub mod hyperloglogplus;
 pub mod if_then_else;
+pub mod linalg;
 pub mod min_max;
 pub mod moment;
 pub mod propagate_dictionary;
@@ -0,0 +1,181 @@
+//! Dense vector kernels over float slices, used for the per-row linear algebra
+//! on fixed-size `Array` columns (dot products, distances and norms).
+#[cfg(feature = "simd")]
+use std::simd::prelude::*;
+
+use num_traits::Float;
+
+const STRIPE: usize = 16;
+
+fn horizontal_sum<F: Float>(mut v: [F; STRIPE]) -> F {
+    // Fold the accumulator onto itself so the reduction order is fixed and
+    // independent of whether the SIMD path was taken.
+    let mut width = STRIPE;
+    while width > 4 {
+        for j in 0..width / 2 {
+            v[j] = v[j] + v[width / 2 + j];
+        }
+        width /= 2;
+    }
+    (v[0] + v[2]) + (v[1] + v[3])
+}
+
+pub trait VectorKernels: Float + Sized {
+    /// Sum of `a[i] * b[i]`.
+    ///
+    /// Panics if the slices are of unequal length.
+    fn dot(a: &[Self], b: &[Self]) -> Self;
+
+    /// Sum of `(a[i] - b[i])^2`.
+    ///
+    /// Panics if the slices are of unequal length.
+    fn squared_l2(a: &[Self], b: &[Self]) -> Self;
+
+    /// Sum of `a[i]^2`.
+    fn sum_of_squares(a: &[Self]) -> Self {
+        Self::dot(a, a)
+    }
+
+    /// The `p`-norm of `a`. `p = inf` gives the maximum absolute value.
+    fn norm(a: &[Self], p: Self) -> Self {
+        if p == Self::from(2.0).unwrap() {
+            Self::sum_of_squares(a).sqrt()
+        } else if p == Self::one() {
+            a.iter().fold(Self::zero(), |acc, x| acc + x.abs())
+        } else if p.is_infinite() && p > Self::zero() {
+            a.iter().fold(Self::zero(), |acc, x| acc.max(x.abs()))
+        } else {
+            a.iter()
+                .fold(Self::zero(), |acc, x| acc + x.abs().powf(p))
+                .powf(p.recip())
+        }
+    }
+
+    /// Cosine similarity of `a` and `b`. Returns NaN if either vector has zero norm.
+    fn cosine_similarity(a: &[Self], b: &[Self]) -> Self {
+        let denom = (Self::sum_of_squares(a) * Self::sum_of_squares(b)).sqrt();
+        if denom == Self::zero() {
+            Self::nan()
+        } else {
+            Self::dot(a, b) / denom
+        }
+    }
+}
+
+macro_rules! impl_vector_kernels {
+    ($T:ty) => {
+        impl VectorKernels for $T {
+            fn dot(a: &[Self], b: &[Self]) -> Self {
+                assert_eq!(a.len(), b.len());
+                let main = a.len() - a.len() % STRIPE;
+                let (a_main, a_rest) = a.split_at(main);
+                let (b_main, b_rest) = b.split_at(main);
+
+                #[cfg(feature = "simd")]
+                let acc = {
+                    let mut acc = Simd::<$T, STRIPE>::splat(0.0);
+                    for (x, y) in a_main
+                        .chunks_exact(STRIPE)
+                        .zip(b_main.chunks_exact(STRIPE))
+                    {
+                        acc += Simd::from_slice(x) * Simd::from_slice(y);
+                    }
+                    acc.to_array()
+                };
+                #[cfg(not(feature = "simd"))]
+                let acc = {
+                    let mut acc = [0.0; STRIPE];
+                    for (x, y) in a_main
+                        .chunks_exact(STRIPE)
+                        .zip(b_main.chunks_exact(STRIPE))
+                    {
+                        for j in 0..STRIPE {
+                            acc[j] += x[j] * y[j];
+                        }
+                    }
+                    acc
+                };
+
+                let rest: $T = a_rest.iter().zip(b_rest).map(|(x, y)| x * y).sum();
+                horizontal_sum(acc) + rest
+            }
+
+            fn squared_l2(a: &[Self], b: &[Self]) -> Self {
+                assert_eq!(a.len(), b.len());
+                let main = a.len() - a.len() % STRIPE;
+                let (a_main, a_rest) = a.split_at(main);
+                let (b_main, b_rest) = b.split_at(main);
+
+                #[cfg(feature = "simd")]
+                let acc = {
+                    let mut acc = Simd::<$T, STRIPE>::splat(0.0);
+                    for (x, y) in a_main
+                        .chunks_exact(STRIPE)
+                        .zip(b_main.chunks_exact(STRIPE))
+                    {
+                        let d = Simd::from_slice(x) - Simd::from_slice(y);
+                        acc += d * d;
+                    }
+                    acc.to_array()
+                };
+                #[cfg(not(feature = "simd"))]
+                let acc = {
+                    let mut acc = [0.0; STRIPE];
+                    for (x, y) in a_main
+                        .chunks_exact(STRIPE)
+                        .zip(b_main.chunks_exact(STRIPE))
+                    {
+                        for j in 0..STRIPE {
+                            let d = x[j] - y[j];
+                            acc[j] += d * d;
+                        }
+                    }
+                    acc
+                };
+
+                let rest: $T = a_rest
+                    .iter()
+                    .zip(b_rest)
+                    .map(|(x, y)| (x - y) * (x - y))
+                    .sum();
+                horizontal_sum(acc) + rest
+            }
+        }
+    };
+}
+
+impl_vector_kernels!(f32);
+impl_vector_kernels!(f64);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn naive_dot(a: &[f64], b: &[f64]) -> f64 {
+        a.iter().zip(b).map(|(x, y)| x * y).sum()
+    }
+
+    #[test]
+    fn test_vector_kernels() {
+        for len in [0, 1, 15, 16, 17, 33, 100] {
+            let a: Vec<f64> = (0..len).map(|i| (i as f64) * 0.5 - 3.0).collect();
+            let b: Vec<f64> = (0..len).map(|i| ((i * 7) % 11) as f64 - 5.0).collect();
+
+            assert!((f64::dot(&a, &b) - naive_dot(&a, &b)).abs() < 1e-9);
+            let diff: Vec<f64> = a.iter().zip(&b).map(|(x, y)| x - y).collect();
+            assert!((f64::squared_l2(&a, &b) - naive_dot(&diff, &diff)).abs() < 1e-9);
+
+            let a32: Vec<f32> = a.iter().map(|x| *x as f32).collect();
+            let b32: Vec<f32> = b.iter().map(|x| *x as f32).collect();
+            assert!((f32::dot(&a32, &b32) as f64 - naive_dot(&a, &b)).abs() < 1e-2);
+        }
+
+        let v = [3.0f64, -4.0];
+        assert_eq!(f64::norm(&v, 2.0), 5.0);
+        assert_eq!(f64::norm(&v, 1.0), 7.0);
+        assert_eq!(f64::norm(&v, f64::INFINITY), 4.0);
+        assert!((f64::norm(&v, 3.0) - 91f64.cbrt()).abs() < 1e-12);
+        assert_eq!(f64::cosine_similarity(&v, &[6.0, -8.0]), 1.0);
+        assert!(f64::cosine_similarity(&v, &[0.0, 0.0]).is_nan());
+    }
+}
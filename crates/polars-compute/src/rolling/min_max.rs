@@ -3,6 +3,7 @@ use std::marker::PhantomData;
 
 use arrow::bitmap::Bitmap;
 use arrow::types::NativeType;
+use polars_utils::IdxSize;
 use polars_utils::min_max::MinMaxPolicy;
 
 use super::RollingFnParams;
@@ -144,3 +145,78 @@ impl<'a, T: NativeType, P: MinMaxPolicy> RollingAggWindowNoNulls<'a, T> for MinM
         }
     }
 }
+
+/// The position of the best value within the window, as per the policy. Ties resolve to the first
+/// occurrence.
+pub struct ArgMinMaxWindow<'a, T, P> {
+    inner: MinMaxWindow<'a, T, P>,
+}
+
+impl<T, P> ArgMinMaxWindow<'_, T, P> {
+    fn arg(&self, start: usize) -> Option<IdxSize> {
+        self.inner
+            .monotonic_idxs
+            .front()
+            .map(|idx| (*idx - start) as IdxSize)
+    }
+}
+
+impl<'a, T: NativeType, P: MinMaxPolicy> RollingAggWindowNulls<'a, T, IdxSize>
+    for ArgMinMaxWindow<'a, T, P>
+{
+    unsafe fn new(
+        slice: &'a [T],
+        validity: &'a Bitmap,
+        start: usize,
+        end: usize,
+        params: Option<RollingFnParams>,
+        window_size: Option<usize>,
+    ) -> Self {
+        let inner = unsafe {
+            <MinMaxWindow<'a, T, P> as RollingAggWindowNulls<'a, T>>::new(
+                slice,
+                validity,
+                start,
+                end,
+                params,
+                window_size,
+            )
+        };
+        Self { inner }
+    }
+
+    unsafe fn update(&mut self, start: usize, end: usize) -> Option<IdxSize> {
+        unsafe { RollingAggWindowNulls::update(&mut self.inner, start, end) };
+        self.arg(start)
+    }
+
+    fn is_valid(&self, min_periods: usize) -> bool {
+        self.inner.is_valid(min_periods)
+    }
+}
+
+impl<'a, T: NativeType, P: MinMaxPolicy> RollingAggWindowNoNulls<'a, T, IdxSize>
+    for ArgMinMaxWindow<'a, T, P>
+{
+    fn new(
+        slice: &'a [T],
+        start: usize,
+        end: usize,
+        params: Option<RollingFnParams>,
+        window_size: Option<usize>,
+    ) -> Self {
+        let inner = <MinMaxWindow<'a, T, P> as RollingAggWindowNoNulls<'a, T>>::new(
+            slice,
+            start,
+            end,
+            params,
+            window_size,
+        );
+        Self { inner }
+    }
+
+    unsafe fn update(&mut self, start: usize, end: usize) -> Option<IdxSize> {
+        unsafe { RollingAggWindowNoNulls::update(&mut self.inner, start, end) };
+        self.arg(start)
+    }
+}
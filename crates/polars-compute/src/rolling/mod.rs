@@ -1,8 +1,12 @@
 mod min_max;
+mod mode;
 pub mod moment;
+mod n_unique;
 pub mod no_nulls;
 pub mod nulls;
+mod product;
 pub mod quantile_filter;
+mod rank;
 pub(super) mod window;
 use std::hash::Hash;
 use std::ops::{Add, AddAssign, Div, Mul, Sub, SubAssign};
@@ -40,6 +44,21 @@ pub enum QuantileMethod {
 #[deprecated(note = "use QuantileMethod instead")]
 pub type QuantileInterpolOptions = QuantileMethod;
 
+/// How ties are ranked by a rolling rank.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default, Hash, IntoStaticStr)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+#[strum(serialize_all = "snake_case")]
+pub enum RollingRankMethod {
+    /// The mean of the ranks the tied values would get.
+    #[default]
+    Average,
+    /// The lowest rank the tied values would get.
+    Min,
+    /// The highest rank the tied values would get.
+    Max,
+}
+
 #[derive(Clone, Copy, Debug, PartialEq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
@@ -48,6 +67,7 @@ pub enum RollingFnParams {
     Var(RollingVarParams),
     Skew { bias: bool },
     Kurtosis { fisher: bool, bias: bool },
+    Rank { method: RollingRankMethod },
 }
 
 fn det_offsets(i: Idx, window_size: WindowSize, _len: Len) -> (usize, usize) {
@@ -0,0 +1,131 @@
+use std::cmp::Reverse;
+use std::collections::BTreeSet;
+
+use arrow::bitmap::Bitmap;
+use arrow::types::NativeType;
+use polars_utils::aliases::{InitHashMaps, PlHashMap};
+use polars_utils::total_ord::{TotalHash, TotalOrd, TotalOrdWrap};
+
+use super::RollingFnParams;
+use super::no_nulls::RollingAggWindowNoNulls;
+use super::nulls::RollingAggWindowNulls;
+
+/// The most frequent non-null value in the window. Ties resolve to the smallest value.
+pub struct ModeWindow<'a, T> {
+    values: &'a [T],
+    validity: Option<&'a Bitmap>,
+    counts: PlHashMap<TotalOrdWrap<T>, usize>,
+    // The (count, value) pairs of `counts`, so the last entry is the mode.
+    by_count: BTreeSet<(usize, Reverse<TotalOrdWrap<T>>)>,
+    nonnulls_in_window: usize,
+    last_start: usize,
+    last_end: usize,
+}
+
+impl<'a, T: NativeType + TotalOrd + TotalHash> ModeWindow<'a, T> {
+    fn new_impl(values: &'a [T], validity: Option<&'a Bitmap>) -> Self {
+        Self {
+            values,
+            validity,
+            counts: PlHashMap::new(),
+            by_count: BTreeSet::new(),
+            nonnulls_in_window: 0,
+            last_start: 0,
+            last_end: 0,
+        }
+    }
+
+    fn add(&mut self, value: TotalOrdWrap<T>) {
+        let count = self.counts.entry(value).or_insert(0);
+        if *count > 0 {
+            self.by_count.remove(&(*count, Reverse(value)));
+        }
+        *count += 1;
+        self.by_count.insert((*count, Reverse(value)));
+        self.nonnulls_in_window += 1;
+    }
+
+    fn remove(&mut self, value: TotalOrdWrap<T>) {
+        let count = self.counts.get_mut(&value).unwrap();
+        self.by_count.remove(&(*count, Reverse(value)));
+        *count -= 1;
+        if *count == 0 {
+            self.counts.remove(&value);
+        } else {
+            self.by_count.insert((*count, Reverse(value)));
+        }
+        self.nonnulls_in_window -= 1;
+    }
+
+    /// # Safety
+    /// `start` and `end` must be in-bounds.
+    unsafe fn update_impl(&mut self, start: usize, end: usize) -> Option<T> {
+        unsafe {
+            if start >= self.last_end {
+                self.counts.clear();
+                self.by_count.clear();
+                self.nonnulls_in_window = 0;
+                self.last_start = start;
+                self.last_end = start;
+            }
+
+            for idx in self.last_start..start {
+                if self.validity.is_none_or(|v| v.get_bit_unchecked(idx)) {
+                    self.remove(TotalOrdWrap(*self.values.get_unchecked(idx)));
+                }
+            }
+            for idx in self.last_end..end {
+                if self.validity.is_none_or(|v| v.get_bit_unchecked(idx)) {
+                    self.add(TotalOrdWrap(*self.values.get_unchecked(idx)));
+                }
+            }
+            self.last_start = start;
+            self.last_end = end;
+
+            self.by_count.last().map(|(_, Reverse(value))| value.0)
+        }
+    }
+}
+
+impl<'a, T: NativeType + TotalOrd + TotalHash> RollingAggWindowNulls<'a, T> for ModeWindow<'a, T> {
+    unsafe fn new(
+        slice: &'a [T],
+        validity: &'a Bitmap,
+        start: usize,
+        end: usize,
+        _params: Option<RollingFnParams>,
+        _window_size: Option<usize>,
+    ) -> Self {
+        let mut slf = Self::new_impl(slice, Some(validity));
+        unsafe { slf.update_impl(start, end) };
+        slf
+    }
+
+    unsafe fn update(&mut self, start: usize, end: usize) -> Option<T> {
+        unsafe { self.update_impl(start, end) }
+    }
+
+    fn is_valid(&self, min_periods: usize) -> bool {
+        self.nonnulls_in_window >= min_periods
+    }
+}
+
+impl<'a, T: NativeType + TotalOrd + TotalHash> RollingAggWindowNoNulls<'a, T>
+    for ModeWindow<'a, T>
+{
+    fn new(
+        slice: &'a [T],
+        start: usize,
+        end: usize,
+        _params: Option<RollingFnParams>,
+        _window_size: Option<usize>,
+    ) -> Self {
+        let mut slf = Self::new_impl(slice, None);
+        unsafe { slf.update_impl(start, end) };
+        slf
+    }
+
+    unsafe fn update(&mut self, start: usize, end: usize) -> Option<T> {
+        unsafe { self.update_impl(start, end) }
+    }
+}
@@ -0,0 +1,113 @@
+use arrow::bitmap::Bitmap;
+use arrow::types::NativeType;
+use polars_utils::IdxSize;
+use polars_utils::aliases::{InitHashMaps, PlHashMap};
+use polars_utils::total_ord::{TotalEq, TotalHash, TotalOrdWrap};
+
+use super::RollingFnParams;
+use super::no_nulls::RollingAggWindowNoNulls;
+use super::nulls::RollingAggWindowNulls;
+
+/// The number of distinct non-null values in the window.
+pub struct NUniqueWindow<'a, T> {
+    values: &'a [T],
+    validity: Option<&'a Bitmap>,
+    counts: PlHashMap<TotalOrdWrap<T>, usize>,
+    nonnulls_in_window: usize,
+    last_start: usize,
+    last_end: usize,
+}
+
+impl<'a, T: NativeType + TotalEq + TotalHash> NUniqueWindow<'a, T> {
+    fn new_impl(values: &'a [T], validity: Option<&'a Bitmap>) -> Self {
+        Self {
+            values,
+            validity,
+            counts: PlHashMap::new(),
+            nonnulls_in_window: 0,
+            last_start: 0,
+            last_end: 0,
+        }
+    }
+
+    /// # Safety
+    /// `start` and `end` must be in-bounds.
+    unsafe fn update_impl(&mut self, start: usize, end: usize) -> Option<IdxSize> {
+        unsafe {
+            if start >= self.last_end {
+                self.counts.clear();
+                self.nonnulls_in_window = 0;
+                self.last_start = start;
+                self.last_end = start;
+            }
+
+            for idx in self.last_start..start {
+                if self.validity.is_none_or(|v| v.get_bit_unchecked(idx)) {
+                    let value = TotalOrdWrap(*self.values.get_unchecked(idx));
+                    let count = self.counts.get_mut(&value).unwrap();
+                    *count -= 1;
+                    if *count == 0 {
+                        self.counts.remove(&value);
+                    }
+                    self.nonnulls_in_window -= 1;
+                }
+            }
+            for idx in self.last_end..end {
+                if self.validity.is_none_or(|v| v.get_bit_unchecked(idx)) {
+                    let value = TotalOrdWrap(*self.values.get_unchecked(idx));
+                    *self.counts.entry(value).or_insert(0) += 1;
+                    self.nonnulls_in_window += 1;
+                }
+            }
+            self.last_start = start;
+            self.last_end = end;
+
+            Some(self.counts.len() as IdxSize)
+        }
+    }
+}
+
+impl<'a, T: NativeType + TotalEq + TotalHash> RollingAggWindowNulls<'a, T, IdxSize>
+    for NUniqueWindow<'a, T>
+{
+    unsafe fn new(
+        slice: &'a [T],
+        validity: &'a Bitmap,
+        start: usize,
+        end: usize,
+        _params: Option<RollingFnParams>,
+        _window_size: Option<usize>,
+    ) -> Self {
+        let mut slf = Self::new_impl(slice, Some(validity));
+        unsafe { slf.update_impl(start, end) };
+        slf
+    }
+
+    unsafe fn update(&mut self, start: usize, end: usize) -> Option<IdxSize> {
+        unsafe { self.update_impl(start, end) }
+    }
+
+    fn is_valid(&self, min_periods: usize) -> bool {
+        self.nonnulls_in_window >= min_periods
+    }
+}
+
+impl<'a, T: NativeType + TotalEq + TotalHash> RollingAggWindowNoNulls<'a, T, IdxSize>
+    for NUniqueWindow<'a, T>
+{
+    fn new(
+        slice: &'a [T],
+        start: usize,
+        end: usize,
+        _params: Option<RollingFnParams>,
+        _window_size: Option<usize>,
+    ) -> Self {
+        let mut slf = Self::new_impl(slice, None);
+        unsafe { slf.update_impl(start, end) };
+        slf
+    }
+
+    unsafe fn update(&mut self, start: usize, end: usize) -> Option<IdxSize> {
+        unsafe { self.update_impl(start, end) }
+    }
+}
@@ -1,10 +1,15 @@
-use polars_utils::min_max::{MaxPropagateNan, MinMaxPolicy, MinPropagateNan};
+use polars_error::polars_ensure;
+use polars_utils::min_max::{
+    MaxIgnoreNan, MaxPropagateNan, MinIgnoreNan, MinMaxPolicy, MinPropagateNan,
+};
 
-use super::super::min_max::MinMaxWindow;
+use super::super::min_max::{ArgMinMaxWindow, MinMaxWindow};
 use super::*;
 
 pub type MinWindow<'a, T> = MinMaxWindow<'a, T, MinPropagateNan>;
 pub type MaxWindow<'a, T> = MinMaxWindow<'a, T, MaxPropagateNan>;
+pub type ArgMinWindow<'a, T> = ArgMinMaxWindow<'a, T, MinIgnoreNan>;
+pub type ArgMaxWindow<'a, T> = ArgMinMaxWindow<'a, T, MaxIgnoreNan>;
 
 fn weighted_min_max<T, P>(values: &[T], weights: &[T]) -> T
 where
@@ -37,7 +42,7 @@ macro_rules! rolling_minmax_func {
                 false => det_offsets,
             };
             match weights {
-                None => rolling_apply_agg_window::<MinMaxWindow<T, $policy>, _, _>(
+                None => rolling_apply_agg_window::<MinMaxWindow<T, $policy>, _, _, _>(
                     values,
                     window_size,
                     min_periods,
@@ -71,8 +76,47 @@ macro_rules! rolling_minmax_func {
 rolling_minmax_func!(rolling_min, MinPropagateNan);
 rolling_minmax_func!(rolling_max, MaxPropagateNan);
 
+macro_rules! rolling_arg_minmax_func {
+    ($rolling_m:ident, $policy:ident) => {
+        /// The position of the extremum in every window, relative to the start of the window.
+        /// NaNs are ignored and ties resolve to the first occurrence.
+        pub fn $rolling_m<T>(
+            values: &[T],
+            window_size: usize,
+            min_periods: usize,
+            center: bool,
+            weights: Option<&[f64]>,
+            _params: Option<RollingFnParams>,
+        ) -> PolarsResult<ArrayRef>
+        where
+            T: NativeType + Num,
+        {
+            polars_ensure!(
+                weights.is_none(),
+                InvalidOperation: "weights are not supported by `{}`", stringify!($rolling_m)
+            );
+            let offset_fn = match center {
+                true => det_offsets_center,
+                false => det_offsets,
+            };
+            rolling_apply_agg_window::<ArgMinMaxWindow<T, $policy>, _, _, _>(
+                values,
+                window_size,
+                min_periods,
+                offset_fn,
+                None,
+            )
+        }
+    };
+}
+
+rolling_arg_minmax_func!(rolling_arg_min, MinIgnoreNan);
+rolling_arg_minmax_func!(rolling_arg_max, MaxIgnoreNan);
+
 #[cfg(test)]
 mod test {
+    use polars_utils::IdxSize;
+
     use super::*;
 
     #[test]
@@ -143,4 +187,25 @@ mod test {
             )
         );
     }
+
+    #[test]
+    fn test_rolling_arg_min_max() {
+        let values = &[3.0f64, 1.0, f64::NAN, 1.0, 4.0, 2.0];
+
+        let out = rolling_arg_min(values, 3, 3, false, None, None).unwrap();
+        let out = out
+            .as_any()
+            .downcast_ref::<PrimitiveArray<IdxSize>>()
+            .unwrap();
+        let out = out.into_iter().map(|v| v.copied()).collect::<Vec<_>>();
+        assert_eq!(out, &[None, None, Some(1), Some(0), Some(1), Some(0)]);
+
+        let out = rolling_arg_max(values, 3, 3, false, None, None).unwrap();
+        let out = out
+            .as_any()
+            .downcast_ref::<PrimitiveArray<IdxSize>>()
+            .unwrap();
+        let out = out.into_iter().map(|v| v.copied()).collect::<Vec<_>>();
+        assert_eq!(out, &[None, None, Some(0), Some(0), Some(2), Some(1)]);
+    }
 }
@@ -0,0 +1,58 @@
+use polars_error::polars_ensure;
+use polars_utils::total_ord::{TotalHash, TotalOrd};
+
+pub use super::super::mode::ModeWindow;
+use super::*;
+
+/// The most frequent value in every window. Ties resolve to the smallest value.
+pub fn rolling_mode<T>(
+    values: &[T],
+    window_size: usize,
+    min_periods: usize,
+    center: bool,
+    weights: Option<&[f64]>,
+    params: Option<RollingFnParams>,
+) -> PolarsResult<ArrayRef>
+where
+    T: NativeType + TotalOrd + TotalHash + Num,
+{
+    polars_ensure!(weights.is_none(), InvalidOperation: "weights are not supported by `rolling_mode`");
+    let offset_fn = match center {
+        true => det_offsets_center,
+        false => det_offsets,
+    };
+    rolling_apply_agg_window::<ModeWindow<T>, _, _, _>(
+        values,
+        window_size,
+        min_periods,
+        offset_fn,
+        params,
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_rolling_mode() {
+        let values = &[4i64, 2, 2, 4, 4, 1, 1, 1];
+
+        let out = rolling_mode(values, 4, 1, false, None, None).unwrap();
+        let out = out.as_any().downcast_ref::<PrimitiveArray<i64>>().unwrap();
+        let out = out.into_iter().map(|v| v.copied()).collect::<Vec<_>>();
+        assert_eq!(
+            out,
+            &[
+                Some(4),
+                Some(2),
+                Some(2),
+                Some(2),
+                Some(2),
+                Some(4),
+                Some(1),
+                Some(1)
+            ]
+        );
+    }
+}
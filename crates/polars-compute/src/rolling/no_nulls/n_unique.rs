@@ -0,0 +1,51 @@
+use polars_error::polars_ensure;
+use polars_utils::total_ord::{TotalEq, TotalHash};
+
+pub use super::super::n_unique::NUniqueWindow;
+use super::*;
+
+/// The number of distinct values in every window.
+pub fn rolling_n_unique<T>(
+    values: &[T],
+    window_size: usize,
+    min_periods: usize,
+    center: bool,
+    weights: Option<&[f64]>,
+    params: Option<RollingFnParams>,
+) -> PolarsResult<ArrayRef>
+where
+    T: NativeType + TotalEq + TotalHash + Num,
+{
+    polars_ensure!(weights.is_none(), InvalidOperation: "weights are not supported by `rolling_n_unique`");
+    let offset_fn = match center {
+        true => det_offsets_center,
+        false => det_offsets,
+    };
+    rolling_apply_agg_window::<NUniqueWindow<T>, _, _, _>(
+        values,
+        window_size,
+        min_periods,
+        offset_fn,
+        params,
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use polars_utils::IdxSize;
+
+    use super::*;
+
+    #[test]
+    fn test_rolling_n_unique() {
+        let values = &[1.0f64, 1.0, 2.0, f64::NAN, f64::NAN, 3.0];
+
+        let out = rolling_n_unique(values, 3, 2, false, None, None).unwrap();
+        let out = out
+            .as_any()
+            .downcast_ref::<PrimitiveArray<IdxSize>>()
+            .unwrap();
+        let out = out.into_iter().map(|v| v.copied()).collect::<Vec<_>>();
+        assert_eq!(out, &[None, Some(1), Some(2), Some(3), Some(2), Some(2)]);
+    }
+}
@@ -0,0 +1,54 @@
+use polars_error::polars_ensure;
+
+pub use super::super::product::ProductWindow;
+use super::*;
+use crate::arithmetic::pl_num::PlNumArithmetic;
+
+/// The product of the values in every window. Integers wrap around on overflow.
+pub fn rolling_product<T>(
+    values: &[T],
+    window_size: usize,
+    min_periods: usize,
+    center: bool,
+    weights: Option<&[f64]>,
+    params: Option<RollingFnParams>,
+) -> PolarsResult<ArrayRef>
+where
+    T: NativeType + One + PlNumArithmetic + Num,
+{
+    polars_ensure!(weights.is_none(), InvalidOperation: "weights are not supported by `rolling_product`");
+    let offset_fn = match center {
+        true => det_offsets_center,
+        false => det_offsets,
+    };
+    rolling_apply_agg_window::<ProductWindow<T>, _, _, _>(
+        values,
+        window_size,
+        min_periods,
+        offset_fn,
+        params,
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_rolling_product() {
+        let values = &[2i64, 0, 3, 4, 5, -1];
+
+        let out = rolling_product(values, 3, 3, false, None, None).unwrap();
+        let out = out.as_any().downcast_ref::<PrimitiveArray<i64>>().unwrap();
+        let out = out.into_iter().map(|v| v.copied()).collect::<Vec<_>>();
+        assert_eq!(out, &[None, None, Some(0), Some(0), Some(60), Some(-20)]);
+
+        let out = rolling_product(values, 2, 1, true, None, None).unwrap();
+        let out = out.as_any().downcast_ref::<PrimitiveArray<i64>>().unwrap();
+        let out = out.into_iter().map(|v| v.copied()).collect::<Vec<_>>();
+        assert_eq!(
+            out,
+            &[Some(2), Some(0), Some(0), Some(12), Some(20), Some(-5)]
+        );
+    }
+}
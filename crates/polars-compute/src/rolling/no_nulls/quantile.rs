@@ -152,7 +152,7 @@ where
                 )));
             }
 
-            rolling_apply_agg_window::<QuantileWindow<_>, _, _>(
+            rolling_apply_agg_window::<QuantileWindow<_>, _, _, _>(
                 values,
                 window_size,
                 min_periods,
@@ -0,0 +1,77 @@
+use polars_error::polars_ensure;
+use polars_utils::total_ord::TotalOrd;
+
+pub use super::super::rank::RankWindow;
+use super::*;
+
+/// The rank of the last value of every window among the values in that window, divided by the
+/// number of values in the window. How ties are ranked is set by [`RollingFnParams::Rank`].
+pub fn rolling_rank<T>(
+    values: &[T],
+    window_size: usize,
+    min_periods: usize,
+    center: bool,
+    weights: Option<&[f64]>,
+    params: Option<RollingFnParams>,
+) -> PolarsResult<ArrayRef>
+where
+    T: NativeType + TotalOrd + Num,
+{
+    polars_ensure!(weights.is_none(), InvalidOperation: "weights are not supported by `rolling_rank`");
+    polars_ensure!(
+        !center,
+        InvalidOperation: "`rolling_rank` ranks the last value of every window and can't be centered"
+    );
+    rolling_apply_agg_window::<RankWindow<T>, _, _, _>(
+        values,
+        window_size,
+        min_periods,
+        det_offsets,
+        params,
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_rolling_rank() {
+        let values = &[3i32, 1, 2, 2, 5, 2];
+        let collect = |out: ArrayRef| {
+            let out = out.as_any().downcast_ref::<PrimitiveArray<f64>>().unwrap();
+            out.into_iter().map(|v| v.copied()).collect::<Vec<_>>()
+        };
+
+        let out = rolling_rank(values, 3, 3, false, None, None).unwrap();
+        assert_eq!(
+            collect(out),
+            &[
+                None,
+                None,
+                Some(2.0 / 3.0),
+                Some(2.5 / 3.0),
+                Some(1.0),
+                Some(1.5 / 3.0)
+            ]
+        );
+
+        let params = Some(RollingFnParams::Rank {
+            method: RollingRankMethod::Min,
+        });
+        let out = rolling_rank(values, 3, 1, false, None, params).unwrap();
+        assert_eq!(
+            collect(out),
+            &[
+                Some(1.0),
+                Some(0.5),
+                Some(2.0 / 3.0),
+                Some(2.0 / 3.0),
+                Some(1.0),
+                Some(1.0 / 3.0)
+            ]
+        );
+
+        assert!(rolling_rank(values, 3, 1, true, None, None).is_err());
+    }
+}
@@ -1,19 +1,27 @@
 mod mean;
 mod min_max;
+mod mode;
 mod moment;
+mod n_unique;
+mod product;
 mod quantile;
+mod rank;
 mod sum;
 
 use arrow::legacy::utils::CustomIterTools;
 pub use mean::*;
 pub use min_max::*;
+pub use mode::*;
 pub use moment::*;
+pub use n_unique::*;
+pub use product::*;
 pub use quantile::*;
+pub use rank::*;
 pub use sum::*;
 
 use super::*;
 
-pub trait RollingAggWindowNulls<'a, T: NativeType> {
+pub trait RollingAggWindowNulls<'a, T: NativeType, Out: NativeType = T> {
     /// # Safety
     /// `start` and `end` must be in bounds for `slice` and `validity`
     unsafe fn new(
@@ -27,13 +35,13 @@ pub trait RollingAggWindowNulls<'a, T: NativeType> {
 
     /// # Safety
     /// `start` and `end` must be in bounds of `slice` and `bitmap`
-    unsafe fn update(&mut self, start: usize, end: usize) -> Option<T>;
+    unsafe fn update(&mut self, start: usize, end: usize) -> Option<Out>;
 
     fn is_valid(&self, min_periods: usize) -> bool;
 }
 
 // Use an aggregation window that maintains the state
-pub(super) fn rolling_apply_agg_window<'a, Agg, T, Fo>(
+pub(super) fn rolling_apply_agg_window<'a, Agg, T, Out, Fo>(
     values: &'a [T],
     validity: &'a Bitmap,
     window_size: usize,
@@ -43,8 +51,9 @@ pub(super) fn rolling_apply_agg_window<'a, Agg, T, Fo>(
 ) -> ArrayRef
 where
     Fo: Fn(Idx, WindowSize, Len) -> (Start, End) + Copy,
-    Agg: RollingAggWindowNulls<'a, T>,
+    Agg: RollingAggWindowNulls<'a, T, Out>,
     T: IsFloat + NativeType,
+    Out: NativeType,
 {
     let len = values.len();
     let (start, end) = det_offsets_fn(0, window_size, len);
@@ -72,20 +81,20 @@ where
                     } else {
                         // SAFETY: we are in bounds
                         unsafe { validity.set_unchecked(idx, false) };
-                        T::default()
+                        Out::default()
                     }
                 },
                 None => {
                     // SAFETY: we are in bounds
                     unsafe { validity.set_unchecked(idx, false) };
-                    T::default()
+                    Out::default()
                 },
             }
         })
         .collect_trusted::<Vec<_>>();
 
     Box::new(PrimitiveArray::new(
-        T::PRIMITIVE.into(),
+        Out::PRIMITIVE.into(),
         out.into(),
         Some(validity.into()),
     ))
@@ -96,6 +105,7 @@ mod test {
     use arrow::array::{Array, Int32Array};
     use arrow::buffer::Buffer;
     use arrow::datatypes::ArrowDataType;
+    use polars_utils::IdxSize;
     use polars_utils::min_max::MaxIgnoreNan;
 
     use super::*;
@@ -249,7 +259,7 @@ mod test {
 
         let arr = Int32Array::new(ArrowDataType::Int32, vals.into(), Some(validity));
 
-        let out = rolling_apply_agg_window::<MinMaxWindow<i32, MaxIgnoreNan>, _, _>(
+        let out = rolling_apply_agg_window::<MinMaxWindow<i32, MaxIgnoreNan>, _, _, _>(
             arr.values().as_slice(),
             arr.validity().as_ref().unwrap(),
             window_size,
@@ -264,4 +274,36 @@ mod test {
             &[3, 10, 10, 10, 10, 10, 9, 8, 7, 6, 5, 4, 3]
         );
     }
+
+    #[test]
+    fn test_rolling_counts_nulls() {
+        // 1, None, 1, 2, None, 2
+        let buf = Buffer::from(vec![1i32, 0, 1, 2, 0, 2]);
+        let arr = &PrimitiveArray::new(
+            ArrowDataType::Int32,
+            buf,
+            Some(Bitmap::from(&[true, false, true, true, false, true])),
+        );
+
+        let out = rolling_n_unique(arr, 3, 2, false, None, None);
+        let out = out
+            .as_any()
+            .downcast_ref::<PrimitiveArray<IdxSize>>()
+            .unwrap();
+        let out = out.into_iter().map(|v| v.copied()).collect::<Vec<_>>();
+        assert_eq!(out, &[None, None, Some(1), Some(2), Some(2), Some(1)]);
+
+        let out = rolling_product(arr, 3, 1, false, None, None);
+        let out = out.as_any().downcast_ref::<PrimitiveArray<i32>>().unwrap();
+        let out = out.into_iter().map(|v| v.copied()).collect::<Vec<_>>();
+        assert_eq!(out, &[Some(1), Some(1), Some(1), Some(2), Some(2), Some(4)]);
+
+        let out = rolling_rank(arr, 3, 1, false, None, None);
+        let out = out.as_any().downcast_ref::<PrimitiveArray<f64>>().unwrap();
+        let out = out.into_iter().map(|v| v.copied()).collect::<Vec<_>>();
+        assert_eq!(
+            out,
+            &[Some(1.0), None, Some(0.75), Some(1.0), None, Some(0.75)]
+        );
+    }
 }
@@ -0,0 +1,33 @@
+use polars_utils::total_ord::{TotalEq, TotalHash};
+
+pub use super::super::n_unique::NUniqueWindow;
+use super::*;
+
+/// The number of distinct non-null values in every window.
+pub fn rolling_n_unique<T>(
+    arr: &PrimitiveArray<T>,
+    window_size: usize,
+    min_periods: usize,
+    center: bool,
+    weights: Option<&[f64]>,
+    params: Option<RollingFnParams>,
+) -> ArrayRef
+where
+    T: NativeType + TotalEq + TotalHash + IsFloat,
+{
+    if weights.is_some() {
+        panic!("weights not yet supported on array with null values")
+    }
+    let offset_fn = match center {
+        true => det_offsets_center,
+        false => det_offsets,
+    };
+    rolling_apply_agg_window::<NUniqueWindow<T>, _, _, _>(
+        arr.values().as_slice(),
+        arr.validity().as_ref().unwrap(),
+        window_size,
+        min_periods,
+        offset_fn,
+        params,
+    )
+}
@@ -0,0 +1,32 @@
+pub use super::super::product::ProductWindow;
+use super::*;
+use crate::arithmetic::pl_num::PlNumArithmetic;
+
+/// The product of the non-null values in every window. Integers wrap around on overflow.
+pub fn rolling_product<T>(
+    arr: &PrimitiveArray<T>,
+    window_size: usize,
+    min_periods: usize,
+    center: bool,
+    weights: Option<&[f64]>,
+    params: Option<RollingFnParams>,
+) -> ArrayRef
+where
+    T: NativeType + One + PlNumArithmetic + IsFloat,
+{
+    if weights.is_some() {
+        panic!("weights not yet supported on array with null values")
+    }
+    let offset_fn = match center {
+        true => det_offsets_center,
+        false => det_offsets,
+    };
+    rolling_apply_agg_window::<ProductWindow<T>, _, _, _>(
+        arr.values().as_slice(),
+        arr.validity().as_ref().unwrap(),
+        window_size,
+        min_periods,
+        offset_fn,
+        params,
+    )
+}
@@ -0,0 +1,34 @@
+use polars_utils::total_ord::TotalOrd;
+
+pub use super::super::rank::RankWindow;
+use super::*;
+
+/// The rank of the last value of every window among the non-null values in that window, divided by the
+/// number of non-null values in the window. How ties are ranked is set by [`RollingFnParams::Rank`].
+pub fn rolling_rank<T>(
+    arr: &PrimitiveArray<T>,
+    window_size: usize,
+    min_periods: usize,
+    center: bool,
+    weights: Option<&[f64]>,
+    params: Option<RollingFnParams>,
+) -> ArrayRef
+where
+    T: NativeType + TotalOrd + IsFloat,
+{
+    if weights.is_some() {
+        panic!("weights not yet supported on array with null values")
+    }
+    assert!(
+        !center,
+        "`rolling_rank` ranks the last value of every window and can't be centered"
+    );
+    rolling_apply_agg_window::<RankWindow<T>, _, _, _>(
+        arr.values().as_slice(),
+        arr.validity().as_ref().unwrap(),
+        window_size,
+        min_periods,
+        det_offsets,
+        params,
+    )
+}
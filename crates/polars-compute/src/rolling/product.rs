@@ -0,0 +1,155 @@
+use arrow::bitmap::Bitmap;
+use arrow::types::NativeType;
+use num_traits::One;
+
+use super::RollingFnParams;
+use super::no_nulls::RollingAggWindowNoNulls;
+use super::nulls::RollingAggWindowNulls;
+use crate::arithmetic::pl_num::PlNumArithmetic;
+
+/// The product of the non-null values in the window.
+///
+/// Multiplication has no inverse for zeros and wrapping integers, so values can't be divided out
+/// of the window once they leave it. Instead the window is split in two: the older part keeps the
+/// products of all of its suffixes and the newer part keeps a running product. Once the window
+/// start passes the older part, the suffix products are rebuilt over the current window. Every
+/// value is part of a rebuild at most once, so updates are amortized O(1).
+pub struct ProductWindow<'a, T> {
+    values: &'a [T],
+    validity: Option<&'a Bitmap>,
+    // suffix_products[i] is the product of values[front_start + i..front_end].
+    suffix_products: Vec<T>,
+    front_start: usize,
+    front_end: usize,
+    // The product of values[front_end..last_end].
+    back_product: T,
+    nonnulls_in_window: usize,
+    last_start: usize,
+    last_end: usize,
+}
+
+impl<'a, T: NativeType + One + PlNumArithmetic> ProductWindow<'a, T> {
+    fn new_impl(values: &'a [T], validity: Option<&'a Bitmap>) -> Self {
+        Self {
+            values,
+            validity,
+            suffix_products: Vec::new(),
+            front_start: 0,
+            front_end: 0,
+            back_product: T::one(),
+            nonnulls_in_window: 0,
+            last_start: 0,
+            last_end: 0,
+        }
+    }
+
+    /// # Safety
+    /// The index must be in-bounds.
+    unsafe fn get(&self, idx: usize) -> Option<T> {
+        unsafe {
+            self.validity
+                .is_none_or(|v| v.get_bit_unchecked(idx))
+                .then(|| *self.values.get_unchecked(idx))
+        }
+    }
+
+    /// # Safety
+    /// `start` and `end` must be in-bounds.
+    unsafe fn rebuild(&mut self, start: usize, end: usize) {
+        self.suffix_products.clear();
+        self.suffix_products.resize(end - start, T::one());
+        let mut product = T::one();
+        for idx in (start..end).rev() {
+            if let Some(value) = unsafe { self.get(idx) } {
+                product = value.wrapping_mul(product);
+            }
+            self.suffix_products[idx - start] = product;
+        }
+        self.front_start = start;
+        self.front_end = end;
+        self.back_product = T::one();
+    }
+
+    /// # Safety
+    /// `start` and `end` must be in-bounds.
+    unsafe fn update_impl(&mut self, start: usize, end: usize) -> Option<T> {
+        unsafe {
+            if start >= self.last_end {
+                self.nonnulls_in_window = 0;
+                self.last_start = start;
+                self.last_end = start;
+            }
+
+            for idx in self.last_start..start {
+                self.nonnulls_in_window -= self.get(idx).is_some() as usize;
+            }
+            if start >= self.front_end {
+                self.rebuild(start, end);
+                self.nonnulls_in_window += (self.last_end..end)
+                    .filter(|idx| self.get(*idx).is_some())
+                    .count();
+            } else {
+                for idx in self.last_end..end {
+                    if let Some(value) = self.get(idx) {
+                        self.back_product = self.back_product.wrapping_mul(value);
+                        self.nonnulls_in_window += 1;
+                    }
+                }
+            }
+            self.last_start = start;
+            self.last_end = end;
+
+            let front_product = if start < self.front_end {
+                *self.suffix_products.get_unchecked(start - self.front_start)
+            } else {
+                T::one()
+            };
+            Some(front_product.wrapping_mul(self.back_product))
+        }
+    }
+}
+
+impl<'a, T: NativeType + One + PlNumArithmetic> RollingAggWindowNulls<'a, T>
+    for ProductWindow<'a, T>
+{
+    unsafe fn new(
+        slice: &'a [T],
+        validity: &'a Bitmap,
+        start: usize,
+        end: usize,
+        _params: Option<RollingFnParams>,
+        _window_size: Option<usize>,
+    ) -> Self {
+        let mut slf = Self::new_impl(slice, Some(validity));
+        unsafe { slf.update_impl(start, end) };
+        slf
+    }
+
+    unsafe fn update(&mut self, start: usize, end: usize) -> Option<T> {
+        unsafe { self.update_impl(start, end) }
+    }
+
+    fn is_valid(&self, min_periods: usize) -> bool {
+        self.nonnulls_in_window >= min_periods
+    }
+}
+
+impl<'a, T: NativeType + One + PlNumArithmetic> RollingAggWindowNoNulls<'a, T>
+    for ProductWindow<'a, T>
+{
+    fn new(
+        slice: &'a [T],
+        start: usize,
+        end: usize,
+        _params: Option<RollingFnParams>,
+        _window_size: Option<usize>,
+    ) -> Self {
+        let mut slf = Self::new_impl(slice, None);
+        unsafe { slf.update_impl(start, end) };
+        slf
+    }
+
+    unsafe fn update(&mut self, start: usize, end: usize) -> Option<T> {
+        unsafe { self.update_impl(start, end) }
+    }
+}
@@ -0,0 +1,162 @@
+use arrow::bitmap::Bitmap;
+use arrow::types::NativeType;
+use polars_utils::total_ord::TotalOrd;
+
+use super::no_nulls::RollingAggWindowNoNulls;
+use super::nulls::RollingAggWindowNulls;
+use super::{RollingFnParams, RollingRankMethod};
+
+/// The rank of the last value of the window among the non-null values in the window, divided by
+/// the number of those values.
+///
+/// The window is kept as a sorted `Vec`, so every value entering or leaving the window costs
+/// `O(w)` for a window of `w` values.
+pub struct RankWindow<'a, T> {
+    values: &'a [T],
+    validity: Option<&'a Bitmap>,
+    // The non-null values within the window, kept sorted.
+    sorted: Vec<T>,
+    method: RollingRankMethod,
+    last_start: usize,
+    last_end: usize,
+}
+
+impl<'a, T: NativeType + TotalOrd> RankWindow<'a, T> {
+    fn new_impl(
+        values: &'a [T],
+        validity: Option<&'a Bitmap>,
+        params: Option<RollingFnParams>,
+        window_size: Option<usize>,
+    ) -> Self {
+        let method = match params {
+            None => RollingRankMethod::default(),
+            Some(RollingFnParams::Rank { method }) => method,
+            _ => unreachable!("expected rank params"),
+        };
+        Self {
+            values,
+            validity,
+            sorted: Vec::with_capacity(window_size.unwrap_or_default()),
+            method,
+            last_start: 0,
+            last_end: 0,
+        }
+    }
+
+    /// # Safety
+    /// The index must be in-bounds.
+    unsafe fn is_nonnull(&self, idx: usize) -> bool {
+        self.validity
+            .is_none_or(|v| unsafe { v.get_bit_unchecked(idx) })
+    }
+
+    /// # Safety
+    /// `start` and `end` must be in-bounds.
+    unsafe fn update_window(&mut self, start: usize, end: usize) {
+        unsafe {
+            if start >= self.last_end {
+                self.sorted.clear();
+                self.last_start = start;
+                self.last_end = start;
+            }
+
+            for idx in self.last_start..start {
+                if self.is_nonnull(idx) {
+                    let value = self.values.get_unchecked(idx);
+                    let pos = self.sorted.partition_point(|v| v.tot_lt(value));
+                    self.sorted.remove(pos);
+                }
+            }
+            for idx in self.last_end..end {
+                if self.is_nonnull(idx) {
+                    let value = *self.values.get_unchecked(idx);
+                    let pos = self.sorted.partition_point(|v| v.tot_lt(&value));
+                    self.sorted.insert(pos, value);
+                }
+            }
+            self.last_start = start;
+            self.last_end = end;
+        }
+    }
+
+    /// Moves the window to `start..end` and returns the rank of the value at `idx`.
+    ///
+    /// If `idx` lies outside of the window, as it does for windows that are not closed on the
+    /// right, the value is ranked as if it was part of the window.
+    ///
+    /// # Safety
+    /// `start`, `end` and `idx` must be in-bounds.
+    pub unsafe fn update_rank(&mut self, start: usize, end: usize, idx: usize) -> Option<f64> {
+        unsafe {
+            self.update_window(start, end);
+            if !self.is_nonnull(idx) {
+                return None;
+            }
+            let value = self.values.get_unchecked(idx);
+            let lower = self.sorted.partition_point(|v| v.tot_lt(value));
+            let mut upper = self.sorted.partition_point(|v| v.tot_le(value));
+            let mut len = self.sorted.len();
+            if !(start..end).contains(&idx) {
+                upper += 1;
+                len += 1;
+            }
+            let rank = match self.method {
+                RollingRankMethod::Average => (lower + 1 + upper) as f64 / 2.0,
+                RollingRankMethod::Min => (lower + 1) as f64,
+                RollingRankMethod::Max => upper as f64,
+            };
+            Some(rank / len as f64)
+        }
+    }
+
+    /// # Safety
+    /// `start` and `end` must be in-bounds.
+    unsafe fn update_impl(&mut self, start: usize, end: usize) -> Option<f64> {
+        if start == end {
+            unsafe { self.update_window(start, end) };
+            return None;
+        }
+        unsafe { self.update_rank(start, end, end - 1) }
+    }
+}
+
+impl<'a, T: NativeType + TotalOrd> RollingAggWindowNulls<'a, T, f64> for RankWindow<'a, T> {
+    unsafe fn new(
+        slice: &'a [T],
+        validity: &'a Bitmap,
+        start: usize,
+        end: usize,
+        params: Option<RollingFnParams>,
+        window_size: Option<usize>,
+    ) -> Self {
+        let mut slf = Self::new_impl(slice, Some(validity), params, window_size);
+        unsafe { slf.update_impl(start, end) };
+        slf
+    }
+
+    unsafe fn update(&mut self, start: usize, end: usize) -> Option<f64> {
+        unsafe { self.update_impl(start, end) }
+    }
+
+    fn is_valid(&self, min_periods: usize) -> bool {
+        self.sorted.len() >= min_periods
+    }
+}
+
+impl<'a, T: NativeType + TotalOrd> RollingAggWindowNoNulls<'a, T, f64> for RankWindow<'a, T> {
+    fn new(
+        slice: &'a [T],
+        start: usize,
+        end: usize,
+        params: Option<RollingFnParams>,
+        window_size: Option<usize>,
+    ) -> Self {
+        let mut slf = Self::new_impl(slice, None, params, window_size);
+        unsafe { slf.update_impl(start, end) };
+        slf
+    }
+
+    unsafe fn update(&mut self, start: usize, end: usize) -> Option<f64> {
+        unsafe { self.update_impl(start, end) }
+    }
+}
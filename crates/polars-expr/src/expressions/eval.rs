@@ -93,6 +93,16 @@ impl EvalExpr {
         }
     }
 
+    /// The dtype of the `List` evaluation. `arr.eval` runs as a `List` evaluation as well and only
+    /// converts the result back to an `Array` at the end.
+    fn list_output_dtype(&self) -> Cow<'_, DataType> {
+        match &self.non_aggregated_output_dtype {
+            #[cfg(feature = "dtype-array")]
+            DataType::Array(inner, _) => Cow::Owned(DataType::List(inner.clone())),
+            dt => Cow::Borrowed(dt),
+        }
+    }
+
     fn run_elementwise_on_values(
         &self,
         lst: &ListChunked,
@@ -101,7 +111,7 @@ impl EvalExpr {
         if lst.chunks().is_empty() {
             return Ok(Column::new_empty(
                 self.output_field_with_ctx.name.clone(),
-                &self.non_aggregated_output_dtype,
+                &self.list_output_dtype(),
             ));
         }
 
@@ -109,9 +119,10 @@ impl EvalExpr {
             .trim_lists_to_normalized_offsets()
             .map_or(Cow::Borrowed(lst), Cow::Owned);
 
-        let output_arrow_dtype = self
-            .non_aggregated_output_dtype
+        let output_dtype = self.list_output_dtype();
+        let output_arrow_dtype = output_dtype
             .clone()
+            .into_owned()
             .to_arrow(CompatLevel::newest());
         let output_arrow_dtype_physical = output_arrow_dtype.underlying_physical_type();
 
@@ -155,7 +166,7 @@ impl EvalExpr {
                 .collect::<PolarsResult<Vec<Box<dyn Array>>>>()?
         };
 
-        let out_inner_dt = self.non_aggregated_output_dtype.inner_dtype().unwrap();
+        let out_inner_dt = output_dtype.inner_dtype().unwrap();
         Ok(unsafe {
             ListChunked::from_chunks(self.output_field_with_ctx.name.clone(), chunks)
                 .from_physical_unchecked(out_inner_dt.clone())
@@ -165,6 +176,7 @@ impl EvalExpr {
     }
 
     fn run_per_sublist(&self, lst: &ListChunked, state: &ExecutionState) -> PolarsResult<Column> {
+        let output_dtype = self.list_output_dtype();
         let mut err = None;
         let mut ca: ListChunked = if self.allow_threading {
             let m_err = Mutex::new(None);
@@ -183,10 +195,7 @@ impl EvalExpr {
                             }
                         })
                     })
-                    .collect_ca_with_dtype(
-                        PlSmallStr::EMPTY,
-                        self.non_aggregated_output_dtype.clone(),
-                    )
+                    .collect_ca_with_dtype(PlSmallStr::EMPTY, output_dtype.clone().into_owned())
             });
             err = m_err.into_inner().unwrap();
             ca
@@ -217,8 +226,8 @@ impl EvalExpr {
         ca.rename(lst.name().clone());
 
         // Cast may still be required in some cases, e.g. for an empty frame when running single-threaded
-        if ca.dtype() != &self.non_aggregated_output_dtype {
-            ca.cast(&self.non_aggregated_output_dtype).map(Column::from)
+        if ca.dtype() != output_dtype.as_ref() {
+            ca.cast(&output_dtype).map(Column::from)
         } else {
             Ok(ca.into_column())
         }
@@ -277,6 +286,22 @@ impl EvalExpr {
         }
     }
 
+    fn evaluate_on_array_column(
+        &self,
+        arr: &Column,
+        as_list: bool,
+        state: &ExecutionState,
+    ) -> PolarsResult<Column> {
+        let inner = self.variant.element_dtype(arr.dtype())?;
+        let lst = arr.cast(&DataType::List(Box::new(inner.clone())))?;
+        let out = self.evaluate_on_list_chunked(lst.list()?, state)?;
+        if as_list {
+            Ok(out)
+        } else {
+            out.cast(&self.non_aggregated_output_dtype)
+        }
+    }
+
     fn evaluate_cumulative_eval(
         &self,
         input: &Series,
@@ -356,6 +381,7 @@ impl PhysicalExpr for EvalExpr {
                 let lst = input.list()?;
                 self.evaluate_on_list_chunked(lst, state)
             },
+            EvalVariant::Array { as_list } => self.evaluate_on_array_column(&input, as_list, state),
             EvalVariant::Cumulative { min_samples } => self
                 .evaluate_cumulative_eval(input.as_materialized_series(), min_samples, state)
                 .map(Column::from),
@@ -374,6 +400,10 @@ impl PhysicalExpr for EvalExpr {
                 let out = self.evaluate_on_list_chunked(input.get_values().list()?, state)?;
                 input.with_values(out, false, Some(&self.expr))?;
             },
+            EvalVariant::Array { as_list } => {
+                let out = self.evaluate_on_array_column(input.get_values(), as_list, state)?;
+                input.with_values(out, false, Some(&self.expr))?;
+            },
             EvalVariant::Cumulative { min_samples } => {
                 let mut builder = AnonymousOwnedListBuilder::new(
                     self.output_field_with_ctx.name().clone(),
@@ -0,0 +1,257 @@
+//! Capture of records that could not be parsed.
+//!
+//! Readers that are given a [`BadRecords`] collector divert records that fail to parse into the
+//! collector instead of raising an error. These records are removed from the output of the
+//! reader, and do not count towards row indices or slices. Once the read is done, the collector
+//! can be turned into a [`DataFrame`] with [`BadRecords::take`].
+//!
+//! Only records that fail to parse are captured. Values that parse, but then fail the cast to the
+//! requested dtype (e.g. temporal types parsed from strings), raise an error as usual.
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::sync::{Arc, Mutex};
+
+use polars_core::prelude::*;
+
+/// A record that could not be parsed, as found by the parser of a single chunk.
+#[derive(Clone, Debug)]
+pub struct RawBadRecord {
+    /// Byte offset of the start of the record in the parsed bytes.
+    pub offset: usize,
+    /// Index of the (null filled) row that was produced for this record.
+    pub row: usize,
+    /// The raw text of the record.
+    pub record: String,
+    pub error: String,
+}
+
+/// Records that could not be parsed in a single chunk of data.
+#[derive(Clone, Debug, Default)]
+pub struct ChunkBadRecords {
+    /// Number of rows produced so far by the parser.
+    pub(crate) n_rows: usize,
+    records: Vec<RawBadRecord>,
+}
+
+impl ChunkBadRecords {
+    pub fn is_empty(&self) -> bool {
+        self.records.is_empty()
+    }
+
+    pub fn records(&self) -> &[RawBadRecord] {
+        &self.records
+    }
+
+    /// Register that the row currently being produced by the parser is bad.
+    pub(crate) fn push(&mut self, offset: usize, record: &[u8], error: String) {
+        self.records.push(RawBadRecord {
+            offset,
+            row: self.n_rows,
+            record: String::from_utf8_lossy(record).into_owned(),
+            error,
+        })
+    }
+
+    /// Remove the rows that were produced for the bad records from `df`.
+    pub fn filter_rows(&self, df: &DataFrame) -> PolarsResult<DataFrame> {
+        // Without columns the height of `df` is not tracked, use the rows of the parser instead.
+        if df.width() == 0 {
+            return Ok(DataFrame::empty_with_height(
+                self.n_rows - self.records.len(),
+            ));
+        }
+        if self.records.is_empty() {
+            return Ok(df.clone());
+        }
+
+        let mut mask = vec![true; df.height()];
+        for r in self.records.iter() {
+            mask[r.row] = false;
+        }
+        let mask = BooleanChunked::from_slice(PlSmallStr::EMPTY, &mask);
+        df.filter(&mask)
+    }
+}
+
+/// Incrementally converts byte offsets into 1-based line numbers.
+///
+/// The cost of a lookup is proportional to the distance to the previously looked up offset, so
+/// offsets should be looked up in (mostly) sorted order.
+#[derive(Clone, Debug)]
+pub struct LineCounter {
+    eol_char: u8,
+    offset: usize,
+    line: usize,
+}
+
+impl LineCounter {
+    pub fn new(eol_char: u8) -> Self {
+        Self::starting_at(eol_char, 1)
+    }
+
+    /// Create a counter for bytes that start at line `line`.
+    pub fn starting_at(eol_char: u8, line: usize) -> Self {
+        Self {
+            eol_char,
+            offset: 0,
+            line,
+        }
+    }
+
+    /// Line number of the byte at `offset` in `bytes`.
+    pub fn line_at(&mut self, bytes: &[u8], offset: usize) -> usize {
+        let offset = offset.min(bytes.len());
+
+        if offset >= self.offset {
+            self.line += memchr::memchr_iter(self.eol_char, &bytes[self.offset..offset]).count();
+        } else {
+            self.line -= memchr::memchr_iter(self.eol_char, &bytes[offset..self.offset]).count();
+        }
+        self.offset = offset;
+
+        self.line
+    }
+}
+
+#[derive(Clone, Debug)]
+struct BadRecord {
+    path: Option<PlSmallStr>,
+    line: usize,
+    record: String,
+    error: String,
+}
+
+/// Shared collector of records that could not be parsed.
+///
+/// Clones refer to the same collector, so a clone can be handed to a reader (or lazy scan) and
+/// inspected after the read. Equality and hashing are based on identity.
+#[derive(Clone, Default)]
+pub struct BadRecords(Arc<Mutex<Vec<BadRecord>>>);
+
+impl BadRecords {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Number of records collected so far.
+    pub fn len(&self) -> usize {
+        self.0.lock().unwrap().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Add the bad records found in a chunk of `bytes`. `lines` must count the lines of `bytes`,
+    /// i.e. start at the line number of the start of `bytes`.
+    pub fn extend(
+        &self,
+        path: Option<&PlSmallStr>,
+        chunk: &ChunkBadRecords,
+        bytes: &[u8],
+        lines: &mut LineCounter,
+    ) {
+        if chunk.is_empty() {
+            return;
+        }
+
+        let records = chunk.records().iter().map(|r| BadRecord {
+            path: path.cloned(),
+            line: lines.line_at(bytes, r.offset),
+            record: r.record.clone(),
+            error: r.error.clone(),
+        });
+
+        self.0.lock().unwrap().extend(records);
+    }
+
+    /// Take the collected records as a [`DataFrame`] with the columns `path`, `line`, `record`
+    /// and `error`, sorted by path and line. The collector is empty afterwards.
+    pub fn take(&self) -> DataFrame {
+        let mut records = std::mem::take(&mut *self.0.lock().unwrap());
+        records.sort_by(|a, b| (&a.path, a.line).cmp(&(&b.path, b.line)));
+
+        let height = records.len();
+        let path = StringChunked::from_iter_options(
+            PlSmallStr::from_static("path"),
+            records.iter().map(|r| r.path.as_deref()),
+        );
+        let line = UInt64Chunked::from_iter_values(
+            PlSmallStr::from_static("line"),
+            records.iter().map(|r| r.line as u64),
+        );
+        let record = StringChunked::from_iter_values(
+            PlSmallStr::from_static("record"),
+            records.iter().map(|r| r.record.as_str()),
+        );
+        let error = StringChunked::from_iter_values(
+            PlSmallStr::from_static("error"),
+            records.iter().map(|r| r.error.as_str()),
+        );
+
+        DataFrame::new_with_height(
+            height,
+            vec![
+                path.into_column(),
+                line.into_column(),
+                record.into_column(),
+                error.into_column(),
+            ],
+        )
+        .unwrap()
+    }
+}
+
+impl fmt::Debug for BadRecords {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "BadRecords({})", self.len())
+    }
+}
+
+impl PartialEq for BadRecords {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl Eq for BadRecords {}
+
+impl Hash for BadRecords {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        Arc::as_ptr(&self.0).hash(state)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use polars_core::df;
+
+    use super::*;
+
+    #[test]
+    fn test_line_counter() {
+        let bytes = b"a\nb\n\nc\n";
+        let mut lines = LineCounter::new(b'\n');
+        assert_eq!(lines.line_at(bytes, 0), 1);
+        assert_eq!(lines.line_at(bytes, 5), 4);
+        assert_eq!(lines.line_at(bytes, 2), 2);
+        assert_eq!(lines.line_at(bytes, 100), 5);
+    }
+
+    #[test]
+    fn test_chunk_bad_records() {
+        let df = df!["a" => [1, 2, 3, 4]].unwrap();
+        let mut chunk = ChunkBadRecords::default();
+        for row in [1, 3] {
+            chunk.n_rows = row;
+            chunk.push(row, b"x", "error".into());
+        }
+
+        assert!(
+            chunk
+                .filter_rows(&df)
+                .unwrap()
+                .equals(&df!["a" => [1, 3]].unwrap())
+        );
+    }
+}
@@ -0,0 +1,232 @@
+//! Transcoding of non-UTF-8 CSV data.
+//!
+//! The CSV parser only operates on UTF-8 (or ASCII compatible) bytes. Data in any other
+//! [`CsvEncoding`] is transcoded to UTF-8 before it is handed to the parser. Transcoding is done
+//! incrementally, so that readers that receive their data in pieces (e.g. the streaming CSV
+//! source) do not need to hold both the encoded and the decoded file in memory.
+use polars_error::PolarsResult;
+
+use super::CsvEncoding;
+
+/// Number of input bytes that are transcoded at once when transcoding a full buffer.
+const TRANSCODE_BLOCK_SIZE: usize = 1 << 20;
+
+impl CsvEncoding {
+    /// Whether data in this encoding has to be transcoded to UTF-8 before it can be parsed.
+    pub fn requires_transcoding(&self) -> bool {
+        !matches!(self, CsvEncoding::Utf8 | CsvEncoding::LossyUtf8)
+    }
+
+    fn to_encoding_rs(self) -> Option<&'static encoding_rs::Encoding> {
+        use CsvEncoding::*;
+        match self {
+            Utf8 | LossyUtf8 | Latin1 => None,
+            Windows1252 => Some(encoding_rs::WINDOWS_1252),
+            ShiftJis => Some(encoding_rs::SHIFT_JIS),
+            Utf16Le => Some(encoding_rs::UTF_16LE),
+            Utf16Be => Some(encoding_rs::UTF_16BE),
+        }
+    }
+}
+
+enum TranscoderImpl {
+    Latin1,
+    EncodingRs(encoding_rs::Decoder),
+}
+
+/// Incremental transcoder from a [`CsvEncoding`] to UTF-8.
+///
+/// Malformed input sequences are replaced with the Unicode replacement character (`�`). A byte
+/// order mark at the start of UTF-16 input is removed.
+pub struct Utf8Transcoder {
+    inner: TranscoderImpl,
+}
+
+impl Utf8Transcoder {
+    /// Returns `None` if the encoding does not require transcoding.
+    pub fn new(encoding: CsvEncoding) -> Option<Self> {
+        if !encoding.requires_transcoding() {
+            return None;
+        }
+
+        let inner = match encoding.to_encoding_rs() {
+            None => TranscoderImpl::Latin1,
+            Some(encoding) => TranscoderImpl::EncodingRs(encoding.new_decoder_with_bom_removal()),
+        };
+
+        Some(Self { inner })
+    }
+
+    /// Transcode `input` and append the UTF-8 output to `out`.
+    ///
+    /// Incomplete multi-byte sequences at the end of `input` are buffered until the next call.
+    /// `last` must be set for the final piece of input so that such a trailing sequence is
+    /// flushed.
+    pub fn transcode(&mut self, input: &[u8], last: bool, out: &mut Vec<u8>) {
+        match &mut self.inner {
+            TranscoderImpl::Latin1 => {
+                out.reserve(input.len());
+                for &b in input {
+                    if b.is_ascii() {
+                        out.push(b);
+                    } else {
+                        // All code points of Latin-1 encode to 2 UTF-8 bytes.
+                        out.extend_from_slice(&[0xC0 | (b >> 6), 0x80 | (b & 0x3F)]);
+                    }
+                }
+            },
+            TranscoderImpl::EncodingRs(decoder) => {
+                let offset = out.len();
+                let max_len = decoder
+                    .max_utf8_buffer_length(input.len())
+                    .expect("transcoding buffer size overflow");
+                out.resize(offset + max_len, 0);
+
+                let (result, n_read, n_written, _) =
+                    decoder.decode_to_utf8(input, &mut out[offset..], last);
+
+                debug_assert_eq!(result, encoding_rs::CoderResult::InputEmpty);
+                debug_assert_eq!(n_read, input.len());
+                out.truncate(offset + n_written);
+            },
+        }
+    }
+}
+
+/// Transcode `bytes` to UTF-8 if the encoding requires it, otherwise simply return it.
+/// An `out` vec must be given for ownership of the transcoded data.
+#[allow(clippy::ptr_arg)]
+pub fn maybe_transcode_bytes<'a>(
+    bytes: &'a [u8],
+    out: &'a mut Vec<u8>,
+    encoding: CsvEncoding,
+) -> PolarsResult<&'a [u8]> {
+    assert!(out.is_empty());
+
+    let Some(mut transcoder) = Utf8Transcoder::new(encoding) else {
+        return Ok(bytes);
+    };
+
+    out.reserve(bytes.len());
+
+    let mut chunks = bytes.chunks(TRANSCODE_BLOCK_SIZE).peekable();
+    while let Some(chunk) = chunks.next() {
+        transcoder.transcode(chunk, chunks.peek().is_none(), out);
+    }
+    if bytes.is_empty() {
+        transcoder.transcode(&[], true, out);
+    }
+
+    Ok(out)
+}
+
+/// Encode UTF-8 data produced by the CSV writer into `encoding`, appending to `out`.
+///
+/// Returns an error if a character cannot be represented in the target encoding.
+pub(crate) fn encode_from_utf8(
+    input: &str,
+    encoding: CsvEncoding,
+    out: &mut Vec<u8>,
+) -> PolarsResult<()> {
+    use polars_error::polars_bail;
+
+    match encoding {
+        CsvEncoding::Utf8 | CsvEncoding::LossyUtf8 => out.extend_from_slice(input.as_bytes()),
+        CsvEncoding::Latin1 => {
+            out.reserve(input.len());
+            for c in input.chars() {
+                let Ok(b) = u8::try_from(c) else {
+                    polars_bail!(ComputeError: "character '{}' cannot be encoded as {:?}", c, encoding)
+                };
+                out.push(b);
+            }
+        },
+        // `encoding_rs` only decodes UTF-16 (its encoders output UTF-8 for these).
+        CsvEncoding::Utf16Le => out.extend(input.encode_utf16().flat_map(u16::to_le_bytes)),
+        CsvEncoding::Utf16Be => out.extend(input.encode_utf16().flat_map(u16::to_be_bytes)),
+        CsvEncoding::Windows1252 | CsvEncoding::ShiftJis => {
+            let mut encoder = encoding.to_encoding_rs().unwrap().new_encoder();
+            let offset = out.len();
+            let max_len = encoder
+                .max_buffer_length_from_utf8_without_replacement(input.len())
+                .expect("transcoding buffer size overflow");
+            out.resize(offset + max_len, 0);
+
+            let (result, _, n_written) =
+                encoder.encode_from_utf8_without_replacement(input, &mut out[offset..], true);
+            out.truncate(offset + n_written);
+
+            if let encoding_rs::EncoderResult::Unmappable(c) = result {
+                polars_bail!(ComputeError: "character '{}' cannot be encoded as {:?}", c, encoding)
+            }
+        },
+    }
+
+    Ok(())
+}
+
+/// Byte order mark of `encoding`, if it has one.
+pub(crate) fn byte_order_mark(encoding: CsvEncoding) -> Option<&'static [u8]> {
+    match encoding {
+        CsvEncoding::Utf8 | CsvEncoding::LossyUtf8 => Some(&[0xEF, 0xBB, 0xBF]),
+        CsvEncoding::Utf16Le => Some(&[0xFF, 0xFE]),
+        CsvEncoding::Utf16Be => Some(&[0xFE, 0xFF]),
+        CsvEncoding::Latin1 | CsvEncoding::Windows1252 | CsvEncoding::ShiftJis => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn transcode(bytes: &[u8], encoding: CsvEncoding) -> String {
+        let mut out = vec![];
+        let bytes = maybe_transcode_bytes(bytes, &mut out, encoding).unwrap();
+        String::from_utf8(bytes.to_vec()).unwrap()
+    }
+
+    #[test]
+    fn test_transcode_to_utf8() {
+        assert_eq!(transcode(b"a,\xe9\n", CsvEncoding::Latin1), "a,é\n");
+        // 0x80 is the euro sign in windows-1252 but a C1 control in Latin-1.
+        assert_eq!(transcode(b"\x80", CsvEncoding::Windows1252), "€");
+        assert_eq!(transcode(b"\x80", CsvEncoding::Latin1), "\u{80}");
+        assert_eq!(
+            transcode(b"\x93\xfa\x96\x7b", CsvEncoding::ShiftJis),
+            "日本"
+        );
+        assert_eq!(
+            transcode(b"\xff\xfea\x00,\x00b\x00", CsvEncoding::Utf16Le),
+            "a,b"
+        );
+        assert_eq!(transcode(b"\x00a\x00\n", CsvEncoding::Utf16Be), "a\n");
+    }
+
+    #[test]
+    fn test_transcode_split_sequence() {
+        // A multi-byte sequence split over two calls must be buffered.
+        let mut transcoder = Utf8Transcoder::new(CsvEncoding::ShiftJis).unwrap();
+        let mut out = vec![];
+        transcoder.transcode(b"\x93", false, &mut out);
+        transcoder.transcode(b"\xfa", true, &mut out);
+        assert_eq!(out, "日".as_bytes());
+    }
+
+    #[test]
+    fn test_encode_from_utf8() {
+        let encode = |s: &str, encoding| {
+            let mut out = vec![];
+            encode_from_utf8(s, encoding, &mut out).map(|_| out)
+        };
+
+        assert_eq!(encode("é", CsvEncoding::Latin1).unwrap(), b"\xe9");
+        assert_eq!(encode("€", CsvEncoding::Windows1252).unwrap(), b"\x80");
+        assert_eq!(
+            encode("日本", CsvEncoding::ShiftJis).unwrap(),
+            b"\x93\xfa\x96\x7b"
+        );
+        assert_eq!(encode("a\n", CsvEncoding::Utf16Le).unwrap(), b"a\x00\n\x00");
+        assert!(encode("€", CsvEncoding::Latin1).is_err());
+        assert!(encode("日", CsvEncoding::Windows1252).is_err());
+    }
+}
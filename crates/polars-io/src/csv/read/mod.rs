@@ -17,6 +17,7 @@
 //! ```
 
 pub mod buffer;
+pub mod encoding;
 mod options;
 mod parser;
 mod read_impl;
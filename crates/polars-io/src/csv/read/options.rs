@@ -10,6 +10,7 @@ use polars_utils::pl_str::PlSmallStr;
 use serde::{Deserialize, Serialize};
 
 use crate::RowIndex;
+use crate::bad_records::BadRecords;
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -42,6 +43,9 @@ pub struct CsvReadOptions {
     pub raise_if_empty: bool,
     pub ignore_errors: bool,
     pub fields_to_cast: Vec<Field>,
+    /// Collector for records that could not be parsed.
+    #[cfg_attr(any(feature = "serde", feature = "dsl-schema"), serde(skip))]
+    pub bad_records: Option<BadRecords>,
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
@@ -88,6 +92,7 @@ impl Default for CsvReadOptions {
             raise_if_empty: true,
             ignore_errors: false,
             fields_to_cast: vec![],
+            bad_records: None,
         }
     }
 }
@@ -247,6 +252,14 @@ impl CsvReadOptions {
         self
     }
 
+    /// Divert records that cannot be parsed to `bad_records` instead of raising an error. These
+    /// records are removed from the output and don't count towards the row index or `n_rows`.
+    /// Takes precedence over `ignore_errors`.
+    pub fn with_bad_records(mut self, bad_records: Option<BadRecords>) -> Self {
+        self.bad_records = bad_records;
+        self
+    }
+
     /// Apply a function to the parse options.
     pub fn map_parse_options<F: Fn(CsvParseOptions) -> CsvParseOptions>(
         mut self,
@@ -279,7 +292,8 @@ impl CsvParseOptions {
         self
     }
 
-    /// Set the encoding used by the file.
+    /// Set the encoding used by the file. Encodings other than UTF-8 are transcoded to UTF-8
+    /// while reading.
     pub fn with_encoding(mut self, encoding: CsvEncoding) -> Self {
         self.encoding = encoding;
         self
@@ -339,6 +353,17 @@ pub enum CsvEncoding {
     Utf8,
     /// Utf8 encoding and unknown bytes are replaced with �.
     LossyUtf8,
+    /// ISO-8859-1 (Latin-1) encoding.
+    Latin1,
+    /// Windows-1252 encoding, a superset of Latin-1 that assigns printable characters to the
+    /// bytes 0x80-0x9F.
+    Windows1252,
+    /// Shift JIS encoding.
+    ShiftJis,
+    /// Little-endian UTF-16 encoding.
+    Utf16Le,
+    /// Big-endian UTF-16 encoding.
+    Utf16Be,
 }
 
 #[derive(Clone, Debug, Eq, PartialEq, Hash)]
@@ -424,7 +449,7 @@ impl NullValuesCompiled {
     /// # Safety
     ///
     /// The caller must ensure that `index` is in bounds
-    pub(super) unsafe fn is_null(&self, field: &[u8], index: usize) -> bool {
+    pub(crate) unsafe fn is_null(&self, field: &[u8], index: usize) -> bool {
         use NullValuesCompiled::*;
         match self {
             AllColumnsSingle(v) => v.as_bytes() == field,
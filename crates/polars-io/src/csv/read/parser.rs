@@ -8,9 +8,10 @@ use polars_utils::select::select_unpredictable;
 use rayon::prelude::*;
 
 use super::CsvParseOptions;
-use super::buffer::Buffer;
-use super::options::{CommentPrefix, NullValuesCompiled};
+use super::buffer::{Buffer, validate_utf8};
+use super::options::{CommentPrefix, CsvEncoding, NullValuesCompiled};
 use super::splitfields::SplitFields;
+use crate::bad_records::ChunkBadRecords;
 use crate::prelude::_csv_read_internal::find_starting_point;
 use crate::utils::compression::maybe_decompress_bytes;
 
@@ -955,11 +956,16 @@ pub(super) fn parse_lines(
     // length of original schema
     schema_len: usize,
     schema: &Schema,
+    mut bad_records: Option<&mut ChunkBadRecords>,
 ) -> PolarsResult<usize> {
     assert!(
         !projection.is_empty(),
         "at least one column should be projected"
     );
+    // When collecting bad records the chunk is not validated upfront, so we validate the string
+    // fields instead.
+    let validate_utf8_fields =
+        bad_records.is_some() && matches!(parse_options.encoding, CsvEncoding::Utf8);
     let mut truncate_ragged_lines = parse_options.truncate_ragged_lines;
     // During projection pushdown we are not checking other csv fields.
     // This would be very expensive and we don't care as we only want
@@ -1004,6 +1010,8 @@ pub(super) fn parse_lines(
         );
         let mut idx = 0u32;
         let mut read_sol = 0;
+        let line_start = bytes;
+        let mut line_error: Option<String> = None;
         loop {
             match iter.next() {
                 // end of line
@@ -1047,6 +1055,41 @@ pub(super) fn parse_lines(
                         }
                         if add_null {
                             buf.add_null(!parse_options.missing_is_null && field.is_empty())
+                        } else if bad_records.is_some() {
+                            let invalid_utf8 = validate_utf8_fields
+                                && matches!(buf, Buffer::Utf8(_))
+                                && !validate_utf8(field);
+
+                            if invalid_utf8
+                                || buf
+                                    .add(
+                                        field,
+                                        false,
+                                        needs_escaping,
+                                        parse_options.missing_is_null,
+                                    )
+                                    .is_err()
+                            {
+                                buf.add_null(false);
+                                if line_error.is_none() {
+                                    let column_name = schema.get_at_index(idx as usize).unwrap().0;
+                                    line_error = Some(if invalid_utf8 {
+                                        format!(
+                                            "invalid utf-8 sequence at column '{}' (column number {})",
+                                            column_name,
+                                            idx + 1
+                                        )
+                                    } else {
+                                        format!(
+                                            "could not parse `{}` as dtype `{}` at column '{}' (column number {})",
+                                            String::from_utf8_lossy(field),
+                                            buf.dtype(),
+                                            column_name,
+                                            idx + 1
+                                        )
+                                    });
+                                }
+                            }
                         } else {
                             buf.add(field, ignore_errors, needs_escaping, parse_options.missing_is_null)
                                 .map_err(|e| {
@@ -1084,9 +1127,15 @@ pub(super) fn parse_lines(
                                     bytes = unsafe { bytes.get_unchecked(read_sol..) };
                                 } else {
                                     if !truncate_ragged_lines && read_sol < bytes.len() {
-                                        polars_bail!(ComputeError: r#"found more fields than defined in 'Schema'
+                                        if bad_records.is_some() {
+                                            line_error.get_or_insert_with(|| {
+                                                "found more fields than defined in 'Schema'".into()
+                                            });
+                                        } else {
+                                            polars_bail!(ComputeError: r#"found more fields than defined in 'Schema'
 
 Consider setting 'truncate_ragged_lines={}'."#, polars_error::constants::TRUE)
+                                        }
                                     }
                                     let bytes_rem = skip_this_line(
                                         unsafe { bytes.get_unchecked(read_sol - 1..) },
@@ -1116,6 +1165,23 @@ Consider setting 'truncate_ragged_lines={}'."#, polars_error::constants::TRUE)
             buf.add_null(!parse_options.missing_is_null);
             processed_fields += 1;
         }
+
+        if let Some(bad_records) = bad_records.as_deref_mut() {
+            if let Some(error) = line_error {
+                let mut line = &line_start[..line_start.len() - bytes.len()];
+                if let [rest @ .., last] = line {
+                    if *last == parse_options.eol_char {
+                        line = rest;
+                    }
+                }
+                if let [rest @ .., b'\r'] = line {
+                    line = rest;
+                }
+                let line_offset = offset + line_start.as_ptr() as usize - start;
+                bad_records.push(line_offset, line, error);
+            }
+            bad_records.n_rows += 1;
+        }
         line_count += 1;
     }
 }
@@ -13,6 +13,7 @@ use rayon::prelude::*;
 
 use super::CsvParseOptions;
 use super::buffer::init_buffers;
+use super::encoding::maybe_transcode_bytes;
 use super::options::{CommentPrefix, CsvEncoding, NullValuesCompiled};
 use super::parser::{
     CountLines, SplitLines, is_comment_line, parse_lines, skip_bom, skip_line_ending,
@@ -23,6 +24,7 @@ use super::schema_inference::infer_file_schema;
 #[cfg(feature = "decompress")]
 use super::utils::decompress;
 use crate::RowIndex;
+use crate::bad_records::{BadRecords, ChunkBadRecords, LineCounter};
 use crate::csv::read::parser::skip_this_line_naive;
 use crate::mmap::ReaderBytes;
 use crate::predicates::PhysicalIoExpr;
@@ -121,6 +123,9 @@ pub(crate) struct CoreReader<'a> {
     predicate: Option<Arc<dyn PhysicalIoExpr>>,
     to_cast: Vec<Field>,
     row_index: Option<RowIndex>,
+    bad_records: Option<BadRecords>,
+    /// Path of the file, used to identify bad records.
+    path: Option<PlSmallStr>,
 }
 
 impl fmt::Debug for CoreReader<'_> {
@@ -156,10 +161,11 @@ impl<'a> CoreReader<'a> {
         skip_rows_after_header: usize,
         row_index: Option<RowIndex>,
         raise_if_empty: bool,
+        bad_records: Option<BadRecords>,
+        path: Option<PlSmallStr>,
     ) -> PolarsResult<CoreReader<'a>> {
         let separator = parse_options.separator;
 
-        #[cfg(feature = "decompress")]
         let mut reader_bytes = reader_bytes;
 
         if !cfg!(feature = "decompress") && SupportedCompression::check(&reader_bytes).is_some() {
@@ -173,8 +179,11 @@ impl<'a> CoreReader<'a> {
         // again after decompression.
         #[cfg(feature = "decompress")]
         {
-            let total_n_rows =
-                n_rows.map(|n| skip_rows + (has_header as usize) + skip_rows_after_header + n);
+            // Lines can only be counted after transcoding, so we decompress everything if the
+            // data is not UTF-8.
+            let total_n_rows = n_rows
+                .filter(|_| !parse_options.encoding.requires_transcoding())
+                .map(|n| skip_rows + (has_header as usize) + skip_rows_after_header + n);
             if let Some(b) = decompress(
                 &reader_bytes,
                 total_n_rows,
@@ -186,6 +195,12 @@ impl<'a> CoreReader<'a> {
             }
         }
 
+        if parse_options.encoding.requires_transcoding() {
+            let mut out = vec![];
+            maybe_transcode_bytes(&reader_bytes, &mut out, parse_options.encoding)?;
+            reader_bytes = ReaderBytes::Owned(out.into());
+        }
+
         let mut schema = match schema {
             Some(schema) => schema,
             None => {
@@ -250,6 +265,8 @@ impl<'a> CoreReader<'a> {
             predicate,
             to_cast,
             row_index,
+            bad_records,
+            path,
         })
     }
 
@@ -289,6 +306,7 @@ impl<'a> CoreReader<'a> {
             .unwrap_or_else(|| Ok((0..self.schema.len()).collect()))
     }
 
+    #[allow(clippy::too_many_arguments)]
     fn read_chunk(
         &self,
         bytes: &[u8],
@@ -297,6 +315,7 @@ impl<'a> CoreReader<'a> {
         capacity: usize,
         starting_point_offset: Option<usize>,
         stop_at_nbytes: usize,
+        bad_records: Option<&mut ChunkBadRecords>,
     ) -> PolarsResult<DataFrame> {
         let mut df = read_chunk(
             bytes,
@@ -310,6 +329,7 @@ impl<'a> CoreReader<'a> {
             usize::MAX,
             stop_at_nbytes,
             starting_point_offset,
+            bad_records,
         )?;
 
         cast_columns(&mut df, &self.to_cast, false, self.ignore_errors)?;
@@ -321,6 +341,7 @@ impl<'a> CoreReader<'a> {
     // In case malformed CSV is detected, a warning or an error will be issued.
     // Not all malformed CSV will be detected, as that would impact performance.
     fn parse_csv(&mut self, bytes: &[u8]) -> PolarsResult<DataFrame> {
+        let full_bytes = bytes;
         let (bytes, _) = self.find_starting_point(
             bytes,
             self.parse_options.quote_char,
@@ -398,8 +419,11 @@ impl<'a> CoreReader<'a> {
         );
         let mut total_offset = 0;
         let mut previous_total_offset = 0;
+        // With bad records, string fields are validated during parsing instead.
         let check_utf8 = matches!(self.parse_options.encoding, CsvEncoding::Utf8)
+            && self.bad_records.is_none()
             && self.schema.iter_fields().any(|f| f.dtype().is_string());
+        let mut line_counter = LineCounter::new(self.parse_options.eol_char);
 
         pool.scope(|s| {
             // Pass 1: identify chunks for parallel processing (line parsing).
@@ -439,6 +463,12 @@ impl<'a> CoreReader<'a> {
 
                 // Pass 2: process each individual chunk in parallel (field parsing)
                 if !b.is_empty() {
+                    // Line number of the start of this chunk, to locate bad records.
+                    let first_line = if self.bad_records.is_some() {
+                        line_counter.line_at(full_bytes, b.as_ptr() as usize - full_bytes.as_ptr() as usize)
+                    } else {
+                        0
+                    };
                     let results = results.clone();
                     let projection = projection.as_ref();
                     let slf = &(*self);
@@ -452,8 +482,9 @@ impl<'a> CoreReader<'a> {
                             return;
                         }
 
+                        let mut chunk_bad_records = slf.bad_records.as_ref().map(|_| ChunkBadRecords::default());
                         let result = slf
-                            .read_chunk(b, projection, 0, count, Some(0), b.len())
+                            .read_chunk(b, projection, 0, count, Some(0), b.len(), chunk_bad_records.as_mut())
                             .and_then(|mut df| {
 
                                 // Check malformed
@@ -468,6 +499,12 @@ impl<'a> CoreReader<'a> {
                                     }
                                 }
 
+                                if let Some(chunk_bad_records) = &chunk_bad_records {
+                                    let mut lines = LineCounter::starting_at(slf.parse_options.eol_char, first_line);
+                                    slf.bad_records.as_ref().unwrap().extend(slf.path.as_ref(), chunk_bad_records, b, &mut lines);
+                                    df = chunk_bad_records.filter_rows(&df)?;
+                                }
+
                                 if slf.n_rows.is_some() {
                                     total_line_count.fetch_add(df.height());
                                 }
@@ -548,7 +585,19 @@ pub fn read_chunk(
     chunk_size: usize,
     stop_at_nbytes: usize,
     starting_point_offset: Option<usize>,
+    mut bad_records: Option<&mut ChunkBadRecords>,
 ) -> PolarsResult<DataFrame> {
+    // Whether a record is bad must not depend on the projection, so all columns are parsed when
+    // collecting bad records and the projection is applied afterwards.
+    let full_projection: Vec<usize>;
+    let (projection, output_projection) =
+        if bad_records.is_some() && projection.len() < schema.len() {
+            full_projection = (0..schema.len()).collect();
+            (full_projection.as_slice(), Some(projection))
+        } else {
+            (projection, None)
+        };
+
     let mut read = bytes_offset_thread;
     // There's an off-by-one error somewhere in the reading code, where it reads
     // one more item than the requested capacity. Given the batch sizes are
@@ -586,6 +635,7 @@ pub fn read_chunk(
             chunk_size,
             schema.len(),
             schema,
+            bad_records.as_deref_mut(),
         )?;
     }
 
@@ -593,7 +643,18 @@ pub fn read_chunk(
         .into_iter()
         .map(|buf| buf.into_series().map(Column::from))
         .collect::<PolarsResult<Vec<_>>>()?;
-    Ok(unsafe { DataFrame::new_no_checks_height_from_first(columns) })
+    let df = unsafe { DataFrame::new_no_checks_height_from_first(columns) };
+
+    match output_projection {
+        Some(projection) => {
+            let columns = projection
+                .iter()
+                .map(|&i| df.get_columns()[i].clone())
+                .collect();
+            Ok(unsafe { DataFrame::new_no_checks(df.height(), columns) })
+        },
+        None => Ok(df),
+    }
 }
 
 #[allow(clippy::too_many_arguments)]
@@ -7,10 +7,12 @@ use polars_core::frame::DataFrame;
 use polars_core::schema::SchemaRef;
 use polars_error::PolarsResult;
 use polars_utils::IdxSize;
+use polars_utils::pl_str::PlSmallStr;
 use rayon::iter::{IntoParallelIterator, ParallelIterator};
 
 use super::{CoreReader, CountLines, cast_columns, read_chunk};
 use crate::RowIndex;
+use crate::bad_records::{BadRecords, ChunkBadRecords, LineCounter};
 use crate::csv::read::CsvReader;
 use crate::csv::read::options::NullValuesCompiled;
 use crate::mmap::{MmapBytesReader, ReaderBytes};
@@ -162,6 +164,7 @@ impl<'a> CoreReader<'a> {
 
         Ok(BatchedCsvReader {
             reader_bytes,
+            line_counter: LineCounter::new(self.parse_options.eol_char),
             parse_options: self.parse_options,
             chunk_size: self.chunk_size,
             file_chunks_iter: file_chunks,
@@ -175,6 +178,8 @@ impl<'a> CoreReader<'a> {
             remaining: self.n_rows.unwrap_or(usize::MAX),
             schema: self.schema,
             rows_read: 0,
+            bad_records: self.bad_records,
+            path: self.path,
         })
     }
 }
@@ -194,6 +199,10 @@ pub struct BatchedCsvReader<'a> {
     remaining: usize,
     schema: SchemaRef,
     rows_read: IdxSize,
+    /// Locates bad records, these are reported in file order.
+    line_counter: LineCounter,
+    bad_records: Option<BadRecords>,
+    path: Option<PlSmallStr>,
 }
 
 impl BatchedCsvReader<'_> {
@@ -216,11 +225,15 @@ impl BatchedCsvReader<'_> {
             bytes = &bytes[pos..];
         }
 
-        let mut chunks = POOL.install(|| {
+        let chunks = POOL.install(|| {
             chunks
                 .into_par_iter()
                 .copied()
                 .map(|(bytes_offset_thread, stop_at_nbytes)| {
+                    let mut chunk_bad_records = self
+                        .bad_records
+                        .as_ref()
+                        .map(|_| ChunkBadRecords::default());
                     let mut df = read_chunk(
                         bytes,
                         &self.parse_options,
@@ -233,19 +246,41 @@ impl BatchedCsvReader<'_> {
                         usize::MAX,
                         stop_at_nbytes,
                         self.starting_point_offset,
+                        chunk_bad_records.as_mut(),
                     )?;
 
                     cast_columns(&mut df, &self.to_cast, false, self.ignore_errors)?;
 
+                    if let Some(chunk_bad_records) = &chunk_bad_records {
+                        df = chunk_bad_records.filter_rows(&df)?;
+                    }
+
                     if let Some(rc) = &self.row_index {
                         unsafe { df.with_row_index_mut(rc.name.clone(), Some(rc.offset)) };
                     }
-                    Ok(df)
+                    Ok((df, chunk_bad_records))
                 })
                 .collect::<PolarsResult<Vec<_>>>()
         })?;
         self.file_chunks.clear();
 
+        let mut chunks = chunks
+            .into_iter()
+            .map(|(df, chunk_bad_records)| {
+                if let (Some(bad_records), Some(chunk_bad_records)) =
+                    (&self.bad_records, &chunk_bad_records)
+                {
+                    bad_records.extend(
+                        self.path.as_ref(),
+                        chunk_bad_records,
+                        &self.reader_bytes,
+                        &mut self.line_counter,
+                    );
+                }
+                df
+            })
+            .collect::<Vec<_>>();
+
         if self.row_index.is_some() {
             update_row_counts2(&mut chunks, self.rows_read)
         }
@@ -131,6 +131,11 @@ impl<R: MmapBytesReader> CsvReader<R> {
             self.options.skip_rows_after_header,
             self.options.row_index.clone(),
             self.options.raise_if_empty,
+            self.options.bad_records.clone(),
+            self.options
+                .path
+                .as_ref()
+                .map(|p| p.to_string_lossy().as_ref().into()),
         )
     }
 
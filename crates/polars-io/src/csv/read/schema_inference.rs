@@ -171,10 +171,11 @@ pub fn infer_field_schema(string: &str, try_parse_dates: bool, decimal_comma: bo
 #[inline]
 fn parse_bytes_with_encoding(bytes: &[u8], encoding: CsvEncoding) -> PolarsResult<Cow<'_, str>> {
     Ok(match encoding {
-        CsvEncoding::Utf8 => simdutf8::basic::from_utf8(bytes)
+        CsvEncoding::LossyUtf8 => String::from_utf8_lossy(bytes),
+        // Other encodings have been transcoded to UTF-8 before parsing.
+        _ => simdutf8::basic::from_utf8(bytes)
             .map_err(|_| polars_err!(ComputeError: "invalid utf-8 sequence"))?
             .into(),
-        CsvEncoding::LossyUtf8 => String::from_utf8_lossy(bytes),
     })
 }
 
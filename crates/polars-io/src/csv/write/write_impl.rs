@@ -6,10 +6,12 @@ use arrow::array::NullArray;
 use arrow::legacy::time_zone::Tz;
 use polars_core::POOL;
 use polars_core::prelude::*;
-use polars_error::polars_ensure;
+use polars_error::{polars_ensure, to_compute_err};
 use rayon::prelude::*;
 use serializer::{serializer_for, string_serializer};
 
+use crate::csv::read::CsvEncoding;
+use crate::csv::read::encoding::{byte_order_mark, encode_from_utf8};
 use crate::csv::write::SerializeOptions;
 
 pub(crate) fn write<W: Write>(
@@ -17,6 +19,7 @@ pub(crate) fn write<W: Write>(
     df: &DataFrame,
     chunk_size: usize,
     options: &SerializeOptions,
+    encoding: CsvEncoding,
     n_threads: usize,
 ) -> PolarsResult<()> {
     for s in df.get_columns() {
@@ -182,6 +185,10 @@ pub(crate) fn write<W: Write>(
                 write_buffer.extend_from_slice(options.line_terminator.as_bytes());
             }
 
+            if encoding.requires_transcoding() {
+                *write_buffer = encode_buffer(write_buffer, encoding)?;
+            }
+
             Ok(())
         };
 
@@ -213,6 +220,7 @@ pub(crate) fn write_header<W: Write>(
     writer: &mut W,
     names: &[&str],
     options: &SerializeOptions,
+    encoding: CsvEncoding,
 ) -> PolarsResult<()> {
     let mut header = Vec::new();
 
@@ -231,13 +239,26 @@ pub(crate) fn write_header<W: Write>(
         }
     }
     header.extend_from_slice(options.line_terminator.as_bytes());
+    if encoding.requires_transcoding() {
+        header = encode_buffer(&header, encoding)?;
+    }
     writer.write_all(&header)?;
     Ok(())
 }
 
-/// Writes a UTF-8 BOM to `writer`.
-pub(crate) fn write_bom<W: Write>(writer: &mut W) -> PolarsResult<()> {
-    const BOM: [u8; 3] = [0xEF, 0xBB, 0xBF];
-    writer.write_all(&BOM)?;
+/// Writes the BOM of `encoding` to `writer`. Encodings without a BOM write nothing.
+pub(crate) fn write_bom<W: Write>(writer: &mut W, encoding: CsvEncoding) -> PolarsResult<()> {
+    if let Some(bom) = byte_order_mark(encoding) {
+        writer.write_all(bom)?;
+    }
     Ok(())
 }
+
+/// Encodes the UTF-8 `buffer` produced by the serializers into `encoding`.
+fn encode_buffer(buffer: &[u8], encoding: CsvEncoding) -> PolarsResult<Vec<u8>> {
+    // The serializers only produce valid UTF-8 (given a valid UTF-8 quote char).
+    let s = std::str::from_utf8(buffer).map_err(to_compute_err)?;
+    let mut out = Vec::with_capacity(buffer.len());
+    encode_from_utf8(s, encoding, &mut out)?;
+    Ok(out)
+}
@@ -8,6 +8,7 @@ use polars_error::PolarsResult;
 
 use super::write_impl::{write, write_bom, write_header};
 use super::{QuoteStyle, SerializeOptions};
+use crate::csv::read::CsvEncoding;
 use crate::shared::SerWriter;
 
 /// Write a DataFrame to csv.
@@ -21,6 +22,7 @@ pub struct CsvWriter<W: Write> {
     header: bool,
     bom: bool,
     batch_size: NonZeroUsize,
+    encoding: CsvEncoding,
     n_threads: usize,
 }
 
@@ -41,13 +43,14 @@ where
             header: true,
             bom: false,
             batch_size: NonZeroUsize::new(1024).unwrap(),
+            encoding: CsvEncoding::Utf8,
             n_threads: POOL.current_num_threads(),
         }
     }
 
     fn finish(&mut self, df: &mut DataFrame) -> PolarsResult<()> {
         if self.bom {
-            write_bom(&mut self.buffer)?;
+            write_bom(&mut self.buffer, self.encoding)?;
         }
         let names = df
             .get_column_names()
@@ -55,13 +58,19 @@ where
             .map(|x| x.as_str())
             .collect::<Vec<_>>();
         if self.header {
-            write_header(&mut self.buffer, names.as_slice(), &self.options)?;
+            write_header(
+                &mut self.buffer,
+                names.as_slice(),
+                &self.options,
+                self.encoding,
+            )?;
         }
         write(
             &mut self.buffer,
             df,
             self.batch_size.into(),
             &self.options,
+            self.encoding,
             self.n_threads,
         )
     }
@@ -71,7 +80,8 @@ impl<W> CsvWriter<W>
 where
     W: Write,
 {
-    /// Set whether to write UTF-8 BOM.
+    /// Set whether to write the byte order mark of the encoding. Encodings without one write
+    /// nothing.
     pub fn include_bom(mut self, include_bom: bool) -> Self {
         self.bom = include_bom;
         self
@@ -166,6 +176,13 @@ where
         self
     }
 
+    /// Set the encoding of the CSV file. Writing fails if a value contains a character that
+    /// cannot be represented in the encoding.
+    pub fn with_encoding(mut self, encoding: CsvEncoding) -> Self {
+        self.encoding = encoding;
+        self
+    }
+
     pub fn n_threads(mut self, n_threads: usize) -> Self {
         self.n_threads = n_threads;
         self
@@ -198,7 +215,7 @@ impl<W: Write> BatchedWriter<W> {
     pub fn write_batch(&mut self, df: &DataFrame) -> PolarsResult<()> {
         if !self.has_written_bom {
             self.has_written_bom = true;
-            write_bom(&mut self.writer.buffer)?;
+            write_bom(&mut self.writer.buffer, self.writer.encoding)?;
         }
 
         if !self.has_written_header {
@@ -212,6 +229,7 @@ impl<W: Write> BatchedWriter<W> {
                 &mut self.writer.buffer,
                 names.as_slice(),
                 &self.writer.options,
+                self.writer.encoding,
             )?;
         }
 
@@ -220,6 +238,7 @@ impl<W: Write> BatchedWriter<W> {
             df,
             self.writer.batch_size.into(),
             &self.writer.options,
+            self.writer.encoding,
             self.writer.n_threads,
         )?;
         Ok(())
@@ -229,7 +248,7 @@ impl<W: Write> BatchedWriter<W> {
     pub fn finish(&mut self) -> PolarsResult<()> {
         if !self.has_written_bom {
             self.has_written_bom = true;
-            write_bom(&mut self.writer.buffer)?;
+            write_bom(&mut self.writer.buffer, self.writer.encoding)?;
         }
 
         if !self.has_written_header {
@@ -239,7 +258,12 @@ impl<W: Write> BatchedWriter<W> {
                 .iter_names()
                 .map(|x| x.as_str())
                 .collect::<Vec<_>>();
-            write_header(&mut self.writer.buffer, &names, &self.writer.options)?;
+            write_header(
+                &mut self.writer.buffer,
+                &names,
+                &self.writer.options,
+                self.writer.encoding,
+            )?;
         };
 
         Ok(())
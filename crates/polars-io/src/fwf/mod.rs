@@ -0,0 +1,31 @@
+//! Functionality for reading fixed-width text files.
+//!
+//! Every column occupies a fixed range of bytes in each line. Values are padded to the width of
+//! their column, and the padding is stripped while reading.
+//!
+//! # Examples
+//!
+//! ```
+//! use polars_core::prelude::*;
+//! use polars_io::prelude::*;
+//!
+//! fn example() -> PolarsResult<DataFrame> {
+//!     let columns = [
+//!         FwfColumn::new("account".into(), 0, 10, DataType::String),
+//!         FwfColumn::new("balance".into(), 10, 12, DataType::Float64),
+//!     ];
+//!     FwfReadOptions::default()
+//!         .with_columns(columns.into())
+//!         .with_skip_rows(1)
+//!         .try_into_reader_with_file_path(Some("extract.txt".into()))?
+//!         .finish()
+//! }
+//! ```
+
+mod options;
+mod read_impl;
+mod reader;
+
+pub use options::{FwfColumn, FwfReadOptions, FwfTrim};
+pub use read_impl::{FwfChunkParser, FwfLines, count_lines, split_at_line};
+pub use reader::FwfReader;
@@ -0,0 +1,212 @@
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use polars_core::datatypes::DataType;
+use polars_core::schema::Schema;
+use polars_error::{PolarsResult, polars_bail, polars_ensure};
+use polars_utils::pl_str::PlSmallStr;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::RowIndex;
+use crate::csv::read::NullValues;
+
+/// Location and data type of a column in a fixed-width file.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub struct FwfColumn {
+    pub name: PlSmallStr,
+    /// Byte offset of the start of the column in a line.
+    pub start: usize,
+    /// Width of the column in bytes.
+    pub width: usize,
+    pub dtype: DataType,
+}
+
+impl FwfColumn {
+    pub fn new(name: PlSmallStr, start: usize, width: usize, dtype: DataType) -> Self {
+        Self {
+            name,
+            start,
+            width,
+            dtype,
+        }
+    }
+
+    /// Byte offset of the end of the column in a line.
+    pub fn end(&self) -> usize {
+        self.start + self.width
+    }
+}
+
+/// Which side of a value is stripped of padding characters.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub enum FwfTrim {
+    /// Keep values as they are.
+    None,
+    /// Strip padding at the start of values, for right-aligned columns.
+    Start,
+    /// Strip padding at the end of values, for left-aligned columns.
+    End,
+    #[default]
+    Both,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub struct FwfReadOptions {
+    pub path: Option<PathBuf>,
+    // Performance related options
+    pub rechunk: bool,
+    pub n_threads: Option<usize>,
+    // Row-wise options
+    pub n_rows: Option<usize>,
+    pub row_index: Option<RowIndex>,
+    // Column-wise options
+    pub columns: Arc<[FwfColumn]>,
+    pub projection: Option<Arc<[PlSmallStr]>>,
+    // Fixed-width specific options
+    /// Number of lines to skip at the start of the file, e.g. a header.
+    pub skip_rows: usize,
+    pub eol_char: u8,
+    /// Character used to pad values to the width of their column.
+    pub pad_char: u8,
+    pub trim: FwfTrim,
+    /// Values that are interpreted as missing, after trimming. Empty values are always missing.
+    pub null_values: Option<NullValues>,
+    pub ignore_errors: bool,
+}
+
+impl Default for FwfReadOptions {
+    fn default() -> Self {
+        Self {
+            path: None,
+
+            rechunk: false,
+            n_threads: None,
+
+            n_rows: None,
+            row_index: None,
+
+            columns: Arc::new([]),
+            projection: None,
+
+            skip_rows: 0,
+            eol_char: b'\n',
+            pad_char: b' ',
+            trim: FwfTrim::default(),
+            null_values: None,
+            ignore_errors: false,
+        }
+    }
+}
+
+impl FwfReadOptions {
+    pub fn with_path<P: Into<PathBuf>>(mut self, path: Option<P>) -> Self {
+        self.path = path.map(|p| p.into());
+        self
+    }
+
+    /// Whether to makes the columns contiguous in memory.
+    pub fn with_rechunk(mut self, rechunk: bool) -> Self {
+        self.rechunk = rechunk;
+        self
+    }
+
+    /// Number of threads to use for reading. Defaults to the size of the polars
+    /// thread pool.
+    pub fn with_n_threads(mut self, n_threads: Option<usize>) -> Self {
+        self.n_threads = n_threads;
+        self
+    }
+
+    /// Limits the number of rows to read.
+    pub fn with_n_rows(mut self, n_rows: Option<usize>) -> Self {
+        self.n_rows = n_rows;
+        self
+    }
+
+    /// Adds a row index column.
+    pub fn with_row_index(mut self, row_index: Option<RowIndex>) -> Self {
+        self.row_index = row_index;
+        self
+    }
+
+    /// Location and data type of the columns in the file. Bytes that are not covered by any
+    /// column are ignored.
+    pub fn with_columns(mut self, columns: Arc<[FwfColumn]>) -> Self {
+        self.columns = columns;
+        self
+    }
+
+    /// Which columns to select.
+    pub fn with_projection(mut self, projection: Option<Arc<[PlSmallStr]>>) -> Self {
+        self.projection = projection;
+        self
+    }
+
+    /// Number of lines to skip at the start of the file.
+    pub fn with_skip_rows(mut self, skip_rows: usize) -> Self {
+        self.skip_rows = skip_rows;
+        self
+    }
+
+    /// Set the character used to indicate an end-of-line (eol).
+    pub fn with_eol_char(mut self, eol_char: u8) -> Self {
+        self.eol_char = eol_char;
+        self
+    }
+
+    /// Set the character used to pad values to the width of their column.
+    pub fn with_pad_char(mut self, pad_char: u8) -> Self {
+        self.pad_char = pad_char;
+        self
+    }
+
+    /// Set which side of values is stripped of padding characters.
+    pub fn with_trim(mut self, trim: FwfTrim) -> Self {
+        self.trim = trim;
+        self
+    }
+
+    /// Set values that will be interpreted as missing/null.
+    pub fn with_null_values(mut self, null_values: Option<NullValues>) -> Self {
+        self.null_values = null_values;
+        self
+    }
+
+    /// Continue with next line when a value cannot be parsed, setting it to null.
+    pub fn with_ignore_errors(mut self, ignore_errors: bool) -> Self {
+        self.ignore_errors = ignore_errors;
+        self
+    }
+
+    /// Schema of the file, as described by the column specification.
+    pub fn schema(&self) -> PolarsResult<Schema> {
+        polars_ensure!(
+            !self.columns.is_empty(),
+            InvalidOperation: "fixed-width reader requires at least one column"
+        );
+
+        let mut schema = Schema::with_capacity(self.columns.len());
+
+        for column in self.columns.iter() {
+            polars_ensure!(
+                column.width > 0,
+                InvalidOperation: "column '{}' of fixed-width file has width 0", column.name
+            );
+            if schema
+                .insert(column.name.clone(), column.dtype.clone())
+                .is_some()
+            {
+                polars_bail!(Duplicate: "column '{}' is specified more than once", column.name);
+            }
+        }
+
+        Ok(schema)
+    }
+}
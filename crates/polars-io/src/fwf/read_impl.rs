@@ -0,0 +1,307 @@
+use polars_core::POOL;
+use polars_core::prelude::*;
+use polars_core::utils::accumulate_dataframes_vertical;
+use polars_error::{PolarsResult, polars_bail, polars_err};
+use rayon::prelude::*;
+
+use super::options::{FwfColumn, FwfReadOptions, FwfTrim};
+use crate::csv::read::_csv_read_internal::{
+    NullValuesCompiled, cast_columns, prepare_csv_schema, validate_utf8,
+};
+use crate::csv::read::CsvEncoding;
+use crate::csv::read::buffer::init_buffers;
+
+/// Iterator over the lines of a fixed-width file, without line endings. Empty lines are skipped.
+#[derive(Clone)]
+pub struct FwfLines<'a> {
+    bytes: &'a [u8],
+    eol_char: u8,
+}
+
+impl<'a> FwfLines<'a> {
+    pub fn new(bytes: &'a [u8], eol_char: u8) -> Self {
+        Self { bytes, eol_char }
+    }
+
+    /// The bytes that have not been iterated over yet.
+    pub fn remainder(&self) -> &'a [u8] {
+        self.bytes
+    }
+}
+
+impl<'a> Iterator for FwfLines<'a> {
+    type Item = &'a [u8];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while !self.bytes.is_empty() {
+            let line = match memchr::memchr(self.eol_char, self.bytes) {
+                Some(i) => {
+                    let line = &self.bytes[..i];
+                    self.bytes = &self.bytes[i + 1..];
+                    line
+                },
+                None => std::mem::take(&mut self.bytes),
+            };
+            let line = line.strip_suffix(b"\r").unwrap_or(line);
+
+            if !line.is_empty() {
+                return Some(line);
+            }
+        }
+
+        None
+    }
+}
+
+/// Splits `bytes` after `n_lines` lines. Returns the number of lines in the first part, which is
+/// less than `n_lines` if `bytes` does not contain enough lines.
+pub fn split_at_line(bytes: &[u8], eol_char: u8, n_lines: usize) -> (usize, &[u8], &[u8]) {
+    let mut lines = FwfLines::new(bytes, eol_char);
+    let n = lines.by_ref().take(n_lines).count();
+    let rest = lines.remainder();
+
+    (n, &bytes[..bytes.len() - rest.len()], rest)
+}
+
+/// Number of (non-empty) lines in `bytes`.
+pub fn count_lines(bytes: &[u8], eol_char: u8) -> usize {
+    FwfLines::new(bytes, eol_char).count()
+}
+
+/// Cut the value of `column` out of `line`. Lines that are too short are treated as if they are
+/// padded with `pad_char`.
+#[inline]
+fn extract_field<'a>(line: &'a [u8], column: &FwfColumn, pad_char: u8, trim: FwfTrim) -> &'a [u8] {
+    let start = column.start.min(line.len());
+    let end = column.end().min(line.len());
+    let mut field = &line[start..end];
+
+    if matches!(trim, FwfTrim::Start | FwfTrim::Both) {
+        let n = field.iter().take_while(|&&b| b == pad_char).count();
+        field = &field[n..];
+    }
+    if matches!(trim, FwfTrim::End | FwfTrim::Both) {
+        let n = field.iter().rev().take_while(|&&b| b == pad_char).count();
+        field = &field[..field.len() - n];
+    }
+
+    field
+}
+
+/// Parses chunks of lines of a fixed-width file into [`DataFrame`]s.
+pub struct FwfChunkParser {
+    /// Schema used for parsing. Data types that cannot be parsed directly are replaced by
+    /// `String` and cast afterwards.
+    schema: SchemaRef,
+    fields_to_cast: Vec<Field>,
+    /// Indices of the projected columns in `schema`.
+    projection: Vec<usize>,
+    columns: Arc<[FwfColumn]>,
+    null_values: Option<NullValuesCompiled>,
+    pad_char: u8,
+    trim: FwfTrim,
+    eol_char: u8,
+    ignore_errors: bool,
+    /// Lower bound of the length of a line, used to estimate the number of lines in a chunk.
+    line_width: usize,
+}
+
+impl FwfChunkParser {
+    /// Create a parser for the columns in `projection`, or all columns if `None`.
+    pub fn try_new(
+        options: &FwfReadOptions,
+        projection: Option<&[PlSmallStr]>,
+    ) -> PolarsResult<Self> {
+        let file_schema = options.schema()?;
+
+        let projection = match projection {
+            Some(names) => names
+                .iter()
+                .map(|name| file_schema.try_index_of(name))
+                .collect::<PolarsResult<Vec<_>>>()?,
+            None => (0..file_schema.len()).collect(),
+        };
+
+        let null_values = options
+            .null_values
+            .clone()
+            .map(|nv| nv.compile(&file_schema))
+            .transpose()?;
+
+        let mut schema = Arc::new(file_schema);
+        let mut fields_to_cast = vec![];
+        prepare_csv_schema(&mut schema, &mut fields_to_cast)?;
+
+        Ok(Self {
+            schema,
+            fields_to_cast,
+            projection,
+            columns: options.columns.clone(),
+            null_values,
+            pad_char: options.pad_char,
+            trim: options.trim,
+            eol_char: options.eol_char,
+            ignore_errors: options.ignore_errors,
+            line_width: options.columns.iter().map(|c| c.end()).max().unwrap_or(0) + 1,
+        })
+    }
+
+    pub fn eol_char(&self) -> u8 {
+        self.eol_char
+    }
+
+    /// Parse all lines in `bytes`.
+    pub fn parse(&self, bytes: &[u8]) -> PolarsResult<DataFrame> {
+        let lines = FwfLines::new(bytes, self.eol_char);
+
+        if self.projection.is_empty() {
+            return Ok(DataFrame::empty_with_height(lines.count()));
+        }
+
+        let mut buffers = init_buffers(
+            &self.projection,
+            bytes.len() / self.line_width,
+            &self.schema,
+            None,
+            CsvEncoding::Utf8,
+            false,
+        )?;
+        let validate = self
+            .projection
+            .iter()
+            .map(|&i| self.schema.get_at_index(i).unwrap().1.is_string())
+            .collect::<Vec<_>>();
+
+        let mut height = 0;
+
+        for line in lines {
+            for ((buf, &i), validate) in buffers.iter_mut().zip(&self.projection).zip(&validate) {
+                let column = &self.columns[i];
+                let field = extract_field(line, column, self.pad_char, self.trim);
+
+                // SAFETY: `i` is in bounds of the schema the null values were compiled with.
+                let is_null = field.is_empty()
+                    || self
+                        .null_values
+                        .as_ref()
+                        .is_some_and(|nv| unsafe { nv.is_null(field, i) });
+
+                if is_null {
+                    buf.add_null(false);
+                    continue;
+                }
+
+                if *validate && !validate_utf8(field) {
+                    if self.ignore_errors {
+                        buf.add_null(false);
+                        continue;
+                    }
+                    polars_bail!(
+                        ComputeError: "invalid utf-8 sequence at column '{}'", column.name
+                    );
+                }
+
+                buf.add(field, self.ignore_errors, false, true)
+                    .map_err(|_| {
+                        polars_err!(
+                            ComputeError: "could not parse `{}` as dtype `{}` at column '{}'",
+                            String::from_utf8_lossy(field), column.dtype, column.name,
+                        )
+                    })?;
+            }
+
+            height += 1;
+        }
+
+        let columns = buffers
+            .into_iter()
+            .map(|buf| buf.into_series().map(Column::from))
+            .collect::<PolarsResult<Vec<_>>>()?;
+
+        let mut df = DataFrame::new_with_height(height, columns)?;
+        cast_columns(&mut df, &self.fields_to_cast, false, self.ignore_errors)?;
+
+        Ok(df)
+    }
+}
+
+/// Read a complete fixed-width file, in parallel.
+pub(super) fn read_fwf(bytes: &[u8], options: &FwfReadOptions) -> PolarsResult<DataFrame> {
+    let parser = FwfChunkParser::try_new(options, options.projection.as_deref())?;
+    let eol_char = options.eol_char;
+
+    let (_, _, mut bytes) = split_at_line(bytes, eol_char, options.skip_rows);
+    if let Some(n_rows) = options.n_rows {
+        bytes = split_at_line(bytes, eol_char, n_rows).1;
+    }
+
+    // Split into chunks of roughly equal size that end at a line ending.
+    let n_threads = options
+        .n_threads
+        .unwrap_or_else(|| POOL.current_num_threads());
+    let chunk_size = bytes.len().div_ceil(n_threads).max(1 << 16);
+    let mut chunks = vec![];
+    let mut rest = bytes;
+
+    while !rest.is_empty() {
+        let mut end = chunk_size.min(rest.len());
+        if let Some(i) = memchr::memchr(eol_char, &rest[end..]) {
+            end += i + 1;
+        } else {
+            end = rest.len();
+        }
+        let (chunk, tail) = rest.split_at(end);
+        chunks.push(chunk);
+        rest = tail;
+    }
+
+    let mut df = if chunks.len() > 1 {
+        let dfs = POOL.install(|| {
+            chunks
+                .into_par_iter()
+                .map(|chunk| parser.parse(chunk))
+                .collect::<PolarsResult<Vec<_>>>()
+        })?;
+        accumulate_dataframes_vertical(dfs)?
+    } else {
+        parser.parse(bytes)?
+    };
+
+    if let Some(row_index) = &options.row_index {
+        df = df.with_row_index(row_index.name.clone(), Some(row_index.offset))?;
+    }
+
+    Ok(df)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fwf_lines() {
+        let bytes = b"ab\r\n\ncd\nef";
+        let lines = FwfLines::new(bytes, b'\n').collect::<Vec<_>>();
+        assert_eq!(lines, [b"ab", b"cd", b"ef"]);
+
+        let (n, head, tail) = split_at_line(bytes, b'\n', 2);
+        assert_eq!(n, 2);
+        assert_eq!(head, b"ab\r\n\ncd\n");
+        assert_eq!(tail, b"ef");
+        assert_eq!(count_lines(tail, b'\n'), 1);
+    }
+
+    #[test]
+    fn test_extract_field() {
+        let column = FwfColumn::new("a".into(), 2, 4, DataType::String);
+        let line = b"xx 1  yy";
+
+        assert_eq!(extract_field(line, &column, b' ', FwfTrim::Both), b"1");
+        assert_eq!(extract_field(line, &column, b' ', FwfTrim::Start), b"1  ");
+        assert_eq!(extract_field(line, &column, b' ', FwfTrim::End), b" 1");
+        assert_eq!(extract_field(line, &column, b' ', FwfTrim::None), b" 1  ");
+        // Short lines are padded.
+        assert_eq!(extract_field(b"xx 1", &column, b' ', FwfTrim::Both), b"1");
+        assert_eq!(extract_field(b"x", &column, b' ', FwfTrim::Both), b"");
+    }
+}
@@ -0,0 +1,127 @@
+use std::fs::File;
+use std::path::PathBuf;
+
+use polars_core::prelude::*;
+
+use super::options::FwfReadOptions;
+use super::read_impl::read_fwf;
+use crate::mmap::MmapBytesReader;
+use crate::path_utils::resolve_homedir;
+use crate::shared::SerReader;
+use crate::utils::compression::maybe_decompress_bytes;
+use crate::utils::get_reader_bytes;
+
+/// Create a new DataFrame by reading a fixed-width file.
+///
+/// # Example
+///
+/// ```
+/// use polars_core::prelude::*;
+/// use polars_io::prelude::*;
+///
+/// fn example() -> PolarsResult<DataFrame> {
+///     let columns = [
+///         FwfColumn::new("id".into(), 0, 6, DataType::Int64),
+///         FwfColumn::new("name".into(), 6, 20, DataType::String),
+///     ];
+///     FwfReadOptions::default()
+///         .with_columns(columns.into())
+///         .try_into_reader_with_file_path(Some("accounts.txt".into()))?
+///         .finish()
+/// }
+/// ```
+#[must_use]
+pub struct FwfReader<R>
+where
+    R: MmapBytesReader,
+{
+    /// File or Stream object.
+    reader: R,
+    /// Options for the fixed-width reader.
+    options: FwfReadOptions,
+}
+
+impl FwfReadOptions {
+    /// Creates a fixed-width reader using a file path.
+    ///
+    /// # Panics
+    /// If both self.path and the path parameter are non-null. Only one of them is
+    /// to be non-null.
+    pub fn try_into_reader_with_file_path(
+        mut self,
+        path: Option<PathBuf>,
+    ) -> PolarsResult<FwfReader<File>> {
+        if self.path.is_some() {
+            assert!(
+                path.is_none(),
+                "impl error: only 1 of self.path or the path parameter is to be non-null"
+            );
+        } else {
+            self.path = path;
+        };
+
+        assert!(
+            self.path.is_some(),
+            "impl error: either one of self.path or the path parameter is to be non-null"
+        );
+
+        let path = resolve_homedir(self.path.as_ref().unwrap());
+        let reader = polars_utils::open_file(&path)?;
+
+        Ok(FwfReader {
+            reader,
+            options: self,
+        })
+    }
+
+    /// Creates a fixed-width reader using a file handle.
+    pub fn into_reader_with_file_handle<R: MmapBytesReader>(self, reader: R) -> FwfReader<R> {
+        FwfReader {
+            reader,
+            options: self,
+        }
+    }
+}
+
+impl<R> SerReader<R> for FwfReader<R>
+where
+    R: MmapBytesReader,
+{
+    /// Create a new FwfReader from a file/stream using default read options. To
+    /// use non-default read options, first construct [FwfReadOptions] and then use
+    /// any of the `(try)_into_` methods.
+    fn new(reader: R) -> Self {
+        FwfReader {
+            reader,
+            options: Default::default(),
+        }
+    }
+
+    fn set_rechunk(mut self, rechunk: bool) -> Self {
+        self.options.rechunk = rechunk;
+        self
+    }
+
+    /// Read the file and create the DataFrame.
+    fn finish(mut self) -> PolarsResult<DataFrame> {
+        let reader_bytes = get_reader_bytes(&mut self.reader)?;
+        let mut owned = vec![];
+        let bytes = maybe_decompress_bytes(&reader_bytes, &mut owned)?;
+
+        let mut df = read_fwf(bytes, &self.options)?;
+
+        if self.options.rechunk && df.first_col_n_chunks() > 1 {
+            df.as_single_chunk_par();
+        }
+
+        Ok(df)
+    }
+}
+
+impl<R: MmapBytesReader> FwfReader<R> {
+    /// Sets custom fixed-width read options.
+    pub fn with_options(mut self, options: FwfReadOptions) -> Self {
+        self.options = options;
+        self
+    }
+}
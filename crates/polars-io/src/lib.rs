@@ -9,6 +9,8 @@ extern crate core;
 
 #[cfg(feature = "avro")]
 pub mod avro;
+#[cfg(any(feature = "csv", feature = "json"))]
+pub mod bad_records;
 #[cfg(feature = "catalog")]
 pub mod catalog;
 pub mod cloud;
@@ -16,6 +18,8 @@ pub mod cloud;
 pub mod csv;
 #[cfg(feature = "file_cache")]
 pub mod file_cache;
+#[cfg(feature = "fwf")]
+pub mod fwf;
 #[cfg(any(feature = "ipc", feature = "ipc_streaming"))]
 pub mod ipc;
 #[cfg(feature = "json")]
@@ -24,6 +28,8 @@ pub mod mmap;
 #[cfg(feature = "json")]
 pub mod ndjson;
 mod options;
+#[cfg(feature = "orc")]
+pub mod orc;
 #[cfg(feature = "parquet")]
 pub mod parquet;
 #[cfg(feature = "parquet")]
@@ -37,6 +37,7 @@ where
     row_index: Option<&'a mut RowIndex>,
     predicate: Option<Arc<dyn PhysicalIoExpr>>,
     projection: Option<Arc<[PlSmallStr]>>,
+    bad_records: Option<BadRecords>,
 }
 
 impl<'a, R> JsonLineReader<'a, R>
@@ -111,6 +112,13 @@ where
         self
     }
 
+    /// Divert lines that cannot be parsed to `bad_records` instead of raising an error. These
+    /// lines are removed from the output. Takes precedence over `ignore_errors`.
+    pub fn with_bad_records(mut self, bad_records: Option<BadRecords>) -> Self {
+        self.bad_records = bad_records;
+        self
+    }
+
     pub fn count(mut self) -> PolarsResult<usize> {
         let reader_bytes = get_reader_bytes(&mut self.reader)?;
         let json_reader = CoreJsonReader::new(
@@ -127,6 +135,10 @@ where
             self.row_index,
             self.predicate,
             self.projection,
+            self.bad_records,
+            self.path
+                .as_ref()
+                .map(|p| p.to_string_lossy().as_ref().into()),
         )?;
 
         json_reader.count()
@@ -162,6 +174,7 @@ where
             row_index: None,
             predicate: None,
             projection: None,
+            bad_records: None,
         }
     }
     fn finish(mut self) -> PolarsResult<DataFrame> {
@@ -181,6 +194,10 @@ where
             self.row_index,
             self.predicate,
             self.projection,
+            self.bad_records,
+            self.path
+                .as_ref()
+                .map(|p| p.to_string_lossy().as_ref().into()),
         )?;
 
         let mut df: DataFrame = json_reader.as_df()?;
@@ -203,6 +220,9 @@ pub(crate) struct CoreJsonReader<'a> {
     row_index: Option<&'a mut RowIndex>,
     predicate: Option<Arc<dyn PhysicalIoExpr>>,
     projection: Option<Arc<[PlSmallStr]>>,
+    bad_records: Option<BadRecords>,
+    /// Path of the file, used to identify bad records.
+    path: Option<PlSmallStr>,
 }
 impl<'a> CoreJsonReader<'a> {
     #[allow(clippy::too_many_arguments)]
@@ -220,6 +240,8 @@ impl<'a> CoreJsonReader<'a> {
         row_index: Option<&'a mut RowIndex>,
         predicate: Option<Arc<dyn PhysicalIoExpr>>,
         projection: Option<Arc<[PlSmallStr]>>,
+        bad_records: Option<BadRecords>,
+        path: Option<PlSmallStr>,
     ) -> PolarsResult<CoreJsonReader<'a>> {
         let reader_bytes = reader_bytes;
 
@@ -248,6 +270,8 @@ impl<'a> CoreJsonReader<'a> {
             row_index,
             predicate,
             projection,
+            bad_records,
+            path,
         })
     }
 
@@ -290,19 +314,47 @@ impl<'a> CoreJsonReader<'a> {
             std::cmp::min(rows_per_thread, max_proxy)
         };
         let file_chunks = get_file_chunks_json(bytes, n_threads);
+        // Line numbers of the start of the chunks, to locate bad records.
+        let first_lines = if self.bad_records.is_some() {
+            let mut lines = LineCounter::new(NEWLINE);
+            file_chunks
+                .iter()
+                .map(|(start_pos, _)| lines.line_at(bytes, *start_pos))
+                .collect()
+        } else {
+            vec![0; file_chunks.len()]
+        };
 
         let row_index = self.row_index.as_ref().map(|ri| ri as &RowIndex);
         let (mut dfs, prepredicate_heights) = POOL.install(|| {
             file_chunks
                 .into_par_iter()
-                .map(|(start_pos, stop_at_nbytes)| {
+                .zip(first_lines)
+                .map(|((start_pos, stop_at_nbytes), first_line)| {
+                    let chunk = &bytes[start_pos..stop_at_nbytes];
+                    let mut chunk_bad_records = self
+                        .bad_records
+                        .as_ref()
+                        .map(|_| ChunkBadRecords::default());
                     let mut local_df = parse_ndjson(
-                        &bytes[start_pos..stop_at_nbytes],
+                        chunk,
                         Some(capacity),
                         &self.schema,
                         self.ignore_errors,
+                        chunk_bad_records.as_mut(),
                     )?;
 
+                    if let Some(chunk_bad_records) = &chunk_bad_records {
+                        let mut lines = LineCounter::starting_at(NEWLINE, first_line);
+                        self.bad_records.as_ref().unwrap().extend(
+                            self.path.as_ref(),
+                            chunk_bad_records,
+                            chunk,
+                            &mut lines,
+                        );
+                        local_df = chunk_bad_records.filter_rows(&local_df)?;
+                    }
+
                     let prepredicate_height = local_df.height() as IdxSize;
                     if let Some(projection) = self.projection.as_deref() {
                         local_df = local_df.select(projection.iter().cloned())?;
@@ -350,32 +402,56 @@ impl<'a> CoreJsonReader<'a> {
     }
 }
 
+/// If `fill_on_error` is set, a null is added to every buffer that failed to parse, such that
+/// all buffers have a value for this line even if an error is returned.
 #[inline(always)]
 fn parse_impl(
     bytes: &[u8],
     buffers: &mut PlIndexMap<BufferKey, Buffer>,
     scratch: &mut Scratch,
     ignore_errors: bool,
+    fill_on_error: bool,
 ) -> PolarsResult<usize> {
     scratch.json.clear();
     scratch.json.extend_from_slice(bytes);
     let n = scratch.json.len();
     let value = simd_json::to_borrowed_value_with_buffers(&mut scratch.json, &mut scratch.buffers)
-        .map_err(|e| polars_err!(ComputeError: "error parsing line: {}", e))?;
+        .map_err(|e| {
+            if fill_on_error {
+                buffers.iter_mut().for_each(|(_, inner)| inner.add_null());
+            }
+            polars_err!(ComputeError: "error parsing line: {}", e)
+        })?;
     match value {
         simd_json::BorrowedValue::Object(value) => {
-            buffers.iter_mut().try_for_each(|(s, inner)| {
-                match s.0.map_lookup(&value) {
-                    Some(v) => inner.add(v)?,
-                    None => inner.add_null(),
+            let mut first_error = None;
+            for (s, inner) in buffers.iter_mut() {
+                let result = match s.0.map_lookup(&value) {
+                    Some(v) => inner.add(v),
+                    None => {
+                        inner.add_null();
+                        Ok(())
+                    },
+                };
+                if let Err(e) = result {
+                    if !fill_on_error {
+                        return Err(e);
+                    }
+                    inner.add_null();
+                    first_error.get_or_insert(e);
                 }
-                PolarsResult::Ok(())
-            })?;
+            }
+            if let Some(e) = first_error {
+                return Err(e);
+            }
         },
         _ if ignore_errors => {
             buffers.iter_mut().for_each(|(_, inner)| inner.add_null());
         },
         v => {
+            if fill_on_error {
+                buffers.iter_mut().for_each(|(_, inner)| inner.add_null());
+            }
             polars_bail!(ComputeError: "NDJSON line expected to contain JSON object: {v}");
         },
     };
@@ -405,26 +481,55 @@ fn parse_lines(
     bytes: &[u8],
     buffers: &mut PlIndexMap<BufferKey, Buffer>,
     ignore_errors: bool,
+    mut bad_records: Option<&mut ChunkBadRecords>,
 ) -> PolarsResult<()> {
     let mut scratch = Scratch::default();
 
     let iter = json_lines(bytes);
-    for bytes in iter {
-        parse_impl(bytes, buffers, &mut scratch, ignore_errors)?;
+    for line in iter {
+        let result = parse_impl(
+            line,
+            buffers,
+            &mut scratch,
+            ignore_errors,
+            bad_records.is_some(),
+        );
+
+        match bad_records.as_deref_mut() {
+            Some(bad_records) => {
+                if let Err(e) = result {
+                    let line = line.strip_suffix(b"\r").unwrap_or(line);
+                    let offset = line.as_ptr() as usize - bytes.as_ptr() as usize;
+                    bad_records.push(offset, line, e.to_string());
+                }
+                bad_records.n_rows += 1;
+            },
+            None => {
+                result?;
+            },
+        }
     }
     Ok(())
 }
 
+/// Parse NDJSON `bytes` into a [`DataFrame`].
+///
+/// If `bad_records` is given, lines that cannot be parsed are recorded there instead of raising
+/// an error. A row of nulls is produced for these lines, which is to be removed with
+/// [`ChunkBadRecords::filter_rows`].
 pub fn parse_ndjson(
     bytes: &[u8],
     n_rows_hint: Option<usize>,
     schema: &Schema,
     ignore_errors: bool,
+    bad_records: Option<&mut ChunkBadRecords>,
 ) -> PolarsResult<DataFrame> {
     let capacity = n_rows_hint.unwrap_or_else(|| estimate_n_lines_in_chunk(bytes));
 
+    // Values of bad records must raise, so that they can be diverted.
+    let ignore_errors = ignore_errors && bad_records.is_none();
     let mut buffers = init_buffers(schema, capacity, ignore_errors)?;
-    parse_lines(bytes, &mut buffers, ignore_errors)?;
+    parse_lines(bytes, &mut buffers, ignore_errors, bad_records)?;
 
     DataFrame::new(
         buffers
@@ -0,0 +1,189 @@
+use std::borrow::Cow;
+use std::io::{Read, Write};
+
+use polars_error::{PolarsResult, polars_bail, to_compute_err};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Compression codec of the streams of an ORC file.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub enum OrcCompression {
+    Uncompressed,
+    Zlib,
+    Snappy,
+    Lz4,
+    #[default]
+    Zstd,
+}
+
+/// Size of the compression chunks that are written.
+pub(super) const DEFAULT_BLOCK_SIZE: usize = 256 * 1024;
+
+impl OrcCompression {
+    pub(super) fn from_kind(kind: u64) -> PolarsResult<Self> {
+        Ok(match kind {
+            0 => Self::Uncompressed,
+            1 => Self::Zlib,
+            2 => Self::Snappy,
+            4 => Self::Lz4,
+            5 => Self::Zstd,
+            3 => polars_bail!(ComputeError: "LZO compression of ORC files is not supported"),
+            _ => polars_bail!(ComputeError: "unknown ORC compression kind: {kind}"),
+        })
+    }
+
+    pub(super) fn kind(self) -> u64 {
+        match self {
+            Self::Uncompressed => 0,
+            Self::Zlib => 1,
+            Self::Snappy => 2,
+            Self::Lz4 => 4,
+            Self::Zstd => 5,
+        }
+    }
+
+    /// Decompress a complete stream. A compressed stream consists of chunks that each start with
+    /// a 3-byte header holding the chunk length and whether the chunk is stored uncompressed.
+    pub(super) fn decompress<'a>(
+        self,
+        mut bytes: &'a [u8],
+        block_size: usize,
+    ) -> PolarsResult<Cow<'a, [u8]>> {
+        if self == Self::Uncompressed {
+            return Ok(Cow::Borrowed(bytes));
+        }
+
+        let mut out = Vec::with_capacity(bytes.len() * 2);
+        while !bytes.is_empty() {
+            let Some((header, rest)) = bytes.split_first_chunk::<3>() else {
+                polars_bail!(ComputeError: "truncated ORC compression chunk header");
+            };
+            let header = u32::from_le_bytes([header[0], header[1], header[2], 0]);
+            let (is_original, len) = (header & 1 == 1, (header >> 1) as usize);
+            let Some((chunk, rest)) = rest.split_at_checked(len) else {
+                polars_bail!(ComputeError: "truncated ORC compression chunk");
+            };
+            bytes = rest;
+
+            if is_original {
+                out.extend_from_slice(chunk);
+                continue;
+            }
+
+            match self {
+                Self::Uncompressed => unreachable!(),
+                Self::Zlib => {
+                    flate2::read::DeflateDecoder::new(chunk)
+                        .read_to_end(&mut out)
+                        .map_err(to_compute_err)?;
+                },
+                Self::Snappy => {
+                    let decompressed = snap::raw::Decoder::new()
+                        .decompress_vec(chunk)
+                        .map_err(to_compute_err)?;
+                    out.extend_from_slice(&decompressed);
+                },
+                Self::Lz4 => {
+                    let decompressed = lz4::block::decompress(chunk, Some(block_size as i32))
+                        .map_err(to_compute_err)?;
+                    out.extend_from_slice(&decompressed);
+                },
+                Self::Zstd => {
+                    zstd::Decoder::with_buffer(chunk)
+                        .and_then(|mut decoder| decoder.read_to_end(&mut out))
+                        .map_err(to_compute_err)?;
+                },
+            }
+        }
+
+        Ok(Cow::Owned(out))
+    }
+
+    /// Compress `bytes` as a complete stream, which is appended to `out`.
+    pub(super) fn compress(self, bytes: &[u8], out: &mut Vec<u8>) -> PolarsResult<()> {
+        if self == Self::Uncompressed {
+            out.extend_from_slice(bytes);
+            return Ok(());
+        }
+
+        for chunk in bytes.chunks(DEFAULT_BLOCK_SIZE) {
+            let header_offset = out.len();
+            out.extend_from_slice(&[0; 3]);
+
+            match self {
+                Self::Uncompressed => unreachable!(),
+                Self::Zlib => {
+                    let mut encoder = flate2::write::DeflateEncoder::new(
+                        &mut *out,
+                        flate2::Compression::default(),
+                    );
+                    encoder.write_all(chunk)?;
+                    encoder.finish()?;
+                },
+                Self::Snappy => {
+                    let compressed = snap::raw::Encoder::new()
+                        .compress_vec(chunk)
+                        .map_err(to_compute_err)?;
+                    out.extend_from_slice(&compressed);
+                },
+                Self::Lz4 => {
+                    let compressed =
+                        lz4::block::compress(chunk, None, false).map_err(to_compute_err)?;
+                    out.extend_from_slice(&compressed);
+                },
+                Self::Zstd => {
+                    let compressed = zstd::bulk::compress(chunk, 0).map_err(to_compute_err)?;
+                    out.extend_from_slice(&compressed);
+                },
+            }
+
+            // Chunks that do not get smaller are stored as is.
+            let mut len = out.len() - header_offset - 3;
+            let is_original = len >= chunk.len();
+            if is_original {
+                out.truncate(header_offset + 3);
+                out.extend_from_slice(chunk);
+                len = chunk.len();
+            }
+
+            let header = ((len as u32) << 1) | is_original as u32;
+            out[header_offset..header_offset + 3].copy_from_slice(&header.to_le_bytes()[..3]);
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_compression_roundtrip() {
+        let data = b"abcdefgh".repeat(100_000);
+
+        for compression in [
+            OrcCompression::Uncompressed,
+            OrcCompression::Zlib,
+            OrcCompression::Snappy,
+            OrcCompression::Lz4,
+            OrcCompression::Zstd,
+        ] {
+            let mut compressed = vec![];
+            compression.compress(&data, &mut compressed).unwrap();
+            let decompressed = compression
+                .decompress(&compressed, DEFAULT_BLOCK_SIZE)
+                .unwrap();
+            assert_eq!(decompressed.as_ref(), data.as_slice());
+        }
+
+        // Incompressible chunks are stored as is.
+        let mut compressed = vec![];
+        OrcCompression::Zstd
+            .compress(b"a", &mut compressed)
+            .unwrap();
+        assert_eq!(compressed, [3, 0, 0, b'a']);
+    }
+}
@@ -0,0 +1,247 @@
+//! Functionality for reading and writing Apache ORC files.
+//!
+//! An ORC file consists of stripes, which are decoded independently and in parallel. The file
+//! metadata holds the statistics of every column per stripe, which are used to skip stripes when
+//! scanning with a predicate.
+//!
+//! # Examples
+//!
+//! ```
+//! use polars_core::prelude::*;
+//! use polars_io::orc::{OrcReader, OrcWriter};
+//! use polars_io::{SerReader, SerWriter};
+//! use std::fs::File;
+//!
+//! fn example(df: &mut DataFrame) -> PolarsResult<DataFrame> {
+//!     let file = File::create("example.orc").expect("could not create file");
+//!     OrcWriter::new(file).finish(df)?;
+//!
+//!     let file = File::open("example.orc").expect("file not found");
+//!     OrcReader::new(file)
+//!         .with_columns(Some(vec!["a".to_string()]))
+//!         .finish()
+//! }
+//! ```
+
+mod compression;
+mod proto;
+mod read;
+mod rle;
+mod write;
+
+use polars_core::POOL;
+use polars_core::prelude::*;
+use polars_core::utils::accumulate_dataframes_vertical_unchecked;
+use rayon::prelude::*;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+pub use self::compression::OrcCompression;
+pub use self::read::{OrcMetadata, read_metadata, read_stripe};
+pub use self::write::{
+    BatchedWriter, EncodedOrcColumn, OrcStripeEncoder, OrcWriter, OrcWriterOptions,
+};
+use crate::RowIndex;
+use crate::mmap::MmapBytesReader;
+use crate::prelude::*;
+use crate::utils::get_reader_bytes;
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub struct OrcScanOptions {
+    /// Use the stripe statistics to skip stripes that cannot match the predicate.
+    pub use_statistics: bool,
+}
+
+impl Default for OrcScanOptions {
+    fn default() -> Self {
+        Self {
+            use_statistics: true,
+        }
+    }
+}
+
+/// Read Apache ORC format into a DataFrame.
+///
+/// # Example
+/// ```
+/// use polars_core::prelude::*;
+/// use polars_io::orc::OrcReader;
+/// use polars_io::SerReader;
+/// use std::fs::File;
+///
+/// fn example() -> PolarsResult<DataFrame> {
+///     let file = File::open("file.orc").expect("file not found");
+///
+///     OrcReader::new(file)
+///         .finish()
+/// }
+/// ```
+#[must_use]
+pub struct OrcReader<R: MmapBytesReader> {
+    reader: R,
+    rechunk: bool,
+    n_rows: Option<usize>,
+    columns: Option<Vec<String>>,
+    projection: Option<Vec<usize>>,
+    row_index: Option<RowIndex>,
+    metadata: Option<Arc<OrcMetadata>>,
+}
+
+impl<R: MmapBytesReader> OrcReader<R> {
+    /// Get the metadata of the ORC file.
+    pub fn metadata(&mut self) -> PolarsResult<&Arc<OrcMetadata>> {
+        if self.metadata.is_none() {
+            let bytes = get_reader_bytes(&mut self.reader)?;
+            self.metadata = Some(Arc::new(read_metadata(&bytes)?));
+        }
+        Ok(self.metadata.as_ref().unwrap())
+    }
+
+    /// Get the schema of the ORC file.
+    pub fn schema(&mut self) -> PolarsResult<SchemaRef> {
+        Ok(self.metadata()?.schema().clone())
+    }
+
+    /// Stop reading after `num_rows` rows.
+    pub fn with_n_rows(mut self, num_rows: Option<usize>) -> Self {
+        self.n_rows = num_rows;
+        self
+    }
+
+    /// Columns to select/ project
+    pub fn with_columns(mut self, columns: Option<Vec<String>>) -> Self {
+        self.columns = columns;
+        self
+    }
+
+    /// Set the reader's column projection. This counts from 0, meaning that
+    /// `vec![0, 4]` would select the 1st and 5th column.
+    pub fn with_projection(mut self, projection: Option<Vec<usize>>) -> Self {
+        self.projection = projection;
+        self
+    }
+
+    /// Add a row index column.
+    pub fn with_row_index(mut self, row_index: Option<RowIndex>) -> Self {
+        self.row_index = row_index;
+        self
+    }
+}
+
+impl<R: MmapBytesReader> SerReader<R> for OrcReader<R> {
+    fn new(reader: R) -> Self {
+        OrcReader {
+            reader,
+            rechunk: false,
+            n_rows: None,
+            columns: None,
+            projection: None,
+            row_index: None,
+            metadata: None,
+        }
+    }
+
+    fn set_rechunk(mut self, rechunk: bool) -> Self {
+        self.rechunk = rechunk;
+        self
+    }
+
+    fn finish(mut self) -> PolarsResult<DataFrame> {
+        let metadata = self.metadata()?.clone();
+        let schema = metadata.schema();
+
+        let projection = match (&self.columns, self.projection.take()) {
+            (Some(columns), _) => columns
+                .iter()
+                .map(|name| schema.try_index_of(name))
+                .collect::<PolarsResult<_>>()?,
+            (None, Some(projection)) => projection,
+            (None, None) => (0..schema.len()).collect(),
+        };
+        let n_rows = self
+            .n_rows
+            .map_or(metadata.num_rows(), |n| n.min(metadata.num_rows()));
+
+        // Only decode the stripes that are needed for the first `n_rows` rows.
+        let mut num_stripes = 0;
+        let mut rows_seen = 0;
+        while rows_seen < n_rows {
+            rows_seen += metadata.stripe_num_rows(num_stripes);
+            num_stripes += 1;
+        }
+
+        let mut df = if projection.is_empty() {
+            DataFrame::empty_with_height(n_rows)
+        } else if num_stripes == 0 {
+            DataFrame::empty_with_schema(&schema.try_project_indices(&projection)?)
+        } else {
+            let bytes = get_reader_bytes(&mut self.reader)?;
+            let dfs = POOL.install(|| {
+                (0..num_stripes)
+                    .into_par_iter()
+                    .map(|i| read_stripe(&bytes, &metadata, i, &projection))
+                    .collect::<PolarsResult<Vec<_>>>()
+            })?;
+            accumulate_dataframes_vertical_unchecked(dfs).slice(0, n_rows)
+        };
+
+        if let Some(row_index) = &self.row_index {
+            // SAFETY: The name of the row index is checked by the caller.
+            unsafe { df.with_row_index_mut(row_index.name.clone(), Some(row_index.offset)) };
+        }
+        if self.rechunk {
+            df.as_single_chunk_par();
+        }
+        Ok(df)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use super::*;
+
+    #[test]
+    fn test_orc_roundtrip() -> PolarsResult<()> {
+        let mut df = df!(
+            "bool" => [Some(true), None, Some(false)],
+            "int" => [Some(1i64), Some(-200), None],
+            "float" => [1.5f64, f64::NAN, -3.0],
+            "str" => [Some("a"), None, Some("ccc")],
+            "list" => [
+                Some(Series::new("".into(), [1i32, 2])),
+                None,
+                Some(Series::new("".into(), Vec::<i32>::new())),
+            ],
+        )?;
+        df.with_column(
+            df.select(["int", "str"])?
+                .into_struct("struct".into())
+                .into_series(),
+        )?;
+
+        for compression in [
+            OrcCompression::Uncompressed,
+            OrcCompression::Zlib,
+            OrcCompression::Snappy,
+            OrcCompression::Lz4,
+            OrcCompression::Zstd,
+        ] {
+            let mut buf = Cursor::new(vec![]);
+            OrcWriter::new(&mut buf)
+                .with_compression(compression)
+                .with_rows_per_stripe(Some(2))
+                .finish(&mut df)?;
+
+            buf.set_position(0);
+            let mut reader = OrcReader::new(buf);
+            assert_eq!(reader.metadata()?.num_stripes(), 2);
+            let out = reader.finish()?;
+            assert!(out.equals_missing(&df));
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,869 @@
+//! Minimal protobuf (de)serialization of the ORC metadata messages.
+//!
+//! Only the fields that are used by the reader and the writer are represented, unknown fields are
+//! skipped while decoding. Field numbers follow `orc_proto.proto` of the ORC specification.
+
+use polars_error::{PolarsResult, polars_bail, polars_err};
+
+pub(super) mod kind {
+    pub const BOOLEAN: u64 = 0;
+    pub const BYTE: u64 = 1;
+    pub const SHORT: u64 = 2;
+    pub const INT: u64 = 3;
+    pub const LONG: u64 = 4;
+    pub const FLOAT: u64 = 5;
+    pub const DOUBLE: u64 = 6;
+    pub const STRING: u64 = 7;
+    pub const BINARY: u64 = 8;
+    pub const TIMESTAMP: u64 = 9;
+    pub const LIST: u64 = 10;
+    pub const MAP: u64 = 11;
+    pub const STRUCT: u64 = 12;
+    pub const UNION: u64 = 13;
+    pub const DECIMAL: u64 = 14;
+    pub const DATE: u64 = 15;
+    pub const VARCHAR: u64 = 16;
+    pub const CHAR: u64 = 17;
+    pub const TIMESTAMP_INSTANT: u64 = 18;
+}
+
+pub(super) mod stream_kind {
+    pub const PRESENT: u64 = 0;
+    pub const DATA: u64 = 1;
+    pub const LENGTH: u64 = 2;
+    pub const DICTIONARY_DATA: u64 = 3;
+    pub const SECONDARY: u64 = 5;
+}
+
+pub(super) mod encoding_kind {
+    pub const DIRECT: u64 = 0;
+    pub const DICTIONARY: u64 = 1;
+    pub const DIRECT_V2: u64 = 2;
+    pub const DICTIONARY_V2: u64 = 3;
+}
+
+enum WireValue<'a> {
+    Varint(u64),
+    Fixed64(u64),
+    Bytes(&'a [u8]),
+    /// Only skipped, none of the fields that are read are 32-bit fixed width.
+    Fixed32,
+}
+
+impl<'a> WireValue<'a> {
+    fn as_u64(&self) -> PolarsResult<u64> {
+        match self {
+            Self::Varint(v) => Ok(*v),
+            _ => Err(malformed()),
+        }
+    }
+
+    fn as_i64(&self) -> PolarsResult<i64> {
+        self.as_u64().map(unzigzag)
+    }
+
+    fn as_f64(&self) -> PolarsResult<f64> {
+        match self {
+            Self::Fixed64(v) => Ok(f64::from_bits(*v)),
+            _ => Err(malformed()),
+        }
+    }
+
+    fn as_bytes(&self) -> PolarsResult<&'a [u8]> {
+        match self {
+            Self::Bytes(v) => Ok(v),
+            _ => Err(malformed()),
+        }
+    }
+
+    fn as_string(&self) -> PolarsResult<String> {
+        let bytes = self.as_bytes()?;
+        String::from_utf8(bytes.to_vec()).map_err(|_| malformed())
+    }
+
+    /// Repeated scalar fields can be either packed or not.
+    fn extend_u64(&self, out: &mut Vec<u64>) -> PolarsResult<()> {
+        match self {
+            Self::Varint(v) => out.push(*v),
+            Self::Bytes(bytes) => {
+                let mut bytes = *bytes;
+                while !bytes.is_empty() {
+                    out.push(read_varint(&mut bytes)?);
+                }
+            },
+            _ => return Err(malformed()),
+        }
+        Ok(())
+    }
+}
+
+fn malformed() -> polars_error::PolarsError {
+    polars_err!(ComputeError: "malformed ORC metadata")
+}
+
+pub(super) fn unzigzag(v: u64) -> i64 {
+    ((v >> 1) as i64) ^ -((v & 1) as i64)
+}
+
+pub(super) fn zigzag(v: i64) -> u64 {
+    ((v << 1) ^ (v >> 63)) as u64
+}
+
+pub(super) fn read_varint(bytes: &mut &[u8]) -> PolarsResult<u64> {
+    let mut out = 0u64;
+    for shift in (0..64).step_by(7) {
+        let Some((&b, rest)) = bytes.split_first() else {
+            polars_bail!(ComputeError: "unexpected end of ORC varint");
+        };
+        *bytes = rest;
+        out |= ((b & 0x7f) as u64) << shift;
+        if b & 0x80 == 0 {
+            return Ok(out);
+        }
+    }
+    polars_bail!(ComputeError: "ORC varint is too long")
+}
+
+pub(super) fn write_varint(out: &mut Vec<u8>, mut v: u64) {
+    while v >= 0x80 {
+        out.push((v as u8) | 0x80);
+        v >>= 7;
+    }
+    out.push(v as u8);
+}
+
+/// Iterate over the fields of an encoded message.
+struct FieldIter<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> FieldIter<'a> {
+    fn next_field(&mut self) -> PolarsResult<Option<(u64, WireValue<'a>)>> {
+        if self.bytes.is_empty() {
+            return Ok(None);
+        }
+
+        let key = read_varint(&mut self.bytes)?;
+        let value = match key & 7 {
+            0 => WireValue::Varint(read_varint(&mut self.bytes)?),
+            1 => {
+                let (v, rest) = self.bytes.split_at_checked(8).ok_or_else(malformed)?;
+                self.bytes = rest;
+                WireValue::Fixed64(u64::from_le_bytes(v.try_into().unwrap()))
+            },
+            2 => {
+                let len = read_varint(&mut self.bytes)? as usize;
+                let (v, rest) = self.bytes.split_at_checked(len).ok_or_else(malformed)?;
+                self.bytes = rest;
+                WireValue::Bytes(v)
+            },
+            5 => {
+                let (_, rest) = self.bytes.split_at_checked(4).ok_or_else(malformed)?;
+                self.bytes = rest;
+                WireValue::Fixed32
+            },
+            _ => return Err(malformed()),
+        };
+
+        Ok(Some((key >> 3, value)))
+    }
+}
+
+macro_rules! for_each_field {
+    ($bytes:expr, |$field:ident, $value:ident| $body:block) => {{
+        let mut iter = FieldIter { bytes: $bytes };
+        while let Some(($field, $value)) = iter.next_field()? {
+            $body
+        }
+    }};
+}
+
+#[derive(Default)]
+struct MessageWriter {
+    buf: Vec<u8>,
+}
+
+impl MessageWriter {
+    fn key(&mut self, field: u64, wire_type: u64) {
+        write_varint(&mut self.buf, (field << 3) | wire_type);
+    }
+
+    fn u64(&mut self, field: u64, v: u64) {
+        self.key(field, 0);
+        write_varint(&mut self.buf, v);
+    }
+
+    fn i64(&mut self, field: u64, v: i64) {
+        self.u64(field, zigzag(v));
+    }
+
+    fn f64(&mut self, field: u64, v: f64) {
+        self.key(field, 1);
+        self.buf.extend_from_slice(&v.to_bits().to_le_bytes());
+    }
+
+    fn bytes(&mut self, field: u64, v: &[u8]) {
+        self.key(field, 2);
+        write_varint(&mut self.buf, v.len() as u64);
+        self.buf.extend_from_slice(v);
+    }
+
+    fn packed(&mut self, field: u64, values: impl IntoIterator<Item = u64>) {
+        let mut packed = vec![];
+        for v in values {
+            write_varint(&mut packed, v);
+        }
+        self.bytes(field, &packed);
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+pub(super) struct PostScript {
+    pub footer_length: u64,
+    pub compression: u64,
+    pub compression_block_size: Option<u64>,
+    pub metadata_length: u64,
+}
+
+impl PostScript {
+    pub fn decode(bytes: &[u8]) -> PolarsResult<Self> {
+        let mut out = Self::default();
+        let mut magic = None;
+        for_each_field!(bytes, |field, value| {
+            match field {
+                1 => out.footer_length = value.as_u64()?,
+                2 => out.compression = value.as_u64()?,
+                3 => out.compression_block_size = Some(value.as_u64()?),
+                5 => out.metadata_length = value.as_u64()?,
+                8000 => magic = Some(value.as_bytes()?),
+                _ => {},
+            }
+        });
+        if magic.is_some_and(|m| m != b"ORC") {
+            polars_bail!(ComputeError: "invalid ORC postscript magic");
+        }
+        Ok(out)
+    }
+
+    pub fn encode(&self) -> Vec<u8> {
+        let mut w = MessageWriter::default();
+        w.u64(1, self.footer_length);
+        w.u64(2, self.compression);
+        if let Some(block_size) = self.compression_block_size {
+            w.u64(3, block_size);
+        }
+        // File format version 0.12.
+        w.packed(4, [0, 12]);
+        w.u64(5, self.metadata_length);
+        // ORC-135: Timestamps are written in UTC.
+        w.u64(6, 6);
+        w.bytes(8000, b"ORC");
+        w.buf
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+pub(super) struct StripeInformation {
+    pub offset: u64,
+    pub index_length: u64,
+    pub data_length: u64,
+    pub footer_length: u64,
+    pub number_of_rows: u64,
+}
+
+impl StripeInformation {
+    fn decode(bytes: &[u8]) -> PolarsResult<Self> {
+        let mut out = Self::default();
+        for_each_field!(bytes, |field, value| {
+            match field {
+                1 => out.offset = value.as_u64()?,
+                2 => out.index_length = value.as_u64()?,
+                3 => out.data_length = value.as_u64()?,
+                4 => out.footer_length = value.as_u64()?,
+                5 => out.number_of_rows = value.as_u64()?,
+                _ => {},
+            }
+        });
+        Ok(out)
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        let mut w = MessageWriter::default();
+        w.u64(1, self.offset);
+        w.u64(2, self.index_length);
+        w.u64(3, self.data_length);
+        w.u64(4, self.footer_length);
+        w.u64(5, self.number_of_rows);
+        w.buf
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub(super) struct Type {
+    pub kind: u64,
+    pub subtypes: Vec<u32>,
+    pub field_names: Vec<String>,
+    pub precision: Option<u64>,
+    pub scale: Option<u64>,
+}
+
+impl Type {
+    fn decode(bytes: &[u8]) -> PolarsResult<Self> {
+        let mut out = Self::default();
+        let mut subtypes = vec![];
+        for_each_field!(bytes, |field, value| {
+            match field {
+                1 => out.kind = value.as_u64()?,
+                2 => value.extend_u64(&mut subtypes)?,
+                3 => out.field_names.push(value.as_string()?),
+                5 => out.precision = Some(value.as_u64()?),
+                6 => out.scale = Some(value.as_u64()?),
+                _ => {},
+            }
+        });
+        out.subtypes = subtypes.into_iter().map(|v| v as u32).collect();
+        Ok(out)
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        let mut w = MessageWriter::default();
+        w.u64(1, self.kind);
+        if !self.subtypes.is_empty() {
+            w.packed(2, self.subtypes.iter().map(|&v| v as u64));
+        }
+        for name in &self.field_names {
+            w.bytes(3, name.as_bytes());
+        }
+        if let Some(precision) = self.precision {
+            w.u64(5, precision);
+        }
+        if let Some(scale) = self.scale {
+            w.u64(6, scale);
+        }
+        w.buf
+    }
+}
+
+/// Type specific part of the column statistics.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub(super) enum TypedStatistics {
+    #[default]
+    None,
+    Int {
+        min: i64,
+        max: i64,
+        sum: Option<i64>,
+    },
+    Double {
+        min: f64,
+        max: f64,
+    },
+    String {
+        min: String,
+        max: String,
+    },
+    Bucket {
+        true_count: u64,
+    },
+    Date {
+        min: i32,
+        max: i32,
+    },
+    Binary {
+        sum: i64,
+    },
+    /// Milliseconds since the unix epoch.
+    Timestamp {
+        min_utc: i64,
+        max_utc: i64,
+    },
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub(super) struct ColumnStatistics {
+    pub number_of_values: u64,
+    pub has_null: Option<bool>,
+    pub typed: TypedStatistics,
+}
+
+impl ColumnStatistics {
+    fn decode(bytes: &[u8]) -> PolarsResult<Self> {
+        let mut out = Self::default();
+        for_each_field!(bytes, |field, value| {
+            match field {
+                1 => out.number_of_values = value.as_u64()?,
+                2 => {
+                    let (mut min, mut max, mut sum) = (None, None, None);
+                    for_each_field!(value.as_bytes()?, |field, value| {
+                        match field {
+                            1 => min = Some(value.as_i64()?),
+                            2 => max = Some(value.as_i64()?),
+                            3 => sum = Some(value.as_i64()?),
+                            _ => {},
+                        }
+                    });
+                    if let (Some(min), Some(max)) = (min, max) {
+                        out.typed = TypedStatistics::Int { min, max, sum };
+                    }
+                },
+                3 => {
+                    let (mut min, mut max) = (None, None);
+                    for_each_field!(value.as_bytes()?, |field, value| {
+                        match field {
+                            1 => min = Some(value.as_f64()?),
+                            2 => max = Some(value.as_f64()?),
+                            _ => {},
+                        }
+                    });
+                    if let (Some(min), Some(max)) = (min, max) {
+                        out.typed = TypedStatistics::Double { min, max };
+                    }
+                },
+                4 => {
+                    let (mut min, mut max) = (None, None);
+                    for_each_field!(value.as_bytes()?, |field, value| {
+                        match field {
+                            1 => min = Some(value.as_string()?),
+                            2 => max = Some(value.as_string()?),
+                            _ => {},
+                        }
+                    });
+                    if let (Some(min), Some(max)) = (min, max) {
+                        out.typed = TypedStatistics::String { min, max };
+                    }
+                },
+                5 => {
+                    let mut counts = vec![];
+                    for_each_field!(value.as_bytes()?, |field, value| {
+                        if field == 1 {
+                            value.extend_u64(&mut counts)?;
+                        }
+                    });
+                    if let Some(&true_count) = counts.first() {
+                        out.typed = TypedStatistics::Bucket { true_count };
+                    }
+                },
+                7 => {
+                    let (mut min, mut max) = (None, None);
+                    for_each_field!(value.as_bytes()?, |field, value| {
+                        match field {
+                            1 => min = Some(value.as_i64()? as i32),
+                            2 => max = Some(value.as_i64()? as i32),
+                            _ => {},
+                        }
+                    });
+                    if let (Some(min), Some(max)) = (min, max) {
+                        out.typed = TypedStatistics::Date { min, max };
+                    }
+                },
+                8 => {
+                    for_each_field!(value.as_bytes()?, |field, value| {
+                        if field == 1 {
+                            out.typed = TypedStatistics::Binary {
+                                sum: value.as_i64()?,
+                            };
+                        }
+                    });
+                },
+                9 => {
+                    let (mut min, mut max) = (None, None);
+                    for_each_field!(value.as_bytes()?, |field, value| {
+                        match field {
+                            3 => min = Some(value.as_i64()?),
+                            4 => max = Some(value.as_i64()?),
+                            _ => {},
+                        }
+                    });
+                    if let (Some(min_utc), Some(max_utc)) = (min, max) {
+                        out.typed = TypedStatistics::Timestamp { min_utc, max_utc };
+                    }
+                },
+                10 => out.has_null = Some(value.as_u64()? != 0),
+                _ => {},
+            }
+        });
+        Ok(out)
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        let mut w = MessageWriter::default();
+        w.u64(1, self.number_of_values);
+
+        let mut inner = MessageWriter::default();
+        let field = match &self.typed {
+            TypedStatistics::None => None,
+            TypedStatistics::Int { min, max, sum } => {
+                inner.i64(1, *min);
+                inner.i64(2, *max);
+                if let Some(sum) = sum {
+                    inner.i64(3, *sum);
+                }
+                Some(2)
+            },
+            TypedStatistics::Double { min, max } => {
+                inner.f64(1, *min);
+                inner.f64(2, *max);
+                Some(3)
+            },
+            TypedStatistics::String { min, max } => {
+                inner.bytes(1, min.as_bytes());
+                inner.bytes(2, max.as_bytes());
+                Some(4)
+            },
+            TypedStatistics::Bucket { true_count } => {
+                inner.packed(1, [*true_count]);
+                Some(5)
+            },
+            TypedStatistics::Date { min, max } => {
+                inner.i64(1, *min as i64);
+                inner.i64(2, *max as i64);
+                Some(7)
+            },
+            TypedStatistics::Binary { sum } => {
+                inner.i64(1, *sum);
+                Some(8)
+            },
+            TypedStatistics::Timestamp { min_utc, max_utc } => {
+                // The writer timezone is UTC, so local and UTC statistics are the same.
+                inner.i64(1, *min_utc);
+                inner.i64(2, *max_utc);
+                inner.i64(3, *min_utc);
+                inner.i64(4, *max_utc);
+                Some(9)
+            },
+        };
+        if let Some(field) = field {
+            w.bytes(field, &inner.buf);
+        }
+
+        if let Some(has_null) = self.has_null {
+            w.u64(10, has_null as u64);
+        }
+        w.buf
+    }
+
+    /// Merge the statistics of `other` into `self`, e.g. to get file statistics from stripe
+    /// statistics.
+    pub fn merge(&mut self, other: &Self) {
+        use TypedStatistics as T;
+
+        let typed = match (&self.typed, &other.typed) {
+            (_, T::None) if other.number_of_values == 0 => self.typed.clone(),
+            (T::None, _) if self.number_of_values == 0 => other.typed.clone(),
+            (
+                T::Int { min, max, sum },
+                T::Int {
+                    min: min_r,
+                    max: max_r,
+                    sum: sum_r,
+                },
+            ) => T::Int {
+                min: *min.min(min_r),
+                max: *max.max(max_r),
+                sum: sum.zip(*sum_r).and_then(|(l, r)| l.checked_add(r)),
+            },
+            (
+                T::Double { min, max },
+                T::Double {
+                    min: min_r,
+                    max: max_r,
+                },
+            ) => T::Double {
+                min: min.min(*min_r),
+                max: max.max(*max_r),
+            },
+            (
+                T::String { min, max },
+                T::String {
+                    min: min_r,
+                    max: max_r,
+                },
+            ) => T::String {
+                min: min.min(min_r).clone(),
+                max: max.max(max_r).clone(),
+            },
+            (T::Bucket { true_count }, T::Bucket { true_count: r }) => T::Bucket {
+                true_count: true_count + r,
+            },
+            (
+                T::Date { min, max },
+                T::Date {
+                    min: min_r,
+                    max: max_r,
+                },
+            ) => T::Date {
+                min: *min.min(min_r),
+                max: *max.max(max_r),
+            },
+            (T::Binary { sum }, T::Binary { sum: r }) => T::Binary {
+                sum: sum.saturating_add(*r),
+            },
+            (
+                T::Timestamp { min_utc, max_utc },
+                T::Timestamp {
+                    min_utc: min_r,
+                    max_utc: max_r,
+                },
+            ) => T::Timestamp {
+                min_utc: *min_utc.min(min_r),
+                max_utc: *max_utc.max(max_r),
+            },
+            _ => T::None,
+        };
+
+        self.number_of_values += other.number_of_values;
+        self.has_null = self.has_null.zip(other.has_null).map(|(l, r)| l || r);
+        self.typed = typed;
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+pub(super) struct Footer {
+    pub header_length: u64,
+    pub content_length: u64,
+    pub stripes: Vec<StripeInformation>,
+    pub types: Vec<Type>,
+    pub number_of_rows: u64,
+    pub statistics: Vec<ColumnStatistics>,
+}
+
+impl Footer {
+    pub fn decode(bytes: &[u8]) -> PolarsResult<Self> {
+        let mut out = Self::default();
+        for_each_field!(bytes, |field, value| {
+            match field {
+                1 => out.header_length = value.as_u64()?,
+                2 => out.content_length = value.as_u64()?,
+                3 => out
+                    .stripes
+                    .push(StripeInformation::decode(value.as_bytes()?)?),
+                4 => out.types.push(Type::decode(value.as_bytes()?)?),
+                6 => out.number_of_rows = value.as_u64()?,
+                7 => out
+                    .statistics
+                    .push(ColumnStatistics::decode(value.as_bytes()?)?),
+                _ => {},
+            }
+        });
+        Ok(out)
+    }
+
+    pub fn encode(&self) -> Vec<u8> {
+        let mut w = MessageWriter::default();
+        w.u64(1, self.header_length);
+        w.u64(2, self.content_length);
+        for stripe in &self.stripes {
+            w.bytes(3, &stripe.encode());
+        }
+        for ty in &self.types {
+            w.bytes(4, &ty.encode());
+        }
+        w.u64(6, self.number_of_rows);
+        for statistics in &self.statistics {
+            w.bytes(7, &statistics.encode());
+        }
+        // No row indexes are written.
+        w.u64(8, 0);
+        w.buf
+    }
+}
+
+/// The file metadata section, containing the statistics of each stripe.
+#[derive(Clone, Debug, Default)]
+pub(super) struct Metadata {
+    pub stripe_statistics: Vec<Vec<ColumnStatistics>>,
+}
+
+impl Metadata {
+    pub fn decode(bytes: &[u8]) -> PolarsResult<Self> {
+        let mut out = Self::default();
+        for_each_field!(bytes, |field, value| {
+            if field == 1 {
+                let mut column_statistics = vec![];
+                for_each_field!(value.as_bytes()?, |field, value| {
+                    if field == 1 {
+                        column_statistics.push(ColumnStatistics::decode(value.as_bytes()?)?);
+                    }
+                });
+                out.stripe_statistics.push(column_statistics);
+            }
+        });
+        Ok(out)
+    }
+
+    pub fn encode(&self) -> Vec<u8> {
+        let mut w = MessageWriter::default();
+        for column_statistics in &self.stripe_statistics {
+            let mut inner = MessageWriter::default();
+            for statistics in column_statistics {
+                inner.bytes(1, &statistics.encode());
+            }
+            w.bytes(1, &inner.buf);
+        }
+        w.buf
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub(super) struct Stream {
+    pub kind: u64,
+    pub column: u32,
+    pub length: u64,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub(super) struct ColumnEncoding {
+    pub kind: u64,
+    pub dictionary_size: u64,
+}
+
+#[derive(Clone, Debug, Default)]
+pub(super) struct StripeFooter {
+    pub streams: Vec<Stream>,
+    pub columns: Vec<ColumnEncoding>,
+    /// The time zone that the `TIMESTAMP` columns of the stripe were written in.
+    pub writer_timezone: Option<String>,
+}
+
+impl StripeFooter {
+    pub fn decode(bytes: &[u8]) -> PolarsResult<Self> {
+        let mut out = Self::default();
+        for_each_field!(bytes, |field, value| {
+            match field {
+                1 => {
+                    let mut stream = Stream::default();
+                    for_each_field!(value.as_bytes()?, |field, value| {
+                        match field {
+                            1 => stream.kind = value.as_u64()?,
+                            2 => stream.column = value.as_u64()? as u32,
+                            3 => stream.length = value.as_u64()?,
+                            _ => {},
+                        }
+                    });
+                    out.streams.push(stream);
+                },
+                2 => {
+                    let mut encoding = ColumnEncoding::default();
+                    for_each_field!(value.as_bytes()?, |field, value| {
+                        match field {
+                            1 => encoding.kind = value.as_u64()?,
+                            2 => encoding.dictionary_size = value.as_u64()?,
+                            _ => {},
+                        }
+                    });
+                    out.columns.push(encoding);
+                },
+                3 => out.writer_timezone = Some(value.as_string()?),
+                _ => {},
+            }
+        });
+        Ok(out)
+    }
+
+    pub fn encode(&self) -> Vec<u8> {
+        let mut w = MessageWriter::default();
+        for stream in &self.streams {
+            let mut inner = MessageWriter::default();
+            inner.u64(1, stream.kind);
+            inner.u64(2, stream.column as u64);
+            inner.u64(3, stream.length);
+            w.bytes(1, &inner.buf);
+        }
+        for encoding in &self.columns {
+            let mut inner = MessageWriter::default();
+            inner.u64(1, encoding.kind);
+            if encoding.dictionary_size > 0 {
+                inner.u64(2, encoding.dictionary_size);
+            }
+            w.bytes(2, &inner.buf);
+        }
+        if let Some(tz) = &self.writer_timezone {
+            w.bytes(3, tz.as_bytes());
+        }
+        w.buf
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_footer_roundtrip() {
+        let footer = Footer {
+            header_length: 3,
+            content_length: 100,
+            stripes: vec![StripeInformation {
+                offset: 3,
+                index_length: 0,
+                data_length: 80,
+                footer_length: 17,
+                number_of_rows: 10,
+            }],
+            types: vec![
+                Type {
+                    kind: kind::STRUCT,
+                    subtypes: vec![1, 2],
+                    field_names: vec!["a".into(), "b".into()],
+                    ..Default::default()
+                },
+                Type {
+                    kind: kind::LONG,
+                    ..Default::default()
+                },
+                Type {
+                    kind: kind::DECIMAL,
+                    precision: Some(10),
+                    scale: Some(2),
+                    ..Default::default()
+                },
+            ],
+            number_of_rows: 10,
+            statistics: vec![ColumnStatistics {
+                number_of_values: 8,
+                has_null: Some(true),
+                typed: TypedStatistics::Int {
+                    min: -5,
+                    max: 300,
+                    sum: Some(1000),
+                },
+            }],
+        };
+
+        let decoded = Footer::decode(&footer.encode()).unwrap();
+        assert_eq!(decoded.types, footer.types);
+        assert_eq!(decoded.statistics, footer.statistics);
+        assert_eq!(decoded.stripes[0].footer_length, 17);
+        assert_eq!(decoded.number_of_rows, 10);
+    }
+
+    #[test]
+    fn test_merge_statistics() {
+        let mut l = ColumnStatistics {
+            number_of_values: 2,
+            has_null: Some(false),
+            typed: TypedStatistics::String {
+                min: "b".into(),
+                max: "c".into(),
+            },
+        };
+        let r = ColumnStatistics {
+            number_of_values: 1,
+            has_null: Some(true),
+            typed: TypedStatistics::String {
+                min: "a".into(),
+                max: "a".into(),
+            },
+        };
+        l.merge(&r);
+        assert_eq!(l.number_of_values, 3);
+        assert_eq!(l.has_null, Some(true));
+        assert_eq!(
+            l.typed,
+            TypedStatistics::String {
+                min: "a".into(),
+                max: "c".into()
+            }
+        );
+    }
+}
@@ -0,0 +1,806 @@
+use std::borrow::Cow;
+
+use arrow::array::{BooleanArray, ListArray, MutableBinaryViewArray, Utf8ViewArray};
+use arrow::bitmap::Bitmap;
+use arrow::offset::Offsets;
+use polars_core::prelude::*;
+use polars_core::utils::arrow;
+use polars_error::{polars_bail, polars_ensure, polars_err};
+use polars_utils::pl_str::PlSmallStr;
+
+use super::compression::{DEFAULT_BLOCK_SIZE, OrcCompression};
+use super::proto::{
+    ColumnEncoding, ColumnStatistics, Footer, Metadata, PostScript, StripeFooter, Type,
+    TypedStatistics, encoding_kind, kind, stream_kind,
+};
+use super::rle;
+
+/// Seconds between the unix epoch and the ORC timestamp epoch (2015-01-01 00:00:00 UTC).
+pub(super) const ORC_EPOCH_SECONDS: i64 = 1_420_070_400;
+
+/// Metadata of an ORC file, read from the tail of the file.
+#[derive(Debug)]
+pub struct OrcMetadata {
+    compression: OrcCompression,
+    block_size: usize,
+    footer: Footer,
+    stripe_statistics: Vec<Vec<ColumnStatistics>>,
+    schema: SchemaRef,
+}
+
+impl OrcMetadata {
+    pub fn schema(&self) -> &SchemaRef {
+        &self.schema
+    }
+
+    pub fn num_rows(&self) -> usize {
+        self.footer.number_of_rows as usize
+    }
+
+    pub fn num_stripes(&self) -> usize {
+        self.footer.stripes.len()
+    }
+
+    pub fn stripe_num_rows(&self, stripe_idx: usize) -> usize {
+        self.footer.stripes[stripe_idx].number_of_rows as usize
+    }
+
+    /// Column id of the top-level field at `field_idx`.
+    fn column_id(&self, field_idx: usize) -> u32 {
+        self.footer.types[0].subtypes[field_idx]
+    }
+
+    /// Get the `[min, max, null_count]` statistics of the top-level field at `field_idx` for
+    /// every stripe. The statistics are NULL where they are not available.
+    ///
+    /// The min and max columns have the dtype of the field. Floating point statistics are not
+    /// used, as writers differ in their handling of NaN.
+    pub fn stripe_statistics_columns(&self, field_idx: usize) -> [Column; 3] {
+        let (_, dtype) = self.schema.get_at_index(field_idx).unwrap();
+        let n_stripes = self.num_stripes();
+
+        let column_id = self.column_id(field_idx) as usize;
+        let statistics = (self.stripe_statistics.len() == n_stripes)
+            .then(|| {
+                self.stripe_statistics
+                    .iter()
+                    .map(|stats| stats.get(column_id))
+                    .collect::<Option<Vec<_>>>()
+            })
+            .flatten();
+
+        let null = |dtype: &DataType| Column::full_null(PlSmallStr::EMPTY, n_stripes, dtype);
+        let Some(statistics) = statistics else {
+            return [null(dtype), null(dtype), null(&IDX_DTYPE)];
+        };
+
+        let null_count: IdxCa = statistics
+            .iter()
+            .zip(&self.footer.stripes)
+            .map(|(stats, stripe)| {
+                Some(stripe.number_of_rows.saturating_sub(stats.number_of_values) as IdxSize)
+            })
+            .collect();
+        let null_count = null_count.into_column();
+
+        let min_max = || -> PolarsResult<Option<(Series, Series)>> {
+            macro_rules! collect {
+                ($variant:ident, |$min:ident, $max:ident| ($min_out:expr, $max_out:expr), $ca:ty) => {{
+                    let (min, max): (Vec<_>, Vec<_>) = statistics
+                        .iter()
+                        .map(|stats| match &stats.typed {
+                            TypedStatistics::$variant { $min, $max, .. } => {
+                                (Some($min_out), Some($max_out))
+                            },
+                            _ => (None, None),
+                        })
+                        .unzip();
+                    (
+                        <$ca>::from_iter_options(PlSmallStr::EMPTY, min.into_iter()).into_series(),
+                        <$ca>::from_iter_options(PlSmallStr::EMPTY, max.into_iter()).into_series(),
+                    )
+                }};
+            }
+
+            let (min, max) = match dtype {
+                DataType::Int8 | DataType::Int16 | DataType::Int32 | DataType::Int64 => {
+                    collect!(Int, |min, max| (*min, *max), Int64Chunked)
+                },
+                DataType::Date => {
+                    collect!(Date, |min, max| (*min, *max), Int32Chunked)
+                },
+                DataType::Datetime(_, _) => {
+                    // The statistics have a millisecond precision.
+                    collect!(
+                        Timestamp,
+                        |min_utc, max_utc| (
+                            min_utc.saturating_mul(1_000_000),
+                            max_utc.saturating_add(1).saturating_mul(1_000_000) - 1
+                        ),
+                        Int64Chunked
+                    )
+                },
+                DataType::String => {
+                    let (min, max): (Vec<_>, Vec<_>) = statistics
+                        .iter()
+                        .map(|stats| match &stats.typed {
+                            TypedStatistics::String { min, max } => {
+                                (Some(min.as_str()), Some(max.as_str()))
+                            },
+                            _ => (None, None),
+                        })
+                        .unzip();
+                    (
+                        StringChunked::from_iter_options(PlSmallStr::EMPTY, min.into_iter())
+                            .into_series(),
+                        StringChunked::from_iter_options(PlSmallStr::EMPTY, max.into_iter())
+                            .into_series(),
+                    )
+                },
+                DataType::Boolean => {
+                    let (min, max): (Vec<_>, Vec<_>) = statistics
+                        .iter()
+                        .map(|stats| match &stats.typed {
+                            TypedStatistics::Bucket { true_count }
+                                if stats.number_of_values > 0 =>
+                            {
+                                (
+                                    Some(*true_count == stats.number_of_values),
+                                    Some(*true_count > 0),
+                                )
+                            },
+                            _ => (None, None),
+                        })
+                        .unzip();
+                    (
+                        BooleanChunked::from_iter_options(PlSmallStr::EMPTY, min.into_iter())
+                            .into_series(),
+                        BooleanChunked::from_iter_options(PlSmallStr::EMPTY, max.into_iter())
+                            .into_series(),
+                    )
+                },
+                _ => return Ok(None),
+            };
+
+            Ok(Some((min.cast(dtype)?, max.cast(dtype)?)))
+        };
+
+        match min_max() {
+            Ok(Some((min, max))) => [min.into_column(), max.into_column(), null_count],
+            _ => [null(dtype), null(dtype), null_count],
+        }
+    }
+}
+
+/// Read the metadata from the tail of an ORC file.
+pub fn read_metadata(bytes: &[u8]) -> PolarsResult<OrcMetadata> {
+    let invalid = || polars_err!(ComputeError: "invalid ORC file: file is too small");
+
+    let (&ps_len, rest) = bytes.split_last().ok_or_else(invalid)?;
+    let (rest, postscript) = rest
+        .split_at_checked(
+            rest.len()
+                .checked_sub(ps_len as usize)
+                .ok_or_else(invalid)?,
+        )
+        .ok_or_else(invalid)?;
+    let postscript = PostScript::decode(postscript)?;
+
+    let compression = OrcCompression::from_kind(postscript.compression)?;
+    let block_size = postscript
+        .compression_block_size
+        .map_or(DEFAULT_BLOCK_SIZE, |v| v as usize);
+
+    let footer_start = rest
+        .len()
+        .checked_sub(postscript.footer_length as usize)
+        .ok_or_else(invalid)?;
+    let metadata_start = footer_start
+        .checked_sub(postscript.metadata_length as usize)
+        .ok_or_else(invalid)?;
+    polars_ensure!(
+        bytes.starts_with(b"ORC"),
+        ComputeError: "invalid ORC file: missing magic bytes"
+    );
+
+    let footer = compression.decompress(&rest[footer_start..], block_size)?;
+    let footer = Footer::decode(&footer)?;
+    let metadata = compression.decompress(&rest[metadata_start..footer_start], block_size)?;
+    let metadata = Metadata::decode(&metadata)?;
+
+    polars_ensure!(
+        footer.types.first().is_some_and(|t| t.kind == kind::STRUCT),
+        ComputeError: "invalid ORC file: the root type must be a struct"
+    );
+    let root = &footer.types[0];
+    let schema = root
+        .field_names
+        .iter()
+        .zip(&root.subtypes)
+        .map(|(name, &id)| Ok(Field::new(name.into(), to_dtype(&footer.types, id)?)))
+        .collect::<PolarsResult<Schema>>()?;
+
+    Ok(OrcMetadata {
+        compression,
+        block_size,
+        footer,
+        stripe_statistics: metadata.stripe_statistics,
+        schema: Arc::new(schema),
+    })
+}
+
+fn get_type(types: &[Type], id: u32) -> PolarsResult<&Type> {
+    types
+        .get(id as usize)
+        .ok_or_else(|| polars_err!(ComputeError: "invalid ORC file: unknown column id {id}"))
+}
+
+fn to_dtype(types: &[Type], id: u32) -> PolarsResult<DataType> {
+    let ty = get_type(types, id)?;
+    let child = |i: usize| {
+        let id = *ty
+            .subtypes
+            .get(i)
+            .ok_or_else(|| polars_err!(ComputeError: "invalid ORC file: missing subtype"))?;
+        to_dtype(types, id)
+    };
+
+    Ok(match ty.kind {
+        kind::BOOLEAN => DataType::Boolean,
+        kind::BYTE => DataType::Int8,
+        kind::SHORT => DataType::Int16,
+        kind::INT => DataType::Int32,
+        kind::LONG => DataType::Int64,
+        kind::FLOAT => DataType::Float32,
+        kind::DOUBLE => DataType::Float64,
+        kind::STRING | kind::VARCHAR | kind::CHAR => DataType::String,
+        kind::BINARY => DataType::Binary,
+        kind::TIMESTAMP => DataType::Datetime(TimeUnit::Nanoseconds, None),
+        kind::TIMESTAMP_INSTANT => DataType::Datetime(TimeUnit::Nanoseconds, Some(TimeZone::UTC)),
+        kind::DATE => DataType::Date,
+        kind::DECIMAL => match (ty.precision, ty.scale) {
+            (Some(precision), Some(scale)) => DataType::Decimal(precision as usize, scale as usize),
+            _ => polars_bail!(nyi = "reading ORC decimals without a precision and scale"),
+        },
+        kind::LIST => DataType::List(Box::new(child(0)?)),
+        kind::MAP => DataType::List(Box::new(DataType::Struct(vec![
+            Field::new("key".into(), child(0)?),
+            Field::new("value".into(), child(1)?),
+        ]))),
+        kind::STRUCT => DataType::Struct(
+            ty.field_names
+                .iter()
+                .enumerate()
+                .map(|(i, name)| Ok(Field::new(name.into(), child(i)?)))
+                .collect::<PolarsResult<_>>()?,
+        ),
+        kind::UNION => polars_bail!(nyi = "reading ORC union types"),
+        k => polars_bail!(ComputeError: "invalid ORC file: unknown type kind {k}"),
+    })
+}
+
+/// Decode the top-level fields at `projection` of the stripe at `stripe_idx`.
+pub fn read_stripe(
+    bytes: &[u8],
+    metadata: &OrcMetadata,
+    stripe_idx: usize,
+    projection: &[usize],
+) -> PolarsResult<DataFrame> {
+    let stripe = &metadata.footer.stripes[stripe_idx];
+    let n_rows = stripe.number_of_rows as usize;
+
+    if projection.is_empty() {
+        return Ok(DataFrame::empty_with_height(n_rows));
+    }
+
+    let invalid = || polars_err!(ComputeError: "invalid ORC file: stripe is out of bounds");
+    let stripe_start = usize::try_from(stripe.offset).map_err(|_| invalid())?;
+    let footer_start = stripe
+        .index_length
+        .checked_add(stripe.data_length)
+        .and_then(|len| usize::try_from(len).ok())
+        .and_then(|len| stripe_start.checked_add(len))
+        .ok_or_else(invalid)?;
+    let footer_end = usize::try_from(stripe.footer_length)
+        .ok()
+        .and_then(|len| footer_start.checked_add(len))
+        .ok_or_else(invalid)?;
+    let footer = bytes.get(footer_start..footer_end).ok_or_else(invalid)?;
+    let footer = metadata
+        .compression
+        .decompress(footer, metadata.block_size)?;
+    let footer = StripeFooter::decode(&footer)?;
+
+    // Streams are stored consecutively, starting with the index streams.
+    let mut streams = PlHashMap::with_capacity(footer.streams.len());
+    let mut offset = stripe_start;
+    for stream in &footer.streams {
+        let end = usize::try_from(stream.length)
+            .ok()
+            .and_then(|len| offset.checked_add(len))
+            .ok_or_else(invalid)?;
+        streams.insert(
+            (stream.column, stream.kind),
+            bytes.get(offset..end).ok_or_else(invalid)?,
+        );
+        offset = end;
+    }
+
+    let reader = StripeReader {
+        metadata,
+        streams,
+        encodings: footer.columns,
+        writer_timezone: footer.writer_timezone,
+    };
+
+    let columns = projection
+        .iter()
+        .map(|&i| {
+            let name = metadata.schema.get_at_index(i).unwrap().0.clone();
+            let id = metadata.column_id(i);
+            Ok(reader.decode(id, name, n_rows)?.into_column())
+        })
+        .collect::<PolarsResult<Vec<_>>>()?;
+
+    DataFrame::new_with_height(n_rows, columns)
+}
+
+struct StripeReader<'a> {
+    metadata: &'a OrcMetadata,
+    streams: PlHashMap<(u32, u64), &'a [u8]>,
+    encodings: Vec<ColumnEncoding>,
+    writer_timezone: Option<String>,
+}
+
+/// The time zone that the `TIMESTAMP` columns of a stripe were written in. Their seconds are
+/// relative to the ORC epoch in that time zone and represent its wall-clock times.
+enum WriterTimezone {
+    Utc,
+    #[cfg(feature = "timezones")]
+    Tz {
+        tz: chrono_tz::Tz,
+        epoch: i64,
+    },
+}
+
+impl WriterTimezone {
+    fn new(name: Option<&str>) -> PolarsResult<Self> {
+        match name {
+            None | Some("UTC" | "GMT" | "Etc/UTC" | "Etc/GMT" | "Z") => Ok(Self::Utc),
+            #[cfg(feature = "timezones")]
+            Some(name) => {
+                use chrono::TimeZone;
+
+                let tz = name.parse::<chrono_tz::Tz>().map_err(
+                    |_| polars_err!(ComputeError: "invalid ORC file: unknown writer time zone '{name}'"),
+                )?;
+                let epoch = tz
+                    .with_ymd_and_hms(2015, 1, 1, 0, 0, 0)
+                    .earliest()
+                    .map_or(ORC_EPOCH_SECONDS, |dt| dt.timestamp());
+                Ok(Self::Tz { tz, epoch })
+            },
+            #[cfg(not(feature = "timezones"))]
+            Some(name) => polars_bail!(
+                ComputeError: "reading ORC timestamps written in time zone '{name}' requires the 'timezones' feature"
+            ),
+        }
+    }
+
+    /// The seconds since the unix epoch of the instant `seconds` after the ORC epoch.
+    fn instant(&self, seconds: i64) -> i64 {
+        match self {
+            Self::Utc => seconds + ORC_EPOCH_SECONDS,
+            #[cfg(feature = "timezones")]
+            Self::Tz { epoch, .. } => seconds + epoch,
+        }
+    }
+
+    /// The wall-clock time of the instant `utc_seconds` in seconds since the unix epoch.
+    fn wall_clock(&self, utc_seconds: i64) -> i64 {
+        match self {
+            Self::Utc => utc_seconds,
+            #[cfg(feature = "timezones")]
+            Self::Tz { tz, .. } => {
+                use chrono::{Offset, TimeZone};
+
+                let offset = chrono::DateTime::from_timestamp(utc_seconds, 0).map_or(0, |dt| {
+                    tz.offset_from_utc_datetime(&dt.naive_utc())
+                        .fix()
+                        .local_minus_utc() as i64
+                });
+                utc_seconds + offset
+            },
+        }
+    }
+}
+
+impl<'a> StripeReader<'a> {
+    fn stream(&self, column: u32, kind: u64) -> PolarsResult<Option<Cow<'a, [u8]>>> {
+        self.streams
+            .get(&(column, kind))
+            .map(|bytes| {
+                self.metadata
+                    .compression
+                    .decompress(bytes, self.metadata.block_size)
+            })
+            .transpose()
+    }
+
+    fn required_stream(&self, column: u32, kind: u64) -> PolarsResult<Cow<'a, [u8]>> {
+        self.stream(column, kind)?.ok_or_else(
+            || polars_err!(ComputeError: "invalid ORC file: missing stream for column {column}"),
+        )
+    }
+
+    fn encoding(&self, column: u32) -> ColumnEncoding {
+        self.encodings
+            .get(column as usize)
+            .copied()
+            .unwrap_or_default()
+    }
+
+    fn ints(&self, column: u32, kind: u64, n: usize, signed: bool) -> PolarsResult<Vec<i64>> {
+        if n == 0 {
+            return Ok(vec![]);
+        }
+        let v2 = matches!(
+            self.encoding(column).kind,
+            encoding_kind::DIRECT_V2 | encoding_kind::DICTIONARY_V2
+        );
+        rle::decode_ints(&self.required_stream(column, kind)?, n, signed, v2)
+    }
+
+    fn lengths(&self, column: u32, n: usize) -> PolarsResult<Vec<usize>> {
+        let lengths = self.ints(column, stream_kind::LENGTH, n, false)?;
+        lengths
+            .into_iter()
+            .map(|l| {
+                usize::try_from(l).map_err(|_| polars_err!(ComputeError: "invalid ORC length"))
+            })
+            .collect()
+    }
+
+    /// Decode `n` rows of the column with id `column`.
+    fn decode(&self, column: u32, name: PlSmallStr, n: usize) -> PolarsResult<Series> {
+        let ty = get_type(&self.metadata.footer.types, column)?;
+
+        let validity = match self.stream(column, stream_kind::PRESENT)? {
+            Some(present) => Some(Bitmap::from_iter(rle::decode_booleans(&present, n)?)),
+            None => None,
+        };
+        let n_values = validity.as_ref().map_or(n, |v| v.set_bits());
+        let validity = validity.filter(|v| v.unset_bits() > 0);
+
+        let data = || self.required_stream(column, stream_kind::DATA);
+
+        let series = match ty.kind {
+            kind::BOOLEAN => {
+                let values = if n_values == 0 {
+                    vec![]
+                } else {
+                    rle::decode_booleans(&data()?, n_values)?
+                };
+                let values = Bitmap::from_iter(expand(values, validity.as_ref()));
+                BooleanChunked::with_chunk(
+                    name,
+                    BooleanArray::new(ArrowDataType::Boolean, values, validity),
+                )
+                .into_series()
+            },
+            kind::BYTE => {
+                let values = if n_values == 0 {
+                    vec![]
+                } else {
+                    rle::decode_bytes(&data()?, n_values)?
+                };
+                let values = values.into_iter().map(|v| v as i8).collect();
+                Int8Chunked::from_vec_validity(name, expand(values, validity.as_ref()), validity)
+                    .into_series()
+            },
+            kind::SHORT => {
+                let values = self.ints(column, stream_kind::DATA, n_values, true)?;
+                let values = values.into_iter().map(|v| v as i16).collect();
+                Int16Chunked::from_vec_validity(name, expand(values, validity.as_ref()), validity)
+                    .into_series()
+            },
+            kind::INT | kind::DATE => {
+                let values = self.ints(column, stream_kind::DATA, n_values, true)?;
+                let values = values.into_iter().map(|v| v as i32).collect();
+                let ca = Int32Chunked::from_vec_validity(
+                    name,
+                    expand(values, validity.as_ref()),
+                    validity,
+                );
+                if ty.kind == kind::DATE {
+                    ca.into_date().into_series()
+                } else {
+                    ca.into_series()
+                }
+            },
+            kind::LONG => {
+                let values = self.ints(column, stream_kind::DATA, n_values, true)?;
+                Int64Chunked::from_vec_validity(name, expand(values, validity.as_ref()), validity)
+                    .into_series()
+            },
+            kind::FLOAT => {
+                let values = if n_values == 0 {
+                    vec![]
+                } else {
+                    read_le::<4>(&data()?, n_values)?
+                        .map(f32::from_le_bytes)
+                        .collect()
+                };
+                Float32Chunked::from_vec_validity(name, expand(values, validity.as_ref()), validity)
+                    .into_series()
+            },
+            kind::DOUBLE => {
+                let values = if n_values == 0 {
+                    vec![]
+                } else {
+                    read_le::<8>(&data()?, n_values)?
+                        .map(f64::from_le_bytes)
+                        .collect()
+                };
+                Float64Chunked::from_vec_validity(name, expand(values, validity.as_ref()), validity)
+                    .into_series()
+            },
+            kind::STRING | kind::VARCHAR | kind::CHAR | kind::BINARY => {
+                self.decode_binary(column, ty.kind, name, n_values, validity)?
+            },
+            kind::TIMESTAMP | kind::TIMESTAMP_INSTANT => {
+                let seconds = self.ints(column, stream_kind::DATA, n_values, true)?;
+                let nanos = self.ints(column, stream_kind::SECONDARY, n_values, false)?;
+                // Instants are always relative to the ORC epoch in UTC.
+                let writer_timezone = if ty.kind == kind::TIMESTAMP {
+                    WriterTimezone::new(self.writer_timezone.as_deref())?
+                } else {
+                    WriterTimezone::Utc
+                };
+
+                let values = seconds
+                    .into_iter()
+                    .zip(nanos)
+                    .map(|(seconds, nanos)| {
+                        let nanos = match nanos & 7 {
+                            0 => nanos >> 3,
+                            z => (nanos >> 3) * 10i64.pow(z as u32 + 1),
+                        };
+                        // Seconds are truncated towards zero by writers.
+                        let mut seconds = writer_timezone.instant(seconds);
+                        if seconds < 0 && nanos > 999_999 {
+                            seconds -= 1;
+                        }
+                        writer_timezone
+                            .wall_clock(seconds)
+                            .checked_mul(1_000_000_000)
+                            .and_then(|v| v.checked_add(nanos))
+                            .ok_or_else(|| {
+                                polars_err!(ComputeError: "ORC timestamp is out of range for nanoseconds")
+                            })
+                    })
+                    .collect::<PolarsResult<Vec<_>>>()?;
+
+                let tz = (ty.kind == kind::TIMESTAMP_INSTANT).then_some(TimeZone::UTC);
+                Int64Chunked::from_vec_validity(name, expand(values, validity.as_ref()), validity)
+                    .into_datetime(TimeUnit::Nanoseconds, tz)
+                    .into_series()
+            },
+            kind::DECIMAL => {
+                let DataType::Decimal(precision, scale) =
+                    to_dtype(&self.metadata.footer.types, column)?
+                else {
+                    unreachable!()
+                };
+
+                let values = if n_values == 0 {
+                    vec![]
+                } else {
+                    rle::decode_varint_i128(&data()?, n_values)?
+                };
+                let scales = self.ints(column, stream_kind::SECONDARY, n_values, true)?;
+                let values = values
+                    .into_iter()
+                    .zip(scales)
+                    .map(|(v, value_scale)| {
+                        let diff = scale as i64 - value_scale;
+                        let factor = 10i128.checked_pow(diff.unsigned_abs() as u32);
+                        match factor {
+                            Some(f) if diff >= 0 => v.checked_mul(f),
+                            Some(f) => Some(v / f),
+                            None => None,
+                        }
+                        .ok_or_else(|| polars_err!(ComputeError: "ORC decimal is out of range"))
+                    })
+                    .collect::<PolarsResult<Vec<_>>>()?;
+
+                Int128Chunked::from_vec_validity(name, expand(values, validity.as_ref()), validity)
+                    .into_decimal_unchecked(precision, scale)
+                    .into_series()
+            },
+            kind::LIST | kind::MAP => {
+                let lengths = self.lengths(column, n_values)?;
+                let n_child = lengths.iter().sum();
+
+                let child = if ty.kind == kind::LIST {
+                    self.decode(ty.subtypes[0], PlSmallStr::from_static("item"), n_child)?
+                } else {
+                    let key =
+                        self.decode(ty.subtypes[0], PlSmallStr::from_static("key"), n_child)?;
+                    let value =
+                        self.decode(ty.subtypes[1], PlSmallStr::from_static("value"), n_child)?;
+                    StructChunked::from_series(
+                        PlSmallStr::from_static("entries"),
+                        n_child,
+                        [key, value].iter(),
+                    )?
+                    .into_series()
+                };
+                let child = child.rechunk();
+
+                let offsets = Offsets::<i64>::try_from_lengths(
+                    expand(lengths, validity.as_ref()).into_iter(),
+                )?;
+                let values = child.to_arrow(0, CompatLevel::newest());
+                let array = ListArray::<i64>::new(
+                    ListArray::<i64>::default_datatype(values.dtype().clone()),
+                    offsets.into(),
+                    values,
+                    validity,
+                );
+
+                // SAFETY: The array is created from the physical representation of `child`.
+                unsafe {
+                    Series::from_chunks_and_dtype_unchecked(
+                        name,
+                        vec![array.boxed()],
+                        &DataType::List(Box::new(child.dtype().clone())),
+                    )
+                }
+            },
+            kind::STRUCT => {
+                let mut fields = ty
+                    .field_names
+                    .iter()
+                    .zip(&ty.subtypes)
+                    .map(|(field_name, &id)| self.decode(id, field_name.into(), n_values))
+                    .collect::<PolarsResult<Vec<_>>>()?;
+
+                // Values of the fields are only stored for the valid rows of the struct.
+                if let Some(validity) = &validity {
+                    let mut idx: IdxSize = 0;
+                    let gather = IdxCa::from_iter_options(
+                        PlSmallStr::EMPTY,
+                        validity.iter().map(|valid| {
+                            valid.then(|| {
+                                idx += 1;
+                                idx - 1
+                            })
+                        }),
+                    );
+                    for field in fields.iter_mut() {
+                        *field = field.take(&gather)?;
+                    }
+                }
+
+                StructChunked::from_series(name, n, fields.iter())?
+                    .with_outer_validity(validity)
+                    .into_series()
+            },
+            kind::UNION => polars_bail!(nyi = "reading ORC union types"),
+            k => polars_bail!(ComputeError: "invalid ORC file: unknown type kind {k}"),
+        };
+
+        debug_assert_eq!(series.len(), n);
+        Ok(series)
+    }
+
+    fn decode_binary(
+        &self,
+        column: u32,
+        type_kind: u64,
+        name: PlSmallStr,
+        n_values: usize,
+        validity: Option<Bitmap>,
+    ) -> PolarsResult<Series> {
+        let encoding = self.encoding(column);
+        let is_dictionary = matches!(
+            encoding.kind,
+            encoding_kind::DICTIONARY | encoding_kind::DICTIONARY_V2
+        );
+
+        // Slices of the (dictionary) values.
+        let (data, lengths, indices) = if is_dictionary {
+            let dictionary_size = encoding.dictionary_size as usize;
+            let data = if dictionary_size == 0 {
+                Cow::Borrowed(&[][..])
+            } else {
+                self.stream(column, stream_kind::DICTIONARY_DATA)?
+                    .unwrap_or_default()
+            };
+            let lengths = self.lengths(column, dictionary_size)?;
+            let indices = self.ints(column, stream_kind::DATA, n_values, false)?;
+            (data, lengths, Some(indices))
+        } else {
+            let data = if n_values == 0 {
+                Cow::Borrowed(&[][..])
+            } else {
+                self.stream(column, stream_kind::DATA)?.unwrap_or_default()
+            };
+            (data, self.lengths(column, n_values)?, None)
+        };
+
+        let mut slices = Vec::with_capacity(lengths.len());
+        let mut offset = 0usize;
+        for length in lengths {
+            let slice = offset
+                .checked_add(length)
+                .and_then(|end| data.get(offset..end))
+                .ok_or_else(
+                    || polars_err!(ComputeError: "invalid ORC file: string data is out of bounds"),
+                )?;
+            slices.push(slice);
+            offset += length;
+        }
+
+        let values: Vec<&[u8]> = match indices {
+            None => slices,
+            Some(indices) => indices
+                .into_iter()
+                .map(|i| {
+                    slices.get(i as usize).copied().ok_or_else(
+                        || polars_err!(ComputeError: "invalid ORC file: dictionary index is out of bounds"),
+                    )
+                })
+                .collect::<PolarsResult<_>>()?,
+        };
+        let values = expand(values, validity.as_ref());
+
+        let mut array = MutableBinaryViewArray::<[u8]>::with_capacity(values.len());
+        for v in values {
+            array.push_value(v);
+        }
+        let array = array.freeze().with_validity(validity);
+
+        Ok(if type_kind == kind::BINARY {
+            BinaryChunked::with_chunk(name, array).into_series()
+        } else {
+            let array: Utf8ViewArray = array
+                .to_utf8view()
+                .map_err(|_| polars_err!(ComputeError: "invalid utf-8 in ORC string column"))?;
+            StringChunked::with_chunk(name, array).into_series()
+        })
+    }
+}
+
+/// Spread `values` over the valid positions of `validity`.
+fn expand<T: Copy + Default>(values: Vec<T>, validity: Option<&Bitmap>) -> Vec<T> {
+    match validity {
+        None => values,
+        Some(validity) => {
+            let mut values = values.into_iter();
+            validity
+                .iter()
+                .map(|valid| {
+                    if valid {
+                        values.next().unwrap_or_default()
+                    } else {
+                        T::default()
+                    }
+                })
+                .collect()
+        },
+    }
+}
+
+fn read_le<const N: usize>(
+    data: &[u8],
+    n: usize,
+) -> PolarsResult<impl Iterator<Item = [u8; N]> + '_> {
+    let data = data
+        .get(..n * N)
+        .ok_or_else(|| polars_err!(ComputeError: "invalid ORC file: data stream is too short"))?;
+    Ok(data
+        .chunks_exact(N)
+        .map(|chunk| <[u8; N]>::try_from(chunk).unwrap()))
+}
@@ -0,0 +1,548 @@
+//! Run-length encodings of ORC streams.
+//!
+//! See <https://orc.apache.org/specification/ORCv1/> for the description of the byte, boolean and
+//! integer (version 1 and 2) run-length encodings.
+
+use polars_error::{PolarsResult, polars_bail, polars_ensure};
+
+use super::proto::{read_varint, unzigzag, write_varint, zigzag};
+
+fn truncated() -> polars_error::PolarsError {
+    polars_error::polars_err!(ComputeError: "unexpected end of ORC run-length encoded stream")
+}
+
+fn take_byte(bytes: &mut &[u8]) -> PolarsResult<u8> {
+    let (&b, rest) = bytes.split_first().ok_or_else(truncated)?;
+    *bytes = rest;
+    Ok(b)
+}
+
+/// Decode `n` bytes of the byte run-length encoding.
+pub(super) fn decode_bytes(mut data: &[u8], n: usize) -> PolarsResult<Vec<u8>> {
+    let mut out = Vec::with_capacity(n);
+    while out.len() < n {
+        let control = take_byte(&mut data)? as i8;
+        if control >= 0 {
+            let value = take_byte(&mut data)?;
+            let len = control as usize + 3;
+            out.extend(std::iter::repeat_n(value, len));
+        } else {
+            let len = -(control as isize) as usize;
+            let (literals, rest) = data.split_at_checked(len).ok_or_else(truncated)?;
+            out.extend_from_slice(literals);
+            data = rest;
+        }
+    }
+    out.truncate(n);
+    Ok(out)
+}
+
+/// Decode `n` booleans, which are stored as bits (most significant first) in a byte run-length
+/// encoded stream.
+pub(super) fn decode_booleans(data: &[u8], n: usize) -> PolarsResult<Vec<bool>> {
+    let bytes = decode_bytes(data, n.div_ceil(8))?;
+    Ok((0..n)
+        .map(|i| bytes[i / 8] & (0x80 >> (i % 8)) != 0)
+        .collect())
+}
+
+pub(super) fn encode_bytes(values: &[u8], out: &mut Vec<u8>) {
+    let mut i = 0;
+    let mut literal_start = 0;
+
+    let flush_literals = |out: &mut Vec<u8>, literals: &[u8]| {
+        for chunk in literals.chunks(128) {
+            out.push((chunk.len() as u8).wrapping_neg());
+            out.extend_from_slice(chunk);
+        }
+    };
+
+    while i < values.len() {
+        let run = values[i..]
+            .iter()
+            .take(130)
+            .take_while(|&&v| v == values[i])
+            .count();
+
+        if run >= 3 {
+            flush_literals(out, &values[literal_start..i]);
+            out.push((run - 3) as u8);
+            out.push(values[i]);
+            i += run;
+            literal_start = i;
+        } else {
+            i += run;
+        }
+    }
+    flush_literals(out, &values[literal_start..]);
+}
+
+pub(super) fn encode_booleans(values: impl Iterator<Item = bool>, out: &mut Vec<u8>) {
+    let mut bytes = vec![];
+    for (i, v) in values.enumerate() {
+        if i % 8 == 0 {
+            bytes.push(0);
+        }
+        if v {
+            *bytes.last_mut().unwrap() |= 0x80 >> (i % 8);
+        }
+    }
+    encode_bytes(&bytes, out);
+}
+
+/// Decode `n` integers. Unsigned values are returned as their bit pattern.
+pub(super) fn decode_ints(data: &[u8], n: usize, signed: bool, v2: bool) -> PolarsResult<Vec<i64>> {
+    let mut out = Vec::with_capacity(n);
+    let mut data = data;
+    while out.len() < n {
+        if v2 {
+            decode_v2_run(&mut data, signed, &mut out)?;
+        } else {
+            decode_v1_run(&mut data, signed, &mut out)?;
+        }
+    }
+    out.truncate(n);
+    Ok(out)
+}
+
+fn read_int_varint(data: &mut &[u8], signed: bool) -> PolarsResult<i64> {
+    let v = read_varint(data)?;
+    Ok(if signed { unzigzag(v) } else { v as i64 })
+}
+
+fn decode_v1_run(data: &mut &[u8], signed: bool, out: &mut Vec<i64>) -> PolarsResult<()> {
+    let control = take_byte(data)? as i8;
+    if control >= 0 {
+        let len = control as i64 + 3;
+        let delta = take_byte(data)? as i8 as i64;
+        let base = read_int_varint(data, signed)?;
+        out.extend((0..len).map(|i| base.wrapping_add(i * delta)));
+    } else {
+        for _ in 0..-(control as i64) {
+            out.push(read_int_varint(data, signed)?);
+        }
+    }
+    Ok(())
+}
+
+/// Bit width of the 5-bit encoded width of the version 2 run-length encoding.
+fn decode_width(code: u8) -> u32 {
+    match code {
+        0..=23 => code as u32 + 1,
+        24 => 26,
+        25 => 28,
+        26 => 30,
+        27 => 32,
+        28 => 40,
+        29 => 48,
+        30 => 56,
+        _ => 64,
+    }
+}
+
+fn encode_width(width: u32) -> u8 {
+    match width {
+        0..=24 => width.max(1) as u8 - 1,
+        25..=26 => 24,
+        27..=28 => 25,
+        29..=30 => 26,
+        31..=32 => 27,
+        33..=40 => 28,
+        41..=48 => 29,
+        49..=56 => 30,
+        _ => 31,
+    }
+}
+
+fn closest_fixed_width(width: u32) -> u32 {
+    decode_width(encode_width(width))
+}
+
+/// Read `n` big-endian, bit-packed values of `width` bits.
+fn unpack(data: &mut &[u8], width: u32, n: usize, out: &mut Vec<u64>) -> PolarsResult<()> {
+    let n_bytes = (n * width as usize).div_ceil(8);
+    let (packed, rest) = data.split_at_checked(n_bytes).ok_or_else(truncated)?;
+    *data = rest;
+
+    if width == 0 {
+        out.extend(std::iter::repeat_n(0, n));
+        return Ok(());
+    }
+
+    let mut bit_pos = 0usize;
+    for _ in 0..n {
+        let mut v = 0u64;
+        let mut remaining = width;
+        while remaining > 0 {
+            let byte = packed[bit_pos / 8];
+            let available = 8 - (bit_pos % 8) as u32;
+            let take = available.min(remaining);
+            let bits = (byte >> (available - take)) & ((1u16 << take) - 1) as u8;
+            v = (v << take) | bits as u64;
+            remaining -= take;
+            bit_pos += take as usize;
+        }
+        out.push(v);
+    }
+    Ok(())
+}
+
+fn pack(values: impl Iterator<Item = u64>, width: u32, out: &mut Vec<u8>) {
+    let mut acc = 0u16;
+    let mut n_bits = 0u32;
+    for v in values {
+        let mut remaining = width;
+        while remaining > 0 {
+            let take = (8 - n_bits).min(remaining);
+            let bits = ((v >> (remaining - take)) & ((1u64 << take) - 1)) as u16;
+            acc = (acc << take) | bits;
+            n_bits += take;
+            remaining -= take;
+            if n_bits == 8 {
+                out.push(acc as u8);
+                acc = 0;
+                n_bits = 0;
+            }
+        }
+    }
+    if n_bits > 0 {
+        out.push((acc << (8 - n_bits)) as u8);
+    }
+}
+
+fn read_be(data: &mut &[u8], n_bytes: usize) -> PolarsResult<u64> {
+    let (bytes, rest) = data.split_at_checked(n_bytes).ok_or_else(truncated)?;
+    *data = rest;
+    Ok(bytes.iter().fold(0u64, |acc, &b| (acc << 8) | b as u64))
+}
+
+fn decode_v2_run(data: &mut &[u8], signed: bool, out: &mut Vec<i64>) -> PolarsResult<()> {
+    let header = take_byte(data)?;
+    let decode_value = |v: u64| if signed { unzigzag(v) } else { v as i64 };
+
+    match header >> 6 {
+        // Short repeat
+        0 => {
+            let width = ((header >> 3) & 7) as usize + 1;
+            let len = (header & 7) as usize + 3;
+            let value = decode_value(read_be(data, width)?);
+            out.extend(std::iter::repeat_n(value, len));
+        },
+        // Direct
+        1 => {
+            let width = decode_width((header >> 1) & 0x1f);
+            let len = ((((header & 1) as usize) << 8) | take_byte(data)? as usize) + 1;
+            let mut values = Vec::with_capacity(len);
+            unpack(data, width, len, &mut values)?;
+            out.extend(values.into_iter().map(decode_value));
+        },
+        // Patched base
+        2 => {
+            let width = decode_width((header >> 1) & 0x1f);
+            let len = ((((header & 1) as usize) << 8) | take_byte(data)? as usize) + 1;
+            let third = take_byte(data)?;
+            let fourth = take_byte(data)?;
+            let base_width = ((third >> 5) & 7) as usize + 1;
+            let patch_width = decode_width(third & 0x1f);
+            let gap_width = ((fourth >> 5) & 7) as u32 + 1;
+            let n_patches = (fourth & 0x1f) as usize;
+            polars_ensure!(
+                patch_width + gap_width <= 64,
+                ComputeError: "invalid ORC patched base run"
+            );
+
+            // The base value is stored in sign-magnitude representation.
+            let base = read_be(data, base_width)?;
+            let sign_bit = 1u64 << (base_width * 8 - 1);
+            let base = if base & sign_bit != 0 {
+                -((base & !sign_bit) as i64)
+            } else {
+                base as i64
+            };
+
+            let mut values = Vec::with_capacity(len);
+            unpack(data, width, len, &mut values)?;
+
+            let mut patches = Vec::with_capacity(n_patches);
+            unpack(
+                data,
+                closest_fixed_width(patch_width + gap_width),
+                n_patches,
+                &mut patches,
+            )?;
+
+            let mut idx = 0usize;
+            for patch in patches {
+                idx += patch.checked_shr(patch_width).unwrap_or(0) as usize;
+                let patch = patch & (u64::MAX >> (64 - patch_width));
+                if patch != 0 {
+                    polars_ensure!(idx < len, ComputeError: "invalid ORC patched base run");
+                    values[idx] |= patch.checked_shl(width).unwrap_or(0);
+                }
+            }
+
+            out.extend(values.into_iter().map(|v| base.wrapping_add(v as i64)));
+        },
+        // Delta
+        _ => {
+            let code = (header >> 1) & 0x1f;
+            let width = if code == 0 { 0 } else { decode_width(code) };
+            let len = ((((header & 1) as usize) << 8) | take_byte(data)? as usize) + 1;
+            let base = read_int_varint(data, signed)?;
+            let delta_base = unzigzag(read_varint(data)?);
+
+            out.push(base);
+            if len == 1 {
+                return Ok(());
+            }
+            let mut prev = base.wrapping_add(delta_base);
+            out.push(prev);
+
+            if width == 0 {
+                for _ in 2..len {
+                    prev = prev.wrapping_add(delta_base);
+                    out.push(prev);
+                }
+            } else {
+                let mut deltas = Vec::with_capacity(len - 2);
+                unpack(data, width, len - 2, &mut deltas)?;
+                for delta in deltas {
+                    prev = if delta_base < 0 {
+                        prev.wrapping_sub(delta as i64)
+                    } else {
+                        prev.wrapping_add(delta as i64)
+                    };
+                    out.push(prev);
+                }
+            }
+        },
+    }
+    Ok(())
+}
+
+const MAX_RUN: usize = 512;
+
+/// Encode integers with the version 2 run-length encoding. Unsigned values must not be negative.
+pub(super) fn encode_ints(values: &[i64], signed: bool, out: &mut Vec<u8>) {
+    let mut i = 0;
+    while i < values.len() {
+        let (run, delta) = fixed_delta_run(&values[i..]);
+
+        if run >= 3 {
+            if delta == 0 && run <= 10 {
+                encode_short_repeat(values[i], run, signed, out);
+            } else {
+                encode_fixed_delta(values[i], delta, run, signed, out);
+            }
+            i += run;
+            continue;
+        }
+
+        // Gather literals until the next run.
+        let start = i;
+        while i < values.len() && i - start < MAX_RUN && fixed_delta_run(&values[i..]).0 < 3 {
+            i += 1;
+        }
+        encode_direct(&values[start..i], signed, out);
+    }
+}
+
+/// Length of the run with a constant difference between consecutive values at the start of
+/// `values`.
+fn fixed_delta_run(values: &[i64]) -> (usize, i64) {
+    if values.len() < 3 {
+        return (values.len().min(1), 0);
+    }
+    let Some(delta) = values[1].checked_sub(values[0]) else {
+        return (1, 0);
+    };
+    let run = 1 + values
+        .windows(2)
+        .take(MAX_RUN - 1)
+        .take_while(|w| w[1].checked_sub(w[0]) == Some(delta))
+        .count();
+    (run, delta)
+}
+
+fn encode_value(v: i64, signed: bool) -> u64 {
+    if signed { zigzag(v) } else { v as u64 }
+}
+
+fn encode_short_repeat(value: i64, run: usize, signed: bool, out: &mut Vec<u8>) {
+    let value = encode_value(value, signed);
+    let n_bytes = (64 - value.leading_zeros()).div_ceil(8).max(1) as usize;
+    out.push((((n_bytes - 1) as u8) << 3) | (run - 3) as u8);
+    out.extend_from_slice(&value.to_be_bytes()[8 - n_bytes..]);
+}
+
+fn encode_fixed_delta(base: i64, delta: i64, run: usize, signed: bool, out: &mut Vec<u8>) {
+    let len = run - 1;
+    out.push(0xc0 | ((len >> 8) as u8));
+    out.push(len as u8);
+    write_varint(out, encode_value(base, signed));
+    write_varint(out, zigzag(delta));
+}
+
+fn encode_direct(values: &[i64], signed: bool, out: &mut Vec<u8>) {
+    let max = values
+        .iter()
+        .map(|&v| encode_value(v, signed))
+        .fold(0, |acc, v| acc | v);
+    let width = closest_fixed_width(64 - max.leading_zeros());
+    let len = values.len() - 1;
+    out.push(0x40 | (encode_width(width) << 1) | ((len >> 8) as u8));
+    out.push(len as u8);
+    pack(values.iter().map(|&v| encode_value(v, signed)), width, out);
+}
+
+/// Decode values that are stored as unbounded base-128 varints, i.e. the data of decimals.
+pub(super) fn decode_varint_i128(mut data: &[u8], n: usize) -> PolarsResult<Vec<i128>> {
+    let mut out = Vec::with_capacity(n);
+    for _ in 0..n {
+        let mut v = 0u128;
+        let mut shift = 0;
+        loop {
+            let b = take_byte(&mut data)?;
+            if shift >= 128 {
+                polars_bail!(ComputeError: "ORC decimal value is too large");
+            }
+            v |= ((b & 0x7f) as u128) << shift;
+            shift += 7;
+            if b & 0x80 == 0 {
+                break;
+            }
+        }
+        out.push(((v >> 1) as i128) ^ -((v & 1) as i128));
+    }
+    Ok(out)
+}
+
+pub(super) fn encode_varint_i128(v: i128, out: &mut Vec<u8>) {
+    let mut v = ((v << 1) ^ (v >> 127)) as u128;
+    while v >= 0x80 {
+        out.push((v as u8) | 0x80);
+        v >>= 7;
+    }
+    out.push(v as u8);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decode_spec_examples() {
+        // Examples of the ORC specification.
+        assert_eq!(
+            decode_ints(&[0x0a, 0x27, 0x10], 5, false, true).unwrap(),
+            [10000; 5]
+        );
+        assert_eq!(
+            decode_ints(
+                &[0x5e, 0x03, 0x5c, 0xa1, 0xab, 0x1e, 0xde, 0xad, 0xbe, 0xef],
+                4,
+                false,
+                true
+            )
+            .unwrap(),
+            [23713, 43806, 57005, 48879]
+        );
+        assert_eq!(
+            decode_ints(
+                &[0xc6, 0x09, 0x02, 0x02, 0x22, 0x42, 0x42, 0x46],
+                10,
+                false,
+                true
+            )
+            .unwrap(),
+            [2, 3, 5, 7, 11, 13, 17, 19, 23, 29]
+        );
+        assert_eq!(
+            decode_ints(
+                &[
+                    0x8e, 0x13, 0x2b, 0x21, 0x07, 0xd0, 0x1e, 0x00, 0x14, 0x70, 0x28, 0x32, 0x3c,
+                    0x46, 0x50, 0x5a, 0x64, 0x6e, 0x78, 0x82, 0x8c, 0x96, 0xa0, 0xaa, 0xb4, 0xbe,
+                    0xfc, 0xe8
+                ],
+                20,
+                false,
+                true
+            )
+            .unwrap(),
+            [
+                2030, 2000, 2020, 1000000, 2040, 2050, 2060, 2070, 2080, 2090, 2100, 2110, 2120,
+                2130, 2140, 2150, 2160, 2170, 2180, 2190
+            ]
+        );
+        assert_eq!(
+            decode_ints(&[0x61, 0x00, 0x07], 100, false, false).unwrap(),
+            [7; 100]
+        );
+        assert_eq!(
+            decode_ints(&[0xfb, 0x02, 0x03, 0x04, 0x07, 0x0b], 5, false, false).unwrap(),
+            [2, 3, 4, 7, 11]
+        );
+        assert_eq!(decode_bytes(&[0x61, 0x00], 100).unwrap(), [0; 100]);
+        assert_eq!(decode_bytes(&[0xfe, 0x44, 0x45], 2).unwrap(), [0x44, 0x45]);
+    }
+
+    #[test]
+    fn test_decode_invalid_patch_width() {
+        // A patched base run whose patch width of 64 leaves no room for the gap.
+        let data = [
+            0x80, 0x00, 0x1f, 0x01, 0x00, 0x00, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ];
+        assert!(decode_ints(&data, 1, false, true).is_err());
+    }
+
+    #[test]
+    fn test_int_roundtrip() {
+        let values: Vec<i64> = (0..2000)
+            .map(|i: i64| match i % 7 {
+                0 => i * 1_000_003,
+                1 => -i,
+                _ => i64::MAX - i,
+            })
+            .chain([5, 5, 5, 5])
+            .chain((0..1000).map(|i| i * 3))
+            .chain([i64::MIN, i64::MAX, 0])
+            .collect();
+
+        let mut out = vec![];
+        encode_ints(&values, true, &mut out);
+        assert_eq!(decode_ints(&out, values.len(), true, true).unwrap(), values);
+
+        let values: Vec<i64> = (0..1000).map(|i| (i * 37) % 101).collect();
+        let mut out = vec![];
+        encode_ints(&values, false, &mut out);
+        assert_eq!(
+            decode_ints(&out, values.len(), false, true).unwrap(),
+            values
+        );
+    }
+
+    #[test]
+    fn test_byte_roundtrip() {
+        let values: Vec<u8> = (0..1000u32)
+            .map(|i| if i % 300 < 200 { 1 } else { i as u8 })
+            .collect();
+        let mut out = vec![];
+        encode_bytes(&values, &mut out);
+        assert_eq!(decode_bytes(&out, values.len()).unwrap(), values);
+
+        let bools: Vec<bool> = (0..1001).map(|i| i % 3 == 0).collect();
+        let mut out = vec![];
+        encode_booleans(bools.iter().copied(), &mut out);
+        assert_eq!(decode_booleans(&out, bools.len()).unwrap(), bools);
+    }
+
+    #[test]
+    fn test_varint_i128_roundtrip() {
+        let values = [0, -1, 1, i128::MAX / 3, -(10i128.pow(37))];
+        let mut out = vec![];
+        for v in values {
+            encode_varint_i128(v, &mut out);
+        }
+        assert_eq!(decode_varint_i128(&out, values.len()).unwrap(), values);
+    }
+}
@@ -0,0 +1,724 @@
+use std::io::Write;
+
+use polars_core::POOL;
+use polars_core::prelude::*;
+use polars_error::{polars_bail, polars_ensure};
+use polars_utils::pl_str::PlSmallStr;
+use rayon::prelude::*;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use super::compression::{DEFAULT_BLOCK_SIZE, OrcCompression};
+use super::proto::{
+    ColumnEncoding, ColumnStatistics, Footer, Metadata, PostScript, Stream, StripeFooter,
+    StripeInformation, Type, TypedStatistics, encoding_kind, kind, stream_kind,
+};
+use super::read::ORC_EPOCH_SECONDS;
+use super::rle;
+use crate::prelude::*;
+
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub struct OrcWriterOptions {
+    /// Compression of the streams.
+    pub compression: OrcCompression,
+    /// Number of rows per stripe. If `None` defaults to 2^18.
+    pub rows_per_stripe: Option<usize>,
+}
+
+const DEFAULT_ROWS_PER_STRIPE: usize = 1 << 18;
+
+impl OrcWriterOptions {
+    pub fn rows_per_stripe(&self) -> usize {
+        self.rows_per_stripe
+            .unwrap_or(DEFAULT_ROWS_PER_STRIPE)
+            .max(1)
+    }
+
+    pub fn to_writer<W: Write>(&self, writer: W) -> OrcWriter<W> {
+        OrcWriter::new(writer)
+            .with_compression(self.compression)
+            .with_rows_per_stripe(self.rows_per_stripe)
+    }
+}
+
+/// Write a DataFrame to the ORC format.
+///
+/// # Example
+///
+/// ```
+/// use polars_core::prelude::*;
+/// use polars_io::orc::OrcWriter;
+/// use polars_io::SerWriter;
+/// use std::fs::File;
+///
+/// fn example(df: &mut DataFrame) -> PolarsResult<()> {
+///     let mut file = File::create("file.orc").expect("could not create file");
+///     OrcWriter::new(&mut file).finish(df)
+/// }
+/// ```
+#[must_use]
+pub struct OrcWriter<W> {
+    writer: W,
+    options: OrcWriterOptions,
+}
+
+impl<W: Write> OrcWriter<W> {
+    /// Set the compression used. Defaults to `Zstd`.
+    pub fn with_compression(mut self, compression: OrcCompression) -> Self {
+        self.options.compression = compression;
+        self
+    }
+
+    /// Set the number of rows per stripe.
+    pub fn with_rows_per_stripe(mut self, rows_per_stripe: Option<usize>) -> Self {
+        self.options.rows_per_stripe = rows_per_stripe;
+        self
+    }
+
+    pub fn batched(self, schema: &Schema) -> PolarsResult<BatchedWriter<W>> {
+        let encoder = OrcStripeEncoder::try_new(schema, self.options)?;
+        BatchedWriter::new(self.writer, encoder)
+    }
+}
+
+impl<W> SerWriter<W> for OrcWriter<W>
+where
+    W: Write,
+{
+    fn new(writer: W) -> Self {
+        OrcWriter {
+            writer,
+            options: OrcWriterOptions::default(),
+        }
+    }
+
+    fn finish(&mut self, df: &mut DataFrame) -> PolarsResult<()> {
+        let encoder = OrcStripeEncoder::try_new(df.schema(), self.options)?;
+        let mut batched = BatchedWriter::new(&mut self.writer, encoder)?;
+        batched.write_batch(df)?;
+        batched.finish()?;
+        Ok(())
+    }
+}
+
+/// The encoded streams of one top-level column of a stripe.
+pub struct EncodedOrcColumn {
+    n_rows: usize,
+    /// Data of the ORC columns of the field in the order of their column ids.
+    columns: Vec<EncodedColumnData>,
+}
+
+struct EncodedColumnData {
+    /// Compressed streams.
+    streams: Vec<(u64, Vec<u8>)>,
+    encoding: ColumnEncoding,
+    statistics: ColumnStatistics,
+}
+
+/// Encodes columns of a [`DataFrame`] into the streams of an ORC stripe.
+#[derive(Clone)]
+pub struct OrcStripeEncoder {
+    options: OrcWriterOptions,
+    /// The dtypes the fields are cast to before encoding.
+    orc_dtypes: Vec<DataType>,
+    /// Column id of every top-level field.
+    column_ids: Vec<u32>,
+    types: Vec<Type>,
+}
+
+impl OrcStripeEncoder {
+    pub fn try_new(schema: &Schema, options: OrcWriterOptions) -> PolarsResult<Self> {
+        let orc_dtypes = schema
+            .iter_values()
+            .map(to_orc_dtype)
+            .collect::<PolarsResult<Vec<_>>>()?;
+
+        let mut types = vec![Type {
+            kind: kind::STRUCT,
+            field_names: schema.iter_names().map(|name| name.to_string()).collect(),
+            ..Default::default()
+        }];
+        let column_ids = orc_dtypes
+            .iter()
+            .map(|dtype| push_types(dtype, &mut types))
+            .collect::<Vec<_>>();
+        types[0].subtypes = column_ids.clone();
+
+        Ok(Self {
+            options,
+            orc_dtypes,
+            column_ids,
+            types,
+        })
+    }
+
+    pub fn options(&self) -> &OrcWriterOptions {
+        &self.options
+    }
+
+    /// Encode the top-level column at `field_idx`.
+    pub fn encode_column(
+        &self,
+        field_idx: usize,
+        column: &Column,
+    ) -> PolarsResult<EncodedOrcColumn> {
+        let series = column
+            .as_materialized_series()
+            .strict_cast(&self.orc_dtypes[field_idx])?;
+
+        let mut columns = vec![];
+        encode_series(&series.rechunk(), self.options.compression, &mut columns)?;
+
+        Ok(EncodedOrcColumn {
+            n_rows: column.len(),
+            columns,
+        })
+    }
+
+    fn num_columns(&self) -> usize {
+        self.types.len()
+    }
+}
+
+fn to_orc_dtype(dtype: &DataType) -> PolarsResult<DataType> {
+    use DataType as D;
+    Ok(match dtype {
+        D::Boolean
+        | D::Int8
+        | D::Int16
+        | D::Int32
+        | D::Int64
+        | D::Float32
+        | D::Float64
+        | D::String
+        | D::Binary
+        | D::Date
+        | D::Decimal(_, _) => dtype.clone(),
+        D::UInt8 => D::Int16,
+        D::UInt16 => D::Int32,
+        D::UInt32 | D::UInt64 => D::Int64,
+        D::BinaryOffset => D::Binary,
+        D::Datetime(tu, tz) => D::Datetime(*tu, tz.as_ref().map(|_| TimeZone::UTC)),
+        #[cfg(feature = "dtype-categorical")]
+        D::Categorical(_, _) | D::Enum(_, _) => D::String,
+        #[cfg(feature = "dtype-array")]
+        D::Array(inner, _) => D::List(Box::new(to_orc_dtype(inner)?)),
+        D::List(inner) => D::List(Box::new(to_orc_dtype(inner)?)),
+        D::Struct(fields) => D::Struct(
+            fields
+                .iter()
+                .map(|f| Ok(Field::new(f.name().clone(), to_orc_dtype(f.dtype())?)))
+                .collect::<PolarsResult<_>>()?,
+        ),
+        dt => polars_bail!(nyi = "writing columns of dtype {dt} to ORC"),
+    })
+}
+
+/// Add the types of `dtype` in pre-order and return the column id of `dtype`.
+fn push_types(dtype: &DataType, types: &mut Vec<Type>) -> u32 {
+    let id = types.len() as u32;
+    types.push(Type::default());
+
+    let ty = match dtype {
+        DataType::Boolean => Type {
+            kind: kind::BOOLEAN,
+            ..Default::default()
+        },
+        DataType::Int8 => Type {
+            kind: kind::BYTE,
+            ..Default::default()
+        },
+        DataType::Int16 => Type {
+            kind: kind::SHORT,
+            ..Default::default()
+        },
+        DataType::Int32 => Type {
+            kind: kind::INT,
+            ..Default::default()
+        },
+        DataType::Int64 => Type {
+            kind: kind::LONG,
+            ..Default::default()
+        },
+        DataType::Float32 => Type {
+            kind: kind::FLOAT,
+            ..Default::default()
+        },
+        DataType::Float64 => Type {
+            kind: kind::DOUBLE,
+            ..Default::default()
+        },
+        DataType::String => Type {
+            kind: kind::STRING,
+            ..Default::default()
+        },
+        DataType::Binary => Type {
+            kind: kind::BINARY,
+            ..Default::default()
+        },
+        DataType::Date => Type {
+            kind: kind::DATE,
+            ..Default::default()
+        },
+        DataType::Datetime(_, tz) => Type {
+            kind: if tz.is_some() {
+                kind::TIMESTAMP_INSTANT
+            } else {
+                kind::TIMESTAMP
+            },
+            ..Default::default()
+        },
+        DataType::Decimal(precision, scale) => Type {
+            kind: kind::DECIMAL,
+            precision: Some(*precision as u64),
+            scale: Some(*scale as u64),
+            ..Default::default()
+        },
+        DataType::List(inner) => Type {
+            kind: kind::LIST,
+            subtypes: vec![push_types(inner, types)],
+            ..Default::default()
+        },
+        DataType::Struct(fields) => Type {
+            kind: kind::STRUCT,
+            subtypes: fields
+                .iter()
+                .map(|f| push_types(f.dtype(), types))
+                .collect(),
+            field_names: fields.iter().map(|f| f.name().to_string()).collect(),
+            ..Default::default()
+        },
+        _ => unreachable!(),
+    };
+
+    types[id as usize] = ty;
+    id
+}
+
+fn int_statistics(values: impl Iterator<Item = i64>) -> TypedStatistics {
+    let mut stats = None;
+    for v in values {
+        let (min, max, sum): &mut (i64, i64, Option<i64>) = stats.get_or_insert((v, v, Some(0)));
+        *min = (*min).min(v);
+        *max = (*max).max(v);
+        *sum = sum.and_then(|s| s.checked_add(v));
+    }
+    stats.map_or(TypedStatistics::None, |(min, max, sum)| {
+        TypedStatistics::Int { min, max, sum }
+    })
+}
+
+/// Encode the streams of `series` and its children, which only contain the values of the valid
+/// rows of their parent.
+fn encode_series(
+    series: &Series,
+    compression: OrcCompression,
+    out: &mut Vec<EncodedColumnData>,
+) -> PolarsResult<()> {
+    let position = out.len();
+    out.push(EncodedColumnData {
+        streams: vec![],
+        encoding: ColumnEncoding {
+            kind: encoding_kind::DIRECT,
+            dictionary_size: 0,
+        },
+        statistics: ColumnStatistics::default(),
+    });
+
+    let mut streams: Vec<(u64, Vec<u8>)> = vec![];
+    let has_nulls = series.null_count() > 0;
+    if has_nulls {
+        let mut present = vec![];
+        let is_not_null = series.is_not_null();
+        rle::encode_booleans(is_not_null.into_no_null_iter(), &mut present);
+        streams.push((stream_kind::PRESENT, present));
+    }
+    let values = series.drop_nulls();
+    let n_values = values.len();
+
+    let mut data = vec![];
+    let mut encoding = encoding_kind::DIRECT;
+    let typed = match values.dtype() {
+        DataType::Boolean => {
+            let ca = values.bool()?;
+            rle::encode_booleans(ca.into_no_null_iter(), &mut data);
+            streams.push((stream_kind::DATA, data));
+            TypedStatistics::Bucket {
+                true_count: ca.num_trues() as u64,
+            }
+        },
+        DataType::Int8 => {
+            let ca = values.i8()?;
+            let bytes = ca.into_no_null_iter().map(|v| v as u8).collect::<Vec<_>>();
+            rle::encode_bytes(&bytes, &mut data);
+            streams.push((stream_kind::DATA, data));
+            int_statistics(ca.into_no_null_iter().map(i64::from))
+        },
+        DataType::Int16 | DataType::Int32 | DataType::Int64 => {
+            encoding = encoding_kind::DIRECT_V2;
+            let ints = values.cast(&DataType::Int64)?;
+            let ints = ints.i64()?;
+            let ints = ints.into_no_null_iter().collect::<Vec<_>>();
+            rle::encode_ints(&ints, true, &mut data);
+            streams.push((stream_kind::DATA, data));
+            int_statistics(ints.into_iter())
+        },
+        DataType::Float32 => {
+            let ca = values.f32()?;
+            for v in ca.into_no_null_iter() {
+                data.extend_from_slice(&v.to_le_bytes());
+            }
+            streams.push((stream_kind::DATA, data));
+            match (ca.min(), ca.max()) {
+                (Some(min), Some(max)) => TypedStatistics::Double {
+                    min: min as f64,
+                    max: max as f64,
+                },
+                _ => TypedStatistics::None,
+            }
+        },
+        DataType::Float64 => {
+            let ca = values.f64()?;
+            for v in ca.into_no_null_iter() {
+                data.extend_from_slice(&v.to_le_bytes());
+            }
+            streams.push((stream_kind::DATA, data));
+            match (ca.min(), ca.max()) {
+                (Some(min), Some(max)) => TypedStatistics::Double { min, max },
+                _ => TypedStatistics::None,
+            }
+        },
+        DataType::String | DataType::Binary => {
+            encoding = encoding_kind::DIRECT_V2;
+            let ca = match values.dtype() {
+                DataType::String => values.str()?.as_binary(),
+                _ => values.binary()?.clone(),
+            };
+
+            let mut lengths = Vec::with_capacity(n_values);
+            for v in ca.into_no_null_iter() {
+                data.extend_from_slice(v);
+                lengths.push(v.len() as i64);
+            }
+            let mut length_data = vec![];
+            rle::encode_ints(&lengths, false, &mut length_data);
+            let total_length = data.len() as i64;
+            streams.push((stream_kind::DATA, data));
+            streams.push((stream_kind::LENGTH, length_data));
+
+            if values.dtype() == &DataType::String {
+                match (ca.min_binary(), ca.max_binary()) {
+                    (Some(min), Some(max)) => TypedStatistics::String {
+                        // SAFETY: The values are valid utf-8.
+                        min: unsafe { std::str::from_utf8_unchecked(min) }.to_string(),
+                        max: unsafe { std::str::from_utf8_unchecked(max) }.to_string(),
+                    },
+                    _ => TypedStatistics::None,
+                }
+            } else {
+                TypedStatistics::Binary { sum: total_length }
+            }
+        },
+        DataType::Date => {
+            encoding = encoding_kind::DIRECT_V2;
+            let ca = values.date()?.physical();
+            let days = ca.into_no_null_iter().map(|v| v as i64).collect::<Vec<_>>();
+            rle::encode_ints(&days, true, &mut data);
+            streams.push((stream_kind::DATA, data));
+            match (ca.min(), ca.max()) {
+                (Some(min), Some(max)) => TypedStatistics::Date { min, max },
+                _ => TypedStatistics::None,
+            }
+        },
+        DataType::Datetime(tu, _) => {
+            encoding = encoding_kind::DIRECT_V2;
+            let ca = values.datetime()?.physical();
+            let units_per_second = match tu {
+                TimeUnit::Nanoseconds => 1_000_000_000,
+                TimeUnit::Microseconds => 1_000_000,
+                TimeUnit::Milliseconds => 1_000,
+            };
+            let nanos_per_unit = 1_000_000_000 / units_per_second;
+
+            let mut seconds = Vec::with_capacity(n_values);
+            let mut nanos = Vec::with_capacity(n_values);
+            for v in ca.into_no_null_iter() {
+                let mut secs = v.div_euclid(units_per_second);
+                let ns = v.rem_euclid(units_per_second) * nanos_per_unit;
+                // Readers subtract a second from negative timestamps with a sub-millisecond
+                // remainder, as seconds are truncated towards zero by the reference writer.
+                if secs < 0 && ns > 999_999 {
+                    secs += 1;
+                }
+                seconds.push(secs - ORC_EPOCH_SECONDS);
+                nanos.push(encode_nanos(ns));
+            }
+            rle::encode_ints(&seconds, true, &mut data);
+            let mut secondary = vec![];
+            rle::encode_ints(&nanos, false, &mut secondary);
+            streams.push((stream_kind::DATA, data));
+            streams.push((stream_kind::SECONDARY, secondary));
+
+            let units_per_milli = units_per_second / 1000;
+            match (ca.min(), ca.max()) {
+                (Some(min), Some(max)) => TypedStatistics::Timestamp {
+                    min_utc: min.div_euclid(units_per_milli),
+                    max_utc: max.div_euclid(units_per_milli),
+                },
+                _ => TypedStatistics::None,
+            }
+        },
+        DataType::Decimal(_, scale) => {
+            encoding = encoding_kind::DIRECT_V2;
+            let ca = values.decimal()?.physical();
+            for v in ca.into_no_null_iter() {
+                rle::encode_varint_i128(v, &mut data);
+            }
+            let mut secondary = vec![];
+            rle::encode_ints(&vec![*scale as i64; n_values], true, &mut secondary);
+            streams.push((stream_kind::DATA, data));
+            streams.push((stream_kind::SECONDARY, secondary));
+            TypedStatistics::None
+        },
+        DataType::List(_) => {
+            encoding = encoding_kind::DIRECT_V2;
+            let ca = values.list()?.rechunk();
+            let arr = ca.downcast_as_array();
+            let offsets = arr.offsets();
+            let lengths = offsets.lengths().map(|l| l as i64).collect::<Vec<_>>();
+            rle::encode_ints(&lengths, false, &mut data);
+            streams.push((stream_kind::LENGTH, data));
+
+            let (start, end) = (*offsets.first() as usize, *offsets.last() as usize);
+            let child = arr.values().sliced(start, end - start);
+            // SAFETY: The values have the physical type of the inner dtype.
+            let child = unsafe {
+                Series::from_chunks_and_dtype_unchecked(
+                    PlSmallStr::EMPTY,
+                    vec![child],
+                    ca.inner_dtype(),
+                )
+            };
+            encode_series(&child, compression, out)?;
+            TypedStatistics::None
+        },
+        DataType::Struct(_) => {
+            for field in values.struct_()?.fields_as_series() {
+                encode_series(&field.rechunk(), compression, out)?;
+            }
+            TypedStatistics::None
+        },
+        dt => polars_bail!(nyi = "writing columns of dtype {dt} to ORC"),
+    };
+
+    let column = &mut out[position];
+    column.streams = streams
+        .into_iter()
+        .filter(|(_, stream)| !stream.is_empty())
+        .map(|(kind, stream)| {
+            let mut compressed = vec![];
+            compression.compress(&stream, &mut compressed)?;
+            Ok((kind, compressed))
+        })
+        .collect::<PolarsResult<_>>()?;
+    column.encoding.kind = encoding;
+    column.statistics = ColumnStatistics {
+        number_of_values: n_values as u64,
+        has_null: Some(has_nulls),
+        typed: if n_values > 0 {
+            typed
+        } else {
+            TypedStatistics::None
+        },
+    };
+    Ok(())
+}
+
+/// Encode nanoseconds with the number of trailing decimal zeros in the lowest 3 bits.
+fn encode_nanos(nanos: i64) -> i64 {
+    if nanos == 0 {
+        return 0;
+    }
+    let mut value = nanos;
+    let mut zeros = 0;
+    while value % 10 == 0 && zeros < 8 {
+        value /= 10;
+        zeros += 1;
+    }
+    if zeros < 2 {
+        nanos << 3
+    } else {
+        (value << 3) | (zeros - 1)
+    }
+}
+
+/// Writes [`DataFrame`]s as stripes of an ORC file.
+pub struct BatchedWriter<W: Write> {
+    writer: W,
+    encoder: OrcStripeEncoder,
+    offset: u64,
+    stripes: Vec<StripeInformation>,
+    stripe_statistics: Vec<Vec<ColumnStatistics>>,
+    num_rows: u64,
+}
+
+impl<W: Write> BatchedWriter<W> {
+    pub fn new(mut writer: W, encoder: OrcStripeEncoder) -> PolarsResult<Self> {
+        writer.write_all(b"ORC")?;
+        Ok(Self {
+            writer,
+            encoder,
+            offset: 3,
+            stripes: vec![],
+            stripe_statistics: vec![],
+            num_rows: 0,
+        })
+    }
+
+    pub fn encoder(&self) -> &OrcStripeEncoder {
+        &self.encoder
+    }
+
+    /// Write a [`DataFrame`] as one or more stripes, depending on the number of rows per stripe.
+    pub fn write_batch(&mut self, df: &DataFrame) -> PolarsResult<()> {
+        let rows_per_stripe = self.encoder.options.rows_per_stripe();
+        let mut offset = 0;
+        while offset < df.height() {
+            let stripe = df.slice(offset as i64, rows_per_stripe);
+            offset += stripe.height();
+
+            let encoder = &self.encoder;
+            let columns = POOL.install(|| {
+                stripe
+                    .get_columns()
+                    .par_iter()
+                    .enumerate()
+                    .map(|(i, column)| encoder.encode_column(i, column))
+                    .collect::<PolarsResult<Vec<_>>>()
+            })?;
+            self.write_stripe(columns)?;
+        }
+        Ok(())
+    }
+
+    /// Write a stripe from the encoded top-level columns, which must all have the same length.
+    pub fn write_stripe(&mut self, columns: Vec<EncodedOrcColumn>) -> PolarsResult<()> {
+        polars_ensure!(
+            columns.len() == self.encoder.column_ids.len(),
+            ComputeError: "expected {} ORC columns, got {}", self.encoder.column_ids.len(), columns.len()
+        );
+        let Some(n_rows) = columns.first().map(|c| c.n_rows) else {
+            return Ok(());
+        };
+        if n_rows == 0 {
+            return Ok(());
+        }
+
+        let mut footer = StripeFooter {
+            streams: vec![],
+            columns: vec![ColumnEncoding {
+                kind: encoding_kind::DIRECT,
+                dictionary_size: 0,
+            }],
+            writer_timezone: Some("UTC".into()),
+        };
+        let mut statistics = vec![ColumnStatistics {
+            number_of_values: n_rows as u64,
+            has_null: Some(false),
+            typed: TypedStatistics::None,
+        }];
+
+        let mut data_length = 0;
+        let mut column_id = 1;
+        for column in columns {
+            debug_assert_eq!(column.n_rows, n_rows);
+            for data in column.columns {
+                for (kind, stream) in data.streams {
+                    self.writer.write_all(&stream)?;
+                    data_length += stream.len() as u64;
+                    footer.streams.push(Stream {
+                        kind,
+                        column: column_id,
+                        length: stream.len() as u64,
+                    });
+                }
+                footer.columns.push(data.encoding);
+                statistics.push(data.statistics);
+                column_id += 1;
+            }
+        }
+        debug_assert_eq!(column_id as usize, self.encoder.num_columns());
+
+        let mut footer_bytes = vec![];
+        self.encoder
+            .options
+            .compression
+            .compress(&footer.encode(), &mut footer_bytes)?;
+        self.writer.write_all(&footer_bytes)?;
+
+        self.stripes.push(StripeInformation {
+            offset: self.offset,
+            index_length: 0,
+            data_length,
+            footer_length: footer_bytes.len() as u64,
+            number_of_rows: n_rows as u64,
+        });
+        self.stripe_statistics.push(statistics);
+        self.offset += data_length + footer_bytes.len() as u64;
+        self.num_rows += n_rows as u64;
+        Ok(())
+    }
+
+    /// Write the file footer. Returns the total size of the file.
+    pub fn finish(&mut self) -> PolarsResult<u64> {
+        let compression = self.encoder.options.compression;
+
+        let mut statistics = vec![ColumnStatistics::default(); self.encoder.num_columns()];
+        for stripe_statistics in &self.stripe_statistics {
+            for (stats, stripe_stats) in statistics.iter_mut().zip(stripe_statistics) {
+                stats.merge(stripe_stats);
+            }
+        }
+        if self.stripes.is_empty() {
+            for stats in statistics.iter_mut() {
+                stats.has_null = Some(false);
+            }
+        }
+
+        let metadata = Metadata {
+            stripe_statistics: std::mem::take(&mut self.stripe_statistics),
+        };
+        let mut metadata_bytes = vec![];
+        compression.compress(&metadata.encode(), &mut metadata_bytes)?;
+
+        let footer = Footer {
+            header_length: 3,
+            content_length: self.offset - 3,
+            stripes: std::mem::take(&mut self.stripes),
+            types: self.encoder.types.clone(),
+            number_of_rows: self.num_rows,
+            statistics,
+        };
+        let mut footer_bytes = vec![];
+        compression.compress(&footer.encode(), &mut footer_bytes)?;
+
+        let postscript = PostScript {
+            footer_length: footer_bytes.len() as u64,
+            compression: compression.kind(),
+            compression_block_size: (compression != OrcCompression::Uncompressed)
+                .then_some(DEFAULT_BLOCK_SIZE as u64),
+            metadata_length: metadata_bytes.len() as u64,
+        }
+        .encode();
+
+        self.writer.write_all(&metadata_bytes)?;
+        self.writer.write_all(&footer_bytes)?;
+        self.writer.write_all(&postscript)?;
+        self.writer.write_all(&[postscript.len() as u8])?;
+        self.writer.flush()?;
+
+        Ok(self.offset + (metadata_bytes.len() + footer_bytes.len() + postscript.len() + 1) as u64)
+    }
+}
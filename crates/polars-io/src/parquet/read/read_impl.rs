@@ -56,7 +56,18 @@ fn should_copy_sortedness(dtype: &DataType) -> bool {
 
     matches!(
         dtype,
-        D::Int8 | D::Int16 | D::Int32 | D::Int64 | D::UInt8 | D::UInt16 | D::UInt32 | D::UInt64
+        D::Int8
+            | D::Int16
+            | D::Int32
+            | D::Int64
+            | D::UInt8
+            | D::UInt16
+            | D::UInt32
+            | D::UInt64
+            | D::Date
+            | D::Datetime(_, _)
+            | D::Duration(_)
+            | D::Time
     )
 }
 
@@ -97,6 +108,83 @@ pub fn create_sorting_map(md: &RowGroupMetadata) -> Vec<(usize, IsSorted)> {
     sorting_map
 }
 
+/// How the file is sorted lexicographically by `columns` over all row groups, if that can be
+/// derived from the metadata.
+///
+/// This requires the columns to be the leading sorting columns of every row group, in order, and
+/// to contain no nulls. The ranges of the first column in consecutive row groups according to the
+/// statistics may not overlap; with more than one column they may also not touch, as the order of
+/// the rows that are equal in the first column isn't known across row groups.
+pub fn get_file_sort_order(
+    md: &FileMetadata,
+    columns: &[(&str, &DataType)],
+) -> Option<Vec<IsSorted>> {
+    use polars_parquet::parquet::statistics::Statistics;
+
+    // The statistics of unsigned 32 and 64 bit integers don't compare as their physical type.
+    if columns.is_empty()
+        || columns.iter().any(|(_, dtype)| {
+            !should_copy_sortedness(dtype) || matches!(dtype, DataType::UInt32 | DataType::UInt64)
+        })
+    {
+        return None;
+    }
+
+    let mut order: Option<Vec<IsSorted>> = None;
+    let mut prev_range: Option<(i64, i64)> = None;
+    for rg in &md.row_groups {
+        let sorting = rg.sorting_columns()?.get(..columns.len())?;
+        let mut rg_order = Vec::with_capacity(columns.len());
+        let mut first_range = None;
+        for ((name, _), sorting) in columns.iter().zip(sorting) {
+            let &[leaf_idx] = rg.columns_idxs_under_root_iter(name)? else {
+                return None;
+            };
+            if sorting.column_idx as usize != leaf_idx {
+                return None;
+            }
+            rg_order.push(if sorting.descending {
+                IsSorted::Descending
+            } else {
+                IsSorted::Ascending
+            });
+
+            let (null_count, min, max) = match rg.parquet_columns()[leaf_idx].statistics()?.ok()? {
+                Statistics::Int32(s) => (
+                    s.null_count,
+                    s.min_value.map(i64::from),
+                    s.max_value.map(i64::from),
+                ),
+                Statistics::Int64(s) => (s.null_count, s.min_value, s.max_value),
+                _ => return None,
+            };
+            if null_count != Some(0) {
+                return None;
+            }
+            first_range.get_or_insert((min?, max?));
+        }
+        if *order.get_or_insert_with(|| rg_order.clone()) != rg_order {
+            return None;
+        }
+
+        let range = first_range?;
+        if let Some(prev) = prev_range {
+            let in_order = match (rg_order[0], columns.len()) {
+                (IsSorted::Descending, 1) => prev.0 >= range.1,
+                (IsSorted::Descending, _) => prev.0 > range.1,
+                (_, 1) => prev.1 <= range.0,
+                _ => prev.1 < range.0,
+            };
+            if !in_order {
+                return None;
+            }
+        }
+        prev_range = Some(range);
+    }
+
+    order
+}
+
 fn column_idx_to_series(
     column_i: usize,
     // The metadata belonging to this column
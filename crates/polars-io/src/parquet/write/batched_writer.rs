@@ -4,11 +4,13 @@ use std::sync::Mutex;
 use arrow::record_batch::RecordBatch;
 use polars_core::POOL;
 use polars_core::prelude::*;
+use polars_core::series::IsSorted;
 use polars_parquet::read::{ParquetError, fallible_streaming_iterator};
 use polars_parquet::write::{
     ColumnWriteOptions, CompressedPage, Compressor, DynIter, DynStreamingIterator,
     FallibleStreamingIterator, FileWriter, Page, ParquetType, RowGroupIterColumns,
-    SchemaDescriptor, WriteOptions, array_to_columns, schema_to_metadata_key,
+    SchemaDescriptor, SortingColumn, WriteOptions, array_to_columns, schema_to_metadata_key,
+    to_parquet_leaves,
 };
 use rayon::prelude::*;
 
@@ -78,10 +80,12 @@ impl<W: Write> BatchedWriter<W> {
             self.options,
             self.parallel,
         );
+        // Every row group is a slice of `df`, so they are all sorted like `df` is.
+        let sorting_columns = get_sorting_columns(df.get_columns(), &self.parquet_schema);
         // Lock before looping so that order is maintained under contention.
         let mut writer = self.writer.lock().unwrap();
         for group in row_group_iter {
-            writer.write(group?)?;
+            writer.write_with_sorting_columns(group?, sorting_columns.clone())?;
         }
         Ok(())
     }
@@ -91,14 +95,18 @@ impl<W: Write> BatchedWriter<W> {
         writer.parquet_schema()
     }
 
-    pub fn write_row_group(&mut self, rg: &[Vec<CompressedPage>]) -> PolarsResult<()> {
+    pub fn write_row_group(
+        &mut self,
+        rg: &[Vec<CompressedPage>],
+        sorting_columns: Option<Vec<SortingColumn>>,
+    ) -> PolarsResult<()> {
         let writer = self.writer.get_mut().unwrap();
         let rg = DynIter::new(rg.iter().map(|col_pages| {
             Ok(DynStreamingIterator::new(
                 fallible_streaming_iterator::convert(col_pages.iter().map(PolarsResult::Ok)),
             ))
         }));
-        writer.write(rg)?;
+        writer.write_with_sorting_columns(rg, sorting_columns)?;
         Ok(())
     }
 
@@ -143,6 +151,59 @@ impl<W: Write> BatchedWriter<W> {
     }
 }
 
+/// The [`SortingColumn`] to record for a column with a sorted flag that is written to the leaf
+/// column `leaf_idx`.
+///
+/// Only columns that map to a single leaf column and whose order matches the order Parquet
+/// defines for their physical type are recorded.
+pub fn get_sorting_column(column: &Column, leaf_idx: usize) -> Option<SortingColumn> {
+    let dtype = column.dtype();
+    if dtype.is_nested() || dtype.is_float() || dtype.is_categorical() || dtype.is_enum() {
+        return None;
+    }
+    let descending = match column.is_sorted_flag() {
+        IsSorted::Ascending => false,
+        IsSorted::Descending => true,
+        IsSorted::Not => return None,
+    };
+    let nulls_first = column.has_nulls() && column.get(0).is_ok_and(|av| av.is_null());
+
+    Some(SortingColumn {
+        column_idx: leaf_idx as i32,
+        descending,
+        nulls_first,
+    })
+}
+
+/// For every top-level field of `parquet_schema` that is a single leaf column, the index of that
+/// leaf column.
+pub fn get_primitive_leaf_idxs(parquet_schema: &SchemaDescriptor) -> Vec<Option<usize>> {
+    let mut num_leaves = 0;
+    parquet_schema
+        .fields()
+        .iter()
+        .map(|type_| {
+            let leaf_idx = num_leaves;
+            num_leaves += to_parquet_leaves(type_.clone()).len();
+            matches!(type_, ParquetType::PrimitiveType(_)).then_some(leaf_idx)
+        })
+        .collect()
+}
+
+/// The [`SortingColumn`]s of all `columns` with a sorted flag.
+pub fn get_sorting_columns(
+    columns: &[Column],
+    parquet_schema: &SchemaDescriptor,
+) -> Option<Vec<SortingColumn>> {
+    let sorting_columns = columns
+        .iter()
+        .zip(get_primitive_leaf_idxs(parquet_schema))
+        .filter_map(|(column, leaf_idx)| get_sorting_column(column, leaf_idx?))
+        .collect::<Vec<_>>();
+
+    (!sorting_columns.is_empty()).then_some(sorting_columns)
+}
+
 // Note that the df should be rechunked
 fn prepare_rg_iter<'a>(
     df: &'a DataFrame,
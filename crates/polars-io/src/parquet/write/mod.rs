@@ -5,7 +5,9 @@ mod key_value_metadata;
 mod options;
 mod writer;
 
-pub use batched_writer::BatchedWriter;
+pub use batched_writer::{
+    BatchedWriter, get_primitive_leaf_idxs, get_sorting_column, get_sorting_columns,
+};
 pub use key_value_metadata::{KeyValueMetadata, ParquetMetadataContext};
 pub use options::{
     BrotliLevel, ChildFieldOverwrites, GzipLevel, MetadataKeyValue, ParquetCompression,
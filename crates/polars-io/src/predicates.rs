@@ -6,6 +6,7 @@ use polars_core::prelude::*;
 #[cfg(feature = "parquet")]
 use polars_parquet::read::expr::{ParquetColumnExpr, ParquetScalar, SpecializedParquetColumnExpr};
 use polars_utils::format_pl_smallstr;
+use polars_utils::relaxed_cell::RelaxedCell;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
@@ -398,6 +399,13 @@ pub trait SkipBatchPredicate: Send + Sync {
         Ok(self.evaluate_with_stat_df(&df)?.get_bit(0))
     }
     fn evaluate_with_stat_df(&self, df: &DataFrame) -> PolarsResult<Bitmap>;
+
+    /// Whether the result may change between evaluations, e.g. because the predicate depends on
+    /// data that only becomes available while the query is running. Readers should then evaluate
+    /// it right before reading a batch instead of once upfront.
+    fn is_dynamic(&self) -> bool {
+        false
+    }
 }
 
 #[derive(Clone)]
@@ -439,6 +447,10 @@ impl SkipBatchPredicate for PhysicalExprWithConstCols<Arc<dyn SkipBatchPredicate
         }
         self.child.evaluate_with_stat_df(&df)
     }
+
+    fn is_dynamic(&self) -> bool {
+        self.child.is_dynamic()
+    }
 }
 
 impl PhysicalIoExpr for PhysicalExprWithConstCols<Arc<dyn PhysicalIoExpr>> {
@@ -473,6 +485,9 @@ pub struct ScanIOPredicate {
     pub hive_predicate: Option<Arc<dyn PhysicalIoExpr>>,
 
     pub hive_predicate_is_full_predicate: bool,
+
+    /// Counters for the work skipped by this predicate, used by `explain_analyze`.
+    pub metrics: Option<Arc<ScanMetrics>>,
 }
 
 impl ScanIOPredicate {
@@ -519,6 +534,32 @@ impl ScanIOPredicate {
     }
 }
 
+/// Amount of data a scan skipped because of predicate pushdown.
+///
+/// Equality and hashing are by identity, so that attaching metrics to a plan does not change how
+/// it compares to other plans.
+#[derive(Debug, Default)]
+pub struct ScanMetrics {
+    pub files_skipped: RelaxedCell<usize>,
+    pub row_groups_skipped: RelaxedCell<usize>,
+    /// Shared with the parquet page decoders.
+    pub pages_skipped: Arc<RelaxedCell<usize>>,
+}
+
+impl PartialEq for ScanMetrics {
+    fn eq(&self, other: &Self) -> bool {
+        std::ptr::eq(self, other)
+    }
+}
+
+impl Eq for ScanMetrics {}
+
+impl std::hash::Hash for ScanMetrics {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::ptr::hash(self, state)
+    }
+}
+
 impl fmt::Debug for ScanIOPredicate {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.write_str("scan_io_predicate")
@@ -1,16 +1,23 @@
+#[cfg(any(feature = "csv", feature = "json"))]
+pub use crate::bad_records::*;
 pub use crate::cloud;
 #[cfg(feature = "csv")]
 pub use crate::csv::{read::*, write::*};
+#[cfg(feature = "fwf")]
+pub use crate::fwf::{FwfColumn, FwfReadOptions, FwfReader, FwfTrim};
 #[cfg(any(feature = "ipc", feature = "ipc_streaming"))]
 pub use crate::ipc::*;
 #[cfg(feature = "json")]
 pub use crate::json::*;
 #[cfg(feature = "json")]
 pub use crate::ndjson::core::*;
+#[cfg(feature = "orc")]
+pub use crate::orc::{OrcCompression, OrcReader, OrcScanOptions, OrcWriter, OrcWriterOptions};
 #[cfg(feature = "parquet")]
 pub use crate::parquet::{metadata::*, read::*, write::*};
 #[cfg(feature = "parquet")]
 pub use crate::partition::write_partitioned_dataset;
 pub use crate::path_utils::*;
 pub use crate::shared::{SerReader, SerWriter};
+pub use crate::utils::compression::ExternalCompression;
 pub use crate::utils::*;
@@ -1,13 +1,22 @@
-use std::io::Read;
+use std::io::{BufRead, Read, Write};
+use std::sync::Mutex;
 
 use polars_core::prelude::*;
 use polars_error::{feature_gated, to_compute_err};
+use polars_utils::mmap::MemSlice;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 
 /// Represents the compression algorithms that we have decoders for
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub enum SupportedCompression {
     GZIP,
     ZLIB,
     ZSTD,
+    BZIP2,
+    XZ,
+    /// The LZ4 frame format.
+    LZ4,
 }
 
 impl SupportedCompression {
@@ -27,9 +36,59 @@ impl SupportedCompression {
             [0x78, 0x9c, _, _] => Some(Self::ZLIB),
             [0x78, 0xda, _, _] => Some(Self::ZLIB),
             [0x28, 0xb5, 0x2f, 0xfd] => Some(Self::ZSTD),
+            [0x04, 0x22, 0x4d, 0x18] => Some(Self::LZ4),
+            // "BZh" is valid text, so the magic of the first block (or of the end of an empty
+            // stream) is checked as well.
+            [b'B', b'Z', b'h', b'1'..=b'9']
+                if bytes.len() >= 10
+                    && matches!(
+                        bytes[4..10],
+                        [0x31, 0x41, 0x59, 0x26, 0x53, 0x59] | [0x17, 0x72, 0x45, 0x38, 0x50, 0x90]
+                    ) =>
+            {
+                Some(Self::BZIP2)
+            },
+            [0xfd, b'7', b'z', b'X'] if bytes.len() >= 6 && bytes[4..6] == [b'Z', 0x00] => {
+                Some(Self::XZ)
+            },
             _ => None,
         }
     }
+
+    /// Wrap `reader` in a decoder for this compression. Concatenated streams (e.g. multiple gzip
+    /// members) are decoded as a whole.
+    #[allow(unused_variables)]
+    pub(crate) fn decoder<'a, R: BufRead + Send + 'a>(
+        self,
+        reader: R,
+    ) -> PolarsResult<Box<dyn Read + Send + 'a>> {
+        Ok(match self {
+            Self::GZIP => feature_gated!(
+                "decompress",
+                Box::new(flate2::bufread::MultiGzDecoder::new(reader))
+            ),
+            Self::ZLIB => {
+                feature_gated!(
+                    "decompress",
+                    Box::new(flate2::bufread::ZlibDecoder::new(reader))
+                )
+            },
+            Self::ZSTD => {
+                feature_gated!("decompress", Box::new(zstd::Decoder::with_buffer(reader)?))
+            },
+            Self::BZIP2 => {
+                feature_gated!(
+                    "bzip2",
+                    Box::new(bzip2::bufread::MultiBzDecoder::new(reader))
+                )
+            },
+            Self::XZ => feature_gated!(
+                "xz",
+                Box::new(liblzma::bufread::XzDecoder::new_multi_decoder(reader))
+            ),
+            Self::LZ4 => feature_gated!("lz4", Box::new(lz4_frames::Decoder::new(reader)?)),
+        })
+    }
 }
 
 /// Decompress `bytes` if compression is detected, otherwise simply return it.
@@ -39,26 +98,317 @@ pub fn maybe_decompress_bytes<'a>(bytes: &'a [u8], out: &'a mut Vec<u8>) -> Pola
     assert!(out.is_empty());
 
     if let Some(algo) = SupportedCompression::check(bytes) {
-        feature_gated!("decompress", {
-            match algo {
-                SupportedCompression::GZIP => {
-                    flate2::read::MultiGzDecoder::new(bytes)
-                        .read_to_end(out)
-                        .map_err(to_compute_err)?;
-                },
-                SupportedCompression::ZLIB => {
-                    flate2::read::ZlibDecoder::new(bytes)
-                        .read_to_end(out)
-                        .map_err(to_compute_err)?;
-                },
-                SupportedCompression::ZSTD => {
-                    zstd::Decoder::with_buffer(bytes)?.read_to_end(out)?;
-                },
-            }
+        algo.decoder(bytes)?
+            .read_to_end(out)
+            .map_err(to_compute_err)?;
 
-            Ok(out)
-        })
+        Ok(out)
     } else {
         Ok(bytes)
     }
 }
+
+/// Decompresses data in blocks, so that the decompressed data does not have to be held in
+/// memory at once.
+pub struct Decompressor {
+    reader: Box<dyn Read + Send>,
+}
+
+impl Decompressor {
+    /// Returns `None` if no compression is detected in `bytes`.
+    pub fn try_new(bytes: MemSlice) -> PolarsResult<Option<Self>> {
+        let Some(algo) = SupportedCompression::check(&bytes) else {
+            return Ok(None);
+        };
+        let reader = algo.decoder(std::io::Cursor::new(bytes))?;
+
+        Ok(Some(Self { reader }))
+    }
+
+    /// Append up to `n` decompressed bytes to `buf`. Returns the number of bytes appended, which
+    /// is only less than `n` at the end of the data.
+    pub fn read_into(&mut self, buf: &mut Vec<u8>, n: usize) -> PolarsResult<usize> {
+        let n = (&mut self.reader)
+            .take(n as u64)
+            .read_to_end(buf)
+            .map_err(to_compute_err)?;
+
+        Ok(n)
+    }
+}
+
+/// Compression of a complete file, as opposed to compression within a file format.
+///
+/// Writers compress their output as a single stream, see [`Compressor`].
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub enum ExternalCompression {
+    #[default]
+    Uncompressed,
+    Gzip {
+        level: Option<u32>,
+    },
+    Zstd {
+        level: Option<i32>,
+    },
+    Bzip2 {
+        level: Option<u32>,
+    },
+    Xz {
+        level: Option<u32>,
+    },
+    /// The LZ4 frame format.
+    Lz4 {
+        level: Option<u32>,
+    },
+}
+
+impl ExternalCompression {
+    pub fn is_uncompressed(&self) -> bool {
+        matches!(self, Self::Uncompressed)
+    }
+
+    /// Compress `bytes` into a complete stream, which is appended to `out`.
+    pub fn compress(&self, bytes: &[u8], out: &mut Vec<u8>) -> PolarsResult<()> {
+        match Compressor::try_new(*self)? {
+            Some(mut compressor) => {
+                out.extend(compressor.compress(bytes)?);
+                out.extend(compressor.finish()?);
+            },
+            None => out.extend_from_slice(bytes),
+        }
+
+        Ok(())
+    }
+}
+
+/// The output of an encoder, which is taken out by the [`Compressor`] as it is produced.
+#[derive(Clone, Default)]
+struct EncoderOutput(Arc<Mutex<Vec<u8>>>);
+
+impl EncoderOutput {
+    fn take(&self) -> Vec<u8> {
+        std::mem::take(&mut *self.0.lock().unwrap())
+    }
+}
+
+impl Write for EncoderOutput {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.0.lock().unwrap().extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+trait Encoder: Write + Send {
+    /// Write the end of the stream.
+    fn finish(self: Box<Self>) -> std::io::Result<()>;
+}
+
+#[cfg(feature = "compress")]
+impl Encoder for flate2::write::GzEncoder<EncoderOutput> {
+    fn finish(self: Box<Self>) -> std::io::Result<()> {
+        (*self).finish().map(drop)
+    }
+}
+
+#[cfg(feature = "compress")]
+impl Encoder for zstd::Encoder<'static, EncoderOutput> {
+    fn finish(self: Box<Self>) -> std::io::Result<()> {
+        (*self).finish().map(drop)
+    }
+}
+
+#[cfg(feature = "bzip2")]
+impl Encoder for bzip2::write::BzEncoder<EncoderOutput> {
+    fn finish(self: Box<Self>) -> std::io::Result<()> {
+        (*self).finish().map(drop)
+    }
+}
+
+#[cfg(feature = "xz")]
+impl Encoder for liblzma::write::XzEncoder<EncoderOutput> {
+    fn finish(self: Box<Self>) -> std::io::Result<()> {
+        (*self).finish().map(drop)
+    }
+}
+
+#[cfg(feature = "lz4")]
+impl Encoder for lz4::Encoder<EncoderOutput> {
+    fn finish(self: Box<Self>) -> std::io::Result<()> {
+        (*self).finish().1
+    }
+}
+
+/// Compresses data that is passed in parts into a single compressed stream.
+pub struct Compressor {
+    encoder: Box<dyn Encoder>,
+    output: EncoderOutput,
+}
+
+impl Compressor {
+    /// Returns `None` if `compression` is [`ExternalCompression::Uncompressed`].
+    #[allow(unused_variables)]
+    pub fn try_new(compression: ExternalCompression) -> PolarsResult<Option<Self>> {
+        use ExternalCompression as C;
+
+        let output = EncoderOutput::default();
+        let out = output.clone();
+
+        let encoder: Box<dyn Encoder> = match compression {
+            C::Uncompressed => return Ok(None),
+            C::Gzip { level } => feature_gated!("compress", {
+                let level =
+                    level.map_or_else(flate2::Compression::default, flate2::Compression::new);
+                Box::new(flate2::write::GzEncoder::new(out, level))
+            }),
+            C::Zstd { level } => feature_gated!("compress", {
+                Box::new(zstd::Encoder::new(out, level.unwrap_or(0))?)
+            }),
+            C::Bzip2 { level } => feature_gated!("bzip2", {
+                let level = level.map_or_else(bzip2::Compression::default, bzip2::Compression::new);
+                Box::new(bzip2::write::BzEncoder::new(out, level))
+            }),
+            C::Xz { level } => feature_gated!("xz", {
+                Box::new(liblzma::write::XzEncoder::new(out, level.unwrap_or(6)))
+            }),
+            C::Lz4 { level } => feature_gated!("lz4", {
+                Box::new(
+                    lz4::EncoderBuilder::new()
+                        .level(level.unwrap_or(0))
+                        .build(out)?,
+                )
+            }),
+        };
+
+        Ok(Some(Self { encoder, output }))
+    }
+
+    /// Compress `bytes`, returning the compressed data that is ready so far. This can be empty, as
+    /// encoders buffer their input.
+    pub fn compress(&mut self, bytes: &[u8]) -> PolarsResult<Vec<u8>> {
+        self.encoder.write_all(bytes)?;
+        Ok(self.output.take())
+    }
+
+    /// End the stream, returning the remaining compressed data.
+    pub fn finish(self) -> PolarsResult<Vec<u8>> {
+        self.encoder.finish()?;
+        Ok(self.output.take())
+    }
+}
+
+#[cfg(feature = "lz4")]
+mod lz4_frames {
+    use std::io::{BufRead, Read, Result};
+
+    /// Decoder of concatenated LZ4 frames. [`lz4::Decoder`] stops after the first frame.
+    pub(super) struct Decoder<R: BufRead> {
+        inner: Option<lz4::Decoder<R>>,
+    }
+
+    impl<R: BufRead> Decoder<R> {
+        pub(super) fn new(reader: R) -> Result<Self> {
+            Ok(Self {
+                inner: Some(lz4::Decoder::new(reader)?),
+            })
+        }
+    }
+
+    impl<R: BufRead> Read for Decoder<R> {
+        fn read(&mut self, buf: &mut [u8]) -> Result<usize> {
+            while let Some(decoder) = self.inner.as_mut() {
+                let n = decoder.read(buf)?;
+                if n > 0 || buf.is_empty() {
+                    return Ok(n);
+                }
+
+                // End of the frame, continue with the next one if there is any.
+                let (mut reader, result) = self.inner.take().unwrap().finish();
+                result?;
+                if !reader.fill_buf()?.is_empty() {
+                    self.inner = Some(lz4::Decoder::new(reader)?);
+                }
+            }
+
+            Ok(0)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check_compression() {
+        assert_eq!(SupportedCompression::check(b"BZh9,a,b\n1,2,3\n"), None);
+        assert_eq!(
+            SupportedCompression::check(b"\xfd7zXZ\x00\x00\x04"),
+            Some(SupportedCompression::XZ)
+        );
+        assert_eq!(
+            SupportedCompression::check(b"\x04\x22\x4d\x18\x64"),
+            Some(SupportedCompression::LZ4)
+        );
+    }
+
+    #[test]
+    #[cfg(all(feature = "decompress", feature = "compress"))]
+    fn test_compress_roundtrip() {
+        let data = b"a,b\n1,2\n3,4\n".repeat(100);
+
+        for compression in [
+            ExternalCompression::Gzip { level: None },
+            ExternalCompression::Zstd { level: Some(3) },
+            #[cfg(feature = "lz4")]
+            ExternalCompression::Lz4 { level: None },
+            #[cfg(feature = "bzip2")]
+            ExternalCompression::Bzip2 { level: Some(1) },
+            #[cfg(feature = "xz")]
+            ExternalCompression::Xz { level: None },
+        ] {
+            // Multiple streams are decompressed as a whole.
+            let mut compressed = vec![];
+            compression.compress(&data[..600], &mut compressed).unwrap();
+            compression.compress(&data[600..], &mut compressed).unwrap();
+
+            let mut out = vec![];
+            let decompressed = maybe_decompress_bytes(&compressed, &mut out).unwrap();
+            assert_eq!(decompressed, data);
+
+            let mut decompressor = Decompressor::try_new(MemSlice::from_vec(compressed))
+                .unwrap()
+                .unwrap();
+            let mut out = vec![];
+            while decompressor.read_into(&mut out, 64).unwrap() == 64 {}
+            assert_eq!(out, data);
+        }
+    }
+
+    #[test]
+    #[cfg(all(feature = "decompress", feature = "compress"))]
+    fn test_compressor_single_stream() {
+        let data = b"a,b\n1,2\n3,4\n".repeat(100);
+
+        let mut compressor = Compressor::try_new(ExternalCompression::Gzip { level: None })
+            .unwrap()
+            .unwrap();
+        let mut compressed = vec![];
+        for part in data.chunks(100) {
+            compressed.extend(compressor.compress(part).unwrap());
+        }
+        compressed.extend(compressor.finish().unwrap());
+
+        // A decoder of a single gzip member sees all data.
+        let mut out = vec![];
+        flate2::read::GzDecoder::new(compressed.as_slice())
+            .read_to_end(&mut out)
+            .unwrap();
+        assert_eq!(out, data);
+    }
+}
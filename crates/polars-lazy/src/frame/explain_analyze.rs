@@ -0,0 +1,364 @@
+//! Execution of a query with a counter in front of every node of the optimized plan, so that the
+//! plan can be rendered together with what actually happened during execution.
+use std::fmt::Write;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use polars_io::predicates::ScanMetrics;
+use polars_plan::plans::write_ir_non_recursive;
+
+use super::*;
+
+#[derive(Default)]
+struct NodeStats {
+    rows_out: u64,
+    first_output: Option<Instant>,
+    last_output: Option<Instant>,
+}
+
+struct AnalyzedNode {
+    depth: usize,
+    /// The node as formatted by `explain`, without indentation.
+    description: String,
+    inputs: Vec<Node>,
+    stats: Option<Arc<Mutex<NodeStats>>>,
+    scan_metrics: Option<Arc<ScanMetrics>>,
+}
+
+impl LazyFrame {
+    /// Execute the query and return the optimized plan, annotated with the actual number of rows
+    /// going in and out of every node, the time every node took and for scans the number of
+    /// files, row groups and pages skipped by predicate pushdown. The last line names the engine
+    /// that ran the query.
+    ///
+    /// See [`LazyFrame::explain_analyze_df`] for how these are measured.
+    pub fn explain_analyze(self, engine: Engine) -> PolarsResult<String> {
+        let df = self.explain_analyze_df(engine)?;
+        let plan = df.column("plan")?.str()?;
+        let counts = [
+            "rows_in",
+            "rows_out",
+            "elapsed_us",
+            "files_skipped",
+            "row_groups_skipped",
+            "pages_skipped",
+        ]
+        .map(|name| df.column(name).and_then(|c| c.u64().cloned()));
+        let [
+            rows_in,
+            rows_out,
+            elapsed_us,
+            files_skipped,
+            row_groups_skipped,
+            pages_skipped,
+        ] = counts;
+        let (rows_in, rows_out, elapsed_us) = (rows_in?, rows_out?, elapsed_us?);
+        let (files_skipped, row_groups_skipped, pages_skipped) =
+            (files_skipped?, row_groups_skipped?, pages_skipped?);
+
+        let engine = df.column("engine")?.str()?.get(0).unwrap_or_default();
+
+        let mut out = String::new();
+        for (i, plan) in plan.into_no_null_iter().enumerate() {
+            let mut annotation = Vec::new();
+            if let Some(rows) = rows_in.get(i) {
+                annotation.push(format!("rows in: {rows}"));
+            }
+            if let Some(rows) = rows_out.get(i) {
+                annotation.push(format!("rows out: {rows}"));
+            }
+            if let Some(us) = elapsed_us.get(i) {
+                annotation.push(format!("elapsed: {:?}", Duration::from_micros(us)));
+            }
+            if let (Some(files), Some(row_groups), Some(pages)) = (
+                files_skipped.get(i),
+                row_groups_skipped.get(i),
+                pages_skipped.get(i),
+            ) {
+                annotation.push(format!(
+                    "skipped files: {files}, row groups: {row_groups}, pages: {pages}"
+                ));
+            }
+
+            if i > 0 {
+                out.push('\n');
+            }
+            let mut lines = plan.lines();
+            out.push_str(lines.next().unwrap_or_default());
+            if !annotation.is_empty() {
+                write!(out, "  [{}]", annotation.join(", ")).unwrap();
+            }
+            for line in lines {
+                write!(out, "\n{line}").unwrap();
+            }
+        }
+        write!(out, "\n[engine: {engine}]").unwrap();
+        Ok(out)
+    }
+
+    /// Execute the query and return a [`DataFrame`] with one row per node of the optimized plan,
+    /// in the order in which [`LazyFrame::explain_analyze`] renders them.
+    ///
+    /// The number of output rows and the time of the first and last output of every node are
+    /// measured by a pass-through node that is inserted above it. The elapsed time of a node is
+    /// measured from the moment it could start until its last output. With the in-memory engine
+    /// a node starts when its last input is done; with the streaming engine nodes run
+    /// concurrently, and a node starts when the first of its inputs produces output. Leaf nodes
+    /// start when the query starts.
+    ///
+    /// Skipped files, row groups and pages are only counted for scans that have a predicate.
+    ///
+    /// [`Engine::Auto`] runs the query on the engine `collect` would pick, which is the in-memory
+    /// engine unless the new streaming engine is requested through `POLARS_AUTO_NEW_STREAMING`
+    /// or `POLARS_FORCE_NEW_STREAMING`. The `engine` column holds the engine that ran the query.
+    pub fn explain_analyze_df(mut self, mut engine: Engine) -> PolarsResult<DataFrame> {
+        polars_ensure!(
+            engine != Engine::Gpu,
+            InvalidOperation: "explain_analyze is not supported for the gpu engine"
+        );
+        if engine == Engine::Auto {
+            let new_streaming_requested =
+                ["POLARS_AUTO_NEW_STREAMING", "POLARS_FORCE_NEW_STREAMING"]
+                    .iter()
+                    .any(|var| std::env::var(var).as_deref() == Ok("1"));
+            engine = if cfg!(feature = "new_streaming") && new_streaming_requested {
+                Engine::Streaming
+            } else {
+                Engine::InMemory
+            };
+        }
+        if engine == Engine::Streaming {
+            feature_gated!("new_streaming", self = self.with_new_streaming(true))
+        }
+        if !matches!(self.logical_plan, DslPlan::Sink { .. }) {
+            self.logical_plan = DslPlan::Sink {
+                input: Arc::new(self.logical_plan),
+                payload: SinkType::Memory,
+            };
+        }
+
+        let mut plan = self.to_alp_optimized()?;
+        let root = match plan.lp_arena.get(plan.lp_top) {
+            IR::Sink {
+                input,
+                payload: SinkTypeIR::Memory,
+            } => *input,
+            _ => plan.lp_top,
+        };
+
+        let mut nodes = PlHashMap::new();
+        instrument(root, 0, &mut plan, &mut nodes);
+
+        let query_start = Instant::now();
+        execute_optimized(plan, engine)?;
+
+        let mut order = Vec::new();
+        collect_in_order(root, &nodes, &mut order);
+        analyzed_nodes_to_df(&order, &nodes, query_start, engine)
+    }
+}
+
+/// Put a counting node above every node, and format the nodes. This needs to happen before
+/// execution, as execution takes the nodes out of the arena.
+fn instrument(
+    node: Node,
+    depth: usize,
+    plan: &mut IRPlan,
+    nodes: &mut PlHashMap<Node, AnalyzedNode>,
+) {
+    if nodes.contains_key(&node) {
+        return;
+    }
+
+    let ir = plan.lp_arena.get(node);
+    let inputs = ir.inputs().collect::<Vec<_>>();
+
+    let mut description = String::new();
+    let output_schema = ir.schema(&plan.lp_arena);
+    write_ir_non_recursive(&mut description, ir, &plan.expr_arena, &output_schema, 0).unwrap();
+
+    let scan_metrics = if let IR::Scan { .. } = ir {
+        let IR::Scan {
+            unified_scan_args, ..
+        } = plan.lp_arena.get_mut(node)
+        else {
+            unreachable!()
+        };
+        let metrics = Arc::new(ScanMetrics::default());
+        unified_scan_args.scan_metrics = Some(metrics.clone());
+        Some(metrics)
+    } else {
+        None
+    };
+
+    let stats = match plan.lp_arena.get(node) {
+        IR::Cache { .. } | IR::Sink { .. } | IR::SinkMultiple { .. } | IR::Invalid => None,
+        _ => {
+            let stats = Arc::new(Mutex::new(NodeStats::default()));
+            let function = {
+                let stats = stats.clone();
+                move |df: DataFrame| {
+                    let now = Instant::now();
+                    let mut stats = stats.lock().unwrap();
+                    stats.rows_out += df.height() as u64;
+                    stats.first_output.get_or_insert(now);
+                    stats.last_output = Some(now);
+                    Ok(df)
+                }
+            };
+
+            let ir = plan.lp_arena.take(node);
+            let moved = plan.lp_arena.add(ir);
+            plan.lp_arena.replace(
+                node,
+                IR::MapFunction {
+                    input: moved,
+                    function: FunctionIR::Opaque {
+                        function: Arc::new(function),
+                        schema: None,
+                        predicate_pd: true,
+                        projection_pd: true,
+                        streamable: true,
+                        fmt_str: PlSmallStr::from_static("EXPLAIN ANALYZE"),
+                    },
+                },
+            );
+            Some(stats)
+        },
+    };
+
+    nodes.insert(
+        node,
+        AnalyzedNode {
+            depth,
+            description,
+            inputs: inputs.clone(),
+            stats,
+            scan_metrics,
+        },
+    );
+
+    for input in inputs {
+        instrument(input, depth + 1, plan, nodes);
+    }
+}
+
+fn collect_in_order(node: Node, nodes: &PlHashMap<Node, AnalyzedNode>, order: &mut Vec<Node>) {
+    order.push(node);
+    for input in &nodes[&node].inputs {
+        collect_in_order(*input, nodes, order);
+    }
+}
+
+/// The statistics of the output of `node`, looking through nodes that pass their input through.
+fn output_stats(node: Node, nodes: &PlHashMap<Node, AnalyzedNode>) -> Option<NodeStats> {
+    let analyzed = &nodes[&node];
+    match &analyzed.stats {
+        Some(stats) => {
+            let stats = stats.lock().unwrap();
+            Some(NodeStats {
+                rows_out: stats.rows_out,
+                first_output: stats.first_output,
+                last_output: stats.last_output,
+            })
+        },
+        None => match analyzed.inputs.as_slice() {
+            [input] => output_stats(*input, nodes),
+            _ => None,
+        },
+    }
+}
+
+fn analyzed_nodes_to_df(
+    order: &[Node],
+    nodes: &PlHashMap<Node, AnalyzedNode>,
+    query_start: Instant,
+    engine: Engine,
+) -> PolarsResult<DataFrame> {
+    let n = order.len();
+    let mut depth = Vec::with_capacity(n);
+    let mut plan = Vec::with_capacity(n);
+    let mut rows_in = Vec::with_capacity(n);
+    let mut rows_out = Vec::with_capacity(n);
+    let mut elapsed_us = Vec::with_capacity(n);
+    let mut files_skipped = Vec::with_capacity(n);
+    let mut row_groups_skipped = Vec::with_capacity(n);
+    let mut pages_skipped = Vec::with_capacity(n);
+
+    for node in order {
+        let analyzed = &nodes[node];
+        let stats = output_stats(*node, nodes);
+        let input_stats = analyzed
+            .inputs
+            .iter()
+            .map(|input| output_stats(*input, nodes))
+            .collect::<Option<Vec<_>>>();
+
+        let node_rows_in = input_stats
+            .as_ref()
+            .filter(|stats| !stats.is_empty())
+            .map(|stats| stats.iter().map(|s| s.rows_out).sum::<u64>());
+
+        let start = match input_stats.as_deref() {
+            Some([]) | None => Some(query_start),
+            Some(stats) if engine == Engine::Streaming => {
+                stats.iter().filter_map(|s| s.first_output).min()
+            },
+            Some(stats) => stats.iter().filter_map(|s| s.last_output).max(),
+        }
+        .unwrap_or(query_start);
+        let elapsed = stats
+            .as_ref()
+            .and_then(|s| s.last_output)
+            .map(|last| last.saturating_duration_since(start));
+
+        let indent = analyzed.depth * 2;
+        let description = analyzed
+            .description
+            .lines()
+            .map(|line| format!("{:indent$}{line}", ""))
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        depth.push(analyzed.depth as u32);
+        plan.push(description);
+        rows_in.push(node_rows_in);
+        rows_out.push(stats.as_ref().map(|s| s.rows_out));
+        elapsed_us.push(
+            elapsed
+                .as_ref()
+                .map(Duration::as_micros)
+                .map(|us| us as u64),
+        );
+        files_skipped.push(
+            analyzed
+                .scan_metrics
+                .as_ref()
+                .map(|m| m.files_skipped.load() as u64),
+        );
+        row_groups_skipped.push(
+            analyzed
+                .scan_metrics
+                .as_ref()
+                .map(|m| m.row_groups_skipped.load() as u64),
+        );
+        pages_skipped.push(
+            analyzed
+                .scan_metrics
+                .as_ref()
+                .map(|m| m.pages_skipped.load() as u64),
+        );
+    }
+
+    DataFrame::new(vec![
+        Column::new("engine".into(), vec![engine.into_static_str(); n]),
+        Column::new("depth".into(), depth),
+        Column::new("plan".into(), plan),
+        Column::new("rows_in".into(), rows_in),
+        Column::new("rows_out".into(), rows_out),
+        Column::new("elapsed_us".into(), elapsed_us),
+        Column::new("files_skipped".into(), files_skipped),
+        Column::new("row_groups_skipped".into(), row_groups_skipped),
+        Column::new("pages_skipped".into(), pages_skipped),
+    ])
+}
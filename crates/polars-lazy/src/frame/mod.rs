@@ -6,8 +6,11 @@ mod cached_arenas;
 mod err;
 #[cfg(not(target_arch = "wasm32"))]
 mod exitable;
+mod explain_analyze;
 #[cfg(feature = "pivot")]
 pub mod pivot;
+#[cfg(feature = "cse")]
+mod result_cache;
 
 use std::num::NonZeroUsize;
 use std::sync::{Arc, Mutex};
@@ -18,10 +21,14 @@ pub use csv::*;
 #[cfg(not(target_arch = "wasm32"))]
 pub use exitable::*;
 pub use file_list_reader::*;
+#[cfg(feature = "fwf")]
+pub use fwf::*;
 #[cfg(feature = "ipc")]
 pub use ipc::*;
 #[cfg(feature = "json")]
 pub use ndjson::*;
+#[cfg(feature = "orc")]
+pub use orc::*;
 #[cfg(feature = "parquet")]
 pub use parquet::*;
 use polars_compute::rolling::QuantileMethod;
@@ -38,6 +45,8 @@ pub use polars_plan::frame::{AllowedOptimizations, OptFlags};
 use polars_utils::pl_str::PlSmallStr;
 use polars_utils::plpath::PlPath;
 use rayon::iter::{IndexedParallelIterator, IntoParallelIterator, ParallelIterator};
+#[cfg(feature = "cse")]
+pub use result_cache::ResultCache;
 
 use crate::frame::cached_arenas::CachedArena;
 use crate::prelude::*;
@@ -206,6 +215,21 @@ impl LazyFrame {
         self
     }
 
+    /// Execute joins and group-bys on hive-partitioned scans partition-by-partition if their keys
+    /// include the partition columns. This is off by default.
+    pub fn with_partition_wise(mut self, toggle: bool) -> Self {
+        self.opt_state.set(OptFlags::PARTITION_WISE, toggle);
+        self
+    }
+
+    /// Reuse the results of cached subplans from the session-level [`ResultCache`], and add the
+    /// results of cached subplans that are not in it yet.
+    #[cfg(feature = "cse")]
+    pub fn with_result_cache(mut self, toggle: bool) -> Self {
+        self.opt_state.set(OptFlags::RESULT_CACHE, toggle);
+        self
+    }
+
     /// Run every node eagerly. This turns off multi-node optimizations.
     pub fn _with_eager(mut self, toggle: bool) -> Self {
         self.opt_state.set(OptFlags::EAGER, toggle);
@@ -681,31 +705,16 @@ impl LazyFrame {
             },
             _ => {},
         }
+        #[cfg(feature = "cse")]
+        let use_result_cache = self.opt_state.contains(OptFlags::RESULT_CACHE);
+        #[cfg_attr(not(feature = "cse"), allow(unused_mut))]
         let mut alp_plan = self.clone().to_alp_optimized()?;
-
-        match engine {
-            Engine::Auto | Engine::Streaming => feature_gated!("new_streaming", {
-                let result = polars_stream::run_query(
-                    alp_plan.lp_top,
-                    &mut alp_plan.lp_arena,
-                    &mut alp_plan.expr_arena,
-                );
-                result.map(|v| v.unwrap_single())
-            }),
-            Engine::Gpu => {
-                Err(polars_err!(InvalidOperation: "sink is not supported for the gpu engine"))
-            },
-            Engine::InMemory => {
-                let mut physical_plan = create_physical_plan(
-                    alp_plan.lp_top,
-                    &mut alp_plan.lp_arena,
-                    &mut alp_plan.expr_arena,
-                    BUILD_STREAMING_EXECUTOR,
-                )?;
-                let mut state = ExecutionState::new();
-                physical_plan.execute(&mut state)
-            },
+        #[cfg(feature = "cse")]
+        if use_result_cache {
+            result_cache::apply_result_cache(&mut alp_plan, engine)?;
         }
+
+        execute_optimized(alp_plan, engine)
     }
 
     pub fn explain_all(plans: Vec<DslPlan>, opt_state: OptFlags) -> PolarsResult<String> {
@@ -910,6 +919,25 @@ impl LazyFrame {
         }))
     }
 
+    /// Stream a query result into an ORC file. This is useful if the final result doesn't fit
+    /// into memory. This methods will return an error if the query cannot be completely done in a
+    /// streaming fashion.
+    #[cfg(feature = "orc")]
+    pub fn sink_orc(
+        self,
+        target: SinkTarget,
+        options: OrcWriterOptions,
+        cloud_options: Option<polars_io::cloud::CloudOptions>,
+        sink_options: SinkOptions,
+    ) -> PolarsResult<Self> {
+        self.sink(SinkType::File(FileSinkType {
+            target,
+            sink_options,
+            file_type: FileType::Orc(options),
+            cloud_options,
+        }))
+    }
+
     /// Stream a query result into an csv file. This is useful if the final result doesn't fit
     /// into memory. This methods will return an error if the query cannot be completely done in a
     /// streaming fashion.
@@ -1272,6 +1300,7 @@ impl LazyFrame {
                 maintain_order: false,
                 dynamic_options: None,
                 rolling_options: None,
+                session_options: None,
             }
         }
 
@@ -1320,6 +1349,7 @@ impl LazyFrame {
             maintain_order: true,
             dynamic_options: None,
             rolling_options: Some(options),
+            session_options: None,
         }
     }
 
@@ -1365,6 +1395,46 @@ impl LazyFrame {
             maintain_order: true,
             dynamic_options: Some(options),
             rolling_options: None,
+            session_options: None,
+        }
+    }
+
+    /// Group based on sessions of a time value (or index value of type Int32, Int64).
+    ///
+    /// A session is a run of rows where every row is at most `gap` after the previous row.
+    /// Every row is a member of exactly one session, which starts at its first value and ends
+    /// `gap` after its last value.
+    ///
+    /// The `group_by` argument should be empty `[]` if you don't want to combine this
+    /// with a ordinary group_by on these keys.
+    #[cfg(feature = "dynamic_group_by")]
+    pub fn group_by_session<E: AsRef<[Expr]>>(
+        mut self,
+        index_column: Expr,
+        group_by: E,
+        mut options: SessionGroupOptions,
+    ) -> LazyGroupBy {
+        if let Expr::Column(name) = index_column {
+            options.index_column = name;
+        } else {
+            let output_field = index_column
+                .to_field(&self.collect_schema().unwrap())
+                .unwrap();
+            return self.with_column(index_column).group_by_session(
+                Expr::Column(output_field.name().clone()),
+                group_by,
+                options,
+            );
+        }
+        let opt_state = self.get_opt_state();
+        LazyGroupBy {
+            logical_plan: self.logical_plan,
+            opt_state,
+            keys: group_by.as_ref().to_vec(),
+            maintain_order: true,
+            dynamic_options: None,
+            rolling_options: None,
+            session_options: Some(options),
         }
     }
 
@@ -1386,6 +1456,7 @@ impl LazyFrame {
                 maintain_order: true,
                 dynamic_options: None,
                 rolling_options: None,
+                session_options: None,
             }
         }
 
@@ -1437,6 +1508,81 @@ impl LazyFrame {
         )
     }
 
+    /// Join this query with another lazy query on approximately equal string keys.
+    ///
+    /// Every pair of rows whose keys are within the threshold of the [`FuzzyJoinMetric`] is
+    /// matched. Both key columns are kept in the output.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use polars_core::prelude::*;
+    /// use polars_lazy::prelude::*;
+    /// fn fuzzy_join_dataframes(ldf: LazyFrame, other: LazyFrame) -> LazyFrame {
+    ///         ldf
+    ///         .fuzzy_join(
+    ///             other,
+    ///             col("name"),
+    ///             col("name"),
+    ///             FuzzyJoinOptions::new(FuzzyJoinMetric::Levenshtein { max_distance: 2 })
+    ///                 .with_score_name(Some("distance".into())),
+    ///         )
+    /// }
+    /// ```
+    #[cfg(feature = "fuzzy_join")]
+    pub fn fuzzy_join<E: Into<Expr>>(
+        self,
+        other: LazyFrame,
+        left_on: E,
+        right_on: E,
+        options: FuzzyJoinOptions,
+    ) -> LazyFrame {
+        self.join(
+            other,
+            [left_on.into()],
+            [right_on.into()],
+            JoinArgs::new(JoinType::Fuzzy(Box::new(options))),
+        )
+    }
+
+    /// Join every row of this query to its `k` nearest neighbours in another lazy query.
+    ///
+    /// The keys must be `Array` columns of equal width holding the vectors. The search is exact,
+    /// the matches of every left row are ordered from nearest to farthest. Rows whose key is null
+    /// or contains a null are not matched.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use polars_core::prelude::*;
+    /// use polars_lazy::prelude::*;
+    /// fn knn_join_dataframes(ldf: LazyFrame, other: LazyFrame) -> LazyFrame {
+    ///         ldf
+    ///         .knn_join(
+    ///             other,
+    ///             col("embedding"),
+    ///             col("embedding"),
+    ///             KnnJoinOptions::new(5, KnnJoinMetric::Cosine)
+    ///                 .with_score_name(Some("similarity".into())),
+    ///         )
+    /// }
+    /// ```
+    #[cfg(feature = "knn_join")]
+    pub fn knn_join<E: Into<Expr>>(
+        self,
+        other: LazyFrame,
+        left_on: E,
+        right_on: E,
+        options: KnnJoinOptions,
+    ) -> LazyFrame {
+        self.join(
+            other,
+            [left_on.into()],
+            [right_on.into()],
+            JoinArgs::new(JoinType::Knn(Box::new(options))),
+        )
+    }
+
     /// Left outer join this query with another lazy query.
     ///
     /// Matches on the values of the expressions `left_on` and `right_on`. For more
@@ -1993,6 +2139,33 @@ impl LazyFrame {
         Self::from_logical_plan(lp, opt_state)
     }
 
+    /// Apply a function over the logical plan which is looked up by `name` in the
+    /// [`PlanRegistry`](polars_plan::dsl::named_serde::PlanRegistry). Unlike [`LazyFrame::map`],
+    /// plans with named functions can be serialized.
+    ///
+    /// The output `schema` is the input schema if it is `None`.
+    pub fn map_named(
+        self,
+        name: PlSmallStr,
+        payload: Vec<u8>,
+        optimizations: AllowedOptimizations,
+        schema: Option<SchemaRef>,
+    ) -> LazyFrame {
+        let opt_state = self.get_opt_state();
+        let lp = self
+            .get_plan_builder()
+            .map_private(DslFunction::Named(NamedUdf {
+                name,
+                payload,
+                schema,
+                predicate_pd: optimizations.contains(OptFlags::PREDICATE_PUSHDOWN),
+                projection_pd: optimizations.contains(OptFlags::PROJECTION_PUSHDOWN),
+                streamable: optimizations.contains(OptFlags::NEW_STREAMING),
+            }))
+            .build();
+        Self::from_logical_plan(lp, opt_state)
+    }
+
     #[cfg(feature = "python")]
     pub fn map_python(
         self,
@@ -2099,6 +2272,35 @@ pub struct LazyGroupBy {
     dynamic_options: Option<DynamicGroupOptions>,
     #[cfg(feature = "dynamic_group_by")]
     rolling_options: Option<RollingGroupOptions>,
+    #[cfg(feature = "dynamic_group_by")]
+    session_options: Option<SessionGroupOptions>,
+}
+
+/// Execute an optimized plan on the given engine.
+fn execute_optimized(mut alp_plan: IRPlan, engine: Engine) -> PolarsResult<DataFrame> {
+    match engine {
+        Engine::Auto | Engine::Streaming => feature_gated!("new_streaming", {
+            let result = polars_stream::run_query(
+                alp_plan.lp_top,
+                &mut alp_plan.lp_arena,
+                &mut alp_plan.expr_arena,
+            );
+            result.map(|v| v.unwrap_single())
+        }),
+        Engine::Gpu => {
+            Err(polars_err!(InvalidOperation: "sink is not supported for the gpu engine"))
+        },
+        Engine::InMemory => {
+            let mut physical_plan = create_physical_plan(
+                alp_plan.lp_top,
+                &mut alp_plan.lp_arena,
+                &mut alp_plan.expr_arena,
+                BUILD_STREAMING_EXECUTOR,
+            )?;
+            let mut state = ExecutionState::new();
+            physical_plan.execute(&mut state)
+        },
+    }
 }
 
 impl From<LazyGroupBy> for LazyFrame {
@@ -2143,6 +2345,7 @@ impl LazyGroupBy {
                 self.maintain_order,
                 self.dynamic_options,
                 self.rolling_options,
+                self.session_options,
             )
             .build();
 
@@ -2186,6 +2389,7 @@ impl LazyGroupBy {
         let options = GroupbyOptions {
             dynamic: self.dynamic_options,
             rolling: self.rolling_options,
+            session: self.session_options,
             slice: None,
         };
 
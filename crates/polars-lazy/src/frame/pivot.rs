@@ -1,16 +1,21 @@
 //! Module containing implementation of the pivot operation.
 //!
-//! Polars lazy does not implement a pivot because it is impossible to know the schema without
+//! In general, a lazy pivot is impossible because the schema cannot be known without
 //! materializing the whole dataset. This makes a pivot quite a terrible operation for performant
 //! workflows. An optimization can never be pushed down past a pivot.
 //!
 //! We can do a pivot on an eager `DataFrame` as that is already materialized. The code for the
 //! pivot is here, because we want to be able to pass expressions to the pivot operation.
 //!
+//! If the values of the `on` column are declared upfront, the schema is known and
+//! [`LazyFrame::pivot`] lowers the pivot to a group-by with one conditional aggregation per
+//! output column. That plan is optimized and can run on the streaming engine like any other.
+//!
 
 use polars_core::frame::group_by::expr::PhysicalAggExpr;
 use polars_core::prelude::*;
 use polars_ops::pivot::PivotAgg;
+use polars_utils::format_pl_smallstr;
 
 use crate::physical_plan::exotic::{contains_column_refs, prepare_expression_for_context};
 use crate::prelude::*;
@@ -98,3 +103,132 @@ where
     let agg_expr = agg_expr.map(|ae| PivotAgg(Arc::new(PivotExpr(ae))));
     polars_ops::pivot::pivot_stable(df, on, index, values, sort_columns, agg_expr, separator)
 }
+
+impl LazyFrame {
+    /// Pivot a [`LazyFrame`] with the values of the `on` column declared upfront.
+    ///
+    /// The output has a column for every value in `on_values`, in that order. If `on_values` is
+    /// `None`, the `on` column must be of the `Enum` type and its categories are used. Values of
+    /// `on` that are not declared are ignored.
+    ///
+    /// The pivot is lowered to a group-by on `index` with conditional aggregations, so the
+    /// output schema is known at plan time. `agg_expr` can only refer to the pivoted values
+    /// through `col("")`; if it is `None`, every cell must hold at most one value, as in the eager
+    /// [`pivot`].
+    pub fn pivot<I1, I2, S1, S2>(
+        mut self,
+        on: impl Into<PlSmallStr>,
+        on_values: Option<Series>,
+        index: Option<I1>,
+        values: Option<I2>,
+        agg_expr: Option<Expr>,
+        // used as separator/delimiter in generated column names.
+        separator: Option<&str>,
+    ) -> PolarsResult<LazyFrame>
+    where
+        I1: IntoIterator<Item = S1>,
+        I2: IntoIterator<Item = S2>,
+        S1: Into<PlSmallStr>,
+        S2: Into<PlSmallStr>,
+    {
+        if agg_expr.as_ref().is_some_and(contains_column_refs) {
+            polars_bail!(InvalidOperation: "explicit column references are not allowed in aggregate_function");
+        }
+
+        let on = on.into();
+        let schema = self.collect_schema()?;
+        let on_dtype = schema.try_get(&on)?;
+
+        let on_values = match on_values {
+            Some(on_values) => on_values.strict_cast(on_dtype)?,
+            #[cfg(feature = "dtype-categorical")]
+            None if on_dtype.is_enum() => {
+                let DataType::Enum(fcats, _) = on_dtype else {
+                    unreachable!()
+                };
+                Series::from_array(on.clone(), fcats.categories().clone()).strict_cast(on_dtype)?
+            },
+            None => polars_bail!(
+                InvalidOperation:
+                "lazy pivot requires the `on` values to be declared, or `on` to be of the Enum type; got {}",
+                on_dtype
+            ),
+        };
+        polars_ensure!(
+            on_values.n_unique()? == on_values.len(),
+            InvalidOperation: "the declared `on` values of a lazy pivot must be unique"
+        );
+
+        let remaining = |exclude: &[PlSmallStr]| -> Vec<PlSmallStr> {
+            schema
+                .iter_names()
+                .filter(|c| **c != on && !exclude.contains(c))
+                .cloned()
+                .collect()
+        };
+        let (index, values) = match (index, values) {
+            (Some(index), Some(values)) => (
+                index.into_iter().map(Into::into).collect(),
+                values.into_iter().map(Into::into).collect(),
+            ),
+            (Some(index), None) => {
+                let index: Vec<PlSmallStr> = index.into_iter().map(Into::into).collect();
+                let values = remaining(&index);
+                (index, values)
+            },
+            (None, Some(values)) => {
+                let values: Vec<PlSmallStr> = values.into_iter().map(Into::into).collect();
+                let index = remaining(&values);
+                (index, values)
+            },
+            (None, None) => {
+                polars_bail!(InvalidOperation: "`index` and `values` cannot both be None in `pivot` operation")
+            },
+        };
+        polars_ensure!(!index.is_empty(), ComputeError: "index cannot be zero length");
+
+        let sep = separator.unwrap_or("_");
+        let headers = on_values.cast(&DataType::String)?;
+        let headers = headers.str()?;
+
+        let mut aggs = Vec::with_capacity(values.len() * on_values.len());
+        for value in &values {
+            for (i, header) in headers.iter().enumerate() {
+                let on_value = Scalar::new(on_dtype.clone(), on_values.get(i)?.into_static());
+                let cell = col(value.clone()).filter(col(on.clone()).eq_missing(lit(on_value)));
+
+                let agg = match &agg_expr {
+                    None => cell.clone().first().map_many(
+                        |c| {
+                            let max_len = c[1].as_materialized_series().idx()?.max();
+                            polars_ensure!(
+                                max_len.is_none_or(|len| len <= 1),
+                                ComputeError:
+                                "found multiple elements in the same group, \
+                                please specify an aggregation function"
+                            );
+                            Ok(std::mem::take(&mut c[0]))
+                        },
+                        &[cell.len()],
+                        |_, fields| Ok(fields[0].clone()),
+                    ),
+                    Some(agg_expr) => agg_expr.clone().map_expr(|e| match e {
+                        Expr::Column(c) if c.is_empty() => cell.clone(),
+                        e => e,
+                    }),
+                };
+
+                let header = header.unwrap_or("null");
+                let name = if values.len() > 1 {
+                    format_pl_smallstr!("{value}{sep}{header}")
+                } else {
+                    PlSmallStr::from_str(header)
+                };
+                aggs.push(agg.alias(name));
+            }
+        }
+
+        let index = index.into_iter().map(col).collect::<Vec<_>>();
+        Ok(self.group_by_stable(index).agg(aggs))
+    }
+}
@@ -0,0 +1,423 @@
+//! A session-level cache of the results of cached subplans, which is shared by all queries.
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+use std::sync::{LazyLock, RwLock};
+
+use polars_core::config;
+use polars_core::error::feature_gated;
+use polars_core::prelude::*;
+use polars_mem_engine::create_physical_plan;
+use polars_plan::plans::prune::prune_plan;
+use polars_plan::plans::visitor::{hash_subplan, subplans_equal};
+use polars_utils::unique_id::UniqueId;
+
+use crate::prelude::*;
+
+static RESULT_CACHE: LazyLock<ResultCache> = LazyLock::new(ResultCache::default);
+
+/// The default maximum number of cached results.
+const DEFAULT_CAPACITY: usize = 32;
+
+enum CachedResult {
+    InMemory(Arc<DataFrame>),
+    #[cfg_attr(not(feature = "ipc"), allow(dead_code))]
+    Spilled(PathBuf),
+}
+
+/// The arenas of the query a result was computed in.
+type Arenas = (Arena<IR>, Arena<AExpr>);
+
+struct CacheEntry {
+    result: CachedResult,
+    /// A pruned copy of the subplan the result was computed from, without the rest of the query.
+    /// It is compared with the subplan of a lookup to rule out hash collisions, and it keeps the
+    /// data frames and functions which are compared by their address alive, so that their
+    /// addresses can't be reused by other data.
+    subplan: IRPlan,
+    last_used: AtomicU64,
+}
+
+/// Caches the results of the cached subplans of queries, so that subsequent queries which share
+/// these subplans don't recompute them.
+///
+/// Subplans are cached with [`LazyFrame::cache`] or by common subplan elimination. They are keyed
+/// on the hash of their optimized plan and a result is only reused if the subplans are equal, of
+/// which in-memory data and user-defined functions are compared by their address. Subplans with
+/// anonymous functions in their expressions are never equal. Results are not invalidated when
+/// the underlying data changes, this must be done explicitly with [`ResultCache::invalidate`] or
+/// [`ResultCache::clear`]. If the cache holds more results than its capacity, the least recently
+/// used results are evicted.
+///
+/// Queries only use the result cache if it is enabled with [`LazyFrame::with_result_cache`].
+pub struct ResultCache {
+    entries: RwLock<PlHashMap<u64, CacheEntry>>,
+    spill_dir: RwLock<Option<PathBuf>>,
+    capacity: AtomicUsize,
+    clock: AtomicU64,
+}
+
+impl Default for ResultCache {
+    fn default() -> Self {
+        Self {
+            entries: Default::default(),
+            spill_dir: Default::default(),
+            capacity: AtomicUsize::new(DEFAULT_CAPACITY),
+            clock: AtomicU64::new(0),
+        }
+    }
+}
+
+impl ResultCache {
+    /// The result cache of this session.
+    pub fn global() -> &'static ResultCache {
+        &RESULT_CACHE
+    }
+
+    /// Set the directory to which new results are spilled as IPC files instead of keeping them
+    /// in memory.
+    #[cfg(feature = "ipc")]
+    pub fn set_spill_dir(&self, dir: Option<PathBuf>) {
+        *self.spill_dir.write().unwrap() = dir;
+    }
+
+    /// Set the maximum number of cached results, evicting the least recently used results if
+    /// there are more.
+    pub fn set_capacity(&self, capacity: usize) {
+        self.capacity.store(capacity, Ordering::Relaxed);
+        self.evict(&mut self.entries.write().unwrap());
+    }
+
+    /// The maximum number of cached results.
+    pub fn capacity(&self) -> usize {
+        self.capacity.load(Ordering::Relaxed)
+    }
+
+    /// The number of cached results.
+    pub fn len(&self) -> usize {
+        self.entries.read().unwrap().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Remove all cached results.
+    pub fn clear(&self) {
+        let entries = std::mem::take(&mut *self.entries.write().unwrap());
+        for entry in entries.into_values() {
+            remove_entry(entry.result);
+        }
+    }
+
+    /// Remove the cached results of the cached subplans of `lf`. Returns the number of removed
+    /// results.
+    pub fn invalidate(&self, lf: LazyFrame) -> PolarsResult<usize> {
+        let plan = lf.to_alp_optimized()?;
+        let keys = cache_keys(plan.lp_top, &plan.lp_arena, &plan.expr_arena);
+        let arenas = (plan.lp_arena, plan.expr_arena);
+
+        let mut entries = self.entries.write().unwrap();
+        let mut n_removed = 0;
+        for &(key, node) in keys.values() {
+            if entries
+                .get(&key)
+                .is_some_and(|entry| entry.computed_from(node, &arenas))
+            {
+                remove_entry(entries.remove(&key).unwrap().result);
+                n_removed += 1;
+            }
+        }
+        Ok(n_removed)
+    }
+
+    /// The cached result of the subplan rooted at `node`, which hashes to `key`.
+    fn get(&self, key: u64, node: Node, arenas: &Arenas) -> PolarsResult<Option<Arc<DataFrame>>> {
+        let entries = self.entries.read().unwrap();
+        let Some(entry) = entries
+            .get(&key)
+            .filter(|entry| entry.computed_from(node, arenas))
+        else {
+            return Ok(None);
+        };
+        entry.last_used.store(
+            self.clock.fetch_add(1, Ordering::Relaxed),
+            Ordering::Relaxed,
+        );
+        match &entry.result {
+            CachedResult::InMemory(df) => Ok(Some(df.clone())),
+            #[cfg(feature = "ipc")]
+            CachedResult::Spilled(path) => {
+                use polars_io::SerReader;
+                use polars_io::ipc::IpcReader;
+
+                let file = polars_utils::open_file(path)?;
+                Ok(Some(Arc::new(IpcReader::new(file).finish()?)))
+            },
+            #[cfg(not(feature = "ipc"))]
+            CachedResult::Spilled(_) => unreachable!(),
+        }
+    }
+
+    fn insert(&self, key: u64, node: Node, arenas: &Arenas, df: Arc<DataFrame>) -> PolarsResult<()> {
+        let spill_dir = self.spill_dir.read().unwrap().clone();
+        let entry = match spill_dir {
+            None => CachedResult::InMemory(df),
+            #[cfg(feature = "ipc")]
+            Some(dir) => {
+                use polars_io::SerWriter;
+                use polars_io::ipc::IpcWriter;
+
+                let path = dir.join(format!("polars-result-cache-{key:016x}.ipc"));
+                let file = polars_utils::create_file(&path)?;
+                IpcWriter::new(file).finish(&mut (*df).clone())?;
+                CachedResult::Spilled(path)
+            },
+            #[cfg(not(feature = "ipc"))]
+            Some(_) => unreachable!(),
+        };
+
+        let entry = CacheEntry {
+            result: entry,
+            subplan: prune_plan(IRPlanRef {
+                lp_top: node,
+                lp_arena: &arenas.0,
+                expr_arena: &arenas.1,
+            }),
+            last_used: AtomicU64::new(self.clock.fetch_add(1, Ordering::Relaxed)),
+        };
+
+        let mut entries = self.entries.write().unwrap();
+        let new_path = match &entry.result {
+            CachedResult::Spilled(path) => Some(path.clone()),
+            CachedResult::InMemory(_) => None,
+        };
+        if let Some(old) = entries.insert(key, entry) {
+            // A previous result of the same key that was spilled to the same file has just been
+            // overwritten by the new result.
+            match old.result {
+                CachedResult::Spilled(path) if Some(&path) == new_path.as_ref() => {},
+                old => remove_entry(old),
+            }
+        }
+        self.evict(&mut entries);
+        Ok(())
+    }
+
+    fn evict(&self, entries: &mut PlHashMap<u64, CacheEntry>) {
+        while entries.len() > self.capacity() {
+            let key = *entries
+                .iter()
+                .min_by_key(|(_, entry)| entry.last_used.load(Ordering::Relaxed))
+                .unwrap()
+                .0;
+            if config::verbose() {
+                eprintln!("evicting result of subplan {key:016x} from result cache");
+            }
+            remove_entry(entries.remove(&key).unwrap().result);
+        }
+    }
+}
+
+impl CacheEntry {
+    fn computed_from(&self, node: Node, arenas: &Arenas) -> bool {
+        subplans_equal(
+            self.subplan.lp_top,
+            (&self.subplan.lp_arena, &self.subplan.expr_arena),
+            node,
+            (&arenas.0, &arenas.1),
+        )
+    }
+}
+
+fn remove_entry(entry: CachedResult) {
+    if let CachedResult::Spilled(path) = entry {
+        let _ = std::fs::remove_file(path);
+    }
+}
+
+/// Computes the key and the input of every cache node reachable from `root`.
+fn cache_keys(
+    root: Node,
+    lp_arena: &Arena<IR>,
+    expr_arena: &Arena<AExpr>,
+) -> PlHashMap<UniqueId, (u64, Node)> {
+    let mut keys = PlHashMap::default();
+    let mut stack = vec![root];
+    while let Some(node) = stack.pop() {
+        let ir = lp_arena.get(node);
+        if let IR::Cache { input, id } = ir {
+            if keys.contains_key(id) {
+                continue;
+            }
+            keys.insert(*id, (hash_subplan(*input, lp_arena, expr_arena), *input));
+        }
+        ir.copy_inputs(&mut stack);
+    }
+    keys
+}
+
+/// Replaces the cache nodes of the plan by their results from the result cache. The results of
+/// cache nodes that are not in the result cache are computed with `engine` and added to it.
+pub(crate) fn apply_result_cache(plan: &mut IRPlan, engine: Engine) -> PolarsResult<()> {
+    let keys = cache_keys(plan.lp_top, &plan.lp_arena, &plan.expr_arena);
+    if keys.is_empty() {
+        return Ok(());
+    }
+
+    // Resolving a cache replaces the caches nested in it, so the subplans are looked up and stored
+    // as they were before any cache was resolved.
+    let original = (plan.lp_arena.clone(), plan.expr_arena.clone());
+    let mut resolved = PlHashMap::default();
+    resolve_caches(plan.lp_top, plan, &original, &keys, &mut resolved, engine)
+}
+
+fn resolve_caches(
+    node: Node,
+    plan: &mut IRPlan,
+    original: &Arenas,
+    keys: &PlHashMap<UniqueId, (u64, Node)>,
+    resolved: &mut PlHashMap<UniqueId, Arc<DataFrame>>,
+    engine: Engine,
+) -> PolarsResult<()> {
+    let IR::Cache { input, id } = plan.lp_arena.get(node) else {
+        let inputs = plan.lp_arena.get(node).get_inputs();
+        for input in inputs {
+            resolve_caches(input, plan, original, keys, resolved, engine)?;
+        }
+        return Ok(());
+    };
+    let (input, id) = (*input, *id);
+    let (key, original_input) = keys[&id];
+    let result_cache = ResultCache::global();
+
+    let df = match resolved.get(&id) {
+        Some(df) => df.clone(),
+        None => match result_cache.get(key, original_input, original)? {
+            Some(df) => {
+                if config::verbose() {
+                    eprintln!("result cache hit for subplan {key:016x}");
+                }
+                df
+            },
+            None => {
+                // Resolve nested caches first so that they can be reused.
+                resolve_caches(input, plan, original, keys, resolved, engine)?;
+                let df = Arc::new(execute_subplan(input, plan, engine)?);
+                if config::verbose() {
+                    eprintln!("storing result of subplan {key:016x} in result cache");
+                }
+                result_cache.insert(key, original_input, original, df.clone())?;
+                df
+            },
+        },
+    };
+    resolved.insert(id, df.clone());
+
+    let schema = plan.lp_arena.get(input).schema(&plan.lp_arena).into_owned();
+    plan.lp_arena.replace(
+        node,
+        IR::DataFrameScan {
+            df,
+            schema,
+            output_schema: None,
+        },
+    );
+    Ok(())
+}
+
+fn execute_subplan(node: Node, plan: &IRPlan, engine: Engine) -> PolarsResult<DataFrame> {
+    let mut lp_arena = plan.lp_arena.clone();
+    let mut expr_arena = plan.expr_arena.clone();
+
+    match engine {
+        Engine::Streaming => feature_gated!("new_streaming", {
+            let sink = lp_arena.add(IR::Sink {
+                input: node,
+                payload: SinkTypeIR::Memory,
+            });
+            polars_stream::run_query(sink, &mut lp_arena, &mut expr_arena)
+                .map(|v| v.unwrap_single())
+        }),
+        _ => {
+            let mut physical_plan = create_physical_plan(
+                node,
+                &mut lp_arena,
+                &mut expr_arena,
+                BUILD_STREAMING_EXECUTOR,
+            )?;
+            let mut state = ExecutionState::new();
+            physical_plan.execute(&mut state)
+        },
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn subplan(lf: LazyFrame) -> PolarsResult<(Node, Arenas)> {
+        let plan = lf.to_alp_optimized()?;
+        Ok((plan.lp_top, (plan.lp_arena, plan.expr_arena)))
+    }
+
+    #[test]
+    fn test_result_cache_verify_and_evict() -> PolarsResult<()> {
+        let lf = df!["a" => [1, 2, 3]]?.lazy();
+        let (node_1, arenas_1) = subplan(lf.clone().select([col("a") + lit(1)]))?;
+        let (node_2, arenas_2) = subplan(lf.clone().select([col("a") * lit(2)]))?;
+        let (node_3, arenas_3) = subplan(lf.filter(col("a").gt(lit(1))))?;
+        let df = Arc::new(df!["a" => [1]]?);
+
+        let cache = ResultCache::default();
+        cache.set_capacity(2);
+        cache.insert(1, node_1, &arenas_1, df.clone())?;
+        cache.insert(2, node_2, &arenas_2, df.clone())?;
+        assert!(cache.get(1, node_1, &arenas_1)?.is_some());
+        // A different plan with the same hash doesn't use the result.
+        assert!(cache.get(2, node_3, &arenas_3)?.is_none());
+
+        // The least recently used result is evicted.
+        cache.insert(3, node_3, &arenas_3, df)?;
+        assert_eq!(cache.len(), 2);
+        assert!(cache.get(2, node_2, &arenas_2)?.is_none());
+        assert!(cache.get(1, node_1, &arenas_1)?.is_some());
+        assert!(cache.get(3, node_3, &arenas_3)?.is_some());
+
+        cache.set_capacity(1);
+        assert_eq!(cache.len(), 1);
+        assert!(cache.get(3, node_3, &arenas_3)?.is_some());
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "ipc")]
+    fn test_result_cache_replace_spilled() -> PolarsResult<()> {
+        let (node, arenas) = subplan(df!["a" => [1, 2, 3]]?.lazy())?;
+        let df = Arc::new(df!["a" => [1]]?);
+        let dir = std::env::temp_dir().join(format!("polars-result-cache-{}", std::process::id()));
+        let (dir_1, dir_2) = (dir.join("1"), dir.join("2"));
+        std::fs::create_dir_all(&dir_1)?;
+        std::fs::create_dir_all(&dir_2)?;
+        let file_name = format!("polars-result-cache-{:016x}.ipc", 1);
+
+        let cache = ResultCache::default();
+        cache.set_spill_dir(Some(dir_1.clone()));
+        cache.insert(1, node, &arenas, df.clone())?;
+        // Replacing a result with one spilled to the same file keeps the file.
+        cache.insert(1, node, &arenas, df.clone())?;
+        assert!(dir_1.join(&file_name).exists());
+
+        cache.set_spill_dir(Some(dir_2.clone()));
+        cache.insert(1, node, &arenas, df.clone())?;
+        assert!(!dir_1.join(&file_name).exists());
+        assert!(dir_2.join(&file_name).exists());
+
+        cache.set_spill_dir(None);
+        cache.insert(1, node, &arenas, df)?;
+        assert!(!dir_2.join(&file_name).exists());
+        assert!(cache.get(1, node, &arenas)?.is_some());
+
+        std::fs::remove_dir_all(dir)?;
+        Ok(())
+    }
+}
@@ -5,9 +5,17 @@ pub use polars_io::csv::write::CsvWriterOptions;
 pub use polars_io::ipc::IpcWriterOptions;
 #[cfg(feature = "json")]
 pub use polars_io::json::JsonWriterOptions;
+#[cfg(feature = "orc")]
+pub use polars_io::orc::OrcWriterOptions;
 #[cfg(feature = "parquet")]
 pub use polars_io::parquet::write::ParquetWriteOptions;
+#[cfg(feature = "fuzzy_join")]
+pub use polars_ops::prelude::{FuzzyJoinMetric, FuzzyJoinOptions};
+#[cfg(feature = "stable_hash")]
+pub use polars_ops::prelude::{HashAlgorithm, SPARK_HASH_SEED};
 pub use polars_ops::prelude::{JoinArgs, JoinType, JoinValidation};
+#[cfg(feature = "knn_join")]
+pub use polars_ops::prelude::{KnnJoinMetric, KnnJoinOptions};
 #[cfg(feature = "rank")]
 pub use polars_ops::prelude::{RankMethod, RankOptions};
 #[cfg(feature = "polars_cloud_client")]
@@ -19,7 +27,9 @@ pub use polars_plan::prelude::{PlanCallback, UnionArgs};
 #[cfg(feature = "rolling_window_by")]
 pub use polars_time::Duration;
 #[cfg(feature = "dynamic_group_by")]
-pub use polars_time::{DynamicGroupOptions, PolarsTemporalGroupby, RollingGroupOptions};
+pub use polars_time::{
+    DynamicGroupOptions, PolarsTemporalGroupby, RollingGroupOptions, SessionGroupOptions,
+};
 pub(crate) use polars_utils::arena::{Arena, Node};
 
 pub use crate::dsl::*;
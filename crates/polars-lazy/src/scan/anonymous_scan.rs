@@ -42,26 +42,7 @@ impl LazyFrame {
                 skip_rows: args.skip_rows,
                 fmt_str: args.name,
             },
-            UnifiedScanArgs {
-                schema: Some(schema),
-                cloud_options: None,
-                hive_options: HiveOptions::new_disabled(),
-                rechunk: false,
-                cache: false,
-                glob: false,
-                hidden_file_prefix: None,
-                projection: None,
-                column_mapping: None,
-                default_values: None,
-                row_index: None,
-                pre_slice: args.n_rows.map(|len| Slice::Positive { offset: 0, len }),
-                cast_columns_policy: CastColumnsPolicy::ERROR_ON_MISMATCH,
-                missing_columns_policy: MissingColumnsPolicy::Raise,
-                extra_columns_policy: ExtraColumnsPolicy::Raise,
-                include_file_paths: None,
-                deletion_files: None,
-                table_statistics: None,
-            },
+            anonymous_unified_scan_args(Some(schema), args.n_rows),
         )?
         .build()
         .into();
@@ -72,4 +53,60 @@ impl LazyFrame {
 
         Ok(lf)
     }
+
+    /// Create a scan which is looked up by `name` in the
+    /// [`PlanRegistry`](polars_plan::dsl::named_serde::PlanRegistry), which unlike an anonymous
+    /// scan can be serialized.
+    ///
+    /// If no schema is given, it is resolved from the scan when the plan is converted.
+    pub fn named_scan(
+        name: PlSmallStr,
+        payload: Vec<u8>,
+        args: ScanArgsAnonymous,
+    ) -> PolarsResult<Self> {
+        let mut lf: LazyFrame = DslPlan::Scan {
+            sources: ScanSources::default(),
+            unified_scan_args: Box::new(anonymous_unified_scan_args(args.schema, args.n_rows)),
+            scan_type: Box::new(FileScanDsl::NamedAnonymous {
+                name,
+                payload,
+                skip_rows: args.skip_rows,
+            }),
+            cached_ir: Default::default(),
+        }
+        .into();
+
+        if let Some(rc) = args.row_index {
+            lf = lf.with_row_index(rc.name.clone(), Some(rc.offset))
+        };
+
+        Ok(lf)
+    }
+}
+
+fn anonymous_unified_scan_args(
+    schema: Option<SchemaRef>,
+    n_rows: Option<usize>,
+) -> UnifiedScanArgs {
+    UnifiedScanArgs {
+        schema,
+        cloud_options: None,
+        hive_options: HiveOptions::new_disabled(),
+        rechunk: false,
+        cache: false,
+        glob: false,
+        hidden_file_prefix: None,
+        projection: None,
+        column_mapping: None,
+        default_values: None,
+        row_index: None,
+        pre_slice: n_rows.map(|len| Slice::Positive { offset: 0, len }),
+        cast_columns_policy: CastColumnsPolicy::ERROR_ON_MISMATCH,
+        missing_columns_policy: MissingColumnsPolicy::Raise,
+        extra_columns_policy: ExtraColumnsPolicy::Raise,
+        include_file_paths: None,
+        deletion_files: None,
+        table_statistics: None,
+        scan_metrics: None,
+    }
 }
@@ -1,5 +1,7 @@
 use polars_core::prelude::*;
+use polars_io::bad_records::BadRecords;
 use polars_io::cloud::CloudOptions;
+use polars_io::csv::read::encoding::maybe_transcode_bytes;
 use polars_io::csv::read::{
     CommentPrefix, CsvEncoding, CsvParseOptions, CsvReadOptions, NullValues, infer_file_schema,
 };
@@ -92,6 +94,15 @@ impl LazyCsvReader {
         self
     }
 
+    /// Divert records that cannot be parsed to `bad_records` instead of raising an error. These
+    /// records are removed from the output and don't count towards the row index or `n_rows`.
+    /// Takes precedence over `ignore_errors`.
+    #[must_use]
+    pub fn with_bad_records(mut self, bad_records: Option<BadRecords>) -> Self {
+        self.read_options.bad_records = bad_records;
+        self
+    }
+
     /// Set the CSV file's schema
     #[must_use]
     pub fn with_schema(mut self, schema: Option<SchemaRef>) -> Self {
@@ -253,6 +264,8 @@ impl LazyCsvReader {
 
             let mut owned = vec![];
             let bytes = maybe_decompress_bytes(bytes.as_ref(), &mut owned)?;
+            let mut transcoded = vec![];
+            let bytes = maybe_transcode_bytes(bytes, &mut transcoded, parse_options.encoding)?;
 
             PolarsResult::Ok(
                 infer_file_schema(
@@ -354,6 +367,7 @@ impl LazyFileListReader for LazyCsvReader {
                 include_file_paths: self.include_file_paths,
                 deletion_files: None,
                 table_statistics: None,
+                scan_metrics: None,
             },
         )?
         .build()
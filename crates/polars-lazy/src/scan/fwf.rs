@@ -0,0 +1,207 @@
+use polars_core::prelude::*;
+use polars_io::cloud::CloudOptions;
+use polars_io::csv::read::NullValues;
+use polars_io::fwf::{FwfColumn, FwfReadOptions, FwfTrim};
+use polars_io::{HiveOptions, RowIndex};
+use polars_utils::plpath::PlPath;
+use polars_utils::slice_enum::Slice;
+
+use crate::prelude::*;
+
+/// Lazily read fixed-width text files.
+#[derive(Clone)]
+pub struct LazyFwfReader {
+    sources: ScanSources,
+    glob: bool,
+    cache: bool,
+    read_options: FwfReadOptions,
+    cloud_options: Option<CloudOptions>,
+    include_file_paths: Option<PlSmallStr>,
+}
+
+impl LazyFwfReader {
+    pub fn new_paths(paths: Arc<[PlPath]>) -> Self {
+        Self::new_with_sources(ScanSources::Paths(paths))
+    }
+
+    pub fn new_with_sources(sources: ScanSources) -> Self {
+        LazyFwfReader {
+            sources,
+            glob: true,
+            cache: true,
+            read_options: Default::default(),
+            cloud_options: Default::default(),
+            include_file_paths: None,
+        }
+    }
+
+    pub fn new(path: PlPath) -> Self {
+        Self::new_with_sources(ScanSources::Paths([path].into()))
+    }
+
+    /// Set the location and data type of the columns in the file.
+    #[must_use]
+    pub fn with_columns(mut self, columns: Arc<[FwfColumn]>) -> Self {
+        self.read_options.columns = columns;
+        self
+    }
+
+    /// Skip the first `n` lines of the file, e.g. a header.
+    #[must_use]
+    pub fn with_skip_rows(mut self, skip_rows: usize) -> Self {
+        self.read_options.skip_rows = skip_rows;
+        self
+    }
+
+    /// Set the character used to indicate an end-of-line (eol).
+    #[must_use]
+    pub fn with_eol_char(mut self, eol_char: u8) -> Self {
+        self.read_options.eol_char = eol_char;
+        self
+    }
+
+    /// Set the character used to pad values to the width of their column.
+    #[must_use]
+    pub fn with_pad_char(mut self, pad_char: u8) -> Self {
+        self.read_options.pad_char = pad_char;
+        self
+    }
+
+    /// Set which side of values is stripped of padding characters.
+    #[must_use]
+    pub fn with_trim(mut self, trim: FwfTrim) -> Self {
+        self.read_options.trim = trim;
+        self
+    }
+
+    /// Set values that will be interpreted as missing/null.
+    #[must_use]
+    pub fn with_null_values(mut self, null_values: Option<NullValues>) -> Self {
+        self.read_options.null_values = null_values;
+        self
+    }
+
+    /// Set values as `Null` if parsing fails.
+    #[must_use]
+    pub fn with_ignore_errors(mut self, ignore_errors: bool) -> Self {
+        self.read_options.ignore_errors = ignore_errors;
+        self
+    }
+
+    /// Cache the DataFrame after reading.
+    #[must_use]
+    pub fn with_cache(mut self, cache: bool) -> Self {
+        self.cache = cache;
+        self
+    }
+
+    /// Expand path given via globbing rules.
+    #[must_use]
+    pub fn with_glob(mut self, toggle: bool) -> Self {
+        self.glob = toggle;
+        self
+    }
+
+    pub fn with_cloud_options(mut self, cloud_options: Option<CloudOptions>) -> Self {
+        self.cloud_options = cloud_options;
+        self
+    }
+
+    pub fn with_include_file_paths(mut self, include_file_paths: Option<PlSmallStr>) -> Self {
+        self.include_file_paths = include_file_paths;
+        self
+    }
+}
+
+impl LazyFileListReader for LazyFwfReader {
+    /// Get the final [LazyFrame].
+    fn finish(self) -> PolarsResult<LazyFrame> {
+        let unified_scan_args = UnifiedScanArgs {
+            schema: None,
+            cloud_options: self.cloud_options,
+            hive_options: HiveOptions::new_disabled(),
+            rechunk: self.read_options.rechunk,
+            cache: self.cache,
+            glob: self.glob,
+            hidden_file_prefix: None,
+            projection: None,
+            column_mapping: None,
+            default_values: None,
+            row_index: self.read_options.row_index.clone(),
+            pre_slice: self
+                .read_options
+                .n_rows
+                .map(|len| Slice::Positive { offset: 0, len }),
+            cast_columns_policy: CastColumnsPolicy::ERROR_ON_MISMATCH,
+            missing_columns_policy: MissingColumnsPolicy::Raise,
+            extra_columns_policy: ExtraColumnsPolicy::Raise,
+            include_file_paths: self.include_file_paths,
+            deletion_files: None,
+            table_statistics: None,
+            scan_metrics: None,
+        };
+
+        let scan_type = Box::new(FileScanDsl::Fwf {
+            options: self.read_options,
+        });
+
+        Ok(LazyFrame::from(DslPlan::Scan {
+            sources: self.sources,
+            unified_scan_args: Box::new(unified_scan_args),
+            scan_type,
+            cached_ir: Default::default(),
+        }))
+    }
+
+    fn finish_no_glob(self) -> PolarsResult<LazyFrame> {
+        unreachable!();
+    }
+
+    fn glob(&self) -> bool {
+        self.glob
+    }
+
+    fn sources(&self) -> &ScanSources {
+        &self.sources
+    }
+
+    fn with_sources(mut self, sources: ScanSources) -> Self {
+        self.sources = sources;
+        self
+    }
+
+    fn with_n_rows(mut self, n_rows: impl Into<Option<usize>>) -> Self {
+        self.read_options.n_rows = n_rows.into();
+        self
+    }
+
+    fn with_row_index(mut self, row_index: impl Into<Option<RowIndex>>) -> Self {
+        self.read_options.row_index = row_index.into();
+        self
+    }
+
+    fn rechunk(&self) -> bool {
+        self.read_options.rechunk
+    }
+
+    /// Rechunk the memory to contiguous chunks when parsing is done.
+    fn with_rechunk(mut self, rechunk: bool) -> Self {
+        self.read_options.rechunk = rechunk;
+        self
+    }
+
+    /// Try to stop parsing when `n` rows are parsed.
+    fn n_rows(&self) -> Option<usize> {
+        self.read_options.n_rows
+    }
+
+    /// Return the row index settings.
+    fn row_index(&self) -> Option<&RowIndex> {
+        self.read_options.row_index.as_ref()
+    }
+
+    /// [CloudOptions] used to list files.
+    fn cloud_options(&self) -> Option<&CloudOptions> {
+        self.cloud_options.as_ref()
+    }
+}
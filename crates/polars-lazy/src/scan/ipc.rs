@@ -83,6 +83,7 @@ impl LazyFileListReader for LazyIpcReader {
                 include_file_paths,
                 deletion_files: None,
                 table_statistics: None,
+                scan_metrics: None,
             },
         )?
         .build()
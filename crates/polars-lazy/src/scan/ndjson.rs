@@ -2,6 +2,7 @@ use std::num::NonZeroUsize;
 use std::sync::Arc;
 
 use polars_core::prelude::*;
+use polars_io::bad_records::BadRecords;
 use polars_io::cloud::CloudOptions;
 use polars_io::{HiveOptions, RowIndex};
 use polars_plan::dsl::{
@@ -26,6 +27,7 @@ pub struct LazyJsonLineReader {
     pub(crate) infer_schema_length: Option<NonZeroUsize>,
     pub(crate) n_rows: Option<usize>,
     pub(crate) ignore_errors: bool,
+    pub(crate) bad_records: Option<BadRecords>,
     pub(crate) include_file_paths: Option<PlSmallStr>,
     pub(crate) cloud_options: Option<CloudOptions>,
 }
@@ -46,6 +48,7 @@ impl LazyJsonLineReader {
             row_index: None,
             infer_schema_length: NonZeroUsize::new(100),
             ignore_errors: false,
+            bad_records: None,
             n_rows: None,
             include_file_paths: None,
             cloud_options: None,
@@ -69,6 +72,14 @@ impl LazyJsonLineReader {
         self.ignore_errors = ignore_errors;
         self
     }
+
+    /// Divert lines that cannot be parsed to `bad_records` instead of raising an error. These
+    /// lines are removed from the output. Takes precedence over `ignore_errors`.
+    #[must_use]
+    pub fn with_bad_records(mut self, bad_records: Option<BadRecords>) -> Self {
+        self.bad_records = bad_records;
+        self
+    }
     /// Try to stop parsing when `n` rows are parsed. During multithreaded parsing the upper bound `n` cannot
     /// be guaranteed.
     #[must_use]
@@ -144,6 +155,7 @@ impl LazyFileListReader for LazyJsonLineReader {
             include_file_paths: self.include_file_paths,
             deletion_files: None,
             table_statistics: None,
+            scan_metrics: None,
         };
 
         let options = NDJsonReadOptions {
@@ -154,6 +166,7 @@ impl LazyFileListReader for LazyJsonLineReader {
             ignore_errors: self.ignore_errors,
             schema: self.schema,
             schema_overwrite: self.schema_overwrite,
+            bad_records: self.bad_records,
         };
 
         let scan_type = Box::new(FileScanDsl::NDJson { options });
@@ -0,0 +1,152 @@
+use polars_core::prelude::*;
+use polars_io::cloud::CloudOptions;
+use polars_io::orc::OrcScanOptions;
+use polars_io::{HiveOptions, RowIndex};
+use polars_utils::plpath::PlPath;
+use polars_utils::slice_enum::Slice;
+
+use crate::prelude::*;
+
+#[derive(Clone)]
+pub struct ScanArgsOrc {
+    pub n_rows: Option<usize>,
+    pub cache: bool,
+    pub rechunk: bool,
+    pub row_index: Option<RowIndex>,
+    pub cloud_options: Option<CloudOptions>,
+    pub hive_options: HiveOptions,
+    /// Use the stripe statistics to skip stripes that cannot match the predicate.
+    pub use_statistics: bool,
+    pub include_file_paths: Option<PlSmallStr>,
+}
+
+impl Default for ScanArgsOrc {
+    fn default() -> Self {
+        Self {
+            n_rows: None,
+            cache: true,
+            rechunk: false,
+            row_index: None,
+            cloud_options: Default::default(),
+            hive_options: Default::default(),
+            use_statistics: true,
+            include_file_paths: None,
+        }
+    }
+}
+
+#[derive(Clone)]
+struct LazyOrcReader {
+    args: ScanArgsOrc,
+    sources: ScanSources,
+}
+
+impl LazyOrcReader {
+    fn new(args: ScanArgsOrc) -> Self {
+        Self {
+            args,
+            sources: ScanSources::default(),
+        }
+    }
+}
+
+impl LazyFileListReader for LazyOrcReader {
+    fn finish(self) -> PolarsResult<LazyFrame> {
+        let args = self.args;
+
+        let options = OrcScanOptions {
+            use_statistics: args.use_statistics,
+        };
+        let pre_slice = args.n_rows.map(|len| Slice::Positive { offset: 0, len });
+
+        let lf: LazyFrame = DslBuilder::scan_orc(
+            self.sources,
+            options,
+            UnifiedScanArgs {
+                schema: None,
+                cloud_options: args.cloud_options,
+                hive_options: args.hive_options,
+                rechunk: args.rechunk,
+                cache: args.cache,
+                glob: true,
+                hidden_file_prefix: None,
+                projection: None,
+                column_mapping: None,
+                default_values: None,
+                row_index: args.row_index,
+                pre_slice,
+                cast_columns_policy: CastColumnsPolicy::ERROR_ON_MISMATCH,
+                missing_columns_policy: MissingColumnsPolicy::Raise,
+                extra_columns_policy: ExtraColumnsPolicy::Raise,
+                include_file_paths: args.include_file_paths,
+                deletion_files: None,
+                table_statistics: None,
+                scan_metrics: None,
+            },
+        )?
+        .build()
+        .into();
+
+        Ok(lf)
+    }
+
+    fn finish_no_glob(self) -> PolarsResult<LazyFrame> {
+        unreachable!()
+    }
+
+    fn sources(&self) -> &ScanSources {
+        &self.sources
+    }
+
+    fn with_sources(mut self, sources: ScanSources) -> Self {
+        self.sources = sources;
+        self
+    }
+
+    fn with_n_rows(mut self, n_rows: impl Into<Option<usize>>) -> Self {
+        self.args.n_rows = n_rows.into();
+        self
+    }
+
+    fn with_row_index(mut self, row_index: impl Into<Option<RowIndex>>) -> Self {
+        self.args.row_index = row_index.into();
+        self
+    }
+
+    fn rechunk(&self) -> bool {
+        self.args.rechunk
+    }
+
+    fn with_rechunk(mut self, toggle: bool) -> Self {
+        self.args.rechunk = toggle;
+        self
+    }
+
+    fn n_rows(&self) -> Option<usize> {
+        self.args.n_rows
+    }
+
+    fn row_index(&self) -> Option<&RowIndex> {
+        self.args.row_index.as_ref()
+    }
+
+    /// [CloudOptions] used to list files.
+    fn cloud_options(&self) -> Option<&CloudOptions> {
+        self.args.cloud_options.as_ref()
+    }
+}
+
+impl LazyFrame {
+    /// Create a LazyFrame directly from an ORC scan.
+    pub fn scan_orc(path: PlPath, args: ScanArgsOrc) -> PolarsResult<Self> {
+        Self::scan_orc_sources(ScanSources::Paths([path].into()), args)
+    }
+
+    pub fn scan_orc_files(paths: Arc<[PlPath]>, args: ScanArgsOrc) -> PolarsResult<Self> {
+        Self::scan_orc_sources(ScanSources::Paths(paths), args)
+    }
+
+    pub fn scan_orc_sources(sources: ScanSources, args: ScanArgsOrc) -> PolarsResult<Self> {
+        LazyOrcReader::new(args).with_sources(sources).finish()
+    }
+}
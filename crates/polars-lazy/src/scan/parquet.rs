@@ -98,6 +98,7 @@ impl LazyFileListReader for LazyParquetReader {
             include_file_paths: self.args.include_file_paths,
             deletion_files: None,
             table_statistics: None,
+            scan_metrics: None,
         };
 
         let mut lf: LazyFrame =
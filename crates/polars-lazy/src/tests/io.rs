@@ -1,6 +1,7 @@
 use polars_io::RowIndex;
 #[cfg(feature = "is_between")]
 use polars_ops::prelude::ClosedInterval;
+use polars_utils::mmap::MemSlice;
 use polars_utils::plpath::PlPath;
 use polars_utils::slice_enum::Slice;
 
@@ -805,3 +806,87 @@ fn scan_small_dtypes() -> PolarsResult<()> {
     }
     Ok(())
 }
+
+#[test]
+#[cfg(feature = "csv")]
+fn test_scan_csv_bad_records() -> PolarsResult<()> {
+    let csv = b"a,b\n1,x\nfoo,y\n3,z,extra\n4,w\n5,v\n";
+    let bad_records = BadRecords::new();
+    let scan = || {
+        LazyCsvReader::new_with_sources(ScanSources::Buffers([MemSlice::from_static(csv)].into()))
+            .with_schema(Some(Arc::new(Schema::from_iter([
+                Field::new("a".into(), DataType::Int64),
+                Field::new("b".into(), DataType::String),
+            ]))))
+            .with_bad_records(Some(bad_records.clone()))
+    };
+
+    let df = scan().finish()?.collect()?;
+    assert!(df.equals(&df!["a" => [1i64, 4, 5], "b" => ["x", "w", "v"]]?));
+
+    let bad = bad_records.take();
+    assert_eq!(Vec::from(bad.column("line")?.u64()?), &[Some(3), Some(4)]);
+    assert_eq!(
+        Vec::from(bad.column("record")?.str()?),
+        &[Some("foo,y"), Some("3,z,extra")]
+    );
+
+    // Bad records don't count towards the row index, the number of rows or the row count.
+    for engine in [
+        Engine::InMemory,
+        #[cfg(feature = "new_streaming")]
+        Engine::Streaming,
+    ] {
+        let df = scan()
+            .with_row_index(Some(RowIndex {
+                name: "idx".into(),
+                offset: 0,
+            }))
+            .with_n_rows(Some(2))
+            .finish()?
+            .collect_with_engine(engine)?;
+        assert!(df.equals(&df![
+            "idx" => [0 as IdxSize, 1],
+            "a" => [1i64, 4],
+            "b" => ["x", "w"],
+        ]?));
+        assert_eq!(bad_records.take().height(), 2);
+
+        let df = scan()
+            .finish()?
+            .select([len()])
+            .collect_with_engine(engine)?;
+        assert_eq!(df.column("len")?.idx()?.get(0), Some(3));
+        assert_eq!(bad_records.take().height(), 2);
+    }
+
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "json")]
+fn test_scan_ndjson_bad_records() -> PolarsResult<()> {
+    let data = b"{\"a\":1}\n{\"a\":\n{\"a\":3}\nnot json\n";
+    let bad_records = BadRecords::new();
+
+    let df = LazyJsonLineReader::new_with_sources(ScanSources::Buffers(
+        [MemSlice::from_static(data)].into(),
+    ))
+    .with_schema(Some(Arc::new(Schema::from_iter([Field::new(
+        "a".into(),
+        DataType::Int64,
+    )]))))
+    .with_bad_records(Some(bad_records.clone()))
+    .finish()?
+    .collect()?;
+    assert!(df.equals(&df!["a" => [1i64, 3]]?));
+
+    let bad = bad_records.take();
+    assert_eq!(Vec::from(bad.column("line")?.u64()?), &[Some(2), Some(4)]);
+    assert_eq!(
+        Vec::from(bad.column("record")?.str()?),
+        &[Some("{\"a\":"), Some("not json")]
+    );
+
+    Ok(())
+}
@@ -650,3 +650,163 @@ fn test_cluster_with_columns_chain() -> Result<(), Box<dyn std::error::Error>> {
 
     Ok(())
 }
+
+#[test]
+fn test_join_reorder_inner_join_chain() -> PolarsResult<()> {
+    let fact = df![
+        "id" => (0..1000i32).collect::<Vec<_>>(),
+        "k1" => (0..1000i32).map(|i| i % 10).collect::<Vec<_>>(),
+        "k2" => (0..1000i32).map(|i| i % 7).collect::<Vec<_>>(),
+    ]?;
+    let dim1 = df![
+        "k1" => (0..10i32).collect::<Vec<_>>(),
+        "v1" => (0..10i32).map(|i| i * 10).collect::<Vec<_>>(),
+    ]?;
+    let dim2 = df![
+        "k2" => (0..7i32).collect::<Vec<_>>(),
+        "v2" => ["a", "b", "c", "d", "e", "f", "g"],
+    ]?;
+
+    let q = fact
+        .lazy()
+        .join(
+            dim1.lazy(),
+            [col("k1")],
+            [col("k1")],
+            JoinType::Inner.into(),
+        )
+        .join(
+            dim2.lazy().filter(col("v2").eq(lit("c"))),
+            [col("k2")],
+            [col("k2")],
+            JoinType::Inner.into(),
+        );
+
+    // The filtered dimension is joined with the fact table first.
+    let (mut expr_arena, mut lp_arena) = get_arenas();
+    let lp = q.clone().optimize(&mut lp_arena, &mut expr_arena)?;
+    assert!(lp_arena.iter(lp).any(|(_, ir)| match ir {
+        IR::Join {
+            input_left,
+            input_right,
+            ..
+        } => {
+            matches!(lp_arena.get(*input_left), IR::DataFrameScan { .. })
+                && matches!(lp_arena.get(*input_right), IR::Filter { .. })
+        },
+        _ => false,
+    }));
+
+    let sort_by_id = |q: LazyFrame| q.sort(["id"], Default::default()).collect();
+    let out = sort_by_id(q.clone())?;
+    let expected = sort_by_id(q.with_row_estimate(false))?;
+
+    assert_eq!(out.get_column_names(), ["id", "k1", "k2", "v1", "v2"]);
+    assert_eq!(out.height(), 143);
+    assert!(out.equals(&expected));
+
+    Ok(())
+}
+
+#[test]
+fn test_remove_sort_of_sorted_input() -> PolarsResult<()> {
+    let df = df![
+        "a" => [3, 1, 2, 5, 4],
+        "b" => [1, 2, 3, 4, 5],
+    ]?;
+    // Sorts whose order is not observed are removed by another optimization, so check whether
+    // the outer sort is kept.
+    let root_is_sort = |q: LazyFrame| -> PolarsResult<bool> {
+        let (mut expr_arena, mut lp_arena) = get_arenas();
+        let lp = q.optimize(&mut lp_arena, &mut expr_arena)?;
+        Ok(matches!(lp_arena.get(lp), IR::Sort { .. }))
+    };
+
+    let sorted = df
+        .clone()
+        .lazy()
+        .sort(["a"], Default::default())
+        .filter(col("b").gt(lit(1)))
+        .with_columns([col("a").alias("c"), (col("b") * lit(2)).alias("b")]);
+
+    // The sorted column, also under a new name, is not sorted again.
+    let q = sorted.clone().sort(["c"], Default::default());
+    assert!(!root_is_sort(q.clone())?);
+    assert_eq!(
+        q.collect()?.column("c")?.i32()?.to_vec(),
+        [Some(1), Some(2), Some(4), Some(5)]
+    );
+
+    // A sort with a slice becomes a slice.
+    let q = sorted
+        .clone()
+        .sort(["a"], SortMultipleOptions::default().with_nulls_last(false))
+        .slice(1, 2);
+    assert!(matches!(
+        {
+            let (mut expr_arena, mut lp_arena) = get_arenas();
+            let lp = q.clone().optimize(&mut lp_arena, &mut expr_arena)?;
+            lp_arena.get(lp).clone()
+        },
+        IR::Slice {
+            offset: 1,
+            len: 2,
+            ..
+        }
+    ));
+    assert_eq!(
+        q.collect()?.column("a")?.i32()?.to_vec(),
+        [Some(2), Some(4)]
+    );
+
+    // A different order, nulls placement or column still needs a sort.
+    let q = sorted.clone().sort(
+        ["a"],
+        SortMultipleOptions::default().with_order_descending(true),
+    );
+    assert!(root_is_sort(q)?);
+    let q = sorted
+        .clone()
+        .sort(["a"], SortMultipleOptions::default().with_nulls_last(true));
+    assert!(root_is_sort(q)?);
+    let q = sorted.sort(["b"], Default::default());
+    assert!(root_is_sort(q)?);
+
+    // A row index is sorted.
+    let q = df
+        .clone()
+        .lazy()
+        .with_row_index("index", None)
+        .sort(["index"], Default::default());
+    assert!(!root_is_sort(q)?);
+
+    // Sorts on multiple columns are removed if the input is sorted by the same columns, or by
+    // more columns starting with them.
+    let options = SortMultipleOptions::default().with_order_descending_multi([false, true]);
+    let sorted = df
+        .lazy()
+        .sort(["a", "b"], options.clone())
+        .with_column(col("b").alias("c"));
+    let q = sorted.clone().sort(["a", "c"], options.clone());
+    assert!(!root_is_sort(q.clone())?);
+    assert_eq!(
+        q.collect()?.column("a")?.i32()?.to_vec(),
+        [Some(1), Some(2), Some(3), Some(4), Some(5)]
+    );
+    assert!(!root_is_sort(
+        sorted.clone().sort(["a"], Default::default())
+    )?);
+    assert!(root_is_sort(
+        sorted.clone().sort(["a", "b"], Default::default())
+    )?);
+    assert!(root_is_sort(sorted.clone().sort(["b", "a"], options))?);
+    assert!(root_is_sort(
+        sorted
+            .clone()
+            .select([col("a")])
+            .with_row_index("index", None)
+            .sort(["a", "index"], Default::default())
+    )?);
+
+    Ok(())
+}
@@ -314,3 +314,147 @@ fn test_push_join_col_predicates_to_both_sides_semi_12565() -> PolarsResult<()>
     assert_eq!(out, expected);
     Ok(())
 }
+
+fn predicate_pushdown_matches_unoptimized(q: LazyFrame) -> PolarsResult<()> {
+    let expected = q.clone().with_predicate_pushdown(false).collect()?;
+    let out = q.collect()?;
+    assert!(out.equals_missing(&expected), "{out}\n{expected}");
+    Ok(())
+}
+
+#[test]
+fn test_predicate_pushdown_past_window_partition_keys() -> PolarsResult<()> {
+    let df = df![
+        "k" => [1, 1, 2, 2, 3],
+        "j" => [1, 2, 1, 2, 1],
+        "v" => [1, 2, 3, 4, 5],
+    ]?;
+
+    // Predicates on the partition keys filter whole partitions.
+    let q = df
+        .clone()
+        .lazy()
+        .with_columns([
+            col("v").sum().over([col("k")]).alias("s"),
+            col("v").shift(lit(1)).over([col("k"), col("j")]).alias("c"),
+            (col("v") * lit(2)).alias("w"),
+        ])
+        .filter(col("k").eq(lit(2)));
+    assert!(predicate_at_scan(q.clone()));
+    predicate_pushdown_matches_unoptimized(q)?;
+
+    // `j` is not a partition key of every window.
+    let q = df
+        .clone()
+        .lazy()
+        .with_columns([
+            col("v").sum().over([col("k")]).alias("s"),
+            col("v").sum().over([col("k"), col("j")]).alias("c"),
+        ])
+        .filter(col("j").eq(lit(2)));
+    assert!(!predicate_at_scan(q.clone()));
+    predicate_pushdown_matches_unoptimized(q)?;
+
+    // The partition key is renamed.
+    let q = df
+        .clone()
+        .lazy()
+        .select([
+            col("k").alias("key"),
+            col("v").sum().over([col("k")]).alias("s"),
+        ])
+        .filter(col("key").eq(lit(1)));
+    assert!(predicate_at_scan(q.clone()));
+    predicate_pushdown_matches_unoptimized(q)?;
+
+    // The filtered column is overwritten, so the predicate does not refer to the partition key.
+    let q = df
+        .lazy()
+        .with_columns([
+            (col("k") * lit(2)).alias("k"),
+            col("v").sum().over([col("k")]).alias("s"),
+        ])
+        .filter(col("k").eq(lit(2)));
+    assert!(!predicate_at_scan(q.clone()));
+    predicate_pushdown_matches_unoptimized(q)
+}
+
+#[test]
+fn test_predicate_pushdown_past_group_by_keys() -> PolarsResult<()> {
+    let df = df![
+        "k" => [1, 1, 2, 2, 3],
+        "v" => [1, 2, 3, 4, 5],
+    ]?;
+
+    let q = df
+        .clone()
+        .lazy()
+        .group_by_stable([col("k")])
+        .agg([col("v").sum()])
+        .filter(col("k").gt(lit(1)));
+    assert!(predicate_at_scan(q.clone()));
+    predicate_pushdown_matches_unoptimized(q)?;
+
+    // Aliased key.
+    let q = df
+        .clone()
+        .lazy()
+        .group_by_stable([col("k").alias("g")])
+        .agg([col("v").sum()])
+        .filter(col("g").gt(lit(1)));
+    assert!(predicate_at_scan(q.clone()));
+    predicate_pushdown_matches_unoptimized(q)?;
+
+    // The key is computed, a predicate on it does not hold on the input column.
+    let q = df
+        .lazy()
+        .group_by_stable([(col("k") * lit(2)).alias("k")])
+        .agg([col("v").sum()])
+        .filter(col("k").eq(lit(2)));
+    assert!(!predicate_at_scan(q.clone()));
+    predicate_pushdown_matches_unoptimized(q)
+}
+
+#[test]
+#[cfg(feature = "dynamic_group_by")]
+fn test_predicate_pushdown_past_rolling_group_by_keys() -> PolarsResult<()> {
+    let df = df![
+        "t" => [1i64, 2, 3, 1, 2, 3],
+        "k" => ["a", "a", "a", "b", "b", "b"],
+        "v" => [1, 2, 3, 4, 5, 6],
+    ]?;
+
+    let q = df
+        .clone()
+        .lazy()
+        .rolling(
+            col("t"),
+            [col("k")],
+            RollingGroupOptions {
+                period: polars_time::Duration::parse("2i"),
+                offset: polars_time::Duration::parse("-2i"),
+                ..Default::default()
+            },
+        )
+        .agg([col("v").sum()])
+        .filter(col("k").eq(lit("b")));
+    assert!(predicate_at_scan(q.clone()));
+    predicate_pushdown_matches_unoptimized(q)?;
+
+    // Predicates on the index column change the windows.
+    let q = df
+        .lazy()
+        .rolling(
+            col("t"),
+            [col("k")],
+            RollingGroupOptions {
+                period: polars_time::Duration::parse("2i"),
+                offset: polars_time::Duration::parse("-2i"),
+                ..Default::default()
+            },
+        )
+        .agg([col("v").sum()])
+        .filter(col("t").gt(lit(1i64)));
+    assert!(!predicate_at_scan(q.clone()));
+    predicate_pushdown_matches_unoptimized(q)
+}
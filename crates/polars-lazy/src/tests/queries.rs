@@ -2010,3 +2010,108 @@ fn test_named_udfs() -> PolarsResult<()> {
 
     Ok(())
 }
+
+#[test]
+#[cfg(feature = "serde")]
+fn test_named_plan_serde() -> PolarsResult<()> {
+    use polars_plan::dsl::named_serde::{PlanRegistry, set_named_plan_registry};
+
+    struct Numbers;
+    impl AnonymousScan for Numbers {
+        fn as_any(&self) -> &dyn std::any::Any {
+            self
+        }
+
+        fn scan(&self, _scan_opts: AnonymousScanArgs) -> PolarsResult<DataFrame> {
+            df!["a" => [1, 2, 3]]
+        }
+
+        fn schema(&self, _infer_schema_length: Option<usize>) -> PolarsResult<SchemaRef> {
+            Ok(Arc::new(Schema::from_iter([Field::new(
+                "a".into(),
+                DataType::Int32,
+            )])))
+        }
+    }
+
+    struct X;
+    impl PlanRegistry for X {
+        fn get_map_function(&self, name: &str, payload: &[u8]) -> Option<Arc<dyn DataFrameUdf>> {
+            assert_eq!(name, "multiply");
+            let factor = payload[0] as i32;
+            Some(Arc::new(move |df: DataFrame| {
+                df.lazy().select([col("a") * lit(factor)]).collect()
+            }))
+        }
+
+        fn get_scan(&self, name: &str, _payload: &[u8]) -> Option<Arc<dyn AnonymousScan>> {
+            (name == "numbers").then(|| Arc::new(Numbers) as _)
+        }
+    }
+
+    set_named_plan_registry(Arc::new(X) as _);
+
+    let lf = LazyFrame::named_scan("numbers".into(), vec![], ScanArgsAnonymous::default())?
+        .map_named(
+            "multiply".into(),
+            vec![2],
+            AllowedOptimizations::default(),
+            None,
+        );
+
+    let mut buf = vec![];
+    lf.logical_plan
+        .serialize_versioned(&mut buf, Default::default())?;
+    DslPlan::check_versioned_compatibility(buf.as_slice())?;
+    let lf = LazyFrame::from(DslPlan::deserialize_versioned(buf.as_slice())?);
+    assert_eq!(lf.collect()?, df!["a" => [2, 4, 6]]?);
+
+    // The major version directly follows the magic bytes.
+    buf[b"DSL_VERSION".len()] ^= 0xff;
+    assert!(DslPlan::check_versioned_compatibility(buf.as_slice()).is_err());
+
+    let lf = df!["a" => [1]]?
+        .lazy()
+        .map(Ok, AllowedOptimizations::default(), None, Some("opaque"));
+    let err = lf
+        .logical_plan
+        .serialize_versioned(&mut vec![], Default::default())
+        .unwrap_err();
+    assert!(err.to_string().contains("opaque map function 'opaque'"));
+
+    // Opaque functions in expressions, also in the inputs of other nodes.
+    let lf = df!["a" => [1]]?
+        .lazy()
+        .with_column(col("a").map(|s| Ok(s * 2), |_, f| Ok(f.clone())))
+        .select([col("a").sum()]);
+    let err = lf
+        .logical_plan
+        .serialize_versioned(&mut vec![], Default::default())
+        .unwrap_err();
+    assert!(
+        err.to_string()
+            .contains("opaque function 'map' in an expression")
+    );
+
+    // Opaque functions nested below the root of an expression.
+    for expr in [
+        col("a").map(|s| Ok(s * 2), |_, f| Ok(f.clone())).alias("b"),
+        col("a").map(|s| Ok(s * 2), |_, f| Ok(f.clone())) + lit(1),
+    ] {
+        let lf = df!["a" => [1]]?.lazy().select([expr]);
+        let err = lf
+            .logical_plan
+            .serialize_versioned(&mut vec![], Default::default())
+            .unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("opaque function 'map' in an expression")
+        );
+    }
+
+    let lf = LazyFrame::named_scan("unknown".into(), vec![], ScanArgsAnonymous::default())?;
+    let err = lf.collect().unwrap_err();
+    assert!(err.to_string().contains("named scan 'unknown' not found"));
+
+    Ok(())
+}
@@ -1,5 +1,12 @@
 use super::*;
 
+/// The windows of a [`GroupByDynamicExec`].
+#[cfg(feature = "dynamic_group_by")]
+pub(crate) enum DynamicWindows {
+    Fixed(DynamicGroupOptions),
+    Session(SessionGroupOptions),
+}
+
 #[cfg_attr(not(feature = "dynamic_group_by"), allow(dead_code))]
 pub(crate) struct GroupByDynamicExec {
     pub(crate) input: Box<dyn Executor>,
@@ -8,7 +15,7 @@ pub(crate) struct GroupByDynamicExec {
     pub(crate) keys: Vec<Arc<dyn PhysicalExpr>>,
     pub(crate) aggs: Vec<Arc<dyn PhysicalExpr>>,
     #[cfg(feature = "dynamic_group_by")]
-    pub(crate) options: DynamicGroupOptions,
+    pub(crate) windows: DynamicWindows,
     pub(crate) input_schema: SchemaRef,
     pub(crate) slice: Option<(i64, usize)>,
     pub(crate) apply: Option<PlanCallback<DataFrame, DataFrame>>,
@@ -37,7 +44,10 @@ impl GroupByDynamicExec {
             None
         };
 
-        let (mut time_key, bounds, groups) = df.group_by_dynamic(group_by, &self.options)?;
+        let (mut time_key, bounds, groups) = match &self.windows {
+            DynamicWindows::Fixed(options) => df.group_by_dynamic(group_by, options)?,
+            DynamicWindows::Session(options) => df.group_by_session(group_by, options)?,
+        };
         POOL.install(|| {
             keys.iter_mut().for_each(|key| {
                 unsafe { *key = key.agg_first(&groups) };
@@ -107,7 +117,11 @@ impl Executor for GroupByDynamicExec {
                 .iter()
                 .map(|s| Ok(s.to_field(&self.input_schema)?.name))
                 .collect::<PolarsResult<Vec<_>>>()?;
-            let name = comma_delimited("group_by_dynamic".to_string(), &by);
+            let name = match self.windows {
+                DynamicWindows::Fixed(_) => "group_by_dynamic",
+                DynamicWindows::Session(_) => "group_by_session",
+            };
+            let name = comma_delimited(name.to_string(), &by);
             Cow::Owned(name)
         } else {
             Cow::Borrowed("")
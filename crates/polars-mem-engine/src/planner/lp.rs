@@ -341,43 +341,75 @@ fn create_physical_plan_impl(
                                 FileType::Csv(options) => {
                                     use polars_io::SerWriter;
                                     use polars_io::csv::write::CsvWriter;
-                                    CsvWriter::new(BufWriter::new(writer))
-                                        .include_bom(options.include_bom)
-                                        .include_header(options.include_header)
-                                        .with_separator(options.serialize_options.separator)
-                                        .with_line_terminator(
-                                            options.serialize_options.line_terminator.clone(),
-                                        )
-                                        .with_quote_char(options.serialize_options.quote_char)
-                                        .with_batch_size(options.batch_size)
-                                        .with_datetime_format(
-                                            options.serialize_options.datetime_format.clone(),
-                                        )
-                                        .with_date_format(
-                                            options.serialize_options.date_format.clone(),
-                                        )
-                                        .with_time_format(
-                                            options.serialize_options.time_format.clone(),
-                                        )
-                                        .with_float_scientific(
-                                            options.serialize_options.float_scientific,
-                                        )
-                                        .with_float_precision(
-                                            options.serialize_options.float_precision,
-                                        )
-                                        .with_decimal_comma(options.serialize_options.decimal_comma)
-                                        .with_null_value(options.serialize_options.null.clone())
-                                        .with_quote_style(options.serialize_options.quote_style)
-                                        .finish(&mut df)?;
+                                    write_maybe_compressed(
+                                        writer,
+                                        options.compression,
+                                        |writer| {
+                                            CsvWriter::new(writer)
+                                                .include_bom(options.include_bom)
+                                                .include_header(options.include_header)
+                                                .with_separator(options.serialize_options.separator)
+                                                .with_line_terminator(
+                                                    options
+                                                        .serialize_options
+                                                        .line_terminator
+                                                        .clone(),
+                                                )
+                                                .with_quote_char(
+                                                    options.serialize_options.quote_char,
+                                                )
+                                                .with_batch_size(options.batch_size)
+                                                .with_datetime_format(
+                                                    options
+                                                        .serialize_options
+                                                        .datetime_format
+                                                        .clone(),
+                                                )
+                                                .with_date_format(
+                                                    options.serialize_options.date_format.clone(),
+                                                )
+                                                .with_time_format(
+                                                    options.serialize_options.time_format.clone(),
+                                                )
+                                                .with_float_scientific(
+                                                    options.serialize_options.float_scientific,
+                                                )
+                                                .with_float_precision(
+                                                    options.serialize_options.float_precision,
+                                                )
+                                                .with_decimal_comma(
+                                                    options.serialize_options.decimal_comma,
+                                                )
+                                                .with_null_value(
+                                                    options.serialize_options.null.clone(),
+                                                )
+                                                .with_quote_style(
+                                                    options.serialize_options.quote_style,
+                                                )
+                                                .with_encoding(options.encoding)
+                                                .finish(&mut df)
+                                        },
+                                    )?;
                                 },
                                 #[cfg(feature = "json")]
-                                FileType::Json(_options) => {
+                                FileType::Json(options) => {
                                     use polars_io::SerWriter;
                                     use polars_io::json::{JsonFormat, JsonWriter};
 
-                                    JsonWriter::new(BufWriter::new(writer))
-                                        .with_json_format(JsonFormat::JsonLines)
-                                        .finish(&mut df)?;
+                                    write_maybe_compressed(
+                                        writer,
+                                        options.compression,
+                                        |writer| {
+                                            JsonWriter::new(writer)
+                                                .with_json_format(JsonFormat::JsonLines)
+                                                .finish(&mut df)
+                                        },
+                                    )?;
+                                },
+                                #[cfg(feature = "orc")]
+                                FileType::Orc(options) => {
+                                    use polars_io::SerWriter;
+                                    options.to_writer(BufWriter::new(writer)).finish(&mut df)?;
                                 },
                                 #[allow(unreachable_patterns)]
                                 _ => panic!("enable filetype feature"),
@@ -657,13 +689,17 @@ fn create_physical_plan_impl(
 
             let _slice = options.slice;
             #[cfg(feature = "dynamic_group_by")]
-            if let Some(options) = options.dynamic {
+            if let Some(windows) = options
+                .dynamic
+                .map(executors::DynamicWindows::Fixed)
+                .or_else(|| options.session.map(executors::DynamicWindows::Session))
+            {
                 let input = recurse!(input, state)?;
                 return Ok(Box::new(executors::GroupByDynamicExec {
                     input,
                     keys: phys_keys,
                     aggs: phys_aggs,
-                    options,
+                    windows,
                     input_schema,
                     slice: _slice,
                     apply,
@@ -889,6 +925,32 @@ fn create_physical_plan_impl(
     }
 }
 
+/// Calls `write` with a buffered writer, whose output is compressed with `compression` before
+/// it is written to `writer`.
+#[cfg(any(feature = "csv", feature = "json"))]
+fn write_maybe_compressed(
+    writer: &mut (dyn std::io::Write + Send),
+    compression: polars_io::utils::compression::ExternalCompression,
+    write: impl FnOnce(&mut dyn std::io::Write) -> PolarsResult<()>,
+) -> PolarsResult<()> {
+    use std::io::{BufWriter, Write};
+
+    if compression.is_uncompressed() {
+        let mut writer = BufWriter::new(writer);
+        write(&mut writer)?;
+        writer.flush()?;
+    } else {
+        let mut buffer = vec![];
+        write(&mut buffer)?;
+
+        let mut compressed = vec![];
+        compression.compress(&buffer, &mut compressed)?;
+        writer.write_all(&compressed)?;
+    }
+
+    Ok(())
+}
+
 pub fn create_scan_predicate(
     predicate: &ExprIR,
     expr_arena: &mut Arena<AExpr>,
@@ -201,6 +201,7 @@ impl ScanPredicate {
             }),
             hive_predicate: self.hive_predicate.clone().map(phys_expr_to_io_expr),
             hive_predicate_is_full_predicate: self.hive_predicate_is_full_predicate,
+            metrics: None,
         }
     }
 }
@@ -0,0 +1,60 @@
+use polars_core::series::ops::NullBehavior;
+
+use super::gather::{ensure_idx_addressable, values_to_array};
+use super::*;
+
+/// Width of the arrays returned by [`array_diff`].
+pub fn array_diff_width(width: usize, n: i64, null_behavior: NullBehavior) -> usize {
+    match null_behavior {
+        NullBehavior::Ignore => width,
+        NullBehavior::Drop => width.saturating_sub(n.unsigned_abs() as usize),
+    }
+}
+
+/// Calculate the `n`-th discrete difference within every array.
+///
+/// With [`NullBehavior::Ignore`] the first `n` elements of every array are null, with
+/// [`NullBehavior::Drop`] they are removed and the arrays become `n` elements narrower.
+pub fn array_diff(ca: &ArrayChunked, n: i64, null_behavior: NullBehavior) -> PolarsResult<Series> {
+    ensure_idx_addressable(ca, "diff")?;
+    let ca = ca.rechunk();
+    let width = ca.width();
+    let out_width = array_diff_width(width, n, null_behavior);
+
+    use DataType::*;
+    let values = ca.get_inner();
+    let values = match values.dtype() {
+        UInt8 => values.cast(&Int16)?,
+        UInt16 => values.cast(&Int32)?,
+        UInt32 | UInt64 => values.cast(&Int64)?,
+        _ => values,
+    };
+
+    // Gather element `j + offset` for every output element `j`, so that the differences never
+    // cross array boundaries.
+    let gather = |offset: i64| {
+        let positions = (0..ca.len()).flat_map(|row| {
+            (0..out_width as i64).map(move |j| {
+                let j = j + offset;
+                (0..width as i64)
+                    .contains(&j)
+                    .then(|| (row * width) as IdxSize + j as IdxSize)
+            })
+        });
+        values.take(&IdxCa::from_iter_options(PlSmallStr::EMPTY, positions))
+    };
+    let out = match null_behavior {
+        NullBehavior::Ignore => (&values - &gather(-n)?)?,
+        NullBehavior::Drop if n >= 0 => (&gather(n)? - &gather(0)?)?,
+        NullBehavior::Drop => (&gather(0)? - &gather(-n)?)?,
+    };
+
+    Ok(values_to_array(
+        ca.name().clone(),
+        out,
+        out_width,
+        ca.len(),
+        ca.rechunk_validity(),
+    )
+    .into_series())
+}
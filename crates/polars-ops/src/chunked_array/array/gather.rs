@@ -0,0 +1,264 @@
+use arrow::array::{FixedSizeListArray, ListArray};
+use arrow::bitmap::{Bitmap, BitmapBuilder};
+use arrow::offset::OffsetsBuffer;
+
+use super::*;
+
+/// Assemble the flat `values` into `len` rows of `width` elements each.
+pub(super) fn values_to_array(
+    name: PlSmallStr,
+    values: Series,
+    width: usize,
+    len: usize,
+    validity: Option<Bitmap>,
+) -> ArrayChunked {
+    let dtype = DataType::Array(Box::new(values.dtype().clone()), width);
+    let values = values.rechunk().chunks()[0].clone();
+    let arrow_dtype = FixedSizeListArray::default_datatype(values.dtype().clone(), width);
+    let arr = FixedSizeListArray::new(arrow_dtype, len, values, validity);
+    // SAFETY: the physical values match the inner dtype.
+    let out = unsafe { Series::from_chunks_and_dtype_unchecked(name, vec![arr.boxed()], &dtype) };
+    out.array().unwrap().clone()
+}
+
+/// Assemble the flat `values` into rows, where row `i` spans `offsets[i]..offsets[i + 1]`.
+pub(super) fn values_to_list(
+    name: PlSmallStr,
+    values: Series,
+    offsets: Vec<i64>,
+    validity: Option<Bitmap>,
+) -> ListChunked {
+    let dtype = DataType::List(Box::new(values.dtype().clone()));
+    let values = values.rechunk().chunks()[0].clone();
+    let arrow_dtype = ListArray::<i64>::default_datatype(values.dtype().clone());
+    // SAFETY: the offsets are monotonically increasing and end at the values length.
+    let offsets = unsafe { OffsetsBuffer::new_unchecked(offsets.into()) };
+    let arr = ListArray::<i64>::new(arrow_dtype, offsets, values, validity);
+    // SAFETY: the physical values match the inner dtype.
+    let out = unsafe { Series::from_chunks_and_dtype_unchecked(name, vec![arr.boxed()], &dtype) };
+    out.list().unwrap().clone()
+}
+
+/// Check that every element of `ca` can be addressed with an `IdxSize`.
+pub(super) fn ensure_idx_addressable(ca: &ArrayChunked, op: &str) -> PolarsResult<()> {
+    polars_ensure!(
+        ca.width().saturating_mul(ca.len()) < IdxSize::MAX as usize,
+        ComputeError: "`arr.{}` is not supported for arrays with more than {} elements",
+        op, IdxSize::MAX
+    );
+    Ok(())
+}
+
+fn row_validity(ca: &ArrayChunked) -> impl Fn(usize) -> bool {
+    let validity = ca.rechunk_validity();
+    move |i| validity.as_ref().is_none_or(|v| v.get_bit(i))
+}
+
+/// Flatten a `List` or `Array` column of indices into its values and row offsets.
+fn index_rows(idx: &Series) -> PolarsResult<(Int64Chunked, Vec<i64>, Option<Bitmap>)> {
+    let (inner_dtype, values, offsets, validity) = match idx.dtype() {
+        DataType::List(inner) => {
+            let ca = idx.list()?.rechunk();
+            let arr = ca.downcast_as_array();
+            let start = arr.offsets().first();
+            let offsets = arr.offsets().iter().map(|o| o - start).collect();
+            let values = arr
+                .values()
+                .sliced(*start as usize, arr.offsets().range() as usize);
+            (inner.as_ref(), values, offsets, arr.validity().cloned())
+        },
+        DataType::Array(inner, width) => {
+            let ca = idx.array()?.rechunk();
+            let arr = ca.downcast_as_array();
+            let offsets = (0..=arr.len()).map(|i| (i * width) as i64).collect();
+            (
+                inner.as_ref(),
+                arr.values().clone(),
+                offsets,
+                arr.validity().cloned(),
+            )
+        },
+        dt => {
+            polars_bail!(InvalidOperation: "`arr.gather` expects List or Array indices, got {}", dt)
+        },
+    };
+    polars_ensure!(
+        inner_dtype.is_integer(),
+        InvalidOperation: "cannot use dtype `{}` as an index", inner_dtype
+    );
+    // SAFETY: the values are of the inner dtype.
+    let values = unsafe {
+        Series::from_chunks_and_dtype_unchecked(PlSmallStr::EMPTY, vec![values], inner_dtype)
+    };
+    let values = values.cast(&DataType::Int64)?.i64()?.rechunk().into_owned();
+    Ok((values, offsets, validity))
+}
+
+/// Take the elements at the given indices from every array. Negative indices count from the end
+/// of the array.
+///
+/// The indices are a `List` or an `Array` of integers. `Array` indices give an `Array` of the same
+/// width, `List` indices give a `List`.
+pub fn array_gather(ca: &ArrayChunked, idx: &Series, null_on_oob: bool) -> PolarsResult<Series> {
+    let len = match (ca.len(), idx.len()) {
+        (a, b) if a == b => a,
+        (1, b) => b,
+        (a, 1) => a,
+        (a, b) => polars_bail!(length_mismatch = "arr.gather", a, b),
+    };
+    ensure_idx_addressable(ca, "gather")?;
+    let (idx_values, idx_offsets, idx_validity) = index_rows(idx)?;
+    let idx_values = idx_values.downcast_as_array();
+
+    let width = ca.width() as i64;
+    let ca_valid = row_validity(ca);
+    let mut positions = Vec::with_capacity(idx_values.len());
+    let mut offsets = Vec::with_capacity(len + 1);
+    offsets.push(0i64);
+    let mut validity = BitmapBuilder::with_capacity(len);
+    for row in 0..len {
+        let src = if ca.len() == 1 { 0 } else { row };
+        let idx_row = if idx.len() == 1 { 0 } else { row };
+        let valid = ca_valid(src) && idx_validity.as_ref().is_none_or(|v| v.get_bit(idx_row));
+        validity.push(valid);
+
+        let start = idx_offsets[idx_row] as usize;
+        let end = idx_offsets[idx_row + 1] as usize;
+        for i in start..end {
+            let position = match idx_values.get(i) {
+                Some(i) if valid => {
+                    let i = if i < 0 { i + width } else { i };
+                    if (0..width).contains(&i) {
+                        Some((src as i64 * width + i) as IdxSize)
+                    } else if null_on_oob {
+                        None
+                    } else {
+                        polars_bail!(OutOfBounds: "gather indices are out of bounds");
+                    }
+                },
+                _ => None,
+            };
+            positions.push(position);
+        }
+        offsets.push(positions.len() as i64);
+    }
+
+    let positions = IdxCa::from_iter_options(PlSmallStr::EMPTY, positions.into_iter());
+    let values = ca.rechunk().get_inner().take(&positions)?;
+    let validity = validity.into_opt_validity();
+    let out = match idx.dtype() {
+        DataType::Array(_, idx_width) => {
+            values_to_array(ca.name().clone(), values, *idx_width, len, validity).into_series()
+        },
+        _ => values_to_list(ca.name().clone(), values, offsets, validity).into_series(),
+    };
+    Ok(out)
+}
+
+#[cfg(feature = "list_sample")]
+fn sample_rows(
+    ca: &ArrayChunked,
+    n: impl Iterator<Item = Option<usize>>,
+    len: usize,
+    with_replacement: bool,
+    shuffle: bool,
+    seed: Option<u64>,
+) -> PolarsResult<ListChunked> {
+    ensure_idx_addressable(ca, "sample")?;
+    let width = ca.width();
+    let row_positions = IdxCa::from_vec(PlSmallStr::EMPTY, (0..width as IdxSize).collect());
+    let ca_valid = row_validity(ca);
+
+    // With a fixed seed every row of the same sample size draws the same positions.
+    let mut cached: Option<(usize, Vec<IdxSize>)> = None;
+    let mut positions = Vec::new();
+    let mut offsets = Vec::with_capacity(len + 1);
+    offsets.push(0i64);
+    let mut validity = BitmapBuilder::with_capacity(len);
+    for (row, n) in n.enumerate().take(len) {
+        let src = if ca.len() == 1 { 0 } else { row };
+        match n {
+            Some(n) if ca_valid(src) => {
+                if seed.is_none() || cached.as_ref().is_none_or(|(cached_n, _)| *cached_n != n) {
+                    let sample = row_positions.sample_n(n, with_replacement, shuffle, seed)?;
+                    cached = Some((n, sample.into_no_null_iter().collect()));
+                }
+                let sample = &cached.as_ref().unwrap().1;
+                let offset = (src * width) as IdxSize;
+                positions.extend(sample.iter().map(|i| offset + i));
+                validity.push(true);
+            },
+            _ => validity.push(false),
+        }
+        offsets.push(positions.len() as i64);
+    }
+
+    let positions = IdxCa::from_vec(PlSmallStr::EMPTY, positions);
+    // SAFETY: all positions are within the values of `ca`.
+    let values = unsafe { ca.rechunk().get_inner().take_unchecked(&positions) };
+    Ok(values_to_list(
+        ca.name().clone(),
+        values,
+        offsets,
+        validity.into_opt_validity(),
+    ))
+}
+
+/// Sample `n` elements from every array.
+#[cfg(feature = "list_sample")]
+pub fn array_sample_n(
+    ca: &ArrayChunked,
+    n: &Series,
+    with_replacement: bool,
+    shuffle: bool,
+    seed: Option<u64>,
+) -> PolarsResult<ListChunked> {
+    let n_s = n.cast(&IDX_DTYPE)?;
+    let n = n_s.idx()?;
+    polars_ensure!(
+        ca.len() == n.len() || ca.len() == 1 || n.len() == 1,
+        length_mismatch = "arr.sample(n)",
+        ca.len(),
+        n.len()
+    );
+    let len = if ca.len() == 1 { n.len() } else { ca.len() };
+    let n: Box<dyn Iterator<Item = Option<usize>>> = if n.len() == 1 {
+        Box::new(std::iter::repeat(n.get(0).map(|n| n as usize)))
+    } else {
+        Box::new(n.iter().map(|n| n.map(|n| n as usize)))
+    };
+    sample_rows(ca, n, len, with_replacement, shuffle, seed)
+}
+
+/// Sample a fraction of the elements of every array.
+#[cfg(feature = "list_sample")]
+pub fn array_sample_fraction(
+    ca: &ArrayChunked,
+    fraction: &Series,
+    with_replacement: bool,
+    shuffle: bool,
+    seed: Option<u64>,
+) -> PolarsResult<ListChunked> {
+    let fraction_s = fraction.cast(&DataType::Float64)?;
+    let fraction = fraction_s.f64()?;
+    polars_ensure!(
+        ca.len() == fraction.len() || ca.len() == 1 || fraction.len() == 1,
+        length_mismatch = "arr.sample(fraction)",
+        ca.len(),
+        fraction.len()
+    );
+    let len = if ca.len() == 1 {
+        fraction.len()
+    } else {
+        ca.len()
+    };
+    let width = ca.width() as f64;
+    let n: Box<dyn Iterator<Item = Option<usize>>> = if fraction.len() == 1 {
+        Box::new(std::iter::repeat(
+            fraction.get(0).map(|f| (width * f) as usize),
+        ))
+    } else {
+        Box::new(fraction.iter().map(|f| f.map(|f| (width * f) as usize)))
+    };
+    sample_rows(ca, n, len, with_replacement, shuffle, seed)
+}
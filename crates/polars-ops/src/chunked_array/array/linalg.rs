@@ -0,0 +1,224 @@
+use arrow::array::{Array, FixedSizeListArray, PrimitiveArray};
+use arrow::bitmap::BitmapBuilder;
+use arrow::types::NativeType;
+use num_traits::NumCast;
+use polars_compute::linalg::VectorKernels;
+use polars_core::prelude::arity::unary_kernel;
+use polars_core::utils::align_chunks_binary;
+use polars_core::with_match_physical_float_polars_type;
+
+use super::*;
+
+/// The float type the linear algebra kernels compute in: `Float32` stays `Float32`,
+/// every other numeric inner type is computed in `Float64`.
+pub(crate) fn linalg_float_dtype(ca: &ArrayChunked, op: &str) -> PolarsResult<DataType> {
+    match ca.inner_dtype() {
+        DataType::Float32 => Ok(DataType::Float32),
+        dt if dt.is_primitive_numeric() => Ok(DataType::Float64),
+        dt => {
+            polars_bail!(InvalidOperation: "`{}` expects a numeric Array, got Array({})", op, dt)
+        },
+    }
+}
+
+/// Cast `ca` to an `Array` of the given float dtype.
+pub(crate) fn cast_linalg_inner(
+    ca: &ArrayChunked,
+    float_dtype: &DataType,
+) -> PolarsResult<ArrayChunked> {
+    let dtype = DataType::Array(Box::new(float_dtype.clone()), ca.width());
+    Ok(ca.cast(&dtype)?.array()?.clone())
+}
+
+/// Iterate over the rows of `arr` as slices of the inner values. Rows that are
+/// null, or that contain a null element, are yielded as `None`.
+pub(crate) fn array_rows<T: NativeType>(
+    arr: &FixedSizeListArray,
+) -> impl Iterator<Item = Option<&[T]>> + '_ {
+    let width = arr.size();
+    let values = arr
+        .values()
+        .as_any()
+        .downcast_ref::<PrimitiveArray<T>>()
+        .unwrap();
+    let inner_validity = values.validity().filter(|v| v.unset_bits() > 0);
+    let slice = values.values().as_slice();
+    (0..arr.len()).map(move |i| {
+        if !arr.is_valid(i)
+            || inner_validity.is_some_and(|v| v.null_count_range(i * width, width) > 0)
+        {
+            None
+        } else {
+            Some(&slice[i * width..(i + 1) * width])
+        }
+    })
+}
+
+type RowOp<'a, T> = &'a dyn Fn(&[T], &[T]) -> T;
+
+fn binary_rows<T>(lhs: &ArrayChunked, rhs: &ArrayChunked, op: RowOp<T::Native>) -> ChunkedArray<T>
+where
+    T: PolarsFloatType,
+    T::Native: VectorKernels,
+{
+    let name = lhs.name().clone();
+    if rhs.len() == 1 && lhs.len() != 1 {
+        let rhs = rhs.rechunk();
+        let rhs_row = array_rows::<T::Native>(rhs.downcast_iter().next().unwrap())
+            .next()
+            .flatten();
+        let Some(rhs_row) = rhs_row else {
+            return ChunkedArray::full_null(name, lhs.len());
+        };
+        let chunks = lhs.downcast_iter().map(|arr| {
+            array_rows::<T::Native>(arr)
+                .map(|row| row.map(|a| op(a, rhs_row)))
+                .collect::<PrimitiveArray<T::Native>>()
+        });
+        return ChunkedArray::from_chunk_iter(name, chunks);
+    }
+    if lhs.len() == 1 && rhs.len() != 1 {
+        let out = binary_rows::<T>(rhs, lhs, &|a, b| op(b, a));
+        return out.with_name(name);
+    }
+
+    let (lhs, rhs) = align_chunks_binary(lhs, rhs);
+    let chunks = lhs.downcast_iter().zip(rhs.downcast_iter()).map(|(a, b)| {
+        array_rows::<T::Native>(a)
+            .zip(array_rows::<T::Native>(b))
+            .map(|(a, b)| Some(op(a?, b?)))
+            .collect::<PrimitiveArray<T::Native>>()
+    });
+    ChunkedArray::from_chunk_iter(name, chunks)
+}
+
+fn binary_linalg(
+    lhs: &ArrayChunked,
+    rhs: &ArrayChunked,
+    op: &str,
+    f32_op: fn(&[f32], &[f32]) -> f32,
+    f64_op: fn(&[f64], &[f64]) -> f64,
+) -> PolarsResult<Series> {
+    polars_ensure!(
+        lhs.width() == rhs.width(),
+        ShapeMismatch: "`arr.{}` requires arrays of equal width, got {} and {}",
+        op, lhs.width(), rhs.width()
+    );
+    polars_ensure!(
+        lhs.len() == rhs.len() || lhs.len() == 1 || rhs.len() == 1,
+        length_mismatch = format!("arr.{op}"),
+        lhs.len(),
+        rhs.len()
+    );
+    let method = format!("arr.{op}");
+    let float_dtype = match (
+        linalg_float_dtype(lhs, &method)?,
+        linalg_float_dtype(rhs, &method)?,
+    ) {
+        (DataType::Float32, DataType::Float32) => DataType::Float32,
+        _ => DataType::Float64,
+    };
+    let lhs = cast_linalg_inner(lhs, &float_dtype)?;
+    let rhs = cast_linalg_inner(rhs, &float_dtype)?;
+
+    let out = match float_dtype {
+        DataType::Float32 => binary_rows::<Float32Type>(&lhs, &rhs, &f32_op).into_series(),
+        _ => binary_rows::<Float64Type>(&lhs, &rhs, &f64_op).into_series(),
+    };
+    Ok(out)
+}
+
+/// Per-row dot product of two arrays of equal width.
+pub fn array_dot(lhs: &ArrayChunked, rhs: &ArrayChunked) -> PolarsResult<Series> {
+    binary_linalg(lhs, rhs, "dot", f32::dot, f64::dot)
+}
+
+/// Per-row cosine similarity of two arrays of equal width. Rows where either
+/// vector has a zero norm give NaN.
+pub fn array_cosine_similarity(lhs: &ArrayChunked, rhs: &ArrayChunked) -> PolarsResult<Series> {
+    binary_linalg(
+        lhs,
+        rhs,
+        "cosine_similarity",
+        f32::cosine_similarity,
+        f64::cosine_similarity,
+    )
+}
+
+/// Per-row Euclidean distance between two arrays of equal width.
+pub fn array_l2_distance(lhs: &ArrayChunked, rhs: &ArrayChunked) -> PolarsResult<Series> {
+    binary_linalg(
+        lhs,
+        rhs,
+        "l2_distance",
+        |a, b| f32::squared_l2(a, b).sqrt(),
+        |a, b| f64::squared_l2(a, b).sqrt(),
+    )
+}
+
+fn check_norm_order(p: f64) -> PolarsResult<()> {
+    polars_ensure!(
+        p > 0.0 && !p.is_nan(),
+        InvalidOperation: "the order `p` of a norm must be positive, got {}", p
+    );
+    Ok(())
+}
+
+/// Per-row `p`-norm.
+pub fn array_norm(ca: &ArrayChunked, p: f64) -> PolarsResult<Series> {
+    check_norm_order(p)?;
+    let float_dtype = linalg_float_dtype(ca, "arr.norm")?;
+    let ca = cast_linalg_inner(ca, &float_dtype)?;
+
+    with_match_physical_float_polars_type!(float_dtype, |$T| {
+        let p: <$T as PolarsNumericType>::Native = NumCast::from(p).unwrap();
+        let chunks = ca.downcast_iter().map(|arr| {
+            array_rows(arr)
+                .map(|row| row.map(|a| VectorKernels::norm(a, p)))
+                .collect::<PrimitiveArray<_>>()
+        });
+        Ok(ChunkedArray::<$T>::from_chunk_iter(ca.name().clone(), chunks).into_series())
+    })
+}
+
+fn normalize_rows<T>(arr: &FixedSizeListArray, p: T) -> FixedSizeListArray
+where
+    T: NativeType + VectorKernels,
+{
+    let width = arr.size();
+    let mut values = Vec::with_capacity(arr.len() * width);
+    let mut validity = BitmapBuilder::with_capacity(arr.len());
+    for row in array_rows::<T>(arr) {
+        match row {
+            Some(row) => {
+                let norm = T::norm(row, p);
+                values.extend(row.iter().map(|x| *x / norm));
+                validity.push(true);
+            },
+            None => {
+                values.extend(std::iter::repeat_n(T::zero(), width));
+                validity.push(false);
+            },
+        }
+    }
+    let values = PrimitiveArray::<T>::from_vec(values);
+    FixedSizeListArray::new(
+        arr.dtype().clone(),
+        arr.len(),
+        values.boxed(),
+        validity.into_opt_validity(),
+    )
+}
+
+/// Divide every row by its `p`-norm. Rows with a zero norm give NaN elements.
+pub fn array_normalize(ca: &ArrayChunked, p: f64) -> PolarsResult<ArrayChunked> {
+    check_norm_order(p)?;
+    let float_dtype = linalg_float_dtype(ca, "arr.normalize")?;
+    let ca = cast_linalg_inner(ca, &float_dtype)?;
+
+    with_match_physical_float_polars_type!(float_dtype, |$T| {
+        let p: <$T as PolarsNumericType>::Native = NumCast::from(p).unwrap();
+        let out: ArrayChunked = unary_kernel(&ca, |arr| normalize_rows(arr, p));
+        Ok(out)
+    })
+}
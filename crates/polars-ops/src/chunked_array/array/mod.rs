@@ -1,17 +1,30 @@
 #[cfg(feature = "array_any_all")]
 mod any_all;
 mod count;
+#[cfg(feature = "diff")]
+mod diff;
 mod dispersion;
+mod gather;
 mod get;
 mod join;
+#[cfg(feature = "array_linalg")]
+mod linalg;
 mod min_max;
 mod namespace;
+#[cfg(feature = "list_sets")]
+mod sets;
 mod sum_mean;
 #[cfg(feature = "array_to_struct")]
 mod to_struct;
 
+#[cfg(feature = "diff")]
+pub use diff::array_diff_width;
+#[cfg(feature = "knn_join")]
+pub(crate) use linalg::{array_rows, cast_linalg_inner, linalg_float_dtype};
 pub use namespace::ArrayNameSpace;
 use polars_core::prelude::*;
+#[cfg(feature = "list_sets")]
+pub use sets::{array_contains_any, array_set_operation};
 #[cfg(feature = "array_to_struct")]
 pub use to_struct::*;
 
@@ -2,6 +2,8 @@ use arrow::array::builder::{ShareStrategy, make_builder};
 use arrow::array::{Array, FixedSizeListArray};
 use arrow::bitmap::BitmapBuilder;
 use polars_core::prelude::arity::unary_kernel;
+#[cfg(feature = "diff")]
+use polars_core::series::ops::NullBehavior;
 use polars_core::utils::slice_offsets;
 
 use super::min_max::AggType;
@@ -12,10 +14,17 @@ use crate::chunked_array::array::count::count_boolean_bits;
 use crate::chunked_array::array::sum_mean::sum_with_nulls;
 #[cfg(feature = "array_any_all")]
 use crate::prelude::array::any_all::{array_all, array_any};
+#[cfg(feature = "diff")]
+use crate::prelude::array::diff::array_diff;
+use crate::prelude::array::gather::array_gather;
+#[cfg(feature = "list_sample")]
+use crate::prelude::array::gather::{array_sample_fraction, array_sample_n};
 use crate::prelude::array::get::array_get;
 use crate::prelude::array::join::array_join;
+#[cfg(feature = "array_linalg")]
+use crate::prelude::array::linalg::*;
 use crate::prelude::array::sum_mean::sum_array_numerical;
-use crate::series::ArgAgg;
+use crate::series::{ArgAgg, SeriesMethods};
 
 pub fn has_inner_nulls(ca: &ArrayChunked) -> bool {
     for arr in ca.downcast_iter() {
@@ -113,6 +122,13 @@ pub trait ArrayNameSpace: AsArray {
         unsafe { ca.try_apply_amortized_same_type(|s| s.as_ref().sort_with(options)) }
     }
 
+    fn array_is_sorted(&self, options: SortOptions) -> PolarsResult<BooleanChunked> {
+        let ca = self.as_array();
+        ca.try_apply_amortized_generic(|opt_s| {
+            opt_s.map(|s| s.as_ref().is_sorted(options)).transpose()
+        })
+    }
+
     fn array_reverse(&self) -> ArrayChunked {
         let ca = self.as_array();
         // SAFETY: Reverse only changes the order of the elements in each subarray
@@ -138,6 +154,41 @@ pub trait ArrayNameSpace: AsArray {
         array_get(ca, index, null_on_oob)
     }
 
+    fn array_gather(&self, idx: &Series, null_on_oob: bool) -> PolarsResult<Series> {
+        let ca = self.as_array();
+        array_gather(ca, idx, null_on_oob)
+    }
+
+    #[cfg(feature = "diff")]
+    fn array_diff(&self, n: i64, null_behavior: NullBehavior) -> PolarsResult<Series> {
+        let ca = self.as_array();
+        array_diff(ca, n, null_behavior)
+    }
+
+    #[cfg(feature = "list_sample")]
+    fn array_sample_n(
+        &self,
+        n: &Series,
+        with_replacement: bool,
+        shuffle: bool,
+        seed: Option<u64>,
+    ) -> PolarsResult<ListChunked> {
+        let ca = self.as_array();
+        array_sample_n(ca, n, with_replacement, shuffle, seed)
+    }
+
+    #[cfg(feature = "list_sample")]
+    fn array_sample_fraction(
+        &self,
+        fraction: &Series,
+        with_replacement: bool,
+        shuffle: bool,
+        seed: Option<u64>,
+    ) -> PolarsResult<ListChunked> {
+        let ca = self.as_array();
+        array_sample_fraction(ca, fraction, with_replacement, shuffle, seed)
+    }
+
     fn array_join(&self, separator: &StringChunked, ignore_nulls: bool) -> PolarsResult<Series> {
         let ca = self.as_array();
         array_join(ca, separator, ignore_nulls).map(|ok| ok.into_series())
@@ -149,6 +200,36 @@ pub trait ArrayNameSpace: AsArray {
         array_count_matches(ca, element)
     }
 
+    #[cfg(feature = "array_linalg")]
+    fn array_dot(&self, other: &ArrayChunked) -> PolarsResult<Series> {
+        let ca = self.as_array();
+        array_dot(ca, other)
+    }
+
+    #[cfg(feature = "array_linalg")]
+    fn array_cosine_similarity(&self, other: &ArrayChunked) -> PolarsResult<Series> {
+        let ca = self.as_array();
+        array_cosine_similarity(ca, other)
+    }
+
+    #[cfg(feature = "array_linalg")]
+    fn array_l2_distance(&self, other: &ArrayChunked) -> PolarsResult<Series> {
+        let ca = self.as_array();
+        array_l2_distance(ca, other)
+    }
+
+    #[cfg(feature = "array_linalg")]
+    fn array_norm(&self, p: f64) -> PolarsResult<Series> {
+        let ca = self.as_array();
+        array_norm(ca, p)
+    }
+
+    #[cfg(feature = "array_linalg")]
+    fn array_normalize(&self, p: f64) -> PolarsResult<ArrayChunked> {
+        let ca = self.as_array();
+        array_normalize(ca, p)
+    }
+
     fn array_shift(&self, n: &Series) -> PolarsResult<Series> {
         let ca = self.as_array();
         let n_s = n.cast(&DataType::Int64)?;
@@ -0,0 +1,77 @@
+use arrow::array::{Array, BooleanArray, FixedSizeListArray};
+use arrow::bitmap::Bitmap;
+use arrow::compute::utils::combine_validities_and;
+use arrow::datatypes::ArrowDataType;
+
+use super::*;
+use crate::chunked_array::list::{SetOperation, set_operation_on_values};
+
+fn implied_offsets(arr: &FixedSizeListArray) -> Vec<i64> {
+    (0..=arr.len()).map(|i| (i * arr.size()) as i64).collect()
+}
+
+/// The validity of a side that is broadcast to `len` rows.
+fn broadcast_validity(arr: &FixedSizeListArray, len: usize) -> Option<Bitmap> {
+    (!arr.is_valid(0)).then(|| Bitmap::new_zeroed(len))
+}
+
+/// Apply a set operation to the elements of every pair of arrays. The arrays may be of different
+/// widths, so the result is a `List`.
+pub fn array_set_operation(
+    a: &ArrayChunked,
+    b: &ArrayChunked,
+    set_op: SetOperation,
+) -> PolarsResult<ListChunked> {
+    polars_ensure!(a.len() == b.len() || b.len() == 1 || a.len() == 1, ShapeMismatch: "column lengths don't match");
+    polars_ensure!(
+        a.inner_dtype() == b.inner_dtype(),
+        InvalidOperation: "cannot do 'set' operation on dtypes: {} and {}", a.dtype(), b.dtype()
+    );
+    let inner_dtype = a.inner_dtype().clone();
+    if a.is_empty() || b.is_empty() {
+        return Ok(ListChunked::full_null_with_dtype(
+            a.name().clone(),
+            0,
+            &inner_dtype,
+        ));
+    }
+
+    let a = a.rechunk();
+    let b = b.rechunk();
+    let a_arr = a.downcast_as_array();
+    let b_arr = b.downcast_as_array();
+    let validity = match (a.len(), b.len()) {
+        (1, len) if len != 1 => {
+            combine_validities_and(broadcast_validity(a_arr, len).as_ref(), b_arr.validity())
+        },
+        (len, 1) if len != 1 => {
+            combine_validities_and(a_arr.validity(), broadcast_validity(b_arr, len).as_ref())
+        },
+        _ => combine_validities_and(a_arr.validity(), b_arr.validity()),
+    };
+
+    let out = set_operation_on_values(
+        a_arr.values().as_ref(),
+        b_arr.values().as_ref(),
+        &implied_offsets(a_arr),
+        &implied_offsets(b_arr),
+        set_op,
+        validity,
+    )?;
+    // SAFETY: the kernel works on the physical values, which keep their logical meaning.
+    let out = unsafe {
+        ListChunked::from_chunks(a.name().clone(), vec![out.boxed()])
+            .from_physical_unchecked(inner_dtype)?
+    };
+    Ok(out)
+}
+
+/// Check whether every pair of arrays has an element in common.
+pub fn array_contains_any(a: &ArrayChunked, b: &ArrayChunked) -> PolarsResult<BooleanChunked> {
+    let common = array_set_operation(a, b, SetOperation::Intersection)?;
+    let common = common.rechunk();
+    let common = common.downcast_as_array();
+    let values = common.offsets().lengths().map(|len| len > 0).collect();
+    let out = BooleanArray::new(ArrowDataType::Boolean, values, common.validity().cloned());
+    Ok(BooleanChunked::with_chunk(a.name().clone(), out))
+}
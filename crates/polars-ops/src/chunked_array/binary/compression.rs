@@ -0,0 +1,152 @@
+//! Compression of binary values with the codecs of the parquet writer.
+//!
+//! Every value is compressed on its own. LZ4 values are raw blocks prefixed with their decompressed
+//! length as a little-endian `u32`, as `lz4.block.compress` writes them by default. Snappy values
+//! are raw blocks as well; the LZ4 frame format and the snappy framing format are not supported.
+use std::borrow::Cow;
+use std::fmt::{Display, Formatter};
+
+use polars_core::prelude::*;
+use polars_parquet::parquet::compression::{
+    BrotliLevel, Compression, CompressionOptions, DecompressionContext, GzipLevel, ZstdLevel,
+    compress as compress_value, decompress as decompress_exact, decompress_to_vec,
+};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+const LZ4_PREFIX_LEN: usize = size_of::<u32>();
+/// LZ4 can't expand its input by more than this factor, so larger size prefixes are corrupt.
+const LZ4_MAX_RATIO: usize = 255;
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub enum BinaryCodec {
+    Gzip,
+    Zstd,
+    Brotli,
+    Snappy,
+    Lz4,
+}
+
+impl BinaryCodec {
+    fn compression(self) -> Compression {
+        match self {
+            Self::Gzip => Compression::Gzip,
+            Self::Zstd => Compression::Zstd,
+            Self::Brotli => Compression::Brotli,
+            Self::Snappy => Compression::Snappy,
+            Self::Lz4 => Compression::Lz4Raw,
+        }
+    }
+
+    fn compression_options(self, level: Option<i32>) -> PolarsResult<CompressionOptions> {
+        let invalid_level = |level| polars_err!(InvalidOperation: "invalid compression level {} for {}", level, self);
+        Ok(match self {
+            Self::Gzip => CompressionOptions::Gzip(
+                level
+                    .map(|l| {
+                        u8::try_from(l)
+                            .map_err(|_| invalid_level(l))
+                            .and_then(GzipLevel::try_new)
+                    })
+                    .transpose()?,
+            ),
+            Self::Zstd => CompressionOptions::Zstd(level.map(ZstdLevel::try_new).transpose()?),
+            Self::Brotli => CompressionOptions::Brotli(
+                level
+                    .map(|l| {
+                        u32::try_from(l)
+                            .map_err(|_| invalid_level(l))
+                            .and_then(BrotliLevel::try_new)
+                    })
+                    .transpose()?,
+            ),
+            Self::Snappy | Self::Lz4 => {
+                polars_ensure!(
+                    level.is_none(),
+                    InvalidOperation: "{} does not support a compression level", self
+                );
+                if self == Self::Snappy {
+                    CompressionOptions::Snappy
+                } else {
+                    CompressionOptions::Lz4Raw
+                }
+            },
+        })
+    }
+}
+
+impl Display for BinaryCodec {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            Self::Gzip => "gzip",
+            Self::Zstd => "zstd",
+            Self::Brotli => "brotli",
+            Self::Snappy => "snappy",
+            Self::Lz4 => "lz4",
+        };
+        write!(f, "{s}")
+    }
+}
+
+pub(super) fn compress(
+    ca: &BinaryChunked,
+    codec: BinaryCodec,
+    level: Option<i32>,
+) -> PolarsResult<BinaryChunked> {
+    let options = codec.compression_options(level)?;
+    ca.try_apply_nonnull_values_generic(|v| {
+        let mut out = Vec::new();
+        if codec == BinaryCodec::Lz4 {
+            let len = u32::try_from(v.len()).map_err(
+                |_| polars_err!(ComputeError: "lz4 can't compress values larger than 4 GiB"),
+            )?;
+            out.extend_from_slice(&len.to_le_bytes());
+        }
+        compress_value(options, v, &mut out)?;
+        PolarsResult::Ok(out)
+    })
+}
+
+fn decompress_one(codec: BinaryCodec, v: &[u8]) -> PolarsResult<Vec<u8>> {
+    let mut out = Vec::new();
+    if codec == BinaryCodec::Lz4 {
+        polars_ensure!(v.len() >= LZ4_PREFIX_LEN, ComputeError: "missing lz4 length prefix");
+        let (prefix, block) = v.split_at(LZ4_PREFIX_LEN);
+        let len = u32::from_le_bytes(prefix.try_into().unwrap()) as usize;
+        polars_ensure!(
+            len <= block.len().saturating_mul(LZ4_MAX_RATIO),
+            ComputeError: "lz4 length prefix {} is too large for a block of {} bytes", len, block.len()
+        );
+        out.resize(len, 0);
+        decompress_exact(
+            codec.compression(),
+            block,
+            &mut out,
+            &mut DecompressionContext::Unset,
+        )?;
+    } else {
+        decompress_to_vec(codec.compression(), v, &mut out)?;
+    }
+    Ok(out)
+}
+
+pub(super) fn decompress(
+    ca: &BinaryChunked,
+    codec: BinaryCodec,
+    strict: bool,
+) -> PolarsResult<BinaryChunked> {
+    if strict {
+        ca.try_apply_nonnull_values_generic(|v| {
+            decompress_one(codec, v).map_err(|e| {
+                polars_err!(
+                    ComputeError:
+                    "invalid `{}` data found ({}); try setting `strict=false` to ignore", codec, e
+                )
+            })
+        })
+    } else {
+        Ok(ca.apply(|opt_v| opt_v.and_then(|v| decompress_one(codec, v).ok().map(Cow::Owned))))
+    }
+}
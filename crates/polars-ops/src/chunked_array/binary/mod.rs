@@ -1,5 +1,9 @@
+#[cfg(feature = "binary_compression")]
+mod compression;
 mod namespace;
 
+#[cfg(feature = "binary_compression")]
+pub use compression::BinaryCodec;
 pub use namespace::*;
 use polars_core::prelude::*;
 
@@ -217,6 +217,20 @@ pub trait BinaryNameSpaceImpl: AsBinary {
             ),
         }
     }
+
+    /// Compress every value on its own with `codec`. The codec's default level is used when
+    /// `level` is `None`.
+    #[cfg(feature = "binary_compression")]
+    fn compress(&self, codec: BinaryCodec, level: Option<i32>) -> PolarsResult<BinaryChunked> {
+        super::compression::compress(self.as_binary(), codec, level)
+    }
+
+    /// Decompress values written by [`compress`](Self::compress). Invalid values raise an error
+    /// when `strict` and give a null otherwise.
+    #[cfg(feature = "binary_compression")]
+    fn decompress(&self, codec: BinaryCodec, strict: bool) -> PolarsResult<BinaryChunked> {
+        super::compression::decompress(self.as_binary(), codec, strict)
+    }
 }
 
 impl BinaryNameSpaceImpl for BinaryChunked {}
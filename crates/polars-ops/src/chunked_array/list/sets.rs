@@ -373,15 +373,30 @@ fn array_set_operation(
     b: &ListArray<i64>,
     set_op: SetOperation,
 ) -> PolarsResult<ListArray<i64>> {
-    let offsets_a = a.offsets().as_slice();
-    let offsets_b = b.offsets().as_slice();
+    let validity = combine_validities_and(a.validity(), b.validity());
+    set_operation_on_values(
+        a.values().as_ref(),
+        b.values().as_ref(),
+        a.offsets().as_slice(),
+        b.offsets().as_slice(),
+        set_op,
+        validity,
+    )
+}
 
-    let values_a = a.values();
-    let values_b = b.values();
+/// Apply `set_op` to the sublists `values[offsets[i]..offsets[i + 1]]` of `a` and `b`. A side
+/// with a single sublist is broadcast.
+pub(crate) fn set_operation_on_values(
+    values_a: &dyn Array,
+    values_b: &dyn Array,
+    offsets_a: &[i64],
+    offsets_b: &[i64],
+    set_op: SetOperation,
+    validity: Option<Bitmap>,
+) -> PolarsResult<ListArray<i64>> {
     assert_eq!(values_a.dtype(), values_b.dtype());
 
     let dtype = values_b.dtype();
-    let validity = combine_validities_and(a.validity(), b.validity());
 
     match dtype {
         ArrowDataType::Utf8View => {
@@ -363,6 +363,18 @@ pub trait StringNameSpaceImpl: AsString {
         Ok(ca.apply_values(f))
     }
 
+    /// Replace the first `n` regex-matched (sub)strings with another string
+    fn replace_n<'a>(&'a self, pat: &str, val: &str, n: usize) -> PolarsResult<StringChunked> {
+        let ca = self.as_string();
+        // `Regex::replacen` replaces all matches for a limit of 0.
+        if n == 0 {
+            return Ok(ca.clone());
+        }
+        let reg = polars_utils::regex_cache::compile_regex(pat)?;
+        let f = |s: &'a str| reg.replacen(s, n, val);
+        Ok(ca.apply_values(f))
+    }
+
     /// Replace the leftmost literal (sub)string with another string
     fn replace_literal<'a>(
         &'a self,
@@ -539,6 +551,20 @@ pub trait StringNameSpaceImpl: AsString {
         split_helper(ca, by, str::split_inclusive)
     }
 
+    /// Split the strings on the matches of the regex `pat`.
+    ///
+    /// With `inclusive` every match is kept at the end of the preceding item. With a `limit` at
+    /// most `limit` items are returned and the last one holds the remainder of the string.
+    fn split_regex(
+        &self,
+        pat: &StringChunked,
+        limit: Option<usize>,
+        inclusive: bool,
+    ) -> PolarsResult<ListChunked> {
+        let ca = self.as_string();
+        split_regex_helper(ca, pat, limit, inclusive)
+    }
+
     /// Extract each successive non-overlapping regex match in an individual string as an array.
     fn extract_all_many(&self, pat: &StringChunked) -> PolarsResult<ListChunked> {
         let ca = self.as_string();
@@ -666,6 +692,60 @@ pub trait StringNameSpaceImpl: AsString {
         reverse::reverse(ca)
     }
 
+    /// Levenshtein distance to the values of a second StringChunked.
+    #[cfg(feature = "string_similarity")]
+    fn str_levenshtein(&self, other: &StringChunked) -> UInt32Chunked {
+        similarity::levenshtein_ca(self.as_string(), other)
+    }
+
+    /// Damerau-Levenshtein distance to the values of a second StringChunked.
+    #[cfg(feature = "string_similarity")]
+    fn str_damerau_levenshtein(&self, other: &StringChunked) -> UInt32Chunked {
+        similarity::damerau_levenshtein_ca(self.as_string(), other)
+    }
+
+    /// Hamming distance to the values of a second StringChunked. Null if the lengths differ.
+    #[cfg(feature = "string_similarity")]
+    fn str_hamming(&self, other: &StringChunked) -> UInt32Chunked {
+        similarity::hamming_ca(self.as_string(), other)
+    }
+
+    /// Jaro-Winkler similarity to the values of a second StringChunked.
+    #[cfg(feature = "string_similarity")]
+    fn str_jaro_winkler(&self, other: &StringChunked) -> Float64Chunked {
+        similarity::jaro_winkler_ca(self.as_string(), other)
+    }
+
+    /// Jaccard similarity of the character n-grams with the values of a second StringChunked.
+    #[cfg(feature = "string_similarity")]
+    fn str_ngram_jaccard(&self, other: &StringChunked, n: usize) -> PolarsResult<Float64Chunked> {
+        similarity::ngram_jaccard_ca(self.as_string(), other, n)
+    }
+
+    /// Split URLs into a struct of scheme, user, host, port, path, query and fragment.
+    #[cfg(feature = "string_url")]
+    fn url_parse(&self, strict: bool) -> PolarsResult<StructChunked> {
+        url_parse(self.as_string(), strict)
+    }
+
+    /// Get the decoded value of the first query parameter `name` of URLs.
+    #[cfg(feature = "string_url")]
+    fn url_query_param(&self, name: &str, strict: bool) -> PolarsResult<StringChunked> {
+        url_query_param(self.as_string(), name, strict)
+    }
+
+    /// Decode percent-encoded strings.
+    #[cfg(feature = "string_url")]
+    fn percent_decode(&self, strict: bool) -> PolarsResult<StringChunked> {
+        percent_decode(self.as_string(), strict)
+    }
+
+    /// Percent-encode all characters except `A-Z a-z 0-9 - . _ ~`.
+    #[cfg(feature = "string_url")]
+    fn percent_encode(&self) -> StringChunked {
+        percent_encode(self.as_string())
+    }
+
     /// Slice the string values.
     ///
     /// Determines a substring starting from `offset` and with length `length` of each of the elements in `array`.
@@ -0,0 +1,283 @@
+//! Edit distances and similarity metrics of strings.
+//!
+//! All metrics work on Unicode scalar values (`char`s), not on bytes.
+use polars_core::prelude::arity::broadcast_binary_elementwise;
+use polars_core::prelude::*;
+
+/// The Levenshtein distance: the minimal number of single-character insertions, deletions and
+/// substitutions to turn `a` into `b`.
+pub fn levenshtein(a: &str, b: &str) -> usize {
+    let a = a.chars().collect::<Vec<_>>();
+    let b = b.chars().collect::<Vec<_>>();
+    levenshtein_chars(&a, &b)
+}
+
+pub(crate) fn levenshtein_chars(a: &[char], b: &[char]) -> usize {
+    let (a, b) = if a.len() < b.len() { (b, a) } else { (a, b) };
+    if b.is_empty() {
+        return a.len();
+    }
+
+    // Single row of the dynamic programming matrix, over the shorter string.
+    let mut row = (0..=b.len()).collect::<Vec<_>>();
+    for (i, ca) in a.iter().enumerate() {
+        let mut diagonal = row[0];
+        row[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = diagonal + (ca != cb) as usize;
+            diagonal = row[j + 1];
+            row[j + 1] = substitution.min(row[j] + 1).min(diagonal + 1);
+        }
+    }
+    row[b.len()]
+}
+
+/// The Damerau-Levenshtein distance: the Levenshtein distance that also allows transpositions of
+/// two adjacent characters as a single edit.
+///
+/// This is the unrestricted distance, which allows editing a substring more than once, so it is a
+/// true metric (unlike the optimal string alignment distance).
+pub fn damerau_levenshtein(a: &str, b: &str) -> usize {
+    let a = a.chars().collect::<Vec<_>>();
+    let b = b.chars().collect::<Vec<_>>();
+    damerau_levenshtein_chars(&a, &b)
+}
+
+pub(crate) fn damerau_levenshtein_chars(a: &[char], b: &[char]) -> usize {
+    if a.is_empty() || b.is_empty() {
+        return a.len().max(b.len());
+    }
+
+    let width = b.len() + 2;
+    let max_distance = a.len() + b.len();
+    // The matrix is shifted by one row and column to hold the `max_distance` sentinels.
+    let mut d = vec![0; (a.len() + 2) * width];
+    d[0] = max_distance;
+    for i in 0..=a.len() {
+        d[(i + 1) * width] = max_distance;
+        d[(i + 1) * width + 1] = i;
+    }
+    for j in 0..=b.len() {
+        d[j + 1] = max_distance;
+        d[width + j + 1] = j;
+    }
+
+    // Last row in which every character of `a` occurred.
+    let mut last_row = PlHashMap::<char, usize>::new();
+    for i in 1..=a.len() {
+        // Last column in this row where the characters matched.
+        let mut last_match_col = 0;
+        for j in 1..=b.len() {
+            let i1 = last_row.get(&b[j - 1]).copied().unwrap_or(0);
+            let j1 = last_match_col;
+            let cost = if a[i - 1] == b[j - 1] {
+                last_match_col = j;
+                0
+            } else {
+                1
+            };
+            let transposition = d[i1 * width + j1] + (i - i1 - 1) + 1 + (j - j1 - 1);
+            d[(i + 1) * width + j + 1] = (d[i * width + j] + cost)
+                .min(d[(i + 1) * width + j] + 1)
+                .min(d[i * width + j + 1] + 1)
+                .min(transposition);
+        }
+        last_row.insert(a[i - 1], i);
+    }
+    d[(a.len() + 1) * width + b.len() + 1]
+}
+
+/// The Hamming distance: the number of positions at which the characters differ.
+///
+/// Returns `None` if the strings have a different number of characters.
+pub fn hamming(a: &str, b: &str) -> Option<usize> {
+    let mut a = a.chars();
+    let mut b = b.chars();
+    let mut distance = 0;
+    loop {
+        match (a.next(), b.next()) {
+            (Some(ca), Some(cb)) => distance += (ca != cb) as usize,
+            (None, None) => return Some(distance),
+            _ => return None,
+        }
+    }
+}
+
+/// The Jaro similarity in `[0, 1]`, where `1` means equal strings.
+pub(crate) fn jaro_chars(a: &[char], b: &[char]) -> f64 {
+    if a.is_empty() && b.is_empty() {
+        return 1.0;
+    }
+    if a.is_empty() || b.is_empty() {
+        return 0.0;
+    }
+
+    // Characters only match if they are not further apart than this.
+    let window = (a.len().max(b.len()) / 2).saturating_sub(1);
+    let mut b_matched = vec![false; b.len()];
+    let mut a_matches = Vec::with_capacity(a.len());
+    for (i, ca) in a.iter().enumerate() {
+        let start = i.saturating_sub(window);
+        let end = (i + window + 1).min(b.len());
+        for j in start..end {
+            if !b_matched[j] && b[j] == *ca {
+                b_matched[j] = true;
+                a_matches.push(*ca);
+                break;
+            }
+        }
+    }
+    let m = a_matches.len();
+    if m == 0 {
+        return 0.0;
+    }
+
+    let b_matches = b
+        .iter()
+        .zip(&b_matched)
+        .filter_map(|(cb, matched)| matched.then_some(cb));
+    let half_transpositions = a_matches
+        .iter()
+        .zip(b_matches)
+        .filter(|(ca, cb)| ca != cb)
+        .count();
+
+    let m = m as f64;
+    let t = (half_transpositions / 2) as f64;
+    (m / a.len() as f64 + m / b.len() as f64 + (m - t) / m) / 3.0
+}
+
+/// Maximal length of the common prefix that is rewarded by the Jaro-Winkler similarity.
+const JARO_WINKLER_MAX_PREFIX: usize = 4;
+/// Scaling factor of the common prefix in the Jaro-Winkler similarity.
+pub(crate) const JARO_WINKLER_PREFIX_SCALE: f64 = 0.1;
+
+pub(crate) fn jaro_winkler_chars(a: &[char], b: &[char]) -> f64 {
+    let jaro = jaro_chars(a, b);
+    let prefix = a
+        .iter()
+        .zip(b)
+        .take(JARO_WINKLER_MAX_PREFIX)
+        .take_while(|(ca, cb)| ca == cb)
+        .count();
+    jaro + prefix as f64 * JARO_WINKLER_PREFIX_SCALE * (1.0 - jaro)
+}
+
+/// The Jaro-Winkler similarity in `[0, 1]`, where `1` means equal strings.
+///
+/// This is the Jaro similarity, boosted for strings that share a common prefix of up to 4
+/// characters with a scaling factor of `0.1`.
+pub fn jaro_winkler(a: &str, b: &str) -> f64 {
+    let a = a.chars().collect::<Vec<_>>();
+    let b = b.chars().collect::<Vec<_>>();
+    jaro_winkler_chars(&a, &b)
+}
+
+/// The character n-grams of `s`.
+///
+/// A non-empty string that is shorter than `n` characters is its own single n-gram.
+pub fn ngrams(s: &str, n: usize) -> impl Iterator<Item = &str> {
+    assert!(n > 0);
+    let boundaries = s
+        .char_indices()
+        .map(|(i, _)| i)
+        .chain(std::iter::once(s.len()))
+        .collect::<Vec<_>>();
+    let n_grams = if s.is_empty() {
+        0
+    } else {
+        boundaries.len().saturating_sub(n).max(1)
+    };
+    (0..n_grams).map(move |i| &s[boundaries[i]..boundaries[(i + n).min(boundaries.len() - 1)]])
+}
+
+/// The Jaccard similarity of the sets of character n-grams of `a` and `b` in `[0, 1]`.
+///
+/// Two empty strings have a similarity of `1`.
+pub fn ngram_jaccard(a: &str, b: &str, n: usize) -> f64 {
+    let a = ngrams(a, n).collect::<PlHashSet<_>>();
+    let b = ngrams(b, n).collect::<PlHashSet<_>>();
+    jaccard(&a, &b)
+}
+
+pub(crate) fn jaccard(a: &PlHashSet<&str>, b: &PlHashSet<&str>) -> f64 {
+    if a.is_empty() && b.is_empty() {
+        return 1.0;
+    }
+    let intersection = a.intersection(b).count();
+    intersection as f64 / (a.len() + b.len() - intersection) as f64
+}
+
+pub fn levenshtein_ca(ca: &StringChunked, other: &StringChunked) -> UInt32Chunked {
+    broadcast_binary_elementwise(ca, other, |a: Option<&str>, b: Option<&str>| {
+        Some(levenshtein(a?, b?) as u32)
+    })
+}
+
+pub fn damerau_levenshtein_ca(ca: &StringChunked, other: &StringChunked) -> UInt32Chunked {
+    broadcast_binary_elementwise(ca, other, |a: Option<&str>, b: Option<&str>| {
+        Some(damerau_levenshtein(a?, b?) as u32)
+    })
+}
+
+pub fn hamming_ca(ca: &StringChunked, other: &StringChunked) -> UInt32Chunked {
+    broadcast_binary_elementwise(ca, other, |a: Option<&str>, b: Option<&str>| {
+        hamming(a?, b?).map(|d| d as u32)
+    })
+}
+
+pub fn jaro_winkler_ca(ca: &StringChunked, other: &StringChunked) -> Float64Chunked {
+    broadcast_binary_elementwise(ca, other, |a: Option<&str>, b: Option<&str>| {
+        Some(jaro_winkler(a?, b?))
+    })
+}
+
+pub fn ngram_jaccard_ca(
+    ca: &StringChunked,
+    other: &StringChunked,
+    n: usize,
+) -> PolarsResult<Float64Chunked> {
+    polars_ensure!(n > 0, InvalidOperation: "n-gram size must be positive");
+    Ok(broadcast_binary_elementwise(
+        ca,
+        other,
+        |a: Option<&str>, b: Option<&str>| Some(ngram_jaccard(a?, b?, n)),
+    ))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_edit_distances() {
+        assert_eq!(levenshtein("kitten", "sitting"), 3);
+        assert_eq!(levenshtein("", "abc"), 3);
+        assert_eq!(levenshtein("flaw", "lawn"), 2);
+        assert_eq!(levenshtein("ça", "ca"), 1);
+
+        assert_eq!(damerau_levenshtein("ca", "ac"), 1);
+        assert_eq!(levenshtein("ca", "ac"), 2);
+        // The optimal string alignment distance would be 3 here.
+        assert_eq!(damerau_levenshtein("ca", "abc"), 2);
+        assert_eq!(damerau_levenshtein("kitten", "sitting"), 3);
+
+        assert_eq!(hamming("karolin", "kathrin"), Some(3));
+        assert_eq!(hamming("abc", "ab"), None);
+    }
+
+    #[test]
+    fn test_similarities() {
+        assert!((jaro_winkler("MARTHA", "MARHTA") - 0.961).abs() < 1e-3);
+        assert!((jaro_winkler("DIXON", "DICKSONX") - 0.813).abs() < 1e-3);
+        assert_eq!(jaro_winkler("abc", "xyz"), 0.0);
+        assert_eq!(jaro_winkler("", ""), 1.0);
+
+        assert_eq!(ngrams("abcd", 2).collect::<Vec<_>>(), ["ab", "bc", "cd"]);
+        assert_eq!(ngrams("a", 2).collect::<Vec<_>>(), ["a"]);
+        assert_eq!(ngrams("", 2).count(), 0);
+        // {ni, ig, gh, ht} and {na, ac, ch, ht}
+        assert_eq!(ngram_jaccard("night", "nacht", 2), 1.0 / 7.0);
+        assert_eq!(ngram_jaccard("", "", 2), 1.0);
+    }
+}
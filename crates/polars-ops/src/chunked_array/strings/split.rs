@@ -2,6 +2,8 @@ use arrow::array::ValueSize;
 #[cfg(feature = "dtype-struct")]
 use arrow::array::{MutableArray, MutableUtf8Array};
 use polars_core::chunked_array::ops::arity::binary_elementwise_for_each;
+use polars_utils::regex_cache::with_regex_cache;
+use regex::Regex;
 
 use super::*;
 
@@ -223,3 +225,73 @@ where
         _ => polars_bail!(length_mismatch = "str.split", ca.len(), by.len()),
     })
 }
+
+/// Split `s` on the matches of `re` into `out`. With `inclusive` every match is kept at the end of
+/// the preceding item. A `limit` caps the number of items, the last one holds the remainder.
+fn split_regex_into<'a>(
+    re: &Regex,
+    s: &'a str,
+    limit: Option<usize>,
+    inclusive: bool,
+    out: &mut Vec<&'a str>,
+) {
+    out.clear();
+    let limit = limit.unwrap_or(usize::MAX);
+    if limit == 0 {
+        return;
+    }
+    let mut last_end = 0;
+    for m in re.find_iter(s) {
+        if out.len() + 1 == limit {
+            break;
+        }
+        let end = if inclusive { m.end() } else { m.start() };
+        out.push(&s[last_end..end]);
+        last_end = m.end();
+    }
+    // Like `str::split_inclusive`, a match at the end of the string doesn't start a new item.
+    if !inclusive || last_end < s.len() {
+        out.push(&s[last_end..]);
+    }
+}
+
+pub fn split_regex_helper(
+    ca: &StringChunked,
+    pat: &StringChunked,
+    limit: Option<usize>,
+    inclusive: bool,
+) -> PolarsResult<ListChunked> {
+    let len = match (ca.len(), pat.len()) {
+        (a, b) if a == b => a,
+        (1, b) => b,
+        (a, 1) => a,
+        (a, b) => polars_bail!(length_mismatch = "str.split_regex", a, b),
+    };
+    let values: Box<dyn Iterator<Item = Option<&str>>> = if ca.len() == len {
+        Box::new(ca.iter())
+    } else {
+        Box::new(std::iter::repeat_n(ca.get(0), len))
+    };
+    let pats: Box<dyn Iterator<Item = Option<&str>>> = if pat.len() == len {
+        Box::new(pat.iter())
+    } else {
+        Box::new(std::iter::repeat_n(pat.get(0), len))
+    };
+
+    let mut builder = ListStringChunkedBuilder::new(ca.name().clone(), len, ca.get_values_size());
+    let mut parts = Vec::new();
+    with_regex_cache(|re_cache| {
+        for (opt_s, opt_pat) in values.zip(pats) {
+            match (opt_s, opt_pat) {
+                (Some(s), Some(pat)) => {
+                    let re = re_cache.compile(pat)?;
+                    split_regex_into(re, s, limit, inclusive, &mut parts);
+                    builder.append_values_iter(parts.drain(..));
+                },
+                _ => builder.append_null(),
+            }
+        }
+        PolarsResult::Ok(())
+    })?;
+    Ok(builder.finish())
+}
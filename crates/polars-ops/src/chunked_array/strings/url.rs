@@ -0,0 +1,137 @@
+//! Parsing of URLs and percent-encoding of strings.
+//!
+//! URLs are parsed according to the WHATWG URL standard. Invalid URLs raise an error when `strict`
+//! and give a null otherwise.
+use std::borrow::Cow;
+
+use arrow::bitmap::BitmapBuilder;
+use percent_encoding::{AsciiSet, NON_ALPHANUMERIC, percent_decode_str, utf8_percent_encode};
+use polars_core::prelude::*;
+use url::Url;
+
+/// Everything except the unreserved characters of RFC 3986 is percent-encoded.
+const COMPONENT: &AsciiSet = &NON_ALPHANUMERIC
+    .remove(b'-')
+    .remove(b'.')
+    .remove(b'_')
+    .remove(b'~');
+
+fn parse(s: &str, strict: bool) -> PolarsResult<Option<Url>> {
+    match Url::parse(s) {
+        Ok(url) => Ok(Some(url)),
+        Err(e) if strict => polars_bail!(
+            ComputeError: "invalid URL '{}' found ({}); try setting `strict=false` to ignore", s, e
+        ),
+        Err(_) => Ok(None),
+    }
+}
+
+/// The dtype returned by [`url_parse`].
+pub fn url_parse_dtype() -> DataType {
+    DataType::Struct(vec![
+        Field::new(PlSmallStr::from_static("scheme"), DataType::String),
+        Field::new(PlSmallStr::from_static("user"), DataType::String),
+        Field::new(PlSmallStr::from_static("host"), DataType::String),
+        Field::new(PlSmallStr::from_static("port"), DataType::UInt16),
+        Field::new(PlSmallStr::from_static("path"), DataType::String),
+        Field::new(PlSmallStr::from_static("query"), DataType::String),
+        Field::new(PlSmallStr::from_static("fragment"), DataType::String),
+    ])
+}
+
+/// Split the URLs into their components. Empty components are null and the port falls back to the
+/// default port of the scheme.
+pub fn url_parse(ca: &StringChunked, strict: bool) -> PolarsResult<StructChunked> {
+    let mut scheme = StringChunkedBuilder::new(PlSmallStr::from_static("scheme"), ca.len());
+    let mut user = StringChunkedBuilder::new(PlSmallStr::from_static("user"), ca.len());
+    let mut host = StringChunkedBuilder::new(PlSmallStr::from_static("host"), ca.len());
+    let mut port =
+        PrimitiveChunkedBuilder::<UInt16Type>::new(PlSmallStr::from_static("port"), ca.len());
+    let mut path = StringChunkedBuilder::new(PlSmallStr::from_static("path"), ca.len());
+    let mut query = StringChunkedBuilder::new(PlSmallStr::from_static("query"), ca.len());
+    let mut fragment = StringChunkedBuilder::new(PlSmallStr::from_static("fragment"), ca.len());
+    let mut validity = BitmapBuilder::with_capacity(ca.len());
+
+    for opt_s in ca.iter() {
+        let url = match opt_s {
+            Some(s) => parse(s, strict)?,
+            None => None,
+        };
+        validity.push(url.is_some());
+        match url {
+            Some(url) => {
+                scheme.append_value(url.scheme());
+                user.append_option(Some(url.username()).filter(|u| !u.is_empty()));
+                host.append_option(url.host_str().filter(|h| !h.is_empty()));
+                port.append_option(url.port_or_known_default());
+                path.append_option(Some(url.path()).filter(|p| !p.is_empty()));
+                query.append_option(url.query().filter(|q| !q.is_empty()));
+                fragment.append_option(url.fragment().filter(|f| !f.is_empty()));
+            },
+            None => {
+                scheme.append_null();
+                user.append_null();
+                host.append_null();
+                port.append_null();
+                path.append_null();
+                query.append_null();
+                fragment.append_null();
+            },
+        }
+    }
+
+    let fields = [
+        scheme.finish().into_series(),
+        user.finish().into_series(),
+        host.finish().into_series(),
+        port.finish().into_series(),
+        path.finish().into_series(),
+        query.finish().into_series(),
+        fragment.finish().into_series(),
+    ];
+    let out = StructChunked::from_series(ca.name().clone(), ca.len(), fields.iter())?;
+    Ok(out.with_outer_validity(validity.into_opt_validity()))
+}
+
+/// Get the decoded value of the first query parameter called `name`.
+pub fn url_query_param(
+    ca: &StringChunked,
+    name: &str,
+    strict: bool,
+) -> PolarsResult<StringChunked> {
+    let mut builder = StringChunkedBuilder::new(ca.name().clone(), ca.len());
+    for opt_s in ca.iter() {
+        let url = match opt_s {
+            Some(s) => parse(s, strict)?,
+            None => None,
+        };
+        let value = url.and_then(|url| {
+            url.query_pairs()
+                .find(|(key, _)| key == name)
+                .map(|(_, value)| value.into_owned())
+        });
+        builder.append_option(value);
+    }
+    Ok(builder.finish())
+}
+
+/// Decode `%XX` escapes. Values that don't decode to valid UTF-8 are invalid.
+pub fn percent_decode(ca: &StringChunked, strict: bool) -> PolarsResult<StringChunked> {
+    if strict {
+        ca.try_apply_nonnull_values_generic(|s| {
+            percent_decode_str(s).decode_utf8().map_err(|_| {
+                polars_err!(
+                    ComputeError:
+                    "invalid percent-encoding found; try setting `strict=false` to ignore"
+                )
+            })
+        })
+    } else {
+        Ok(ca.apply(|opt_s| opt_s.and_then(|s| percent_decode_str(s).decode_utf8().ok())))
+    }
+}
+
+/// Percent-encode everything except the unreserved characters `A-Z a-z 0-9 - . _ ~`.
+pub fn percent_encode(ca: &StringChunked) -> StringChunked {
+    ca.apply_values(|s| Cow::from(utf8_percent_encode(s, COMPONENT)))
+}
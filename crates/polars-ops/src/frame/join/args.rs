@@ -57,6 +57,10 @@ pub enum JoinType {
     #[cfg(feature = "iejoin")]
     // Options are set by optimizer/planner in Options
     IEJoin,
+    #[cfg(feature = "fuzzy_join")]
+    Fuzzy(Box<FuzzyJoinOptions>),
+    #[cfg(feature = "knn_join")]
+    Knn(Box<KnnJoinOptions>),
     // Options are set by optimizer/planner in Options
     Cross,
 }
@@ -86,6 +90,10 @@ impl JoinCoalesce {
             AsOf(_) => matches!(self, JoinSpecific | CoalesceColumns),
             #[cfg(feature = "iejoin")]
             IEJoin => false,
+            #[cfg(feature = "fuzzy_join")]
+            Fuzzy(_) => false,
+            #[cfg(feature = "knn_join")]
+            Knn(_) => false,
             Cross => false,
             #[cfg(feature = "semi_anti_join")]
             Semi | Anti => false,
@@ -217,6 +225,10 @@ impl Display for JoinType {
             AsOf(_) => "ASOF",
             #[cfg(feature = "iejoin")]
             IEJoin => "IEJOIN",
+            #[cfg(feature = "fuzzy_join")]
+            Fuzzy(_) => "FUZZY",
+            #[cfg(feature = "knn_join")]
+            Knn(_) => "KNN",
             Cross => "CROSS",
             #[cfg(feature = "semi_anti_join")]
             Semi => "SEMI",
@@ -285,6 +297,45 @@ impl JoinType {
         }
     }
 
+    pub fn is_fuzzy(&self) -> bool {
+        #[cfg(feature = "fuzzy_join")]
+        {
+            matches!(self, JoinType::Fuzzy(_))
+        }
+        #[cfg(not(feature = "fuzzy_join"))]
+        {
+            false
+        }
+    }
+
+    pub fn is_knn(&self) -> bool {
+        #[cfg(feature = "knn_join")]
+        {
+            matches!(self, JoinType::Knn(_))
+        }
+        #[cfg(not(feature = "knn_join"))]
+        {
+            false
+        }
+    }
+
+    /// Column that the join adds next to the columns of both tables, if any.
+    pub fn score_field(&self) -> Option<Field> {
+        match self {
+            #[cfg(feature = "fuzzy_join")]
+            JoinType::Fuzzy(options) => options
+                .score_name
+                .as_ref()
+                .map(|name| Field::new(name.clone(), options.metric.score_dtype())),
+            #[cfg(feature = "knn_join")]
+            JoinType::Knn(options) => options
+                .score_name
+                .as_ref()
+                .map(|name| Field::new(name.clone(), DataType::Float64)),
+            _ => None,
+        }
+    }
+
     pub fn is_cross(&self) -> bool {
         matches!(self, JoinType::Cross)
     }
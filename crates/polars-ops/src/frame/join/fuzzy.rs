@@ -0,0 +1,489 @@
+//! Joins on string keys that are similar rather than equal.
+//!
+//! Instead of comparing every pair of keys, the keys of the right table are indexed by their
+//! q-grams. Every metric gives a lower bound on the number of q-grams that two keys within the
+//! threshold must share, so only pairs that pass this count filter are verified with the (much more
+//! expensive) metric itself:
+//!
+//! - Edit distances: every edit destroys at most `q` q-grams (`q + 1` for a transposition), so keys
+//!   within distance `k` share at least `max(|a|, |b|) - q + 1 - k * q` q-grams. If that bound is
+//!   not positive, all keys whose length is within `k` are verified.
+//! - Jaro-Winkler: the similarity is bounded by the number of shared characters (1-grams).
+//! - n-gram Jaccard: the similarity is bounded by the number of shared n-grams.
+use std::collections::BTreeMap;
+use std::hash::{Hash, Hasher};
+
+use polars_core::POOL;
+use polars_core::prelude::*;
+use polars_core::utils::_split_offsets;
+use rayon::prelude::*;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use super::_finish_join;
+use crate::chunked_array::strings::{
+    JARO_WINKLER_PREFIX_SCALE, damerau_levenshtein_chars, jaccard, jaro_winkler_chars,
+    levenshtein_chars, ngrams,
+};
+
+/// Gram size of the index for the edit distances.
+const Q: usize = 2;
+/// Slack for the bounds of the similarities, so that rounding doesn't filter out exact matches.
+const EPSILON: f64 = 1e-9;
+
+/// The metric and threshold for which keys match in a fuzzy join.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub enum FuzzyJoinMetric {
+    /// Levenshtein distance of at most `max_distance`.
+    Levenshtein { max_distance: u32 },
+    /// Damerau-Levenshtein distance of at most `max_distance`.
+    DamerauLevenshtein { max_distance: u32 },
+    /// Hamming distance of at most `max_distance`. Keys of different lengths never match.
+    Hamming { max_distance: u32 },
+    /// Jaro-Winkler similarity of at least `min_similarity`.
+    JaroWinkler { min_similarity: f64 },
+    /// Jaccard similarity of the character `n`-grams of at least `min_similarity`.
+    NgramJaccard { n: usize, min_similarity: f64 },
+}
+
+impl Hash for FuzzyJoinMetric {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        std::mem::discriminant(self).hash(state);
+        match self {
+            Self::Levenshtein { max_distance }
+            | Self::DamerauLevenshtein { max_distance }
+            | Self::Hamming { max_distance } => max_distance.hash(state),
+            Self::JaroWinkler { min_similarity } => min_similarity.to_bits().hash(state),
+            Self::NgramJaccard { n, min_similarity } => {
+                n.hash(state);
+                min_similarity.to_bits().hash(state);
+            },
+        }
+    }
+}
+
+impl FuzzyJoinMetric {
+    /// Data type of the score of a matched pair.
+    pub fn score_dtype(&self) -> DataType {
+        match self {
+            Self::Levenshtein { .. } | Self::DamerauLevenshtein { .. } | Self::Hamming { .. } => {
+                DataType::UInt32
+            },
+            Self::JaroWinkler { .. } | Self::NgramJaccard { .. } => DataType::Float64,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub struct FuzzyJoinOptions {
+    pub metric: FuzzyJoinMetric,
+    /// Add a column with this name holding the distance or similarity of the matched keys.
+    pub score_name: Option<PlSmallStr>,
+}
+
+impl FuzzyJoinOptions {
+    pub fn new(metric: FuzzyJoinMetric) -> Self {
+        Self {
+            metric,
+            score_name: None,
+        }
+    }
+
+    pub fn with_score_name(mut self, score_name: Option<PlSmallStr>) -> Self {
+        self.score_name = score_name;
+        self
+    }
+
+    fn validate(&self) -> PolarsResult<()> {
+        match self.metric {
+            FuzzyJoinMetric::NgramJaccard { n: 0, .. } => {
+                polars_bail!(InvalidOperation: "n-gram size of a fuzzy join must be positive")
+            },
+            FuzzyJoinMetric::JaroWinkler { min_similarity }
+            | FuzzyJoinMetric::NgramJaccard { min_similarity, .. } => {
+                polars_ensure!(
+                    !min_similarity.is_nan(),
+                    InvalidOperation: "minimal similarity of a fuzzy join must not be NaN"
+                )
+            },
+            _ => {},
+        }
+        Ok(())
+    }
+}
+
+/// Score of a matched pair.
+#[derive(Clone, Copy)]
+enum Score {
+    Distance(u32),
+    Similarity(f64),
+}
+
+/// Number of occurrences of every gram of a key.
+type GramCounts<K> = PlHashMap<K, u32>;
+
+/// Inverted index from the grams of the right keys to the keys that contain them.
+struct GramIndex<K> {
+    postings: PlHashMap<K, Vec<(IdxSize, u32)>>,
+}
+
+impl<K: Hash + Eq> GramIndex<K> {
+    fn new(grams: &[Option<GramCounts<K>>]) -> Self
+    where
+        K: Clone,
+    {
+        let mut postings: PlHashMap<K, Vec<(IdxSize, u32)>> = PlHashMap::default();
+        for (idx, counts) in grams.iter().enumerate() {
+            for (gram, count) in counts.iter().flatten() {
+                postings
+                    .entry(gram.clone())
+                    .or_default()
+                    .push((idx as IdxSize, *count));
+            }
+        }
+        Self { postings }
+    }
+
+    /// Adds the number of grams that every right key shares with `grams` to `shared`, and
+    /// returns the keys with at least one shared gram.
+    fn count_shared(&self, grams: &GramCounts<K>, shared: &mut [u32]) -> Vec<IdxSize> {
+        let mut touched = vec![];
+        for (gram, count) in grams {
+            for (idx, right_count) in self.postings.get(gram).into_iter().flatten() {
+                let entry = &mut shared[*idx as usize];
+                if *entry == 0 {
+                    touched.push(*idx);
+                }
+                *entry += (*count).min(*right_count);
+            }
+        }
+        touched
+    }
+}
+
+fn gram_counts<K: Hash + Eq>(grams: impl Iterator<Item = K>) -> GramCounts<K> {
+    let mut counts = GramCounts::default();
+    for gram in grams {
+        *counts.entry(gram).or_insert(0) += 1;
+    }
+    counts
+}
+
+fn q_grams(chars: &[char]) -> GramCounts<[char; Q]> {
+    gram_counts(chars.windows(Q).map(|w| w.try_into().unwrap()))
+}
+
+/// Matched pairs of a range of left keys.
+type Matches = Vec<(IdxSize, IdxSize, Score)>;
+
+/// Runs `join_range` in parallel over ranges of the left keys.
+fn par_join_ranges<F>(n_left: usize, join_range: F) -> Matches
+where
+    F: Fn(usize, usize) -> Matches + Send + Sync,
+{
+    let n_threads = POOL.current_num_threads();
+    let offsets = _split_offsets(n_left, n_threads);
+    let matches = POOL.install(|| {
+        offsets
+            .into_par_iter()
+            .map(|(offset, len)| join_range(offset, len))
+            .collect::<Vec<_>>()
+    });
+    matches.into_iter().flatten().collect()
+}
+
+fn collect_chars(ca: &StringChunked) -> Vec<Option<Vec<char>>> {
+    ca.iter().map(|s| Some(s?.chars().collect())).collect()
+}
+
+fn join_edit_distance(
+    left: &StringChunked,
+    right: &StringChunked,
+    max_distance: u32,
+    metric: FuzzyJoinMetric,
+) -> Matches {
+    let k = max_distance as usize;
+    // Number of q-grams that a single edit can destroy.
+    let grams_per_edit = match metric {
+        FuzzyJoinMetric::DamerauLevenshtein { .. } => Q + 1,
+        _ => Q,
+    };
+    let is_hamming = matches!(metric, FuzzyJoinMetric::Hamming { .. });
+    let distance = |a: &[char], b: &[char]| match metric {
+        FuzzyJoinMetric::Levenshtein { .. } => levenshtein_chars(a, b),
+        FuzzyJoinMetric::DamerauLevenshtein { .. } => damerau_levenshtein_chars(a, b),
+        _ => a.iter().zip(b).filter(|(ca, cb)| ca != cb).count(),
+    };
+    let min_shared = |la: usize, lb: usize| (la.max(lb) + 1).saturating_sub(Q + k * grams_per_edit);
+
+    let left = collect_chars(left);
+    let right = collect_chars(right);
+    let right_grams = right
+        .iter()
+        .map(|chars| chars.as_deref().map(q_grams))
+        .collect::<Vec<_>>();
+    let index = GramIndex::new(&right_grams);
+    let mut by_length: BTreeMap<usize, Vec<IdxSize>> = BTreeMap::new();
+    for (idx, chars) in right.iter().enumerate() {
+        if let Some(chars) = chars {
+            by_length
+                .entry(chars.len())
+                .or_default()
+                .push(idx as IdxSize);
+        }
+    }
+
+    par_join_ranges(left.len(), |offset, len| {
+        let mut matches = vec![];
+        let mut shared = vec![0u32; right.len()];
+        for (i, a) in left[offset..offset + len].iter().enumerate() {
+            let Some(a) = a else { continue };
+            let left_idx = (offset + i) as IdxSize;
+            let la = a.len();
+            let (min_len, max_len) = if is_hamming {
+                (la, la)
+            } else {
+                (la.saturating_sub(k), la + k)
+            };
+
+            let candidates = if min_shared(la, min_len) == 0 {
+                // The count filter is not selective, verify all keys of a similar length.
+                by_length
+                    .range(min_len..=max_len)
+                    .flat_map(|(_, idxs)| idxs.iter().copied())
+                    .collect::<Vec<_>>()
+            } else {
+                let mut candidates = index.count_shared(&q_grams(a), &mut shared);
+                candidates.retain(|&idx| {
+                    let lb = right[idx as usize].as_ref().unwrap().len();
+                    let keep = (min_len..=max_len).contains(&lb)
+                        && shared[idx as usize] as usize >= min_shared(la, lb);
+                    shared[idx as usize] = 0;
+                    keep
+                });
+                candidates.sort_unstable();
+                candidates
+            };
+
+            for right_idx in candidates {
+                let b = right[right_idx as usize].as_ref().unwrap();
+                let d = distance(a, b);
+                if d <= k {
+                    matches.push((left_idx, right_idx, Score::Distance(d as u32)));
+                }
+            }
+        }
+        matches
+    })
+}
+
+fn join_jaro_winkler(left: &StringChunked, right: &StringChunked, min_similarity: f64) -> Matches {
+    // The common prefix can add at most `4 * 0.1 * (1 - jaro)`.
+    let max_boost = 4.0 * JARO_WINKLER_PREFIX_SCALE;
+    let min_jaro = (min_similarity - max_boost) / (1.0 - max_boost);
+
+    let left = collect_chars(left);
+    let right = collect_chars(right);
+    let right_grams = right
+        .iter()
+        .map(|chars| chars.as_deref().map(|c| gram_counts(c.iter().copied())))
+        .collect::<Vec<_>>();
+    let index = GramIndex::new(&right_grams);
+    let right_non_null = (0..right.len() as IdxSize)
+        .filter(|idx| right[*idx as usize].is_some())
+        .collect::<Vec<_>>();
+    let right_empty = (0..right.len() as IdxSize)
+        .filter(|idx| right[*idx as usize].as_ref().is_some_and(|c| c.is_empty()))
+        .collect::<Vec<_>>();
+
+    par_join_ranges(left.len(), |offset, len| {
+        let mut matches = vec![];
+        let mut shared = vec![0u32; right.len()];
+        for (i, a) in left[offset..offset + len].iter().enumerate() {
+            let Some(a) = a else { continue };
+            let left_idx = (offset + i) as IdxSize;
+
+            let candidates = if min_similarity <= 0.0 {
+                right_non_null.clone()
+            } else if a.is_empty() {
+                right_empty.clone()
+            } else {
+                // The Jaro similarity is at most `(c / |a| + c / |b| + 1) / 3` with `c` the number
+                // of shared characters.
+                let mut candidates =
+                    index.count_shared(&gram_counts(a.iter().copied()), &mut shared);
+                candidates.retain(|&idx| {
+                    let lb = right[idx as usize].as_ref().unwrap().len() as f64;
+                    let c = shared[idx as usize] as f64;
+                    shared[idx as usize] = 0;
+                    (c / a.len() as f64 + c / lb + 1.0) / 3.0 + EPSILON >= min_jaro
+                });
+                candidates.sort_unstable();
+                candidates
+            };
+
+            for right_idx in candidates {
+                let b = right[right_idx as usize].as_ref().unwrap();
+                let similarity = jaro_winkler_chars(a, b);
+                if similarity >= min_similarity {
+                    matches.push((left_idx, right_idx, Score::Similarity(similarity)));
+                }
+            }
+        }
+        matches
+    })
+}
+
+fn join_ngram_jaccard(
+    left: &StringChunked,
+    right: &StringChunked,
+    n: usize,
+    min_similarity: f64,
+) -> Matches {
+    fn gram_sets(ca: &StringChunked, n: usize) -> Vec<Option<PlHashSet<&str>>> {
+        ca.iter().map(|s| Some(ngrams(s?, n).collect())).collect()
+    }
+    let left_grams = gram_sets(left, n);
+    let right_grams = gram_sets(right, n);
+    let right_counts = right_grams
+        .iter()
+        .map(|grams| Some(grams.as_ref()?.iter().map(|g| (*g, 1)).collect()))
+        .collect::<Vec<_>>();
+    let index = GramIndex::new(&right_counts);
+    let right_non_null = (0..right.len() as IdxSize)
+        .filter(|idx| right_grams[*idx as usize].is_some())
+        .collect::<Vec<_>>();
+    let right_empty = (0..right.len() as IdxSize)
+        .filter(|idx| {
+            right_grams[*idx as usize]
+                .as_ref()
+                .is_some_and(|g| g.is_empty())
+        })
+        .collect::<Vec<_>>();
+
+    par_join_ranges(left.len(), |offset, len| {
+        let mut matches = vec![];
+        let mut shared = vec![0u32; right.len()];
+        for (i, a) in left_grams[offset..offset + len].iter().enumerate() {
+            let Some(a) = a else { continue };
+            let left_idx = (offset + i) as IdxSize;
+
+            let candidates = if min_similarity <= 0.0 {
+                right_non_null.clone()
+            } else if a.is_empty() {
+                right_empty.clone()
+            } else {
+                // `|A ∩ B| / |A ∪ B| >= s` implies `|A ∩ B| >= s / (1 + s) * (|A| + |B|)`.
+                let counts = a.iter().map(|g| (*g, 1)).collect();
+                let mut candidates = index.count_shared(&counts, &mut shared);
+                candidates.retain(|&idx| {
+                    let lb = right_grams[idx as usize].as_ref().unwrap().len();
+                    let c = shared[idx as usize] as f64;
+                    shared[idx as usize] = 0;
+                    c + EPSILON >= min_similarity / (1.0 + min_similarity) * (a.len() + lb) as f64
+                });
+                candidates.sort_unstable();
+                candidates
+            };
+
+            for right_idx in candidates {
+                let b = right_grams[right_idx as usize].as_ref().unwrap();
+                let similarity = jaccard(a, b);
+                if similarity >= min_similarity {
+                    matches.push((left_idx, right_idx, Score::Similarity(similarity)));
+                }
+            }
+        }
+        matches
+    })
+}
+
+/// Returns the matched pairs of rows, ordered by the left and then the right row, and the score
+/// of every pair.
+pub fn fuzzy_join_tuples(
+    left: &StringChunked,
+    right: &StringChunked,
+    options: &FuzzyJoinOptions,
+) -> PolarsResult<(IdxCa, IdxCa, Series)> {
+    options.validate()?;
+    let matches = match options.metric {
+        FuzzyJoinMetric::Levenshtein { max_distance }
+        | FuzzyJoinMetric::DamerauLevenshtein { max_distance }
+        | FuzzyJoinMetric::Hamming { max_distance } => {
+            join_edit_distance(left, right, max_distance, options.metric)
+        },
+        FuzzyJoinMetric::JaroWinkler { min_similarity } => {
+            join_jaro_winkler(left, right, min_similarity)
+        },
+        FuzzyJoinMetric::NgramJaccard { n, min_similarity } => {
+            join_ngram_jaccard(left, right, n, min_similarity)
+        },
+    };
+
+    let left_idx = IdxCa::from_vec(PlSmallStr::EMPTY, matches.iter().map(|m| m.0).collect());
+    let right_idx = IdxCa::from_vec(PlSmallStr::EMPTY, matches.iter().map(|m| m.1).collect());
+    let score_name = options.score_name.clone().unwrap_or_default();
+    let scores = match options.metric.score_dtype() {
+        DataType::UInt32 => UInt32Chunked::from_vec(
+            score_name,
+            matches
+                .iter()
+                .map(|m| match m.2 {
+                    Score::Distance(d) => d,
+                    Score::Similarity(_) => unreachable!(),
+                })
+                .collect(),
+        )
+        .into_series(),
+        _ => Float64Chunked::from_vec(
+            score_name,
+            matches
+                .iter()
+                .map(|m| match m.2 {
+                    Score::Similarity(s) => s,
+                    Score::Distance(_) => unreachable!(),
+                })
+                .collect(),
+        )
+        .into_series(),
+    };
+    Ok((left_idx, right_idx, scores))
+}
+
+pub(super) fn fuzzy_join(
+    left_df: &DataFrame,
+    right_df: &DataFrame,
+    left_key: &Series,
+    right_key: &Series,
+    options: &FuzzyJoinOptions,
+    suffix: Option<PlSmallStr>,
+    slice: Option<(i64, usize)>,
+) -> PolarsResult<DataFrame> {
+    polars_ensure!(
+        left_key.dtype() == &DataType::String && right_key.dtype() == &DataType::String,
+        InvalidOperation: "fuzzy join is only supported on String keys, got {} and {}",
+        left_key.dtype(), right_key.dtype()
+    );
+    let (mut left_idx, mut right_idx, mut scores) =
+        fuzzy_join_tuples(left_key.str()?, right_key.str()?, options)?;
+    if let Some((offset, len)) = slice {
+        left_idx = left_idx.slice(offset, len);
+        right_idx = right_idx.slice(offset, len);
+        scores = scores.slice(offset, len);
+    }
+
+    // SAFETY: the indices are in bounds.
+    let (left, right) = POOL.join(
+        || unsafe { left_df.take_unchecked(&left_idx) },
+        || unsafe { right_df.take_unchecked(&right_idx) },
+    );
+    let mut out = _finish_join(left, right, suffix)?;
+    if options.score_name.is_some() {
+        out.with_column(scores)?;
+    }
+    Ok(out)
+}
@@ -0,0 +1,328 @@
+//! Joins every row of the left table to its `k` nearest neighbours in the right table, where the
+//! keys are fixed-size `Array` columns holding vectors.
+//!
+//! The search is exact: every left vector is compared to every right vector. To stay cache
+//! friendly the comparison is blocked, a block of left vectors is scored against a block of right
+//! vectors at a time, and the left rows are split over the thread pool.
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+
+use arrow::array::FixedSizeListArray;
+use arrow::types::NativeType;
+use polars_compute::linalg::VectorKernels;
+use polars_core::POOL;
+use polars_core::prelude::*;
+use polars_core::utils::_split_offsets;
+use rayon::prelude::*;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use super::_finish_join;
+use crate::chunked_array::array::{array_rows, cast_linalg_inner, linalg_float_dtype};
+
+/// Number of left vectors scored together.
+const LEFT_BLOCK: usize = 16;
+/// Number of right vectors scored together.
+const RIGHT_BLOCK: usize = 256;
+
+/// How the distance between two vectors is measured in a nearest-neighbour join.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub enum KnnJoinMetric {
+    /// Smallest Euclidean distance.
+    #[default]
+    L2,
+    /// Largest cosine similarity. Vectors with a zero norm never match.
+    Cosine,
+    /// Largest dot product.
+    Dot,
+}
+
+impl KnnJoinMetric {
+    fn higher_is_closer(&self) -> bool {
+        !matches!(self, Self::L2)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub struct KnnJoinOptions {
+    /// Number of neighbours to find for every left row.
+    pub k: usize,
+    pub metric: KnnJoinMetric,
+    /// Add a `Float64` column with this name holding the distance or similarity of the matched
+    /// vectors.
+    pub score_name: Option<PlSmallStr>,
+}
+
+impl KnnJoinOptions {
+    pub fn new(k: usize, metric: KnnJoinMetric) -> Self {
+        Self {
+            k,
+            metric,
+            score_name: None,
+        }
+    }
+
+    pub fn with_score_name(mut self, score_name: Option<PlSmallStr>) -> Self {
+        self.score_name = score_name;
+        self
+    }
+}
+
+/// A candidate neighbour. Ordered such that the worst candidate is the greatest, so that a
+/// max-heap of candidates can evict it.
+#[derive(Clone, Copy)]
+struct Candidate {
+    /// The score, negated for metrics where higher is closer.
+    key: f64,
+    idx: IdxSize,
+}
+
+impl PartialEq for Candidate {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Candidate {}
+
+impl PartialOrd for Candidate {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Candidate {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // On equal scores the lower right index wins.
+        self.key
+            .total_cmp(&other.key)
+            .then(self.idx.cmp(&other.idx))
+    }
+}
+
+/// Keeps the `k` best candidates seen so far.
+struct TopK {
+    k: usize,
+    heap: BinaryHeap<Candidate>,
+}
+
+impl TopK {
+    /// `n_candidates` bounds the number of candidates that will be pushed.
+    fn new(k: usize, n_candidates: usize) -> Self {
+        Self {
+            k,
+            heap: BinaryHeap::with_capacity(k.min(n_candidates).saturating_add(1)),
+        }
+    }
+
+    fn push(&mut self, candidate: Candidate) {
+        if self.heap.len() < self.k {
+            self.heap.push(candidate);
+        } else if let Some(mut worst) = self.heap.peek_mut()
+            && candidate < *worst
+        {
+            *worst = candidate;
+        }
+    }
+
+    /// Returns the candidates from best to worst.
+    fn into_sorted(self) -> Vec<Candidate> {
+        self.heap.into_sorted_vec()
+    }
+}
+
+/// Matched pairs with their scores, ordered by the left row and then by closeness.
+type Matches = Vec<(IdxSize, IdxSize, f64)>;
+
+fn knn_rows<T>(
+    left: &FixedSizeListArray,
+    right: &FixedSizeListArray,
+    options: &KnnJoinOptions,
+) -> Matches
+where
+    T: NativeType + VectorKernels,
+{
+    let metric = options.metric;
+    let norm = |v: &[T]| T::sum_of_squares(v).sqrt();
+    let score = |a: &[T], b: &[T]| -> f64 {
+        match metric {
+            KnnJoinMetric::L2 => T::squared_l2(a, b).to_f64().unwrap(),
+            _ => T::dot(a, b).to_f64().unwrap(),
+        }
+    };
+    let finish = |raw: f64, a_norm: f64, b_norm: f64| -> f64 {
+        match metric {
+            KnnJoinMetric::L2 => raw.sqrt(),
+            KnnJoinMetric::Cosine => raw / (a_norm * b_norm),
+            KnnJoinMetric::Dot => raw,
+        }
+    };
+
+    let left_rows = array_rows::<T>(left).collect::<Vec<_>>();
+    let (right_idx, right_rows): (Vec<IdxSize>, Vec<&[T]>) = array_rows::<T>(right)
+        .enumerate()
+        .filter_map(|(idx, row)| {
+            let row = row?;
+            // Zero vectors have no direction, so they have no cosine similarity.
+            let keep = metric != KnnJoinMetric::Cosine || norm(row) != T::zero();
+            keep.then_some((idx as IdxSize, row))
+        })
+        .unzip();
+    let right_norms = if metric == KnnJoinMetric::Cosine {
+        right_rows
+            .iter()
+            .map(|row| norm(row).to_f64().unwrap())
+            .collect()
+    } else {
+        vec![1.0; right_rows.len()]
+    };
+
+    let n_threads = POOL.current_num_threads();
+    let offsets = _split_offsets(left_rows.len(), n_threads);
+    let matches = POOL.install(|| {
+        offsets
+            .into_par_iter()
+            .map(|(offset, len)| {
+                let mut matches = Matches::new();
+                let left_range = &left_rows[offset..offset + len];
+                for (block_idx, left_block) in left_range.chunks(LEFT_BLOCK).enumerate() {
+                    let block_offset = offset + block_idx * LEFT_BLOCK;
+                    let left_norms = left_block
+                        .iter()
+                        .map(|row| match (metric, row) {
+                            (KnnJoinMetric::Cosine, Some(row)) => norm(row).to_f64().unwrap(),
+                            _ => 1.0,
+                        })
+                        .collect::<Vec<_>>();
+                    let mut top_k = left_block
+                        .iter()
+                        .map(|_| TopK::new(options.k, right_rows.len()))
+                        .collect::<Vec<_>>();
+
+                    for right_start in (0..right_rows.len()).step_by(RIGHT_BLOCK) {
+                        let right_end = (right_start + RIGHT_BLOCK).min(right_rows.len());
+                        for (i, a) in left_block.iter().enumerate() {
+                            let Some(a) = a else { continue };
+                            if left_norms[i] == 0.0 {
+                                continue;
+                            }
+                            for j in right_start..right_end {
+                                let s =
+                                    finish(score(a, right_rows[j]), left_norms[i], right_norms[j]);
+                                if s.is_nan() {
+                                    continue;
+                                }
+                                let key = if metric.higher_is_closer() { -s } else { s };
+                                top_k[i].push(Candidate {
+                                    key,
+                                    idx: j as IdxSize,
+                                });
+                            }
+                        }
+                    }
+
+                    for (i, top_k) in top_k.into_iter().enumerate() {
+                        let left_idx = (block_offset + i) as IdxSize;
+                        for c in top_k.into_sorted() {
+                            let s = if metric.higher_is_closer() {
+                                -c.key
+                            } else {
+                                c.key
+                            };
+                            matches.push((left_idx, right_idx[c.idx as usize], s));
+                        }
+                    }
+                }
+                matches
+            })
+            .collect::<Vec<_>>()
+    });
+    matches.into_iter().flatten().collect()
+}
+
+/// Returns for every left row the indices of its `k` nearest right rows, ordered by the left row
+/// and then from nearest to farthest, and the score of every pair.
+///
+/// Rows that are null or contain a null element never match.
+pub fn knn_join_tuples(
+    left: &ArrayChunked,
+    right: &ArrayChunked,
+    options: &KnnJoinOptions,
+) -> PolarsResult<(IdxCa, IdxCa, Float64Chunked)> {
+    polars_ensure!(
+        options.k > 0,
+        InvalidOperation: "the number of neighbours `k` of a nearest-neighbour join must be positive"
+    );
+    polars_ensure!(
+        left.width() == right.width(),
+        ShapeMismatch: "nearest-neighbour join requires arrays of equal width, got {} and {}",
+        left.width(), right.width()
+    );
+    let float_dtype = match (
+        linalg_float_dtype(left, "knn_join")?,
+        linalg_float_dtype(right, "knn_join")?,
+    ) {
+        (DataType::Float32, DataType::Float32) => DataType::Float32,
+        _ => DataType::Float64,
+    };
+    let left = cast_linalg_inner(left, &float_dtype)?
+        .rechunk()
+        .into_owned();
+    let right = cast_linalg_inner(right, &float_dtype)?
+        .rechunk()
+        .into_owned();
+    let left_arr = left.downcast_as_array();
+    let right_arr = right.downcast_as_array();
+
+    let matches = match float_dtype {
+        DataType::Float32 => knn_rows::<f32>(left_arr, right_arr, options),
+        _ => knn_rows::<f64>(left_arr, right_arr, options),
+    };
+
+    let left_idx = IdxCa::from_vec(PlSmallStr::EMPTY, matches.iter().map(|m| m.0).collect());
+    let right_idx = IdxCa::from_vec(PlSmallStr::EMPTY, matches.iter().map(|m| m.1).collect());
+    let scores = Float64Chunked::from_vec(
+        options.score_name.clone().unwrap_or_default(),
+        matches.iter().map(|m| m.2).collect(),
+    );
+    Ok((left_idx, right_idx, scores))
+}
+
+pub(super) fn knn_join(
+    left_df: &DataFrame,
+    right_df: &DataFrame,
+    left_key: &Series,
+    right_key: &Series,
+    options: &KnnJoinOptions,
+    suffix: Option<PlSmallStr>,
+    slice: Option<(i64, usize)>,
+) -> PolarsResult<DataFrame> {
+    polars_ensure!(
+        matches!(left_key.dtype(), DataType::Array(_, _))
+            && matches!(right_key.dtype(), DataType::Array(_, _)),
+        InvalidOperation: "nearest-neighbour join is only supported on Array keys, got {} and {}",
+        left_key.dtype(), right_key.dtype()
+    );
+    let (mut left_idx, mut right_idx, mut scores) =
+        knn_join_tuples(left_key.array()?, right_key.array()?, options)?;
+    if let Some((offset, len)) = slice {
+        left_idx = left_idx.slice(offset, len);
+        right_idx = right_idx.slice(offset, len);
+        scores = scores.slice(offset, len);
+    }
+
+    // SAFETY: the indices are in bounds.
+    let (left, right) = POOL.join(
+        || unsafe { left_df.take_unchecked(&left_idx) },
+        || unsafe { right_df.take_unchecked(&right_idx) },
+    );
+    let mut out = _finish_join(left, right, suffix)?;
+    if options.score_name.is_some() {
+        out.with_column(scores.into_series())?;
+    }
+    Ok(out)
+}
@@ -3,10 +3,14 @@ mod args;
 mod asof;
 mod cross_join;
 mod dispatch_left_right;
+#[cfg(feature = "fuzzy_join")]
+mod fuzzy;
 mod general;
 mod hash_join;
 #[cfg(feature = "iejoin")]
 mod iejoin;
+#[cfg(feature = "knn_join")]
+mod knn;
 #[cfg(feature = "merge_sorted")]
 mod merge_sorted;
 
@@ -21,6 +25,8 @@ pub use asof::{AsOfOptions, AsofJoin, AsofJoinBy, AsofStrategy};
 pub use cross_join::CrossJoin;
 #[cfg(feature = "chunked_ids")]
 use either::Either;
+#[cfg(feature = "fuzzy_join")]
+pub use fuzzy::{FuzzyJoinMetric, FuzzyJoinOptions, fuzzy_join_tuples};
 #[cfg(feature = "chunked_ids")]
 use general::create_chunked_index_mapping;
 pub use general::{_coalesce_full_join, _finish_join, _join_suffix_name};
@@ -28,6 +34,8 @@ pub use hash_join::*;
 use hashbrown::hash_map::{Entry, RawEntryMut};
 #[cfg(feature = "iejoin")]
 pub use iejoin::{IEJoinOptions, InequalityOperator};
+#[cfg(feature = "knn_join")]
+pub use knn::{KnnJoinMetric, KnnJoinOptions, knn_join_tuples};
 #[cfg(feature = "merge_sorted")]
 pub use merge_sorted::_merge_sorted_dfs;
 use polars_core::POOL;
@@ -317,6 +325,26 @@ pub trait DataFrameJoinOps: IntoDf {
                         panic!("expected by arguments on both sides")
                     },
                 },
+                #[cfg(feature = "fuzzy_join")]
+                JoinType::Fuzzy(options) => fuzzy::fuzzy_join(
+                    left_df,
+                    other,
+                    s_left,
+                    s_right,
+                    &options,
+                    args.suffix,
+                    args.slice,
+                ),
+                #[cfg(feature = "knn_join")]
+                JoinType::Knn(options) => knn::knn_join(
+                    left_df,
+                    other,
+                    s_left,
+                    s_right,
+                    &options,
+                    args.suffix,
+                    args.slice,
+                ),
                 #[cfg(feature = "iejoin")]
                 JoinType::IEJoin => {
                     unreachable!()
@@ -362,6 +390,14 @@ pub trait DataFrameJoinOps: IntoDf {
             JoinType::AsOf(_) => polars_bail!(
                 ComputeError: "asof join not supported for join on multiple keys"
             ),
+            #[cfg(feature = "fuzzy_join")]
+            JoinType::Fuzzy(_) => polars_bail!(
+                ComputeError: "fuzzy join not supported for join on multiple keys"
+            ),
+            #[cfg(feature = "knn_join")]
+            JoinType::Knn(_) => polars_bail!(
+                ComputeError: "nearest-neighbour join not supported for join on multiple keys"
+            ),
             #[cfg(feature = "iejoin")]
             JoinType::IEJoin => {
                 unreachable!()
@@ -5,10 +5,11 @@ use arrow::bitmap::MutableBitmap;
 use num_traits::{NumCast, Zero};
 use polars_core::downcast_as_macro_arg_physical;
 use polars_core::prelude::*;
-#[cfg(feature = "serde")]
-use serde::{Deserialize, Serialize};
 
-use super::{linear_itp, nearest_itp};
+use super::{
+    InterpolationMethod, InterpolationOptions, apply_fill_limits, cubic_interp, linear_itp,
+    nearest_itp, step_indices,
+};
 
 fn near_interp<T>(low: T, high: T, steps: IdxSize, steps_n: T, out: &mut Vec<T>)
 where
@@ -200,24 +201,58 @@ fn linear_interp_signed<T: PolarsNumericType>(ca: &ChunkedArray<T>) -> Series {
     interpolate_impl(ca, signed_interp::<T::Native>).into_series()
 }
 
-#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
-#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
-pub enum InterpolationMethod {
-    Linear,
-    Nearest,
+fn interpolate_step(s: &Series, backward: bool) -> Series {
+    match s.rechunk_validity() {
+        None => s.clone(),
+        Some(validity) => unsafe { s.take_unchecked(&step_indices(&validity, backward)) },
+    }
+}
+
+fn interpolate_cubic(s: &Series, monotone: bool) -> Series {
+    let logical = s.dtype();
+    let y = if logical.is_temporal() {
+        s.to_physical_repr().cast(&DataType::Float64).unwrap()
+    } else if logical.is_primitive_numeric() || logical.is_decimal() || logical.is_bool() {
+        s.cast(&DataType::Float64).unwrap()
+    } else {
+        return s.clone();
+    };
+
+    let x: Vec<f64> = (0..s.len()).map(|idx| idx as f64).collect();
+    let out = cubic_interp(y.f64().unwrap(), &x, monotone);
+    match logical {
+        DataType::Float32 => out.into_series().cast(logical).unwrap(),
+        dt if dt.is_temporal() => {
+            let out = out.apply_values(|v| v.round()).into_series();
+            let out = out.cast(&dt.to_physical()).unwrap();
+            unsafe { out.from_physical_unchecked(dt).unwrap() }
+        },
+        _ => out.into_series(),
+    }
 }
 
 pub fn interpolate(s: &Series, method: InterpolationMethod) -> Series {
-    match method {
+    interpolate_with_options(s, method.into())
+}
+
+/// Interpolate the nulls in `s`, filling at most `limit` consecutive nulls in the `limit_area` of
+/// `options`.
+pub fn interpolate_with_options(s: &Series, options: InterpolationOptions) -> Series {
+    let out = match options.method {
         InterpolationMethod::Linear => interpolate_linear(s),
         InterpolationMethod::Nearest => interpolate_nearest(s),
-    }
+        InterpolationMethod::Previous => interpolate_step(s, false),
+        InterpolationMethod::Next => interpolate_step(s, true),
+        InterpolationMethod::CubicSpline => interpolate_cubic(s, false),
+        InterpolationMethod::Pchip => interpolate_cubic(s, true),
+    };
+    apply_fill_limits(out, s, &options)
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::series::InterpolationLimitArea;
 
     #[test]
     fn test_interpolate() {
@@ -320,4 +355,115 @@ mod test {
             ]
         );
     }
+
+    #[test]
+    fn test_interpolate_step() {
+        let s = UInt32Chunked::new("".into(), &[None, Some(1), None, None, Some(4), None])
+            .into_series();
+        let out = interpolate(&s, InterpolationMethod::Previous);
+        assert_eq!(
+            Vec::from(out.u32().unwrap()),
+            &[None, Some(1), Some(1), Some(1), Some(4), Some(4)]
+        );
+        let out = interpolate(&s, InterpolationMethod::Next);
+        assert_eq!(
+            Vec::from(out.u32().unwrap()),
+            &[Some(1), Some(1), Some(4), Some(4), Some(4), None]
+        );
+    }
+
+    #[test]
+    fn test_interpolate_limits() {
+        let s = UInt32Chunked::new("".into(), &[None, Some(1), None, None, Some(4), None])
+            .into_series();
+        let options = |method, limit, limit_area| InterpolationOptions {
+            method,
+            limit,
+            limit_area,
+        };
+
+        let out =
+            interpolate_with_options(&s, options(InterpolationMethod::Previous, Some(1), None));
+        assert_eq!(
+            Vec::from(out.u32().unwrap()),
+            &[None, Some(1), Some(1), None, Some(4), Some(4)]
+        );
+        let out = interpolate_with_options(&s, options(InterpolationMethod::Next, Some(1), None));
+        assert_eq!(
+            Vec::from(out.u32().unwrap()),
+            &[Some(1), Some(1), None, Some(4), Some(4), None]
+        );
+        let out = interpolate_with_options(
+            &s,
+            options(
+                InterpolationMethod::Previous,
+                None,
+                Some(InterpolationLimitArea::Inside),
+            ),
+        );
+        assert_eq!(
+            Vec::from(out.u32().unwrap()),
+            &[None, Some(1), Some(1), Some(1), Some(4), None]
+        );
+        let out = interpolate_with_options(
+            &s,
+            options(
+                InterpolationMethod::Previous,
+                None,
+                Some(InterpolationLimitArea::Outside),
+            ),
+        );
+        assert_eq!(
+            Vec::from(out.u32().unwrap()),
+            &[None, Some(1), None, None, Some(4), Some(4)]
+        );
+        let out = interpolate_with_options(&s, options(InterpolationMethod::Linear, Some(1), None));
+        assert_eq!(
+            Vec::from(out.f64().unwrap()),
+            &[None, Some(1.0), Some(2.0), None, Some(4.0), None]
+        );
+    }
+
+    #[test]
+    fn test_interpolate_cubic() {
+        let s = Float64Chunked::new(
+            "".into(),
+            &[None, Some(0.0), None, Some(2.0), None, Some(0.0), None],
+        )
+        .into_series();
+        let out = interpolate(&s, InterpolationMethod::CubicSpline);
+        assert_eq!(
+            Vec::from(out.f64().unwrap()),
+            &[
+                None,
+                Some(0.0),
+                Some(1.375),
+                Some(2.0),
+                Some(1.375),
+                Some(0.0),
+                None
+            ]
+        );
+        let out = interpolate(&s, InterpolationMethod::Pchip);
+        assert_eq!(
+            Vec::from(out.f64().unwrap()),
+            &[
+                None,
+                Some(0.0),
+                Some(1.5),
+                Some(2.0),
+                Some(1.5),
+                Some(0.0),
+                None
+            ]
+        );
+
+        // With two valid values both fall back to a straight line.
+        let s = Int32Chunked::new("".into(), &[Some(1), None, None, Some(4)]).into_series();
+        let out = interpolate(&s, InterpolationMethod::CubicSpline);
+        assert_eq!(
+            Vec::from(out.f64().unwrap()),
+            &[Some(1.0), Some(2.0), Some(3.0), Some(4.0)]
+        );
+    }
 }
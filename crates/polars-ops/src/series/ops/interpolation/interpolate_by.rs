@@ -4,10 +4,14 @@ use arrow::array::PrimitiveArray;
 use arrow::bitmap::MutableBitmap;
 use bytemuck::allocation::zeroed_vec;
 use num_traits::{NumCast, Zero};
+use polars_core::downcast_as_macro_arg_physical;
 use polars_core::prelude::*;
 use polars_utils::slice::SliceAble;
 
-use super::linear_itp;
+use super::{
+    InterpolationMethod, InterpolationOptions, apply_fill_limits, cubic_interp, linear_itp,
+    nearest_indices, step_indices,
+};
 
 /// # Safety
 /// - `x` must be non-empty.
@@ -263,9 +267,7 @@ where
     }
 }
 
-pub fn interpolate_by(s: &Column, by: &Column, by_is_sorted: bool) -> PolarsResult<Column> {
-    polars_ensure!(s.len() == by.len(), InvalidOperation: "`by` column must be the same length as Series ({}), got {}", s.len(), by.len());
-
+fn interpolate_linear_by(s: &Column, by: &Column, by_is_sorted: bool) -> PolarsResult<Column> {
     fn func<T, F>(
         ca: &ChunkedArray<T>,
         by: &ChunkedArray<F>,
@@ -327,13 +329,15 @@ pub fn interpolate_by(s: &Column, by: &Column, by_is_sorted: bool) -> PolarsResu
             func(s.f32().unwrap(), by.u32().unwrap(), by_is_sorted)
         },
         #[cfg(feature = "dtype-date")]
-        (_, DataType::Date) => interpolate_by(s, &by.cast(&DataType::Int32).unwrap(), by_is_sorted),
+        (_, DataType::Date) => {
+            interpolate_linear_by(s, &by.cast(&DataType::Int32).unwrap(), by_is_sorted)
+        },
         #[cfg(feature = "dtype-datetime")]
         (_, DataType::Datetime(_, _)) => {
-            interpolate_by(s, &by.cast(&DataType::Int64).unwrap(), by_is_sorted)
+            interpolate_linear_by(s, &by.cast(&DataType::Int64).unwrap(), by_is_sorted)
         },
         (DataType::UInt64 | DataType::UInt32 | DataType::Int64 | DataType::Int32, _) => {
-            interpolate_by(&s.cast(&DataType::Float64).unwrap(), by, by_is_sorted)
+            interpolate_linear_by(&s.cast(&DataType::Float64).unwrap(), by, by_is_sorted)
         },
         _ => {
             polars_bail!(InvalidOperation: "expected series to be Float64, Float32, \
@@ -342,3 +346,108 @@ pub fn interpolate_by(s: &Column, by: &Column, by_is_sorted: bool) -> PolarsResu
         },
     }
 }
+
+/// The x-coordinates of the sorted `by` column, relative to its first value so that large
+/// timestamps keep their precision.
+fn x_coordinates(by: &Column) -> PolarsResult<Vec<f64>> {
+    fn offsets<F: PolarsNumericType>(by: &ChunkedArray<F>) -> Vec<f64> {
+        let Some(first) = by.get(0) else {
+            return Vec::new();
+        };
+        by.into_no_null_iter()
+            .map(|v| NumCast::from(v - first).unwrap())
+            .collect()
+    }
+
+    let by = by.as_materialized_series().to_physical_repr();
+    polars_ensure!(
+        by.dtype().is_primitive_numeric(),
+        InvalidOperation: "expected `by` to be numeric or temporal in 'interpolate_by', got {}", by.dtype()
+    );
+    macro_rules! dispatch {
+        ($ca:expr) => {{ offsets($ca) }};
+    }
+    Ok(downcast_as_macro_arg_physical!(by, dispatch))
+}
+
+fn interpolate_cubic_by(s: &Column, by: &Column, monotone: bool) -> PolarsResult<Column> {
+    let dtype = s.dtype();
+    polars_ensure!(
+        dtype.is_primitive_numeric() || dtype.is_decimal(),
+        InvalidOperation: "expected series to be numeric in 'interpolate_by', got {}", dtype
+    );
+    let x = x_coordinates(by)?;
+    // Knots at the same x-coordinate would make the spline divide by a zero interval.
+    polars_ensure!(
+        x.windows(2).all(|w| w[0] < w[1]),
+        InvalidOperation: "`by` column must not contain duplicate values for '{}' interpolation in 'interpolate_by'",
+        if monotone { "pchip" } else { "cubic_spline" }
+    );
+    let y = s.cast(&DataType::Float64)?;
+    let out = cubic_interp(y.f64()?, &x, monotone).into_column();
+    match dtype {
+        DataType::Float32 => out.cast(dtype),
+        _ => Ok(out),
+    }
+}
+
+/// Interpolate the nulls in `s` against the x-coordinates in `by`, filling at most `limit`
+/// consecutive nulls in the `limit_area` of `options`.
+pub fn interpolate_by(
+    s: &Column,
+    by: &Column,
+    by_is_sorted: bool,
+    options: InterpolationOptions,
+) -> PolarsResult<Column> {
+    polars_ensure!(s.len() == by.len(), InvalidOperation: "`by` column must be the same length as Series ({}), got {}", s.len(), by.len());
+    if options.method == InterpolationMethod::Linear
+        && options.limit.is_none()
+        && options.limit_area.is_none()
+    {
+        return interpolate_linear_by(s, by, by_is_sorted);
+    }
+
+    polars_ensure!(by.null_count() == 0, InvalidOperation: "null values in `by` column are not yet supported in 'interpolate_by' expression");
+    if !by_is_sorted {
+        let sorting_indices = by.as_materialized_series().arg_sort(Default::default());
+        let (s_sorted, by_sorted) = unsafe {
+            (
+                s.take_unchecked(&sorting_indices),
+                by.take_unchecked(&sorting_indices),
+            )
+        };
+        let out = interpolate_by(&s_sorted, &by_sorted, true, options)?;
+
+        // Put the rows back in their original positions.
+        let mut inverse_indices = vec![0 as IdxSize; s.len()];
+        for (sorted_idx, idx) in sorting_indices.into_no_null_iter().enumerate() {
+            inverse_indices[idx as usize] = sorted_idx as IdxSize;
+        }
+        let inverse_indices = IdxCa::from_vec(PlSmallStr::EMPTY, inverse_indices);
+        return Ok(unsafe { out.take_unchecked(&inverse_indices) });
+    }
+
+    let out = match options.method {
+        InterpolationMethod::Linear => interpolate_linear_by(s, by, true)?,
+        InterpolationMethod::Nearest
+        | InterpolationMethod::Previous
+        | InterpolationMethod::Next => match s.as_materialized_series().rechunk_validity() {
+            None => s.clone(),
+            Some(validity) => {
+                let indices = match options.method {
+                    InterpolationMethod::Nearest => nearest_indices(&validity, &x_coordinates(by)?),
+                    method => step_indices(&validity, method == InterpolationMethod::Next),
+                };
+                unsafe { s.take_unchecked(&indices) }
+            },
+        },
+        InterpolationMethod::CubicSpline => interpolate_cubic_by(s, by, false)?,
+        InterpolationMethod::Pchip => interpolate_cubic_by(s, by, true)?,
+    };
+    Ok(apply_fill_limits(
+        out.take_materialized_series(),
+        s.as_materialized_series(),
+        &options,
+    )
+    .into())
+}
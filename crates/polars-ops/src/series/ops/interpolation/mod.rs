@@ -1,9 +1,63 @@
 use std::ops::{Add, Div, Mul, Sub};
+
+use arrow::bitmap::{Bitmap, MutableBitmap};
+use polars_core::prelude::*;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
 #[cfg(feature = "interpolate")]
 pub mod interpolate;
 #[cfg(feature = "interpolate_by")]
 pub mod interpolate_by;
 
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub enum InterpolationMethod {
+    #[default]
+    Linear,
+    Nearest,
+    /// Carry the last valid value forward.
+    Previous,
+    /// Carry the next valid value backward.
+    Next,
+    /// A natural cubic spline through the valid values.
+    CubicSpline,
+    /// A monotone piecewise cubic (PCHIP) that doesn't overshoot the valid values.
+    Pchip,
+}
+
+/// Which nulls may be filled, relative to the valid values.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub enum InterpolationLimitArea {
+    /// Only fill nulls that are surrounded by valid values.
+    Inside,
+    /// Only fill nulls before the first or after the last valid value.
+    Outside,
+}
+
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub struct InterpolationOptions {
+    pub method: InterpolationMethod,
+    /// The maximum number of consecutive nulls to fill. `Next` fills the nulls closest to the
+    /// next valid value, the other methods the nulls closest to the previous one.
+    pub limit: Option<IdxSize>,
+    pub limit_area: Option<InterpolationLimitArea>,
+}
+
+impl From<InterpolationMethod> for InterpolationOptions {
+    fn from(method: InterpolationMethod) -> Self {
+        Self {
+            method,
+            ..Default::default()
+        }
+    }
+}
+
 fn linear_itp<T>(low: T, step: T, slope: T) -> T
 where
     T: Sub<Output = T> + Mul<Output = T> + Add<Output = T> + Div<Output = T>,
@@ -24,3 +78,216 @@ where
         low + diff
     }
 }
+
+/// The row that fills each row for `Previous` (or `Next` if `backward`), given the validity of
+/// the rows in x order.
+fn step_indices(validity: &Bitmap, backward: bool) -> IdxCa {
+    let mut source = None;
+    let mut step = |(idx, valid): (usize, bool)| {
+        if valid {
+            source = Some(idx as IdxSize);
+        }
+        source
+    };
+    if backward {
+        let mut out: Vec<_> = validity.iter().enumerate().rev().map(&mut step).collect();
+        out.reverse();
+        IdxCa::from_iter(out)
+    } else {
+        validity.iter().enumerate().map(step).collect()
+    }
+}
+
+/// The row that fills each null between two valid rows for `Nearest`, given the validity of the
+/// rows and their increasing x-coordinates. Ties go to the next valid row.
+#[cfg(feature = "interpolate_by")]
+fn nearest_indices(validity: &Bitmap, x: &[f64]) -> IdxCa {
+    let previous = step_indices(validity, false);
+    let next = step_indices(validity, true);
+    previous
+        .iter()
+        .zip(next.iter())
+        .enumerate()
+        .map(|(idx, (low, high))| {
+            let (low, high) = (low?, high?);
+            let to_low = x[idx] - x[low as usize];
+            let to_high = x[high as usize] - x[idx];
+            Some(if to_high > to_low { low } else { high })
+        })
+        .collect()
+}
+
+fn sign(v: f64) -> f64 {
+    if v > 0.0 {
+        1.0
+    } else if v < 0.0 {
+        -1.0
+    } else {
+        0.0
+    }
+}
+
+/// The slopes at the knots of a natural cubic spline, which has a zero second derivative at both
+/// ends.
+fn spline_slopes(h: &[f64], d: &[f64]) -> Vec<f64> {
+    let n = h.len() + 1;
+    // Continuity of the second derivative gives a tridiagonal system in the slopes.
+    let mut sub = vec![0.0; n];
+    let mut diag = vec![0.0; n];
+    let mut sup = vec![0.0; n];
+    let mut rhs = vec![0.0; n];
+    diag[0] = 2.0;
+    sup[0] = 1.0;
+    rhs[0] = 3.0 * d[0];
+    for i in 1..n - 1 {
+        sub[i] = 1.0 / h[i - 1];
+        diag[i] = 2.0 * (1.0 / h[i - 1] + 1.0 / h[i]);
+        sup[i] = 1.0 / h[i];
+        rhs[i] = 3.0 * (d[i - 1] / h[i - 1] + d[i] / h[i]);
+    }
+    sub[n - 1] = 1.0;
+    diag[n - 1] = 2.0;
+    rhs[n - 1] = 3.0 * d[n - 2];
+
+    for i in 1..n {
+        let w = sub[i] / diag[i - 1];
+        diag[i] -= w * sup[i - 1];
+        rhs[i] -= w * rhs[i - 1];
+    }
+    let mut slopes = vec![0.0; n];
+    slopes[n - 1] = rhs[n - 1] / diag[n - 1];
+    for i in (0..n - 1).rev() {
+        slopes[i] = (rhs[i] - sup[i] * slopes[i + 1]) / diag[i];
+    }
+    slopes
+}
+
+/// The slopes at the knots of the Fritsch-Carlson monotone cubic, as in PCHIP.
+fn pchip_slopes(h: &[f64], d: &[f64]) -> Vec<f64> {
+    let n = h.len() + 1;
+    if n == 2 {
+        return vec![d[0]; 2];
+    }
+
+    fn end_slope(h0: f64, h1: f64, d0: f64, d1: f64) -> f64 {
+        let m = ((2.0 * h0 + h1) * d0 - h0 * d1) / (h0 + h1);
+        if sign(m) != sign(d0) {
+            0.0
+        } else if sign(d0) != sign(d1) && m.abs() > 3.0 * d0.abs() {
+            3.0 * d0
+        } else {
+            m
+        }
+    }
+
+    let mut slopes = Vec::with_capacity(n);
+    slopes.push(end_slope(h[0], h[1], d[0], d[1]));
+    for i in 1..n - 1 {
+        if sign(d[i - 1]) * sign(d[i]) <= 0.0 {
+            slopes.push(0.0);
+        } else {
+            let w1 = 2.0 * h[i] + h[i - 1];
+            let w2 = h[i] + 2.0 * h[i - 1];
+            slopes.push((w1 + w2) / (w1 / d[i - 1] + w2 / d[i]));
+        }
+    }
+    slopes.push(end_slope(h[n - 2], h[n - 3], d[n - 2], d[n - 3]));
+    slopes
+}
+
+/// Fill the nulls between the first and last valid value of `y` with a piecewise cubic through the
+/// valid values: a natural cubic spline, or the PCHIP cubic if `monotone`. `x` holds the
+/// strictly increasing x-coordinates of the rows.
+fn cubic_interp(y: &Float64Chunked, x: &[f64], monotone: bool) -> Float64Chunked {
+    let knots: Vec<usize> = y
+        .iter()
+        .enumerate()
+        .filter_map(|(idx, v)| v.map(|_| idx))
+        .collect();
+    if knots.len() < 2 || knots.len() == y.len() {
+        return y.clone();
+    }
+
+    let y_values: Vec<f64> = y.iter().map(|v| v.unwrap_or_default()).collect();
+    let (h, d): (Vec<f64>, Vec<f64>) = knots
+        .windows(2)
+        .map(|w| {
+            let h = x[w[1]] - x[w[0]];
+            (h, (y_values[w[1]] - y_values[w[0]]) / h)
+        })
+        .unzip();
+    let slopes = if monotone {
+        pchip_slopes(&h, &d)
+    } else {
+        spline_slopes(&h, &d)
+    };
+
+    let mut out = y_values.clone();
+    for (k, w) in knots.windows(2).enumerate() {
+        let (low, high) = (w[0], w[1]);
+        for idx in low + 1..high {
+            let t = (x[idx] - x[low]) / h[k];
+            let (t2, t3) = (t * t, t * t * t);
+            out[idx] = (2.0 * t3 - 3.0 * t2 + 1.0) * y_values[low]
+                + (t3 - 2.0 * t2 + t) * h[k] * slopes[k]
+                + (-2.0 * t3 + 3.0 * t2) * y_values[high]
+                + (t3 - t2) * h[k] * slopes[k + 1];
+        }
+    }
+
+    let first = knots[0];
+    let last = knots[knots.len() - 1];
+    let mut validity = MutableBitmap::with_capacity(y.len());
+    validity.extend_constant(first, false);
+    validity.extend_constant(last + 1 - first, true);
+    validity.extend_constant(y.len() - last - 1, false);
+    Float64Chunked::from_vec_validity(y.name().clone(), out, Some(validity.into()))
+}
+
+/// The rows that may hold a value under the `limit` and `limit_area` of `options`, given the
+/// validity of the rows in x order.
+fn fill_mask(validity: &Bitmap, options: &InterpolationOptions) -> Bitmap {
+    let len = validity.len();
+    let mut mask = MutableBitmap::with_capacity(len);
+    let mut start = 0;
+    while start < len {
+        if validity.get_bit(start) {
+            mask.push(true);
+            start += 1;
+            continue;
+        }
+        let end = (start..len)
+            .find(|idx| validity.get_bit(*idx))
+            .unwrap_or(len);
+        let run = end - start;
+        let inside = start > 0 && end < len;
+        let fill = match options.limit_area {
+            Some(InterpolationLimitArea::Inside) if !inside => 0,
+            Some(InterpolationLimitArea::Outside) if inside => 0,
+            _ => options.limit.map_or(run, |limit| run.min(limit as usize)),
+        };
+        if options.method == InterpolationMethod::Next {
+            mask.extend_constant(run - fill, false);
+            mask.extend_constant(fill, true);
+        } else {
+            mask.extend_constant(fill, true);
+            mask.extend_constant(run - fill, false);
+        }
+        start = end;
+    }
+    mask.into()
+}
+
+/// Null out the rows of `out` that the `limit` and `limit_area` of `options` don't allow to be
+/// filled. `original` is the input of the interpolation, in x order.
+fn apply_fill_limits(out: Series, original: &Series, options: &InterpolationOptions) -> Series {
+    if options.limit.is_none() && options.limit_area.is_none() {
+        return out;
+    }
+    let Some(validity) = original.rechunk_validity() else {
+        return out;
+    };
+    let mask = BooleanChunked::from_bitmap(PlSmallStr::EMPTY, fill_mask(&validity, options));
+    let nulls = Series::full_null(out.name().clone(), out.len(), out.dtype());
+    out.zip_with(&mask, &nulls).unwrap()
+}
@@ -0,0 +1,611 @@
+//! Hash functions whose values are stable across versions, platforms and processes.
+//!
+//! Unlike [`SeriesMethods::hash`](super::SeriesMethods::hash), these implement named algorithms,
+//! so the values can be persisted and compared with other systems.
+//!
+//! # Value model
+//!
+//! [`HashAlgorithm::Murmur3`] and [`HashAlgorithm::XxHash64`] follow the semantics of Spark's
+//! `hash` and `xxhash64` functions. Every value is hashed with the running seed:
+//! * `Boolean`, 8, 16 and 32-bit integers and `Date` are hashed as a 32-bit integer.
+//! * `UInt32`, 64-bit integers, `Datetime`, `Duration` and `Time` are hashed as a 64-bit
+//!   integer of their physical value. Cast a `Datetime` to microseconds to match Spark timestamps.
+//! * Floats are hashed by their bits, where `-0.0` is hashed as `0.0` and all NaNs are equal.
+//! * `Decimal`s with a precision of at most 18 are hashed as a 64-bit integer, wider decimals and
+//!   128-bit integers as the minimal big-endian two's complement bytes.
+//! * `String`, `Binary` and categorical values are hashed as their bytes.
+//! * The elements of a `List` or `Array` and the fields of a `Struct` are hashed in order, each
+//!   with the hash of the previous one as seed. Hashing a struct of columns thus gives the same
+//!   value as Spark's `hash` of those columns.
+//! * A null leaves the seed unchanged, so a null row hashes to the seed.
+//!
+//! [`HashAlgorithm::SipHash24`] and the digests hash a canonical byte encoding of every value and
+//! return null for a null row:
+//! * `Boolean`s are a single byte, numbers are their little-endian bytes and temporal types the
+//!   bytes of their physical value. Floats are canonicalized as above.
+//! * `String`, `Binary` and categorical values are their bytes, so the digest of a string is the
+//!   digest of its UTF-8 bytes.
+//! * Nested values are prefixed by a byte that is `0` for a null and `1` otherwise. Lists and
+//!   arrays are the number of elements as a little-endian `u64` followed by their elements,
+//!   structs the concatenation of their fields. Nested strings and binary values are prefixed by
+//!   their length as a little-endian `u64`.
+use std::hash::Hasher;
+
+use arrow::array::Array;
+use md5::Md5;
+use polars_core::prelude::*;
+use polars_core::with_match_physical_integer_polars_type;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use sha1::Sha1;
+use sha2::{Digest, Sha256};
+use siphasher::sip::SipHasher24;
+
+/// The default seed of Spark's `hash` and `xxhash64` functions.
+pub const SPARK_HASH_SEED: u32 = 42;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub enum HashAlgorithm {
+    /// 64-bit xxHash, returns `UInt64`.
+    XxHash64 { seed: u64 },
+    /// 32-bit x86 MurmurHash3 as implemented by Spark, returns `Int32`.
+    ///
+    /// Note that Spark's variant hashes the tail of strings differently from the reference
+    /// implementation.
+    Murmur3 { seed: u32 },
+    /// SipHash-2-4 with a 128-bit key, returns `UInt64`.
+    SipHash24 { k0: u64, k1: u64 },
+    /// MD5 digest, returns `Binary` or a lowercase hexadecimal `String`.
+    Md5 { hex: bool },
+    /// SHA-1 digest, returns `Binary` or a lowercase hexadecimal `String`.
+    Sha1 { hex: bool },
+    /// SHA-256 digest, returns `Binary` or a lowercase hexadecimal `String`.
+    Sha256 { hex: bool },
+}
+
+impl HashAlgorithm {
+    pub fn output_dtype(&self) -> DataType {
+        use HashAlgorithm as H;
+        match self {
+            H::XxHash64 { .. } | H::SipHash24 { .. } => DataType::UInt64,
+            H::Murmur3 { .. } => DataType::Int32,
+            H::Md5 { hex } | H::Sha1 { hex } | H::Sha256 { hex } => {
+                if *hex {
+                    DataType::String
+                } else {
+                    DataType::Binary
+                }
+            },
+        }
+    }
+}
+
+/// Hash every row of `s` with `algorithm`.
+///
+/// See the [module documentation](self) for how values and nulls are hashed.
+pub fn stable_hash(s: &Series, algorithm: HashAlgorithm) -> PolarsResult<Series> {
+    let name = s.name().clone();
+    let out = match algorithm {
+        HashAlgorithm::XxHash64 { seed } => {
+            let mut seeds = vec![seed; s.len()];
+            fold_seeds::<XxHash64>(s, &mut seeds)?;
+            UInt64Chunked::from_vec(name, seeds).into_series()
+        },
+        HashAlgorithm::Murmur3 { seed } => {
+            let mut seeds = vec![seed; s.len()];
+            fold_seeds::<Murmur3>(s, &mut seeds)?;
+            Int32Chunked::from_iter_values(name, seeds.into_iter().map(|h| h as i32)).into_series()
+        },
+        HashAlgorithm::SipHash24 { k0, k1 } => {
+            let encoded = encode(s)?;
+            encoded
+                .into_iter()
+                .map(|bytes| {
+                    bytes.map(|bytes| {
+                        let mut hasher = SipHasher24::new_with_keys(k0, k1);
+                        hasher.write(&bytes);
+                        hasher.finish()
+                    })
+                })
+                .collect::<UInt64Chunked>()
+                .with_name(name)
+                .into_series()
+        },
+        HashAlgorithm::Md5 { hex } => digest::<Md5>(s, hex)?,
+        HashAlgorithm::Sha1 { hex } => digest::<Sha1>(s, hex)?,
+        HashAlgorithm::Sha256 { hex } => digest::<Sha256>(s, hex)?,
+    };
+    Ok(out)
+}
+
+fn digest<D: Digest>(s: &Series, hex: bool) -> PolarsResult<Series> {
+    let digests = encode(s)?
+        .into_iter()
+        .map(|bytes| bytes.map(|bytes| D::digest(bytes)));
+    let out = if hex {
+        digests
+            .map(|digest| digest.map(hex::encode))
+            .collect::<StringChunked>()
+            .into_series()
+    } else {
+        digests
+            .map(|digest| digest.map(|digest| digest.to_vec()))
+            .collect::<BinaryChunked>()
+            .into_series()
+    };
+    Ok(out.with_name(s.name().clone()))
+}
+
+/// Canonicalize `-0.0` and NaNs, so equal values have equal bits.
+fn canonical_f32(v: f32) -> f32 {
+    if v.is_nan() {
+        f32::NAN
+    } else if v == 0.0 {
+        0.0
+    } else {
+        v
+    }
+}
+
+fn canonical_f64(v: f64) -> f64 {
+    if v.is_nan() {
+        f64::NAN
+    } else if v == 0.0 {
+        0.0
+    } else {
+        v
+    }
+}
+
+/// Strip the redundant sign bytes of a big-endian two's complement integer, as Java's
+/// `BigInteger::toByteArray` does.
+#[cfg(any(feature = "dtype-i128", feature = "dtype-u128"))]
+fn minimal_big_endian(bytes: &[u8]) -> &[u8] {
+    let mut start = 0;
+    while start + 1 < bytes.len() {
+        let redundant = match bytes[start] {
+            0x00 => bytes[start + 1] & 0x80 == 0,
+            0xFF => bytes[start + 1] & 0x80 != 0,
+            _ => false,
+        };
+        if !redundant {
+            break;
+        }
+        start += 1;
+    }
+    &bytes[start..]
+}
+
+#[cfg(feature = "dtype-u128")]
+fn u128_big_endian(v: u128) -> [u8; 17] {
+    let mut bytes = [0; 17];
+    bytes[1..].copy_from_slice(&v.to_be_bytes());
+    bytes
+}
+
+/// A hash function that is applied value-by-value with the previous hash as seed.
+trait SeededHasher {
+    type Seed: Copy;
+
+    fn hash_int(v: i32, seed: Self::Seed) -> Self::Seed;
+    fn hash_long(v: i64, seed: Self::Seed) -> Self::Seed;
+    fn hash_bytes(v: &[u8], seed: Self::Seed) -> Self::Seed;
+}
+
+struct XxHash64;
+
+impl SeededHasher for XxHash64 {
+    type Seed = u64;
+
+    fn hash_int(v: i32, seed: u64) -> u64 {
+        xxhash_rust::xxh64::xxh64(&v.to_le_bytes(), seed)
+    }
+
+    fn hash_long(v: i64, seed: u64) -> u64 {
+        xxhash_rust::xxh64::xxh64(&v.to_le_bytes(), seed)
+    }
+
+    fn hash_bytes(v: &[u8], seed: u64) -> u64 {
+        xxhash_rust::xxh64::xxh64(v, seed)
+    }
+}
+
+struct Murmur3;
+
+impl Murmur3 {
+    const C1: u32 = 0xcc9e2d51;
+    const C2: u32 = 0x1b873593;
+
+    fn mix_k1(k1: u32) -> u32 {
+        k1.wrapping_mul(Self::C1)
+            .rotate_left(15)
+            .wrapping_mul(Self::C2)
+    }
+
+    fn mix_h1(h1: u32, k1: u32) -> u32 {
+        (h1 ^ k1)
+            .rotate_left(13)
+            .wrapping_mul(5)
+            .wrapping_add(0xe6546b64)
+    }
+
+    fn fmix(mut h1: u32, len: u32) -> u32 {
+        h1 ^= len;
+        h1 ^= h1 >> 16;
+        h1 = h1.wrapping_mul(0x85ebca6b);
+        h1 ^= h1 >> 13;
+        h1 = h1.wrapping_mul(0xc2b2ae35);
+        h1 ^ (h1 >> 16)
+    }
+}
+
+impl SeededHasher for Murmur3 {
+    type Seed = u32;
+
+    fn hash_int(v: i32, seed: u32) -> u32 {
+        Self::fmix(Self::mix_h1(seed, Self::mix_k1(v as u32)), 4)
+    }
+
+    fn hash_long(v: i64, seed: u32) -> u32 {
+        let h1 = Self::mix_h1(seed, Self::mix_k1(v as u32));
+        let h1 = Self::mix_h1(h1, Self::mix_k1((v >> 32) as u32));
+        Self::fmix(h1, 8)
+    }
+
+    fn hash_bytes(v: &[u8], seed: u32) -> u32 {
+        let mut chunks = v.chunks_exact(4);
+        let mut h1 = seed;
+        for chunk in &mut chunks {
+            let k1 = u32::from_le_bytes(chunk.try_into().unwrap());
+            h1 = Self::mix_h1(h1, Self::mix_k1(k1));
+        }
+        // Spark mixes every trailing byte separately as a sign-extended integer.
+        for &byte in chunks.remainder() {
+            h1 = Self::mix_h1(h1, Self::mix_k1(byte as i8 as u32));
+        }
+        Self::fmix(h1, v.len() as u32)
+    }
+}
+
+fn update_seeds<H: SeededHasher, T>(
+    seeds: &mut [H::Seed],
+    values: impl IntoIterator<Item = Option<T>>,
+    hash: impl Fn(T, H::Seed) -> H::Seed,
+) {
+    for (seed, v) in seeds.iter_mut().zip(values) {
+        if let Some(v) = v {
+            *seed = hash(v, *seed);
+        }
+    }
+}
+
+/// The `[row, start, end]` element ranges of the non-empty, valid rows of a list or array.
+type ElementRanges = Vec<[usize; 3]>;
+
+fn list_element_ranges(ca: &ListChunked) -> ElementRanges {
+    let arr = ca.downcast_as_array();
+    let offsets = arr.offsets();
+    (0..arr.len())
+        .filter(|&i| arr.is_valid(i))
+        .map(|i| [i, offsets[i] as usize, offsets[i + 1] as usize])
+        .filter(|[_, start, end]| start < end)
+        .collect()
+}
+
+#[cfg(feature = "dtype-array")]
+fn array_element_ranges(ca: &ArrayChunked) -> ElementRanges {
+    let arr = ca.downcast_as_array();
+    let width = ca.width();
+    if width == 0 {
+        return vec![];
+    }
+    (0..arr.len())
+        .filter(|&i| arr.is_valid(i))
+        .map(|i| [i, i * width, (i + 1) * width])
+        .collect()
+}
+
+/// Call `f` with the `k`-th elements of all rows with more than `k` elements, for every `k` in
+/// order.
+fn for_each_position(
+    values: &Series,
+    mut ranges: ElementRanges,
+    mut f: impl FnMut(&Series, &ElementRanges) -> PolarsResult<()>,
+) -> PolarsResult<()> {
+    let mut k = 0;
+    while !ranges.is_empty() {
+        let idx = ranges
+            .iter()
+            .map(|[_, start, _]| (start + k) as IdxSize)
+            .collect::<Vec<_>>();
+        let elements = values.take_slice(&idx)?;
+        f(&elements, &ranges)?;
+        k += 1;
+        ranges.retain(|[_, start, end]| start + k < *end);
+    }
+    Ok(())
+}
+
+fn fold_elements<H: SeededHasher>(
+    values: &Series,
+    ranges: ElementRanges,
+    seeds: &mut [H::Seed],
+) -> PolarsResult<()> {
+    for_each_position(values, ranges, |elements, ranges| {
+        let mut element_seeds = ranges
+            .iter()
+            .map(|[row, ..]| seeds[*row])
+            .collect::<Vec<_>>();
+        fold_seeds::<H>(elements, &mut element_seeds)?;
+        for ([row, ..], seed) in ranges.iter().zip(element_seeds) {
+            seeds[*row] = seed;
+        }
+        Ok(())
+    })
+}
+
+/// Hash every value of `s` into the seed of its row.
+fn fold_seeds<H: SeededHasher>(s: &Series, seeds: &mut [H::Seed]) -> PolarsResult<()> {
+    use DataType as D;
+    match s.dtype() {
+        D::Boolean | D::UInt8 | D::UInt16 | D::Int8 | D::Int16 | D::Int32 | D::Date => {
+            let s = s.to_physical_repr().cast(&D::Int32)?;
+            update_seeds::<H, _>(seeds, s.i32()?, H::hash_int);
+        },
+        D::UInt32 | D::Int64 | D::Datetime(_, _) | D::Duration(_) | D::Time => {
+            let s = s.to_physical_repr().cast(&D::Int64)?;
+            update_seeds::<H, _>(seeds, s.i64()?, H::hash_long);
+        },
+        D::UInt64 => update_seeds::<H, _>(seeds, s.u64()?, |v, seed| H::hash_long(v as i64, seed)),
+        D::Float32 => update_seeds::<H, _>(seeds, s.f32()?, |v, seed| {
+            H::hash_int(canonical_f32(v).to_bits() as i32, seed)
+        }),
+        D::Float64 => update_seeds::<H, _>(seeds, s.f64()?, |v, seed| {
+            H::hash_long(canonical_f64(v).to_bits() as i64, seed)
+        }),
+        #[cfg(feature = "dtype-decimal")]
+        D::Decimal(precision, _) => {
+            let ca = s.decimal()?.physical();
+            if *precision <= 18 {
+                update_seeds::<H, _>(seeds, ca, |v, seed| H::hash_long(v as i64, seed))
+            } else {
+                update_seeds::<H, _>(seeds, ca, |v, seed| {
+                    H::hash_bytes(minimal_big_endian(&v.to_be_bytes()), seed)
+                })
+            }
+        },
+        #[cfg(feature = "dtype-i128")]
+        D::Int128 => update_seeds::<H, _>(seeds, s.i128()?, |v, seed| {
+            H::hash_bytes(minimal_big_endian(&v.to_be_bytes()), seed)
+        }),
+        #[cfg(feature = "dtype-u128")]
+        D::UInt128 => update_seeds::<H, _>(seeds, s.u128()?, |v, seed| {
+            H::hash_bytes(minimal_big_endian(&u128_big_endian(v)), seed)
+        }),
+        D::String => {
+            update_seeds::<H, _>(seeds, s.str()?, |v, seed| H::hash_bytes(v.as_bytes(), seed))
+        },
+        D::Binary => update_seeds::<H, _>(seeds, s.binary()?, H::hash_bytes),
+        D::BinaryOffset => fold_seeds::<H>(&s.cast(&D::Binary)?, seeds)?,
+        #[cfg(feature = "dtype-categorical")]
+        D::Categorical(_, _) | D::Enum(_, _) => fold_seeds::<H>(&s.cast(&D::String)?, seeds)?,
+        D::Null => {},
+        D::List(_) => {
+            let ca = s.list()?.rechunk();
+            fold_elements::<H>(&ca.get_inner(), list_element_ranges(&ca), seeds)?;
+        },
+        #[cfg(feature = "dtype-array")]
+        D::Array(_, _) => {
+            let ca = s.array()?.rechunk();
+            fold_elements::<H>(&ca.get_inner(), array_element_ranges(&ca), seeds)?;
+        },
+        #[cfg(feature = "dtype-struct")]
+        D::Struct(_) => {
+            let mut field_seeds = seeds.to_vec();
+            for field in s.struct_()?.fields_as_series() {
+                fold_seeds::<H>(&field, &mut field_seeds)?;
+            }
+            for ((seed, field_seed), valid) in
+                seeds.iter_mut().zip(field_seeds).zip(&s.is_not_null())
+            {
+                if valid.unwrap() {
+                    *seed = field_seed;
+                }
+            }
+        },
+        dt => polars_bail!(opq = stable_hash, dt),
+    }
+    Ok(())
+}
+
+/// The canonical encoding of every row of `s`, `None` for a null row.
+fn encode(s: &Series) -> PolarsResult<Vec<Option<Vec<u8>>>> {
+    let mut bufs = vec![vec![]; s.len()];
+    encode_rows(s, &mut bufs, false)?;
+    Ok(bufs
+        .into_iter()
+        .zip(&s.is_not_null())
+        .map(|(buf, valid)| valid.unwrap().then_some(buf))
+        .collect())
+}
+
+fn extend_bufs<T>(
+    bufs: &mut [Vec<u8>],
+    values: impl IntoIterator<Item = Option<T>>,
+    extend: impl Fn(&mut Vec<u8>, T),
+) {
+    for (buf, v) in bufs.iter_mut().zip(values) {
+        if let Some(v) = v {
+            extend(buf, v);
+        }
+    }
+}
+
+fn encode_bytes(buf: &mut Vec<u8>, v: &[u8], nested: bool) {
+    if nested {
+        encode_length(buf, v.len());
+    }
+    buf.extend_from_slice(v);
+}
+
+fn encode_elements(
+    values: &Series,
+    ranges: ElementRanges,
+    bufs: &mut [Vec<u8>],
+) -> PolarsResult<()> {
+    for_each_position(values, ranges, |elements, ranges| {
+        let mut element_bufs = ranges
+            .iter()
+            .map(|[row, ..]| std::mem::take(&mut bufs[*row]))
+            .collect::<Vec<_>>();
+        encode_rows(elements, &mut element_bufs, true)?;
+        for ([row, ..], buf) in ranges.iter().zip(element_bufs) {
+            bufs[*row] = buf;
+        }
+        Ok(())
+    })
+}
+
+fn encode_length(buf: &mut Vec<u8>, len: usize) {
+    buf.extend_from_slice(&(len as u64).to_le_bytes());
+}
+
+/// Append the canonical encoding of every row of `s` to its buffer.
+fn encode_rows(s: &Series, bufs: &mut [Vec<u8>], nested: bool) -> PolarsResult<()> {
+    if nested {
+        extend_bufs(bufs, &s.is_not_null(), |buf, valid| buf.push(valid as u8));
+    }
+    encode_values(s, bufs, nested)
+}
+
+/// Append the canonical encoding of every valid value of `s` to its buffer.
+fn encode_values(s: &Series, bufs: &mut [Vec<u8>], nested: bool) -> PolarsResult<()> {
+    use DataType as D;
+    match s.dtype() {
+        D::Boolean => extend_bufs(bufs, s.bool()?, |buf, v| buf.push(v as u8)),
+        D::Float32 => extend_bufs(bufs, s.f32()?, |buf, v| {
+            buf.extend_from_slice(&canonical_f32(v).to_le_bytes())
+        }),
+        D::Float64 => extend_bufs(bufs, s.f64()?, |buf, v| {
+            buf.extend_from_slice(&canonical_f64(v).to_le_bytes())
+        }),
+        dt if dt.to_physical().is_integer() => {
+            let s = s.to_physical_repr();
+            with_match_physical_integer_polars_type!(s.dtype(), |$T| {
+                let ca = s.unpack::<$T>()?;
+                extend_bufs(bufs, ca, |buf, v| buf.extend_from_slice(v.to_le_bytes().as_ref()))
+            })
+        },
+        D::String => extend_bufs(bufs, s.str()?, |buf, v| {
+            encode_bytes(buf, v.as_bytes(), nested)
+        }),
+        D::Binary => extend_bufs(bufs, s.binary()?, |buf, v| encode_bytes(buf, v, nested)),
+        D::BinaryOffset => encode_values(&s.cast(&D::Binary)?, bufs, nested)?,
+        #[cfg(feature = "dtype-categorical")]
+        D::Categorical(_, _) | D::Enum(_, _) => encode_values(&s.cast(&D::String)?, bufs, nested)?,
+        D::Null => {},
+        D::List(_) => {
+            let ca = s.list()?.rechunk();
+            let arr = ca.downcast_as_array();
+            let lengths =
+                (0..arr.len()).map(|i| arr.is_valid(i).then(|| arr.offsets().length_at(i)));
+            extend_bufs(bufs, lengths, encode_length);
+            encode_elements(&ca.get_inner(), list_element_ranges(&ca), bufs)?;
+        },
+        #[cfg(feature = "dtype-array")]
+        D::Array(_, _) => {
+            let ca = s.array()?.rechunk();
+            let validity = s.is_not_null();
+            let lengths = validity
+                .into_iter()
+                .map(|valid| valid.unwrap().then_some(ca.width()));
+            extend_bufs(bufs, lengths, encode_length);
+            encode_elements(&ca.get_inner(), array_element_ranges(&ca), bufs)?;
+        },
+        #[cfg(feature = "dtype-struct")]
+        D::Struct(_) => {
+            let mut field_bufs = vec![vec![]; bufs.len()];
+            for field in s.struct_()?.fields_as_series() {
+                encode_rows(&field, &mut field_bufs, true)?;
+            }
+            extend_bufs(
+                bufs,
+                field_bufs
+                    .into_iter()
+                    .zip(&s.is_not_null())
+                    .map(|(field_buf, valid)| valid.unwrap().then_some(field_buf)),
+                |buf, field_buf| buf.extend_from_slice(&field_buf),
+            );
+        },
+        dt => polars_bail!(opq = stable_hash, dt),
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_spark_hashes() -> PolarsResult<()> {
+        let s = Series::new("a".into(), [Some(1i32), None]);
+        let out = stable_hash(&s, HashAlgorithm::Murmur3 { seed: 42 })?;
+        assert_eq!(out.i32()?.to_vec(), [Some(-559580957), Some(42)]);
+
+        // `SELECT hash('Spark', array(123), 2)` and `SELECT xxhash64('Spark', array(123), 2)`.
+        #[cfg(feature = "dtype-struct")]
+        {
+            let list = Series::new("b".into(), [Series::new("".into(), [123i32])]);
+            let s = StructChunked::from_series(
+                "s".into(),
+                1,
+                [
+                    Series::new("a".into(), ["Spark"]),
+                    list,
+                    Series::new("c".into(), [2i32]),
+                ]
+                .iter(),
+            )?
+            .into_series();
+            let out = stable_hash(&s, HashAlgorithm::Murmur3 { seed: 42 })?;
+            assert_eq!(out.i32()?.get(0), Some(-1321691492));
+            let out = stable_hash(&s, HashAlgorithm::XxHash64 { seed: 42 })?;
+            assert_eq!(out.u64()?.get(0), Some(5602566077635097486));
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_digests() -> PolarsResult<()> {
+        let s = Series::new("a".into(), [Some("Spark"), None]);
+        let out = stable_hash(&s, HashAlgorithm::Md5 { hex: true })?;
+        assert_eq!(
+            out.str()?.iter().collect::<Vec<_>>(),
+            [Some("8cde774d6f7333752ed72cacddb05126"), None]
+        );
+        let out = stable_hash(&s, HashAlgorithm::Sha1 { hex: true })?;
+        assert_eq!(
+            out.str()?.get(0),
+            Some("85f5955f4b27a9a4c2aab6ffe5d7189fc298b92c")
+        );
+        let out = stable_hash(&s, HashAlgorithm::Sha256 { hex: false })?;
+        assert_eq!(
+            hex::encode(out.binary()?.get(0).unwrap()),
+            "529bc3b07127ecb7e53a4dcf1991d9152c24537d919178022b2c42657f79a26b"
+        );
+
+        // Reference vector of the SipHash paper for an empty message.
+        let s = Series::new("a".into(), [""]);
+        let out = stable_hash(
+            &s,
+            HashAlgorithm::SipHash24 {
+                k0: 0x0706050403020100,
+                k1: 0x0f0e0d0c0b0a0908,
+            },
+        )?;
+        assert_eq!(out.u64()?.get(0), Some(0x726fdb47dd0e0e31));
+        Ok(())
+    }
+}
@@ -1,7 +1,9 @@
 use std::ops::Range;
+use std::sync::Arc;
 
 use arrow::array::Splitable;
 use arrow::bitmap::Bitmap;
+use polars_utils::relaxed_cell::RelaxedCell;
 
 use crate::read::expr::ParquetColumnExprRef;
 
@@ -9,6 +11,8 @@ use crate::read::expr::ParquetColumnExprRef;
 pub struct PredicateFilter {
     pub predicate: ParquetColumnExprRef,
     pub include_values: bool,
+    /// Incremented for every page skipped without being decompressed.
+    pub num_skipped_pages: Option<Arc<RelaxedCell<usize>>>,
 }
 
 #[derive(Clone)]
@@ -578,6 +578,9 @@ impl<D: Decoder> PageDecoder<D> {
 
             if can_skip_page {
                 pred_true_mask.extend_constant(page.num_values(), false);
+                if let Some(num_skipped_pages) = &p.num_skipped_pages {
+                    num_skipped_pages.fetch_add(1);
+                }
                 continue;
             }
 
@@ -6,7 +6,7 @@ use polars_error::{PolarsError, PolarsResult};
 use super::schema::schema_to_metadata_key;
 use super::{ColumnWriteOptions, ThriftFileMetadata, WriteOptions, to_parquet_schema};
 use crate::parquet::metadata::{KeyValue, SchemaDescriptor};
-use crate::parquet::write::{RowGroupIterColumns, WriteOptions as FileWriteOptions};
+use crate::parquet::write::{RowGroupIterColumns, SortingColumn, WriteOptions as FileWriteOptions};
 
 /// An interface to write a parquet to a [`Write`]
 pub struct FileWriter<W: Write> {
@@ -83,6 +83,17 @@ impl<W: Write> FileWriter<W> {
         Ok(self.writer.write(row_group)?)
     }
 
+    /// Writes a row group to the file, recording the order of its rows in its metadata.
+    pub fn write_with_sorting_columns(
+        &mut self,
+        row_group: RowGroupIterColumns<'_, PolarsError>,
+        sorting_columns: Option<Vec<SortingColumn>>,
+    ) -> PolarsResult<()> {
+        Ok(self
+            .writer
+            .write_with_sorting_columns(row_group, sorting_columns)?)
+    }
+
     /// Writes the footer of the parquet file. Returns the total size of the file.
     /// If `key_value_metadata` is provided, the value is taken as-is. If it is not provided,
     /// the Arrow schema is added to the metadata.
@@ -45,8 +45,8 @@ pub use crate::parquet::schema::types::{
     FieldInfo, ParquetType, PhysicalType as ParquetPhysicalType,
 };
 pub use crate::parquet::write::{
-    Compressor, DynIter, DynStreamingIterator, RowGroupIterColumns, Version, compress,
-    write_metadata_sidecar,
+    Compressor, DynIter, DynStreamingIterator, RowGroupIterColumns, SortingColumn, Version,
+    compress, write_metadata_sidecar,
 };
 pub use crate::parquet::{FallibleStreamingIterator, fallible_streaming_iterator};
 
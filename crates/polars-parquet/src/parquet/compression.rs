@@ -244,6 +244,72 @@ pub fn decompress(
     }
 }
 
+/// A snappy copy element of 3 bytes expands to at most 64 bytes, so a snappy block can't decompress
+/// to more than this multiple of its own length.
+#[cfg(feature = "snappy")]
+const SNAPPY_MAX_RATIO: usize = 22;
+
+/// Decompresses data stored in slice `input_buf` whose decompressed size is not known upfront and
+/// appends the output to `output_buf`.
+///
+/// Only codecs that record the end of the compressed stream in their own framing are supported.
+/// Concatenated gzip members are decompressed as a single stream.
+#[allow(unused_variables)]
+pub fn decompress_to_vec(
+    compression: Compression,
+    input_buf: &[u8],
+    output_buf: &mut Vec<u8>,
+) -> ParquetResult<()> {
+    match compression {
+        #[cfg(feature = "brotli")]
+        Compression::Brotli => {
+            use std::io::Read;
+            const BROTLI_DEFAULT_BUFFER_SIZE: usize = 4096;
+            brotli::Decompressor::new(input_buf, BROTLI_DEFAULT_BUFFER_SIZE)
+                .read_to_end(output_buf)
+                .map(|_| ())
+                .map_err(|e| e.into())
+        },
+        #[cfg(feature = "gzip")]
+        Compression::Gzip => {
+            use std::io::Read;
+            flate2::read::MultiGzDecoder::new(input_buf)
+                .read_to_end(output_buf)
+                .map(|_| ())
+                .map_err(|e| e.into())
+        },
+        #[cfg(feature = "snappy")]
+        Compression::Snappy => {
+            use snap::raw::{Decoder, decompress_len};
+
+            let len = decompress_len(input_buf)?;
+            if len > input_buf.len().saturating_mul(SNAPPY_MAX_RATIO) {
+                return Err(ParquetError::oos(format!(
+                    "snappy length header {len} is too large for a block of {} bytes",
+                    input_buf.len()
+                )));
+            }
+            let original_length = output_buf.len();
+            output_buf.resize(original_length + len, 0);
+            Decoder::new()
+                .decompress(input_buf, &mut output_buf[original_length..])
+                .map_err(|e| e.into())
+                .map(|_| ())
+        },
+        #[cfg(feature = "zstd")]
+        Compression::Zstd => {
+            use std::io::Read;
+            zstd::Decoder::with_buffer(input_buf)?
+                .read_to_end(output_buf)
+                .map(|_| ())
+                .map_err(|e| e.into())
+        },
+        _ => Err(ParquetError::FeatureNotSupported(format!(
+            "Decompressing {compression:?} without a known output size is not supported",
+        ))),
+    }
+}
+
 /// Try to decompress the buffer as if it was compressed with the Hadoop Lz4Codec.
 /// Translated from the apache arrow c++ function [TryDecompressHadoop](https://github.com/apache/arrow/blob/bf18e6e4b5bb6180706b1ba0d597a65a4ce5ca48/cpp/src/arrow/util/compression_lz4.cc#L474).
 /// Returns error if decompression failed.
@@ -343,6 +409,14 @@ mod tests {
         )
         .expect("Error when decompressing");
         assert_eq!(data, decompressed.as_slice());
+
+        // Raw lz4 blocks don't record their decompressed size
+        if c != CompressionOptions::Lz4Raw {
+            let mut decompressed = vec![];
+            decompress_to_vec(c.into(), &compressed[offset..], &mut decompressed)
+                .expect("Error when decompressing without a known size");
+            assert_eq!(data, decompressed.as_slice());
+        }
     }
 
     fn test_codec(c: CompressionOptions) {
@@ -358,6 +432,16 @@ mod tests {
         test_codec(CompressionOptions::Snappy);
     }
 
+    #[cfg(feature = "snappy")]
+    #[test]
+    fn test_snappy_oversized_length_header() {
+        // A varint length header of `u32::MAX` followed by a single literal byte.
+        let input = [0xff, 0xff, 0xff, 0xff, 0x0f, 0x00, 0x00];
+        let mut decompressed = vec![];
+        assert!(decompress_to_vec(Compression::Snappy, &input, &mut decompressed).is_err());
+        assert!(decompressed.capacity() < 1024);
+    }
+
     #[test]
     fn test_codec_gzip_default() {
         test_codec(CompressionOptions::Gzip(None));
@@ -1,4 +1,10 @@
 use polars_core::prelude::*;
+#[cfg(feature = "diff")]
+use polars_core::series::ops::NullBehavior;
+#[cfg(feature = "list_sets")]
+use polars_ops::chunked_array::list::SetOperation;
+#[cfg(feature = "array_linalg")]
+use polars_utils::total_ord::TotalOrdWrap;
 
 use crate::dsl::function_expr::ArrayFunction;
 use crate::prelude::*;
@@ -97,6 +103,12 @@ impl ArrayNameSpace {
             .map_unary(FunctionExpr::ArrayExpr(ArrayFunction::Sort(options)))
     }
 
+    /// Check whether every subarray is sorted in the order of `options`.
+    pub fn is_sorted(self, options: SortOptions) -> Expr {
+        self.0
+            .map_unary(FunctionExpr::ArrayExpr(ArrayFunction::IsSorted(options)))
+    }
+
     pub fn reverse(self) -> Expr {
         self.0
             .map_unary(FunctionExpr::ArrayExpr(ArrayFunction::Reverse))
@@ -148,6 +160,174 @@ impl ArrayNameSpace {
         )
     }
 
+    #[cfg(feature = "array_linalg")]
+    /// Compute the dot product of every subarray with the subarray of `other`.
+    pub fn dot<E: Into<Expr>>(self, other: E) -> Expr {
+        self.0
+            .map_binary(FunctionExpr::ArrayExpr(ArrayFunction::Dot), other.into())
+    }
+
+    #[cfg(feature = "array_linalg")]
+    /// Compute the cosine similarity of every subarray with the subarray of `other`.
+    pub fn cosine_similarity<E: Into<Expr>>(self, other: E) -> Expr {
+        self.0.map_binary(
+            FunctionExpr::ArrayExpr(ArrayFunction::CosineSimilarity),
+            other.into(),
+        )
+    }
+
+    #[cfg(feature = "array_linalg")]
+    /// Compute the Euclidean distance between every subarray and the subarray of `other`.
+    pub fn l2_distance<E: Into<Expr>>(self, other: E) -> Expr {
+        self.0.map_binary(
+            FunctionExpr::ArrayExpr(ArrayFunction::L2Distance),
+            other.into(),
+        )
+    }
+
+    #[cfg(feature = "array_linalg")]
+    /// Compute the `p`-norm of every subarray.
+    pub fn norm(self, p: f64) -> Expr {
+        self.0
+            .map_unary(FunctionExpr::ArrayExpr(ArrayFunction::Norm(TotalOrdWrap(
+                p,
+            ))))
+    }
+
+    #[cfg(feature = "array_linalg")]
+    /// Divide every subarray by its `p`-norm.
+    pub fn normalize(self, p: f64) -> Expr {
+        self.0
+            .map_unary(FunctionExpr::ArrayExpr(ArrayFunction::Normalize(
+                TotalOrdWrap(p),
+            )))
+    }
+
+    /// Get items in every subarray by multiple indexes. `Array` indexes give an `Array`, `List`
+    /// indexes give a `List`.
+    ///
+    /// # Arguments
+    /// - `null_on_oob`: Return a null when an index is out of bounds.
+    ///   This behavior is more expensive than defaulting to returning an `Error`.
+    #[cfg(feature = "list_gather")]
+    pub fn gather(self, index: Expr, null_on_oob: bool) -> Expr {
+        self.0.map_binary(
+            FunctionExpr::ArrayExpr(ArrayFunction::Gather(null_on_oob)),
+            index,
+        )
+    }
+
+    /// Diff every subarray. With [`NullBehavior::Drop`] the subarrays become `n` elements
+    /// narrower.
+    #[cfg(feature = "diff")]
+    pub fn diff(self, n: i64, null_behavior: NullBehavior) -> Expr {
+        self.0
+            .map_unary(FunctionExpr::ArrayExpr(ArrayFunction::Diff {
+                n,
+                null_behavior,
+            }))
+    }
+
+    /// Sample `n` items from every subarray.
+    #[cfg(feature = "list_sample")]
+    pub fn sample_n(
+        self,
+        n: Expr,
+        with_replacement: bool,
+        shuffle: bool,
+        seed: Option<u64>,
+    ) -> Expr {
+        self.0.map_binary(
+            FunctionExpr::ArrayExpr(ArrayFunction::Sample {
+                is_fraction: false,
+                with_replacement,
+                shuffle,
+                seed,
+            }),
+            n,
+        )
+    }
+
+    /// Sample a fraction of the items of every subarray.
+    #[cfg(feature = "list_sample")]
+    pub fn sample_fraction(
+        self,
+        fraction: Expr,
+        with_replacement: bool,
+        shuffle: bool,
+        seed: Option<u64>,
+    ) -> Expr {
+        self.0.map_binary(
+            FunctionExpr::ArrayExpr(ArrayFunction::Sample {
+                is_fraction: true,
+                with_replacement,
+                shuffle,
+                seed,
+            }),
+            fraction,
+        )
+    }
+
+    #[cfg(feature = "list_sets")]
+    fn set_operation(self, other: Expr, set_operation: SetOperation) -> Expr {
+        self.0.map_binary(
+            FunctionExpr::ArrayExpr(ArrayFunction::SetOperation(set_operation)),
+            other,
+        )
+    }
+
+    /// Return the SET UNION between both arrays.
+    #[cfg(feature = "list_sets")]
+    pub fn union<E: Into<Expr>>(self, other: E) -> Expr {
+        self.set_operation(other.into(), SetOperation::Union)
+    }
+
+    /// Return the SET DIFFERENCE between both arrays.
+    #[cfg(feature = "list_sets")]
+    pub fn set_difference<E: Into<Expr>>(self, other: E) -> Expr {
+        self.set_operation(other.into(), SetOperation::Difference)
+    }
+
+    /// Return the SET INTERSECTION between both arrays.
+    #[cfg(feature = "list_sets")]
+    pub fn set_intersection<E: Into<Expr>>(self, other: E) -> Expr {
+        self.set_operation(other.into(), SetOperation::Intersection)
+    }
+
+    /// Return the SET SYMMETRIC DIFFERENCE between both arrays.
+    #[cfg(feature = "list_sets")]
+    pub fn set_symmetric_difference<E: Into<Expr>>(self, other: E) -> Expr {
+        self.set_operation(other.into(), SetOperation::SymmetricDifference)
+    }
+
+    /// Check whether both arrays have an element in common.
+    #[cfg(feature = "list_sets")]
+    pub fn contains_any<E: Into<Expr>>(self, other: E) -> Expr {
+        self.0.map_binary(
+            FunctionExpr::ArrayExpr(ArrayFunction::ContainsAny),
+            other.into(),
+        )
+    }
+
+    /// Run an expression on the elements of every subarray.
+    ///
+    /// The result is an `Array` of the same width, or of width 1 if the expression is a scalar.
+    /// Set `as_list` to collect the result into a `List` instead, which allows expressions that
+    /// change the number of elements.
+    pub fn eval<E: Into<Expr>>(self, other: E, as_list: bool) -> Expr {
+        Expr::Eval {
+            expr: Arc::new(self.0),
+            evaluation: Arc::new(other.into()),
+            variant: EvalVariant::Array { as_list },
+        }
+    }
+
+    /// Keep the elements of every subarray for which `predicate` is true. The result is a `List`.
+    #[cfg(feature = "list_filter")]
+    pub fn filter(self, predicate: Expr) -> Expr {
+        self.eval(Expr::Column(PlSmallStr::EMPTY).filter(predicate), true)
+    }
+
     #[cfg(feature = "array_to_struct")]
     pub fn to_struct(self, name_generator: Option<DslNameGenerator>) -> Expr {
         self.0.map_unary(ArrayFunction::ToStruct(name_generator))
@@ -61,4 +61,25 @@ impl BinaryNameSpace {
                 is_little_endian,
             )))
     }
+
+    /// Compress every value with `codec`, using the codec's default level when `level` is `None`.
+    #[cfg(feature = "binary_compression")]
+    pub fn compress(self, codec: BinaryCodec, level: Option<i32>) -> Expr {
+        self.0
+            .map_unary(FunctionExpr::BinaryExpr(BinaryFunction::Compress {
+                codec,
+                level,
+            }))
+    }
+
+    /// Decompress values compressed with `codec`. Invalid values raise an error when `strict` and
+    /// give a null otherwise.
+    #[cfg(feature = "binary_compression")]
+    pub fn decompress(self, codec: BinaryCodec, strict: bool) -> Expr {
+        self.0
+            .map_unary(FunctionExpr::BinaryExpr(BinaryFunction::Decompress {
+                codec,
+                strict,
+            }))
+    }
 }
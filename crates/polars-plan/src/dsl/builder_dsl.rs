@@ -5,6 +5,8 @@ use polars_core::prelude::*;
 use polars_io::csv::read::CsvReadOptions;
 #[cfg(feature = "ipc")]
 use polars_io::ipc::IpcScanOptions;
+#[cfg(feature = "orc")]
+use polars_io::orc::OrcScanOptions;
 #[cfg(feature = "parquet")]
 use polars_io::parquet::read::ParquetOptions;
 use polars_utils::unique_id::UniqueId;
@@ -83,6 +85,21 @@ impl DslBuilder {
         .into())
     }
 
+    #[cfg(feature = "orc")]
+    pub fn scan_orc(
+        sources: ScanSources,
+        options: OrcScanOptions,
+        unified_scan_args: UnifiedScanArgs,
+    ) -> PolarsResult<Self> {
+        Ok(DslPlan::Scan {
+            sources,
+            unified_scan_args: Box::new(unified_scan_args),
+            scan_type: Box::new(FileScanDsl::Orc { options }),
+            cached_ir: Default::default(),
+        }
+        .into())
+    }
+
     #[allow(clippy::too_many_arguments)]
     #[cfg(feature = "csv")]
     pub fn scan_csv(
@@ -228,6 +245,7 @@ impl DslBuilder {
         .into()
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub fn group_by<E: AsRef<[Expr]>>(
         self,
         keys: Vec<Expr>,
@@ -236,6 +254,7 @@ impl DslBuilder {
         maintain_order: bool,
         #[cfg(feature = "dynamic_group_by")] dynamic_options: Option<DynamicGroupOptions>,
         #[cfg(feature = "dynamic_group_by")] rolling_options: Option<RollingGroupOptions>,
+        #[cfg(feature = "dynamic_group_by")] session_options: Option<SessionGroupOptions>,
     ) -> Self {
         let aggs = aggs.as_ref().to_vec();
         let options = GroupbyOptions {
@@ -243,6 +262,8 @@ impl DslBuilder {
             dynamic: dynamic_options,
             #[cfg(feature = "dynamic_group_by")]
             rolling: rolling_options,
+            #[cfg(feature = "dynamic_group_by")]
+            session: session_options,
             slice: None,
         };
 
@@ -13,7 +13,6 @@ use polars_core::prelude::*;
 use polars_utils::format_pl_smallstr;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
-#[cfg(feature = "serde")]
 pub mod named_serde;
 #[cfg(feature = "serde")]
 mod serde_expr;
@@ -542,6 +541,12 @@ pub enum EvalVariant {
     /// `list.eval`
     List,
 
+    /// `arr.eval`
+    ///
+    /// With `as_list` the result is a `List`, otherwise it is an `Array` of the input width, or of
+    /// width 1 if the evaluation is a scalar.
+    Array { as_list: bool },
+
     /// `cumulative_eval`
     Cumulative { min_samples: usize },
 }
@@ -550,6 +555,7 @@ impl EvalVariant {
     pub fn to_name(&self) -> &'static str {
         match self {
             Self::List => "list.eval",
+            Self::Array { .. } => "arr.eval",
             Self::Cumulative { min_samples: _ } => "cumulative_eval",
         }
     }
@@ -558,6 +564,8 @@ impl EvalVariant {
     pub fn element_dtype<'a>(&self, dtype: &'a DataType) -> PolarsResult<&'a DataType> {
         match (self, dtype) {
             (Self::List, DataType::List(inner)) => Ok(inner.as_ref()),
+            #[cfg(feature = "dtype-array")]
+            (Self::Array { .. }, DataType::Array(inner, _)) => Ok(inner.as_ref()),
             (Self::Cumulative { min_samples: _ }, dt) => Ok(dt),
             _ => polars_bail!(op = self.to_name(), dtype),
         }
@@ -565,21 +573,21 @@ impl EvalVariant {
 
     pub fn is_elementwise(&self) -> bool {
         match self {
-            EvalVariant::List => true,
+            EvalVariant::List | EvalVariant::Array { .. } => true,
             EvalVariant::Cumulative { min_samples: _ } => false,
         }
     }
 
     pub fn is_row_separable(&self) -> bool {
         match self {
-            EvalVariant::List => true,
+            EvalVariant::List | EvalVariant::Array { .. } => true,
             EvalVariant::Cumulative { min_samples: _ } => false,
         }
     }
 
     pub fn is_length_preserving(&self) -> bool {
         match self {
-            EvalVariant::List | EvalVariant::Cumulative { .. } => true,
+            EvalVariant::List | EvalVariant::Array { .. } | EvalVariant::Cumulative { .. } => true,
         }
     }
 }
@@ -1,17 +1,73 @@
 use std::sync::{Arc, LazyLock, RwLock};
 
+use polars_core::error::{PolarsResult, polars_bail};
+
+#[cfg(feature = "serde")]
 use super::AnonymousColumnsUdf;
+use crate::plans::{AnonymousScan, DataFrameUdf};
 
 // Can be used to have named anonymous functions.
 // The receiver must have implemented this registry and map the names to the proper UDFs.
+#[cfg(feature = "serde")]
 pub trait ExprRegistry: Sync + Send {
     fn get_function(&self, name: &str, payload: &[u8]) -> Option<Arc<dyn AnonymousColumnsUdf>>;
 }
 
+#[cfg(feature = "serde")]
 pub(super) static NAMED_SERDE_REGISTRY_EXPR: LazyLock<RwLock<Option<Arc<dyn ExprRegistry>>>> =
     LazyLock::new(Default::default);
 
+#[cfg(feature = "serde")]
 pub fn set_named_serde_registry(reg: Arc<dyn ExprRegistry>) {
     let mut lock = NAMED_SERDE_REGISTRY_EXPR.write().unwrap();
     *lock = Some(reg);
 }
+
+// Can be used to have named map functions and anonymous scans in plans, which can then be
+// serialized. Both the process that builds the plan and the process that executes it must have
+// implemented this registry and map the names to the proper functions and scans.
+pub trait PlanRegistry: Sync + Send {
+    fn get_map_function(&self, _name: &str, _payload: &[u8]) -> Option<Arc<dyn DataFrameUdf>> {
+        None
+    }
+
+    fn get_scan(&self, _name: &str, _payload: &[u8]) -> Option<Arc<dyn AnonymousScan>> {
+        None
+    }
+}
+
+static NAMED_SERDE_REGISTRY_PLAN: LazyLock<RwLock<Option<Arc<dyn PlanRegistry>>>> =
+    LazyLock::new(Default::default);
+
+pub fn set_named_plan_registry(reg: Arc<dyn PlanRegistry>) {
+    let mut lock = NAMED_SERDE_REGISTRY_PLAN.write().unwrap();
+    *lock = Some(reg);
+}
+
+fn named_plan_registry(kind: &str, name: &str) -> PolarsResult<Arc<dyn PlanRegistry>> {
+    match &*NAMED_SERDE_REGISTRY_PLAN.read().unwrap() {
+        Some(reg) => Ok(reg.clone()),
+        None => polars_bail!(
+            ComputeError: "cannot resolve named {kind} '{name}': named plan registry not set"
+        ),
+    }
+}
+
+pub(crate) fn get_named_map_function(
+    name: &str,
+    payload: &[u8],
+) -> PolarsResult<Arc<dyn DataFrameUdf>> {
+    match named_plan_registry("map function", name)?.get_map_function(name, payload) {
+        Some(function) => Ok(function),
+        None => polars_bail!(
+            ComputeError: "named map function '{name}' not found in named plan registry"
+        ),
+    }
+}
+
+pub(crate) fn get_named_scan(name: &str, payload: &[u8]) -> PolarsResult<Arc<dyn AnonymousScan>> {
+    match named_plan_registry("scan", name)?.get_scan(name, payload) {
+        Some(scan) => Ok(scan),
+        None => polars_bail!(ComputeError: "named scan '{name}' not found in named plan registry"),
+    }
+}
@@ -7,12 +7,17 @@ use polars_core::utils::get_numeric_upcast_supertype_lossless;
 use polars_io::cloud::CloudOptions;
 #[cfg(feature = "csv")]
 use polars_io::csv::read::CsvReadOptions;
+#[cfg(feature = "fwf")]
+use polars_io::fwf::FwfReadOptions;
 #[cfg(feature = "ipc")]
 use polars_io::ipc::IpcScanOptions;
+#[cfg(feature = "orc")]
+use polars_io::orc::{OrcMetadata, OrcScanOptions};
 #[cfg(feature = "parquet")]
 use polars_io::parquet::metadata::FileMetadataRef;
 #[cfg(feature = "parquet")]
 use polars_io::parquet::read::ParquetOptions;
+use polars_io::predicates::ScanMetrics;
 use polars_io::{HiveOptions, RowIndex};
 use polars_utils::slice_enum::Slice;
 #[cfg(feature = "serde")]
@@ -47,12 +52,18 @@ pub enum FileScanDsl {
     #[cfg(feature = "json")]
     NDJson { options: NDJsonReadOptions },
 
+    #[cfg(feature = "fwf")]
+    Fwf { options: FwfReadOptions },
+
     #[cfg(feature = "parquet")]
     Parquet { options: ParquetOptions },
 
     #[cfg(feature = "ipc")]
     Ipc { options: IpcScanOptions },
 
+    #[cfg(feature = "orc")]
+    Orc { options: OrcScanOptions },
+
     #[cfg(feature = "python")]
     PythonDataset {
         dataset_object: Arc<python_dataset::PythonDatasetProvider>,
@@ -64,6 +75,15 @@ pub enum FileScanDsl {
         function: Arc<dyn AnonymousScan>,
         file_info: FileInfo,
     },
+
+    /// An [`AnonymousScan`] that is looked up by name in the
+    /// [`PlanRegistry`](crate::dsl::named_serde::PlanRegistry) when the plan is converted to IR,
+    /// so that it can be serialized.
+    NamedAnonymous {
+        name: PlSmallStr,
+        payload: Vec<u8>,
+        skip_rows: Option<usize>,
+    },
 }
 
 #[derive(Clone, Debug, IntoStaticStr)]
@@ -77,6 +97,9 @@ pub enum FileScanIR {
     #[cfg(feature = "json")]
     NDJson { options: NDJsonReadOptions },
 
+    #[cfg(feature = "fwf")]
+    Fwf { options: FwfReadOptions },
+
     #[cfg(feature = "parquet")]
     Parquet {
         options: ParquetOptions,
@@ -91,6 +114,13 @@ pub enum FileScanIR {
         metadata: Option<Arc<arrow::io::ipc::read::FileMetadata>>,
     },
 
+    #[cfg(feature = "orc")]
+    Orc {
+        options: OrcScanOptions,
+        #[cfg_attr(any(feature = "serde", feature = "dsl-schema"), serde(skip))]
+        metadata: Option<Arc<OrcMetadata>>,
+    },
+
     #[cfg(feature = "python")]
     PythonDataset {
         dataset_object: Arc<python_dataset::PythonDatasetProvider>,
@@ -113,6 +143,8 @@ impl FileScanIR {
             Self::Ipc { .. } => ScanFlags::empty(),
             #[cfg(feature = "parquet")]
             Self::Parquet { .. } => ScanFlags::SPECIALIZED_PREDICATE_FILTER,
+            #[cfg(feature = "orc")]
+            Self::Orc { .. } => ScanFlags::SPECIALIZED_PREDICATE_FILTER,
             #[cfg(feature = "json")]
             Self::NDJson { .. } => ScanFlags::empty(),
             #[allow(unreachable_patterns)]
@@ -289,6 +321,9 @@ pub struct UnifiedScanArgs {
 
     pub deletion_files: Option<DeletionFilesList>,
     pub table_statistics: Option<TableStatistics>,
+    /// Filled in during execution when set, see `LazyFrame::explain_analyze`.
+    #[cfg_attr(any(feature = "serde", feature = "dsl-schema"), serde(skip))]
+    pub scan_metrics: Option<Arc<ScanMetrics>>,
 }
 
 impl Default for UnifiedScanArgs {
@@ -312,6 +347,7 @@ impl Default for UnifiedScanArgs {
             include_file_paths: None,
             deletion_files: None,
             table_statistics: None,
+            scan_metrics: None,
         }
     }
 }
@@ -353,6 +389,11 @@ mod _file_scan_eq_hash {
             options: &'a crate::prelude::NDJsonReadOptions,
         },
 
+        #[cfg(feature = "fwf")]
+        Fwf {
+            options: &'a polars_io::fwf::FwfReadOptions,
+        },
+
         #[cfg(feature = "parquet")]
         Parquet {
             options: &'a polars_io::prelude::ParquetOptions,
@@ -365,6 +406,12 @@ mod _file_scan_eq_hash {
             metadata: Option<usize>,
         },
 
+        #[cfg(feature = "orc")]
+        Orc {
+            options: &'a polars_io::orc::OrcScanOptions,
+            metadata: Option<usize>,
+        },
+
         #[cfg(feature = "python")]
         PythonDataset {
             dataset_object: usize,
@@ -390,6 +437,9 @@ mod _file_scan_eq_hash {
                 #[cfg(feature = "json")]
                 FileScanIR::NDJson { options } => FileScanEqHashWrap::NDJson { options },
 
+                #[cfg(feature = "fwf")]
+                FileScanIR::Fwf { options } => FileScanEqHashWrap::Fwf { options },
+
                 #[cfg(feature = "parquet")]
                 FileScanIR::Parquet { options, metadata } => FileScanEqHashWrap::Parquet {
                     options,
@@ -402,6 +452,12 @@ mod _file_scan_eq_hash {
                     metadata: metadata.as_ref().map(arc_as_ptr),
                 },
 
+                #[cfg(feature = "orc")]
+                FileScanIR::Orc { options, metadata } => FileScanEqHashWrap::Orc {
+                    options,
+                    metadata: metadata.as_ref().map(arc_as_ptr),
+                },
+
                 #[cfg(feature = "python")]
                 FileScanIR::PythonDataset {
                     dataset_object,
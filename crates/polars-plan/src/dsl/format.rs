@@ -178,6 +178,9 @@ impl fmt::Debug for Expr {
                 variant,
             } => match variant {
                 EvalVariant::List => write!(f, "{input:?}.list.eval({evaluation:?})"),
+                EvalVariant::Array { as_list } => {
+                    write!(f, "{input:?}.arr.eval({evaluation:?}, as_list={as_list})")
+                },
                 EvalVariant::Cumulative { min_samples } => write!(
                     f,
                     "{input:?}.Cumulative_eval({evaluation:?}, min_samples={min_samples}"
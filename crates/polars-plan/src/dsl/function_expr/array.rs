@@ -1,6 +1,12 @@
 use std::fmt;
 
 use polars_core::prelude::SortOptions;
+#[cfg(feature = "diff")]
+use polars_core::series::ops::NullBehavior;
+#[cfg(feature = "list_sets")]
+use polars_ops::chunked_array::list::SetOperation;
+#[cfg(feature = "array_linalg")]
+use polars_utils::total_ord::TotalOrdWrap;
 
 use super::FunctionExpr;
 
@@ -25,6 +31,7 @@ pub enum ArrayFunction {
     #[cfg(feature = "array_any_all")]
     All,
     Sort(SortOptions),
+    IsSorted(SortOptions),
     Reverse,
     ArgMin,
     ArgMax,
@@ -36,6 +43,34 @@ pub enum ArrayFunction {
     },
     #[cfg(feature = "array_count")]
     CountMatches,
+    #[cfg(feature = "array_linalg")]
+    Dot,
+    #[cfg(feature = "array_linalg")]
+    CosineSimilarity,
+    #[cfg(feature = "array_linalg")]
+    L2Distance,
+    #[cfg(feature = "array_linalg")]
+    Norm(TotalOrdWrap<f64>),
+    #[cfg(feature = "array_linalg")]
+    Normalize(TotalOrdWrap<f64>),
+    #[cfg(feature = "list_gather")]
+    Gather(bool),
+    #[cfg(feature = "diff")]
+    Diff {
+        n: i64,
+        null_behavior: NullBehavior,
+    },
+    #[cfg(feature = "list_sample")]
+    Sample {
+        is_fraction: bool,
+        with_replacement: bool,
+        shuffle: bool,
+        seed: Option<u64>,
+    },
+    #[cfg(feature = "list_sets")]
+    SetOperation(SetOperation),
+    #[cfg(feature = "list_sets")]
+    ContainsAny,
     Shift,
     Explode {
         skip_empty: bool,
@@ -67,6 +102,7 @@ impl fmt::Display for ArrayFunction {
             #[cfg(feature = "array_any_all")]
             All => "all",
             Sort(_) => "sort",
+            IsSorted(_) => "is_sorted",
             Reverse => "reverse",
             ArgMin => "arg_min",
             ArgMax => "arg_max",
@@ -76,6 +112,32 @@ impl fmt::Display for ArrayFunction {
             Contains { nulls_equal: _ } => "contains",
             #[cfg(feature = "array_count")]
             CountMatches => "count_matches",
+            #[cfg(feature = "array_linalg")]
+            Dot => "dot",
+            #[cfg(feature = "array_linalg")]
+            CosineSimilarity => "cosine_similarity",
+            #[cfg(feature = "array_linalg")]
+            L2Distance => "l2_distance",
+            #[cfg(feature = "array_linalg")]
+            Norm(_) => "norm",
+            #[cfg(feature = "array_linalg")]
+            Normalize(_) => "normalize",
+            #[cfg(feature = "list_gather")]
+            Gather(_) => "gather",
+            #[cfg(feature = "diff")]
+            Diff { .. } => "diff",
+            #[cfg(feature = "list_sample")]
+            Sample { is_fraction, .. } => {
+                if *is_fraction {
+                    "sample_fraction"
+                } else {
+                    "sample_n"
+                }
+            },
+            #[cfg(feature = "list_sets")]
+            SetOperation(s) => return write!(f, "arr.{s}"),
+            #[cfg(feature = "list_sets")]
+            ContainsAny => "contains_any",
             Shift => "shift",
             Explode { .. } => "explode",
             #[cfg(feature = "array_to_struct")]
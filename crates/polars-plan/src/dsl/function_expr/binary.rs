@@ -23,6 +23,16 @@ pub enum BinaryFunction {
     /// The parameters are destination type, and whether to use little endian
     /// encoding.
     Reinterpret(DataTypeExpr, bool),
+    #[cfg(feature = "binary_compression")]
+    Compress {
+        codec: BinaryCodec,
+        level: Option<i32>,
+    },
+    #[cfg(feature = "binary_compression")]
+    Decompress {
+        codec: BinaryCodec,
+        strict: bool,
+    },
 }
 
 impl Display for BinaryFunction {
@@ -43,6 +53,10 @@ impl Display for BinaryFunction {
             Size => "size_bytes",
             #[cfg(feature = "binary_encoding")]
             Reinterpret(_, _) => "reinterpret",
+            #[cfg(feature = "binary_compression")]
+            Compress { .. } => "compress",
+            #[cfg(feature = "binary_compression")]
+            Decompress { .. } => "decompress",
         };
         write!(f, "bin.{s}")
     }
@@ -106,6 +106,8 @@ pub enum FunctionExpr {
     Pow(PowFunction),
     #[cfg(feature = "row_hash")]
     Hash(u64, u64, u64, u64),
+    #[cfg(feature = "stable_hash")]
+    StableHash(HashAlgorithm),
     #[cfg(feature = "arg_where")]
     ArgWhere,
     #[cfg(feature = "index_of")]
@@ -220,9 +222,9 @@ pub enum FunctionExpr {
     #[cfg(feature = "pct_change")]
     PctChange,
     #[cfg(feature = "interpolate")]
-    Interpolate(InterpolationMethod),
+    Interpolate(InterpolationOptions),
     #[cfg(feature = "interpolate_by")]
-    InterpolateBy,
+    InterpolateBy(InterpolationOptions),
     #[cfg(feature = "log")]
     Entropy {
         base: f64,
@@ -411,7 +413,7 @@ impl Hash for FunctionExpr {
             #[cfg(feature = "interpolate")]
             Interpolate(f) => f.hash(state),
             #[cfg(feature = "interpolate_by")]
-            InterpolateBy => {},
+            InterpolateBy(f) => f.hash(state),
             #[cfg(feature = "ffi_plugin")]
             FfiPlugin {
                 flags: _,
@@ -489,6 +491,8 @@ impl Hash for FunctionExpr {
             Sign => {},
             #[cfg(feature = "row_hash")]
             Hash(a, b, c, d) => (a, b, c, d).hash(state),
+            #[cfg(feature = "stable_hash")]
+            StableHash(algorithm) => algorithm.hash(state),
             FillNull => {},
             #[cfg(feature = "rolling_window")]
             RollingExpr { function, options } => {
@@ -691,6 +695,8 @@ impl Display for FunctionExpr {
             Pow(func) => return write!(f, "{func}"),
             #[cfg(feature = "row_hash")]
             Hash(_, _, _, _) => "hash",
+            #[cfg(feature = "stable_hash")]
+            StableHash(_) => "stable_hash",
             #[cfg(feature = "arg_where")]
             ArgWhere => "arg_where",
             #[cfg(feature = "index_of")]
@@ -774,7 +780,7 @@ impl Display for FunctionExpr {
             #[cfg(feature = "interpolate")]
             Interpolate(_) => "interpolate",
             #[cfg(feature = "interpolate_by")]
-            InterpolateBy => "interpolate_by",
+            InterpolateBy(_) => "interpolate_by",
             #[cfg(feature = "log")]
             Entropy { .. } => "entropy",
             #[cfg(feature = "log")]
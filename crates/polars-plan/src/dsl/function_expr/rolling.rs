@@ -11,6 +11,12 @@ pub enum RollingFunction {
     Quantile,
     Var,
     Std,
+    Rank,
+    NUnique,
+    ArgMin,
+    ArgMax,
+    Mode,
+    Product,
     #[cfg(feature = "moment")]
     Skew,
     #[cfg(feature = "moment")]
@@ -36,6 +42,12 @@ impl Display for RollingFunction {
             Quantile => "quantile",
             Var => "var",
             Std => "std",
+            Rank => "rank",
+            NUnique => "n_unique",
+            ArgMin => "arg_min",
+            ArgMax => "arg_max",
+            Mode => "mode",
+            Product => "product",
             #[cfg(feature = "moment")]
             Skew => "skew",
             #[cfg(feature = "moment")]
@@ -11,6 +11,12 @@ pub enum RollingFunctionBy {
     QuantileBy,
     VarBy,
     StdBy,
+    RankBy,
+    NUniqueBy,
+    ArgMinBy,
+    ArgMaxBy,
+    ModeBy,
+    ProductBy,
 }
 
 impl Display for RollingFunctionBy {
@@ -25,6 +31,12 @@ impl Display for RollingFunctionBy {
             QuantileBy => "rolling_quantile_by",
             VarBy => "rolling_var_by",
             StdBy => "rolling_std_by",
+            RankBy => "rolling_rank_by",
+            NUniqueBy => "rolling_n_unique_by",
+            ArgMinBy => "rolling_arg_min_by",
+            ArgMaxBy => "rolling_arg_max_by",
+            ModeBy => "rolling_mode_by",
+            ProductBy => "rolling_product_by",
         };
 
         write!(f, "{name}")
@@ -61,6 +61,31 @@ pub enum StringFunction {
     },
     #[cfg(feature = "string_reverse")]
     Reverse,
+    #[cfg(feature = "string_similarity")]
+    Levenshtein,
+    #[cfg(feature = "string_similarity")]
+    DamerauLevenshtein,
+    #[cfg(feature = "string_similarity")]
+    Hamming,
+    #[cfg(feature = "string_similarity")]
+    JaroWinkler,
+    #[cfg(feature = "string_similarity")]
+    NgramJaccard(usize),
+    #[cfg(feature = "string_url")]
+    UrlParse {
+        strict: bool,
+    },
+    #[cfg(feature = "string_url")]
+    UrlQueryParam {
+        name: PlSmallStr,
+        strict: bool,
+    },
+    #[cfg(feature = "string_url")]
+    PercentDecode {
+        strict: bool,
+    },
+    #[cfg(feature = "string_url")]
+    PercentEncode,
     #[cfg(feature = "string_pad")]
     PadStart {
         fill_char: char,
@@ -96,6 +121,11 @@ pub enum StringFunction {
     #[cfg(feature = "temporal")]
     Strptime(DataTypeExpr, StrptimeOptions),
     Split(bool),
+    #[cfg(feature = "regex")]
+    SplitRegex {
+        inclusive: bool,
+        limit: Option<usize>,
+    },
     #[cfg(feature = "dtype-decimal")]
     ToDecimal {
         scale: usize,
@@ -166,6 +196,24 @@ impl Display for StringFunction {
             Normalize { .. } => "normalize",
             #[cfg(feature = "string_reverse")]
             Reverse => "reverse",
+            #[cfg(feature = "string_similarity")]
+            Levenshtein => "levenshtein",
+            #[cfg(feature = "string_similarity")]
+            DamerauLevenshtein => "damerau_levenshtein",
+            #[cfg(feature = "string_similarity")]
+            Hamming => "hamming",
+            #[cfg(feature = "string_similarity")]
+            JaroWinkler => "jaro_winkler",
+            #[cfg(feature = "string_similarity")]
+            NgramJaccard(_) => "ngram_jaccard",
+            #[cfg(feature = "string_url")]
+            UrlParse { .. } => "url.parse",
+            #[cfg(feature = "string_url")]
+            UrlQueryParam { .. } => "url.query_param",
+            #[cfg(feature = "string_url")]
+            PercentDecode { .. } => "url.percent_decode",
+            #[cfg(feature = "string_url")]
+            PercentEncode => "url.percent_encode",
             #[cfg(feature = "string_encoding")]
             HexEncode => "hex_encode",
             #[cfg(feature = "binary_encoding")]
@@ -200,6 +248,14 @@ impl Display for StringFunction {
                     "split"
                 }
             },
+            #[cfg(feature = "regex")]
+            SplitRegex { inclusive, .. } => {
+                if *inclusive {
+                    "split_regex_inclusive"
+                } else {
+                    "split_regex"
+                }
+            },
             #[cfg(feature = "nightly")]
             Titlecase => "titlecase",
             #[cfg(feature = "dtype-decimal")]
@@ -1064,9 +1064,17 @@ impl Expr {
 
     #[cfg(feature = "interpolate")]
     /// Interpolate intermediate values.
-    /// Nulls at the beginning and end of the series remain null.
+    /// Nulls at the beginning and end of the series remain null, except that `Previous` and `Next`
+    /// carry the last and first valid value outward.
     pub fn interpolate(self, method: InterpolationMethod) -> Expr {
-        self.map_unary(FunctionExpr::Interpolate(method))
+        self.interpolate_with_options(method.into())
+    }
+
+    #[cfg(feature = "interpolate")]
+    /// Interpolate intermediate values, filling at most `limit` consecutive nulls in the
+    /// `limit_area` of `options`.
+    pub fn interpolate_with_options(self, options: InterpolationOptions) -> Expr {
+        self.map_unary(FunctionExpr::Interpolate(options))
     }
 
     #[cfg(feature = "rolling_window_by")]
@@ -1091,7 +1099,14 @@ impl Expr {
     /// Nulls at the beginning and end of the series remain null.
     /// The `by` column provides the x-coordinates for interpolation and must not contain nulls.
     pub fn interpolate_by(self, by: Expr) -> Expr {
-        self.map_binary(FunctionExpr::InterpolateBy, by)
+        self.interpolate_by_with_options(by, Default::default())
+    }
+
+    #[cfg(feature = "interpolate_by")]
+    /// Interpolate intermediate values against the x-coordinates in `by`, filling at most `limit`
+    /// consecutive nulls in the `limit_area` of `options`.
+    pub fn interpolate_by_with_options(self, by: Expr, options: InterpolationOptions) -> Expr {
+        self.map_binary(FunctionExpr::InterpolateBy(options), by)
     }
 
     #[cfg(feature = "rolling_window")]
@@ -1167,6 +1182,52 @@ impl Expr {
         self.rolling_quantile_by(by, QuantileMethod::Linear, 0.5, options)
     }
 
+    /// Apply a rolling rank based on another column.
+    ///
+    /// This is the rank of every value among the values in its window, divided by the number of
+    /// values in the window. If the window doesn't contain the value's own row, the value is
+    /// ranked as if it did.
+    #[cfg(feature = "rolling_window_by")]
+    pub fn rolling_rank_by(
+        self,
+        by: Expr,
+        method: RollingRankMethod,
+        mut options: RollingOptionsDynamicWindow,
+    ) -> Expr {
+        options.fn_params = Some(RollingFnParams::Rank { method });
+        self.finish_rolling_by(by, options, RollingFunctionBy::RankBy)
+    }
+
+    /// Apply a rolling count of distinct values based on another column.
+    #[cfg(feature = "rolling_window_by")]
+    pub fn rolling_n_unique_by(self, by: Expr, options: RollingOptionsDynamicWindow) -> Expr {
+        self.finish_rolling_by(by, options, RollingFunctionBy::NUniqueBy)
+    }
+
+    /// Apply a rolling arg_min based on another column.
+    #[cfg(feature = "rolling_window_by")]
+    pub fn rolling_arg_min_by(self, by: Expr, options: RollingOptionsDynamicWindow) -> Expr {
+        self.finish_rolling_by(by, options, RollingFunctionBy::ArgMinBy)
+    }
+
+    /// Apply a rolling arg_max based on another column.
+    #[cfg(feature = "rolling_window_by")]
+    pub fn rolling_arg_max_by(self, by: Expr, options: RollingOptionsDynamicWindow) -> Expr {
+        self.finish_rolling_by(by, options, RollingFunctionBy::ArgMaxBy)
+    }
+
+    /// Apply a rolling mode based on another column.
+    #[cfg(feature = "rolling_window_by")]
+    pub fn rolling_mode_by(self, by: Expr, options: RollingOptionsDynamicWindow) -> Expr {
+        self.finish_rolling_by(by, options, RollingFunctionBy::ModeBy)
+    }
+
+    /// Apply a rolling product based on another column.
+    #[cfg(feature = "rolling_window_by")]
+    pub fn rolling_product_by(self, by: Expr, options: RollingOptionsDynamicWindow) -> Expr {
+        self.finish_rolling_by(by, options, RollingFunctionBy::ProductBy)
+    }
+
     /// Apply a rolling minimum.
     ///
     /// See: [`RollingAgg::rolling_min`]
@@ -1239,6 +1300,50 @@ impl Expr {
         self.finish_rolling(options, RollingFunction::Std)
     }
 
+    /// Apply a rolling rank.
+    ///
+    /// This is the rank of the last value of every window among the values in that window,
+    /// divided by the number of values in the window.
+    #[cfg(feature = "rolling_window")]
+    pub fn rolling_rank(
+        self,
+        method: RollingRankMethod,
+        mut options: RollingOptionsFixedWindow,
+    ) -> Expr {
+        options.fn_params = Some(RollingFnParams::Rank { method });
+        self.finish_rolling(options, RollingFunction::Rank)
+    }
+
+    /// Apply a rolling count of distinct values.
+    #[cfg(feature = "rolling_window")]
+    pub fn rolling_n_unique(self, options: RollingOptionsFixedWindow) -> Expr {
+        self.finish_rolling(options, RollingFunction::NUnique)
+    }
+
+    /// Apply a rolling arg_min.
+    #[cfg(feature = "rolling_window")]
+    pub fn rolling_arg_min(self, options: RollingOptionsFixedWindow) -> Expr {
+        self.finish_rolling(options, RollingFunction::ArgMin)
+    }
+
+    /// Apply a rolling arg_max.
+    #[cfg(feature = "rolling_window")]
+    pub fn rolling_arg_max(self, options: RollingOptionsFixedWindow) -> Expr {
+        self.finish_rolling(options, RollingFunction::ArgMax)
+    }
+
+    /// Apply a rolling mode.
+    #[cfg(feature = "rolling_window")]
+    pub fn rolling_mode(self, options: RollingOptionsFixedWindow) -> Expr {
+        self.finish_rolling(options, RollingFunction::Mode)
+    }
+
+    /// Apply a rolling product.
+    #[cfg(feature = "rolling_window")]
+    pub fn rolling_product(self, options: RollingOptionsFixedWindow) -> Expr {
+        self.finish_rolling(options, RollingFunction::Product)
+    }
+
     /// Apply a rolling skew.
     #[cfg(feature = "rolling_window")]
     #[cfg(feature = "moment")]
@@ -1546,6 +1651,16 @@ impl Expr {
         self.map_unary(FunctionExpr::Hash(k0, k1, k2, k3))
     }
 
+    #[cfg(feature = "stable_hash")]
+    /// Compute the hash of every element with a named algorithm.
+    ///
+    /// Unlike [`Expr::hash`], the values are stable across versions and platforms. See
+    /// [`HashAlgorithm`] for the supported algorithms and the output types, and
+    /// [`polars_ops::series::stable_hash`] for how values and nulls are hashed.
+    pub fn stable_hash(self, algorithm: HashAlgorithm) -> Expr {
+        self.map_unary(FunctionExpr::StableHash(algorithm))
+    }
+
     pub fn to_physical(self) -> Expr {
         self.map_unary(FunctionExpr::ToPhysical)
     }
@@ -8,12 +8,16 @@ mod sink;
 
 use polars_core::error::PolarsResult;
 use polars_core::prelude::*;
+#[cfg(feature = "json")]
+use polars_io::bad_records::BadRecords;
 #[cfg(feature = "csv")]
 use polars_io::csv::write::CsvWriterOptions;
 #[cfg(feature = "ipc")]
 use polars_io::ipc::IpcWriterOptions;
 #[cfg(feature = "json")]
 use polars_io::json::JsonWriterOptions;
+#[cfg(feature = "orc")]
+use polars_io::orc::OrcWriterOptions;
 #[cfg(feature = "parquet")]
 use polars_io::parquet::write::ParquetWriteOptions;
 #[cfg(feature = "iejoin")]
@@ -24,6 +28,8 @@ use polars_ops::prelude::{JoinArgs, JoinType};
 use polars_time::DynamicGroupOptions;
 #[cfg(feature = "dynamic_group_by")]
 use polars_time::RollingGroupOptions;
+#[cfg(feature = "dynamic_group_by")]
+use polars_time::SessionGroupOptions;
 use polars_utils::IdxSize;
 use polars_utils::pl_str::PlSmallStr;
 #[cfg(feature = "serde")]
@@ -303,6 +309,8 @@ pub struct GroupbyOptions {
     pub dynamic: Option<DynamicGroupOptions>,
     #[cfg(feature = "dynamic_group_by")]
     pub rolling: Option<RollingGroupOptions>,
+    #[cfg(feature = "dynamic_group_by")]
+    pub session: Option<SessionGroupOptions>,
     /// Take only a slice of the result
     pub slice: Option<(i64, usize)>,
 }
@@ -322,7 +330,7 @@ impl GroupbyOptions {
     pub(crate) fn is_dynamic(&self) -> bool {
         #[cfg(feature = "dynamic_group_by")]
         {
-            self.dynamic.is_some()
+            self.dynamic.is_some() || self.session.is_some()
         }
         #[cfg(not(feature = "dynamic_group_by"))]
         {
@@ -375,6 +383,8 @@ pub enum FileType {
     Csv(CsvWriterOptions),
     #[cfg(feature = "json")]
     Json(JsonWriterOptions),
+    #[cfg(feature = "orc")]
+    Orc(OrcWriterOptions),
 }
 
 impl FileType {
@@ -388,6 +398,8 @@ impl FileType {
             Self::Csv(_) => "csv",
             #[cfg(feature = "json")]
             Self::Json(_) => "jsonl",
+            #[cfg(feature = "orc")]
+            Self::Orc(_) => "orc",
 
             #[allow(unreachable_patterns)]
             _ => unreachable!("enable file type features"),
@@ -449,4 +461,7 @@ pub struct NDJsonReadOptions {
     pub ignore_errors: bool,
     pub schema: Option<SchemaRef>,
     pub schema_overwrite: Option<SchemaRef>,
+    /// Collector for lines that could not be parsed.
+    #[cfg_attr(any(feature = "serde", feature = "dsl-schema"), serde(skip))]
+    pub bad_records: Option<BadRecords>,
 }
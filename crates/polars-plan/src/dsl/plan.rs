@@ -263,6 +263,8 @@ impl DslPlan {
         // serialization so there is no proper way to do this except replace serde.
         polars_utils::pl_serialize::USE_CLOUDPICKLE.set(ctx.use_cloudpickle);
 
+        self.check_serializable()?;
+
         writer.write_all(DSL_MAGIC_BYTES)?;
         writer.write_all(&le_major)?;
         writer.write_all(&le_minor)?;
@@ -270,8 +272,83 @@ impl DslPlan {
         pl_serialize::serialize_dsl(writer, self)
     }
 
+    /// Checks that every node of the plan can be serialized, with an error that names the first
+    /// node that can't.
+    #[cfg(feature = "serde")]
+    fn check_serializable(&self) -> PolarsResult<()> {
+        let not_serializable = |msg: &str| -> PolarsResult<()> {
+            Err(polars_err!(InvalidOperation: "serialization of the plan failed: {msg}"))
+        };
+
+        for node in self {
+            match node {
+                DslPlan::Scan {
+                    sources, scan_type, ..
+                } => {
+                    match sources {
+                        ScanSources::Paths(_) => {},
+                        ScanSources::Files(_) => {
+                            return not_serializable("it contains a scan of opened files");
+                        },
+                        ScanSources::Buffers(_) => {
+                            return not_serializable("it contains a scan of in-memory buffers");
+                        },
+                    }
+                    if matches!(&**scan_type, FileScanDsl::Anonymous { .. }) {
+                        return not_serializable(
+                            "it contains an anonymous scan, use a named scan registered in the named plan registry instead",
+                        );
+                    }
+                },
+                DslPlan::MapFunction {
+                    function: DslFunction::FunctionIR(function),
+                    ..
+                } => {
+                    return not_serializable(&format!(
+                        "it contains the opaque map function '{function}', use a named map function registered in the named plan registry instead"
+                    ));
+                },
+                _ => {},
+            }
+
+            for expr in node.exprs().into_iter().flat_map(|e| e.into_iter()) {
+                match expr {
+                    Expr::AnonymousFunction {
+                        function: LazySerde::Deserialized(function),
+                        fmt_str,
+                        ..
+                    } => {
+                        if function.try_serialize(&mut vec![]).is_err() {
+                            return not_serializable(&format!(
+                                "it contains the opaque function '{fmt_str}' in an expression, use a named function registered in the expression registry instead"
+                            ));
+                        }
+                    },
+                    Expr::SubPlan(plan, _) => plan.check_serializable()?,
+                    _ => {},
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Checks that a plan serialized with [`DslPlan::serialize_versioned`] is compatible with
+    /// this version of Polars, by reading only its version header.
+    #[cfg(feature = "serde")]
+    pub fn check_versioned_compatibility<R: Read>(mut reader: R) -> PolarsResult<()> {
+        Self::read_version_header(&mut reader)
+    }
+
     #[cfg(feature = "serde")]
     pub fn deserialize_versioned<R: Read>(mut reader: R) -> PolarsResult<Self> {
+        Self::read_version_header(&mut reader)?;
+
+        pl_serialize::deserialize_dsl(reader)
+            .map_err(|e| polars_err!(ComputeError: "deserialization failed\n\nerror: {e}"))
+    }
+
+    #[cfg(feature = "serde")]
+    fn read_version_header<R: Read>(reader: &mut R) -> PolarsResult<()> {
         const MAGIC_LEN: usize = DSL_MAGIC_BYTES.len();
         let mut version_magic = [0u8; MAGIC_LEN + 4];
         reader
@@ -333,8 +410,7 @@ impl DslPlan {
             );
         }
 
-        pl_serialize::deserialize_dsl(reader)
-            .map_err(|e| polars_err!(ComputeError: "deserialization failed\n\nerror: {e}"))
+        Ok(())
     }
 
     #[cfg(feature = "dsl-schema")]
@@ -415,3 +491,114 @@ impl std::fmt::Display for SchemaHash<'_> {
         write!(f, "{}", self.0)
     }
 }
+
+impl DslPlan {
+    /// The expressions of this node, not of its inputs.
+    #[cfg(feature = "serde")]
+    fn exprs(&self) -> Vec<&Expr> {
+        use DslPlan::*;
+        match self {
+            Select { expr, .. } => expr.iter().collect(),
+            GroupBy { keys, aggs, .. } => keys.iter().chain(aggs).collect(),
+            Filter { predicate, .. } => vec![predicate],
+            HStack { exprs, .. } => exprs.iter().collect(),
+            Sort { by_column, .. } => by_column.iter().collect(),
+            Join {
+                left_on,
+                right_on,
+                predicates,
+                ..
+            } => left_on.iter().chain(right_on).chain(predicates).collect(),
+            MapFunction {
+                function:
+                    DslFunction::FillNan(expr)
+                    | DslFunction::Stats(StatsFunction::Quantile { quantile: expr, .. }),
+                ..
+            } => vec![expr],
+            Sink {
+                payload: SinkType::Partition(partition),
+                ..
+            } => {
+                let keys = match &partition.variant {
+                    PartitionVariant::MaxSize(_) => &[][..],
+                    PartitionVariant::Parted { key_exprs, .. }
+                    | PartitionVariant::ByKey { key_exprs, .. } => key_exprs,
+                };
+                let sort_by = partition.per_partition_sort_by.iter().flatten();
+                keys.iter().chain(sort_by.map(|c| &c.expr)).collect()
+            },
+            // The subset of `Distinct` is a selector of column names and the contexts of
+            // `ExtContext` are walked as inputs, so neither holds expressions.
+            _ => vec![],
+        }
+    }
+
+    fn inputs<'a>(&'a self, scratch: &mut Vec<&'a DslPlan>) {
+        use DslPlan::*;
+        match self {
+            Select { input, .. }
+            | GroupBy { input, .. }
+            | Filter { input, .. }
+            | Distinct { input, .. }
+            | Sort { input, .. }
+            | Slice { input, .. }
+            | HStack { input, .. }
+            | MatchToSchema { input, .. }
+            | PipeWithSchema { input, .. }
+            | MapFunction { input, .. }
+            | Sink { input, .. }
+            | Cache { input, .. } => scratch.push(input),
+            Union { inputs, .. } | HConcat { inputs, .. } | SinkMultiple { inputs } => {
+                scratch.extend(inputs)
+            },
+            Join {
+                input_left,
+                input_right,
+                ..
+            } => {
+                scratch.push(input_left);
+                scratch.push(input_right);
+            },
+            ExtContext { input, contexts } => {
+                scratch.push(input);
+                scratch.extend(contexts);
+            },
+            IR { dsl, .. } => scratch.push(dsl),
+            Scan { .. } | DataFrameScan { .. } => (),
+            #[cfg(feature = "python")]
+            PythonScan { .. } => (),
+            #[cfg(feature = "merge_sorted")]
+            MergeSorted {
+                input_left,
+                input_right,
+                ..
+            } => {
+                scratch.push(input_left);
+                scratch.push(input_right);
+            },
+        }
+    }
+}
+
+pub struct DslPlanIter<'a> {
+    stack: Vec<&'a DslPlan>,
+}
+
+impl<'a> Iterator for DslPlanIter<'a> {
+    type Item = &'a DslPlan;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.stack
+            .pop()
+            .inspect(|next| next.inputs(&mut self.stack))
+    }
+}
+
+impl<'a> IntoIterator for &'a DslPlan {
+    type Item = &'a DslPlan;
+    type IntoIter = DslPlanIter<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        DslPlanIter { stack: vec![self] }
+    }
+}
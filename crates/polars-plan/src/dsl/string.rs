@@ -329,6 +329,34 @@ impl StringNameSpace {
         self.0.map_binary(StringFunction::Split(true), by)
     }
 
+    #[cfg(feature = "regex")]
+    /// Split the string on every match of the regex `pat`. The resulting dtype is `List<String>`.
+    ///
+    /// With a `limit`, at most `limit` items are returned and the last item holds the remainder of
+    /// the string.
+    pub fn split_regex(self, pat: Expr, limit: Option<usize>) -> Expr {
+        self.0.map_binary(
+            StringFunction::SplitRegex {
+                inclusive: false,
+                limit,
+            },
+            pat,
+        )
+    }
+
+    #[cfg(feature = "regex")]
+    /// Split the string on every match of the regex `pat` and keep the match at the end of the
+    /// preceding item. The resulting dtype is `List<String>`.
+    pub fn split_regex_inclusive(self, pat: Expr, limit: Option<usize>) -> Expr {
+        self.0.map_binary(
+            StringFunction::SplitRegex {
+                inclusive: true,
+                limit,
+            },
+            pat,
+        )
+    }
+
     #[cfg(feature = "dtype-struct")]
     /// Split exactly `n` times by a given substring. The resulting dtype is [`DataType::Struct`].
     pub fn split_exact(self, by: Expr, n: usize) -> Expr {
@@ -383,6 +411,48 @@ impl StringNameSpace {
         self.0.map_unary(StringFunction::Normalize { form })
     }
 
+    /// Compute the Levenshtein distance to the strings of `other`.
+    #[cfg(feature = "string_similarity")]
+    pub fn levenshtein(self, other: Expr) -> Expr {
+        self.0.map_binary(StringFunction::Levenshtein, other)
+    }
+
+    /// Compute the Damerau-Levenshtein distance to the strings of `other`.
+    ///
+    /// Like the Levenshtein distance, but a transposition of two adjacent characters counts as a
+    /// single edit.
+    #[cfg(feature = "string_similarity")]
+    pub fn damerau_levenshtein(self, other: Expr) -> Expr {
+        self.0.map_binary(StringFunction::DamerauLevenshtein, other)
+    }
+
+    /// Compute the Hamming distance to the strings of `other`.
+    ///
+    /// Null if the strings have a different number of characters.
+    #[cfg(feature = "string_similarity")]
+    pub fn hamming(self, other: Expr) -> Expr {
+        self.0.map_binary(StringFunction::Hamming, other)
+    }
+
+    /// Compute the Jaro-Winkler similarity to the strings of `other`, in `[0, 1]`.
+    #[cfg(feature = "string_similarity")]
+    pub fn jaro_winkler(self, other: Expr) -> Expr {
+        self.0.map_binary(StringFunction::JaroWinkler, other)
+    }
+
+    /// Compute the Jaccard similarity of the sets of character `n`-grams with the strings of
+    /// `other`, in `[0, 1]`.
+    #[cfg(feature = "string_similarity")]
+    pub fn ngram_jaccard(self, other: Expr, n: usize) -> Expr {
+        self.0.map_binary(StringFunction::NgramJaccard(n), other)
+    }
+
+    /// Get the [`UrlNameSpace`] to parse URLs and percent-encode strings.
+    #[cfg(feature = "string_url")]
+    pub fn url(self) -> UrlNameSpace {
+        UrlNameSpace(self.0)
+    }
+
     #[cfg(feature = "string_reverse")]
     /// Reverse each string
     pub fn reverse(self) -> Expr {
@@ -491,3 +561,43 @@ impl StringNameSpace {
         self.0.map_unary(StringFunction::EscapeRegex)
     }
 }
+
+/// Specialized expressions for [`Series`] of [`DataType::String`] holding URLs.
+#[cfg(feature = "string_url")]
+pub struct UrlNameSpace(pub(crate) Expr);
+
+#[cfg(feature = "string_url")]
+impl UrlNameSpace {
+    /// Split URLs into a [`DataType::Struct`] with the fields `scheme`, `user`, `host`, `port`,
+    /// `path`, `query` and `fragment`. Missing components are null, the port falls back to the
+    /// default port of the scheme.
+    ///
+    /// Invalid URLs raise an error if `strict`, otherwise they are null.
+    pub fn parse(self, strict: bool) -> Expr {
+        self.0.map_unary(StringFunction::UrlParse { strict })
+    }
+
+    /// Get the decoded value of the first query parameter called `name`, or null if the URL
+    /// has no such parameter.
+    ///
+    /// Invalid URLs raise an error if `strict`, otherwise they are null.
+    pub fn query_param(self, name: &str, strict: bool) -> Expr {
+        self.0.map_unary(StringFunction::UrlQueryParam {
+            name: name.into(),
+            strict,
+        })
+    }
+
+    /// Decode `%XX` escapes.
+    ///
+    /// Values that don't decode to valid UTF-8 raise an error if `strict`, otherwise they are
+    /// null.
+    pub fn percent_decode(self, strict: bool) -> Expr {
+        self.0.map_unary(StringFunction::PercentDecode { strict })
+    }
+
+    /// Percent-encode all characters except the unreserved characters `A-Z a-z 0-9 - . _ ~`.
+    pub fn percent_encode(self) -> Expr {
+        self.0.map_unary(StringFunction::PercentEncode)
+    }
+}
@@ -37,6 +37,12 @@ bitflags! {
         /// Check if operations are order dependent and unset maintaining_order if
         /// the order would not be observed.
         const CHECK_ORDER_OBSERVE = 1 << 15;
+        /// Reuse the results of cached subplans across queries from the session-level result
+        /// cache.
+        const RESULT_CACHE = 1 << 16;
+        /// Execute joins and group-bys on hive-partitioned scans partition-by-partition if their
+        /// keys include the partition columns. Off by default.
+        const PARTITION_WISE = 1 << 17;
     }
 }
 
@@ -76,7 +82,11 @@ impl OptFlags {
 
 impl Default for OptFlags {
     fn default() -> Self {
-        Self::from_bits_truncate(u32::MAX) & !Self::NEW_STREAMING & !Self::EAGER
+        Self::from_bits_truncate(u32::MAX)
+            & !Self::NEW_STREAMING
+            & !Self::EAGER
+            & !Self::RESULT_CACHE
+            & !Self::PARTITION_WISE
     }
 }
 
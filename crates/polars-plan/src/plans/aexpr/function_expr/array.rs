@@ -1,6 +1,16 @@
+#[cfg(feature = "diff")]
+use polars_core::series::ops::NullBehavior;
 use polars_core::utils::slice_offsets;
+#[cfg(feature = "list_sets")]
+use polars_core::utils::try_get_supertype;
 use polars_ops::chunked_array::array::*;
+#[cfg(feature = "list_sets")]
+use polars_ops::chunked_array::list::SetOperation;
+#[cfg(feature = "array_linalg")]
+use polars_utils::total_ord::TotalOrdWrap;
 
+#[cfg(feature = "diff")]
+use super::list::diff_inner_dtype;
 use super::*;
 use crate::{map, map_as_slice};
 
@@ -23,6 +33,7 @@ pub enum IRArrayFunction {
     #[cfg(feature = "array_any_all")]
     All,
     Sort(SortOptions),
+    IsSorted(SortOptions),
     Reverse,
     ArgMin,
     ArgMax,
@@ -34,6 +45,37 @@ pub enum IRArrayFunction {
     },
     #[cfg(feature = "array_count")]
     CountMatches,
+    #[cfg(feature = "array_linalg")]
+    Dot,
+    #[cfg(feature = "array_linalg")]
+    CosineSimilarity,
+    #[cfg(feature = "array_linalg")]
+    L2Distance,
+    #[cfg(feature = "array_linalg")]
+    Norm(TotalOrdWrap<f64>),
+    #[cfg(feature = "array_linalg")]
+    Normalize(TotalOrdWrap<f64>),
+    #[cfg(feature = "list_gather")]
+    Gather(bool),
+    #[cfg(feature = "diff")]
+    Diff {
+        n: i64,
+        null_behavior: NullBehavior,
+    },
+    #[cfg(feature = "list_sample")]
+    Sample {
+        is_fraction: bool,
+        with_replacement: bool,
+        shuffle: bool,
+        seed: Option<u64>,
+        /// The number of elements sampled from every subarray if it is a literal, which makes
+        /// the output an `Array` of that width.
+        width: Option<usize>,
+    },
+    #[cfg(feature = "list_sets")]
+    SetOperation(SetOperation),
+    #[cfg(feature = "list_sets")]
+    ContainsAny,
     Shift,
     Explode {
         skip_empty: bool,
@@ -70,6 +112,7 @@ impl IRArrayFunction {
             #[cfg(feature = "array_any_all")]
             Any | All => mapper.with_dtype(DataType::Boolean),
             Sort(_) => mapper.with_same_dtype(),
+            IsSorted(_) => mapper.with_dtype(DataType::Boolean),
             Reverse => mapper.with_same_dtype(),
             ArgMin | ArgMax => mapper.with_dtype(IDX_DTYPE),
             Get(_) => mapper.map_to_list_and_array_inner_dtype(),
@@ -78,6 +121,60 @@ impl IRArrayFunction {
             Contains { nulls_equal: _ } => mapper.with_dtype(DataType::Boolean),
             #[cfg(feature = "array_count")]
             CountMatches => mapper.with_dtype(IDX_DTYPE),
+            #[cfg(feature = "array_linalg")]
+            Dot | CosineSimilarity | L2Distance | Norm(_) => {
+                mapper.try_map_dtype(|_| linalg_output_dtype(mapper.args()))
+            },
+            #[cfg(feature = "array_linalg")]
+            Normalize(_) => mapper.try_map_dtype(|dtype| {
+                let DataType::Array(_, width) = dtype else {
+                    polars_bail!(InvalidOperation: "expected Array type, got: {dtype}")
+                };
+                let float_dtype = linalg_output_dtype(mapper.args())?;
+                Ok(DataType::Array(Box::new(float_dtype), *width))
+            }),
+            #[cfg(feature = "list_gather")]
+            Gather(_) => {
+                let fields = mapper.args();
+                let DataType::Array(inner, _) = fields[0].dtype() else {
+                    polars_bail!(op = "arr.gather", fields[0].dtype());
+                };
+                let dtype = match fields[1].dtype() {
+                    DataType::Array(_, width) => DataType::Array(inner.clone(), *width),
+                    DataType::List(_) => DataType::List(inner.clone()),
+                    dt => {
+                        polars_bail!(InvalidOperation: "`arr.gather` expects List or Array indices, got {}", dt)
+                    },
+                };
+                mapper.with_dtype(dtype)
+            },
+            #[cfg(feature = "diff")]
+            Diff { n, null_behavior } => mapper.try_map_dtype(|dt| {
+                let DataType::Array(inner, width) = dt else {
+                    polars_bail!(op = "arr.diff", dt);
+                };
+                Ok(DataType::Array(
+                    Box::new(diff_inner_dtype(inner)),
+                    array_diff_width(*width, *n, *null_behavior),
+                ))
+            }),
+            #[cfg(feature = "list_sample")]
+            Sample { width, .. } => mapper.try_map_dtype(|dt| match (dt, width) {
+                (DataType::Array(inner, _), Some(width)) => {
+                    Ok(DataType::Array(inner.clone(), *width))
+                },
+                _ => map_array_dtype_to_list_dtype(dt),
+            }),
+            #[cfg(feature = "list_sets")]
+            SetOperation(_) => {
+                let inner = set_operation_inner_dtype(mapper.args())?;
+                mapper.with_dtype(DataType::List(Box::new(inner)))
+            },
+            #[cfg(feature = "list_sets")]
+            ContainsAny => {
+                set_operation_inner_dtype(mapper.args())?;
+                mapper.with_dtype(DataType::Boolean)
+            },
             Shift => mapper.with_same_dtype(),
             Explode { .. } => mapper.try_map_to_array_inner_dtype(),
             Slice(offset, length) => {
@@ -112,6 +209,18 @@ impl IRArrayFunction {
             A::Contains { nulls_equal: _ } => FunctionOptions::elementwise(),
             #[cfg(feature = "array_count")]
             A::CountMatches => FunctionOptions::elementwise(),
+            #[cfg(feature = "array_linalg")]
+            A::Dot | A::CosineSimilarity | A::L2Distance | A::Norm(_) | A::Normalize(_) => {
+                FunctionOptions::elementwise()
+            },
+            #[cfg(feature = "list_gather")]
+            A::Gather(_) => FunctionOptions::elementwise(),
+            #[cfg(feature = "diff")]
+            A::Diff { .. } => FunctionOptions::elementwise(),
+            #[cfg(feature = "list_sample")]
+            A::Sample { .. } => FunctionOptions::elementwise(),
+            #[cfg(feature = "list_sets")]
+            A::SetOperation(_) | A::ContainsAny => FunctionOptions::elementwise(),
             A::Concat => FunctionOptions::elementwise()
                 .with_flags(|f| f | FunctionFlags::INPUT_WILDCARD_EXPANSION),
             A::Length
@@ -126,6 +235,7 @@ impl IRArrayFunction {
             | A::Mean
             | A::Median
             | A::Sort(_)
+            | A::IsSorted(_)
             | A::Reverse
             | A::ArgMin
             | A::ArgMax
@@ -140,6 +250,37 @@ impl IRArrayFunction {
     }
 }
 
+/// Float type of the linear algebra functions: `Float32` if all inputs hold `Float32`, `Float64`
+/// for all other numeric inputs.
+#[cfg(feature = "array_linalg")]
+fn linalg_output_dtype(fields: &[Field]) -> PolarsResult<DataType> {
+    let mut all_f32 = true;
+    for field in fields {
+        match field.dtype() {
+            DataType::Array(inner, _) if inner.is_primitive_numeric() => {
+                all_f32 &= matches!(inner.as_ref(), DataType::Float32);
+            },
+            dt => polars_bail!(InvalidOperation: "expected a numeric Array, got: {dt}"),
+        }
+    }
+    Ok(if all_f32 {
+        DataType::Float32
+    } else {
+        DataType::Float64
+    })
+}
+
+/// Inner type of a set operation on two arrays: the supertype of both inner types.
+#[cfg(feature = "list_sets")]
+fn set_operation_inner_dtype(fields: &[Field]) -> PolarsResult<DataType> {
+    match (fields[0].dtype(), fields[1].dtype()) {
+        (DataType::Array(a, _), DataType::Array(b, _)) => try_get_supertype(a, b),
+        (a, b) => {
+            polars_bail!(InvalidOperation: "set operations on arrays expect two Arrays, got {} and {}", a, b)
+        },
+    }
+}
+
 fn map_array_dtype_to_list_dtype(datatype: &DataType) -> PolarsResult<DataType> {
     if let DataType::Array(inner, _) = datatype {
         Ok(DataType::List(inner.clone()))
@@ -190,6 +331,7 @@ impl Display for IRArrayFunction {
             #[cfg(feature = "array_any_all")]
             All => "all",
             Sort(_) => "sort",
+            IsSorted(_) => "is_sorted",
             Reverse => "reverse",
             ArgMin => "arg_min",
             ArgMax => "arg_max",
@@ -199,6 +341,32 @@ impl Display for IRArrayFunction {
             Contains { nulls_equal: _ } => "contains",
             #[cfg(feature = "array_count")]
             CountMatches => "count_matches",
+            #[cfg(feature = "array_linalg")]
+            Dot => "dot",
+            #[cfg(feature = "array_linalg")]
+            CosineSimilarity => "cosine_similarity",
+            #[cfg(feature = "array_linalg")]
+            L2Distance => "l2_distance",
+            #[cfg(feature = "array_linalg")]
+            Norm(_) => "norm",
+            #[cfg(feature = "array_linalg")]
+            Normalize(_) => "normalize",
+            #[cfg(feature = "list_gather")]
+            Gather(_) => "gather",
+            #[cfg(feature = "diff")]
+            Diff { .. } => "diff",
+            #[cfg(feature = "list_sample")]
+            Sample { is_fraction, .. } => {
+                if *is_fraction {
+                    "sample_fraction"
+                } else {
+                    "sample_n"
+                }
+            },
+            #[cfg(feature = "list_sets")]
+            SetOperation(s) => return write!(f, "arr.{s}"),
+            #[cfg(feature = "list_sets")]
+            ContainsAny => "contains_any",
             Shift => "shift",
             Slice(_, _) => "slice",
             Explode { .. } => "explode",
@@ -230,6 +398,7 @@ impl From<IRArrayFunction> for SpecialEq<Arc<dyn ColumnsUdf>> {
             #[cfg(feature = "array_any_all")]
             All => map!(all),
             Sort(options) => map!(sort, options),
+            IsSorted(options) => map!(is_sorted, options),
             Reverse => map!(reverse),
             ArgMin => map!(arg_min),
             ArgMax => map!(arg_max),
@@ -239,6 +408,38 @@ impl From<IRArrayFunction> for SpecialEq<Arc<dyn ColumnsUdf>> {
             Contains { nulls_equal } => map_as_slice!(contains, nulls_equal),
             #[cfg(feature = "array_count")]
             CountMatches => map_as_slice!(count_matches),
+            #[cfg(feature = "array_linalg")]
+            Dot => map_as_slice!(dot),
+            #[cfg(feature = "array_linalg")]
+            CosineSimilarity => map_as_slice!(cosine_similarity),
+            #[cfg(feature = "array_linalg")]
+            L2Distance => map_as_slice!(l2_distance),
+            #[cfg(feature = "array_linalg")]
+            Norm(p) => map!(norm, p.0),
+            #[cfg(feature = "array_linalg")]
+            Normalize(p) => map!(normalize, p.0),
+            #[cfg(feature = "list_gather")]
+            Gather(null_on_oob) => map_as_slice!(gather, null_on_oob),
+            #[cfg(feature = "diff")]
+            Diff { n, null_behavior } => map!(diff, n, null_behavior),
+            #[cfg(feature = "list_sample")]
+            Sample {
+                is_fraction,
+                with_replacement,
+                shuffle,
+                seed,
+                width,
+            } => {
+                if is_fraction {
+                    map_as_slice!(sample_fraction, with_replacement, shuffle, seed)
+                } else {
+                    map_as_slice!(sample_n, with_replacement, shuffle, seed, width)
+                }
+            },
+            #[cfg(feature = "list_sets")]
+            SetOperation(set_op) => map_as_slice!(set_operation, set_op),
+            #[cfg(feature = "list_sets")]
+            ContainsAny => map_as_slice!(contains_any),
             Shift => map_as_slice!(shift),
             Explode { skip_empty } => map_as_slice!(explode, skip_empty),
             Slice(offset, length) => map!(slice, offset, length),
@@ -332,6 +533,10 @@ pub(super) fn sort(s: &Column, options: SortOptions) -> PolarsResult<Column> {
     Ok(s.array()?.array_sort(options)?.into_column())
 }
 
+pub(super) fn is_sorted(s: &Column, options: SortOptions) -> PolarsResult<Column> {
+    Ok(s.array()?.array_is_sorted(options)?.into_column())
+}
+
 pub(super) fn reverse(s: &Column) -> PolarsResult<Column> {
     Ok(s.array()?.array_reverse().into_column())
 }
@@ -387,6 +592,108 @@ pub(super) fn count_matches(args: &[Column]) -> PolarsResult<Column> {
         .map(Column::from)
 }
 
+#[cfg(feature = "array_linalg")]
+pub(super) fn dot(s: &[Column]) -> PolarsResult<Column> {
+    s[0].array()?.array_dot(s[1].array()?).map(Column::from)
+}
+
+#[cfg(feature = "array_linalg")]
+pub(super) fn cosine_similarity(s: &[Column]) -> PolarsResult<Column> {
+    s[0].array()?
+        .array_cosine_similarity(s[1].array()?)
+        .map(Column::from)
+}
+
+#[cfg(feature = "array_linalg")]
+pub(super) fn l2_distance(s: &[Column]) -> PolarsResult<Column> {
+    s[0].array()?
+        .array_l2_distance(s[1].array()?)
+        .map(Column::from)
+}
+
+#[cfg(feature = "array_linalg")]
+pub(super) fn norm(s: &Column, p: f64) -> PolarsResult<Column> {
+    s.array()?.array_norm(p).map(Column::from)
+}
+
+#[cfg(feature = "array_linalg")]
+pub(super) fn normalize(s: &Column, p: f64) -> PolarsResult<Column> {
+    Ok(s.array()?.array_normalize(p)?.into_column())
+}
+
+#[cfg(feature = "list_gather")]
+pub(super) fn gather(s: &[Column], null_on_oob: bool) -> PolarsResult<Column> {
+    s[0].array()?
+        .array_gather(s[1].as_materialized_series(), null_on_oob)
+        .map(Column::from)
+}
+
+#[cfg(feature = "diff")]
+pub(super) fn diff(s: &Column, n: i64, null_behavior: NullBehavior) -> PolarsResult<Column> {
+    s.array()?.array_diff(n, null_behavior).map(Column::from)
+}
+
+#[cfg(feature = "list_sample")]
+pub(super) fn sample_n(
+    s: &[Column],
+    with_replacement: bool,
+    shuffle: bool,
+    seed: Option<u64>,
+    width: Option<usize>,
+) -> PolarsResult<Column> {
+    let ca = s[0].array()?;
+    let n = &s[1];
+    let out = ca
+        .array_sample_n(n.as_materialized_series(), with_replacement, shuffle, seed)?
+        .into_column();
+    match width {
+        Some(width) => out.cast(&DataType::Array(Box::new(ca.inner_dtype().clone()), width)),
+        None => Ok(out),
+    }
+}
+
+#[cfg(feature = "list_sample")]
+pub(super) fn sample_fraction(
+    s: &[Column],
+    with_replacement: bool,
+    shuffle: bool,
+    seed: Option<u64>,
+) -> PolarsResult<Column> {
+    let ca = s[0].array()?;
+    let fraction = &s[1];
+    ca.array_sample_fraction(
+        fraction.as_materialized_series(),
+        with_replacement,
+        shuffle,
+        seed,
+    )
+    .map(|ok| ok.into_column())
+}
+
+/// Cast both arrays to the supertype of their inner types.
+#[cfg(feature = "list_sets")]
+fn cast_to_common_inner(s: &[Column]) -> PolarsResult<[Column; 2]> {
+    let fields = [s[0].field().into_owned(), s[1].field().into_owned()];
+    let inner = set_operation_inner_dtype(&fields)?;
+    let cast = |c: &Column| match c.dtype() {
+        DataType::Array(_, width) => c.cast(&DataType::Array(Box::new(inner.clone()), *width)),
+        _ => unreachable!(),
+    };
+    Ok([cast(&s[0])?, cast(&s[1])?])
+}
+
+#[cfg(feature = "list_sets")]
+pub(super) fn set_operation(s: &[Column], set_op: SetOperation) -> PolarsResult<Column> {
+    let [a, b] = cast_to_common_inner(s)?;
+    array_set_operation(a.array()?, b.array()?, set_op).map(|ok| ok.into_column())
+}
+
+#[cfg(feature = "list_sets")]
+pub(super) fn contains_any(s: &[Column]) -> PolarsResult<Column> {
+    let [a, b] = cast_to_common_inner(s)?;
+    array_contains_any(a.array()?, b.array()?).map(|ok| ok.into_column())
+}
+
 pub(super) fn shift(s: &[Column]) -> PolarsResult<Column> {
     let ca = s[0].array()?;
     let n = &s[1];
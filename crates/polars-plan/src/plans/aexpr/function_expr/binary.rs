@@ -18,6 +18,16 @@ pub enum IRBinaryFunction {
     Size,
     #[cfg(feature = "binary_encoding")]
     Reinterpret(DataType, bool),
+    #[cfg(feature = "binary_compression")]
+    Compress {
+        codec: BinaryCodec,
+        level: Option<i32>,
+    },
+    #[cfg(feature = "binary_compression")]
+    Decompress {
+        codec: BinaryCodec,
+        strict: bool,
+    },
 }
 
 impl IRBinaryFunction {
@@ -33,6 +43,8 @@ impl IRBinaryFunction {
             Size => mapper.with_dtype(DataType::UInt32),
             #[cfg(feature = "binary_encoding")]
             Reinterpret(dtype, _) => mapper.with_dtype(dtype.clone()),
+            #[cfg(feature = "binary_compression")]
+            Compress { .. } | Decompress { .. } => mapper.with_same_dtype(),
         }
     }
 
@@ -49,6 +61,8 @@ impl IRBinaryFunction {
             | B::Base64Decode(_)
             | B::Base64Encode
             | B::Reinterpret(_, _) => FunctionOptions::elementwise(),
+            #[cfg(feature = "binary_compression")]
+            B::Compress { .. } | B::Decompress { .. } => FunctionOptions::elementwise(),
         }
     }
 }
@@ -71,6 +85,10 @@ impl Display for IRBinaryFunction {
             Size => "size_bytes",
             #[cfg(feature = "binary_encoding")]
             Reinterpret(_, _) => "reinterpret",
+            #[cfg(feature = "binary_compression")]
+            Compress { .. } => "compress",
+            #[cfg(feature = "binary_compression")]
+            Decompress { .. } => "decompress",
         };
         write!(f, "bin.{s}")
     }
@@ -100,6 +118,10 @@ impl From<IRBinaryFunction> for SpecialEq<Arc<dyn ColumnsUdf>> {
             Size => map!(size_bytes),
             #[cfg(feature = "binary_encoding")]
             Reinterpret(dtype, is_little_endian) => map!(reinterpret, &dtype, is_little_endian),
+            #[cfg(feature = "binary_compression")]
+            Compress { codec, level } => map!(compress, codec, level),
+            #[cfg(feature = "binary_compression")]
+            Decompress { codec, strict } => map!(decompress, codec, strict),
         }
     }
 }
@@ -173,6 +195,18 @@ pub(super) fn reinterpret(
         .map(|val| val.into())
 }
 
+#[cfg(feature = "binary_compression")]
+pub(super) fn compress(s: &Column, codec: BinaryCodec, level: Option<i32>) -> PolarsResult<Column> {
+    let ca = s.binary()?;
+    ca.compress(codec, level).map(|ok| ok.into_column())
+}
+
+#[cfg(feature = "binary_compression")]
+pub(super) fn decompress(s: &Column, codec: BinaryCodec, strict: bool) -> PolarsResult<Column> {
+    let ca = s.binary()?;
+    ca.decompress(codec, strict).map(|ok| ok.into_column())
+}
+
 impl From<IRBinaryFunction> for IRFunctionExpr {
     fn from(b: IRBinaryFunction) -> Self {
         IRFunctionExpr::BinaryExpr(b)
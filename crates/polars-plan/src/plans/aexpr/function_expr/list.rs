@@ -97,21 +97,7 @@ impl IRListFunction {
                 let DataType::List(inner) = dt else {
                     polars_bail!(op = "list.diff", dt);
                 };
-
-                let inner_dt = match inner.as_ref() {
-                    #[cfg(feature = "dtype-datetime")]
-                    DataType::Datetime(tu, _) => DataType::Duration(*tu),
-                    #[cfg(feature = "dtype-date")]
-                    DataType::Date => DataType::Duration(TimeUnit::Microseconds),
-                    #[cfg(feature = "dtype-time")]
-                    DataType::Time => DataType::Duration(TimeUnit::Nanoseconds),
-                    DataType::UInt64 | DataType::UInt32 => DataType::Int64,
-                    DataType::UInt16 => DataType::Int32,
-                    DataType::UInt8 => DataType::Int16,
-                    inner_dt => inner_dt.clone(),
-                };
-
-                Ok(DataType::List(Box::new(inner_dt)))
+                Ok(DataType::List(Box::new(diff_inner_dtype(inner))))
             }),
             Sort(_) => mapper.ensure_is_list()?.with_same_dtype(),
             Reverse => mapper.ensure_is_list()?.with_same_dtype(),
@@ -371,6 +357,23 @@ pub(super) fn drop_nulls(s: &Column) -> PolarsResult<Column> {
     Ok(list.lst_drop_nulls().into_column())
 }
 
+/// The dtype of the differences between elements of the given dtype.
+#[cfg(feature = "diff")]
+pub(super) fn diff_inner_dtype(dtype: &DataType) -> DataType {
+    match dtype {
+        #[cfg(feature = "dtype-datetime")]
+        DataType::Datetime(tu, _) => DataType::Duration(*tu),
+        #[cfg(feature = "dtype-date")]
+        DataType::Date => DataType::Duration(TimeUnit::Microseconds),
+        #[cfg(feature = "dtype-time")]
+        DataType::Time => DataType::Duration(TimeUnit::Nanoseconds),
+        DataType::UInt64 | DataType::UInt32 => DataType::Int64,
+        DataType::UInt16 => DataType::Int32,
+        DataType::UInt8 => DataType::Int16,
+        dt => dt.clone(),
+    }
+}
+
 #[cfg(feature = "list_sample")]
 pub(super) fn sample_n(
     s: &[Column],
@@ -66,6 +66,8 @@ mod search_sorted;
 mod shift_and_fill;
 #[cfg(feature = "sign")]
 mod sign;
+#[cfg(feature = "stable_hash")]
+mod stable_hash;
 #[cfg(feature = "strings")]
 mod strings;
 #[cfg(feature = "dtype-struct")]
@@ -157,6 +159,8 @@ pub enum IRFunctionExpr {
     Pow(IRPowFunction),
     #[cfg(feature = "row_hash")]
     Hash(u64, u64, u64, u64),
+    #[cfg(feature = "stable_hash")]
+    StableHash(HashAlgorithm),
     #[cfg(feature = "arg_where")]
     ArgWhere,
     #[cfg(feature = "index_of")]
@@ -271,9 +275,9 @@ pub enum IRFunctionExpr {
     #[cfg(feature = "pct_change")]
     PctChange,
     #[cfg(feature = "interpolate")]
-    Interpolate(InterpolationMethod),
+    Interpolate(InterpolationOptions),
     #[cfg(feature = "interpolate_by")]
-    InterpolateBy,
+    InterpolateBy(InterpolationOptions),
     #[cfg(feature = "log")]
     Entropy {
         base: f64,
@@ -466,7 +470,7 @@ impl Hash for IRFunctionExpr {
             #[cfg(feature = "interpolate")]
             Interpolate(f) => f.hash(state),
             #[cfg(feature = "interpolate_by")]
-            InterpolateBy => {},
+            InterpolateBy(f) => f.hash(state),
             #[cfg(feature = "ffi_plugin")]
             FfiPlugin {
                 flags: _,
@@ -547,6 +551,8 @@ impl Hash for IRFunctionExpr {
             Sign => {},
             #[cfg(feature = "row_hash")]
             Hash(a, b, c, d) => (a, b, c, d).hash(state),
+            #[cfg(feature = "stable_hash")]
+            StableHash(algorithm) => algorithm.hash(state),
             FillNull => {},
             #[cfg(feature = "rolling_window")]
             RollingExpr { function, options } => {
@@ -752,6 +758,8 @@ impl Display for IRFunctionExpr {
             Pow(func) => return write!(f, "{func}"),
             #[cfg(feature = "row_hash")]
             Hash(_, _, _, _) => "hash",
+            #[cfg(feature = "stable_hash")]
+            StableHash(_) => "stable_hash",
             #[cfg(feature = "arg_where")]
             ArgWhere => "arg_where",
             #[cfg(feature = "index_of")]
@@ -835,7 +843,7 @@ impl Display for IRFunctionExpr {
             #[cfg(feature = "interpolate")]
             Interpolate(_) => "interpolate",
             #[cfg(feature = "interpolate_by")]
-            InterpolateBy => "interpolate_by",
+            InterpolateBy(_) => "interpolate_by",
             #[cfg(feature = "log")]
             Entropy { .. } => "entropy",
             #[cfg(feature = "log")]
@@ -1053,6 +1061,8 @@ impl From<IRFunctionExpr> for SpecialEq<Arc<dyn ColumnsUdf>> {
             Hash(k0, k1, k2, k3) => {
                 map!(row_hash::row_hash, k0, k1, k2, k3)
             },
+            #[cfg(feature = "stable_hash")]
+            StableHash(algorithm) => map!(stable_hash::stable_hash, algorithm),
             #[cfg(feature = "arg_where")]
             ArgWhere => {
                 wrap!(arg_where::arg_where)
@@ -1095,6 +1105,12 @@ impl From<IRFunctionExpr> for SpecialEq<Arc<dyn ColumnsUdf>> {
                     Quantile => map!(rolling::rolling_quantile, options.clone()),
                     Var => map!(rolling::rolling_var, options.clone()),
                     Std => map!(rolling::rolling_std, options.clone()),
+                    Rank => map!(rolling::rolling_rank, options.clone()),
+                    NUnique => map!(rolling::rolling_n_unique, options.clone()),
+                    ArgMin => map!(rolling::rolling_arg_min, options.clone()),
+                    ArgMax => map!(rolling::rolling_arg_max, options.clone()),
+                    Mode => map!(rolling::rolling_mode, options.clone()),
+                    Product => map!(rolling::rolling_product, options.clone()),
                     #[cfg(feature = "moment")]
                     Skew => map!(rolling::rolling_skew, options.clone()),
                     #[cfg(feature = "moment")]
@@ -1132,6 +1148,16 @@ impl From<IRFunctionExpr> for SpecialEq<Arc<dyn ColumnsUdf>> {
                     },
                     VarBy => map_as_slice!(rolling_by::rolling_var_by, options.clone()),
                     StdBy => map_as_slice!(rolling_by::rolling_std_by, options.clone()),
+                    RankBy => map_as_slice!(rolling_by::rolling_rank_by, options.clone()),
+                    NUniqueBy => {
+                        map_as_slice!(rolling_by::rolling_n_unique_by, options.clone())
+                    },
+                    ArgMinBy => map_as_slice!(rolling_by::rolling_arg_min_by, options.clone()),
+                    ArgMaxBy => map_as_slice!(rolling_by::rolling_arg_max_by, options.clone()),
+                    ModeBy => map_as_slice!(rolling_by::rolling_mode_by, options.clone()),
+                    ProductBy => {
+                        map_as_slice!(rolling_by::rolling_product_by, options.clone())
+                    },
                 }
             },
             #[cfg(feature = "hist")]
@@ -1220,12 +1246,12 @@ impl From<IRFunctionExpr> for SpecialEq<Arc<dyn ColumnsUdf>> {
             #[cfg(feature = "pct_change")]
             PctChange => map_as_slice!(dispatch::pct_change),
             #[cfg(feature = "interpolate")]
-            Interpolate(method) => {
-                map!(dispatch::interpolate, method)
+            Interpolate(options) => {
+                map!(dispatch::interpolate, options)
             },
             #[cfg(feature = "interpolate_by")]
-            InterpolateBy => {
-                map_as_slice!(dispatch::interpolate_by)
+            InterpolateBy(options) => {
+                map_as_slice!(dispatch::interpolate_by, options)
             },
             #[cfg(feature = "log")]
             Entropy { base, normalize } => map!(log::entropy, base, normalize),
@@ -1443,6 +1469,8 @@ impl IRFunctionExpr {
             },
             #[cfg(feature = "row_hash")]
             F::Hash(_, _, _, _) => FunctionOptions::elementwise(),
+            #[cfg(feature = "stable_hash")]
+            F::StableHash(_) => FunctionOptions::elementwise(),
             #[cfg(feature = "arg_where")]
             F::ArgWhere => FunctionOptions::groupwise(),
             #[cfg(feature = "index_of")]
@@ -1539,7 +1567,7 @@ impl IRFunctionExpr {
             #[cfg(feature = "interpolate")]
             F::Interpolate(_) => FunctionOptions::length_preserving(),
             #[cfg(feature = "interpolate_by")]
-            F::InterpolateBy => FunctionOptions::length_preserving(),
+            F::InterpolateBy(_) => FunctionOptions::length_preserving(),
             #[cfg(feature = "log")]
             F::Log | F::Log1p | F::Exp => FunctionOptions::elementwise(),
             #[cfg(feature = "log")]
@@ -18,6 +18,12 @@ pub enum IRRollingFunction {
     Quantile,
     Var,
     Std,
+    Rank,
+    NUnique,
+    ArgMin,
+    ArgMax,
+    Mode,
+    Product,
     #[cfg(feature = "moment")]
     Skew,
     #[cfg(feature = "moment")]
@@ -43,6 +49,12 @@ impl Display for IRRollingFunction {
             Quantile => "quantile",
             Var => "var",
             Std => "std",
+            Rank => "rank",
+            NUnique => "n_unique",
+            ArgMin => "arg_min",
+            ArgMax => "arg_max",
+            Mode => "mode",
+            Product => "product",
             #[cfg(feature = "moment")]
             Skew => "skew",
             #[cfg(feature = "moment")]
@@ -114,6 +126,60 @@ pub(super) fn rolling_std(s: &Column, options: RollingOptionsFixedWindow) -> Pol
         .map(Column::from)
 }
 
+pub(super) fn rolling_rank(s: &Column, options: RollingOptionsFixedWindow) -> PolarsResult<Column> {
+    // @scalar-opt
+    s.as_materialized_series()
+        .rolling_rank(options)
+        .map(Column::from)
+}
+
+pub(super) fn rolling_n_unique(
+    s: &Column,
+    options: RollingOptionsFixedWindow,
+) -> PolarsResult<Column> {
+    // @scalar-opt
+    s.as_materialized_series()
+        .rolling_n_unique(options)
+        .map(Column::from)
+}
+
+pub(super) fn rolling_arg_min(
+    s: &Column,
+    options: RollingOptionsFixedWindow,
+) -> PolarsResult<Column> {
+    // @scalar-opt
+    s.as_materialized_series()
+        .rolling_arg_min(options)
+        .map(Column::from)
+}
+
+pub(super) fn rolling_arg_max(
+    s: &Column,
+    options: RollingOptionsFixedWindow,
+) -> PolarsResult<Column> {
+    // @scalar-opt
+    s.as_materialized_series()
+        .rolling_arg_max(options)
+        .map(Column::from)
+}
+
+pub(super) fn rolling_mode(s: &Column, options: RollingOptionsFixedWindow) -> PolarsResult<Column> {
+    // @scalar-opt
+    s.as_materialized_series()
+        .rolling_mode(options)
+        .map(Column::from)
+}
+
+pub(super) fn rolling_product(
+    s: &Column,
+    options: RollingOptionsFixedWindow,
+) -> PolarsResult<Column> {
+    // @scalar-opt
+    s.as_materialized_series()
+        .rolling_product(options)
+        .map(Column::from)
+}
+
 #[cfg(feature = "moment")]
 pub(super) fn rolling_skew(s: &Column, options: RollingOptionsFixedWindow) -> PolarsResult<Column> {
     // @scalar-opt
@@ -12,6 +12,12 @@ pub enum IRRollingFunctionBy {
     QuantileBy,
     VarBy,
     StdBy,
+    RankBy,
+    NUniqueBy,
+    ArgMinBy,
+    ArgMaxBy,
+    ModeBy,
+    ProductBy,
 }
 
 impl Display for IRRollingFunctionBy {
@@ -26,6 +32,12 @@ impl Display for IRRollingFunctionBy {
             QuantileBy => "rolling_quantile_by",
             VarBy => "rolling_var_by",
             StdBy => "rolling_std_by",
+            RankBy => "rolling_rank_by",
+            NUniqueBy => "rolling_n_unique_by",
+            ArgMinBy => "rolling_arg_min_by",
+            ArgMaxBy => "rolling_arg_max_by",
+            ModeBy => "rolling_mode_by",
+            ProductBy => "rolling_product_by",
         };
 
         write!(f, "{name}")
@@ -107,3 +119,63 @@ pub(super) fn rolling_std_by(
         .rolling_std_by(s[1].as_materialized_series(), options)
         .map(Column::from)
 }
+
+pub(super) fn rolling_rank_by(
+    s: &[Column],
+    options: RollingOptionsDynamicWindow,
+) -> PolarsResult<Column> {
+    // @scalar-opt
+    s[0].as_materialized_series()
+        .rolling_rank_by(s[1].as_materialized_series(), options)
+        .map(Column::from)
+}
+
+pub(super) fn rolling_n_unique_by(
+    s: &[Column],
+    options: RollingOptionsDynamicWindow,
+) -> PolarsResult<Column> {
+    // @scalar-opt
+    s[0].as_materialized_series()
+        .rolling_n_unique_by(s[1].as_materialized_series(), options)
+        .map(Column::from)
+}
+
+pub(super) fn rolling_arg_min_by(
+    s: &[Column],
+    options: RollingOptionsDynamicWindow,
+) -> PolarsResult<Column> {
+    // @scalar-opt
+    s[0].as_materialized_series()
+        .rolling_arg_min_by(s[1].as_materialized_series(), options)
+        .map(Column::from)
+}
+
+pub(super) fn rolling_arg_max_by(
+    s: &[Column],
+    options: RollingOptionsDynamicWindow,
+) -> PolarsResult<Column> {
+    // @scalar-opt
+    s[0].as_materialized_series()
+        .rolling_arg_max_by(s[1].as_materialized_series(), options)
+        .map(Column::from)
+}
+
+pub(super) fn rolling_mode_by(
+    s: &[Column],
+    options: RollingOptionsDynamicWindow,
+) -> PolarsResult<Column> {
+    // @scalar-opt
+    s[0].as_materialized_series()
+        .rolling_mode_by(s[1].as_materialized_series(), options)
+        .map(Column::from)
+}
+
+pub(super) fn rolling_product_by(
+    s: &[Column],
+    options: RollingOptionsDynamicWindow,
+) -> PolarsResult<Column> {
+    // @scalar-opt
+    s[0].as_materialized_series()
+        .rolling_product_by(s[1].as_materialized_series(), options)
+        .map(Column::from)
+}
@@ -43,6 +43,8 @@ impl IRFunctionExpr {
             Coalesce => mapper.map_to_supertype(),
             #[cfg(feature = "row_hash")]
             Hash(..) => mapper.with_dtype(DataType::UInt64),
+            #[cfg(feature = "stable_hash")]
+            StableHash(algorithm) => mapper.with_dtype(algorithm.output_dtype()),
             #[cfg(feature = "arg_where")]
             ArgWhere => mapper.with_dtype(IDX_DTYPE),
             #[cfg(feature = "index_of")]
@@ -64,10 +66,13 @@ impl IRFunctionExpr {
             RollingExpr { function, options } => {
                 use IRRollingFunction::*;
                 match function {
-                    Min | Max => mapper.with_same_dtype(),
+                    Min | Max | Mode => mapper.with_same_dtype(),
                     Mean | Quantile | Std => mapper.moment_dtype(),
                     Var => mapper.var_dtype(),
                     Sum => mapper.sum_dtype(),
+                    Rank => mapper.with_dtype(DataType::Float64),
+                    NUnique | ArgMin | ArgMax => mapper.with_dtype(IDX_DTYPE),
+                    Product => mapper.map_dtype(product_dtype),
                     #[cfg(feature = "cov")]
                     CorrCov { .. } => mapper.map_to_float_dtype(),
                     #[cfg(feature = "moment")]
@@ -89,10 +94,13 @@ impl IRFunctionExpr {
             RollingExprBy { function_by, .. } => {
                 use IRRollingFunctionBy::*;
                 match function_by {
-                    MinBy | MaxBy => mapper.with_same_dtype(),
+                    MinBy | MaxBy | ModeBy => mapper.with_same_dtype(),
                     MeanBy | QuantileBy | StdBy => mapper.moment_dtype(),
                     VarBy => mapper.var_dtype(),
                     SumBy => mapper.sum_dtype(),
+                    RankBy => mapper.with_dtype(DataType::Float64),
+                    NUniqueBy | ArgMinBy | ArgMaxBy => mapper.with_dtype(IDX_DTYPE),
+                    ProductBy => mapper.map_dtype(product_dtype),
                 }
             },
             Rechunk => mapper.with_same_dtype(),
@@ -115,17 +123,7 @@ impl IRFunctionExpr {
             #[cfg(feature = "moment")]
             Kurtosis(..) => mapper.with_dtype(DataType::Float64),
             ArgUnique | ArgMin | ArgMax | ArgSort { .. } => mapper.with_dtype(IDX_DTYPE),
-            Product => mapper.map_dtype(|dtype| {
-                use DataType as T;
-                match dtype {
-                    T::Float32 => T::Float32,
-                    T::Float64 => T::Float64,
-                    T::UInt64 => T::UInt64,
-                    #[cfg(feature = "dtype-i128")]
-                    T::Int128 => T::Int128,
-                    _ => T::Int64,
-                }
-            }),
+            Product => mapper.map_dtype(product_dtype),
             Repeat => mapper.with_same_dtype(),
             #[cfg(feature = "rank")]
             Rank { options, .. } => mapper.with_dtype(match options.method {
@@ -231,12 +229,24 @@ impl IRFunctionExpr {
                 _ => DataType::Float64,
             }),
             #[cfg(feature = "interpolate")]
-            Interpolate(method) => match method {
+            Interpolate(options) => match options.method {
                 InterpolationMethod::Linear => mapper.map_numeric_to_float_dtype(false),
-                InterpolationMethod::Nearest => mapper.with_same_dtype(),
+                InterpolationMethod::Nearest
+                | InterpolationMethod::Previous
+                | InterpolationMethod::Next => mapper.with_same_dtype(),
+                InterpolationMethod::CubicSpline | InterpolationMethod::Pchip => {
+                    mapper.map_numeric_to_float_dtype(true)
+                },
             },
             #[cfg(feature = "interpolate_by")]
-            InterpolateBy => mapper.map_numeric_to_float_dtype(true),
+            InterpolateBy(options) => match options.method {
+                InterpolationMethod::Nearest
+                | InterpolationMethod::Previous
+                | InterpolationMethod::Next => mapper.with_same_dtype(),
+                InterpolationMethod::Linear
+                | InterpolationMethod::CubicSpline
+                | InterpolationMethod::Pchip => mapper.map_numeric_to_float_dtype(true),
+            },
             #[cfg(feature = "log")]
             Entropy { .. } | Log1p | Exp => mapper.map_to_float_dtype(),
             #[cfg(feature = "log")]
@@ -430,6 +440,18 @@ impl IRFunctionExpr {
     }
 }
 
+fn product_dtype(dtype: &DataType) -> DataType {
+    use DataType as T;
+    match dtype {
+        T::Float32 => T::Float32,
+        T::Float64 => T::Float64,
+        T::UInt64 => T::UInt64,
+        #[cfg(feature = "dtype-i128")]
+        T::Int128 => T::Int128,
+        _ => T::Int64,
+    }
+}
+
 pub struct FieldsMapper<'a> {
     fields: &'a [Field],
 }
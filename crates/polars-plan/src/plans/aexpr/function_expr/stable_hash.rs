@@ -0,0 +1,6 @@
+use super::*;
+
+pub(super) fn stable_hash(c: &Column, algorithm: HashAlgorithm) -> PolarsResult<Column> {
+    // @scalar-opt
+    polars_ops::series::stable_hash(c.as_materialized_series(), algorithm).map(Column::from)
+}
@@ -74,6 +74,31 @@ pub enum IRStringFunction {
     },
     #[cfg(feature = "string_reverse")]
     Reverse,
+    #[cfg(feature = "string_similarity")]
+    Levenshtein,
+    #[cfg(feature = "string_similarity")]
+    DamerauLevenshtein,
+    #[cfg(feature = "string_similarity")]
+    Hamming,
+    #[cfg(feature = "string_similarity")]
+    JaroWinkler,
+    #[cfg(feature = "string_similarity")]
+    NgramJaccard(usize),
+    #[cfg(feature = "string_url")]
+    UrlParse {
+        strict: bool,
+    },
+    #[cfg(feature = "string_url")]
+    UrlQueryParam {
+        name: PlSmallStr,
+        strict: bool,
+    },
+    #[cfg(feature = "string_url")]
+    PercentDecode {
+        strict: bool,
+    },
+    #[cfg(feature = "string_url")]
+    PercentEncode,
     #[cfg(feature = "string_pad")]
     PadStart {
         fill_char: char,
@@ -110,6 +135,11 @@ pub enum IRStringFunction {
     // DataType can only be Date/Datetime/Time
     Strptime(DataType, StrptimeOptions),
     Split(bool),
+    #[cfg(feature = "regex")]
+    SplitRegex {
+        inclusive: bool,
+        limit: Option<usize>,
+    },
     #[cfg(feature = "dtype-decimal")]
     ToDecimal {
         scale: usize,
@@ -171,6 +201,18 @@ impl IRStringFunction {
             Normalize { .. } => mapper.with_same_dtype(),
             #[cfg(feature = "string_reverse")]
             Reverse => mapper.with_same_dtype(),
+            #[cfg(feature = "string_similarity")]
+            Levenshtein | DamerauLevenshtein | Hamming => mapper.with_dtype(DataType::UInt32),
+            #[cfg(feature = "string_similarity")]
+            JaroWinkler | NgramJaccard(_) => mapper.with_dtype(DataType::Float64),
+            #[cfg(feature = "string_url")]
+            UrlParse { .. } => {
+                mapper.with_dtype(polars_ops::chunked_array::strings::url_parse_dtype())
+            },
+            #[cfg(feature = "string_url")]
+            UrlQueryParam { .. } | PercentDecode { .. } | PercentEncode => {
+                mapper.with_dtype(DataType::String)
+            },
             #[cfg(feature = "temporal")]
             Strptime(dtype, options) => match dtype {
                 #[cfg(feature = "dtype-datetime")]
@@ -190,6 +232,8 @@ impl IRStringFunction {
                 _ => mapper.with_dtype(dtype.clone()),
             },
             Split(_) => mapper.with_dtype(DataType::List(Box::new(DataType::String))),
+            #[cfg(feature = "regex")]
+            SplitRegex { .. } => mapper.with_dtype(DataType::List(Box::new(DataType::String))),
             #[cfg(feature = "nightly")]
             Titlecase => mapper.with_same_dtype(),
             #[cfg(feature = "dtype-decimal")]
@@ -267,11 +311,26 @@ impl IRStringFunction {
             S::Normalize { .. } => FunctionOptions::elementwise(),
             #[cfg(feature = "string_reverse")]
             S::Reverse => FunctionOptions::elementwise(),
+            #[cfg(feature = "string_similarity")]
+            S::Levenshtein
+            | S::DamerauLevenshtein
+            | S::Hamming
+            | S::JaroWinkler
+            | S::NgramJaccard(_) => {
+                FunctionOptions::elementwise().with_supertyping(Default::default())
+            },
+            #[cfg(feature = "string_url")]
+            S::UrlParse { .. }
+            | S::UrlQueryParam { .. }
+            | S::PercentDecode { .. }
+            | S::PercentEncode => FunctionOptions::elementwise(),
             #[cfg(feature = "temporal")]
             S::Strptime(_, options) if options.format.is_some() => FunctionOptions::elementwise(),
             #[cfg(feature = "temporal")]
             S::Strptime(_, _) => FunctionOptions::elementwise_with_infer(),
             S::Split(_) => FunctionOptions::elementwise(),
+            #[cfg(feature = "regex")]
+            S::SplitRegex { .. } => FunctionOptions::elementwise(),
             #[cfg(feature = "nightly")]
             S::Titlecase => FunctionOptions::elementwise(),
             #[cfg(feature = "dtype-decimal")]
@@ -348,6 +407,24 @@ impl Display for IRStringFunction {
             Normalize { .. } => "normalize",
             #[cfg(feature = "string_reverse")]
             Reverse => "reverse",
+            #[cfg(feature = "string_similarity")]
+            Levenshtein => "levenshtein",
+            #[cfg(feature = "string_similarity")]
+            DamerauLevenshtein => "damerau_levenshtein",
+            #[cfg(feature = "string_similarity")]
+            Hamming => "hamming",
+            #[cfg(feature = "string_similarity")]
+            JaroWinkler => "jaro_winkler",
+            #[cfg(feature = "string_similarity")]
+            NgramJaccard(_) => "ngram_jaccard",
+            #[cfg(feature = "string_url")]
+            UrlParse { .. } => "url.parse",
+            #[cfg(feature = "string_url")]
+            UrlQueryParam { .. } => "url.query_param",
+            #[cfg(feature = "string_url")]
+            PercentDecode { .. } => "url.percent_decode",
+            #[cfg(feature = "string_url")]
+            PercentEncode => "url.percent_encode",
             #[cfg(feature = "string_encoding")]
             HexEncode => "hex_encode",
             #[cfg(feature = "binary_encoding")]
@@ -382,6 +459,14 @@ impl Display for IRStringFunction {
                     "split"
                 }
             },
+            #[cfg(feature = "regex")]
+            SplitRegex { inclusive, .. } => {
+                if *inclusive {
+                    "split_regex_inclusive"
+                } else {
+                    "split_regex"
+                }
+            },
             #[cfg(feature = "nightly")]
             Titlecase => "titlecase",
             #[cfg(feature = "dtype-decimal")]
@@ -446,6 +531,10 @@ impl From<IRStringFunction> for SpecialEq<Arc<dyn ColumnsUdf>> {
             Split(inclusive) => {
                 map_as_slice!(strings::split, inclusive)
             },
+            #[cfg(feature = "regex")]
+            SplitRegex { inclusive, limit } => {
+                map_as_slice!(strings::split_regex, inclusive, limit)
+            },
             #[cfg(feature = "dtype-struct")]
             SplitExact { n, inclusive } => map_as_slice!(strings::split_exact, n, inclusive),
             #[cfg(feature = "dtype-struct")]
@@ -466,6 +555,24 @@ impl From<IRStringFunction> for SpecialEq<Arc<dyn ColumnsUdf>> {
             Normalize { form } => map!(strings::normalize, form.clone()),
             #[cfg(feature = "string_reverse")]
             Reverse => map!(strings::reverse),
+            #[cfg(feature = "string_similarity")]
+            Levenshtein => map_as_slice!(strings::levenshtein),
+            #[cfg(feature = "string_similarity")]
+            DamerauLevenshtein => map_as_slice!(strings::damerau_levenshtein),
+            #[cfg(feature = "string_similarity")]
+            Hamming => map_as_slice!(strings::hamming),
+            #[cfg(feature = "string_similarity")]
+            JaroWinkler => map_as_slice!(strings::jaro_winkler),
+            #[cfg(feature = "string_similarity")]
+            NgramJaccard(n) => map_as_slice!(strings::ngram_jaccard, n),
+            #[cfg(feature = "string_url")]
+            UrlParse { strict } => map!(strings::url_parse, strict),
+            #[cfg(feature = "string_url")]
+            UrlQueryParam { name, strict } => map!(strings::url_query_param, &name, strict),
+            #[cfg(feature = "string_url")]
+            PercentDecode { strict } => map!(strings::percent_decode, strict),
+            #[cfg(feature = "string_url")]
+            PercentEncode => map!(strings::percent_encode),
             Uppercase => map!(uppercase),
             Lowercase => map!(lowercase),
             #[cfg(feature = "nightly")]
@@ -625,6 +732,59 @@ pub(super) fn find(s: &[Column], literal: bool, strict: bool) -> PolarsResult<Co
         .map(|ok| ok.into_column())
 }
 
+#[cfg(feature = "string_similarity")]
+pub(super) fn levenshtein(s: &[Column]) -> PolarsResult<Column> {
+    _check_same_length(s, "levenshtein")?;
+    Ok(s[0].str()?.str_levenshtein(s[1].str()?).into_column())
+}
+
+#[cfg(feature = "string_similarity")]
+pub(super) fn damerau_levenshtein(s: &[Column]) -> PolarsResult<Column> {
+    _check_same_length(s, "damerau_levenshtein")?;
+    Ok(s[0]
+        .str()?
+        .str_damerau_levenshtein(s[1].str()?)
+        .into_column())
+}
+
+#[cfg(feature = "string_similarity")]
+pub(super) fn hamming(s: &[Column]) -> PolarsResult<Column> {
+    _check_same_length(s, "hamming")?;
+    Ok(s[0].str()?.str_hamming(s[1].str()?).into_column())
+}
+
+#[cfg(feature = "string_similarity")]
+pub(super) fn jaro_winkler(s: &[Column]) -> PolarsResult<Column> {
+    _check_same_length(s, "jaro_winkler")?;
+    Ok(s[0].str()?.str_jaro_winkler(s[1].str()?).into_column())
+}
+
+#[cfg(feature = "string_similarity")]
+pub(super) fn ngram_jaccard(s: &[Column], n: usize) -> PolarsResult<Column> {
+    _check_same_length(s, "ngram_jaccard")?;
+    Ok(s[0].str()?.str_ngram_jaccard(s[1].str()?, n)?.into_column())
+}
+
+#[cfg(feature = "string_url")]
+pub(super) fn url_parse(s: &Column, strict: bool) -> PolarsResult<Column> {
+    Ok(s.str()?.url_parse(strict)?.into_column())
+}
+
+#[cfg(feature = "string_url")]
+pub(super) fn url_query_param(s: &Column, name: &str, strict: bool) -> PolarsResult<Column> {
+    Ok(s.str()?.url_query_param(name, strict)?.into_column())
+}
+
+#[cfg(feature = "string_url")]
+pub(super) fn percent_decode(s: &Column, strict: bool) -> PolarsResult<Column> {
+    Ok(s.str()?.percent_decode(strict)?.into_column())
+}
+
+#[cfg(feature = "string_url")]
+pub(super) fn percent_encode(s: &Column) -> PolarsResult<Column> {
+    Ok(s.str()?.percent_encode().into_column())
+}
+
 pub(super) fn ends_with(s: &[Column]) -> PolarsResult<Column> {
     _check_same_length(s, "ends_with")?;
     let ca = s[0].str()?.as_binary();
@@ -822,6 +982,18 @@ pub(super) fn split(s: &[Column], inclusive: bool) -> PolarsResult<Column> {
     }
 }
 
+#[cfg(feature = "regex")]
+pub(super) fn split_regex(
+    s: &[Column],
+    inclusive: bool,
+    limit: Option<usize>,
+) -> PolarsResult<Column> {
+    let ca = s[0].str()?;
+    let pat = s[1].str()?;
+
+    Ok(ca.split_regex(pat, limit, inclusive)?.into_column())
+}
+
 #[cfg(feature = "dtype-date")]
 fn to_date(s: &Column, options: &StrptimeOptions) -> PolarsResult<Column> {
     let ca = s.str()?;
@@ -971,6 +1143,60 @@ fn is_literal_pat(pat: &str) -> bool {
     pat.chars().all(|c| !c.is_ascii_punctuation())
 }
 
+/// Replace the first `n` matches, or all matches if `n` is `None`, with a pattern per row.
+#[cfg(feature = "regex")]
+fn replace_per_row(
+    ca: &StringChunked,
+    pat: &StringChunked,
+    val: &StringChunked,
+    literal: bool,
+    n: Option<usize>,
+) -> PolarsResult<StringChunked> {
+    polars_ensure!(
+        pat.len() == ca.len(),
+        ComputeError:
+        "pattern length ({}) does not match string column length ({})",
+        pat.len(), ca.len(),
+    );
+    polars_ensure!(
+        val.len() == 1 || val.len() == ca.len(),
+        ComputeError:
+        "replacement value length ({}) does not match string column length ({})",
+        val.len(), ca.len(),
+    );
+    if n == Some(0) {
+        return Ok(ca.clone());
+    }
+    let vals: Box<dyn Iterator<Item = Option<&str>>> = if val.len() == ca.len() {
+        Box::new(val.iter())
+    } else {
+        Box::new(std::iter::repeat_n(val.get(0), ca.len()))
+    };
+
+    // A limit of 0 replaces all matches.
+    let limit = n.unwrap_or(0);
+    let mut builder = StringChunkedBuilder::new(ca.name().clone(), ca.len());
+    polars_utils::regex_cache::with_regex_cache(|reg_cache| {
+        for ((opt_src, opt_pat), opt_val) in ca.iter().zip(pat.iter()).zip(vals) {
+            let (Some(src), Some(pat), Some(val)) = (opt_src, opt_pat, opt_val) else {
+                builder.append_null();
+                continue;
+            };
+            let out = if literal || is_literal_pat(pat) {
+                match n {
+                    Some(n) => Cow::Owned(src.replacen(pat, val, n)),
+                    None => Cow::Owned(src.replace(pat, val)),
+                }
+            } else {
+                reg_cache.compile(pat)?.replacen(src, limit, val)
+            };
+            builder.append_value(out);
+        }
+        PolarsResult::Ok(())
+    })?;
+    Ok(builder.finish())
+}
+
 #[cfg(feature = "regex")]
 fn replace_n<'a>(
     ca: &'a StringChunked,
@@ -989,24 +1215,15 @@ fn replace_n<'a>(
 
             match literal {
                 true => ca.replace_literal(pat, val, n),
-                false => {
-                    if n > 1 {
-                        polars_bail!(ComputeError: "regex replacement with 'n > 1' not yet supported")
-                    }
-                    ca.replace(pat, val)
-                },
+                false => ca.replace_n(pat, val, n),
             }
         },
         (1, len_val) => {
-            if n > 1 {
-                polars_bail!(ComputeError: "multivalue replacement with 'n > 1' not yet supported")
-            }
-
+            // `Regex::replacen` replaces all matches for a limit of 0.
             if n == 0 {
                 return Ok(ca.clone());
             };
 
-            // from here on, we know that n == 1
             let mut pat = get_pat(pat)?.to_string();
             polars_ensure!(
                 len_val == ca.len(),
@@ -1025,17 +1242,15 @@ fn replace_n<'a>(
 
             let f = |s: &'a str, val: &'a str| {
                 if literal {
-                    reg.replace(s, NoExpand(val))
+                    reg.replacen(s, n, NoExpand(val))
                 } else {
-                    reg.replace(s, val)
+                    reg.replacen(s, n, val)
                 }
             };
 
             Ok(iter_and_replace(ca, val, f))
         },
-        _ => polars_bail!(
-            ComputeError: "dynamic pattern length in 'str.replace' expressions is not supported yet"
-        ),
+        _ => replace_per_row(ca, pat, val, literal, Some(n)),
     }
 }
 
@@ -1088,9 +1303,7 @@ fn replace_all<'a>(
 
             Ok(iter_and_replace(ca, val, f))
         },
-        _ => polars_bail!(
-            ComputeError: "dynamic pattern length in 'str.replace' expressions is not supported yet"
-        ),
+        _ => replace_per_row(ca, pat, val, literal, None),
     }
 }
 
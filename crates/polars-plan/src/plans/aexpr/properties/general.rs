@@ -24,7 +24,7 @@ impl AExpr {
             Literal(v) => v.is_scalar(),
 
             Eval { variant, .. } => match variant {
-                EvalVariant::List => true,
+                EvalVariant::List | EvalVariant::Array { .. } => true,
                 EvalVariant::Cumulative { min_samples: _ } => false,
             },
 
@@ -189,6 +189,28 @@ pub fn is_row_separable_rec(node: Node, expr_arena: &Arena<AExpr>) -> bool {
     property_rec(node, expr_arena, is_row_separable)
 }
 
+/// Checks whether the expression outputs as many rows as the columns it reads. Scalar inputs are
+/// broadcast, so they don't change the length.
+pub fn is_length_preserving_rec(node: Node, expr_arena: &Arena<AExpr>) -> bool {
+    let ae = expr_arena.get(node);
+    let length_preserving_top_level = match ae {
+        AExpr::Function { options, .. } | AExpr::AnonymousFunction { options, .. } => {
+            options.is_length_preserving()
+        },
+        AExpr::Sort { .. } | AExpr::SortBy { .. } => true,
+        ae => ae.is_elementwise_top_level(),
+    };
+    if !length_preserving_top_level {
+        return false;
+    }
+
+    let mut inputs = unitvec![];
+    ae.inputs_rev(&mut inputs);
+    inputs
+        .into_iter()
+        .all(|input| is_scalar_ae(input, expr_arena) || is_length_preserving_rec(input, expr_arena))
+}
+
 #[derive(Debug, Clone)]
 pub enum ExprPushdownGroup {
     /// Can be pushed. (elementwise, infallible)
@@ -243,6 +265,12 @@ impl ExprPushdownGroup {
                         ..
                     } => true,
 
+                    #[cfg(all(feature = "dtype-array", feature = "list_gather"))]
+                    AExpr::Function {
+                        function: IRFunctionExpr::ArrayExpr(IRArrayFunction::Gather(false)),
+                        ..
+                    } => true,
+
                     #[cfg(all(feature = "strings", feature = "temporal"))]
                     AExpr::Function {
                         input,
@@ -281,6 +281,23 @@ impl AExpr {
 
                 output_field.dtype = match variant {
                     EvalVariant::List => DataType::List(Box::new(output_field.dtype)),
+                    #[cfg(feature = "dtype-array")]
+                    EvalVariant::Array { as_list: false } => {
+                        let DataType::Array(_, width) = field.dtype() else {
+                            unreachable!()
+                        };
+                        let width = if is_scalar_ae(*evaluation, ctx.arena) {
+                            1
+                        } else {
+                            polars_ensure!(
+                                is_length_preserving_rec(*evaluation, ctx.arena),
+                                InvalidOperation: "`arr.eval` with an expression that changes the number of elements needs `as_list`"
+                            );
+                            *width
+                        };
+                        DataType::Array(Box::new(output_field.dtype), width)
+                    },
+                    EvalVariant::Array { .. } => DataType::List(Box::new(output_field.dtype)),
                     EvalVariant::Cumulative { .. } => output_field.dtype,
                 };
                 output_field.name = field.name;
@@ -295,6 +295,14 @@ impl<'a> IRBuilder<'a> {
                     schema.with_column("_upper_boundary".into(), dtype.clone());
                 }
                 schema.with_column(name.clone(), dtype.clone());
+            } else if let Some(options) = options.session.as_ref() {
+                let name = &options.index_column;
+                let dtype = current_schema.get(name).unwrap();
+                if options.include_boundaries {
+                    schema.with_column("_lower_boundary".into(), dtype.clone());
+                    schema.with_column("_upper_boundary".into(), dtype.clone());
+                }
+                schema.with_column(name.clone(), dtype.clone());
             }
         }
 
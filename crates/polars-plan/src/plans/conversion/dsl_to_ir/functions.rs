@@ -78,6 +78,7 @@ pub(super) fn convert_functions(
                 #[cfg(feature = "array_any_all")]
                 A::All => IA::All,
                 A::Sort(sort_options) => IA::Sort(sort_options),
+                A::IsSorted(sort_options) => IA::IsSorted(sort_options),
                 A::Reverse => IA::Reverse,
                 A::ArgMin => IA::ArgMin,
                 A::ArgMax => IA::ArgMax,
@@ -87,6 +88,40 @@ pub(super) fn convert_functions(
                 A::Contains { nulls_equal } => IA::Contains { nulls_equal },
                 #[cfg(feature = "array_count")]
                 A::CountMatches => IA::CountMatches,
+                #[cfg(feature = "array_linalg")]
+                A::Dot => IA::Dot,
+                #[cfg(feature = "array_linalg")]
+                A::CosineSimilarity => IA::CosineSimilarity,
+                #[cfg(feature = "array_linalg")]
+                A::L2Distance => IA::L2Distance,
+                #[cfg(feature = "array_linalg")]
+                A::Norm(p) => IA::Norm(p),
+                #[cfg(feature = "array_linalg")]
+                A::Normalize(p) => IA::Normalize(p),
+                #[cfg(feature = "list_gather")]
+                A::Gather(null_on_oob) => IA::Gather(null_on_oob),
+                #[cfg(feature = "diff")]
+                A::Diff { n, null_behavior } => IA::Diff { n, null_behavior },
+                #[cfg(feature = "list_sample")]
+                A::Sample {
+                    is_fraction,
+                    with_replacement,
+                    shuffle,
+                    seed,
+                } => IA::Sample {
+                    is_fraction,
+                    with_replacement,
+                    shuffle,
+                    seed,
+                    width: match ctx.arena.get(e[1].node()) {
+                        AExpr::Literal(n) if !is_fraction => n.extract_usize().ok(),
+                        _ => None,
+                    },
+                },
+                #[cfg(feature = "list_sets")]
+                A::SetOperation(set_operation) => IA::SetOperation(set_operation),
+                #[cfg(feature = "list_sets")]
+                A::ContainsAny => IA::ContainsAny,
                 A::Shift => IA::Shift,
                 A::Explode { skip_empty } => IA::Explode { skip_empty },
                 A::Concat => IA::Concat,
@@ -110,6 +145,10 @@ pub(super) fn convert_functions(
                 #[cfg(feature = "binary_encoding")]
                 B::Base64Encode => IB::Base64Encode,
                 B::Size => IB::Size,
+                #[cfg(feature = "binary_compression")]
+                B::Compress { codec, level } => IB::Compress { codec, level },
+                #[cfg(feature = "binary_compression")]
+                B::Decompress { codec, strict } => IB::Decompress { codec, strict },
                 #[cfg(feature = "binary_encoding")]
                 B::Reinterpret(dtype_expr, v) => {
                     let dtype = dtype_expr.into_datatype(ctx.schema)?;
@@ -247,6 +286,24 @@ pub(super) fn convert_functions(
                 S::Normalize { form } => IS::Normalize { form },
                 #[cfg(feature = "string_reverse")]
                 S::Reverse => IS::Reverse,
+                #[cfg(feature = "string_similarity")]
+                S::Levenshtein => IS::Levenshtein,
+                #[cfg(feature = "string_similarity")]
+                S::DamerauLevenshtein => IS::DamerauLevenshtein,
+                #[cfg(feature = "string_similarity")]
+                S::Hamming => IS::Hamming,
+                #[cfg(feature = "string_similarity")]
+                S::JaroWinkler => IS::JaroWinkler,
+                #[cfg(feature = "string_similarity")]
+                S::NgramJaccard(n) => IS::NgramJaccard(n),
+                #[cfg(feature = "string_url")]
+                S::UrlParse { strict } => IS::UrlParse { strict },
+                #[cfg(feature = "string_url")]
+                S::UrlQueryParam { name, strict } => IS::UrlQueryParam { name, strict },
+                #[cfg(feature = "string_url")]
+                S::PercentDecode { strict } => IS::PercentDecode { strict },
+                #[cfg(feature = "string_url")]
+                S::PercentEncode => IS::PercentEncode,
                 #[cfg(feature = "string_pad")]
                 S::PadStart { fill_char } => IS::PadStart { fill_char },
                 #[cfg(feature = "string_pad")]
@@ -288,6 +345,8 @@ pub(super) fn convert_functions(
                     IS::Strptime(dtype, strptime_options)
                 },
                 S::Split(v) => IS::Split(v),
+                #[cfg(feature = "regex")]
+                S::SplitRegex { inclusive, limit } => IS::SplitRegex { inclusive, limit },
                 #[cfg(feature = "dtype-decimal")]
                 S::ToDecimal { scale } => IS::ToDecimal { scale },
                 #[cfg(feature = "nightly")]
@@ -570,6 +629,8 @@ pub(super) fn convert_functions(
         }),
         #[cfg(feature = "row_hash")]
         F::Hash(s0, s1, s2, s3) => I::Hash(s0, s1, s2, s3),
+        #[cfg(feature = "stable_hash")]
+        F::StableHash(algorithm) => I::StableHash(algorithm),
         #[cfg(feature = "arg_where")]
         F::ArgWhere => I::ArgWhere,
         #[cfg(feature = "index_of")]
@@ -693,6 +754,12 @@ pub(super) fn convert_functions(
                     R::Quantile => IR::Quantile,
                     R::Var => IR::Var,
                     R::Std => IR::Std,
+                    R::Rank => IR::Rank,
+                    R::NUnique => IR::NUnique,
+                    R::ArgMin => IR::ArgMin,
+                    R::ArgMax => IR::ArgMax,
+                    R::Mode => IR::Mode,
+                    R::Product => IR::Product,
                     #[cfg(feature = "moment")]
                     R::Skew => IR::Skew,
                     #[cfg(feature = "moment")]
@@ -727,6 +794,12 @@ pub(super) fn convert_functions(
                     R::QuantileBy => IR::QuantileBy,
                     R::VarBy => IR::VarBy,
                     R::StdBy => IR::StdBy,
+                    R::RankBy => IR::RankBy,
+                    R::NUniqueBy => IR::NUniqueBy,
+                    R::ArgMinBy => IR::ArgMinBy,
+                    R::ArgMaxBy => IR::ArgMaxBy,
+                    R::ModeBy => IR::ModeBy,
+                    R::ProductBy => IR::ProductBy,
                 },
                 options,
             }
@@ -816,9 +889,9 @@ pub(super) fn convert_functions(
         #[cfg(feature = "pct_change")]
         F::PctChange => I::PctChange,
         #[cfg(feature = "interpolate")]
-        F::Interpolate(interpolation_method) => I::Interpolate(interpolation_method),
+        F::Interpolate(options) => I::Interpolate(options),
         #[cfg(feature = "interpolate_by")]
-        F::InterpolateBy => I::InterpolateBy,
+        F::InterpolateBy(options) => I::InterpolateBy(options),
         #[cfg(feature = "log")]
         F::Entropy { base, normalize } => I::Entropy { base, normalize },
         #[cfg(feature = "log")]
@@ -96,6 +96,19 @@ pub fn resolve_join(
             }
         }
 
+        if options.args.how.is_fuzzy() {
+            polars_ensure!(
+                left_on.len() == 1 && right_on.len() == 1,
+                InvalidOperation: "fuzzy join expects a single join key on each side"
+            );
+        }
+        if options.args.how.is_knn() {
+            polars_ensure!(
+                left_on.len() == 1 && right_on.len() == 1,
+                InvalidOperation: "nearest-neighbour join expects a single join key on each side"
+            );
+        }
+
         polars_ensure!(
             left_on.len() == right_on.len(),
             InvalidOperation:
@@ -21,6 +21,7 @@ pub(super) fn dsl_to_ir(
     let mut cached_ir = cached_ir.lock().unwrap();
 
     if cached_ir.is_none() {
+        let scan_type = resolve_named_scan(scan_type, &unified_scan_args_box)?;
         let unified_scan_args = unified_scan_args_box.as_mut();
 
         if let Some(hive_schema) = unified_scan_args.hive_options.schema.as_deref() {
@@ -47,10 +48,14 @@ pub(super) fn dsl_to_ir(
             },
             #[cfg(feature = "ipc")]
             FileScanDsl::Ipc { .. } => sources.expand_paths_with_hive_update(unified_scan_args)?,
+            #[cfg(feature = "orc")]
+            FileScanDsl::Orc { .. } => sources.expand_paths_with_hive_update(unified_scan_args)?,
             #[cfg(feature = "csv")]
             FileScanDsl::Csv { .. } => sources.expand_paths(unified_scan_args)?,
             #[cfg(feature = "json")]
             FileScanDsl::NDJson { .. } => sources.expand_paths(unified_scan_args)?,
+            #[cfg(feature = "fwf")]
+            FileScanDsl::Fwf { .. } => sources.expand_paths(unified_scan_args)?,
             #[cfg(feature = "python")]
             FileScanDsl::PythonDataset { .. } => {
                 // There are a lot of places that short-circuit if the paths is empty,
@@ -58,6 +63,7 @@ pub(super) fn dsl_to_ir(
                 ScanSources::Paths(Arc::from([PlPath::from_str("dummy")]))
             },
             FileScanDsl::Anonymous { .. } => sources.clone(),
+            FileScanDsl::NamedAnonymous { .. } => unreachable!("named scans are resolved"),
         };
 
         // For cloud we must deduplicate files. Serialization/deserialization leads to Arc's losing there
@@ -168,6 +174,40 @@ pub(super) fn dsl_to_ir(
     Ok(cached_ir.clone().unwrap())
 }
 
+/// Looks up named scans in the named plan registry, after which they are anonymous scans.
+fn resolve_named_scan(
+    scan_type: Box<FileScanDsl>,
+    unified_scan_args: &UnifiedScanArgs,
+) -> PolarsResult<Box<FileScanDsl>> {
+    let FileScanDsl::NamedAnonymous {
+        name,
+        payload,
+        skip_rows,
+    } = *scan_type
+    else {
+        return Ok(scan_type);
+    };
+
+    let function = crate::dsl::named_serde::get_named_scan(&name, &payload)?;
+    let schema = match &unified_scan_args.schema {
+        Some(schema) => schema.clone(),
+        None => function.schema(None)?,
+    };
+
+    Ok(Box::new(FileScanDsl::Anonymous {
+        options: Arc::new(AnonymousScanOptions {
+            skip_rows,
+            fmt_str: "NAMED SCAN",
+        }),
+        function,
+        file_info: FileInfo {
+            schema: schema.clone(),
+            reader_schema: Some(Either::Right(schema)),
+            ..Default::default()
+        },
+    }))
+}
+
 pub(super) fn insert_row_index_to_schema(
     schema: &mut Schema,
     name: PlSmallStr,
@@ -186,7 +226,7 @@ pub(super) fn insert_row_index_to_schema(
     Ok(())
 }
 
-#[cfg(any(feature = "parquet", feature = "ipc"))]
+#[cfg(any(feature = "parquet", feature = "ipc", feature = "orc"))]
 fn prepare_output_schema(
     mut schema: Schema,
     row_index: Option<&RowIndex>,
@@ -318,6 +358,42 @@ pub(super) fn ipc_file_info(
     Ok((file_info, metadata))
 }
 
+#[cfg(feature = "orc")]
+pub(super) fn orc_file_info(
+    first_scan_source: ScanSourceRef<'_>,
+    row_index: Option<&RowIndex>,
+    #[allow(unused)] cloud_options: Option<&polars_io::cloud::CloudOptions>,
+) -> PolarsResult<(FileInfo, polars_io::orc::OrcMetadata)> {
+    use polars_core::error::feature_gated;
+
+    // The metadata is at the tail of the file, cloud files are downloaded entirely.
+    let run_async = first_scan_source.is_cloud_url();
+
+    let cache_entries = if run_async {
+        feature_gated!("cloud", {
+            Some(polars_io::file_cache::init_entries_from_uri_list(
+                [Arc::from(first_scan_source.as_path().unwrap().to_str())].into_iter(),
+                cloud_options,
+            )?)
+        })
+    } else {
+        None
+    };
+
+    let memslice =
+        first_scan_source.to_memslice_possibly_async(run_async, cache_entries.as_ref(), 0)?;
+    let metadata = polars_io::orc::read_metadata(&memslice)?;
+    let num_rows = metadata.num_rows();
+
+    let file_info = FileInfo::new(
+        prepare_output_schema(metadata.schema().as_ref().clone(), row_index)?,
+        Some(Either::Right(metadata.schema().clone())),
+        (Some(num_rows), num_rows),
+    );
+
+    Ok((file_info, metadata))
+}
+
 #[cfg(feature = "csv")]
 pub fn csv_file_info(
     sources: &ScanSources,
@@ -330,6 +406,7 @@ pub fn csv_file_info(
 
     use polars_core::error::feature_gated;
     use polars_core::{POOL, config};
+    use polars_io::csv::read::encoding::maybe_transcode_bytes;
     use polars_io::csv::read::schema_inference::SchemaInferenceResult;
     use polars_io::utils::get_reader_bytes;
     use rayon::iter::{IntoParallelIterator, ParallelIterator};
@@ -365,7 +442,12 @@ pub fn csv_file_info(
         let source = sources.at(i);
         let memslice = source.to_memslice_possibly_async(run_async, cache_entries.as_ref(), i)?;
         let owned = &mut vec![];
-        let mut reader = std::io::Cursor::new(maybe_decompress_bytes(&memslice, owned)?);
+        let transcoded = &mut vec![];
+        let mut reader = std::io::Cursor::new(maybe_transcode_bytes(
+            maybe_decompress_bytes(&memslice, owned)?,
+            transcoded,
+            csv_options.parse_options.encoding,
+        )?);
         if reader.read(&mut [0; 4])? < 2 && csv_options.raise_if_empty {
             polars_bail!(NoData: "empty CSV")
         }
@@ -620,6 +702,33 @@ this scan to succeed with an empty DataFrame.",
                 ))
             })()
             .map_err(|e| e.context(failed_here!(ipc scan)))?,
+            #[cfg(feature = "orc")]
+            FileScanDsl::Orc { options } => (|| {
+                let first_scan_source =
+                    require_first_source("failed to retrieve first file schema (orc)", "")?;
+
+                if verbose() {
+                    eprintln!(
+                        "sourcing orc scan file schema from: '{}'",
+                        first_scan_source.to_include_path_name()
+                    )
+                }
+
+                let (file_info, md) = scans::orc_file_info(
+                    first_scan_source,
+                    unified_scan_args.row_index.as_ref(),
+                    cloud_options,
+                )?;
+
+                PolarsResult::Ok((
+                    file_info,
+                    FileScanIR::Orc {
+                        options,
+                        metadata: Some(Arc::new(md)),
+                    },
+                ))
+            })()
+            .map_err(|e| e.context(failed_here!(orc scan)))?,
             #[cfg(feature = "csv")]
             FileScanDsl::Csv { mut options } => {
                 (|| {
@@ -677,6 +786,27 @@ this scan to succeed with an empty DataFrame.",
                 ))
             })()
             .map_err(|e| e.context(failed_here!(ndjson scan)))?,
+            #[cfg(feature = "fwf")]
+            FileScanDsl::Fwf { options } => (|| {
+                // The schema is fully described by the column specification, so the files do not
+                // need to be read.
+                let mut schema = Arc::new(options.schema()?);
+                let reader_schema = schema.clone();
+
+                if let Some(row_index) = &unified_scan_args.row_index {
+                    insert_row_index_to_schema(Arc::make_mut(&mut schema), row_index.name.clone())?;
+                }
+
+                PolarsResult::Ok((
+                    FileInfo {
+                        schema,
+                        reader_schema: Some(either::Either::Right(reader_schema)),
+                        row_estimation: (None, usize::MAX),
+                    },
+                    FileScanIR::Fwf { options },
+                ))
+            })()
+            .map_err(|e| e.context(failed_here!(fwf scan)))?,
             #[cfg(feature = "python")]
             FileScanDsl::PythonDataset { dataset_object } => (|| {
                 if crate::dsl::DATASET_PROVIDER_VTABLE.get().is_none() {
@@ -708,6 +838,7 @@ this scan to succeed with an empty DataFrame.",
                 options,
                 function,
             } => (file_info, FileScanIR::Anonymous { options, function }),
+            FileScanDsl::NamedAnonymous { .. } => unreachable!("named scans are resolved"),
         })
     }
 
@@ -754,6 +885,16 @@ this scan to succeed with an empty DataFrame.",
                 let v = self.inner.get(&key);
                 (key, v)
             },
+            #[cfg(feature = "orc")]
+            FileScanDsl::Orc { options: _ } => {
+                let key = CachedSourceKey::ParquetIpc {
+                    first_path: paths[0].clone(),
+                    schema_overwrite: None,
+                };
+
+                let v = self.inner.get(&key);
+                (key, v)
+            },
             #[cfg(feature = "csv")]
             FileScanDsl::Csv { options } => {
                 let key = CachedSourceKey::CsvJson {
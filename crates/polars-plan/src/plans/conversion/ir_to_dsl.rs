@@ -269,6 +269,7 @@ pub fn ir_function_to_dsl(input: Vec<Expr>, function: IRFunctionExpr) -> Expr {
                 #[cfg(feature = "array_any_all")]
                 IA::All => A::All,
                 IA::Sort(v) => A::Sort(v),
+                IA::IsSorted(v) => A::IsSorted(v),
                 IA::Reverse => A::Reverse,
                 IA::ArgMin => A::ArgMin,
                 IA::ArgMax => A::ArgMax,
@@ -278,6 +279,37 @@ pub fn ir_function_to_dsl(input: Vec<Expr>, function: IRFunctionExpr) -> Expr {
                 IA::Contains { nulls_equal } => A::Contains { nulls_equal },
                 #[cfg(feature = "array_count")]
                 IA::CountMatches => A::CountMatches,
+                #[cfg(feature = "array_linalg")]
+                IA::Dot => A::Dot,
+                #[cfg(feature = "array_linalg")]
+                IA::CosineSimilarity => A::CosineSimilarity,
+                #[cfg(feature = "array_linalg")]
+                IA::L2Distance => A::L2Distance,
+                #[cfg(feature = "array_linalg")]
+                IA::Norm(p) => A::Norm(p),
+                #[cfg(feature = "array_linalg")]
+                IA::Normalize(p) => A::Normalize(p),
+                #[cfg(feature = "list_gather")]
+                IA::Gather(null_on_oob) => A::Gather(null_on_oob),
+                #[cfg(feature = "diff")]
+                IA::Diff { n, null_behavior } => A::Diff { n, null_behavior },
+                #[cfg(feature = "list_sample")]
+                IA::Sample {
+                    is_fraction,
+                    with_replacement,
+                    shuffle,
+                    seed,
+                    width: _,
+                } => A::Sample {
+                    is_fraction,
+                    with_replacement,
+                    shuffle,
+                    seed,
+                },
+                #[cfg(feature = "list_sets")]
+                IA::SetOperation(set_operation) => A::SetOperation(set_operation),
+                #[cfg(feature = "list_sets")]
+                IA::ContainsAny => A::ContainsAny,
                 IA::Shift => A::Shift,
                 IA::Slice(offset, length) => A::Slice(offset, length),
                 IA::Explode { skip_empty } => A::Explode { skip_empty },
@@ -302,6 +334,10 @@ pub fn ir_function_to_dsl(input: Vec<Expr>, function: IRFunctionExpr) -> Expr {
                 IB::Size => B::Size,
                 #[cfg(feature = "binary_encoding")]
                 IB::Reinterpret(data_type, v) => B::Reinterpret(data_type.into(), v),
+                #[cfg(feature = "binary_compression")]
+                IB::Compress { codec, level } => B::Compress { codec, level },
+                #[cfg(feature = "binary_compression")]
+                IB::Decompress { codec, strict } => B::Decompress { codec, strict },
             })
         },
         #[cfg(feature = "dtype-categorical")]
@@ -424,6 +460,24 @@ pub fn ir_function_to_dsl(input: Vec<Expr>, function: IRFunctionExpr) -> Expr {
                 IB::Normalize { form } => B::Normalize { form },
                 #[cfg(feature = "string_reverse")]
                 IB::Reverse => B::Reverse,
+                #[cfg(feature = "string_similarity")]
+                IB::Levenshtein => B::Levenshtein,
+                #[cfg(feature = "string_similarity")]
+                IB::DamerauLevenshtein => B::DamerauLevenshtein,
+                #[cfg(feature = "string_similarity")]
+                IB::Hamming => B::Hamming,
+                #[cfg(feature = "string_similarity")]
+                IB::JaroWinkler => B::JaroWinkler,
+                #[cfg(feature = "string_similarity")]
+                IB::NgramJaccard(n) => B::NgramJaccard(n),
+                #[cfg(feature = "string_url")]
+                IB::UrlParse { strict } => B::UrlParse { strict },
+                #[cfg(feature = "string_url")]
+                IB::UrlQueryParam { name, strict } => B::UrlQueryParam { name, strict },
+                #[cfg(feature = "string_url")]
+                IB::PercentDecode { strict } => B::PercentDecode { strict },
+                #[cfg(feature = "string_url")]
+                IB::PercentEncode => B::PercentEncode,
                 #[cfg(feature = "string_pad")]
                 IB::PadStart { fill_char } => B::PadStart { fill_char },
                 #[cfg(feature = "string_pad")]
@@ -454,6 +508,8 @@ pub fn ir_function_to_dsl(input: Vec<Expr>, function: IRFunctionExpr) -> Expr {
                     B::Strptime(dtype.into(), strptime_options)
                 },
                 IB::Split(v) => B::Split(v),
+                #[cfg(feature = "regex")]
+                IB::SplitRegex { inclusive, limit } => B::SplitRegex { inclusive, limit },
                 #[cfg(feature = "dtype-decimal")]
                 IB::ToDecimal { scale } => B::ToDecimal { scale },
                 #[cfg(feature = "nightly")]
@@ -687,6 +743,8 @@ pub fn ir_function_to_dsl(input: Vec<Expr>, function: IRFunctionExpr) -> Expr {
         },
         #[cfg(feature = "row_hash")]
         IF::Hash(s0, s1, s2, s3) => F::Hash(s0, s1, s2, s3),
+        #[cfg(feature = "stable_hash")]
+        IF::StableHash(algorithm) => F::StableHash(algorithm),
         #[cfg(feature = "arg_where")]
         IF::ArgWhere => F::ArgWhere,
         #[cfg(feature = "index_of")]
@@ -785,6 +843,12 @@ pub fn ir_function_to_dsl(input: Vec<Expr>, function: IRFunctionExpr) -> Expr {
                     IR::Quantile => R::Quantile,
                     IR::Var => R::Var,
                     IR::Std => R::Std,
+                    IR::Rank => R::Rank,
+                    IR::NUnique => R::NUnique,
+                    IR::ArgMin => R::ArgMin,
+                    IR::ArgMax => R::ArgMax,
+                    IR::Mode => R::Mode,
+                    IR::Product => R::Product,
                     #[cfg(feature = "moment")]
                     IR::Skew => R::Skew,
                     #[cfg(feature = "moment")]
@@ -817,6 +881,12 @@ pub fn ir_function_to_dsl(input: Vec<Expr>, function: IRFunctionExpr) -> Expr {
                     IR::QuantileBy => R::QuantileBy,
                     IR::VarBy => R::VarBy,
                     IR::StdBy => R::StdBy,
+                    IR::RankBy => R::RankBy,
+                    IR::NUniqueBy => R::NUniqueBy,
+                    IR::ArgMinBy => R::ArgMinBy,
+                    IR::ArgMaxBy => R::ArgMaxBy,
+                    IR::ModeBy => R::ModeBy,
+                    IR::ProductBy => R::ProductBy,
                 },
                 options,
             }
@@ -894,7 +964,7 @@ pub fn ir_function_to_dsl(input: Vec<Expr>, function: IRFunctionExpr) -> Expr {
         #[cfg(feature = "interpolate")]
         IF::Interpolate(m) => F::Interpolate(m),
         #[cfg(feature = "interpolate_by")]
-        IF::InterpolateBy => F::InterpolateBy,
+        IF::InterpolateBy(options) => F::InterpolateBy(options),
         #[cfg(feature = "log")]
         IF::Entropy { base, normalize } => F::Entropy { base, normalize },
         #[cfg(feature = "log")]
@@ -4,12 +4,14 @@ use arrow::io::ipc::read::get_row_count as count_rows_ipc_sync;
     feature = "parquet",
     feature = "ipc",
     feature = "json",
-    feature = "csv"
+    feature = "csv",
+    feature = "fwf",
+    feature = "orc"
 ))]
 use polars_core::error::feature_gated;
 #[cfg(any(feature = "json", feature = "parquet"))]
 use polars_io::SerReader;
-#[cfg(any(feature = "parquet", feature = "json"))]
+#[cfg(any(feature = "parquet", feature = "json", feature = "orc"))]
 use polars_io::cloud::CloudOptions;
 #[cfg(feature = "parquet")]
 use polars_io::parquet::read::ParquetReader;
@@ -30,7 +32,9 @@ pub fn count_rows(
         feature = "parquet",
         feature = "ipc",
         feature = "json",
-        feature = "csv"
+        feature = "csv",
+        feature = "fwf",
+        feature = "orc"
     )))]
     {
         unreachable!()
@@ -40,7 +44,9 @@ pub fn count_rows(
         feature = "parquet",
         feature = "ipc",
         feature = "json",
-        feature = "csv"
+        feature = "csv",
+        feature = "fwf",
+        feature = "orc"
     ))]
     {
         let count: PolarsResult<usize> = match scan_type {
@@ -57,6 +63,13 @@ pub fn count_rows(
             ),
             #[cfg(feature = "json")]
             FileScanIR::NDJson { options } => count_rows_ndjson(sources, cloud_options),
+            #[cfg(feature = "fwf")]
+            FileScanIR::Fwf { options } => count_rows_fwf(sources, options),
+            #[cfg(feature = "orc")]
+            FileScanIR::Orc {
+                options: _,
+                metadata,
+            } => count_rows_orc(sources, cloud_options, metadata.as_deref()),
             #[cfg(feature = "python")]
             FileScanIR::PythonDataset { .. } => unreachable!(),
             FileScanIR::Anonymous { .. } => {
@@ -77,26 +90,39 @@ fn count_all_rows_csv(
     sources: &ScanSources,
     options: &polars_io::prelude::CsvReadOptions,
 ) -> PolarsResult<usize> {
+    use polars_io::csv::read::encoding::maybe_transcode_bytes;
+    use polars_io::utils::compression::maybe_decompress_bytes;
+
     let parse_options = options.get_parse_options();
 
     sources
         .iter()
         .map(|source| match source {
-            ScanSourceRef::Path(addr) => polars_io::csv::read::count_rows(
-                addr,
-                parse_options.quote_char,
-                parse_options.comment_prefix.as_ref(),
-                parse_options.eol_char,
-                options.has_header,
-                options.skip_lines,
-                options.skip_rows,
-                options.skip_rows_after_header,
-            ),
+            // Lines can only be counted on UTF-8 data.
+            ScanSourceRef::Path(addr) if !parse_options.encoding.requires_transcoding() => {
+                polars_io::csv::read::count_rows(
+                    addr,
+                    parse_options.quote_char,
+                    parse_options.comment_prefix.as_ref(),
+                    parse_options.eol_char,
+                    options.has_header,
+                    options.skip_lines,
+                    options.skip_rows,
+                    options.skip_rows_after_header,
+                )
+            },
             _ => {
                 let memslice = source.to_memslice()?;
+                let owned = &mut vec![];
+                let transcoded = &mut vec![];
+                let bytes = maybe_transcode_bytes(
+                    maybe_decompress_bytes(&memslice[..], owned)?,
+                    transcoded,
+                    parse_options.encoding,
+                )?;
 
                 polars_io::csv::read::count_rows_from_slice_par(
-                    &memslice[..],
+                    bytes,
                     parse_options.quote_char,
                     parse_options.comment_prefix.as_ref(),
                     parse_options.eol_char,
@@ -110,6 +136,68 @@ fn count_all_rows_csv(
         .sum()
 }
 
+#[cfg(feature = "fwf")]
+fn count_rows_fwf(
+    sources: &ScanSources,
+    options: &polars_io::fwf::FwfReadOptions,
+) -> PolarsResult<usize> {
+    use polars_io::fwf::count_lines;
+    use polars_io::utils::compression::maybe_decompress_bytes;
+
+    sources
+        .iter()
+        .map(|source| {
+            let memslice = source.to_memslice()?;
+            let owned = &mut vec![];
+            let bytes = maybe_decompress_bytes(&memslice[..], owned)?;
+
+            Ok(count_lines(bytes, options.eol_char).saturating_sub(options.skip_rows))
+        })
+        .sum()
+}
+
+#[cfg(feature = "orc")]
+fn count_rows_orc(
+    sources: &ScanSources,
+    #[allow(unused)] cloud_options: Option<&CloudOptions>,
+    metadata: Option<&polars_io::orc::OrcMetadata>,
+) -> PolarsResult<usize> {
+    if sources.is_empty() {
+        return Ok(0);
+    };
+    let run_async = sources.is_cloud_url();
+
+    let cache_entries = if run_async {
+        feature_gated!("cloud", {
+            Some(polars_io::file_cache::init_entries_from_uri_list(
+                sources
+                    .as_paths()
+                    .unwrap()
+                    .iter()
+                    .map(|path| Arc::from(path.to_str())),
+                cloud_options,
+            )?)
+        })
+    } else {
+        None
+    };
+
+    // The row count is stored in the file footer.
+    (0..sources.len())
+        .map(|i| match metadata {
+            Some(md) if i == 0 => Ok(md.num_rows()),
+            _ => {
+                let memslice = sources.at(i).to_memslice_possibly_async(
+                    run_async,
+                    cache_entries.as_ref(),
+                    i,
+                )?;
+                Ok(polars_io::orc::read_metadata(&memslice)?.num_rows())
+            },
+        })
+        .sum()
+}
+
 #[cfg(feature = "parquet")]
 pub(super) fn count_rows_parquet(
     sources: &ScanSources,
@@ -18,6 +18,24 @@ pub struct OpaquePythonUdf {
     pub validate_output: bool,
 }
 
+/// A map function that is looked up by name in the
+/// [`PlanRegistry`](crate::dsl::named_serde::PlanRegistry) when the plan is converted to IR, so
+/// that it can be serialized.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+#[derive(Clone)]
+pub struct NamedUdf {
+    pub name: PlSmallStr,
+    pub payload: Vec<u8>,
+    /// The output schema, or `None` if it is equal to the input schema.
+    pub schema: Option<SchemaRef>,
+    ///  allow predicate pushdown optimizations
+    pub predicate_pd: bool,
+    ///  allow projection pushdown optimizations
+    pub projection_pd: bool,
+    pub streamable: bool,
+}
+
 // Except for Opaque functions, this only has the DSL name of the function.
 #[derive(Clone, IntoStaticStr)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -31,6 +49,7 @@ pub enum DslFunction {
     // This is both in DSL and IR because we want to be able to serialize it.
     #[cfg(feature = "python")]
     OpaquePython(OpaquePythonUdf),
+    Named(NamedUdf),
     Explode {
         columns: Selector,
         allow_empty: bool,
@@ -126,6 +145,27 @@ impl DslFunction {
             },
             #[cfg(feature = "python")]
             DslFunction::OpaquePython(inner) => FunctionIR::OpaquePython(inner),
+            DslFunction::Named(NamedUdf {
+                name,
+                payload,
+                schema,
+                predicate_pd,
+                projection_pd,
+                streamable,
+            }) => {
+                let function = crate::dsl::named_serde::get_named_map_function(&name, &payload)?;
+                let schema = schema.map(|schema| {
+                    Arc::new(move |_: &Schema| Ok(schema.clone())) as Arc<dyn UdfSchema>
+                });
+                FunctionIR::Opaque {
+                    function,
+                    schema,
+                    predicate_pd,
+                    projection_pd,
+                    streamable,
+                    fmt_str: name,
+                }
+            },
             DslFunction::Stats(_)
             | DslFunction::FillNan(_)
             | DslFunction::Rename { .. }
@@ -149,6 +189,7 @@ impl Display for DslFunction {
         use DslFunction::*;
         match self {
             FunctionIR(inner) => write!(f, "{inner}"),
+            Named(inner) => write!(f, "{}", inner.name),
             v => {
                 let s: &str = v.into();
                 write!(f, "{s}")
@@ -169,7 +169,14 @@ fn visit_logical_plan_for_scan_paths(
                 #[cfg(feature = "csv")]
                 FileScanIR::Csv { .. } => true,
                 _ => false,
-            });
+            }) && match scan_type.as_ref() {
+                // Bad records are only found by parsing, counting the lines would include them.
+                #[cfg(feature = "csv")]
+                FileScanIR::Csv { options } => options.bad_records.is_none(),
+                #[cfg(feature = "json")]
+                FileScanIR::NDJson { options } => options.bad_records.is_none(),
+                _ => true,
+            };
 
             if use_fast_file_count {
                 Some(CountStarExpr {
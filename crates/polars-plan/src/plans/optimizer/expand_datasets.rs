@@ -164,6 +164,8 @@ pub(super) fn expand_datasets(
                         cached_ir: _,
                     } => {
                         use crate::dsl::FileScanDsl;
+                        use crate::dsl::named_serde::get_named_scan;
+                        use crate::prelude::AnonymousScanOptions;
 
                         // We only want a few configuration flags from here (e.g. column casting config).
                         // The rest we either expect to be None (e.g. projection / row_index), or ignore.
@@ -186,6 +188,7 @@ pub(super) fn expand_datasets(
                             include_file_paths: _include_file_paths @ None,
                             deletion_files,
                             table_statistics,
+                            scan_metrics: _,
                         } = resolved_unified_scan_args.as_ref()
                         else {
                             panic!(
@@ -262,9 +265,18 @@ pub(super) fn expand_datasets(
                                 metadata: None,
                             },
 
+                            #[cfg(feature = "orc")]
+                            FileScanDsl::Orc { options } => FileScanIR::Orc {
+                                options,
+                                metadata: None,
+                            },
+
                             #[cfg(feature = "json")]
                             FileScanDsl::NDJson { options } => FileScanIR::NDJson { options },
 
+                            #[cfg(feature = "fwf")]
+                            FileScanDsl::Fwf { options } => FileScanIR::Fwf { options },
+
                             #[cfg(feature = "python")]
                             FileScanDsl::PythonDataset { dataset_object } => {
                                 FileScanIR::PythonDataset {
@@ -278,6 +290,18 @@ pub(super) fn expand_datasets(
                                 function,
                                 file_info: _,
                             } => FileScanIR::Anonymous { options, function },
+
+                            FileScanDsl::NamedAnonymous {
+                                name,
+                                payload,
+                                skip_rows,
+                            } => FileScanIR::Anonymous {
+                                options: Arc::new(AnonymousScanOptions {
+                                    skip_rows,
+                                    fmt_str: "NAMED SCAN",
+                                }),
+                                function: get_named_scan(&name, &payload)?,
+                            },
                         });
                     },
 
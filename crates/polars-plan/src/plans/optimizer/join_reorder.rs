@@ -0,0 +1,472 @@
+//! Cost-based reordering of chains of inner joins.
+//!
+//! A chain of inner joins `base.join(d1).join(d2)...` is executed in the order in which the user
+//! wrote it. For star-schema queries, where a large fact table is joined with many (filtered)
+//! dimension tables, the order matters a lot: joining the most selective dimensions first shrinks
+//! the intermediate results for all following joins.
+//!
+//! This pass estimates the number of rows of every input and greedily picks the join order that
+//! keeps the intermediate results the smallest. Only the right-hand inputs of the chain are
+//! reordered, the base keeps providing the coalesced key columns, so the output is the same up to
+//! row order, which inner joins don't guarantee unless requested.
+use std::sync::Arc;
+
+use polars_core::prelude::*;
+use polars_ops::frame::{JoinType, MaintainOrderJoin};
+use polars_utils::arena::{Arena, Node};
+use recursive::recursive;
+
+use crate::plans::aexpr::{AExpr, IRBooleanFunction, IRFunctionExpr};
+use crate::plans::ir::IR;
+use crate::plans::schema::det_join_schema;
+use crate::plans::{ExprIR, Operator};
+use crate::prelude::JoinOptionsIR;
+
+/// Selectivity of a predicate that is not understood.
+const DEFAULT_SELECTIVITY: f64 = 0.5;
+/// Selectivity of an equality predicate.
+const EQ_SELECTIVITY: f64 = 0.1;
+/// Selectivity of a single range predicate (e.g. `a < 5`).
+const RANGE_SELECTIVITY: f64 = 1.0 / 3.0;
+/// Selectivity of a bounded range predicate (e.g. `a.is_between(1, 5)`).
+const BETWEEN_SELECTIVITY: f64 = 0.25;
+/// Selectivity of an `is_in` predicate.
+const IS_IN_SELECTIVITY: f64 = 0.2;
+
+/// Estimated size of the output of a plan.
+#[derive(Clone, Copy, Debug)]
+pub(super) struct RowEstimate {
+    /// Estimated number of rows, `usize::MAX` if nothing is known.
+    pub estimate: usize,
+    /// Estimated fraction of the rows of the sources that pass the filters in the plan.
+    pub selectivity: f64,
+}
+
+impl RowEstimate {
+    fn exact(n: usize) -> Self {
+        Self {
+            estimate: n,
+            selectivity: 1.0,
+        }
+    }
+
+    fn unknown() -> Self {
+        Self {
+            estimate: usize::MAX,
+            selectivity: 1.0,
+        }
+    }
+
+    fn scale(n: usize, factor: f64) -> usize {
+        (n as f64 * factor) as usize
+    }
+
+    fn filter(self, selectivity: f64) -> Self {
+        Self {
+            estimate: Self::scale(self.estimate, selectivity),
+            selectivity: self.selectivity * selectivity,
+        }
+    }
+
+    fn limit(self, len: usize) -> Self {
+        Self {
+            estimate: self.estimate.min(len),
+            selectivity: self.selectivity,
+        }
+    }
+
+    /// Estimate of an inner join.
+    ///
+    /// We assume a foreign key join, where every row of the larger side matches at most one row of
+    /// the smaller side. Rows of the larger side only survive if their match passed the filters
+    /// of the smaller side.
+    fn inner_join(self, other: Self) -> Self {
+        let (large, small) = if self.estimate >= other.estimate {
+            (self, other)
+        } else {
+            (other, self)
+        };
+
+        Self {
+            estimate: Self::scale(large.estimate, small.selectivity),
+            selectivity: large.selectivity * small.selectivity,
+        }
+    }
+}
+
+/// Estimate the fraction of rows that pass `predicate`.
+#[recursive]
+fn predicate_selectivity(predicate: Node, expr_arena: &Arena<AExpr>) -> f64 {
+    let selectivity = match expr_arena.get(predicate) {
+        AExpr::BinaryExpr { left, op, right } => {
+            use Operator as O;
+
+            match op {
+                O::And | O::LogicalAnd => {
+                    predicate_selectivity(*left, expr_arena)
+                        * predicate_selectivity(*right, expr_arena)
+                },
+                O::Or | O::LogicalOr => {
+                    let l = predicate_selectivity(*left, expr_arena);
+                    let r = predicate_selectivity(*right, expr_arena);
+                    l + r - l * r
+                },
+                O::Eq | O::EqValidity => EQ_SELECTIVITY,
+                O::NotEq | O::NotEqValidity => 1.0 - EQ_SELECTIVITY,
+                O::Lt | O::LtEq | O::Gt | O::GtEq => RANGE_SELECTIVITY,
+                _ => DEFAULT_SELECTIVITY,
+            }
+        },
+        AExpr::Function {
+            input,
+            function: IRFunctionExpr::Boolean(function),
+            ..
+        } => {
+            use IRBooleanFunction as B;
+
+            match function {
+                B::Not => 1.0 - predicate_selectivity(input[0].node(), expr_arena),
+                B::IsNull => EQ_SELECTIVITY,
+                B::IsNotNull => 1.0 - EQ_SELECTIVITY,
+                #[cfg(feature = "is_between")]
+                B::IsBetween { .. } => BETWEEN_SELECTIVITY,
+                #[cfg(feature = "is_in")]
+                B::IsIn { .. } => IS_IN_SELECTIVITY,
+                _ => DEFAULT_SELECTIVITY,
+            }
+        },
+        _ => DEFAULT_SELECTIVITY,
+    };
+
+    selectivity.clamp(0.0, 1.0)
+}
+
+/// Estimate the number of rows that `node` produces.
+#[recursive]
+pub(super) fn estimate_rows(
+    node: Node,
+    lp_arena: &Arena<IR>,
+    expr_arena: &Arena<AExpr>,
+) -> RowEstimate {
+    use IR::*;
+
+    let rec = |node: Node| estimate_rows(node, lp_arena, expr_arena);
+
+    match lp_arena.get(node) {
+        DataFrameScan { df, .. } => RowEstimate::exact(df.height()),
+        Scan {
+            sources,
+            file_info,
+            predicate,
+            unified_scan_args,
+            ..
+        } => {
+            // The file info is resolved from the first source.
+            let mut out = RowEstimate {
+                estimate: file_info
+                    .row_estimation
+                    .1
+                    .saturating_mul(sources.len().max(1)),
+                selectivity: 1.0,
+            };
+
+            if let Some(pre_slice) = &unified_scan_args.pre_slice {
+                out = out.limit(pre_slice.len());
+            }
+            if let Some(predicate) = predicate {
+                out = out.filter(predicate_selectivity(predicate.node(), expr_arena));
+            }
+
+            out
+        },
+        Filter { input, predicate } => {
+            rec(*input).filter(predicate_selectivity(predicate.node(), expr_arena))
+        },
+        Slice { input, len, .. } => rec(*input).limit(*len as usize),
+        Sort { input, slice, .. } => {
+            let out = rec(*input);
+            match slice {
+                Some((_, len)) => out.limit(*len),
+                None => out,
+            }
+        },
+        SimpleProjection { input, .. }
+        | Select { input, .. }
+        | HStack { input, .. }
+        | Cache { input, .. }
+        | ExtContext { input, .. }
+        | Sink { input, .. } => rec(*input),
+        // The input size is an upper bound.
+        GroupBy { input, .. } | Distinct { input, .. } | MapFunction { input, .. } => rec(*input),
+        Join {
+            input_left,
+            input_right,
+            options,
+            ..
+        } => {
+            let left = rec(*input_left);
+            let right = rec(*input_right);
+
+            match &options.args.how {
+                JoinType::Inner => left.inner_join(right),
+                JoinType::Left => left,
+                JoinType::Right => right,
+                JoinType::Full => RowEstimate {
+                    estimate: left.estimate.saturating_add(right.estimate),
+                    selectivity: 1.0,
+                },
+                JoinType::Cross => RowEstimate {
+                    estimate: left.estimate.saturating_mul(right.estimate),
+                    selectivity: left.selectivity * right.selectivity,
+                },
+                #[cfg(feature = "semi_anti_join")]
+                JoinType::Semi => left.filter(right.selectivity),
+                #[allow(unreachable_patterns)]
+                _ => left,
+            }
+        },
+        Union { inputs, .. } => {
+            inputs
+                .iter()
+                .map(|i| rec(*i))
+                .fold(RowEstimate::exact(0), |acc, e| RowEstimate {
+                    estimate: acc.estimate.saturating_add(e.estimate),
+                    selectivity: acc.selectivity.min(e.selectivity),
+                })
+        },
+        HConcat { inputs, .. } => inputs
+            .iter()
+            .map(|i| rec(*i))
+            .max_by_key(|e| e.estimate)
+            .unwrap_or(RowEstimate::exact(0)),
+        _ => RowEstimate::unknown(),
+    }
+}
+
+/// An inner join of a chain, without its left input.
+struct ChainLink {
+    input_right: Node,
+    left_on: Vec<ExprIR>,
+    right_on: Vec<ExprIR>,
+    options: Arc<JoinOptionsIR>,
+}
+
+fn is_reorderable_join(
+    left_on: &[ExprIR],
+    right_on: &[ExprIR],
+    options: &JoinOptionsIR,
+    expr_arena: &Arena<AExpr>,
+) -> bool {
+    let args = &options.args;
+
+    matches!(args.how, JoinType::Inner)
+        && options.options.is_none()
+        && args.slice.is_none()
+        && args.maintain_order == MaintainOrderJoin::None
+        && !args.validation.needs_checks()
+        && args.should_coalesce()
+        && left_on
+            .iter()
+            .chain(right_on)
+            .all(|e| matches!(expr_arena.get(e.node()), AExpr::Column(_)))
+}
+
+fn column_name<'a>(e: &ExprIR, expr_arena: &'a Arena<AExpr>) -> &'a PlSmallStr {
+    let AExpr::Column(name) = expr_arena.get(e.node()) else {
+        unreachable!()
+    };
+    name
+}
+
+/// Reorder the chains of inner joins in the plan. Returns the number of chains that were
+/// reordered.
+pub(super) fn reorder_joins(
+    root: Node,
+    lp_arena: &mut Arena<IR>,
+    expr_arena: &Arena<AExpr>,
+) -> PolarsResult<usize> {
+    let mut n_reordered = 0;
+    let mut stack = vec![root];
+
+    while let Some(node) = stack.pop() {
+        let ir = lp_arena.get(node);
+
+        let IR::Join {
+            left_on,
+            right_on,
+            options,
+            ..
+        } = ir
+        else {
+            ir.copy_inputs(&mut stack);
+            continue;
+        };
+
+        if !is_reorderable_join(left_on, right_on, options, expr_arena) {
+            ir.copy_inputs(&mut stack);
+            continue;
+        }
+
+        // Collect the chain from the top down.
+        let mut links = Vec::new();
+        let mut base = node;
+        while let IR::Join {
+            input_left,
+            input_right,
+            left_on,
+            right_on,
+            options,
+            ..
+        } = lp_arena.get(base)
+        {
+            if !is_reorderable_join(left_on, right_on, options, expr_arena) {
+                break;
+            }
+
+            links.push(ChainLink {
+                input_right: *input_right,
+                left_on: left_on.clone(),
+                right_on: right_on.clone(),
+                options: options.clone(),
+            });
+            base = *input_left;
+        }
+        links.reverse();
+
+        stack.push(base);
+        stack.extend(links.iter().map(|l| l.input_right));
+
+        if links.len() > 1 && reorder_chain(node, base, links, lp_arena, expr_arena)? {
+            n_reordered += 1;
+        }
+    }
+
+    Ok(n_reordered)
+}
+
+/// Reorder a single chain of inner joins. `top` is replaced with the reordered chain if a better
+/// order is found.
+fn reorder_chain(
+    top: Node,
+    base: Node,
+    links: Vec<ChainLink>,
+    lp_arena: &mut Arena<IR>,
+    expr_arena: &Arena<AExpr>,
+) -> PolarsResult<bool> {
+    let base_schema = lp_arena.get(base).schema(lp_arena).into_owned();
+
+    // Index of the input that provides each column, `None` for the base.
+    let mut column_origin: PlHashMap<PlSmallStr, Option<usize>> = base_schema
+        .iter_names()
+        .map(|name| (name.clone(), None))
+        .collect();
+
+    for (i, link) in links.iter().enumerate() {
+        let right_schema = lp_arena.get(link.input_right).schema(lp_arena);
+
+        for name in right_schema.iter_names() {
+            // Coalesced into the left key column.
+            if link
+                .right_on
+                .iter()
+                .any(|e| column_name(e, expr_arena) == name)
+            {
+                continue;
+            }
+
+            // Duplicate names get a suffix that depends on the join order.
+            if column_origin.insert(name.clone(), Some(i)).is_some() {
+                return Ok(false);
+            }
+        }
+    }
+
+    // The inputs that have to be joined before each link to provide its left keys.
+    let mut dependencies = Vec::with_capacity(links.len());
+    for link in &links {
+        let mut deps = Vec::new();
+        for e in &link.left_on {
+            match column_origin.get(column_name(e, expr_arena)) {
+                Some(Some(i)) => deps.push(*i),
+                Some(None) => {},
+                None => return Ok(false),
+            }
+        }
+        dependencies.push(deps);
+    }
+
+    let link_estimates = links
+        .iter()
+        .map(|l| estimate_rows(l.input_right, lp_arena, expr_arena))
+        .collect::<Vec<_>>();
+
+    // Greedily join the input that keeps the intermediate result the smallest. Ties keep the
+    // original order.
+    let mut order = Vec::with_capacity(links.len());
+    let mut joined = vec![false; links.len()];
+    let mut acc = estimate_rows(base, lp_arena, expr_arena);
+
+    while order.len() < links.len() {
+        let (next, out) = (0..links.len())
+            .filter(|&i| !joined[i] && dependencies[i].iter().all(|&d| joined[d]))
+            .map(|i| (i, acc.inner_join(link_estimates[i])))
+            .min_by_key(|(i, out)| (out.estimate, *i))
+            .unwrap();
+
+        joined[next] = true;
+        order.push(next);
+        acc = out;
+    }
+
+    if order.iter().copied().eq(0..links.len()) {
+        return Ok(false);
+    }
+
+    let output_schema = lp_arena.get(top).schema(lp_arena).into_owned();
+
+    let mut links = links.into_iter().map(Some).collect::<Vec<_>>();
+    let mut input_left = base;
+    let mut left_schema = base_schema;
+
+    for i in order {
+        let ChainLink {
+            input_right,
+            left_on,
+            right_on,
+            options,
+        } = links[i].take().unwrap();
+        let right_schema = lp_arena.get(input_right).schema(lp_arena).into_owned();
+
+        let schema = det_join_schema(
+            &left_schema,
+            &right_schema,
+            &left_on,
+            &right_on,
+            &options,
+            expr_arena,
+        )?;
+
+        input_left = lp_arena.add(IR::Join {
+            input_left,
+            input_right,
+            schema: schema.clone(),
+            left_on,
+            right_on,
+            options,
+        });
+        left_schema = schema;
+    }
+
+    // Restore the original column order.
+    let reordered = if left_schema.iter_names().eq(output_schema.iter_names()) {
+        lp_arena.take(input_left)
+    } else {
+        IR::SimpleProjection {
+            input: input_left,
+            columns: output_schema,
+        }
+    };
+    lp_arena.replace(top, reordered);
+
+    Ok(true)
+}
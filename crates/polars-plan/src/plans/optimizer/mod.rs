@@ -14,7 +14,9 @@ mod cse;
 mod flatten_union;
 #[cfg(feature = "fused")]
 mod fused;
+mod join_reorder;
 mod join_utils;
+mod partition_wise;
 pub(crate) use join_utils::ExprOrigin;
 mod expand_datasets;
 #[cfg(feature = "python")]
@@ -25,6 +27,7 @@ pub mod set_order;
 mod simplify_expr;
 mod slice_pushdown_expr;
 mod slice_pushdown_lp;
+mod sorted_input;
 mod stack_opt;
 
 use collapse_and_project::SimpleProjectionAndCollapse;
@@ -38,6 +41,7 @@ pub use predicate_pushdown::PredicatePushDown;
 pub use projection_pushdown::ProjectionPushDown;
 pub use simplify_expr::{SimplifyBooleanRule, SimplifyExprRule};
 use slice_pushdown_lp::SlicePushDown;
+use sorted_input::SortedInputRule;
 pub use stack_opt::{OptimizationRule, OptimizeExprContext, StackOptimizer};
 
 use self::flatten_union::FlattenUnionRule;
@@ -180,6 +184,17 @@ pub fn optimize(
         lp_arena.replace(lp_top, alp);
     }
 
+    // Run after predicate pushdown, so that the filters are close to the sources they estimate.
+    if opt_flags.contains(OptFlags::ROW_ESTIMATE)
+        && !opt_flags.eager()
+        && get_or_init_members!().has_joins_or_unions
+    {
+        let n_reordered = join_reorder::reorder_joins(lp_top, lp_arena, expr_arena)?;
+        if verbose && n_reordered > 0 {
+            eprintln!("reordered {n_reordered} chain(s) of inner joins based on row estimates")
+        }
+    }
+
     // Make sure its before slice pushdown.
     if opt_flags.fast_projection() {
         rules.push(Box::new(SimpleProjectionAndCollapse::new(
@@ -217,6 +232,7 @@ pub fn optimize(
 
     if !opt_flags.eager() {
         rules.push(Box::new(FlattenUnionRule {}));
+        rules.push(Box::new(SortedInputRule {}));
     }
 
     lp_top = opt.optimize_loop(&mut rules, expr_arena, lp_arena, lp_top)?;
@@ -225,6 +241,16 @@ pub fn optimize(
         cluster_with_columns::optimize(lp_top, lp_arena, expr_arena)
     }
 
+    if opt_flags.contains(OptFlags::PARTITION_WISE)
+        && !opt_flags.eager()
+        && (get_or_init_members!().has_joins_or_unions || get_or_init_members!().has_group_by)
+    {
+        let n_rewritten = partition_wise::partition_wise(lp_top, lp_arena, expr_arena)?;
+        if verbose && n_rewritten > 0 {
+            eprintln!("executing {n_rewritten} join(s)/group-by(s) partition-by-partition")
+        }
+    }
+
     if _cse_plan_changed
         && get_members_opt!().is_some_and(|members| {
             (members.has_joins_or_unions | members.has_sink_multiple) && members.has_cache
@@ -0,0 +1,383 @@
+//! Partition-wise execution of joins and group-bys on hive-partitioned scans.
+//!
+//! If the keys of a group-by include hive partition columns, every group lies within the files
+//! of a single partition. Likewise, if an inner join matches the partition columns of two
+//! hive-partitioned datasets, rows can only join with rows from the matching partition of the
+//! other side. Such operations are rewritten into a sequential union of the same operation on
+//! every partition (or matching pair of partitions), so that only one file set is processed at a
+//! time. This bounds the memory of the hash tables and allows earlier output.
+//!
+//! The rewrite changes the output order, so it is only done if the operation doesn't maintain
+//! order.
+use polars_core::prelude::*;
+use polars_ops::frame::{JoinType, MaintainOrderJoin};
+use polars_utils::arena::{Arena, Node};
+use polars_utils::unitvec;
+
+use crate::dsl::{FileScanIR, ScanSources, UnionOptions};
+use crate::plans::ExprIR;
+use crate::plans::aexpr::{AExpr, is_elementwise_rec};
+use crate::plans::hive::HivePartitionsDf;
+use crate::plans::ir::IR;
+
+/// A chain of row-wise operations ending in a multi-file hive-partitioned scan.
+struct HiveChain {
+    /// Nodes from the top of the chain down to the scan, which is the last node.
+    nodes: Vec<Node>,
+}
+
+impl HiveChain {
+    /// Returns the chain if `node` only consists of row-wise operations that don't modify the
+    /// hive partition columns on top of a hive-partitioned scan of more than one file.
+    fn try_new(mut node: Node, lp_arena: &Arena<IR>, expr_arena: &Arena<AExpr>) -> Option<Self> {
+        let mut nodes = vec![];
+        loop {
+            nodes.push(node);
+            match lp_arena.get(node) {
+                IR::Filter { input, predicate } => {
+                    if !is_elementwise_rec(predicate.node(), expr_arena) {
+                        return None;
+                    }
+                    node = *input;
+                },
+                IR::SimpleProjection { input, .. } => node = *input,
+                IR::Select { input, expr, .. }
+                | IR::HStack {
+                    input, exprs: expr, ..
+                } => {
+                    if !expr
+                        .iter()
+                        .all(|e| is_elementwise_rec(e.node(), expr_arena))
+                    {
+                        return None;
+                    }
+                    node = *input;
+                },
+                IR::Scan {
+                    sources,
+                    hive_parts: Some(hive_parts),
+                    scan_type,
+                    unified_scan_args,
+                    ..
+                } => {
+                    let scan_type_supported = match scan_type.as_ref() {
+                        #[cfg(feature = "python")]
+                        FileScanIR::PythonDataset { .. } => false,
+                        FileScanIR::Anonymous { .. } => false,
+                        #[allow(unreachable_patterns)]
+                        _ => true,
+                    };
+                    if !scan_type_supported
+                        || !matches!(sources, ScanSources::Paths(_))
+                        || sources.len() < 2
+                        || hive_parts.df().height() != sources.len()
+                        || unified_scan_args.row_index.is_some()
+                        || unified_scan_args.pre_slice.is_some()
+                        || unified_scan_args.deletion_files.is_some()
+                        || unified_scan_args.table_statistics.is_some()
+                    {
+                        return None;
+                    }
+                    break;
+                },
+                _ => return None,
+            }
+        }
+
+        let chain = Self { nodes };
+        // The partition columns must reach the top of the chain unmodified.
+        let hive_schema = chain.hive_parts(lp_arena).schema().clone();
+        for &node in &chain.nodes {
+            if let IR::Select { expr, .. } | IR::HStack { exprs: expr, .. } = lp_arena.get(node) {
+                let modifies_partition_column = expr.iter().any(|e| {
+                    hive_schema.contains(e.output_name())
+                        && !matches!(
+                            expr_arena.get(e.node()),
+                            AExpr::Column(name) if name == e.output_name()
+                        )
+                });
+                if modifies_partition_column {
+                    return None;
+                }
+            }
+        }
+        Some(chain)
+    }
+
+    fn hive_parts<'a>(&self, lp_arena: &'a Arena<IR>) -> &'a HivePartitionsDf {
+        let IR::Scan {
+            hive_parts: Some(hive_parts),
+            ..
+        } = lp_arena.get(*self.nodes.last().unwrap())
+        else {
+            unreachable!()
+        };
+        hive_parts
+    }
+
+    /// Groups the files of the scan by the values of the partition columns `names`.
+    fn partitions(
+        &self,
+        names: &[PlSmallStr],
+        lp_arena: &Arena<IR>,
+    ) -> PolarsResult<PlIndexMap<Vec<AnyValue<'static>>, Vec<IdxSize>>> {
+        let df = self.hive_parts(lp_arena).df();
+        let columns = names
+            .iter()
+            .map(|name| df.column(name))
+            .collect::<PolarsResult<Vec<_>>>()?;
+
+        let mut partitions: PlIndexMap<_, Vec<IdxSize>> = PlIndexMap::default();
+        for i in 0..df.height() {
+            let key = columns
+                .iter()
+                .map(|c| c.get(i).map(|av| av.into_static()))
+                .collect::<PolarsResult<Vec<_>>>()?;
+            partitions.entry(key).or_default().push(i as IdxSize);
+        }
+        Ok(partitions)
+    }
+
+    /// Copies the chain, scanning only the files at `file_idxs`.
+    fn copy_for_files(&self, file_idxs: &[IdxSize], lp_arena: &mut Arena<IR>) -> Node {
+        let (scan, nodes) = self.nodes.split_last().unwrap();
+
+        let mut ir = lp_arena.get(*scan).clone();
+        let IR::Scan {
+            sources,
+            file_info,
+            hive_parts,
+            scan_type,
+            ..
+        } = &mut ir
+        else {
+            unreachable!()
+        };
+        let ScanSources::Paths(paths) = sources else {
+            unreachable!()
+        };
+        *sources = ScanSources::Paths(
+            file_idxs
+                .iter()
+                .map(|&i| paths[i as usize].clone())
+                .collect(),
+        );
+        *hive_parts = hive_parts.as_ref().map(|hp| hp.take_indices(file_idxs));
+        // The row estimation is per file and, like the cached metadata, resolved from the first
+        // file.
+        if file_idxs[0] != 0 {
+            file_info.row_estimation.0 = None;
+            clear_metadata(scan_type);
+        }
+
+        let mut node = lp_arena.add(ir);
+        for &n in nodes.iter().rev() {
+            let ir = lp_arena.get(n).clone().with_inputs([node]);
+            node = lp_arena.add(ir);
+        }
+        node
+    }
+}
+
+fn clear_metadata(scan_type: &mut FileScanIR) {
+    match scan_type {
+        #[cfg(feature = "parquet")]
+        FileScanIR::Parquet { metadata, .. } => *metadata = None,
+        #[cfg(feature = "ipc")]
+        FileScanIR::Ipc { metadata, .. } => *metadata = None,
+        #[cfg(feature = "orc")]
+        FileScanIR::Orc { metadata, .. } => *metadata = None,
+        _ => {},
+    }
+}
+
+/// Names of the key columns that are plain references to a column in `names`.
+fn key_columns<'a>(
+    keys: &'a [ExprIR],
+    names: &'a Schema,
+    expr_arena: &'a Arena<AExpr>,
+) -> impl Iterator<Item = (usize, &'a PlSmallStr)> + 'a {
+    keys.iter()
+        .enumerate()
+        .filter_map(move |(i, e)| match expr_arena.get(e.node()) {
+            AExpr::Column(name) if names.contains(name) => Some((i, name)),
+            _ => None,
+        })
+}
+
+fn sequential_union(inputs: Vec<Node>) -> IR {
+    IR::Union {
+        inputs,
+        options: UnionOptions {
+            parallel: false,
+            maintain_order: false,
+            ..Default::default()
+        },
+    }
+}
+
+fn try_rewrite_group_by(
+    node: Node,
+    lp_arena: &mut Arena<IR>,
+    expr_arena: &Arena<AExpr>,
+) -> PolarsResult<Option<IR>> {
+    let IR::GroupBy {
+        input,
+        keys,
+        maintain_order,
+        options,
+        apply,
+        ..
+    } = lp_arena.get(node)
+    else {
+        return Ok(None);
+    };
+    if *maintain_order
+        || apply.is_some()
+        || options.slice.is_some()
+        || options.is_dynamic()
+        || options.is_rolling()
+        || !keys
+            .iter()
+            .all(|e| is_elementwise_rec(e.node(), expr_arena))
+    {
+        return Ok(None);
+    }
+    let Some(chain) = HiveChain::try_new(*input, lp_arena, expr_arena) else {
+        return Ok(None);
+    };
+
+    let hive_schema = chain.hive_parts(lp_arena).schema();
+    let names = key_columns(keys, hive_schema, expr_arena)
+        .map(|(_, name)| name.clone())
+        .collect::<Vec<_>>();
+    if names.is_empty() {
+        return Ok(None);
+    }
+    let partitions = chain.partitions(&names, lp_arena)?;
+    if partitions.len() < 2 {
+        return Ok(None);
+    }
+
+    let group_by = lp_arena.get(node).clone();
+    let inputs = partitions
+        .values()
+        .map(|file_idxs| {
+            let input = chain.copy_for_files(file_idxs, lp_arena);
+            lp_arena.add(group_by.clone().with_inputs([input]))
+        })
+        .collect();
+    Ok(Some(sequential_union(inputs)))
+}
+
+fn try_rewrite_join(
+    node: Node,
+    lp_arena: &mut Arena<IR>,
+    expr_arena: &Arena<AExpr>,
+) -> PolarsResult<Option<IR>> {
+    let IR::Join {
+        input_left,
+        input_right,
+        left_on,
+        right_on,
+        options,
+        ..
+    } = lp_arena.get(node)
+    else {
+        return Ok(None);
+    };
+    if !matches!(options.args.how, JoinType::Inner)
+        || options.args.slice.is_some()
+        || options.args.maintain_order != MaintainOrderJoin::None
+        || options.options.is_some()
+    {
+        return Ok(None);
+    }
+    let Some(left) = HiveChain::try_new(*input_left, lp_arena, expr_arena) else {
+        return Ok(None);
+    };
+    let Some(right) = HiveChain::try_new(*input_right, lp_arena, expr_arena) else {
+        return Ok(None);
+    };
+
+    // Pairs of partition columns that are joined with each other.
+    let left_hive_schema = left.hive_parts(lp_arena).schema();
+    let right_hive_schema = right.hive_parts(lp_arena).schema();
+    let (left_names, right_names): (Vec<_>, Vec<_>) =
+        key_columns(left_on, left_hive_schema, expr_arena)
+            .filter_map(|(i, left_name)| match expr_arena.get(right_on[i].node()) {
+                AExpr::Column(right_name)
+                    if right_hive_schema.get(right_name) == left_hive_schema.get(left_name) =>
+                {
+                    Some((left_name.clone(), right_name.clone()))
+                },
+                _ => None,
+            })
+            .unzip();
+    if left_names.is_empty() {
+        return Ok(None);
+    }
+
+    let nulls_equal = options.args.nulls_equal;
+    let left_partitions = left.partitions(&left_names, lp_arena)?;
+    let mut right_partitions = right.partitions(&right_names, lp_arena)?;
+    let pairs = left_partitions
+        .into_iter()
+        .filter(|(key, _)| nulls_equal || !key.iter().any(|av| av.is_null()))
+        .filter_map(|(key, left_idxs)| Some((left_idxs, right_partitions.swap_remove(&key)?)))
+        .collect::<Vec<_>>();
+
+    let n_left_files = left.hive_parts(lp_arena).df().height();
+    let n_right_files = right.hive_parts(lp_arena).df().height();
+    match pairs.as_slice() {
+        // The result is empty, there is nothing to gain.
+        [] => return Ok(None),
+        [(left_idxs, right_idxs)]
+            if left_idxs.len() == n_left_files && right_idxs.len() == n_right_files =>
+        {
+            return Ok(None);
+        },
+        _ => {},
+    }
+
+    let join = lp_arena.get(node).clone();
+    let inputs = pairs
+        .iter()
+        .map(|(left_idxs, right_idxs)| {
+            let input_left = left.copy_for_files(left_idxs, lp_arena);
+            let input_right = right.copy_for_files(right_idxs, lp_arena);
+            lp_arena.add(join.clone().with_inputs([input_left, input_right]))
+        })
+        .collect();
+    Ok(Some(sequential_union(inputs)))
+}
+
+/// Rewrites joins and group-bys on hive-partitioned scans to be executed partition-by-partition.
+///
+/// Returns the number of rewritten operations.
+pub(super) fn partition_wise(
+    root: Node,
+    lp_arena: &mut Arena<IR>,
+    expr_arena: &Arena<AExpr>,
+) -> PolarsResult<usize> {
+    let mut n_rewritten = 0;
+    let mut stack = unitvec![root];
+
+    while let Some(node) = stack.pop() {
+        let rewritten = match lp_arena.get(node) {
+            IR::GroupBy { .. } => try_rewrite_group_by(node, lp_arena, expr_arena)?,
+            IR::Join { .. } => try_rewrite_join(node, lp_arena, expr_arena)?,
+            _ => None,
+        };
+        match rewritten {
+            // The inputs of the rewritten operation only consist of row-wise operations.
+            Some(ir) => {
+                lp_arena.replace(node, ir);
+                n_rewritten += 1;
+            },
+            None => lp_arena.get(node).copy_inputs(&mut stack),
+        }
+    }
+
+    Ok(n_rewritten)
+}
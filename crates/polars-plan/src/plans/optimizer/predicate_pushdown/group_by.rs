@@ -16,14 +16,21 @@ pub(super) fn process_group_by(
 ) -> PolarsResult<IR> {
     use IR::*;
 
+    // Rolling, dynamic and session group-bys compute their windows within the groups of the keys,
+    // so removing whole groups is fine, but filtering on the index column is not.
     #[cfg(feature = "dynamic_group_by")]
-    let no_push = { options.rolling.is_some() || options.dynamic.is_some() };
+    let index_column = match (&options.rolling, &options.dynamic, &options.session) {
+        (Some(rolling), _, _) => Some(&rolling.index_column),
+        (_, Some(dynamic), _) => Some(&dynamic.index_column),
+        (_, _, Some(session)) => Some(&session.index_column),
+        _ => None,
+    };
 
     #[cfg(not(feature = "dynamic_group_by"))]
-    let no_push = false;
+    let index_column: Option<&PlSmallStr> = None;
 
     // Don't pushdown predicates on these cases.
-    if apply.is_some() || no_push || options.slice.is_some() {
+    if apply.is_some() || options.slice.is_some() {
         let lp = GroupBy {
             input,
             keys,
@@ -36,33 +43,37 @@ pub(super) fn process_group_by(
         return opt.no_pushdown_restart_opt(lp, acc_predicates, lp_arena, expr_arena);
     }
 
-    // If the predicate only resolves to the keys we can push it down.
-    // When it filters the aggregations, the predicate should be done after aggregation.
-    let mut local_predicates = Vec::with_capacity(acc_predicates.len());
-    let key_schema = aexprs_to_schema(
-        &keys,
-        lp_arena.get(input).schema(lp_arena).as_ref(),
-        expr_arena,
-    );
+    // A predicate that only refers to keys which are (aliased) input columns filters whole groups,
+    // so it can be done before the aggregation. Keys that are computed can map different input
+    // values to the same group, so predicates on them are done after the aggregation.
+    let mut key_to_input_column = PlHashMap::with_capacity(keys.len());
+    for key in &keys {
+        if let AExpr::Column(name) = expr_arena.get(key.node())
+            && index_column != Some(name)
+        {
+            key_to_input_column.insert(key.output_name().clone(), name.clone());
+        }
+    }
+    let rename_map = key_to_input_column
+        .iter()
+        .filter(|(key, name)| key != name)
+        .map(|(key, name)| (key.clone(), name.clone()))
+        .collect::<PlHashMap<_, _>>();
 
+    let mut local_predicates = Vec::with_capacity(acc_predicates.len());
     let mut new_acc_predicates = PlHashMap::with_capacity(acc_predicates.len());
 
-    for (pred_name, predicate) in acc_predicates {
+    for (pred_name, mut predicate) in acc_predicates {
         // Counts change due to groupby's
-        // TODO! handle aliases, so that the predicate that is pushed down refers to the column before alias.
-        let mut push_down = !has_aexpr(predicate.node(), expr_arena, |ae| matches!(ae, AExpr::Len));
-
-        for name in aexpr_to_leaf_names_iter(predicate.node(), expr_arena) {
-            push_down &= key_schema.contains(name.as_ref());
+        let push_down = !has_aexpr(predicate.node(), expr_arena, |ae| matches!(ae, AExpr::Len))
+            && aexpr_to_leaf_names_iter(predicate.node(), expr_arena)
+                .all(|name| key_to_input_column.contains_key(&name));
 
-            if !push_down {
-                break;
-            }
-        }
         if !push_down {
             local_predicates.push(predicate)
         } else {
-            new_acc_predicates.insert(pred_name.clone(), predicate.clone());
+            map_column_references(&mut predicate, expr_arena, &rename_map);
+            new_acc_predicates.insert(pred_name, predicate);
         }
     }
 
@@ -196,6 +196,14 @@ pub(super) fn process_join(
             #[cfg(feature = "iejoin")]
             IEJoin => false,
 
+            // Fuzzy join keys only match approximately.
+            #[cfg(feature = "fuzzy_join")]
+            Fuzzy(_) => false,
+
+            // Nearest neighbours are not equal.
+            #[cfg(feature = "knn_join")]
+            Knn(_) => false,
+
             Cross => unreachable!(), // Cross left/right_on should be empty
         } {
             // Note: `lhs_input_key` maintains its name in the output column for all cases except
@@ -230,11 +238,20 @@ pub(super) fn process_join(
         init_hashmap(Some(acc_predicates.len()));
     let mut local_predicates = Vec::with_capacity(acc_predicates.len());
 
+    let score_name = options.args.how.score_field().map(|field| field.name);
+
     for (_, predicate) in acc_predicates {
         let mut push_left = true;
         let mut push_right = true;
 
         for col_name in aexpr_to_leaf_names_iter(predicate.node(), expr_arena) {
+            // The score column is created by the join itself.
+            if score_name.as_ref() == Some(&col_name) {
+                push_left = false;
+                push_right = false;
+                continue;
+            }
+
             let origin: ExprOrigin = ExprOrigin::get_column_origin(
                 col_name.as_str(),
                 &schema_left,
@@ -314,6 +331,18 @@ pub(super) fn process_join(
             // Same as inner-join.
             #[cfg(feature = "iejoin")]
             JoinType::IEJoin => !(push_left || push_right),
+
+            // Same as inner-join.
+            #[cfg(feature = "fuzzy_join")]
+            JoinType::Fuzzy(_) => !(push_left || push_right),
+
+            // Every left row is matched independently, but filtering the right table changes
+            // which rows are its nearest neighbours.
+            #[cfg(feature = "knn_join")]
+            JoinType::Knn(_) => {
+                push_right = false;
+                !push_left
+            },
         };
 
         if has_residual {
@@ -376,6 +376,8 @@ impl PredicatePushDown<'_> {
                     FileScanIR::Anonymous { function, .. } => function.allows_predicate_pushdown(),
                     #[cfg(feature = "json")]
                     FileScanIR::NDJson { .. } => true,
+                    #[cfg(feature = "fwf")]
+                    FileScanIR::Fwf { .. } => unified_scan_args.pre_slice.is_none(),
                     #[allow(unreachable_patterns)]
                     _ => true,
                 };
@@ -376,11 +376,10 @@ pub fn pushdown_eligibility(
 
     // Note: has_window is constant.
     let can_use_column = |col: &str| {
-        if has_window {
-            common_window_inputs.contains(col)
-        } else {
-            !modified_projection_columns.contains(col)
-        }
+        // A window partition key that is overwritten in the same projection is a different column
+        // in the predicate than in the window.
+        !modified_projection_columns.contains(col)
+            && (!has_window || common_window_inputs.contains(col))
     };
 
     // For an allocation-free dyn iterator
@@ -75,6 +75,12 @@ pub(super) fn process_group_by(
             let node = expr_arena.add(AExpr::Column(options.index_column.clone()));
             add_expr_to_accumulated(node, &mut acc_projections, &mut names, expr_arena);
         }
+        // make sure that the session key is projected
+        #[cfg(feature = "dynamic_group_by")]
+        if let Some(options) = &options.session {
+            let node = expr_arena.add(AExpr::Column(options.index_column.clone()));
+            add_expr_to_accumulated(node, &mut acc_projections, &mut names, expr_arena);
+        }
         let ctx = ProjectionContext::new(acc_projections, names, ctx.inner);
 
         proj_pd.pushdown_and_assign(input, ctx, lp_arena, expr_arena)?;
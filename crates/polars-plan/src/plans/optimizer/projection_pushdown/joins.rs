@@ -430,6 +430,15 @@ fn process_projection(
             }
             local_projection.push(proj);
         }
+        // The score column is created by the join itself.
+        else if options
+            .args
+            .how
+            .score_field()
+            .is_some_and(|field| field.name == leaf_column_name)
+        {
+            local_projection.push(proj);
+        }
     }
     // did succeed pushdown at least in any of the two tables
     // if not already added locally we ensure we project local as well
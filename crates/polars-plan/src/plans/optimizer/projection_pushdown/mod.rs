@@ -451,8 +451,12 @@ impl ProjectionPushDown {
                     FileScanIR::Anonymous { function, .. } => function.allows_projection_pushdown(),
                     #[cfg(feature = "json")]
                     FileScanIR::NDJson { .. } => true,
+                    #[cfg(feature = "fwf")]
+                    FileScanIR::Fwf { .. } => true,
                     #[cfg(feature = "ipc")]
                     FileScanIR::Ipc { .. } => true,
+                    #[cfg(feature = "orc")]
+                    FileScanIR::Orc { .. } => true,
                     #[cfg(feature = "csv")]
                     FileScanIR::Csv { .. } => true,
                     #[cfg(feature = "parquet")]
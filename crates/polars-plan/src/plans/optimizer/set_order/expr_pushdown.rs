@@ -279,7 +279,7 @@ pub fn get_frame_observing(
             evaluation: _,
             variant,
         } => match variant {
-            EvalVariant::List => rec!(*expr),
+            EvalVariant::List | EvalVariant::Array { .. } => rec!(*expr),
             EvalVariant::Cumulative { min_samples: _ } => {
                 let expr = rec!(*expr);
                 if expr.has_frame_ordering() {
@@ -227,12 +227,18 @@ impl SlicePushDown {
                 #[cfg(feature = "ipc")]
                 FileScanIR::Ipc { .. } => true,
 
+                #[cfg(feature = "orc")]
+                FileScanIR::Orc { .. } => true,
+
                 #[cfg(feature = "csv")]
                 FileScanIR::Csv { .. } => true,
 
                 #[cfg(feature = "json")]
                 FileScanIR::NDJson { .. } => true,
 
+                #[cfg(feature = "fwf")]
+                FileScanIR::Fwf { .. } => true,
+
                 #[cfg(feature = "python")]
                 FileScanIR::PythonDataset { .. } => true,
 
@@ -0,0 +1,211 @@
+//! Removes sorts of inputs that are already known to be sorted.
+use super::*;
+
+/// How a column is known to be sorted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Sortedness {
+    descending: bool,
+    /// `None` if the column is known to contain no nulls.
+    nulls_last: Option<bool>,
+}
+
+impl Sortedness {
+    fn satisfies(&self, descending: bool, nulls_last: bool) -> bool {
+        self.descending == descending && self.nulls_last.is_none_or(|nl| nl == nulls_last)
+    }
+}
+
+/// Whether the `i`-th column of a sort is descending and has its nulls last.
+fn sort_key_order(sort_options: &SortMultipleOptions, i: usize) -> (bool, bool) {
+    let nth = |v: &[bool]| *v.get(i).unwrap_or(&v[0]);
+    (nth(&sort_options.descending), nth(&sort_options.nulls_last))
+}
+
+/// Follow the columns `names` from the output of `node` back to where the rows are known to be
+/// sorted lexicographically by them, and return how each of them is sorted.
+///
+/// Sortedness is known from sorts, row indices and the metadata of single-file Parquet scans.
+fn sortedness_of(
+    mut node: Node,
+    mut names: Vec<PlSmallStr>,
+    lp_arena: &Arena<IR>,
+    expr_arena: &Arena<AExpr>,
+) -> Option<Vec<Sortedness>> {
+    const NO_NULLS_ASCENDING: Sortedness = Sortedness {
+        descending: false,
+        nulls_last: None,
+    };
+
+    loop {
+        match lp_arena.get(node) {
+            IR::Filter { input, .. }
+            | IR::Slice { input, .. }
+            | IR::Cache { input, .. }
+            | IR::SimpleProjection { input, .. } => node = *input,
+            IR::Select { input, expr, .. }
+            | IR::HStack {
+                input, exprs: expr, ..
+            } => {
+                for name in names.iter_mut() {
+                    match expr.iter().find(|e| e.output_name() == name) {
+                        Some(e) => match expr_arena.get(e.node()) {
+                            AExpr::Column(input_name) => *name = input_name.clone(),
+                            _ => return None,
+                        },
+                        None if matches!(lp_arena.get(node), IR::HStack { .. }) => {},
+                        None => return None,
+                    }
+                }
+                node = *input;
+            },
+            IR::MapFunction {
+                input,
+                function: FunctionIR::RowIndex { name: index, .. },
+            } => {
+                if names.as_slice() == [index.clone()] {
+                    return Some(vec![NO_NULLS_ASCENDING]);
+                }
+                if names.contains(index) {
+                    return None;
+                }
+                node = *input;
+            },
+            IR::Sort {
+                by_column,
+                sort_options,
+                ..
+            } => {
+                if by_column.len() < names.len() {
+                    return None;
+                }
+                return names
+                    .iter()
+                    .zip(by_column)
+                    .enumerate()
+                    .map(|(i, (name, by))| match expr_arena.get(by.node()) {
+                        AExpr::Column(sorted_name) if sorted_name == name => {
+                            let (descending, nulls_last) = sort_key_order(sort_options, i);
+                            Some(Sortedness {
+                                descending,
+                                nulls_last: Some(nulls_last),
+                            })
+                        },
+                        _ => None,
+                    })
+                    .collect();
+            },
+            IR::Scan {
+                sources,
+                file_info: _file_info,
+                hive_parts,
+                scan_type,
+                unified_scan_args,
+                ..
+            } => {
+                if let Some(ri) = &unified_scan_args.row_index {
+                    if names.as_slice() == [ri.name.clone()] {
+                        return Some(vec![NO_NULLS_ASCENDING]);
+                    }
+                    if names.contains(&ri.name) {
+                        return None;
+                    }
+                }
+                if sources.len() != 1
+                    || names.iter().any(|name| {
+                        hive_parts
+                            .as_ref()
+                            .is_some_and(|hive| hive.schema().contains(name))
+                            || unified_scan_args.include_file_paths.as_ref() == Some(name)
+                    })
+                {
+                    return None;
+                }
+
+                return match scan_type.as_ref() {
+                    #[cfg(feature = "parquet")]
+                    FileScanIR::Parquet {
+                        metadata: Some(md), ..
+                    } => {
+                        let columns = names
+                            .iter()
+                            .map(|name| Some((name.as_str(), _file_info.schema.get(name)?)))
+                            .collect::<Option<Vec<_>>>()?;
+                        let order = polars_io::prelude::get_file_sort_order(md, &columns)?;
+                        Some(
+                            order
+                                .into_iter()
+                                .map(|is_sorted| Sortedness {
+                                    descending: is_sorted
+                                        == polars_core::series::IsSorted::Descending,
+                                    nulls_last: None,
+                                })
+                                .collect(),
+                        )
+                    },
+                    _ => None,
+                };
+            },
+            _ => return None,
+        }
+    }
+}
+
+/// Replaces sorts on columns by which the input is already sorted in the requested order by their
+/// input.
+///
+/// Other operators consume sortedness at runtime rather than in the plan: the in-memory engine
+/// picks a sorted merge join and the sorted group-by fast path when the keys carry a sorted flag,
+/// which sorts and the Parquet reader set. The streaming engine doesn't use sortedness.
+pub(super) struct SortedInputRule {}
+
+impl OptimizationRule for SortedInputRule {
+    fn optimize_plan(
+        &mut self,
+        lp_arena: &mut Arena<IR>,
+        expr_arena: &mut Arena<AExpr>,
+        node: Node,
+    ) -> PolarsResult<Option<IR>> {
+        let IR::Sort {
+            input,
+            by_column,
+            slice,
+            sort_options,
+        } = lp_arena.get(node)
+        else {
+            return Ok(None);
+        };
+        let Some(names) = by_column
+            .iter()
+            .map(|by| match expr_arena.get(by.node()) {
+                AExpr::Column(name) => Some(name.clone()),
+                _ => None,
+            })
+            .collect::<Option<Vec<_>>>()
+        else {
+            return Ok(None);
+        };
+
+        let Some(sortedness) = sortedness_of(*input, names.clone(), lp_arena, expr_arena) else {
+            return Ok(None);
+        };
+        let satisfied = sortedness.iter().enumerate().all(|(i, s)| {
+            let (descending, nulls_last) = sort_key_order(sort_options, i);
+            s.satisfies(descending, nulls_last)
+        });
+        if !satisfied {
+            return Ok(None);
+        }
+
+        if verbose() {
+            eprintln!("input is already sorted by {names:?}; removing sort")
+        }
+        Ok(Some(match *slice {
+            Some((offset, len)) => IR::Slice {
+                input: *input,
+                offset,
+                len: len as IdxSize,
+            },
+            None => lp_arena.get(*input).clone(),
+        }))
+    }
+}
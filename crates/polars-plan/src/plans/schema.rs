@@ -228,6 +228,10 @@ pub(crate) fn det_join_schema(
                 })?;
             }
 
+            if let Some(field) = how.score_field() {
+                new_schema.try_insert(field.name, field.dtype)?;
+            }
+
             Ok(Arc::new(new_schema))
         },
     }
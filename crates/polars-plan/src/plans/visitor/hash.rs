@@ -1,12 +1,13 @@
-use std::hash::{Hash, Hasher};
+use std::hash::{BuildHasher, Hash, Hasher};
 use std::sync::Arc;
 
-use polars_utils::arena::Arena;
+use polars_utils::aliases::PlFixedStateQuality;
+use polars_utils::arena::{Arena, Node};
 
 use super::*;
 #[cfg(feature = "python")]
 use crate::plans::PythonOptions;
-use crate::plans::{AExpr, IR};
+use crate::plans::{AExpr, FunctionIR, IR};
 use crate::prelude::ExprIR;
 use crate::prelude::aexpr::traverse_and_hash_aexpr;
 
@@ -21,6 +22,7 @@ impl IRNode {
             lp_arena,
             expr_arena,
             ignore_cache: false,
+            udfs_by_address: false,
         }
     }
 }
@@ -30,6 +32,7 @@ pub(crate) struct HashableEqLP<'a> {
     lp_arena: &'a Arena<IR>,
     expr_arena: &'a Arena<AExpr>,
     ignore_cache: bool,
+    udfs_by_address: bool,
 }
 
 impl HashableEqLP<'_> {
@@ -39,6 +42,96 @@ impl HashableEqLP<'_> {
         self.ignore_cache = true;
         self
     }
+
+    /// Compare user-defined functions by their address instead of never considering them equal.
+    #[cfg(feature = "cse")]
+    pub(crate) fn udfs_by_address(mut self) -> Self {
+        self.udfs_by_address = true;
+        self
+    }
+}
+
+/// Hashes the complete plan rooted at `node`, looking through caches.
+///
+/// Unlike the [`Hash`] of [`HashableEqLP`] this includes all inputs and uses a fixed seed, so equal
+/// plans in different arenas hash the same within this process. Data frames are hashed by their
+/// address.
+pub fn hash_subplan(node: Node, lp_arena: &Arena<IR>, expr_arena: &Arena<AExpr>) -> u64 {
+    let mut hasher = PlFixedStateQuality::default().build_hasher();
+    let mut stack = vec![node];
+    while let Some(node) = stack.pop() {
+        let ir = lp_arena.get(node);
+        if let IR::Cache { input, .. } = ir {
+            stack.push(*input);
+            continue;
+        }
+        IRNode::new(node)
+            .hashable_and_cmp(lp_arena, expr_arena)
+            .hash(&mut hasher);
+        ir.copy_inputs(&mut stack);
+    }
+    hasher.finish()
+}
+
+/// Whether the complete plans rooted at `l` and `r` are equal, looking through caches. The plans
+/// may live in different arenas. User-defined functions are equal if they have the same address.
+#[cfg(feature = "cse")]
+pub fn subplans_equal(
+    l: Node,
+    l_arenas: (&Arena<IR>, &Arena<AExpr>),
+    r: Node,
+    r_arenas: (&Arena<IR>, &Arena<AExpr>),
+) -> bool {
+    let (l, r) = (IRNode::new(l), IRNode::new(r));
+    let l = l
+        .hashable_and_cmp(l_arenas.0, l_arenas.1)
+        .ignore_caches()
+        .udfs_by_address();
+    let r = r
+        .hashable_and_cmp(r_arenas.0, r_arenas.1)
+        .ignore_caches()
+        .udfs_by_address();
+    l == r
+}
+
+/// Whether `l` and `r` apply the same user-defined function with the same options.
+fn same_udf(l: &FunctionIR, r: &FunctionIR) -> bool {
+    match (l, r) {
+        #[cfg(feature = "python")]
+        (FunctionIR::OpaquePython(l), FunctionIR::OpaquePython(r)) => {
+            l.function.0.as_ptr() == r.function.0.as_ptr()
+                && l.schema == r.schema
+                && l.predicate_pd == r.predicate_pd
+                && l.projection_pd == r.projection_pd
+                && l.streamable == r.streamable
+                && l.validate_output == r.validate_output
+        },
+        (
+            FunctionIR::Opaque {
+                function: function_l,
+                predicate_pd: predicate_pd_l,
+                projection_pd: projection_pd_l,
+                streamable: streamable_l,
+                fmt_str: fmt_str_l,
+                ..
+            },
+            FunctionIR::Opaque {
+                function: function_r,
+                predicate_pd: predicate_pd_r,
+                projection_pd: projection_pd_r,
+                streamable: streamable_r,
+                fmt_str: fmt_str_r,
+                ..
+            },
+        ) => {
+            std::ptr::addr_eq(Arc::as_ptr(function_l), Arc::as_ptr(function_r))
+                && predicate_pd_l == predicate_pd_r
+                && projection_pd_l == projection_pd_r
+                && streamable_l == streamable_r
+                && fmt_str_l == fmt_str_r
+        },
+        _ => false,
+    }
 }
 
 fn hash_option_expr<H: Hasher>(expr: &Option<ExprIR>, expr_arena: &Arena<AExpr>, state: &mut H) {
@@ -75,13 +168,16 @@ fn hash_python_predicate<H: Hasher>(
 fn python_predicate_eq(
     l: &crate::prelude::PythonPredicate,
     r: &crate::prelude::PythonPredicate,
-    expr_arena: &Arena<AExpr>,
+    l_arena: &Arena<AExpr>,
+    r_arena: &Arena<AExpr>,
 ) -> bool {
     use crate::prelude::PythonPredicate;
     match (l, r) {
         (PythonPredicate::None, PythonPredicate::None) => true,
         (PythonPredicate::PyArrow(a), PythonPredicate::PyArrow(b)) => a == b,
-        (PythonPredicate::Polars(a), PythonPredicate::Polars(b)) => expr_ir_eq(a, b, expr_arena),
+        (PythonPredicate::Polars(a), PythonPredicate::Polars(b)) => {
+            expr_ir_eq(a, b, l_arena, r_arena)
+        },
         _ => false,
     }
 }
@@ -263,22 +359,30 @@ impl Hash for HashableEqLP<'_> {
     }
 }
 
-fn expr_irs_eq(l: &[ExprIR], r: &[ExprIR], expr_arena: &Arena<AExpr>) -> bool {
-    l.len() == r.len() && l.iter().zip(r).all(|(l, r)| expr_ir_eq(l, r, expr_arena))
+fn expr_irs_eq(l: &[ExprIR], r: &[ExprIR], l_arena: &Arena<AExpr>, r_arena: &Arena<AExpr>) -> bool {
+    l.len() == r.len()
+        && l.iter()
+            .zip(r)
+            .all(|(l, r)| expr_ir_eq(l, r, l_arena, r_arena))
 }
 
-fn expr_ir_eq(l: &ExprIR, r: &ExprIR, expr_arena: &Arena<AExpr>) -> bool {
+fn expr_ir_eq(l: &ExprIR, r: &ExprIR, l_arena: &Arena<AExpr>, r_arena: &Arena<AExpr>) -> bool {
     l.get_alias() == r.get_alias() && {
         let l = AexprNode::new(l.node());
         let r = AexprNode::new(r.node());
-        l.hashable_and_cmp(expr_arena) == r.hashable_and_cmp(expr_arena)
+        l.hashable_and_cmp(l_arena) == r.hashable_and_cmp(r_arena)
     }
 }
 
-fn opt_expr_ir_eq(l: &Option<ExprIR>, r: &Option<ExprIR>, expr_arena: &Arena<AExpr>) -> bool {
+fn opt_expr_ir_eq(
+    l: &Option<ExprIR>,
+    r: &Option<ExprIR>,
+    l_arena: &Arena<AExpr>,
+    r_arena: &Arena<AExpr>,
+) -> bool {
     match (l, r) {
         (None, None) => true,
-        (Some(l), Some(r)) => expr_ir_eq(l, r, expr_arena),
+        (Some(l), Some(r)) => expr_ir_eq(l, r, l_arena, r_arena),
         _ => false,
     }
 }
@@ -286,7 +390,8 @@ fn opt_expr_ir_eq(l: &Option<ExprIR>, r: &Option<ExprIR>, expr_arena: &Arena<AEx
 impl HashableEqLP<'_> {
     fn is_equal(&self, other: &Self) -> bool {
         let alp_l = self.node.to_alp(self.lp_arena);
-        let alp_r = other.node.to_alp(self.lp_arena);
+        let alp_r = other.node.to_alp(other.lp_arena);
+        let (l_arena, r_arena) = (self.expr_arena, other.expr_arena);
         if std::mem::discriminant(alp_l) != std::mem::discriminant(alp_r) {
             return false;
         }
@@ -343,7 +448,7 @@ impl HashableEqLP<'_> {
                     && python_source_l == python_source_r
                     && n_rows_l == n_rows_r
                     && validate_schema_l == validate_schema_r
-                    && python_predicate_eq(predicate_l, predicate_r, self.expr_arena)
+                    && python_predicate_eq(predicate_l, predicate_r, l_arena, r_arena)
             },
             (
                 IR::Slice {
@@ -366,7 +471,7 @@ impl HashableEqLP<'_> {
                     input: _,
                     predicate: r,
                 },
-            ) => expr_ir_eq(l, r, self.expr_arena),
+            ) => expr_ir_eq(l, r, l_arena, r_arena),
             (
                 IR::Scan {
                     sources: pl,
@@ -390,7 +495,7 @@ impl HashableEqLP<'_> {
                 pl == pr
                     && stl == str
                     && ol == or
-                    && opt_expr_ir_eq(pred_l, pred_r, self.expr_arena)
+                    && opt_expr_ir_eq(pred_l, pred_r, l_arena, r_arena)
             },
             (
                 IR::DataFrameScan {
@@ -427,7 +532,7 @@ impl HashableEqLP<'_> {
                     options: or,
                     schema: _,
                 },
-            ) => ol == or && expr_irs_eq(el, er, self.expr_arena),
+            ) => ol == or && expr_irs_eq(el, er, l_arena, r_arena),
             (
                 IR::Sort {
                     input: _,
@@ -443,7 +548,7 @@ impl HashableEqLP<'_> {
                 },
             ) => {
                 (l_slice == r_slice && l_options == r_options)
-                    && expr_irs_eq(cl, cr, self.expr_arena)
+                    && expr_irs_eq(cl, cr, l_arena, r_arena)
             },
             (
                 IR::GroupBy {
@@ -469,8 +574,8 @@ impl HashableEqLP<'_> {
                     && apply_r.is_none()
                     && ol == or
                     && maintain_l == maintain_r
-                    && expr_irs_eq(keys_l, keys_r, self.expr_arena)
-                    && expr_irs_eq(aggs_l, aggs_r, self.expr_arena)
+                    && expr_irs_eq(keys_l, keys_r, l_arena, r_arena)
+                    && expr_irs_eq(aggs_l, aggs_r, l_arena, r_arena)
             },
             (
                 IR::Join {
@@ -491,8 +596,8 @@ impl HashableEqLP<'_> {
                 },
             ) => {
                 ol == or
-                    && expr_irs_eq(ll, lr, self.expr_arena)
-                    && expr_irs_eq(rl, rr, self.expr_arena)
+                    && expr_irs_eq(ll, lr, l_arena, r_arena)
+                    && expr_irs_eq(rl, rr, l_arena, r_arena)
             },
             (
                 IR::HStack {
@@ -507,7 +612,7 @@ impl HashableEqLP<'_> {
                     schema: _,
                     options: or,
                 },
-            ) => ol == or && expr_irs_eq(el, er, self.expr_arena),
+            ) => ol == or && expr_irs_eq(el, er, l_arena, r_arena),
             (
                 IR::Distinct {
                     input: _,
@@ -527,7 +632,7 @@ impl HashableEqLP<'_> {
                     input: _,
                     function: r,
                 },
-            ) => l == r,
+            ) => l == r || (self.udfs_by_address && same_udf(l, r)),
             (
                 IR::Union {
                     inputs: _,
@@ -564,8 +669,8 @@ impl HashableEqLP<'_> {
             ) => {
                 l.len() == r.len()
                     && l.iter().zip(r.iter()).all(|(l, r)| {
-                        let l = AexprNode::new(*l).hashable_and_cmp(self.expr_arena);
-                        let r = AexprNode::new(*r).hashable_and_cmp(self.expr_arena);
+                        let l = AexprNode::new(*l).hashable_and_cmp(l_arena);
+                        let r = AexprNode::new(*r).hashable_and_cmp(r_arena);
                         l == r
                     })
             },
@@ -588,7 +693,7 @@ impl PartialEq for HashableEqLP<'_> {
                     let l = IRNode::new(l);
                     let r = IRNode::new(r);
                     let l_alp = l.to_alp(self.lp_arena);
-                    let r_alp = r.to_alp(self.lp_arena);
+                    let r_alp = r.to_alp(other.lp_arena);
 
                     if self.ignore_cache {
                         match (l_alp, r_alp) {
@@ -611,10 +716,9 @@ impl PartialEq for HashableEqLP<'_> {
                         }
                     }
 
-                    if !l
-                        .hashable_and_cmp(self.lp_arena, self.expr_arena)
-                        .is_equal(&r.hashable_and_cmp(self.lp_arena, self.expr_arena))
-                    {
+                    let mut l = l.hashable_and_cmp(self.lp_arena, self.expr_arena);
+                    l.udfs_by_address = self.udfs_by_address;
+                    if !l.is_equal(&r.hashable_and_cmp(other.lp_arena, other.expr_arena)) {
                         return false;
                     }
 
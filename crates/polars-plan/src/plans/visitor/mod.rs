@@ -8,6 +8,8 @@ mod lp;
 mod visitors;
 
 pub use expr::*;
+#[cfg(feature = "cse")]
+pub use hash::{hash_subplan, subplans_equal};
 pub use lp::*;
 pub use visitors::*;
 
@@ -280,6 +280,22 @@ pub fn _polars_runtime_64(py: Python, m: &Bound<PyModule>) -> PyResult<()> {
         .unwrap();
     m.add_wrapped(wrap_pyfunction!(functions::using_string_cache))
         .unwrap();
+    #[cfg(feature = "cse")]
+    {
+        m.add_wrapped(wrap_pyfunction!(functions::clear_result_cache))
+            .unwrap();
+        m.add_wrapped(wrap_pyfunction!(functions::invalidate_result_cache))
+            .unwrap();
+        m.add_wrapped(wrap_pyfunction!(functions::result_cache_len))
+            .unwrap();
+        m.add_wrapped(wrap_pyfunction!(functions::set_result_cache_capacity))
+            .unwrap();
+        m.add_wrapped(wrap_pyfunction!(functions::get_result_cache_capacity))
+            .unwrap();
+    }
+    #[cfg(all(feature = "cse", feature = "ipc"))]
+    m.add_wrapped(wrap_pyfunction!(functions::set_result_cache_spill_dir))
+        .unwrap();
 
     // Numeric formatting
     m.add_wrapped(wrap_pyfunction!(functions::get_thousands_separator))
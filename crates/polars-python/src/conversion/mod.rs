@@ -810,9 +810,13 @@ impl<'py> FromPyObject<'py> for Wrap<InterpolationMethod> {
         let parsed = match &*(ob.extract::<PyBackedStr>()?) {
             "linear" => InterpolationMethod::Linear,
             "nearest" => InterpolationMethod::Nearest,
+            "previous" => InterpolationMethod::Previous,
+            "next" => InterpolationMethod::Next,
+            "cubic_spline" => InterpolationMethod::CubicSpline,
+            "pchip" => InterpolationMethod::Pchip,
             v => {
                 return Err(PyValueError::new_err(format!(
-                    "interpolation `method` must be one of {{'linear', 'nearest'}}, got {v}",
+                    "interpolation `method` must be one of {{'linear', 'nearest', 'previous', 'next', 'cubic_spline', 'pchip'}}, got {v}",
                 )));
             },
         };
@@ -918,9 +922,14 @@ impl<'py> FromPyObject<'py> for Wrap<CsvEncoding> {
         let parsed = match &*ob.extract::<PyBackedStr>()? {
             "utf8" => CsvEncoding::Utf8,
             "utf8-lossy" => CsvEncoding::LossyUtf8,
+            "latin1" => CsvEncoding::Latin1,
+            "windows-1252" => CsvEncoding::Windows1252,
+            "shift-jis" => CsvEncoding::ShiftJis,
+            "utf16-le" => CsvEncoding::Utf16Le,
+            "utf16-be" => CsvEncoding::Utf16Be,
             v => {
                 return Err(PyValueError::new_err(format!(
-                    "csv `encoding` must be one of {{'utf8', 'utf8-lossy'}}, got {v}",
+                    "csv `encoding` must be one of {{'utf8', 'utf8-lossy', 'latin1', 'windows-1252', 'shift-jis', 'utf16-le', 'utf16-be'}}, got {v}",
                 )));
             },
         };
@@ -7,6 +7,8 @@ mod meta;
 mod misc;
 mod random;
 mod range;
+#[cfg(feature = "cse")]
+mod result_cache;
 mod string_cache;
 mod strings;
 mod utils;
@@ -21,6 +23,8 @@ pub use meta::*;
 pub use misc::*;
 pub use random::*;
 pub use range::*;
+#[cfg(feature = "cse")]
+pub use result_cache::*;
 pub use string_cache::*;
 pub use strings::*;
 pub use utils::*;
@@ -0,0 +1,40 @@
+use std::path::PathBuf;
+
+use polars::prelude::ResultCache;
+use pyo3::prelude::*;
+
+use crate::PyLazyFrame;
+use crate::utils::EnterPolarsExt;
+
+#[pyfunction]
+pub fn clear_result_cache() {
+    ResultCache::global().clear();
+}
+
+#[pyfunction]
+pub fn invalidate_result_cache(lf: PyLazyFrame, py: Python<'_>) -> PyResult<usize> {
+    let lf = lf.ldf.into_inner();
+    py.enter_polars(|| ResultCache::global().invalidate(lf))
+}
+
+#[pyfunction]
+pub fn result_cache_len() -> usize {
+    ResultCache::global().len()
+}
+
+#[pyfunction]
+pub fn set_result_cache_capacity(capacity: usize) {
+    ResultCache::global().set_capacity(capacity);
+}
+
+#[pyfunction]
+pub fn get_result_cache_capacity() -> usize {
+    ResultCache::global().capacity()
+}
+
+#[cfg(feature = "ipc")]
+#[pyfunction]
+#[pyo3(signature = (dir=None))]
+pub fn set_result_cache_spill_dir(dir: Option<PathBuf>) {
+    ResultCache::global().set_spill_dir(dir);
+}
@@ -132,6 +132,7 @@ impl PyScanOptions<'_> {
             include_file_paths: include_file_paths.map(|x| x.0),
             deletion_files: DeletionFilesList::filter_empty(deletion_files.map(|x| x.0)),
             table_statistics: table_statistics.map(|x| x.0),
+            scan_metrics: None,
         };
 
         Ok(unified_scan_args)
@@ -835,7 +835,7 @@ impl PyLazyFrame {
     #[pyo3(signature = (
         target, include_bom, include_header, separator, line_terminator, quote_char, batch_size,
         datetime_format, date_format, time_format, float_scientific, float_precision, decimal_comma, null_value,
-        quote_style, cloud_options, credential_provider, retries, sink_options
+        quote_style, encoding, cloud_options, credential_provider, retries, sink_options
     ))]
     fn sink_csv(
         &self,
@@ -855,6 +855,7 @@ impl PyLazyFrame {
         decimal_comma: bool,
         null_value: Option<String>,
         quote_style: Option<Wrap<QuoteStyle>>,
+        encoding: Wrap<CsvEncoding>,
         cloud_options: Option<Vec<(String, String)>>,
         credential_provider: Option<PyObject>,
         retries: usize,
@@ -882,6 +883,8 @@ impl PyLazyFrame {
             include_header,
             batch_size,
             serialize_options,
+            encoding: encoding.0,
+            compression: ExternalCompression::Uncompressed,
         };
 
         #[cfg(feature = "cloud")]
@@ -937,7 +940,7 @@ impl PyLazyFrame {
         retries: usize,
         sink_options: Wrap<SinkOptions>,
     ) -> PyResult<PyLazyFrame> {
-        let options = JsonWriterOptions {};
+        let options = JsonWriterOptions::default();
 
         let cloud_options = match target.base_path() {
             None => None,
@@ -168,6 +168,7 @@ pub enum PyStringFunction {
     SplitN,
     Strptime,
     Split,
+    SplitRegex,
     ToDecimal,
     Titlecase,
     Uppercase,
@@ -888,6 +889,10 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<PyObject> {
                     IRStringFunction::Split(inclusive) => {
                         (PyStringFunction::Split, inclusive).into_py_any(py)
                     },
+                    #[cfg(feature = "regex")]
+                    IRStringFunction::SplitRegex { inclusive, limit } => {
+                        (PyStringFunction::SplitRegex, inclusive, limit).into_py_any(py)
+                    },
                     IRStringFunction::ToDecimal { scale } => {
                         (PyStringFunction::ToDecimal, scale).into_py_any(py)
                     },
@@ -915,6 +920,33 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<PyObject> {
                     IRStringFunction::EscapeRegex => {
                         (PyStringFunction::EscapeRegex,).into_py_any(py)
                     },
+                    #[cfg(feature = "string_similarity")]
+                    IRStringFunction::Levenshtein => {
+                        return Err(PyNotImplementedError::new_err("levenshtein"));
+                    },
+                    #[cfg(feature = "string_similarity")]
+                    IRStringFunction::DamerauLevenshtein => {
+                        return Err(PyNotImplementedError::new_err("damerau_levenshtein"));
+                    },
+                    #[cfg(feature = "string_similarity")]
+                    IRStringFunction::Hamming => {
+                        return Err(PyNotImplementedError::new_err("hamming"));
+                    },
+                    #[cfg(feature = "string_similarity")]
+                    IRStringFunction::JaroWinkler => {
+                        return Err(PyNotImplementedError::new_err("jaro_winkler"));
+                    },
+                    #[cfg(feature = "string_similarity")]
+                    IRStringFunction::NgramJaccard(_) => {
+                        return Err(PyNotImplementedError::new_err("ngram_jaccard"));
+                    },
+                    #[cfg(feature = "string_url")]
+                    IRStringFunction::UrlParse { .. }
+                    | IRStringFunction::UrlQueryParam { .. }
+                    | IRStringFunction::PercentDecode { .. }
+                    | IRStringFunction::PercentEncode => {
+                        return Err(PyNotImplementedError::new_err("url"));
+                    },
                 },
                 IRFunctionExpr::StructExpr(fun) => match fun {
                     IRStructFunction::FieldByName(name) => {
@@ -1112,6 +1144,10 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<PyObject> {
                 IRFunctionExpr::Hash(seed, seed_1, seed_2, seed_3) => {
                     ("hash", seed, seed_1, seed_2, seed_3).into_py_any(py)
                 },
+                #[cfg(feature = "stable_hash")]
+                IRFunctionExpr::StableHash(_) => {
+                    return Err(PyNotImplementedError::new_err("stable_hash"));
+                },
                 IRFunctionExpr::ArgWhere => ("argwhere",).into_py_any(py),
                 #[cfg(feature = "index_of")]
                 IRFunctionExpr::IndexOf => ("index_of",).into_py_any(py),
@@ -1180,6 +1216,24 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<PyObject> {
                     IRRollingFunctionBy::StdBy => {
                         return Err(PyNotImplementedError::new_err("rolling std by"));
                     },
+                    IRRollingFunctionBy::RankBy => {
+                        return Err(PyNotImplementedError::new_err("rolling rank by"));
+                    },
+                    IRRollingFunctionBy::NUniqueBy => {
+                        return Err(PyNotImplementedError::new_err("rolling n_unique by"));
+                    },
+                    IRRollingFunctionBy::ArgMinBy => {
+                        return Err(PyNotImplementedError::new_err("rolling arg_min by"));
+                    },
+                    IRRollingFunctionBy::ArgMaxBy => {
+                        return Err(PyNotImplementedError::new_err("rolling arg_max by"));
+                    },
+                    IRRollingFunctionBy::ModeBy => {
+                        return Err(PyNotImplementedError::new_err("rolling mode by"));
+                    },
+                    IRRollingFunctionBy::ProductBy => {
+                        return Err(PyNotImplementedError::new_err("rolling product by"));
+                    },
                 },
                 IRFunctionExpr::Rechunk => ("rechunk",).into_py_any(py),
                 IRFunctionExpr::Append { upcast } => ("append", upcast).into_py_any(py),
@@ -1248,15 +1302,31 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<PyObject> {
                     .into_py_any(py),
                 #[cfg(feature = "pct_change")]
                 IRFunctionExpr::PctChange => ("pct_change",).into_py_any(py),
-                IRFunctionExpr::Interpolate(method) => (
-                    "interpolate",
-                    match method {
-                        InterpolationMethod::Linear => "linear",
-                        InterpolationMethod::Nearest => "nearest",
-                    },
-                )
-                    .into_py_any(py),
-                IRFunctionExpr::InterpolateBy => ("interpolate_by",).into_py_any(py),
+                IRFunctionExpr::Interpolate(options) => {
+                    if options.limit.is_some() || options.limit_area.is_some() {
+                        return Err(PyNotImplementedError::new_err("interpolate with limit"));
+                    }
+                    (
+                        "interpolate",
+                        match options.method {
+                            InterpolationMethod::Linear => "linear",
+                            InterpolationMethod::Nearest => "nearest",
+                            InterpolationMethod::Previous => "previous",
+                            InterpolationMethod::Next => "next",
+                            InterpolationMethod::CubicSpline => "cubic_spline",
+                            InterpolationMethod::Pchip => "pchip",
+                        },
+                    )
+                        .into_py_any(py)
+                },
+                IRFunctionExpr::InterpolateBy(options) => {
+                    if *options != Default::default() {
+                        return Err(PyNotImplementedError::new_err(
+                            "interpolate_by with options",
+                        ));
+                    }
+                    ("interpolate_by",).into_py_any(py)
+                },
                 IRFunctionExpr::Entropy { base, normalize } => {
                     ("entropy", base, normalize).into_py_any(py)
                 },
@@ -455,9 +455,15 @@ impl SQLContext {
     // EXPLAIN SELECT * FROM DF
     fn execute_explain(&mut self, stmt: &Statement) -> PolarsResult<LazyFrame> {
         match stmt {
-            Statement::Explain { statement, .. } => {
+            Statement::Explain {
+                statement, analyze, ..
+            } => {
                 let lf = self.execute_statement(statement)?;
-                let plan = lf.describe_optimized_plan()?;
+                let plan = if *analyze {
+                    lf.explain_analyze(Engine::Auto)?
+                } else {
+                    lf.describe_optimized_plan()?
+                };
                 let plan = plan
                     .split('\n')
                     .collect::<Series>()
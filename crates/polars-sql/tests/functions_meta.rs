@@ -30,3 +30,32 @@ fn test_describe() {
 
     assert_eq!(actual, expected);
 }
+
+#[test]
+fn test_explain_analyze() {
+    let lf = df! {
+      "year"=> [2018, 2019, 2020],
+      "sales"=> [1000.0, 200.0, 3000.0]
+    }
+    .unwrap()
+    .lazy();
+    let mut context = SQLContext::new();
+    context.register("df", lf);
+    let sql = r#"EXPLAIN ANALYZE SELECT year FROM df WHERE sales > 500"#;
+    let df = context.execute(sql).unwrap().collect().unwrap();
+    let plan = df
+        .column("Logical Plan")
+        .unwrap()
+        .str()
+        .unwrap()
+        .into_no_null_iter()
+        .collect::<Vec<_>>();
+
+    assert!(plan[0].contains("[rows in: 2, rows out: 2, elapsed: "));
+    assert!(plan[1].starts_with("  FILTER"));
+    assert!(plan[1].contains("[rows in: 3, rows out: 2, elapsed: "));
+    assert!(
+        plan.iter()
+            .any(|line| line.starts_with("    DF") && line.contains("[rows out: 3, elapsed: "))
+    );
+}
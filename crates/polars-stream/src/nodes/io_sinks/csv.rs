@@ -7,6 +7,7 @@ use polars_error::PolarsResult;
 use polars_io::SerWriter;
 use polars_io::cloud::CloudOptions;
 use polars_io::prelude::{CsvWriter, CsvWriterOptions};
+use polars_io::utils::compression::Compressor;
 use polars_plan::dsl::{SinkOptions, SinkTarget};
 use polars_utils::priority::Priority;
 
@@ -81,9 +82,14 @@ impl SinkNode for CsvSinkNode {
                 .open_into_writeable_async(&sink_options, cloud_options.as_ref())
                 .await?;
 
+            // The output is compressed as a single stream, so this happens here rather than in
+            // the encode tasks.
+            let mut compressor = Compressor::try_new(options.compression)?;
+
             // Write the header
             if options.include_header || options.include_bom {
-                let mut writer = CsvWriter::new(&mut *file)
+                let mut header = vec![];
+                let mut writer = CsvWriter::new(&mut header)
                     .include_bom(options.include_bom)
                     .include_header(options.include_header)
                     .with_separator(options.serialize_options.separator)
@@ -97,19 +103,33 @@ impl SinkNode for CsvSinkNode {
                     .with_decimal_comma(options.serialize_options.decimal_comma)
                     .with_null_value(options.serialize_options.null.clone())
                     .with_quote_style(options.serialize_options.quote_style)
+                    .with_encoding(options.encoding)
                     .n_threads(1) // Disable rayon parallelism
                     .batched(&schema)?;
                 writer.write_batch(&DataFrame::empty_with_schema(&schema))?;
+                drop(writer);
+
+                if let Some(compressor) = compressor.as_mut() {
+                    header = compressor.compress(&header)?;
+                }
+                std::io::Write::write_all(&mut *file, &header)?;
             }
 
             let mut file = file.try_into_async_writeable()?;
 
             while let Ok(mut lin_rx) = io_rx.recv().await {
-                while let Some(Priority(_, buffer)) = lin_rx.get().await {
+                while let Some(Priority(_, mut buffer)) = lin_rx.get().await {
+                    if let Some(compressor) = compressor.as_mut() {
+                        buffer = compressor.compress(&buffer)?;
+                    }
                     file.write_all(&buffer).await?;
                 }
             }
 
+            if let Some(compressor) = compressor {
+                file.write_all(&compressor.finish()?).await?;
+            }
+
             file.sync_on_close(sink_options.sync_on_close).await?;
             file.close().await?;
 
@@ -175,6 +195,7 @@ impl SinkNode for CsvSinkNode {
                             .with_decimal_comma(options.serialize_options.decimal_comma)
                             .with_null_value(options.serialize_options.null.clone())
                             .with_quote_style(options.serialize_options.quote_style)
+                            .with_encoding(options.encoding)
                             .n_threads(1) // Disable rayon parallelism
                             .batched(&schema)?;
 
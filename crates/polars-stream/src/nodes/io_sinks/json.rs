@@ -3,7 +3,8 @@ use std::pin::Pin;
 
 use polars_error::PolarsResult;
 use polars_io::cloud::CloudOptions;
-use polars_io::json::BatchedWriter;
+use polars_io::json::{BatchedWriter, JsonWriterOptions};
+use polars_io::utils::compression::Compressor;
 use polars_plan::dsl::{SinkOptions, SinkTarget};
 use polars_utils::priority::Priority;
 
@@ -23,6 +24,7 @@ type IOSend = Linearizer<Priority<Reverse<MorselSeq>, Vec<u8>>>;
 pub struct NDJsonSinkNode {
     target: SinkTarget,
     sink_options: SinkOptions,
+    write_options: JsonWriterOptions,
     cloud_options: Option<CloudOptions>,
 
     io_tx: Option<Sender<IOSend>>,
@@ -32,11 +34,13 @@ impl NDJsonSinkNode {
     pub fn new(
         target: SinkTarget,
         sink_options: SinkOptions,
+        write_options: JsonWriterOptions,
         cloud_options: Option<CloudOptions>,
     ) -> Self {
         Self {
             target,
             sink_options,
+            write_options,
             cloud_options,
 
             io_tx: None,
@@ -66,6 +70,7 @@ impl SinkNode for NDJsonSinkNode {
         let sink_options = self.sink_options.clone();
         let cloud_options = self.cloud_options.clone();
         let target = self.target.clone();
+        let compression = self.write_options.compression;
         let io_task = polars_io::pl_async::get_runtime().spawn(async move {
             use tokio::io::AsyncWriteExt;
 
@@ -74,12 +79,23 @@ impl SinkNode for NDJsonSinkNode {
                 .await?
                 .try_into_async_writeable()?;
 
+            // The output is compressed as a single stream, so this happens here rather than in
+            // the encode tasks.
+            let mut compressor = Compressor::try_new(compression)?;
+
             while let Ok(mut lin_rx) = io_rx.recv().await {
-                while let Some(Priority(_, buffer)) = lin_rx.get().await {
+                while let Some(Priority(_, mut buffer)) = lin_rx.get().await {
+                    if let Some(compressor) = compressor.as_mut() {
+                        buffer = compressor.compress(&buffer)?;
+                    }
                     file.write_all(&buffer).await?;
                 }
             }
 
+            if let Some(compressor) = compressor {
+                file.write_all(&compressor.finish()?).await?;
+            }
+
             file.sync_on_close(sink_options.sync_on_close).await?;
             file.close().await?;
 
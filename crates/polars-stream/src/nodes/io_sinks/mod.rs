@@ -29,6 +29,8 @@ pub mod csv;
 pub mod ipc;
 #[cfg(feature = "json")]
 pub mod json;
+#[cfg(feature = "orc")]
+pub mod orc;
 #[cfg(feature = "parquet")]
 pub mod parquet;
 pub mod partition;
@@ -0,0 +1,219 @@
+use std::cmp::Reverse;
+use std::io::BufWriter;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+
+use polars_core::schema::SchemaRef;
+use polars_error::PolarsResult;
+use polars_io::cloud::CloudOptions;
+use polars_io::orc::{BatchedWriter, EncodedOrcColumn, OrcStripeEncoder, OrcWriterOptions};
+use polars_plan::dsl::{SinkOptions, SinkTarget};
+use polars_utils::priority::Priority;
+
+use super::{
+    DEFAULT_SINK_DISTRIBUTOR_BUFFER_SIZE, DEFAULT_SINK_LINEARIZER_BUFFER_SIZE, SinkInputPort,
+    SinkNode, buffer_and_distribute_columns_task,
+};
+use crate::async_executor::spawn;
+use crate::async_primitives::connector::{Receiver, Sender, connector};
+use crate::async_primitives::distributor_channel::distributor_channel;
+use crate::async_primitives::linearizer::Linearizer;
+use crate::execute::StreamingExecutionState;
+use crate::nodes::io_sinks::phase::PhaseOutcome;
+use crate::nodes::{JoinHandle, TaskPriority};
+use crate::utils::task_handles_ext::AbortOnDropHandle;
+
+pub struct OrcSinkNode {
+    target: SinkTarget,
+
+    input_schema: SchemaRef,
+    sink_options: SinkOptions,
+    encoder: Arc<OrcStripeEncoder>,
+    cloud_options: Option<CloudOptions>,
+
+    io_tx: Option<Sender<Vec<EncodedOrcColumn>>>,
+    io_task: Option<AbortOnDropHandle<PolarsResult<()>>>,
+}
+
+impl OrcSinkNode {
+    pub fn new(
+        input_schema: SchemaRef,
+        target: SinkTarget,
+        sink_options: SinkOptions,
+        write_options: OrcWriterOptions,
+        cloud_options: Option<CloudOptions>,
+    ) -> PolarsResult<Self> {
+        let encoder = Arc::new(OrcStripeEncoder::try_new(&input_schema, write_options)?);
+
+        Ok(Self {
+            target,
+
+            input_schema,
+            sink_options,
+            encoder,
+            cloud_options,
+
+            io_tx: None,
+            io_task: None,
+        })
+    }
+}
+
+impl SinkNode for OrcSinkNode {
+    fn name(&self) -> &str {
+        "orc-sink"
+    }
+
+    fn is_sink_input_parallel(&self) -> bool {
+        false
+    }
+    fn do_maintain_order(&self) -> bool {
+        self.sink_options.maintain_order
+    }
+
+    fn initialize(&mut self, _state: &StreamingExecutionState) -> PolarsResult<()> {
+        // Collect task -> IO task
+        let (io_tx, mut io_rx) = connector::<Vec<EncodedOrcColumn>>();
+
+        // IO task.
+        //
+        // Task that will actually do write to the target file. It is important that this is only
+        // spawned once.
+        let target = self.target.clone();
+        let sink_options = self.sink_options.clone();
+        let cloud_options = self.cloud_options.clone();
+        let encoder = self.encoder.as_ref().clone();
+        let io_task = polars_io::pl_async::get_runtime().spawn(async move {
+            let mut file = target
+                .open_into_writeable_async(&sink_options, cloud_options.as_ref())
+                .await?;
+
+            let mut writer = BatchedWriter::new(BufWriter::new(&mut *file), encoder)?;
+
+            while let Ok(stripe) = io_rx.recv().await {
+                // @TODO: At the moment this is a sync write, this is not ideal because we can only
+                // have so many blocking threads in the tokio threadpool.
+                writer.write_stripe(stripe)?;
+            }
+
+            writer.finish()?;
+            drop(writer);
+
+            file.sync_on_close(sink_options.sync_on_close)?;
+            file.close()?;
+
+            PolarsResult::Ok(())
+        });
+
+        self.io_tx = Some(io_tx);
+        self.io_task = Some(AbortOnDropHandle(io_task));
+
+        Ok(())
+    }
+
+    fn spawn_sink(
+        &mut self,
+        recv_port_rx: Receiver<(PhaseOutcome, SinkInputPort)>,
+        state: &StreamingExecutionState,
+        join_handles: &mut Vec<JoinHandle<PolarsResult<()>>>,
+    ) {
+        // Collect task -> IO task
+        let mut io_tx = self
+            .io_tx
+            .take()
+            .expect("not initialized / spawn called more than once");
+        // Buffer task -> Encode tasks
+        let (dist_tx, dist_rxs) =
+            distributor_channel(state.num_pipelines, *DEFAULT_SINK_DISTRIBUTOR_BUFFER_SIZE);
+        // Encode tasks -> Collect task
+        let (mut lin_rx, lin_txs) =
+            Linearizer::new(state.num_pipelines, *DEFAULT_SINK_LINEARIZER_BUFFER_SIZE);
+
+        // Buffer task.
+        //
+        // Every buffered chunk becomes a single stripe.
+        join_handles.push(buffer_and_distribute_columns_task(
+            recv_port_rx,
+            dist_tx,
+            self.encoder.options().rows_per_stripe(),
+            self.input_schema.clone(),
+            Arc::new(Mutex::new(None)),
+        ));
+
+        // Encode tasks.
+        //
+        // Tasks encode the columns into the streams of their ORC columns.
+        join_handles.extend(
+            dist_rxs
+                .into_iter()
+                .zip(lin_txs)
+                .map(|(mut dist_rx, mut lin_tx)| {
+                    let encoder = self.encoder.clone();
+
+                    spawn(TaskPriority::High, async move {
+                        while let Ok((stripe_idx, col_idx, column)) = dist_rx.recv().await {
+                            let encoded = encoder.encode_column(col_idx, &column)?;
+
+                            if lin_tx
+                                .insert(Priority(Reverse(stripe_idx), (col_idx, encoded)))
+                                .await
+                                .is_err()
+                            {
+                                return Ok(());
+                            }
+                        }
+
+                        PolarsResult::Ok(())
+                    })
+                }),
+        );
+
+        // Collect task.
+        //
+        // Collects the encoded columns of a stripe and sends them to the IO task.
+        let num_columns = self.input_schema.len();
+        join_handles.push(spawn(TaskPriority::High, async move {
+            let mut num_columns_seen = 0;
+            let mut columns: Vec<Option<EncodedOrcColumn>> =
+                (0..num_columns).map(|_| None).collect();
+
+            while let Some(Priority(_, (i, encoded))) = lin_rx.get().await {
+                debug_assert!(columns[i].is_none());
+                columns[i] = Some(encoded);
+                num_columns_seen += 1;
+
+                if num_columns_seen == num_columns {
+                    let stripe = columns.iter_mut().map(|c| c.take().unwrap()).collect();
+
+                    if io_tx.send(stripe).await.is_err() {
+                        return Ok(());
+                    }
+                    num_columns_seen = 0;
+                }
+            }
+
+            Ok(())
+        }));
+    }
+
+    fn finalize(
+        &mut self,
+        _state: &StreamingExecutionState,
+    ) -> Option<Pin<Box<dyn Future<Output = PolarsResult<()>> + Send>>> {
+        // If we were never spawned, we need to make sure that the `tx` is taken. This signals to
+        // the IO task that it is done and prevents deadlocks.
+        drop(self.io_tx.take());
+
+        let io_task = self
+            .io_task
+            .take()
+            .expect("not initialized / finish called more than once");
+
+        // Wait for the IO task to complete.
+        Some(Box::pin(async move {
+            io_task
+                .await
+                .unwrap_or_else(|e| Err(std::io::Error::from(e).into()))
+        }))
+    }
+}
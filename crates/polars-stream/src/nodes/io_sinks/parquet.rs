@@ -7,14 +7,14 @@ use polars_core::prelude::{ArrowSchema, CompatLevel};
 use polars_core::schema::SchemaRef;
 use polars_error::PolarsResult;
 use polars_io::cloud::CloudOptions;
-use polars_io::parquet::write::BatchedWriter;
+use polars_io::parquet::write::{BatchedWriter, get_primitive_leaf_idxs, get_sorting_column};
 use polars_io::prelude::{ParquetWriteOptions, get_column_write_options};
 use polars_io::schema_to_arrow_checked;
 use polars_parquet::parquet::error::ParquetResult;
 use polars_parquet::read::ParquetError;
 use polars_parquet::write::{
-    ColumnWriteOptions, CompressedPage, Compressor, FileWriter, SchemaDescriptor, Version,
-    WriteOptions, array_to_columns, to_parquet_schema,
+    ColumnWriteOptions, CompressedPage, Compressor, FileWriter, SchemaDescriptor, SortingColumn,
+    Version, WriteOptions, array_to_columns, to_parquet_schema,
 };
 use polars_plan::dsl::{SinkOptions, SinkTarget};
 use polars_utils::priority::Priority;
@@ -34,6 +34,9 @@ use crate::nodes::io_sinks::phase::PhaseOutcome;
 use crate::nodes::{JoinHandle, TaskPriority};
 use crate::utils::task_handles_ext::AbortOnDropHandle;
 
+/// The pages of all leaf columns of a row group, and the order of its rows.
+type EncodedRowGroup = (Vec<Vec<CompressedPage>>, Option<Vec<SortingColumn>>);
+
 pub struct ParquetSinkNode {
     target: SinkTarget,
 
@@ -44,12 +47,14 @@ pub struct ParquetSinkNode {
     parquet_schema: SchemaDescriptor,
     arrow_schema: ArrowSchema,
     column_options: Vec<ColumnWriteOptions>,
+    /// For every column that is a single Parquet leaf column, the index of that leaf column.
+    leaf_idxs: Arc<[Option<usize>]>,
     cloud_options: Option<CloudOptions>,
 
     file_size: Arc<RelaxedCell<u64>>,
     metrics: Arc<Mutex<Option<WriteMetrics>>>,
 
-    io_tx: Option<crate::async_primitives::connector::Sender<Vec<Vec<CompressedPage>>>>,
+    io_tx: Option<crate::async_primitives::connector::Sender<EncodedRowGroup>>,
     io_task: Option<AbortOnDropHandle<PolarsResult<()>>>,
 }
 
@@ -66,6 +71,7 @@ impl ParquetSinkNode {
         let column_options: Vec<ColumnWriteOptions> =
             get_column_write_options(&schema, &write_options.field_overwrites);
         let parquet_schema = to_parquet_schema(&schema, &column_options)?;
+        let leaf_idxs = get_primitive_leaf_idxs(&parquet_schema).into();
         let metrics =
             Arc::new(Mutex::new(collect_metrics.then(|| {
                 WriteMetrics::new(target.to_display_string(), &input_schema)
@@ -81,6 +87,7 @@ impl ParquetSinkNode {
             parquet_schema,
             arrow_schema: schema,
             column_options,
+            leaf_idxs,
             cloud_options,
 
             file_size: Arc::default(),
@@ -109,7 +116,7 @@ impl SinkNode for ParquetSinkNode {
 
     fn initialize(&mut self, _state: &StreamingExecutionState) -> PolarsResult<()> {
         // Collect task -> IO task
-        let (io_tx, mut io_rx) = connector::<Vec<Vec<CompressedPage>>>();
+        let (io_tx, mut io_rx) = connector::<EncodedRowGroup>();
 
         // IO task.
         //
@@ -151,11 +158,11 @@ impl SinkNode for ParquetSinkNode {
             );
 
             let num_parquet_columns = writer.parquet_schema().leaves().len();
-            while let Ok(current_row_group) = io_rx.recv().await {
+            while let Ok((current_row_group, sorting_columns)) = io_rx.recv().await {
                 // @TODO: At the moment this is a sync write, this is not ideal because we can only
                 // have so many blocking threads in the tokio threadpool.
                 assert_eq!(current_row_group.len(), num_parquet_columns);
-                writer.write_row_group(&current_row_group)?;
+                writer.write_row_group(&current_row_group, sorting_columns)?;
             }
 
             let file_size = writer.finish()?;
@@ -222,11 +229,14 @@ impl SinkNode for ParquetSinkNode {
                 .map(|(mut dist_rx, mut lin_tx)| {
                     let parquet_schema = self.parquet_schema.clone();
                     let column_options = self.column_options.clone();
+                    let leaf_idxs = self.leaf_idxs.clone();
 
                     spawn(TaskPriority::High, async move {
                         while let Ok((rg_idx, col_idx, column)) = dist_rx.recv().await {
                             let type_ = &parquet_schema.fields()[col_idx];
                             let column_options = &column_options[col_idx];
+                            let sorting_column = leaf_idxs[col_idx]
+                                .and_then(|leaf_idx| get_sorting_column(&column, leaf_idx));
 
                             let array = column.as_materialized_series().rechunk();
                             let array = array.to_arrow(0, CompatLevel::newest());
@@ -261,7 +271,10 @@ impl SinkNode for ParquetSinkNode {
                                 .collect::<ParquetResult<Vec<_>>>()?;
 
                             if lin_tx
-                                .insert(Priority(Reverse(rg_idx), (col_idx, compressed_pages)))
+                                .insert(Priority(
+                                    Reverse(rg_idx),
+                                    (col_idx, compressed_pages, sorting_column),
+                                ))
                                 .await
                                 .is_err()
                             {
@@ -284,16 +297,20 @@ impl SinkNode for ParquetSinkNode {
                 seq: usize,
                 num_columns_seen: usize,
                 columns: Vec<Option<Vec<Vec<CompressedPage>>>>,
+                sorting_columns: Vec<Option<SortingColumn>>,
             }
 
             let mut current = Current {
                 seq: 0,
                 num_columns_seen: 0,
                 columns: (0..input_schema.len()).map(|_| None).collect(),
+                sorting_columns: vec![None; input_schema.len()],
             };
 
             // Linearize from all the Encoder tasks.
-            while let Some(Priority(Reverse(seq), (i, compressed_pages))) = lin_rx.get().await {
+            while let Some(Priority(Reverse(seq), (i, compressed_pages, sorting_column))) =
+                lin_rx.get().await
+            {
                 if current.num_columns_seen == 0 {
                     current.seq = seq;
                 }
@@ -301,6 +318,7 @@ impl SinkNode for ParquetSinkNode {
                 debug_assert_eq!(current.seq, seq);
                 debug_assert!(current.columns[i].is_none());
                 current.columns[i] = Some(compressed_pages);
+                current.sorting_columns[i] = sorting_column;
                 current.num_columns_seen += 1;
 
                 if current.num_columns_seen == input_schema.len() {
@@ -312,7 +330,18 @@ impl SinkNode for ParquetSinkNode {
                         current_row_group.extend(column.take().unwrap());
                     }
 
-                    if io_tx.send(current_row_group).await.is_err() {
+                    let sorting_columns = current
+                        .sorting_columns
+                        .iter_mut()
+                        .filter_map(Option::take)
+                        .collect::<Vec<_>>();
+                    let sorting_columns = (!sorting_columns.is_empty()).then_some(sorting_columns);
+
+                    if io_tx
+                        .send((current_row_group, sorting_columns))
+                        .await
+                        .is_err()
+                    {
                         return Ok(());
                     }
                     current.num_columns_seen = 0;
@@ -59,10 +59,11 @@ pub fn get_create_new_fn(
             Ok(sink)
         }) as _,
         #[cfg(feature = "json")]
-        FileType::Json(_ndjson_writer_options) => Arc::new(move |_input_schema, target| {
+        FileType::Json(ndjson_writer_options) => Arc::new(move |_input_schema, target| {
             let sink = Box::new(super::json::NDJsonSinkNode::new(
                 target,
                 sink_options.clone(),
+                ndjson_writer_options,
                 cloud_options.clone(),
             )) as Box<dyn SinkNode + Send>;
             Ok(sink)
@@ -92,11 +93,23 @@ pub fn get_create_new_fn(
             )) as Box<dyn SinkNode + Send>;
             Ok(sink)
         }) as _,
+        #[cfg(feature = "orc")]
+        FileType::Orc(orc_writer_options) => Arc::new(move |input_schema, target| {
+            let sink = Box::new(super::orc::OrcSinkNode::new(
+                input_schema,
+                target,
+                sink_options.clone(),
+                orc_writer_options,
+                cloud_options.clone(),
+            )?) as Box<dyn SinkNode + Send>;
+            Ok(sink)
+        }) as _,
         #[cfg(not(any(
             feature = "csv",
             feature = "parquet",
             feature = "json",
-            feature = "ipc"
+            feature = "ipc",
+            feature = "orc"
         )))]
         _ => {
             panic!("activate source feature")
@@ -6,20 +6,23 @@ use polars_core::prelude::{Column, Field};
 use polars_core::schema::{SchemaExt, SchemaRef};
 use polars_error::{PolarsResult, polars_bail, polars_err, polars_warn};
 use polars_io::RowIndex;
+use polars_io::bad_records::{BadRecords, ChunkBadRecords, LineCounter};
 use polars_io::cloud::CloudOptions;
 use polars_io::prelude::_csv_read_internal::{
     CountLines, NullValuesCompiled, cast_columns, find_starting_point, prepare_csv_schema,
     read_chunk,
 };
 use polars_io::prelude::buffer::validate_utf8;
+use polars_io::prelude::encoding::{Utf8Transcoder, maybe_transcode_bytes};
 use polars_io::prelude::{
     CommentPrefix, CsvEncoding, CsvParseOptions, CsvReadOptions, count_rows_from_slice_raw,
 };
-use polars_io::utils::compression::maybe_decompress_bytes;
+use polars_io::utils::compression::{Decompressor, maybe_decompress_bytes};
 use polars_io::utils::slice::SplitSlicePosition;
 use polars_plan::dsl::ScanSource;
 use polars_utils::IdxSize;
 use polars_utils::mmap::MemSlice;
+use polars_utils::pl_str::PlSmallStr;
 use polars_utils::slice_enum::Slice;
 
 use super::multi_scan::reader_interface::output::FileReaderOutputRecv;
@@ -54,8 +57,10 @@ pub mod builder {
         fn reader_capabilities(&self) -> ReaderCapabilities {
             use ReaderCapabilities as RC;
 
+            // Lines are sliced before they are parsed, so this does not account for comments or
+            // bad records.
             RC::NEEDS_FILE_CACHE_INIT
-                | if self.parse_options.comment_prefix.is_some() {
+                | if self.parse_options.comment_prefix.is_some() || self.bad_records.is_some() {
                     RC::empty()
                 } else {
                     RC::PRE_SLICE
@@ -78,6 +83,7 @@ pub mod builder {
                 options,
                 verbose,
                 cached_bytes: None,
+                cached_bytes_transcoded: false,
             };
 
             Box::new(reader) as Box<dyn FileReader>
@@ -92,12 +98,13 @@ const NO_SLICE: (usize, usize) = (0, usize::MAX);
 const SLICE_ENDED: (usize, usize) = (usize::MAX, 0);
 
 struct LineBatch {
-    // Safety: All receivers (LineBatchProcessors) hold a MemSlice ref to this.
-    bytes: &'static [u8],
+    bytes: MemSlice,
     n_lines: usize,
     slice: (usize, usize),
     /// Position of this chunk relative to the start of the file according to CountLines.
     row_offset: usize,
+    /// Line number of the start of this chunk. Only tracked when collecting bad records.
+    first_line: usize,
     morsel_seq: MorselSeq,
 }
 
@@ -108,6 +115,8 @@ struct CsvFileReader {
     options: Arc<CsvReadOptions>,
     // Cached on first access - we may be called multiple times e.g. on negative slice.
     cached_bytes: Option<MemSlice>,
+    /// Whether `cached_bytes` has already been transcoded to UTF-8.
+    cached_bytes_transcoded: bool,
     verbose: bool,
 }
 
@@ -131,8 +140,6 @@ impl FileReader for CsvFileReader {
     ) -> PolarsResult<(FileReaderOutputRecv, JoinHandle<PolarsResult<()>>)> {
         let verbose = self.verbose;
 
-        let memslice = self.get_bytes_maybe_decompress()?;
-
         let BeginReadArgs {
             projection: Projection::Plain(projected_schema),
             // Because we currently only support PRE_SLICE we don't need to handle row index here.
@@ -176,6 +183,15 @@ impl FileReader for CsvFileReader {
             self.options.infer_schema_length
         };
 
+        let file_size = self.cached_bytes.as_ref().unwrap().len();
+        // If the file is decompressed or transcoded while it is read, `memslice` only contains a
+        // prefix of the data.
+        let (memslice, block_reader) = self.get_bytes_or_block_reader(infer_schema_length)?;
+
+        if verbose && block_reader.is_some() {
+            eprintln!("[CsvFileReader]: decompressing or transcoding while reading");
+        }
+
         let (mut inferred_schema, ..) = polars_io::csv::read::infer_file_schema(
             &polars_io::mmap::ReaderBytes::Owned(memslice.clone()),
             &self.options.parse_options,
@@ -260,6 +276,10 @@ impl FileReader for CsvFileReader {
             projection,
             row_index,
             alt_count_lines.clone(),
+            self.scan_source
+                .as_scan_source_ref()
+                .to_include_path_name()
+                .into(),
         )?);
 
         let needs_full_row_count = n_rows_in_file_tx.is_some();
@@ -270,7 +290,9 @@ impl FileReader for CsvFileReader {
         let line_batch_source_handle = AbortOnDropHandle::new(spawn(
             TaskPriority::Low,
             LineBatchSource {
-                memslice: memslice.clone(),
+                memslice,
+                block_reader,
+                file_size,
                 line_counter: CountLines::new(
                     self.options.parse_options.quote_char,
                     self.options.parse_options.eol_char,
@@ -296,8 +318,6 @@ impl FileReader for CsvFileReader {
             .zip(morsel_senders)
             .enumerate()
             .map(|(worker_idx, (mut line_batch_rx, mut morsel_tx))| {
-                // Hold a ref as we are receiving `&'static [u8]`s pointing to this.
-                let global_memslice = memslice.clone();
                 // Only verbose log from the last worker to avoid flooding output.
                 let verbose = verbose && worker_idx == n_workers - 1;
                 let mut n_rows_processed: usize = 0;
@@ -312,22 +332,22 @@ impl FileReader for CsvFileReader {
                         n_lines,
                         slice,
                         row_offset,
+                        first_line,
                         morsel_seq,
                     }) = line_batch_rx.recv().await
                     {
-                        debug_assert!(bytes.as_ptr() as usize >= global_memslice.as_ptr() as usize);
-                        debug_assert!(
-                            bytes.as_ptr() as usize + bytes.len()
-                                <= global_memslice.as_ptr() as usize + global_memslice.len()
-                        );
-
                         let (offset, len) = match slice {
                             SLICE_ENDED => (0, 1),
                             v => v,
                         };
 
-                        let (df, n_rows_in_chunk) =
-                            chunk_reader.read_chunk(bytes, n_lines, (offset, len), row_offset)?;
+                        let (df, n_rows_in_chunk) = chunk_reader.read_chunk(
+                            &bytes,
+                            n_lines,
+                            (offset, len),
+                            row_offset,
+                            first_line,
+                        )?;
 
                         n_rows_processed = n_rows_processed.saturating_add(n_rows_in_chunk);
 
@@ -356,13 +376,14 @@ impl FileReader for CsvFileReader {
                             n_lines,
                             slice,
                             row_offset: _,
+                            first_line: _,
                             morsel_seq: _,
                         }) = line_batch_rx.recv().await
                         {
                             assert_eq!(slice, SLICE_ENDED);
 
                             let n_lines = if let Some(v) = alt_count_lines.as_deref() {
-                                v.count_lines(bytes)?
+                                v.count_lines(&bytes)?
                             } else {
                                 n_lines
                             };
@@ -410,6 +431,8 @@ impl FileReader for CsvFileReader {
 }
 
 impl CsvFileReader {
+    /// Decompresses and transcodes the file to UTF-8 if needed.
+    ///
     /// # Panics
     /// Panics if `self.cached_bytes` is None.
     fn get_bytes_maybe_decompress(&mut self) -> PolarsResult<MemSlice> {
@@ -420,12 +443,140 @@ impl CsvFileReader {
             self.cached_bytes = Some(MemSlice::from_vec(out));
         }
 
+        let encoding = self.options.parse_options.encoding;
+
+        if encoding.requires_transcoding() && !self.cached_bytes_transcoded {
+            let mut out = vec![];
+            maybe_transcode_bytes(self.cached_bytes.as_deref().unwrap(), &mut out, encoding)?;
+            self.cached_bytes = Some(MemSlice::from_vec(out));
+            self.cached_bytes_transcoded = true;
+        }
+
         Ok(self.cached_bytes.clone().unwrap())
     }
+
+    /// Like `get_bytes_maybe_decompress`, but if the file is compressed or needs transcoding,
+    /// only a prefix containing the header and the rows used for schema inference is decompressed
+    /// and transcoded. The rest of the data is left to the returned `BlockReader`.
+    ///
+    /// # Panics
+    /// Panics if `self.cached_bytes` is None.
+    fn get_bytes_or_block_reader(
+        &mut self,
+        infer_schema_length: Option<usize>,
+    ) -> PolarsResult<(MemSlice, Option<BlockReader>)> {
+        let options = self.options.as_ref();
+        let parse_options = options.parse_options.as_ref();
+
+        // Comments and full schema inference need the complete data up front.
+        let Some(infer_schema_length) =
+            infer_schema_length.filter(|_| parse_options.comment_prefix.is_none())
+        else {
+            return Ok((self.get_bytes_maybe_decompress()?, None));
+        };
+
+        let Some(mut block_reader) =
+            BlockReader::try_new(self.cached_bytes.clone().unwrap(), parse_options.encoding)?
+        else {
+            return Ok((self.get_bytes_maybe_decompress()?, None));
+        };
+
+        // One more line than needed, so that the last inferred line is complete.
+        let n_lines = (options.skip_lines
+            + options.skip_rows
+            + usize::from(options.has_header)
+            + options.skip_rows_after_header
+            + 1)
+        .saturating_add(infer_schema_length);
+        let line_counter = CountLines::new(parse_options.quote_char, parse_options.eol_char, None);
+
+        let mut prefix = vec![];
+        let mut block_size = 64 * 1024;
+
+        let eof = loop {
+            if block_reader.read_into(&mut prefix, block_size)? {
+                break true;
+            }
+            if line_counter.count(&prefix).0 >= n_lines {
+                break false;
+            }
+            block_size *= 2;
+        };
+
+        Ok((MemSlice::from_vec(prefix), (!eof).then_some(block_reader)))
+    }
+}
+
+enum BlockSource {
+    Decompressor(Decompressor),
+    Uncompressed { bytes: MemSlice, offset: usize },
+}
+
+/// Reads the file in blocks, decompressing and transcoding each block to UTF-8 as needed, so that
+/// neither the decompressed nor the transcoded file has to be held in memory at once.
+struct BlockReader {
+    source: BlockSource,
+    transcoder: Option<Utf8Transcoder>,
+    /// Block of decompressed data that is transcoded.
+    block: Vec<u8>,
+}
+
+impl BlockReader {
+    /// Returns `None` if `bytes` can be parsed as is.
+    fn try_new(bytes: MemSlice, encoding: CsvEncoding) -> PolarsResult<Option<Self>> {
+        let transcoder = Utf8Transcoder::new(encoding);
+        let source = match Decompressor::try_new(bytes.clone())? {
+            Some(decompressor) => BlockSource::Decompressor(decompressor),
+            None if transcoder.is_some() => BlockSource::Uncompressed { bytes, offset: 0 },
+            None => return Ok(None),
+        };
+
+        Ok(Some(Self {
+            source,
+            transcoder,
+            block: vec![],
+        }))
+    }
+
+    fn read_source_into(&mut self, buf: &mut Vec<u8>, n: usize) -> PolarsResult<usize> {
+        match &mut self.source {
+            BlockSource::Decompressor(decompressor) => decompressor.read_into(buf, n),
+            BlockSource::Uncompressed { bytes, offset } => {
+                let end = bytes.len().min(*offset + n);
+                buf.extend_from_slice(&bytes[*offset..end]);
+                let n_read = end - *offset;
+                *offset = end;
+                Ok(n_read)
+            },
+        }
+    }
+
+    /// Append the data of the next `n` decompressed bytes to `buf`. Returns whether the end of the
+    /// data was reached.
+    fn read_into(&mut self, buf: &mut Vec<u8>, n: usize) -> PolarsResult<bool> {
+        if self.transcoder.is_none() {
+            return Ok(self.read_source_into(buf, n)? < n);
+        }
+
+        let mut block = std::mem::take(&mut self.block);
+        block.clear();
+        let eof = self.read_source_into(&mut block, n)? < n;
+        self.transcoder
+            .as_mut()
+            .unwrap()
+            .transcode(&block, eof, buf);
+        self.block = block;
+        Ok(eof)
+    }
 }
 
 struct LineBatchSource {
     memslice: MemSlice,
+    /// Reads the data following `memslice`, if the file is decompressed or transcoded while it is
+    /// read.
+    block_reader: Option<BlockReader>,
+    /// Size of the (possibly compressed) file.
+    file_size: usize,
     line_counter: CountLines,
     line_batch_tx: distributor_channel::Sender<LineBatch>,
     options: Arc<CsvReadOptions>,
@@ -441,6 +592,8 @@ impl LineBatchSource {
     async fn run(self) -> PolarsResult<usize> {
         let LineBatchSource {
             memslice,
+            mut block_reader,
+            file_size,
             line_counter,
             mut line_batch_tx,
             options,
@@ -466,19 +619,21 @@ impl LineBatchSource {
 
         let morsel_seq_ref = &mut MorselSeq::default();
         let current_row_offset_ref = &mut 0usize;
+        let mut line_numbers = options
+            .bad_records
+            .as_ref()
+            .map(|_| LineCounter::new(options.parse_options.eol_char));
 
         if verbose {
             eprintln!("[CsvSource]: Start line splitting",);
         }
 
-        let global_bytes: &[u8] = memslice.as_ref();
-        let global_bytes: &'static [u8] = unsafe { std::mem::transmute(global_bytes) };
+        let eol_char = options.parse_options.eol_char;
 
         let i = {
             let parse_options = options.parse_options.as_ref();
 
             let quote_char = parse_options.quote_char;
-            let eol_char = parse_options.eol_char;
 
             let skip_lines = options.skip_lines;
             let skip_rows_before_header = options.skip_rows;
@@ -487,7 +642,7 @@ impl LineBatchSource {
             let has_header = options.has_header;
 
             find_starting_point(
-                global_bytes,
+                &memslice,
                 quote_char,
                 eol_char,
                 file_schema_len,
@@ -499,14 +654,23 @@ impl LineBatchSource {
             )?
         };
 
-        let mut bytes = &global_bytes[i..];
+        // The data that is split into line batches. When decompressing or transcoding, this is
+        // refilled with the next block of data once the data after `offset` runs low.
+        let mut buffer = memslice;
+        let mut offset = i;
+        let mut eof = block_reader.is_none();
 
         let mut chunk_size = {
             let max_chunk_size = 16 * 1024 * 1024;
+            let n_bytes = if eof {
+                buffer.len() - offset
+            } else {
+                file_size
+            };
             let chunk_size = if global_slice.is_some() {
                 max_chunk_size
             } else {
-                std::cmp::min(bytes.len() / (16 * num_pipelines), max_chunk_size)
+                std::cmp::min(n_bytes / (16 * num_pipelines), max_chunk_size)
             };
 
             // Use a small min chunk size to catch failures in tests.
@@ -518,21 +682,47 @@ impl LineBatchSource {
         };
 
         loop {
+            if !eof && buffer.len() - offset < chunk_size {
+                eof = refill_buffer(
+                    &mut buffer,
+                    &mut offset,
+                    block_reader.as_mut().unwrap(),
+                    4 * chunk_size,
+                    &mut line_numbers,
+                    eol_char,
+                )?;
+            }
+
+            let bytes = &buffer[offset..];
+
             if bytes.is_empty() {
                 break;
             }
 
             let (count, position) = line_counter.find_next(bytes, &mut chunk_size);
             let (count, position) = if count == 0 {
+                if !eof {
+                    // The line continues in the next block.
+                    eof = refill_buffer(
+                        &mut buffer,
+                        &mut offset,
+                        block_reader.as_mut().unwrap(),
+                        4 * chunk_size,
+                        &mut line_numbers,
+                        eol_char,
+                    )?;
+                    continue;
+                }
+
                 (1, bytes.len())
             } else {
                 let pos = (position + 1).min(bytes.len()); // +1 for '\n'
                 (count, pos)
             };
 
-            let slice_start = bytes.as_ptr() as usize - global_bytes.as_ptr() as usize;
+            let slice_start = offset;
 
-            bytes = &bytes[position..];
+            offset += position;
 
             let current_row_offset = *current_row_offset_ref;
             *current_row_offset_ref += count;
@@ -564,16 +754,21 @@ impl LineBatchSource {
                 NO_SLICE
             };
 
-            let bytes_this_chunk = &global_bytes[slice_start..slice_start + position];
+            let bytes_this_chunk = buffer.slice(slice_start..slice_start + position);
 
             let morsel_seq = *morsel_seq_ref;
             *morsel_seq_ref = morsel_seq.successor();
 
+            let first_line = line_numbers
+                .as_mut()
+                .map_or(0, |lines| lines.line_at(&buffer, slice_start));
+
             let batch = LineBatch {
                 bytes: bytes_this_chunk,
                 n_lines: count,
                 slice,
                 row_offset: current_row_offset,
+                first_line,
                 morsel_seq,
             };
 
@@ -586,6 +781,32 @@ impl LineBatchSource {
     }
 }
 
+/// Replaces `buffer` by its data after `offset`, followed by the data of the next `block_size`
+/// bytes read by `block_reader`. Returns whether the end of the data was reached.
+fn refill_buffer(
+    buffer: &mut MemSlice,
+    offset: &mut usize,
+    block_reader: &mut BlockReader,
+    block_size: usize,
+    line_numbers: &mut Option<LineCounter>,
+    eol_char: u8,
+) -> PolarsResult<bool> {
+    let remainder = &buffer[*offset..];
+
+    if let Some(lines) = line_numbers.as_mut() {
+        *lines = LineCounter::starting_at(eol_char, lines.line_at(buffer, *offset));
+    }
+
+    let mut new_buffer = Vec::with_capacity(remainder.len() + block_size);
+    new_buffer.extend_from_slice(remainder);
+    let eof = block_reader.read_into(&mut new_buffer, block_size)?;
+
+    *buffer = MemSlice::from_vec(new_buffer);
+    *offset = 0;
+
+    Ok(eof)
+}
+
 #[derive(Default)]
 struct ChunkReader {
     reader_schema: SchemaRef,
@@ -598,6 +819,9 @@ struct ChunkReader {
     row_index: Option<RowIndex>,
     // Alternate line counter when there are comments. This is used on empty projection.
     alt_count_lines: Option<Arc<CountLinesWithComments>>,
+    bad_records: Option<BadRecords>,
+    /// Path of the file, used to identify bad records.
+    path: PlSmallStr,
 }
 
 impl ChunkReader {
@@ -607,6 +831,7 @@ impl ChunkReader {
         projection: Vec<usize>,
         row_index: Option<RowIndex>,
         alt_count_lines: Option<Arc<CountLinesWithComments>>,
+        path: PlSmallStr,
     ) -> PolarsResult<Self> {
         let mut fields_to_cast: Vec<Field> = options.fields_to_cast.clone();
         prepare_csv_schema(&mut reader_schema, &mut fields_to_cast)?;
@@ -621,7 +846,9 @@ impl ChunkReader {
             .map(|nv| nv.compile(&reader_schema))
             .transpose()?;
 
+        // With bad records, string fields are validated during parsing instead.
         let validate_utf8 = matches!(parse_options.encoding, CsvEncoding::Utf8)
+            && options.bad_records.is_none()
             && reader_schema.iter_fields().any(|f| f.dtype().is_string());
 
         Ok(Self {
@@ -634,6 +861,8 @@ impl ChunkReader {
             validate_utf8,
             row_index,
             alt_count_lines,
+            bad_records: options.bad_records.clone(),
+            path,
         })
     }
 
@@ -645,13 +874,20 @@ impl ChunkReader {
         n_lines: usize,
         slice: (usize, usize),
         chunk_row_offset: usize,
+        first_line: usize,
     ) -> PolarsResult<(DataFrame, usize)> {
         if self.validate_utf8 && !validate_utf8(chunk) {
             polars_bail!(ComputeError: "invalid utf-8 sequence")
         }
 
+        let mut chunk_bad_records = self
+            .bad_records
+            .as_ref()
+            .map(|_| ChunkBadRecords::default());
+
         // If projection is empty create a DataFrame with the correct height by counting the lines.
-        let mut df = if self.projection.is_empty() {
+        // Bad records are only found by parsing, so we can't count lines when collecting them.
+        let mut df = if self.projection.is_empty() && chunk_bad_records.is_none() {
             let h = if let Some(v) = &self.alt_count_lines {
                 v.count_lines(chunk)?
             } else {
@@ -672,10 +908,10 @@ impl ChunkReader {
                 usize::MAX,  // chunk_size
                 chunk.len(), // stop_at_nbytes
                 Some(0),     // starting_point_offset
+                chunk_bad_records.as_mut(),
             )?
         };
 
-        let height = df.height();
         let n_lines_is_correct = df.height() == n_lines;
 
         // Check malformed
@@ -697,6 +933,23 @@ impl ChunkReader {
             }
         }
 
+        // Bad records don't count as rows. The reader does not support PRE_SLICE with bad
+        // records, so slices and row indices are applied to the remaining rows afterwards.
+        if let Some(chunk_bad_records) = &chunk_bad_records {
+            debug_assert!(slice == NO_SLICE && self.row_index.is_none());
+
+            let mut lines = LineCounter::starting_at(self.parse_options.eol_char, first_line);
+            self.bad_records.as_ref().unwrap().extend(
+                Some(&self.path),
+                chunk_bad_records,
+                chunk,
+                &mut lines,
+            );
+            df = chunk_bad_records.filter_rows(&df)?;
+        }
+
+        let height = df.height();
+
         if slice != NO_SLICE {
             assert!(slice != SLICE_ENDED);
             assert!(n_lines_is_correct || slice.1 == 0);
@@ -0,0 +1,357 @@
+use std::ops::Range;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use polars_core::prelude::Column;
+use polars_error::{PolarsResult, polars_err};
+use polars_io::RowIndex;
+use polars_io::cloud::CloudOptions;
+use polars_io::fwf::{FwfChunkParser, FwfReadOptions, count_lines, split_at_line};
+use polars_io::utils::compression::maybe_decompress_bytes;
+use polars_io::utils::slice::SplitSlicePosition;
+use polars_plan::dsl::ScanSource;
+use polars_utils::IdxSize;
+use polars_utils::mmap::MemSlice;
+use polars_utils::slice_enum::Slice;
+
+use super::multi_scan::reader_interface::output::FileReaderOutputRecv;
+use super::multi_scan::reader_interface::{BeginReadArgs, FileReader, FileReaderCallbacks};
+use crate::DEFAULT_DISTRIBUTOR_BUFFER_SIZE;
+use crate::async_executor::{AbortOnDropHandle, spawn};
+use crate::async_primitives::distributor_channel::{self, distributor_channel};
+use crate::morsel::{SourceToken, get_ideal_morsel_size};
+use crate::nodes::compute_node_prelude::*;
+use crate::nodes::io_sources::multi_scan::reader_interface::Projection;
+use crate::nodes::io_sources::multi_scan::reader_interface::output::FileReaderOutputSend;
+use crate::nodes::{MorselSeq, TaskPriority};
+
+pub mod builder {
+    use std::sync::Arc;
+
+    use polars_core::config;
+    use polars_io::cloud::CloudOptions;
+    use polars_io::fwf::FwfReadOptions;
+    use polars_plan::dsl::ScanSource;
+
+    use super::FwfFileReader;
+    use crate::nodes::io_sources::multi_scan::reader_interface::FileReader;
+    use crate::nodes::io_sources::multi_scan::reader_interface::builder::FileReaderBuilder;
+    use crate::nodes::io_sources::multi_scan::reader_interface::capabilities::ReaderCapabilities;
+
+    impl FileReaderBuilder for Arc<FwfReadOptions> {
+        fn reader_name(&self) -> &str {
+            "fwf"
+        }
+
+        fn reader_capabilities(&self) -> ReaderCapabilities {
+            use ReaderCapabilities as RC;
+
+            RC::NEEDS_FILE_CACHE_INIT | RC::PRE_SLICE
+        }
+
+        fn build_file_reader(
+            &self,
+            source: ScanSource,
+            cloud_options: Option<Arc<CloudOptions>>,
+            _scan_source_idx: usize,
+        ) -> Box<dyn FileReader> {
+            let reader = FwfFileReader {
+                scan_source: source,
+                cloud_options,
+                options: self.clone(),
+                verbose: config::verbose(),
+                cached_bytes: None,
+            };
+
+            Box::new(reader) as Box<dyn FileReader>
+        }
+    }
+}
+
+struct LineBatch {
+    bytes: MemSlice,
+    /// Slice of the lines in this batch to output.
+    slice: (usize, usize),
+    /// Row position of the start of this batch in the file.
+    row_offset: usize,
+    morsel_seq: MorselSeq,
+}
+
+struct FwfFileReader {
+    scan_source: ScanSource,
+    #[expect(unused)] // Will be used when implementing cloud streaming.
+    cloud_options: Option<Arc<CloudOptions>>,
+    options: Arc<FwfReadOptions>,
+    // Cached on first access - we may be called multiple times e.g. on negative slice.
+    cached_bytes: Option<MemSlice>,
+    verbose: bool,
+}
+
+#[async_trait]
+impl FileReader for FwfFileReader {
+    async fn initialize(&mut self) -> PolarsResult<()> {
+        let memslice = self
+            .scan_source
+            .as_scan_source_ref()
+            .to_memslice_async_assume_latest(self.scan_source.run_async())?;
+
+        // Note: We do not decompress in `initialize()`.
+        self.cached_bytes = Some(memslice);
+
+        Ok(())
+    }
+
+    fn begin_read(
+        &mut self,
+        args: BeginReadArgs,
+    ) -> PolarsResult<(FileReaderOutputRecv, JoinHandle<PolarsResult<()>>)> {
+        let verbose = self.verbose;
+
+        let memslice = self.get_bytes_maybe_decompress()?;
+
+        let BeginReadArgs {
+            projection: Projection::Plain(projected_schema),
+            row_index,
+            pre_slice,
+            predicate: None,
+            cast_columns_policy: _,
+            num_pipelines,
+            callbacks:
+                FileReaderCallbacks {
+                    file_schema_tx,
+                    n_rows_in_file_tx,
+                    row_position_on_end_tx,
+                },
+        } = args
+        else {
+            panic!("unsupported args: {:?}", &args)
+        };
+
+        let global_slice = match pre_slice {
+            None => None,
+            Some(pre_slice @ Slice::Positive { .. }) => Some(Range::<usize>::from(pre_slice)),
+            Some(Slice::Negative { .. }) => unimplemented!(),
+        };
+
+        // The schema is fully described by the column specification.
+        let file_schema = Arc::new(self.options.schema()?);
+
+        if let Some(mut tx) = file_schema_tx {
+            _ = tx.try_send(file_schema.clone())
+        }
+
+        // Only the byte ranges of the projected columns are parsed.
+        let projection = projected_schema
+            .iter_names()
+            .filter(|name| file_schema.contains(name))
+            .cloned()
+            .collect::<Vec<_>>();
+
+        if verbose {
+            eprintln!(
+                "[FwfFileReader]: project: {} / {}, slice: {:?}, row_index: {:?}",
+                projection.len(),
+                file_schema.len(),
+                &global_slice,
+                &row_index,
+            )
+        }
+
+        let parser = Arc::new(FwfChunkParser::try_new(
+            &self.options,
+            Some(projection.as_slice()),
+        )?);
+
+        let (line_batch_tx, line_batch_receivers) =
+            distributor_channel(num_pipelines, *DEFAULT_DISTRIBUTOR_BUFFER_SIZE);
+
+        let line_batch_source_handle = AbortOnDropHandle::new(spawn(
+            TaskPriority::Low,
+            LineBatchSource {
+                memslice,
+                line_batch_tx,
+                options: self.options.clone(),
+                global_slice,
+                needs_full_row_count: n_rows_in_file_tx.is_some(),
+            }
+            .run(),
+        ));
+
+        let (morsel_senders, rx) = FileReaderOutputSend::new_parallel(num_pipelines);
+
+        let line_batch_decode_handles = line_batch_receivers
+            .into_iter()
+            .zip(morsel_senders)
+            .map(|(mut line_batch_rx, mut morsel_tx)| {
+                let parser = parser.clone();
+                let row_index = row_index.clone();
+                // Note: We don't use this (it is handled by the bridge). But morsels require a source token.
+                let source_token = SourceToken::new();
+
+                AbortOnDropHandle::new(spawn(TaskPriority::Low, async move {
+                    while let Ok(LineBatch {
+                        bytes,
+                        slice,
+                        row_offset,
+                        morsel_seq,
+                    }) = line_batch_rx.recv().await
+                    {
+                        let df = parse_line_batch(&parser, &bytes, slice, row_offset, &row_index)?;
+                        let morsel = Morsel::new(df, morsel_seq, source_token.clone());
+
+                        if morsel_tx.send_morsel(morsel).await.is_err() {
+                            break;
+                        }
+                    }
+
+                    PolarsResult::Ok(())
+                }))
+            })
+            .collect::<Vec<_>>();
+
+        Ok((
+            rx,
+            spawn(TaskPriority::Low, async move {
+                for handle in line_batch_decode_handles {
+                    handle.await?;
+                }
+
+                let row_position = line_batch_source_handle.await?;
+                let row_position = IdxSize::try_from(row_position)
+                    .map_err(|_| polars_err!(bigidx, ctx = "fwf file", size = row_position))?;
+
+                if let Some(mut n_rows_in_file_tx) = n_rows_in_file_tx {
+                    _ = n_rows_in_file_tx.try_send(row_position);
+                }
+
+                if let Some(mut row_position_on_end_tx) = row_position_on_end_tx {
+                    _ = row_position_on_end_tx.try_send(row_position);
+                }
+
+                Ok(())
+            }),
+        ))
+    }
+}
+
+impl FwfFileReader {
+    /// # Panics
+    /// Panics if `self.cached_bytes` is None.
+    fn get_bytes_maybe_decompress(&mut self) -> PolarsResult<MemSlice> {
+        let mut out = vec![];
+        maybe_decompress_bytes(self.cached_bytes.as_deref().unwrap(), &mut out)?;
+
+        if !out.is_empty() {
+            self.cached_bytes = Some(MemSlice::from_vec(out));
+        }
+
+        Ok(self.cached_bytes.clone().unwrap())
+    }
+}
+
+fn parse_line_batch(
+    parser: &FwfChunkParser,
+    bytes: &[u8],
+    slice: (usize, usize),
+    row_offset: usize,
+    row_index: &Option<RowIndex>,
+) -> PolarsResult<DataFrame> {
+    let (offset, len) = slice;
+
+    // Lines before the slice are not parsed.
+    let bytes = split_at_line(bytes, parser.eol_char(), offset).2;
+    let bytes = split_at_line(bytes, parser.eol_char(), len).1;
+    let mut df = parser.parse(bytes)?;
+
+    if let Some(ri) = row_index {
+        let row_offset = IdxSize::try_from(row_offset + offset).unwrap_or(IdxSize::MAX);
+
+        unsafe {
+            df.with_column_unchecked(Column::new_row_index(
+                ri.name.clone(),
+                ri.offset.saturating_add(row_offset),
+                df.height(),
+            )?);
+        }
+    }
+
+    Ok(df)
+}
+
+struct LineBatchSource {
+    memslice: MemSlice,
+    line_batch_tx: distributor_channel::Sender<LineBatch>,
+    options: Arc<FwfReadOptions>,
+    global_slice: Option<Range<usize>>,
+    needs_full_row_count: bool,
+}
+
+impl LineBatchSource {
+    /// Returns the row position reached in the file.
+    async fn run(self) -> PolarsResult<usize> {
+        let LineBatchSource {
+            memslice,
+            mut line_batch_tx,
+            options,
+            global_slice,
+            needs_full_row_count,
+        } = self;
+
+        let eol_char = options.eol_char;
+        let lines_per_batch = get_ideal_morsel_size();
+
+        let (_, _, mut bytes) = split_at_line(&memslice, eol_char, options.skip_rows);
+        let mut row_offset = 0;
+        let mut morsel_seq = MorselSeq::default();
+
+        while !bytes.is_empty() {
+            let (n_lines, batch, rest) = split_at_line(bytes, eol_char, lines_per_batch);
+            let batch_row_offset = row_offset;
+
+            let slice = if let Some(global_slice) = &global_slice {
+                match SplitSlicePosition::split_slice_at_file(
+                    batch_row_offset,
+                    n_lines,
+                    global_slice.clone(),
+                ) {
+                    SplitSlicePosition::Before => {
+                        row_offset += n_lines;
+                        bytes = rest;
+                        continue;
+                    },
+                    SplitSlicePosition::Overlapping(offset, len) => (offset, len),
+                    SplitSlicePosition::After => {
+                        if needs_full_row_count {
+                            row_offset += count_lines(bytes, eol_char);
+                        }
+                        break;
+                    },
+                }
+            } else {
+                (0, n_lines)
+            };
+
+            row_offset += n_lines;
+            bytes = rest;
+
+            let offset = batch.as_ptr() as usize - memslice.as_ptr() as usize;
+            let batch = LineBatch {
+                bytes: memslice.slice(offset..offset + batch.len()),
+                slice,
+                row_offset: batch_row_offset,
+                morsel_seq,
+            };
+            morsel_seq = morsel_seq.successor();
+
+            if line_batch_tx.send(batch).await.is_err() {
+                // The remaining lines are still counted if needed.
+                if needs_full_row_count {
+                    row_offset += count_lines(bytes, eol_char);
+                }
+                break;
+            }
+        }
+
+        Ok(row_offset)
+    }
+}
@@ -3,9 +3,13 @@ pub mod multi_scan;
 pub mod batch;
 #[cfg(feature = "csv")]
 pub mod csv;
+#[cfg(feature = "fwf")]
+pub mod fwf;
 #[cfg(feature = "ipc")]
 pub mod ipc;
 #[cfg(feature = "json")]
 pub mod ndjson;
+#[cfg(feature = "orc")]
+pub mod orc;
 #[cfg(feature = "parquet")]
 pub mod parquet;
@@ -458,6 +458,12 @@ fn initialize_predicate<'a>(
             );
         }
 
+        if let Some(metrics) = &predicate.metrics {
+            metrics
+                .files_skipped
+                .fetch_add(skip_files_mask.num_skipped_files());
+        }
+
         return Ok((
             Some(skip_files_mask),
             send_predicate_to_readers.then_some(predicate),
@@ -1,8 +1,10 @@
 use polars_core::schema::SchemaRef;
 use polars_error::PolarsResult;
+use polars_io::bad_records::{BadRecords, ChunkBadRecords, LineCounter};
 use polars_io::ndjson;
 use polars_io::prelude::parse_ndjson;
 use polars_plan::dsl::NDJsonReadOptions;
+use polars_utils::pl_str::PlSmallStr;
 
 use crate::nodes::compute_node_prelude::*;
 
@@ -11,26 +13,61 @@ use crate::nodes::compute_node_prelude::*;
 pub(super) struct ChunkReader {
     projected_schema: SchemaRef,
     ignore_errors: bool,
+    bad_records: Option<BadRecords>,
+    /// Path of the file, used to identify bad records.
+    path: PlSmallStr,
 }
 
 impl ChunkReader {
     pub(super) fn try_new(
         options: &NDJsonReadOptions,
         projected_schema: &SchemaRef,
+        path: PlSmallStr,
     ) -> PolarsResult<Self> {
         let projected_schema = projected_schema.clone();
 
         Ok(Self {
             projected_schema,
             ignore_errors: options.ignore_errors,
+            bad_records: options.bad_records.clone(),
+            path,
         })
     }
 
-    pub(super) fn read_chunk(&self, chunk: &[u8]) -> PolarsResult<DataFrame> {
-        if self.projected_schema.is_empty() {
-            Ok(DataFrame::empty_with_height(ndjson::count_rows(chunk)))
-        } else {
-            parse_ndjson(chunk, None, &self.projected_schema, self.ignore_errors)
+    /// Whether line numbers have to be tracked to locate bad records.
+    pub(super) fn needs_line_numbers(&self) -> bool {
+        self.bad_records.is_some()
+    }
+
+    /// `first_line` is the line number of the start of `chunk`, it is only used if
+    /// [`Self::needs_line_numbers`].
+    pub(super) fn read_chunk(&self, chunk: &[u8], first_line: usize) -> PolarsResult<DataFrame> {
+        // Bad records can only be found by parsing the lines.
+        if self.projected_schema.is_empty() && self.bad_records.is_none() {
+            return Ok(DataFrame::empty_with_height(ndjson::count_rows(chunk)));
         }
+
+        let Some(bad_records) = &self.bad_records else {
+            return parse_ndjson(
+                chunk,
+                None,
+                &self.projected_schema,
+                self.ignore_errors,
+                None,
+            );
+        };
+
+        let mut chunk_bad_records = ChunkBadRecords::default();
+        let df = parse_ndjson(
+            chunk,
+            None,
+            &self.projected_schema,
+            self.ignore_errors,
+            Some(&mut chunk_bad_records),
+        )?;
+
+        let mut lines = LineCounter::starting_at(b'\n', first_line);
+        bad_records.extend(Some(&self.path), &chunk_bad_records, chunk, &mut lines);
+        chunk_bad_records.filter_rows(&df)
     }
 }
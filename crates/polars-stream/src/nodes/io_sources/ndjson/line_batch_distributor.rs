@@ -1,6 +1,8 @@
 use polars_core::config;
 use polars_error::PolarsResult;
+use polars_io::bad_records::LineCounter;
 use polars_io::prelude::json_lines;
+use polars_io::utils::compression::Decompressor;
 use polars_utils::idx_mapper::IdxMapper;
 use polars_utils::mmap::MemSlice;
 
@@ -9,26 +11,35 @@ use crate::async_primitives::distributor_channel;
 
 pub(super) struct LineBatchDistributor {
     pub(super) global_bytes: MemSlice,
+    /// Set if `global_bytes` is compressed, in which case it is decompressed while it is split
+    /// into line batches. Not supported with `reverse`.
+    pub(super) decompressor: Option<Decompressor>,
     pub(super) chunk_size: usize,
     pub(super) n_rows_to_skip: usize,
     pub(super) reverse: bool,
+    /// Whether to track the line numbers of the chunks.
+    pub(super) track_line_numbers: bool,
     pub(super) line_batch_distribute_tx: distributor_channel::Sender<LineBatch>,
 }
 
 impl LineBatchDistributor {
     /// Returns the number of rows skipped (i.e. were not sent to LineBatchProcessors).
-    pub(super) async fn run(self) -> PolarsResult<usize> {
+    pub(super) async fn run(mut self) -> PolarsResult<usize> {
+        if let Some(decompressor) = self.decompressor.take() {
+            return self.run_decompressing(decompressor).await;
+        }
+
         let LineBatchDistributor {
             global_bytes: global_bytes_mem_slice,
+            decompressor: _,
             chunk_size,
             n_rows_to_skip,
             reverse,
+            track_line_numbers,
             mut line_batch_distribute_tx,
         } = self;
 
-        // Safety: All receivers (LineBatchProcessors) hold a MemSlice ref to this.
-        let global_bytes: &'static [u8] =
-            unsafe { std::mem::transmute(global_bytes_mem_slice.as_ref()) };
+        let global_bytes: &[u8] = global_bytes_mem_slice.as_ref();
         let n_chunks = global_bytes.len().div_ceil(chunk_size);
         let verbose = config::verbose();
 
@@ -53,7 +64,7 @@ impl LineBatchDistributor {
         // The logic below processes in fixed chunks with remainder handling so that in the future
         // we can handle receiving data in a batched manner.
 
-        let mut prev_remainder: &'static [u8] = &[];
+        let mut prev_remainder: &[u8] = &[];
 
         let global_idx_map = IdxMapper::new(global_bytes.len(), reverse);
 
@@ -62,6 +73,8 @@ impl LineBatchDistributor {
             reverse,
         };
 
+        let mut line_counter = track_line_numbers.then(|| LineCounter::new(b'\n'));
+
         for chunk_idx in 0..n_chunks {
             let offset = chunk_idx.saturating_mul(chunk_size);
             let range = offset..offset.saturating_add(chunk_size).min(global_bytes.len());
@@ -103,16 +116,22 @@ impl LineBatchDistributor {
                 prev_remainder = &[];
                 row_skipper.skip_rows(&mut full_chunk);
 
-                if !full_chunk.is_empty()
-                    && line_batch_distribute_tx
-                        .send(LineBatch {
-                            bytes: full_chunk,
-                            chunk_idx,
-                        })
-                        .await
-                        .is_err()
-                {
-                    break;
+                // Note: `full_chunk` may not point into `global_bytes` if it is empty.
+                if !full_chunk.is_empty() {
+                    let offset = full_chunk.as_ptr() as usize - global_bytes.as_ptr() as usize;
+                    let first_line = line_counter
+                        .as_mut()
+                        .map_or(0, |lines| lines.line_at(global_bytes, offset));
+
+                    let batch = LineBatch {
+                        bytes: global_bytes_mem_slice.slice(offset..offset + full_chunk.len()),
+                        chunk_idx,
+                        first_line,
+                    };
+
+                    if line_batch_distribute_tx.send(batch).await.is_err() {
+                        break;
+                    }
                 }
             }
 
@@ -136,6 +155,86 @@ impl LineBatchDistributor {
 
         Ok(n_rows_skipped)
     }
+
+    /// Splits the data into line batches while it is being decompressed, in blocks of (at least)
+    /// `chunk_size` decompressed bytes.
+    async fn run_decompressing(self, mut decompressor: Decompressor) -> PolarsResult<usize> {
+        let LineBatchDistributor {
+            global_bytes: _,
+            decompressor: _,
+            chunk_size,
+            n_rows_to_skip,
+            reverse,
+            track_line_numbers,
+            mut line_batch_distribute_tx,
+        } = self;
+
+        assert!(!reverse);
+
+        if config::verbose() {
+            eprintln!(
+                "[NDJSON LineBatchDistributor]: decompressing, chunk_size: {chunk_size}, n_rows_to_skip: {n_rows_to_skip}"
+            )
+        }
+
+        let mut row_skipper = RowSkipper {
+            remaining_rows_to_skip: n_rows_to_skip,
+            reverse,
+        };
+
+        let mut line_counter = track_line_numbers.then(|| LineCounter::new(b'\n'));
+
+        // Data after the last newline char of the previous block.
+        let mut remainder: Vec<u8> = vec![];
+
+        for chunk_idx in 0.. {
+            let mut chunk = std::mem::take(&mut remainder);
+            // Read at least as much as the remainder, to not repeatedly copy long lines.
+            let n_bytes_to_read = chunk_size.max(chunk.len());
+            let eof = decompressor.read_into(&mut chunk, n_bytes_to_read)? < n_bytes_to_read;
+
+            if !eof {
+                // chunk:     ---------\n---
+                // remainder:            ---
+                let remainder_len = chunk.rsplit(|&c| c == b'\n').next().unwrap().len();
+                remainder = chunk.split_off(chunk.len() - remainder_len);
+            }
+
+            let chunk = MemSlice::from_vec(chunk);
+            let mut full_chunk: &[u8] = &chunk;
+            row_skipper.skip_rows(&mut full_chunk);
+
+            if !full_chunk.is_empty() {
+                let offset = chunk.len() - full_chunk.len();
+                let first_line = line_counter
+                    .as_mut()
+                    .map_or(0, |lines| lines.line_at(&chunk, offset));
+
+                let batch = LineBatch {
+                    bytes: chunk.slice(offset..chunk.len()),
+                    chunk_idx,
+                    first_line,
+                };
+
+                if line_batch_distribute_tx.send(batch).await.is_err() {
+                    break;
+                }
+            }
+
+            if eof {
+                break;
+            }
+
+            // The next chunk starts with the remainder of this one.
+            if let Some(lines) = line_counter.as_mut() {
+                *lines = LineCounter::starting_at(b'\n', lines.line_at(&chunk, chunk.len()));
+            }
+        }
+
+        let n_rows_skipped = n_rows_to_skip - row_skipper.remaining_rows_to_skip;
+
+        Ok(n_rows_skipped)
+    }
 }
 
 struct RowSkipper {
@@ -19,9 +19,6 @@ pub(super) struct LineBatchProcessor {
     /// Mainly for logging
     pub(super) worker_idx: usize,
 
-    /// We need to hold a ref to this as `LineBatch` we receive contains `&[u8]`
-    /// references to it.
-    pub(super) global_bytes: MemSlice,
     pub(super) chunk_reader: Arc<ChunkReader>,
 
     // Input
@@ -41,7 +38,6 @@ impl LineBatchProcessor {
     pub(super) async fn run(self) -> PolarsResult<usize> {
         let LineBatchProcessor {
             worker_idx,
-            global_bytes: _global_bytes,
             chunk_reader,
             mut line_batch_rx,
             mut output_port,
@@ -59,8 +55,13 @@ impl LineBatchProcessor {
 
         let mut n_rows_processed: usize = 0;
 
-        while let Ok(LineBatch { bytes, chunk_idx }) = line_batch_rx.recv().await {
-            let df = chunk_reader.read_chunk(bytes)?;
+        while let Ok(LineBatch {
+            bytes,
+            chunk_idx,
+            first_line,
+        }) = line_batch_rx.recv().await
+        {
+            let df = chunk_reader.read_chunk(&bytes, first_line)?;
 
             n_rows_processed = n_rows_processed.saturating_add(df.height());
 
@@ -79,9 +80,10 @@ impl LineBatchProcessor {
             while let Ok(LineBatch {
                 bytes,
                 chunk_idx: _,
+                first_line: _,
             }) = line_batch_rx.recv().await
             {
-                n_rows_processed = n_rows_processed.saturating_add(ndjson::count_rows(bytes));
+                n_rows_processed = n_rows_processed.saturating_add(ndjson::count_rows(&bytes));
             }
         }
 
@@ -95,9 +97,10 @@ impl LineBatchProcessor {
 
 /// Represents a complete chunk of NDJSON data (i.e. no partial lines).
 pub(super) struct LineBatch {
-    /// Safety: This is sent between 2 places that both hold a reference to the underlying MemSlice.
-    pub(super) bytes: &'static [u8],
+    pub(super) bytes: MemSlice,
     pub(super) chunk_idx: usize,
+    /// Line number of the start of this chunk. Only tracked when collecting bad records.
+    pub(super) first_line: usize,
 }
 
 /// We are connected to different outputs depending on query.
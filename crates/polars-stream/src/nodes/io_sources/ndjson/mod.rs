@@ -12,7 +12,7 @@ use polars_core::schema::SchemaRef;
 use polars_error::{PolarsResult, polars_bail, polars_err};
 use polars_io::cloud::CloudOptions;
 use polars_io::prelude::estimate_n_lines_in_file;
-use polars_io::utils::compression::maybe_decompress_bytes;
+use polars_io::utils::compression::{Decompressor, maybe_decompress_bytes};
 use polars_plan::dsl::{NDJsonReadOptions, ScanSource};
 use polars_utils::IdxSize;
 use polars_utils::mem::prefetch::get_memory_prefetch_func;
@@ -80,9 +80,18 @@ impl FileReader for NDJsonFileReader {
             panic!("unsupported args: {:?}", &args)
         };
 
-        // TODO: This currently downloads and decompresses everything upfront in a blocking manner.
-        // Ideally we have a streaming download/decompression.
-        let global_bytes = self.get_bytes_maybe_decompress()?;
+        let is_negative_slice = matches!(pre_slice, Some(Slice::Negative { .. }));
+
+        // TODO: This currently downloads everything upfront in a blocking manner.
+        // Ideally we have a streaming download.
+        //
+        // Compressed files are decompressed while they are read, except for negative slices, which
+        // are read from the end of the file.
+        let (global_bytes, decompressor) = if is_negative_slice {
+            (self.get_bytes_maybe_decompress()?, None)
+        } else {
+            self.get_bytes_or_decompressor()?
+        };
 
         // NDJSON: We just use the projected schema - the parser will automatically append NULL if
         // the field is not found.
@@ -97,8 +106,6 @@ impl FileReader for NDJsonFileReader {
             _ = tx.try_send(schema.clone())
         }
 
-        let is_negative_slice = matches!(pre_slice, Some(Slice::Negative { .. }));
-
         // Convert (offset, len) to Range
         // Note: This is converted to right-to-left for negative slice (i.e. range.start is position
         // from end).
@@ -134,7 +141,8 @@ impl FileReader for NDJsonFileReader {
 
         let chunk_size: usize = {
             let n_bytes_to_split = if let Some(x) = global_slice.as_ref() {
-                if needs_total_row_count {
+                // Note: The size of compressed data is used as is.
+                if needs_total_row_count || decompressor.is_some() {
                     global_bytes.len()
                 } else {
                     // There may be early stopping, try to heuristically use a smaller chunk size to stop faster.
@@ -181,13 +189,15 @@ impl FileReader for NDJsonFileReader {
                 row_index: {:?}, \
                 chunk_size: {}, \
                 n_chunks: {}, \
-                is_negative_slice: {}",
+                is_negative_slice: {}, \
+                decompressing: {}",
                 schema.len(),
                 &global_slice,
                 &row_index,
                 chunk_size,
                 global_bytes.len().div_ceil(chunk_size),
                 is_negative_slice,
+                decompressor.is_some(),
             );
         }
 
@@ -285,7 +295,7 @@ impl FileReader for NDJsonFileReader {
         let schema = Arc::new(schema);
         let chunk_reader = Arc::new(self.try_init_chunk_reader(&schema)?);
 
-        if !is_negative_slice {
+        if !is_negative_slice && decompressor.is_none() {
             get_memory_prefetch_func(verbose)(global_bytes.as_ref());
         }
 
@@ -308,7 +318,6 @@ impl FileReader for NDJsonFileReader {
             .enumerate()
             .rev()
             .map(|(worker_idx, line_batch_rx)| {
-                let global_bytes = global_bytes.clone();
                 let chunk_reader = chunk_reader.clone();
                 // Note: We don't use this (it is handled by the bridge). But morsels require a source token.
                 let source_token = SourceToken::new();
@@ -318,7 +327,6 @@ impl FileReader for NDJsonFileReader {
                     LineBatchProcessor {
                         worker_idx,
 
-                        global_bytes,
                         chunk_reader,
 
                         line_batch_rx,
@@ -346,9 +354,11 @@ impl FileReader for NDJsonFileReader {
             TaskPriority::Low,
             line_batch_distributor::LineBatchDistributor {
                 global_bytes,
+                decompressor,
                 chunk_size,
                 n_rows_to_skip,
                 reverse: is_negative_slice,
+                track_line_numbers: chunk_reader.needs_line_numbers(),
                 line_batch_distribute_tx,
             }
             .run(),
@@ -436,10 +446,17 @@ impl FileReader for NDJsonFileReader {
 
 impl NDJsonFileReader {
     fn try_init_chunk_reader(&self, schema: &SchemaRef) -> PolarsResult<ChunkReader> {
-        ChunkReader::try_new(&self.options, schema)
+        ChunkReader::try_new(
+            &self.options,
+            schema,
+            self.scan_source
+                .as_scan_source_ref()
+                .to_include_path_name()
+                .into(),
+        )
     }
 
-    fn get_bytes_maybe_decompress(&mut self) -> PolarsResult<MemSlice> {
+    fn get_bytes(&mut self) -> PolarsResult<MemSlice> {
         if self.cached_bytes.is_none() {
             let run_async = self.scan_source.run_async();
             let source = self
@@ -447,20 +464,30 @@ impl NDJsonFileReader {
                 .as_scan_source_ref()
                 .to_memslice_async_assume_latest(run_async)?;
 
-            let memslice = {
-                let mut out = vec![];
-                maybe_decompress_bytes(&source, &mut out)?;
+            self.cached_bytes = Some(source);
+        }
 
-                if out.is_empty() {
-                    source
-                } else {
-                    MemSlice::from_vec(out)
-                }
-            };
+        Ok(self.cached_bytes.clone().unwrap())
+    }
+
+    fn get_bytes_maybe_decompress(&mut self) -> PolarsResult<MemSlice> {
+        let source = self.get_bytes()?;
+
+        let mut out = vec![];
+        maybe_decompress_bytes(&source, &mut out)?;
 
-            self.cached_bytes = Some(memslice);
+        if !out.is_empty() {
+            self.cached_bytes = Some(MemSlice::from_vec(out));
         }
 
         Ok(self.cached_bytes.clone().unwrap())
     }
+
+    /// Returns the bytes of the file, along with a `Decompressor` of them if they are compressed.
+    fn get_bytes_or_decompressor(&mut self) -> PolarsResult<(MemSlice, Option<Decompressor>)> {
+        let source = self.get_bytes()?;
+        let decompressor = Decompressor::try_new(source.clone())?;
+
+        Ok((source, decompressor))
+    }
 }
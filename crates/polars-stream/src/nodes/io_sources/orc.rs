@@ -0,0 +1,580 @@
+use std::cmp::Reverse;
+use std::ops::Range;
+use std::sync::Arc;
+
+use arrow::bitmap::Bitmap;
+use async_trait::async_trait;
+use polars_core::frame::DataFrame;
+use polars_core::prelude::{Column, IdxCa, IntoColumn, NewChunkedArray};
+use polars_error::PolarsResult;
+use polars_io::RowIndex;
+use polars_io::cloud::CloudOptions;
+use polars_io::orc::{OrcMetadata, OrcScanOptions, read_metadata, read_stripe};
+use polars_io::predicates::ScanIOPredicate;
+use polars_plan::dsl::{CastColumnsPolicy, ScanSource, ScanSourceRef};
+use polars_utils::mmap::MemSlice;
+use polars_utils::pl_str::PlSmallStr;
+use polars_utils::priority::Priority;
+use polars_utils::slice_enum::Slice;
+use polars_utils::{IdxSize, format_pl_smallstr};
+
+use super::multi_scan::components::column_selector::ColumnSelector;
+use super::multi_scan::components::projection::MappedProjectionRef;
+use super::multi_scan::components::projection::builder::ProjectionBuilder;
+use super::multi_scan::reader_interface::output::{FileReaderOutputRecv, FileReaderOutputSend};
+use super::multi_scan::reader_interface::{
+    BeginReadArgs, FileReader, FileReaderCallbacks, Projection, calc_row_position_after_slice,
+};
+use crate::async_executor::{AbortOnDropHandle, JoinHandle, TaskPriority, spawn};
+use crate::async_primitives::distributor_channel::distributor_channel;
+use crate::async_primitives::linearizer::Linearizer;
+use crate::morsel::{Morsel, MorselSeq, SourceToken, get_ideal_morsel_size};
+use crate::{DEFAULT_DISTRIBUTOR_BUFFER_SIZE, DEFAULT_LINEARIZER_BUFFER_SIZE};
+
+pub mod builder {
+    use std::sync::Arc;
+
+    use polars_core::config;
+    use polars_io::cloud::CloudOptions;
+    use polars_io::orc::{OrcMetadata, OrcScanOptions};
+    use polars_plan::dsl::ScanSource;
+
+    use super::OrcFileReader;
+    use crate::nodes::io_sources::multi_scan::reader_interface::FileReader;
+    use crate::nodes::io_sources::multi_scan::reader_interface::builder::FileReaderBuilder;
+    use crate::nodes::io_sources::multi_scan::reader_interface::capabilities::ReaderCapabilities;
+
+    #[derive(Debug)]
+    pub struct OrcReaderBuilder {
+        pub options: Arc<OrcScanOptions>,
+        pub first_metadata: Option<Arc<OrcMetadata>>,
+    }
+
+    impl FileReaderBuilder for OrcReaderBuilder {
+        fn reader_name(&self) -> &str {
+            "orc"
+        }
+
+        fn reader_capabilities(&self) -> ReaderCapabilities {
+            use ReaderCapabilities as RC;
+
+            RC::NEEDS_FILE_CACHE_INIT
+                | RC::ROW_INDEX
+                | RC::PRE_SLICE
+                | RC::NEGATIVE_PRE_SLICE
+                | RC::PARTIAL_FILTER
+                | RC::MAPPED_COLUMN_PROJECTION
+        }
+
+        fn build_file_reader(
+            &self,
+            source: ScanSource,
+            cloud_options: Option<Arc<CloudOptions>>,
+            scan_source_idx: usize,
+        ) -> Box<dyn FileReader> {
+            let metadata = if scan_source_idx == 0 {
+                self.first_metadata.clone()
+            } else {
+                None
+            };
+
+            let reader = OrcFileReader {
+                scan_source: source,
+                cloud_options,
+                options: self.options.clone(),
+                metadata,
+                verbose: config::verbose(),
+                init_data: None,
+            };
+
+            Box::new(reader) as Box<dyn FileReader>
+        }
+    }
+}
+
+struct OrcFileReader {
+    scan_source: ScanSource,
+    cloud_options: Option<Arc<CloudOptions>>,
+    options: Arc<OrcScanOptions>,
+    metadata: Option<Arc<OrcMetadata>>,
+    verbose: bool,
+
+    init_data: Option<InitializedState>,
+}
+
+#[derive(Clone)]
+struct InitializedState {
+    memslice: MemSlice,
+    metadata: Arc<OrcMetadata>,
+}
+
+/// A top-level field of the ORC file that is projected, potentially casted and/or renamed.
+struct OrcFieldProjection {
+    field_idx: usize,
+    output_name: PlSmallStr,
+    transform: Option<ColumnSelector>,
+}
+
+impl OrcFieldProjection {
+    fn apply_transform(&self, column: Column) -> PolarsResult<Column> {
+        match &self.transform {
+            None => Ok(column),
+            Some(transform) => {
+                let output_height = column.len();
+                transform.select_from_columns(&[column], output_height)
+            },
+        }
+    }
+}
+
+fn resolve_field_projections(
+    metadata: &OrcMetadata,
+    projection: Projection,
+    cast_columns_policy: CastColumnsPolicy,
+) -> PolarsResult<Arc<[OrcFieldProjection]>> {
+    let file_schema = metadata.schema();
+
+    let projection: Projection = match projection {
+        Projection::Plain(projected_schema) => ProjectionBuilder::new(projected_schema, None, None)
+            .build_projection(Some(file_schema), None, cast_columns_policy, usize::MAX)?,
+        Projection::Mapped { .. } => projection,
+    };
+
+    Ok(projection
+        .iter_non_missing_columns()
+        .map(
+            |MappedProjectionRef {
+                 source_name,
+                 output_name,
+                 output_dtype: _,
+                 resolved_transform,
+             }| {
+                let field_idx = file_schema.index_of(source_name.as_str()).unwrap();
+
+                OrcFieldProjection {
+                    field_idx,
+                    output_name: output_name.clone(),
+                    transform: resolved_transform
+                        .map(|t| t.attach_transforms(ColumnSelector::Position(0))),
+                }
+            },
+        )
+        .collect())
+}
+
+#[async_trait]
+impl FileReader for OrcFileReader {
+    async fn initialize(&mut self) -> PolarsResult<()> {
+        if self.init_data.is_some() {
+            return Ok(());
+        }
+
+        // check_latest: IR resolution only downloads the first file.
+        if let ScanSourceRef::Path(addr) = self.scan_source.as_scan_source_ref() {
+            polars_io::file_cache::init_entries_from_uri_list(
+                [Arc::from(addr.to_str())].into_iter(),
+                self.cloud_options.as_deref(),
+            )?;
+        }
+
+        let memslice = self
+            .scan_source
+            .as_scan_source_ref()
+            .to_memslice_async_check_latest(self.scan_source.run_async())?;
+
+        let metadata = if let Some(v) = self.metadata.clone() {
+            v
+        } else {
+            Arc::new(read_metadata(memslice.as_ref())?)
+        };
+
+        self.init_data = Some(InitializedState { memslice, metadata });
+
+        Ok(())
+    }
+
+    fn begin_read(
+        &mut self,
+        args: BeginReadArgs,
+    ) -> PolarsResult<(FileReaderOutputRecv, JoinHandle<PolarsResult<()>>)> {
+        let verbose = self.verbose;
+
+        let InitializedState { memslice, metadata } = self.init_data.clone().unwrap();
+
+        let BeginReadArgs {
+            projection,
+            row_index,
+            pre_slice: pre_slice_arg,
+            predicate,
+            cast_columns_policy,
+            num_pipelines,
+            callbacks:
+                FileReaderCallbacks {
+                    file_schema_tx,
+                    n_rows_in_file_tx,
+                    row_position_on_end_tx,
+                },
+        } = args;
+
+        let n_rows_in_file = metadata.num_rows();
+
+        let normalized_pre_slice = pre_slice_arg
+            .clone()
+            .map(|pre_slice| pre_slice.restrict_to_bounds(n_rows_in_file));
+
+        if let Some(mut n_rows_in_file_tx) = n_rows_in_file_tx {
+            _ = n_rows_in_file_tx.try_send(self._n_rows_in_file()?);
+        }
+
+        if let Some(mut row_position_on_end_tx) = row_position_on_end_tx {
+            _ = row_position_on_end_tx
+                .try_send(self._row_position_after_slice(normalized_pre_slice.clone())?);
+        }
+
+        if let Some(mut file_schema_tx) = file_schema_tx {
+            _ = file_schema_tx.try_send(metadata.schema().clone());
+        }
+
+        if normalized_pre_slice.as_ref().is_some_and(|x| x.len() == 0) {
+            let (_, rx) = FileReaderOutputSend::new_serial();
+
+            if verbose {
+                eprintln!(
+                    "[OrcFileReader]: early return: \
+                    n_rows_in_file: {n_rows_in_file}, \
+                    pre_slice: {pre_slice_arg:?}, \
+                    resolved_pre_slice: {normalized_pre_slice:?} \
+                    "
+                )
+            }
+
+            return Ok((rx, spawn(TaskPriority::Low, std::future::ready(Ok(())))));
+        }
+
+        let field_projections =
+            resolve_field_projections(&metadata, projection, cast_columns_policy)?;
+        let projection_indices: Arc<[usize]> =
+            field_projections.iter().map(|p| p.field_idx).collect();
+
+        if verbose {
+            eprintln!(
+                "[OrcFileReader]: \
+                project: {} / {}, \
+                pre_slice: {:?}, \
+                resolved_pre_slice: {:?} \
+                ",
+                projection_indices.len(),
+                metadata.schema().len(),
+                pre_slice_arg,
+                normalized_pre_slice
+            )
+        }
+
+        // Always create a slice. If no slice was given, just make the biggest slice possible.
+        let slice: Range<usize> = normalized_pre_slice.map_or(0..usize::MAX, Range::<usize>::from);
+
+        let max_morsel_size = get_ideal_morsel_size();
+
+        /// Messages sent from Walker task to Decoder tasks.
+        struct StripeMessage {
+            stripe_idx: usize,
+            /// Slice of the rows within the stripe.
+            slice: Range<usize>,
+            /// Position of the first row of `slice` within the file.
+            row_position: usize,
+            morsel_seq_base: u64,
+        }
+
+        let (mut morsel_sender, morsel_rx) = FileReaderOutputSend::new_serial();
+
+        // Walker task -> Decoder tasks.
+        let (mut stripe_tx, stripe_rxs) =
+            distributor_channel::<StripeMessage>(num_pipelines, *DEFAULT_DISTRIBUTOR_BUFFER_SIZE);
+        // Decoder tasks -> Distributor task.
+        let (mut decoded_rx, decoded_tx) =
+            Linearizer::<Priority<Reverse<MorselSeq>, DataFrame>>::new(
+                num_pipelines,
+                *DEFAULT_LINEARIZER_BUFFER_SIZE,
+            );
+
+        // Explicitly linearize here to redistribute the morsels of large stripes over the
+        // pipelines.
+        let distributor_handle = AbortOnDropHandle::new(spawn(TaskPriority::High, async move {
+            // Note: We don't use this (it is handled by the bridge). But morsels require a source token.
+            let source_token = SourceToken::new();
+
+            while let Some(Priority(Reverse(seq), df)) = decoded_rx.get().await {
+                let morsel = Morsel::new(df, seq, source_token.clone());
+
+                if morsel_sender.send_morsel(morsel).await.is_err() {
+                    break;
+                }
+            }
+
+            PolarsResult::Ok(())
+        }));
+
+        // Decoder tasks.
+        //
+        // Every stripe is decoded independently, which gives stripe-level parallelism.
+        let decoder_handles = decoded_tx
+            .into_iter()
+            .zip(stripe_rxs)
+            .map(|(mut send, mut rx)| {
+                let memslice = memslice.clone();
+                let metadata = metadata.clone();
+                let row_index = row_index.clone();
+                let field_projections = field_projections.clone();
+                let projection_indices = projection_indices.clone();
+
+                AbortOnDropHandle::new(spawn(TaskPriority::Low, async move {
+                    while let Ok(m) = rx.recv().await {
+                        let StripeMessage {
+                            stripe_idx,
+                            slice,
+                            row_position,
+                            morsel_seq_base,
+                        } = m;
+
+                        let df = read_stripe(
+                            memslice.as_ref(),
+                            &metadata,
+                            stripe_idx,
+                            &projection_indices,
+                        )?;
+                        let height = slice.len();
+                        let df = df.slice(slice.start as i64, height);
+
+                        let columns = df
+                            .take_columns()
+                            .into_iter()
+                            .zip(field_projections.iter())
+                            .map(|(c, projection)| projection.apply_transform(c))
+                            .collect::<PolarsResult<Vec<_>>>()?;
+                        let mut df = DataFrame::new_with_height(height, columns)?;
+
+                        if let Some(RowIndex { name, offset }) = &row_index {
+                            let offset = offset.saturating_add(row_position as IdxSize);
+                            df = df.with_row_index(name.clone(), Some(offset))?;
+                        }
+
+                        for i in 0..df.height().div_ceil(max_morsel_size) {
+                            let morsel_df = df.slice((i * max_morsel_size) as i64, max_morsel_size);
+                            let seq = MorselSeq::new(morsel_seq_base + i as u64);
+                            if send
+                                .insert(Priority(Reverse(seq), morsel_df))
+                                .await
+                                .is_err()
+                            {
+                                return Ok(());
+                            }
+                        }
+                    }
+
+                    PolarsResult::Ok(())
+                }))
+            })
+            .collect::<Vec<_>>();
+
+        let use_statistics = self.options.use_statistics;
+
+        // Walker task.
+        //
+        // Walks the stripes of the file and sends the ones that overlap with the slice and cannot
+        // be skipped based on their statistics to the decoder tasks.
+        let walker_handle = AbortOnDropHandle::new(spawn(TaskPriority::Low, async move {
+            let skip_stripe_mask = calculate_stripe_pred_pushdown_skip_mask(
+                use_statistics,
+                predicate.as_ref(),
+                &metadata,
+                &field_projections,
+                row_index.as_ref(),
+                verbose,
+            )?;
+
+            let mut morsel_seq: u64 = 0;
+            let mut stripe_offset: usize = 0;
+
+            for stripe_idx in 0..metadata.num_stripes() {
+                let stripe_rows =
+                    stripe_offset..stripe_offset + metadata.stripe_num_rows(stripe_idx);
+                stripe_offset = stripe_rows.end;
+
+                if stripe_rows.start >= slice.end {
+                    break;
+                }
+
+                let start = stripe_rows.start.max(slice.start);
+                let end = stripe_rows.end.min(slice.end);
+
+                if start >= end
+                    || skip_stripe_mask
+                        .as_ref()
+                        .is_some_and(|mask| mask.get_bit(stripe_idx))
+                {
+                    continue;
+                }
+
+                let message = StripeMessage {
+                    stripe_idx,
+                    slice: start - stripe_rows.start..end - stripe_rows.start,
+                    row_position: start,
+                    morsel_seq_base: morsel_seq,
+                };
+
+                if stripe_tx.send(message).await.is_err() {
+                    break;
+                }
+
+                morsel_seq += (end - start).div_ceil(max_morsel_size) as u64;
+            }
+
+            PolarsResult::Ok(())
+        }));
+
+        Ok((
+            morsel_rx,
+            spawn(TaskPriority::Low, async move {
+                distributor_handle.await?;
+
+                for handle in decoder_handles {
+                    handle.await?;
+                }
+
+                walker_handle.await?;
+                Ok(())
+            }),
+        ))
+    }
+
+    async fn file_schema(&mut self) -> PolarsResult<polars_core::schema::SchemaRef> {
+        Ok(self.init_data.as_ref().unwrap().metadata.schema().clone())
+    }
+
+    async fn n_rows_in_file(&mut self) -> PolarsResult<IdxSize> {
+        self._n_rows_in_file()
+    }
+
+    async fn fast_n_rows_in_file(&mut self) -> PolarsResult<Option<IdxSize>> {
+        self._n_rows_in_file().map(Some)
+    }
+
+    async fn row_position_after_slice(
+        &mut self,
+        pre_slice: Option<Slice>,
+    ) -> PolarsResult<IdxSize> {
+        self._row_position_after_slice(pre_slice)
+    }
+}
+
+impl OrcFileReader {
+    fn _n_rows_in_file(&self) -> PolarsResult<IdxSize> {
+        let n_rows = self.init_data.as_ref().unwrap().metadata.num_rows();
+
+        IdxSize::try_from(n_rows)
+            .map_err(|_| polars_error::polars_err!(bigidx, ctx = "orc file", size = n_rows))
+    }
+
+    fn _row_position_after_slice(&self, pre_slice: Option<Slice>) -> PolarsResult<IdxSize> {
+        Ok(calc_row_position_after_slice(
+            self._n_rows_in_file()?,
+            pre_slice,
+        ))
+    }
+}
+
+/// Evaluate the skip batch predicate on the stripe statistics. Set bits in the returned mask
+/// indicate stripes that can be skipped.
+fn calculate_stripe_pred_pushdown_skip_mask(
+    use_statistics: bool,
+    predicate: Option<&ScanIOPredicate>,
+    metadata: &OrcMetadata,
+    field_projections: &[OrcFieldProjection],
+    row_index: Option<&RowIndex>,
+    verbose: bool,
+) -> PolarsResult<Option<Bitmap>> {
+    if !use_statistics {
+        return Ok(None);
+    }
+
+    let Some(predicate) = predicate else {
+        return Ok(None);
+    };
+
+    let Some(sbp) = predicate.skip_batch_predicate.as_ref() else {
+        return Ok(None);
+    };
+
+    let num_stripes = metadata.num_stripes();
+    let live_columns = &predicate.live_columns;
+
+    let lengths: Vec<IdxSize> = (0..num_stripes)
+        .map(|i| metadata.stripe_num_rows(i) as IdxSize)
+        .collect();
+
+    let mut columns = Vec::with_capacity(1 + live_columns.len() * 3);
+    columns.push(Column::new("len".into(), &lengths));
+
+    for projection in field_projections {
+        let c = &projection.output_name;
+
+        if !live_columns.contains(c) {
+            continue;
+        }
+
+        let [min, max, null_count] = metadata.stripe_statistics_columns(projection.field_idx);
+
+        // Note: Order is important here. We re-use the transform for the output column, meaning
+        // that it may set the column name.
+        let min = projection.apply_transform(min)?;
+        let max = projection.apply_transform(max)?;
+
+        columns.extend([
+            min.with_name(format_pl_smallstr!("{c}_min")),
+            max.with_name(format_pl_smallstr!("{c}_max")),
+            null_count.with_name(format_pl_smallstr!("{c}_nc")),
+        ]);
+    }
+
+    if let Some(RowIndex { name, offset }) = row_index {
+        let mut offset = Some(*offset);
+        let (min, max): (Vec<_>, Vec<_>) = lengths
+            .iter()
+            .map(|&n_rows| {
+                let start = offset;
+                offset = offset.and_then(|o| o.checked_add(n_rows));
+
+                match (start, offset) {
+                    (Some(start), Some(_)) if n_rows > 0 => (Some(start), Some(start + n_rows - 1)),
+                    _ => (None, None),
+                }
+            })
+            .unzip();
+
+        columns.extend([
+            IdxCa::from_iter_options(format_pl_smallstr!("{name}_min"), min.into_iter())
+                .into_column(),
+            IdxCa::from_iter_options(format_pl_smallstr!("{name}_max"), max.into_iter())
+                .into_column(),
+            Column::new_scalar(
+                format_pl_smallstr!("{name}_nc"),
+                (0 as IdxSize).into(),
+                num_stripes,
+            ),
+        ]);
+    }
+
+    let statistics_df = DataFrame::new_with_height(num_stripes, columns)?;
+    let skip_stripe_mask = sbp.evaluate_with_stat_df(&statistics_df)?;
+
+    if verbose {
+        eprintln!(
+            "[OrcFileReader]: Predicate pushdown: \
+            reading {} / {} stripes",
+            skip_stripe_mask.unset_bits(),
+            num_stripes,
+        );
+    }
+
+    Ok(Some(skip_stripe_mask))
+}
@@ -3,7 +3,6 @@ use std::sync::Arc;
 
 use polars_core::prelude::PlHashMap;
 use polars_core::series::IsSorted;
-use polars_core::utils::arrow::bitmap::Bitmap;
 use polars_error::PolarsResult;
 use polars_io::predicates::ScanIOPredicate;
 use polars_io::prelude::{FileMetadata, create_sorting_map};
@@ -13,6 +12,7 @@ use polars_utils::mmap::MemSlice;
 use polars_utils::pl_str::PlSmallStr;
 
 use crate::nodes::io_sources::parquet::projection::ArrowFieldProjection;
+use crate::nodes::io_sources::parquet::statistics::RowGroupSkipMask;
 use crate::utils::task_handles_ext;
 
 /// Represents byte-data that can be transformed into a DataFrame after some computation.
@@ -35,7 +35,7 @@ pub(super) struct RowGroupDataFetcher {
     pub(super) byte_source: Arc<DynByteSource>,
 
     pub(super) row_group_slice: Range<usize>,
-    pub(super) row_group_mask: Option<Bitmap>,
+    pub(super) row_group_mask: Option<RowGroupSkipMask>,
 
     pub(super) row_offset: usize,
 }
@@ -69,8 +69,25 @@ impl RowGroupDataFetcher {
             };
 
             if let Some(row_group_mask) = self.row_group_mask.as_mut() {
-                let do_skip = row_group_mask.get_bit(0);
-                row_group_mask.slice(1, self.row_group_slice.len());
+                let mut do_skip = row_group_mask.mask.get_bit(0);
+                row_group_mask.mask.slice(1, self.row_group_slice.len());
+
+                if let Some((sbp, statistics_df)) = row_group_mask.dynamic.as_mut() {
+                    if !do_skip {
+                        match sbp.evaluate_with_stat_df(&statistics_df.slice(0, 1)) {
+                            Ok(mask) => do_skip = mask.get_bit(0),
+                            Err(e) => return Some(Err(e)),
+                        }
+
+                        if do_skip
+                            && let Some(metrics) =
+                                self.predicate.as_ref().and_then(|p| p.metrics.as_ref())
+                        {
+                            metrics.row_groups_skipped.fetch_add(1);
+                        }
+                    }
+                    *statistics_df = statistics_df.slice(1, self.row_group_slice.len());
+                }
 
                 if do_skip {
                     continue;
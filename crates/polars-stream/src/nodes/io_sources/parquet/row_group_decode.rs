@@ -14,6 +14,7 @@ pub use polars_io::prelude::_internal::PrefilterMaskSetting;
 use polars_io::prelude::try_set_sorted_flag;
 use polars_parquet::read::{Filter, ParquetType, PredicateFilter, PrimitiveLogicalType};
 use polars_utils::pl_str::PlSmallStr;
+use polars_utils::relaxed_cell::RelaxedCell;
 use polars_utils::{IdxSize, UnitVec};
 
 use super::row_group_data_fetch::RowGroupData;
@@ -349,6 +350,7 @@ fn decode_column_in_filter(
     arrow_field: &ArrowField,
     use_column_predicates: bool,
     column_predicates: &ColumnPredicates,
+    num_skipped_pages: Option<&Arc<RelaxedCell<usize>>>,
     row_group_data: &RowGroupData,
     projection_height: usize,
 ) -> PolarsResult<(Column, Bitmap)> {
@@ -372,6 +374,7 @@ fn decode_column_in_filter(
             filter = Some(Filter::Predicate(PredicateFilter {
                 predicate: Arc::new(p) as _,
                 include_values: constant.is_none(),
+                num_skipped_pages: num_skipped_pages.cloned(),
             }));
         }
     }
@@ -443,6 +446,10 @@ impl RowGroupDecoder {
                         let predicate_field_indices = predicate_field_indices.clone();
                         let projected_arrow_fields = projected_arrow_fields.clone();
                         let column_predicates = scan_predicate.column_predicates.clone();
+                        let num_skipped_pages = scan_predicate
+                            .metrics
+                            .as_ref()
+                            .map(|m| m.pages_skipped.clone());
 
                         async move {
                             (offset
@@ -464,6 +471,7 @@ impl RowGroupDecoder {
                                         projection.arrow_field(),
                                         use_column_predicates,
                                         column_predicates.as_ref(),
+                                        num_skipped_pages.as_ref(),
                                         row_group_data.as_ref(),
                                         projection_height,
                                     )?;
@@ -1,11 +1,12 @@
 use std::ops::Range;
+use std::sync::Arc;
 
 use arrow::array::{MutablePrimitiveArray, PrimitiveArray};
 use arrow::bitmap::Bitmap;
 use arrow::pushable::Pushable;
 use polars_core::prelude::*;
 use polars_io::RowIndex;
-use polars_io::predicates::ScanIOPredicate;
+use polars_io::predicates::{ScanIOPredicate, SkipBatchPredicate};
 use polars_io::prelude::FileMetadata;
 use polars_parquet::read::RowGroupMetadata;
 use polars_parquet::read::statistics::{ArrowColumnStatisticsArrays, deserialize_all};
@@ -74,6 +75,14 @@ impl StatisticsColumns {
     }
 }
 
+/// Row groups that can be skipped based on their statistics.
+pub(super) struct RowGroupSkipMask {
+    pub(super) mask: Bitmap,
+    /// Dynamic predicates are evaluated again right before each row group is fetched, together with
+    /// the statistics of the remaining row groups.
+    pub(super) dynamic: Option<(Arc<dyn SkipBatchPredicate>, DataFrame)>,
+}
+
 pub(super) async fn calculate_row_group_pred_pushdown_skip_mask(
     row_group_slice: Range<usize>,
     use_statistics: bool,
@@ -84,7 +93,7 @@ pub(super) async fn calculate_row_group_pred_pushdown_skip_mask(
     // row group.
     mut row_index: Option<RowIndex>,
     verbose: bool,
-) -> PolarsResult<Option<Bitmap>> {
+) -> PolarsResult<Option<RowGroupSkipMask>> {
     if !use_statistics {
         return Ok(None);
     }
@@ -152,8 +161,12 @@ pub(super) async fn calculate_row_group_pred_pushdown_skip_mask(
         }
 
         let statistics_df = DataFrame::new_with_height(num_row_groups, columns)?;
+        let mask = sbp.evaluate_with_stat_df(&statistics_df)?;
 
-        sbp.evaluate_with_stat_df(&statistics_df)
+        PolarsResult::Ok(RowGroupSkipMask {
+            mask,
+            dynamic: sbp.is_dynamic().then_some((sbp, statistics_df)),
+        })
     })
     .await?;
 
@@ -161,11 +174,17 @@ pub(super) async fn calculate_row_group_pred_pushdown_skip_mask(
         eprintln!(
             "[ParquetFileReader]: Predicate pushdown: \
             reading {} / {} row groups",
-            skip_row_group_mask.unset_bits(),
+            skip_row_group_mask.mask.unset_bits(),
             num_row_groups,
         );
     }
 
+    if let Some(metrics) = &predicate.metrics {
+        metrics
+            .row_groups_skipped
+            .fetch_add(skip_row_group_mask.mask.set_bits());
+    }
+
     Ok(Some(skip_row_group_mask))
 }
 
@@ -1,12 +1,16 @@
 use std::cmp::Reverse;
 use std::collections::BinaryHeap;
+use std::ops::Range;
 use std::sync::Arc;
 use std::sync::atomic::{AtomicU64, Ordering};
 
 use arrow::array::builder::ShareStrategy;
+use arrow::bitmap::MutableBitmap;
 use polars_core::frame::builder::DataFrameBuilder;
+use polars_core::prelude::row_encode::_get_rows_encoded_ca;
 use polars_core::prelude::*;
 use polars_core::schema::{Schema, SchemaExt};
+use polars_core::utils::accumulate_dataframes_vertical_unchecked;
 use polars_core::{POOL, config};
 use polars_expr::hash_keys::HashKeys;
 use polars_expr::idx_table::{IdxTable, new_idx_table};
@@ -23,7 +27,11 @@ use polars_utils::sparse_init_vec::SparseInitVec;
 use polars_utils::{IdxSize, format_pl_smallstr};
 use rayon::prelude::*;
 
-use super::{BufferedStream, JOIN_SAMPLE_LIMIT, LOPSIDED_SAMPLE_FACTOR};
+use super::runtime_filter::RuntimeJoinFilter;
+use super::{
+    BufferedStream, JOIN_BROADCAST_LIMIT, JOIN_SAMPLE_LIMIT, JOIN_SORTED_MERGE_LIMIT,
+    LOPSIDED_SAMPLE_FACTOR,
+};
 use crate::async_executor;
 use crate::async_primitives::connector::{Receiver, Sender};
 use crate::async_primitives::wait_group::WaitGroup;
@@ -34,6 +42,9 @@ use crate::nodes::in_memory_source::InMemorySourceNode;
 
 struct EquiJoinParams {
     left_is_build: Option<bool>,
+    /// The number of partitions of the hash table, a single partition means
+    /// the hash table is broadcast to all probing pipelines.
+    num_partitions: usize,
     preserve_order_build: bool,
     preserve_order_probe: bool,
     left_key_schema: Arc<Schema>,
@@ -47,6 +58,8 @@ struct EquiJoinParams {
     right_payload_schema: Arc<Schema>,
     args: JoinArgs,
     random_state: PlRandomState,
+    /// Filters to create for the scans of the left and right input.
+    runtime_filters: [Option<RuntimeJoinFilter>; 2],
 }
 
 impl EquiJoinParams {
@@ -67,6 +80,43 @@ impl EquiJoinParams {
             self.args.how == JoinType::Left || self.args.how == JoinType::Full
         }
     }
+
+    /// The runtime filter to create from the build keys, if any.
+    fn probe_runtime_filter(&self) -> Option<&RuntimeJoinFilter> {
+        let probe_idx = if self.left_is_build.unwrap() { 1 } else { 0 };
+        self.runtime_filters[probe_idx]
+            .as_ref()
+            .filter(|_| !self.emit_unmatched_probe())
+    }
+
+    /// Whether the join can be done with a sorted merge if both inputs are completely sampled and
+    /// sorted on their keys.
+    fn sorted_merge_candidate(&self) -> bool {
+        let keeps_order = match self.args.how {
+            JoinType::Inner | JoinType::Left => !matches!(
+                self.args.maintain_order,
+                MaintainOrderJoin::Right | MaintainOrderJoin::RightLeft
+            ),
+            JoinType::Right | JoinType::Full => self.args.maintain_order == MaintainOrderJoin::None,
+            _ => false,
+        };
+        keeps_order
+            && self
+                .left_key_schema
+                .iter_values()
+                .all(supports_sorted_merge)
+    }
+
+    /// The side that is only sampled once the other side is completely sampled. If the other side
+    /// turns out to be small it becomes the build side without reading this side, so that the
+    /// runtime filter is created before this side's scan starts.
+    fn deferred_sample_side(&self) -> Option<usize> {
+        match &self.runtime_filters {
+            [Some(_), None] => Some(0),
+            [None, Some(_)] => Some(1),
+            _ => None,
+        }
+    }
 }
 
 /// A payload selector contains for each column whether that column should be
@@ -166,17 +216,25 @@ fn select_schema(schema: &Schema, selector: &[Option<PlSmallStr>]) -> Schema {
         .collect()
 }
 
-async fn select_keys(
+async fn select_key_columns(
     df: &DataFrame,
     key_selectors: &[StreamExpr],
-    params: &EquiJoinParams,
     state: &ExecutionState,
-) -> PolarsResult<HashKeys> {
+) -> PolarsResult<DataFrame> {
     let mut key_columns = Vec::new();
     for selector in key_selectors {
         key_columns.push(selector.evaluate(df, state).await?.into_column());
     }
-    let keys = DataFrame::new_with_broadcast_len(key_columns, df.height())?;
+    DataFrame::new_with_broadcast_len(key_columns, df.height())
+}
+
+async fn select_keys(
+    df: &DataFrame,
+    key_selectors: &[StreamExpr],
+    params: &EquiJoinParams,
+    state: &ExecutionState,
+) -> PolarsResult<HashKeys> {
+    let keys = select_key_columns(df, key_selectors, state).await?;
     Ok(HashKeys::from_df(
         &keys,
         params.random_state,
@@ -247,23 +305,129 @@ fn estimate_cardinality(
     })
 }
 
+/// How a hash join is executed.
+#[derive(Debug, PartialEq)]
+struct HashJoinStrategy {
+    left_is_build: bool,
+    /// Whether the build side is put in a single hash table that is shared by all probing
+    /// pipelines instead of being partitioned.
+    broadcast: bool,
+}
+
+/// Chooses the build side and hash table of a join given the number of rows sampled from both
+/// sides and whether those sides are done. Returns `None` if more has to be sampled.
+///
+/// `deferred_build` is the build side if it was decided on before sampling the other side.
+fn choose_hash_join_strategy(
+    [left_len, right_len]: [usize; 2],
+    [left_done, right_done]: [bool; 2],
+    deferred_build: Option<bool>,
+    estimate_cardinalities: impl FnOnce() -> PolarsResult<(f64, f64)>,
+) -> PolarsResult<Option<HashJoinStrategy>> {
+    let left_saturated = left_len >= *JOIN_SAMPLE_LIMIT;
+    let right_saturated = right_len >= *JOIN_SAMPLE_LIMIT;
+    let left_sampled = left_done || left_saturated;
+    let right_sampled = right_done || right_saturated;
+    let stop_sampling = deferred_build.is_some()
+        || (left_sampled && right_sampled)
+        || (left_sampled && right_len >= LOPSIDED_SAMPLE_FACTOR * left_len)
+        || (right_sampled && left_len >= LOPSIDED_SAMPLE_FACTOR * right_len);
+    if !stop_sampling {
+        return Ok(None);
+    }
+
+    if config::verbose() {
+        eprintln!("choosing build side, sample lengths are: {left_len} vs. {right_len}");
+    }
+
+    let left_is_build = match (deferred_build, left_saturated, right_saturated) {
+        // The deferred side hasn't been read.
+        (Some(left_is_build), _, _) => left_is_build,
+
+        // Don't bother estimating cardinality, just choose smaller side as
+        // we have everything in-memory anyway.
+        (None, false, false) => left_len < right_len,
+
+        // Choose the unsaturated side, the saturated side could be
+        // arbitrarily big.
+        (None, false, true) => true,
+        (None, true, false) => false,
+
+        // Estimate cardinality and choose smaller.
+        (None, true, true) => {
+            let (lc, rc) = estimate_cardinalities()?;
+            lc < rc
+        },
+    };
+
+    if config::verbose() {
+        eprintln!(
+            "build side chosen: {}",
+            if left_is_build { "left" } else { "right" }
+        );
+    }
+
+    // If the build side is completely known and small we don't partition
+    // it, all probing pipelines share a single hash table instead.
+    let (build_len, build_done) = if left_is_build {
+        (left_len, left_done)
+    } else {
+        (right_len, right_done)
+    };
+    let broadcast = build_done && build_len <= *JOIN_BROADCAST_LIMIT;
+
+    if config::verbose() {
+        eprintln!(
+            "hash table strategy chosen: {}",
+            if broadcast {
+                "broadcast"
+            } else {
+                "partitioned"
+            }
+        );
+    }
+
+    Ok(Some(HashJoinStrategy {
+        left_is_build,
+        broadcast,
+    }))
+}
+
 #[derive(Default)]
 struct SampleState {
     left: Vec<Morsel>,
     left_len: usize,
     right: Vec<Morsel>,
     right_len: usize,
+    /// The evaluated join keys of each sampled morsel, if a sorted merge join is possible.
+    left_keys: Vec<DataFrame>,
+    right_keys: Vec<DataFrame>,
 }
 
 impl SampleState {
+    fn len(&self, side: usize) -> usize {
+        if side == 0 {
+            self.left_len
+        } else {
+            self.right_len
+        }
+    }
+
+    #[allow(clippy::too_many_arguments)]
     async fn sink(
         mut recv: Receiver<Morsel>,
         morsels: &mut Vec<Morsel>,
+        keys: &mut Vec<DataFrame>,
+        key_selectors: Option<&[StreamExpr]>,
         len: &mut usize,
         this_final_len: Arc<RelaxedCell<usize>>,
         other_final_len: Arc<RelaxedCell<usize>>,
+        state: &ExecutionState,
     ) -> PolarsResult<()> {
         while let Ok(mut morsel) = recv.recv().await {
+            if let Some(key_selectors) = key_selectors {
+                keys.push(select_key_columns(morsel.df(), key_selectors, state).await?);
+            }
             *len += morsel.df().height();
             if *len >= *JOIN_SAMPLE_LIMIT
                 || *len
@@ -287,25 +451,11 @@ impl SampleState {
         params: &mut EquiJoinParams,
         state: &StreamingExecutionState,
     ) -> PolarsResult<Option<BuildState>> {
-        let left_saturated = self.left_len >= *JOIN_SAMPLE_LIMIT;
-        let right_saturated = self.right_len >= *JOIN_SAMPLE_LIMIT;
-        let left_done = recv[0] == PortState::Done || left_saturated;
-        let right_done = recv[1] == PortState::Done || right_saturated;
-        #[expect(clippy::nonminimal_bool)]
-        let stop_sampling = (left_done && right_done)
-            || (left_done && self.right_len >= LOPSIDED_SAMPLE_FACTOR * self.left_len)
-            || (right_done && self.left_len >= LOPSIDED_SAMPLE_FACTOR * self.right_len);
-        if !stop_sampling {
-            return Ok(None);
-        }
-
-        if config::verbose() {
-            eprintln!(
-                "choosing build side, sample lengths are: {} vs. {}",
-                self.left_len, self.right_len
-            );
-        }
-
+        // If the other side of the deferred side is completely known, build on it.
+        let deferred_build = params
+            .deferred_sample_side()
+            .filter(|&side| self.len(side) == 0 && recv[1 - side] == PortState::Done)
+            .map(|side| side == 1);
         let estimate_cardinalities = || {
             let left_cardinality = estimate_cardinality(
                 &self.left,
@@ -327,29 +477,19 @@ impl SampleState {
             PolarsResult::Ok((left_cardinality, right_cardinality))
         };
 
-        let left_is_build = match (left_saturated, right_saturated) {
-            // Don't bother estimating cardinality, just choose smaller side as
-            // we have everything in-memory anyway.
-            (false, false) => self.left_len < self.right_len,
-
-            // Choose the unsaturated side, the saturated side could be
-            // arbitrarily big.
-            (false, true) => true,
-            (true, false) => false,
-
-            // Estimate cardinality and choose smaller.
-            (true, true) => {
-                let (lc, rc) = estimate_cardinalities()?;
-                lc < rc
-            },
+        let Some(HashJoinStrategy {
+            left_is_build,
+            broadcast,
+        }) = choose_hash_join_strategy(
+            [self.left_len, self.right_len],
+            [recv[0] == PortState::Done, recv[1] == PortState::Done],
+            deferred_build,
+            estimate_cardinalities,
+        )?
+        else {
+            return Ok(None);
         };
-
-        if config::verbose() {
-            eprintln!(
-                "build side chosen: {}",
-                if left_is_build { "left" } else { "right" }
-            );
-        }
+        params.num_partitions = if broadcast { 1 } else { state.num_pipelines };
 
         // Transition to building state.
         params.left_is_build = Some(left_is_build);
@@ -361,10 +501,10 @@ impl SampleState {
             core::mem::swap(&mut sampled_build_morsels, &mut sampled_probe_morsels);
         }
 
-        let partitioner = HashPartitioner::new(state.num_pipelines, 0);
+        let partitioner = HashPartitioner::new(params.num_partitions, 0);
         let mut build_state = BuildState::new(
             state.num_pipelines,
-            state.num_pipelines,
+            params.num_partitions,
             sampled_probe_morsels,
         );
 
@@ -400,6 +540,224 @@ impl SampleState {
 
         Ok(Some(build_state))
     }
+
+    /// If both inputs were sampled completely and the join keys of both turn
+    /// out to be sorted, joins them with a sorted merge instead of building a
+    /// hash table. The keys are evaluated while sampling.
+    fn try_sorted_merge_join(
+        &mut self,
+        recv: &[PortState],
+        params: &EquiJoinParams,
+    ) -> PolarsResult<Option<DataFrame>> {
+        if recv[0] != PortState::Done
+            || recv[1] != PortState::Done
+            || !params.sorted_merge_candidate()
+            || self.left_len == 0
+            || self.right_len == 0
+        {
+            return Ok(None);
+        }
+
+        let (left, left_keys) = concat_in_order(&self.left, &self.left_keys);
+        let (right, right_keys) = concat_in_order(&self.right, &self.right_keys);
+        let Some([left_idx, right_idx]) = sorted_merge_join_idxs(
+            &left_keys,
+            &right_keys,
+            &params.args.how,
+            *JOIN_SORTED_MERGE_LIMIT,
+        )?
+        else {
+            return Ok(None);
+        };
+
+        if config::verbose() {
+            eprintln!("join keys are sorted, using sorted merge join");
+        }
+
+        self.left.clear();
+        self.right.clear();
+        self.left_keys.clear();
+        self.right_keys.clear();
+        let left_payload = select_payload(left, &params.left_payload_select);
+        let right_payload = select_payload(right, &params.right_payload_select);
+        let out = unsafe {
+            let mut out = left_payload.take_unchecked(&left_idx);
+            out.hstack_mut_unchecked(right_payload.take_unchecked(&right_idx).get_columns());
+            out
+        };
+        Ok(Some(postprocess_join(out, params)))
+    }
+}
+
+/// Concatenates the sampled morsels and their keys in the order of the input.
+fn concat_in_order(morsels: &[Morsel], keys: &[DataFrame]) -> (DataFrame, DataFrame) {
+    let mut order: Vec<usize> = (0..morsels.len()).collect();
+    order.sort_by_key(|&i| morsels[i].seq());
+    let df =
+        accumulate_dataframes_vertical_unchecked(order.iter().map(|&i| morsels[i].df().clone()));
+    let keys = accumulate_dataframes_vertical_unchecked(order.iter().map(|&i| keys[i].clone()));
+    (df, keys)
+}
+
+/// Whether a join key of this type can be joined with a sorted merge.
+fn supports_sorted_merge(dtype: &DataType) -> bool {
+    // The physical order of categoricals differs from their order, and floats compare equal in
+    // joins when they are not equal in their ordering.
+    if dtype.is_categorical() || dtype.is_enum() {
+        return false;
+    }
+    let dtype = dtype.to_physical();
+    dtype.is_integer() || dtype.is_bool() || matches!(dtype, DataType::String | DataType::Binary)
+}
+
+/// The row indices of both sides of a sorted merge join of the keys, where rows that are emitted
+/// without a match get a null index on the other side. Returns `None` if the keys of either side
+/// are not sorted ascendingly without nulls, or if the join would output more than `limit` rows.
+fn sorted_merge_join_idxs(
+    left: &DataFrame,
+    right: &DataFrame,
+    how: &JoinType,
+    limit: usize,
+) -> PolarsResult<Option<[IdxCa; 2]>> {
+    let has_nulls = |df: &DataFrame| df.get_columns().iter().any(|c| c.has_nulls());
+    if has_nulls(left) || has_nulls(right) {
+        return Ok(None);
+    }
+
+    if let ([left], [right]) = (left.get_columns(), right.get_columns()) {
+        let left = left.as_materialized_series().to_physical_repr().rechunk();
+        let right = right.as_materialized_series().to_physical_repr().rechunk();
+        match left.dtype() {
+            DataType::Int32 => {
+                let (l, r) = (left.i32()?.cont_slice()?, right.i32()?.cont_slice()?);
+                return Ok(sorted_merge_join_idxs_impl(l, r, how, limit));
+            },
+            DataType::Int64 => {
+                let (l, r) = (left.i64()?.cont_slice()?, right.i64()?.cont_slice()?);
+                return Ok(sorted_merge_join_idxs_impl(l, r, how, limit));
+            },
+            DataType::UInt32 => {
+                let (l, r) = (left.u32()?.cont_slice()?, right.u32()?.cont_slice()?);
+                return Ok(sorted_merge_join_idxs_impl(l, r, how, limit));
+            },
+            DataType::UInt64 => {
+                let (l, r) = (left.u64()?.cont_slice()?, right.u64()?.cont_slice()?);
+                return Ok(sorted_merge_join_idxs_impl(l, r, how, limit));
+            },
+            _ => {},
+        }
+    }
+
+    // The row encoding preserves the lexicographical order of the keys.
+    let encode = |df: &DataFrame| {
+        let flags = vec![false; df.width()];
+        _get_rows_encoded_ca(PlSmallStr::EMPTY, df.get_columns(), &flags, &flags)
+    };
+    let (left, right) = (encode(left)?, encode(right)?);
+    let left: Vec<&[u8]> = left.into_no_null_iter().collect();
+    let right: Vec<&[u8]> = right.into_no_null_iter().collect();
+    Ok(sorted_merge_join_idxs_impl(&left, &right, how, limit))
+}
+
+fn sorted_merge_join_idxs_impl<T: Ord>(
+    left: &[T],
+    right: &[T],
+    how: &JoinType,
+    limit: usize,
+) -> Option<[IdxCa; 2]> {
+    let is_sorted = |keys: &[T]| keys.windows(2).all(|w| w[0] <= w[1]);
+    if !is_sorted(left) || !is_sorted(right) {
+        return None;
+    }
+
+    let emit_unmatched_left = matches!(how, JoinType::Left | JoinType::Full);
+    let emit_unmatched_right = matches!(how, JoinType::Right | JoinType::Full);
+    let run_len = |l: &Range<usize>, r: &Range<usize>| match (l.is_empty(), r.is_empty()) {
+        (false, false) => l.len().saturating_mul(r.len()),
+        (false, true) if emit_unmatched_left => l.len(),
+        (true, false) if emit_unmatched_right => r.len(),
+        _ => 0,
+    };
+
+    // Check the size of the output before materializing it.
+    let mut len = 0usize;
+    merge_sorted_runs(left, right, |l, r| {
+        len = len.saturating_add(run_len(&l, &r))
+    });
+    if len > limit {
+        if config::verbose() {
+            eprintln!("sorted merge join would output {len} rows, using a hash join instead");
+        }
+        return None;
+    }
+
+    let mut left_idx = Vec::with_capacity(len);
+    let mut right_idx = Vec::with_capacity(len);
+    let mut left_validity = emit_unmatched_right.then(|| MutableBitmap::with_capacity(len));
+    let mut right_validity = emit_unmatched_left.then(|| MutableBitmap::with_capacity(len));
+    merge_sorted_runs(left, right, |l, r| {
+        let n = run_len(&l, &r);
+        if n == 0 {
+            return;
+        }
+        if r.is_empty() {
+            left_idx.extend(l.start as IdxSize..l.end as IdxSize);
+            right_idx.extend(std::iter::repeat_n(0, n));
+        } else if l.is_empty() {
+            left_idx.extend(std::iter::repeat_n(0, n));
+            right_idx.extend(r.start as IdxSize..r.end as IdxSize);
+        } else {
+            for li in l.clone() {
+                left_idx.extend(std::iter::repeat_n(li as IdxSize, r.len()));
+                right_idx.extend(r.start as IdxSize..r.end as IdxSize);
+            }
+        }
+        if let Some(validity) = left_validity.as_mut() {
+            validity.extend_constant(n, !l.is_empty());
+        }
+        if let Some(validity) = right_validity.as_mut() {
+            validity.extend_constant(n, !r.is_empty());
+        }
+    });
+
+    Some([
+        IdxCa::from_vec_validity(PlSmallStr::EMPTY, left_idx, left_validity.map(Into::into)),
+        IdxCa::from_vec_validity(PlSmallStr::EMPTY, right_idx, right_validity.map(Into::into)),
+    ])
+}
+
+/// Walks two ascendingly sorted keys and calls `f` with the ranges of every distinct key on both
+/// sides, in order. The range of a side is empty if the key doesn't occur on that side.
+fn merge_sorted_runs<T: Ord>(
+    left: &[T],
+    right: &[T],
+    mut f: impl FnMut(Range<usize>, Range<usize>),
+) {
+    let (mut i, mut j) = (0, 0);
+    while i < left.len() && j < right.len() {
+        let i_end = i + left[i..].partition_point(|x| *x <= left[i]);
+        let j_end = j + right[j..].partition_point(|x| *x <= right[j]);
+        match left[i].cmp(&right[j]) {
+            std::cmp::Ordering::Less => {
+                f(i..i_end, j..j);
+                i = i_end;
+            },
+            std::cmp::Ordering::Greater => {
+                f(i..i, j..j_end);
+                j = j_end;
+            },
+            std::cmp::Ordering::Equal => {
+                f(i..i_end, j..j_end);
+                (i, j) = (i_end, j_end);
+            },
+        }
+    }
+    if i < left.len() {
+        f(i..left.len(), right.len()..right.len());
+    }
+    if j < right.len() {
+        f(left.len()..left.len(), j..right.len());
+    }
 }
 
 #[derive(Default)]
@@ -416,6 +774,9 @@ struct LocalBuilder {
     // let stop = morsel_idxs_offsets[(i + 1) * num_partitions + p];
     morsel_idxs_values_per_p: Vec<Vec<IdxSize>>,
     morsel_idxs_offsets_per_p: Vec<usize>,
+
+    // The keys seen by this builder that are used for the runtime filter of the probe side.
+    runtime_filter_keys: Vec<Vec<Column>>,
 }
 
 struct BuildState {
@@ -435,6 +796,7 @@ impl BuildState {
                 sketch_per_p: vec![CardinalitySketch::default(); num_partitions],
                 morsel_idxs_values_per_p: vec![Vec::new(); num_partitions],
                 morsel_idxs_offsets_per_p: vec![0; num_partitions],
+                runtime_filter_keys: Vec::new(),
             })
             .collect();
         Self {
@@ -451,6 +813,7 @@ impl BuildState {
         state: &StreamingExecutionState,
     ) -> PolarsResult<()> {
         let track_unmatchable = params.emit_unmatched_build();
+        let runtime_filter = params.probe_runtime_filter();
         let (key_selectors, payload_selector);
         if params.left_is_build.unwrap() {
             payload_selector = &params.left_payload_select;
@@ -463,13 +826,15 @@ impl BuildState {
         while let Ok(morsel) = recv.recv().await {
             // Compute hashed keys and payload. We must rechunk the payload for
             // later gathers.
-            let hash_keys = select_keys(
-                morsel.df(),
-                key_selectors,
-                params,
-                &state.in_memory_exec_state,
-            )
-            .await?;
+            let keys =
+                select_key_columns(morsel.df(), key_selectors, &state.in_memory_exec_state).await?;
+            if let Some(runtime_filter) = runtime_filter {
+                local
+                    .runtime_filter_keys
+                    .push(runtime_filter.select_build_keys(&keys));
+            }
+            let hash_keys =
+                HashKeys::from_df(&keys, params.random_state, params.args.nulls_equal, false);
             let mut payload = select_payload(morsel.df().clone(), payload_selector);
             payload.rechunk_mut();
 
@@ -1151,6 +1516,7 @@ impl EmitUnmatchedState {
 
 enum EquiJoinState {
     Sample(SampleState),
+    SortedMerge(InMemorySourceNode),
     Build(BuildState),
     Probe(ProbeState),
     EmitUnmatchedBuild(EmitUnmatchedState),
@@ -1175,6 +1541,7 @@ impl EquiJoinNode {
         left_key_selectors: Vec<StreamExpr>,
         right_key_selectors: Vec<StreamExpr>,
         args: JoinArgs,
+        runtime_filters: [Option<RuntimeJoinFilter>; 2],
         num_pipelines: usize,
     ) -> PolarsResult<Self> {
         let left_is_build = match args.maintain_order {
@@ -1229,6 +1596,7 @@ impl EquiJoinNode {
             state,
             params: EquiJoinParams {
                 left_is_build,
+                num_partitions: num_pipelines,
                 preserve_order_build,
                 preserve_order_probe,
                 left_key_schema,
@@ -1241,6 +1609,7 @@ impl EquiJoinNode {
                 right_payload_schema,
                 args,
                 random_state: PlRandomState::default(),
+                runtime_filters,
             },
             table: new_idx_table(unique_key_schema),
         })
@@ -1265,6 +1634,15 @@ impl ComputeNode for EquiJoinNode {
             self.state = EquiJoinState::Done;
         }
 
+        // If we are sampling and both sides are completely sampled and sorted,
+        // merge them directly.
+        if let EquiJoinState::Sample(sample_state) = &mut self.state {
+            if let Some(out) = sample_state.try_sorted_merge_join(recv, &self.params)? {
+                let src = InMemorySourceNode::new(Arc::new(out), MorselSeq::default());
+                self.state = EquiJoinState::SortedMerge(src);
+            }
+        }
+
         // If we are sampling and both sides are done/filled, transition to building.
         if let EquiJoinState::Sample(sample_state) = &mut self.state {
             if let Some(build_state) =
@@ -1284,6 +1662,15 @@ impl ComputeNode for EquiJoinNode {
         // If we are building and the build input is done, transition to probing.
         if let EquiJoinState::Build(build_state) = &mut self.state {
             if recv[build_idx] == PortState::Done {
+                if let Some(runtime_filter) = self.params.probe_runtime_filter() {
+                    let build_keys = build_state
+                        .local_builders
+                        .iter_mut()
+                        .flat_map(|l| core::mem::take(&mut l.runtime_filter_keys))
+                        .collect();
+                    runtime_filter.set(build_keys, self.params.args.nulls_equal)?;
+                }
+
                 let probe_state = if self.params.preserve_order_build {
                     build_state.finalize_ordered(&self.params, &*self.table)
                 } else {
@@ -1330,19 +1717,20 @@ impl ComputeNode for EquiJoinNode {
         match &mut self.state {
             EquiJoinState::Sample(sample_state) => {
                 send[0] = PortState::Blocked;
-                if recv[0] != PortState::Done {
-                    recv[0] = if sample_state.left_len < *JOIN_SAMPLE_LIMIT {
-                        PortState::Ready
-                    } else {
-                        PortState::Blocked
-                    };
-                }
-                if recv[1] != PortState::Done {
-                    recv[1] = if sample_state.right_len < *JOIN_SAMPLE_LIMIT {
-                        PortState::Ready
-                    } else {
-                        PortState::Blocked
-                    };
+                let deferred = self.params.deferred_sample_side().filter(|&side| {
+                    recv[1 - side] != PortState::Done
+                        && sample_state.len(1 - side) < *JOIN_SAMPLE_LIMIT
+                });
+                for (side, port) in recv.iter_mut().enumerate() {
+                    if *port != PortState::Done {
+                        *port = if sample_state.len(side) < *JOIN_SAMPLE_LIMIT
+                            && deferred != Some(side)
+                        {
+                            PortState::Ready
+                        } else {
+                            PortState::Blocked
+                        };
+                    }
                 }
             },
             EquiJoinState::Build(_) => {
@@ -1372,7 +1760,8 @@ impl ComputeNode for EquiJoinNode {
                 recv[build_idx] = PortState::Done;
                 recv[probe_idx] = PortState::Done;
             },
-            EquiJoinState::EmitUnmatchedBuildInOrder(src_node) => {
+            EquiJoinState::SortedMerge(src_node)
+            | EquiJoinState::EmitUnmatchedBuildInOrder(src_node) => {
                 recv[build_idx] = PortState::Done;
                 recv[probe_idx] = PortState::Done;
                 src_node.update_state(&mut [], &mut send[0..1], state)?;
@@ -1428,15 +1817,19 @@ impl ComputeNode for EquiJoinNode {
                     usize::MAX
                 }));
 
+                let sorted_merge_candidate = self.params.sorted_merge_candidate();
                 if let Some(left_recv) = recv_ports[0].take() {
                     join_handles.push(scope.spawn_task(
                         TaskPriority::High,
                         SampleState::sink(
                             left_recv.serial(),
                             &mut sample_state.left,
+                            &mut sample_state.left_keys,
+                            sorted_merge_candidate.then_some(&self.params.left_key_selectors[..]),
                             &mut sample_state.left_len,
                             left_final_len.clone(),
                             right_final_len.clone(),
+                            &state.in_memory_exec_state,
                         ),
                     ));
                 }
@@ -1446,9 +1839,12 @@ impl ComputeNode for EquiJoinNode {
                         SampleState::sink(
                             right_recv.serial(),
                             &mut sample_state.right,
+                            &mut sample_state.right_keys,
+                            sorted_merge_candidate.then_some(&self.params.right_key_selectors[..]),
                             &mut sample_state.right_len,
                             right_final_len,
                             left_final_len,
+                            &state.in_memory_exec_state,
                         ),
                     ));
                 }
@@ -1458,7 +1854,7 @@ impl ComputeNode for EquiJoinNode {
                 assert!(recv_ports[probe_idx].is_none());
                 let receivers = recv_ports[build_idx].take().unwrap().parallel();
 
-                let partitioner = HashPartitioner::new(state.num_pipelines, 0);
+                let partitioner = HashPartitioner::new(self.params.num_partitions, 0);
                 for (local_builder, recv) in build_state.local_builders.iter_mut().zip(receivers) {
                     join_handles.push(scope.spawn_task(
                         TaskPriority::High,
@@ -1485,7 +1881,7 @@ impl ComputeNode for EquiJoinNode {
                     )
                     .unwrap();
 
-                let partitioner = HashPartitioner::new(state.num_pipelines, 0);
+                let partitioner = HashPartitioner::new(self.params.num_partitions, 0);
                 let probe_tasks = receivers
                     .into_iter()
                     .zip(senders)
@@ -1522,7 +1918,8 @@ impl ComputeNode for EquiJoinNode {
                     emit_state.emit_unmatched(send, &self.params, state.num_pipelines),
                 ));
             },
-            EquiJoinState::EmitUnmatchedBuildInOrder(src_node) => {
+            EquiJoinState::SortedMerge(src_node)
+            | EquiJoinState::EmitUnmatchedBuildInOrder(src_node) => {
                 assert!(recv_ports[build_idx].is_none());
                 assert!(recv_ports[probe_idx].is_none());
                 src_node.spawn(scope, &mut [], send_ports, state, join_handles);
@@ -1531,3 +1928,104 @@ impl ComputeNode for EquiJoinNode {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_choose_hash_join_strategy() {
+        let choose = |lens, done, deferred_build| {
+            choose_hash_join_strategy(lens, done, deferred_build, || Ok((1.0, 2.0))).unwrap()
+        };
+        let strategy = |left_is_build, broadcast| {
+            Some(HashJoinStrategy {
+                left_is_build,
+                broadcast,
+            })
+        };
+
+        // Keep sampling until a side is done.
+        assert_eq!(choose([10, 50], [false, false], None), None);
+        // A small and complete build side is broadcast.
+        assert_eq!(
+            choose([50, 20_000], [true, true], None),
+            strategy(true, true)
+        );
+        assert_eq!(choose([10, 200], [true, false], None), strategy(true, true));
+        // A big build side is partitioned.
+        assert_eq!(
+            choose([200_000, 150_000], [true, true], None),
+            strategy(false, false)
+        );
+        // Both sides are saturated, the side with the smaller cardinality is the build side.
+        let saturated = *JOIN_SAMPLE_LIMIT;
+        assert_eq!(
+            choose([saturated, saturated], [false, false], None),
+            strategy(true, false)
+        );
+        // The other side of a deferred side is built on without sampling the deferred side.
+        assert_eq!(
+            choose([5, 0], [true, false], Some(true)),
+            strategy(true, true)
+        );
+    }
+
+    #[test]
+    fn test_sorted_merge_join_idxs() -> PolarsResult<()> {
+        let idxs = |left: &DataFrame, right: &DataFrame, how: JoinType, limit: usize| {
+            let idxs = sorted_merge_join_idxs(left, right, &how, limit)?;
+            PolarsResult::Ok(idxs.map(|idxs| idxs.map(|idx| idx.into_iter().collect::<Vec<_>>())))
+        };
+
+        let left = df!["a" => [1i64, 1, 2, 4]]?;
+        let right = df!["a" => [1i64, 2, 2, 3]]?;
+        assert_eq!(
+            idxs(&left, &right, JoinType::Inner, usize::MAX)?,
+            Some([
+                vec![Some(0), Some(1), Some(2), Some(2)],
+                vec![Some(0), Some(0), Some(1), Some(2)],
+            ])
+        );
+        assert_eq!(
+            idxs(&left, &right, JoinType::Full, usize::MAX)?,
+            Some([
+                vec![Some(0), Some(1), Some(2), Some(2), None, Some(3)],
+                vec![Some(0), Some(0), Some(1), Some(2), Some(3), None],
+            ])
+        );
+        assert_eq!(
+            idxs(&left, &right, JoinType::Right, usize::MAX)?,
+            Some([
+                vec![Some(0), Some(1), Some(2), Some(2), None],
+                vec![Some(0), Some(0), Some(1), Some(2), Some(3)],
+            ])
+        );
+        // The output is too big.
+        assert_eq!(idxs(&left, &right, JoinType::Inner, 3)?, None);
+        // Unsorted or null keys.
+        assert_eq!(
+            idxs(&df!["a" => [2i64, 1]]?, &right, JoinType::Inner, usize::MAX)?,
+            None
+        );
+        assert_eq!(
+            idxs(
+                &df!["a" => [Some(1i64), None]]?,
+                &right,
+                JoinType::Inner,
+                usize::MAX
+            )?,
+            None
+        );
+
+        // Multiple keys are compared by their row encoding.
+        let left = df!["a" => ["a", "b"], "b" => [1i32, 0]]?;
+        let right = df!["a" => ["a", "a", "b"], "b" => [1i32, 2, 0]]?;
+        assert_eq!(
+            idxs(&left, &right, JoinType::Left, usize::MAX)?,
+            Some([vec![Some(0), Some(1)], vec![Some(0), Some(2)]])
+        );
+
+        Ok(())
+    }
+}
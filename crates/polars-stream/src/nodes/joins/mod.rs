@@ -15,6 +15,7 @@ use crate::pipe::RecvPort;
 pub mod cross_join;
 pub mod equi_join;
 pub mod in_memory;
+pub mod runtime_filter;
 #[cfg(feature = "semi_anti_join")]
 pub mod semi_anti_join;
 
@@ -24,6 +25,23 @@ static JOIN_SAMPLE_LIMIT: LazyLock<usize> = LazyLock::new(|| {
         .unwrap_or(10_000_000)
 });
 
+// If the build side of a join is completely known and at most this many rows we
+// build a single hash table which is shared by all probing pipelines instead of
+// partitioning it.
+static JOIN_BROADCAST_LIMIT: LazyLock<usize> = LazyLock::new(|| {
+    std::env::var("POLARS_JOIN_BROADCAST_LIMIT")
+        .map(|limit| limit.parse().unwrap())
+        .unwrap_or(100_000)
+});
+
+// A sorted merge join materializes its output at once. If it would output more
+// than this many rows a hash join is used instead, which streams its output.
+static JOIN_SORTED_MERGE_LIMIT: LazyLock<usize> = LazyLock::new(|| {
+    std::env::var("POLARS_JOIN_SORTED_MERGE_LIMIT")
+        .map(|limit| limit.parse().unwrap())
+        .unwrap_or(10_000_000)
+});
+
 // If one side is this much bigger than the other side we'll always use the
 // smaller side as the build side without checking cardinalities.
 const LOPSIDED_SAMPLE_FACTOR: usize = 10;
@@ -0,0 +1,387 @@
+//! Runtime join filters.
+//!
+//! Once the build side of a join is complete, we know which keys on the probe side can find a
+//! match. A [`RuntimeJoinFilter`] is created from the build keys and given to the scan that
+//! produces the probe side, so that it can skip row groups and rows that cannot match before
+//! decoding the remaining columns.
+use std::sync::{Arc, OnceLock};
+
+use arrow::bitmap::Bitmap;
+use polars_core::config;
+use polars_core::prelude::*;
+use polars_io::predicates::{
+    ColumnPredicates, PhysicalIoExpr, ScanIOPredicate, SkipBatchPredicate,
+};
+use polars_utils::aliases::{PlSeedableRandomStateQuality, SeedableFromU64SeedExt};
+use polars_utils::format_pl_smallstr;
+
+/// Number of bits in the bloom filter per build key.
+const BLOOM_FILTER_BITS_PER_KEY: usize = 16;
+/// Maximum number of bits in a bloom filter, 8MiB.
+const BLOOM_FILTER_MAX_BITS: usize = 1 << 26;
+
+fn bloom_filter_hasher() -> PlSeedableRandomStateQuality {
+    PlSeedableRandomStateQuality::seed_from_u64(0x9E37_79B9_7F4A_7C15)
+}
+
+/// Whether a runtime filter can be created for join keys of this type.
+pub fn supports_runtime_filter(dtype: &DataType) -> bool {
+    // Floats are excluded as their equality in joins does not match their ordering and hashing.
+    dtype.is_integer()
+        || dtype.is_temporal()
+        || matches!(dtype, DataType::String | DataType::Binary)
+}
+
+/// Converts a boolean mask to a bitmap, where nulls are false.
+fn mask_to_bitmap(mask: &BooleanChunked) -> Bitmap {
+    let mask = mask.rechunk();
+    let arr = mask.downcast_as_array();
+    match arr.validity() {
+        Some(validity) => arr.values() & validity,
+        None => arr.values().clone(),
+    }
+}
+
+struct BloomFilter {
+    bits: Vec<u64>,
+    bit_mask: u64,
+}
+
+impl BloomFilter {
+    fn new(num_keys: usize) -> Self {
+        let num_bits = num_keys
+            .saturating_mul(BLOOM_FILTER_BITS_PER_KEY)
+            .next_power_of_two()
+            .clamp(64, BLOOM_FILTER_MAX_BITS);
+
+        Self {
+            bits: vec![0; num_bits / 64],
+            bit_mask: num_bits as u64 - 1,
+        }
+    }
+
+    fn positions(&self, hash: u64) -> [u64; 3] {
+        [
+            hash & self.bit_mask,
+            hash.rotate_left(21) & self.bit_mask,
+            hash.rotate_left(42) & self.bit_mask,
+        ]
+    }
+
+    fn insert(&mut self, hash: u64) {
+        for p in self.positions(hash) {
+            self.bits[(p / 64) as usize] |= 1 << (p % 64);
+        }
+    }
+
+    fn contains(&self, hash: u64) -> bool {
+        self.positions(hash)
+            .iter()
+            .all(|p| self.bits[(p / 64) as usize] & (1 << (p % 64)) != 0)
+    }
+}
+
+/// Filter on a single join key.
+struct KeyFilter {
+    /// Minimum and maximum of the non-null build keys, `None` if there are none.
+    range: Option<(Series, Series)>,
+    bloom_filter: BloomFilter,
+    /// Whether null keys can find a match.
+    keep_nulls: bool,
+}
+
+impl KeyFilter {
+    fn new(build_keys: &Column, nulls_equal: bool) -> PolarsResult<Self> {
+        let build_keys = build_keys.as_materialized_series();
+        let num_valid = build_keys.len() - build_keys.null_count();
+
+        let range = if num_valid > 0 {
+            Some((
+                build_keys.min_reduce()?.into_series(PlSmallStr::EMPTY),
+                build_keys.max_reduce()?.into_series(PlSmallStr::EMPTY),
+            ))
+        } else {
+            None
+        };
+
+        let mut bloom_filter = BloomFilter::new(num_valid);
+        let mut hashes = Vec::with_capacity(build_keys.len());
+        build_keys.vec_hash(bloom_filter_hasher(), &mut hashes)?;
+        let is_valid = build_keys.is_not_null();
+        for (hash, is_valid) in hashes.into_iter().zip(is_valid.into_no_null_iter()) {
+            if is_valid {
+                bloom_filter.insert(hash);
+            }
+        }
+
+        Ok(Self {
+            range,
+            bloom_filter,
+            keep_nulls: nulls_equal && num_valid < build_keys.len(),
+        })
+    }
+
+    /// Returns a mask of the rows that can find a match.
+    fn filter_rows(&self, keys: &Series) -> PolarsResult<Bitmap> {
+        let mut mask = match &self.range {
+            None => Bitmap::new_zeroed(keys.len()),
+            Some((min, max)) => {
+                let in_range = keys.gt_eq(min)? & keys.lt_eq(max)?;
+                let in_range = mask_to_bitmap(&in_range);
+
+                let mut hashes = Vec::with_capacity(keys.len());
+                keys.vec_hash(bloom_filter_hasher(), &mut hashes)?;
+                let in_bloom_filter: Bitmap = hashes
+                    .into_iter()
+                    .map(|h| self.bloom_filter.contains(h))
+                    .collect();
+
+                &in_range & &in_bloom_filter
+            },
+        };
+
+        if self.keep_nulls && keys.has_nulls() {
+            mask = &mask | &mask_to_bitmap(&keys.is_null());
+        }
+
+        Ok(mask)
+    }
+
+    /// Returns a mask of the batches that cannot contain a match.
+    fn skip_batches(
+        &self,
+        min: &Series,
+        max: &Series,
+        null_count: &Series,
+    ) -> PolarsResult<Bitmap> {
+        let mut skip = match &self.range {
+            None => Bitmap::new_with_value(true, min.len()),
+            Some((key_min, key_max)) => {
+                let below = mask_to_bitmap(&max.lt(key_min)?);
+                let above = mask_to_bitmap(&min.gt(key_max)?);
+                &below | &above
+            },
+        };
+
+        if self.keep_nulls {
+            let no_nulls = mask_to_bitmap(&null_count.idx()?.equal(0));
+            skip = &skip & &no_nulls;
+        }
+
+        Ok(skip)
+    }
+}
+
+/// A join key to filter on, as its index in the join keys and the name and type of the column it
+/// refers to in the scan.
+pub type RuntimeFilterKey = (usize, PlSmallStr, DataType);
+
+/// A filter on the probe-side keys of a join that is created at runtime from the build-side keys.
+///
+/// Before the build side is complete the filter lets everything through.
+#[derive(Clone)]
+pub struct RuntimeJoinFilter {
+    keys: Arc<[RuntimeFilterKey]>,
+    filters: Arc<OnceLock<Vec<KeyFilter>>>,
+}
+
+impl RuntimeJoinFilter {
+    pub fn new(keys: Vec<RuntimeFilterKey>) -> Self {
+        assert!(!keys.is_empty());
+        Self {
+            keys: keys.into(),
+            filters: Default::default(),
+        }
+    }
+
+    /// Selects the filtered keys from the evaluated build-side keys.
+    pub fn select_build_keys(&self, keys: &DataFrame) -> Vec<Column> {
+        self.keys
+            .iter()
+            .map(|(key_idx, _, _)| keys.get_columns()[*key_idx].clone())
+            .collect()
+    }
+
+    /// Creates the filter from all build-side keys, as returned by
+    /// [`RuntimeJoinFilter::select_build_keys`].
+    pub fn set(&self, build_keys: Vec<Vec<Column>>, nulls_equal: bool) -> PolarsResult<()> {
+        let mut num_build_keys = 0;
+        let filters = self
+            .keys
+            .iter()
+            .enumerate()
+            .map(|(i, (_, name, dtype))| {
+                let mut keys = Column::new_empty(name.clone(), dtype);
+                for chunk in &build_keys {
+                    keys.append(&chunk[i])?;
+                }
+                num_build_keys = keys.len();
+                KeyFilter::new(&keys, nulls_equal)
+            })
+            .collect::<PolarsResult<Vec<_>>>()?;
+
+        if config::verbose() {
+            eprintln!(
+                "created runtime join filter on {:?} from {} build keys",
+                self.keys
+                    .iter()
+                    .map(|(_, name, _)| name)
+                    .collect::<Vec<_>>(),
+                num_build_keys
+            );
+        }
+
+        _ = self.filters.set(filters);
+        Ok(())
+    }
+
+    fn iter_set(&self) -> Option<impl Iterator<Item = (&PlSmallStr, &DataType, &KeyFilter)>> {
+        let filters = self.filters.get()?;
+        Some(
+            self.keys
+                .iter()
+                .zip(filters)
+                .map(|((_, name, dtype), filter)| (name, dtype, filter)),
+        )
+    }
+
+    /// Adds this filter to the predicate of a scan.
+    pub fn to_scan_predicate(
+        &self,
+        file_schema: SchemaRef,
+        predicate: Option<ScanIOPredicate>,
+    ) -> ScanIOPredicate {
+        let filter = Arc::new(RuntimeFilterPredicate {
+            filter: self.clone(),
+            schema: file_schema,
+        });
+        let mut live_columns: PlIndexSet<PlSmallStr> =
+            self.keys.iter().map(|(_, name, _)| name.clone()).collect();
+
+        let Some(predicate) = predicate else {
+            return ScanIOPredicate {
+                predicate: filter.clone(),
+                live_columns: Arc::new(live_columns),
+                skip_batch_predicate: Some(filter),
+                column_predicates: Arc::new(ColumnPredicates::default()),
+                hive_predicate: None,
+                hive_predicate_is_full_predicate: false,
+                metrics: None,
+            };
+        };
+
+        live_columns.extend(predicate.live_columns.iter().cloned());
+
+        let skip_batch_predicate: Arc<dyn SkipBatchPredicate> = match predicate.skip_batch_predicate
+        {
+            Some(sbp) => Arc::new(AnySkipBatchPredicate([sbp, filter.clone()])),
+            None => filter.clone(),
+        };
+
+        // The column predicates no longer cover the full predicate.
+        let mut column_predicates = predicate.column_predicates.as_ref().clone();
+        column_predicates.is_sumwise_complete = false;
+
+        ScanIOPredicate {
+            predicate: Arc::new(AllIoExpr([predicate.predicate, filter])),
+            live_columns: Arc::new(live_columns),
+            skip_batch_predicate: Some(skip_batch_predicate),
+            column_predicates: Arc::new(column_predicates),
+            hive_predicate: predicate.hive_predicate,
+            hive_predicate_is_full_predicate: false,
+            metrics: predicate.metrics,
+        }
+    }
+}
+
+struct RuntimeFilterPredicate {
+    filter: RuntimeJoinFilter,
+    schema: SchemaRef,
+}
+
+impl PhysicalIoExpr for RuntimeFilterPredicate {
+    fn evaluate_io(&self, df: &DataFrame) -> PolarsResult<Series> {
+        let mut mask: Option<Bitmap> = None;
+
+        for (name, dtype, filter) in self.filter.iter_set().into_iter().flatten() {
+            // The column can have a different type in the file, in which case we don't filter.
+            let Some(keys) = df.column(name).ok().filter(|c| c.dtype() == dtype) else {
+                continue;
+            };
+            let key_mask = filter.filter_rows(keys.as_materialized_series())?;
+            mask = Some(match mask {
+                Some(mask) => &mask & &key_mask,
+                None => key_mask,
+            });
+        }
+
+        let mask = mask.unwrap_or_else(|| Bitmap::new_with_value(true, df.height()));
+        Ok(BooleanChunked::from_bitmap(PlSmallStr::EMPTY, mask).into_series())
+    }
+}
+
+impl SkipBatchPredicate for RuntimeFilterPredicate {
+    fn schema(&self) -> &SchemaRef {
+        &self.schema
+    }
+
+    fn evaluate_with_stat_df(&self, df: &DataFrame) -> PolarsResult<Bitmap> {
+        let mut skip = Bitmap::new_zeroed(df.height());
+
+        for (name, dtype, filter) in self.filter.iter_set().into_iter().flatten() {
+            let (Ok(min), Ok(max), Ok(null_count)) = (
+                df.column(&format_pl_smallstr!("{name}_min")),
+                df.column(&format_pl_smallstr!("{name}_max")),
+                df.column(&format_pl_smallstr!("{name}_nc")),
+            ) else {
+                continue;
+            };
+            if min.dtype() != dtype || max.dtype() != dtype || null_count.dtype() != &IDX_DTYPE {
+                continue;
+            }
+
+            let key_skip = filter.skip_batches(
+                min.as_materialized_series(),
+                max.as_materialized_series(),
+                null_count.as_materialized_series(),
+            )?;
+            skip = &skip | &key_skip;
+        }
+
+        Ok(skip)
+    }
+
+    fn is_dynamic(&self) -> bool {
+        self.filter.filters.get().is_none()
+    }
+}
+
+/// Lets a row through if all predicates let it through.
+struct AllIoExpr([Arc<dyn PhysicalIoExpr>; 2]);
+
+impl PhysicalIoExpr for AllIoExpr {
+    fn evaluate_io(&self, df: &DataFrame) -> PolarsResult<Series> {
+        let [a, b] = &self.0;
+        let a = a.evaluate_io(df)?;
+        let b = b.evaluate_io(df)?;
+        Ok((a.bool()? & b.bool()?).into_series())
+    }
+}
+
+/// Skips a batch if any predicate skips it.
+struct AnySkipBatchPredicate([Arc<dyn SkipBatchPredicate>; 2]);
+
+impl SkipBatchPredicate for AnySkipBatchPredicate {
+    fn schema(&self) -> &SchemaRef {
+        self.0[0].schema()
+    }
+
+    fn evaluate_with_stat_df(&self, df: &DataFrame) -> PolarsResult<Bitmap> {
+        let [a, b] = &self.0;
+        Ok(&a.evaluate_with_stat_df(df)? | &b.evaluate_with_stat_df(df)?)
+    }
+
+    fn is_dynamic(&self) -> bool {
+        self.0.iter().any(|p| p.is_dynamic())
+    }
+}
@@ -17,18 +17,27 @@ use crate::async_executor;
 use crate::async_primitives::connector::{Receiver, Sender};
 use crate::expression::StreamExpr;
 use crate::nodes::compute_node_prelude::*;
+use crate::nodes::joins::runtime_filter::RuntimeJoinFilter;
 
-async fn select_keys(
+async fn select_key_columns(
     df: &DataFrame,
     key_selectors: &[StreamExpr],
-    params: &SemiAntiJoinParams,
     state: &ExecutionState,
-) -> PolarsResult<HashKeys> {
+) -> PolarsResult<DataFrame> {
     let mut key_columns = Vec::new();
     for selector in key_selectors {
         key_columns.push(selector.evaluate(df, state).await?.into_column());
     }
-    let keys = DataFrame::new_with_broadcast_len(key_columns, df.height())?;
+    DataFrame::new_with_broadcast_len(key_columns, df.height())
+}
+
+async fn select_keys(
+    df: &DataFrame,
+    key_selectors: &[StreamExpr],
+    params: &SemiAntiJoinParams,
+    state: &ExecutionState,
+) -> PolarsResult<HashKeys> {
+    let keys = select_key_columns(df, key_selectors, state).await?;
     Ok(HashKeys::from_df(
         &keys,
         params.random_state,
@@ -45,6 +54,8 @@ struct SemiAntiJoinParams {
     is_anti: bool,
     return_bool: bool,
     random_state: PlRandomState,
+    /// Filter to create for the scan of the probe side.
+    runtime_filter: Option<RuntimeJoinFilter>,
 }
 
 pub struct SemiAntiJoinNode {
@@ -60,6 +71,7 @@ impl SemiAntiJoinNode {
         right_key_selectors: Vec<StreamExpr>,
         args: JoinArgs,
         return_bool: bool,
+        runtime_filter: Option<RuntimeJoinFilter>,
         num_pipelines: usize,
     ) -> PolarsResult<Self> {
         let left_is_build = false;
@@ -77,6 +89,7 @@ impl SemiAntiJoinNode {
                 nulls_equal: args.nulls_equal,
                 return_bool,
                 is_anti,
+                runtime_filter: runtime_filter.filter(|_| !is_anti && !return_bool),
             },
             grouper: new_hash_grouper(unique_key_schema),
         })
@@ -103,6 +116,9 @@ struct LocalBuilder {
     // let stop = key_idxs_offsets[(i + 1) * num_partitions + p];
     key_idxs_values_per_p: Vec<Vec<IdxSize>>,
     key_idxs_offsets_per_p: Vec<usize>,
+
+    // The keys seen by this builder that are used for the runtime filter of the probe side.
+    runtime_filter_keys: Vec<Vec<Column>>,
 }
 
 struct BuildState {
@@ -117,6 +133,7 @@ impl BuildState {
                 sketch_per_p: vec![CardinalitySketch::default(); num_partitions],
                 key_idxs_values_per_p: vec![Vec::new(); num_partitions],
                 key_idxs_offsets_per_p: vec![0; num_partitions],
+                runtime_filter_keys: Vec::new(),
             })
             .collect();
         Self { local_builders }
@@ -136,13 +153,15 @@ impl BuildState {
         };
 
         while let Ok(morsel) = recv.recv().await {
-            let hash_keys = select_keys(
-                morsel.df(),
-                key_selectors,
-                params,
-                &state.in_memory_exec_state,
-            )
-            .await?;
+            let keys =
+                select_key_columns(morsel.df(), key_selectors, &state.in_memory_exec_state).await?;
+            if let Some(runtime_filter) = &params.runtime_filter {
+                local
+                    .runtime_filter_keys
+                    .push(runtime_filter.select_build_keys(&keys));
+            }
+            let hash_keys =
+                HashKeys::from_df(&keys, params.random_state, params.nulls_equal, false);
 
             hash_keys.gen_idxs_per_partition(
                 &partitioner,
@@ -365,6 +384,15 @@ impl ComputeNode for SemiAntiJoinNode {
         // If we are building and the build input is done, transition to probing.
         if let SemiAntiJoinState::Build(build_state) = &mut self.state {
             if recv[build_idx] == PortState::Done {
+                if let Some(runtime_filter) = &self.params.runtime_filter {
+                    let build_keys = build_state
+                        .local_builders
+                        .iter_mut()
+                        .flat_map(|l| core::mem::take(&mut l.runtime_filter_keys))
+                        .collect();
+                    runtime_filter.set(build_keys, self.params.nulls_equal)?;
+                }
+
                 let probe_state = build_state.finalize(&*self.grouper);
                 self.state = SemiAntiJoinState::Probe(probe_state);
             }
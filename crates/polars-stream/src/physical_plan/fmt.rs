@@ -271,6 +271,8 @@ fn visualize_plan_rec(
             FileType::Csv(_) => ("csv-sink".to_string(), from_ref(input)),
             #[cfg(feature = "json")]
             FileType::Json(_) => ("ndjson-sink".to_string(), from_ref(input)),
+            #[cfg(feature = "orc")]
+            FileType::Orc(_) => ("orc-sink".to_string(), from_ref(input)),
             #[allow(unreachable_patterns)]
             _ => todo!(),
         },
@@ -295,6 +297,8 @@ fn visualize_plan_rec(
                 FileType::Csv(_) => (format!("{variant}[csv]"), from_ref(input)),
                 #[cfg(feature = "json")]
                 FileType::Json(_) => (format!("{variant}[ndjson]"), from_ref(input)),
+                #[cfg(feature = "orc")]
+                FileType::Orc(_) => (format!("{variant}[orc]"), from_ref(input)),
                 #[allow(unreachable_patterns)]
                 _ => todo!(),
             }
@@ -314,7 +318,7 @@ fn visualize_plan_rec(
             }
             (label, from_ref(input))
         },
-        PhysNodeKind::Map { input, map: _ } => ("map".to_string(), from_ref(input)),
+        PhysNodeKind::Map { input, .. } => ("map".to_string(), from_ref(input)),
         PhysNodeKind::Sort {
             input,
             by_column,
@@ -401,6 +405,7 @@ fn visualize_plan_rec(
             forbid_extra_columns: _,
             deletion_files,
             table_statistics: _,
+            scan_metrics: _,
             file_schema: _,
         } => {
             let mut out = format!("multi-scan[{}]", file_reader_builder.reader_name());
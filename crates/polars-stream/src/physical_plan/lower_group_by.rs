@@ -412,7 +412,7 @@ fn try_build_streaming_group_by(
     }
 
     #[cfg(feature = "dynamic_group_by")]
-    if options.dynamic.is_some() || options.rolling.is_some() {
+    if options.dynamic.is_some() || options.rolling.is_some() || options.session.is_some() {
         return None; // TODO
     }
 
@@ -434,10 +434,19 @@ pub fn lower_ir(
                 },
 
                 function if function.is_streamable() => {
+                    let predicate_pd = matches!(
+                        function,
+                        FunctionIR::Opaque {
+                            schema: None,
+                            predicate_pd: true,
+                            ..
+                        }
+                    );
                     let map = Arc::new(move |df| function.evaluate(df));
                     PhysNodeKind::Map {
                         input: phys_input,
                         map,
+                        predicate_pd,
                     }
                 },
 
@@ -641,6 +650,15 @@ pub fn lower_ir(
                         first_metadata: first_metadata.clone(),
                     }) as Arc<dyn FileReaderBuilder>,
 
+                    #[cfg(feature = "orc")]
+                    FileScanIR::Orc {
+                        options,
+                        metadata: first_metadata,
+                    } => Arc::new(crate::nodes::io_sources::orc::builder::OrcReaderBuilder {
+                        options: Arc::new(options.clone()),
+                        first_metadata: first_metadata.clone(),
+                    }) as Arc<dyn FileReaderBuilder>,
+
                     #[cfg(feature = "csv")]
                     FileScanIR::Csv { options } => {
                         Arc::new(Arc::new(options.clone())) as Arc<dyn FileReaderBuilder>
@@ -651,6 +669,11 @@ pub fn lower_ir(
                         Arc::new(Arc::new(options.clone())) as Arc<dyn FileReaderBuilder>
                     },
 
+                    #[cfg(feature = "fwf")]
+                    FileScanIR::Fwf { options } => {
+                        Arc::new(Arc::new(options.clone())) as Arc<dyn FileReaderBuilder>
+                    },
+
                     #[cfg(feature = "python")]
                     FileScanIR::PythonDataset {
                         dataset_object: _,
@@ -747,6 +770,7 @@ pub fn lower_ir(
                             unified_scan_args.deletion_files,
                         ),
                         table_statistics: unified_scan_args.table_statistics,
+                        scan_metrics: unified_scan_args.scan_metrics,
                         file_schema,
                     };
 
@@ -7,6 +7,7 @@ use polars_core::schema::{Schema, SchemaRef};
 use polars_error::PolarsResult;
 use polars_io::RowIndex;
 use polars_io::cloud::CloudOptions;
+use polars_io::predicates::ScanMetrics;
 use polars_ops::frame::JoinArgs;
 use polars_plan::dsl::deletion::DeletionFilesList;
 use polars_plan::dsl::{
@@ -23,6 +24,7 @@ mod io;
 mod lower_expr;
 mod lower_group_by;
 mod lower_ir;
+mod runtime_join_filters;
 mod to_graph;
 
 pub use fmt::visualize_plan;
@@ -199,6 +201,10 @@ pub enum PhysNodeKind {
     Map {
         input: PhysStream,
         map: Arc<dyn DataFrameUdf>,
+
+        /// Whether the map passes the rows and columns of its input through unchanged, so that
+        /// filters on its output may be applied to its input instead.
+        predicate_pd: bool,
     },
 
     Sort {
@@ -275,6 +281,7 @@ pub enum PhysNodeKind {
 
         deletion_files: Option<DeletionFilesList>,
         table_statistics: Option<TableStatistics>,
+        scan_metrics: Option<Arc<ScanMetrics>>,
 
         /// Schema of columns contained in the file. Does not contain external columns (e.g. hive / row_index).
         file_schema: SchemaRef,
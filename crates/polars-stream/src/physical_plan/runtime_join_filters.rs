@@ -0,0 +1,207 @@
+use polars_core::config;
+use polars_ops::frame::{JoinArgs, JoinType};
+use polars_plan::plans::{AExpr, is_elementwise_rec};
+use polars_plan::prelude::expr_ir::ExprIR;
+use polars_utils::arena::Arena;
+use polars_utils::pl_str::PlSmallStr;
+use slotmap::{SecondaryMap, SlotMap};
+
+use super::{PhysNode, PhysNodeKey, PhysNodeKind, PhysStream};
+use crate::nodes::io_sources::multi_scan::reader_interface::capabilities::ReaderCapabilities;
+use crate::nodes::joins::runtime_filter::{
+    RuntimeFilterKey, RuntimeJoinFilter, supports_runtime_filter,
+};
+
+/// The runtime filters that joins create for the scans that produce their inputs.
+#[derive(Default)]
+pub struct RuntimeJoinFilters {
+    /// The filters each join creates for its left and right input.
+    pub joins: SecondaryMap<PhysNodeKey, [Option<RuntimeJoinFilter>; 2]>,
+    /// The filter each scan applies.
+    pub scans: SecondaryMap<PhysNodeKey, RuntimeJoinFilter>,
+}
+
+/// Finds the scans whose output can be filtered by the keys of a join they feed into.
+///
+/// This must be called after multiplexers are inserted, a stream that is consumed by a join is
+/// then not consumed by anything else.
+pub fn plan_runtime_join_filters(
+    phys_sm: &SlotMap<PhysNodeKey, PhysNode>,
+    expr_arena: &Arena<AExpr>,
+) -> RuntimeJoinFilters {
+    let mut filters = RuntimeJoinFilters::default();
+
+    if std::env::var("POLARS_NO_RUNTIME_JOIN_FILTERS").as_deref() == Ok("1") {
+        return filters;
+    }
+
+    for (node_key, node) in phys_sm.iter() {
+        // Only sides of which unmatched rows are not emitted can be filtered.
+        let (input_left, input_right, left_on, right_on, filter_sides) = match &node.kind {
+            PhysNodeKind::EquiJoin {
+                input_left,
+                input_right,
+                left_on,
+                right_on,
+                args,
+            } => {
+                let sides = match args.how {
+                    _ if args.validation.needs_checks() => [false, false],
+                    JoinType::Inner => [true, true],
+                    JoinType::Left => [false, true],
+                    JoinType::Right => [true, false],
+                    _ => [false, false],
+                };
+                (input_left, input_right, left_on, right_on, sides)
+            },
+            #[cfg(feature = "semi_anti_join")]
+            PhysNodeKind::SemiAntiJoin {
+                input_left,
+                input_right,
+                left_on,
+                right_on,
+                args:
+                    JoinArgs {
+                        how: JoinType::Semi,
+                        ..
+                    },
+                output_bool: false,
+            } => (input_left, input_right, left_on, right_on, [true, false]),
+            _ => continue,
+        };
+
+        let mut join_filters = [None, None];
+        for (side, (input, on)) in [(input_left, left_on), (input_right, right_on)]
+            .into_iter()
+            .enumerate()
+        {
+            if !filter_sides[side] {
+                continue;
+            }
+
+            let Some((scan_key, keys)) = trace_keys_to_scan(*input, on, phys_sm, expr_arena) else {
+                continue;
+            };
+
+            if config::verbose() {
+                eprintln!(
+                    "join keys {:?} can be filtered at runtime in the scan",
+                    keys.iter().map(|(_, name, _)| name).collect::<Vec<_>>()
+                );
+            }
+
+            let filter = RuntimeJoinFilter::new(keys);
+            filters.scans.insert(scan_key, filter.clone());
+            join_filters[side] = Some(filter);
+        }
+
+        if join_filters.iter().any(Option::is_some) {
+            filters.joins.insert(node_key, join_filters);
+        }
+    }
+
+    filters
+}
+
+/// Follows the join keys that are plain columns back to the scan that produces them. Returns the
+/// scan and for every key that can be filtered its index and the name and type of the column in
+/// the scan.
+fn trace_keys_to_scan(
+    mut stream: PhysStream,
+    on: &[ExprIR],
+    phys_sm: &SlotMap<PhysNodeKey, PhysNode>,
+    expr_arena: &Arena<AExpr>,
+) -> Option<(PhysNodeKey, Vec<RuntimeFilterKey>)> {
+    let mut keys: Vec<(usize, PlSmallStr)> = on
+        .iter()
+        .enumerate()
+        .filter_map(|(i, e)| match expr_arena.get(e.node()) {
+            AExpr::Column(name) => Some((i, name.clone())),
+            _ => None,
+        })
+        .collect();
+
+    loop {
+        if keys.is_empty() || stream.port != 0 {
+            return None;
+        }
+
+        let node = &phys_sm[stream.node];
+        match &node.kind {
+            PhysNodeKind::Filter { input, predicate } => {
+                if !is_elementwise_rec(predicate.node(), expr_arena) {
+                    return None;
+                }
+                stream = *input;
+            },
+            PhysNodeKind::SimpleProjection { input, .. }
+            | PhysNodeKind::Map {
+                input,
+                predicate_pd: true,
+                ..
+            } => stream = *input,
+            PhysNodeKind::Select {
+                input,
+                selectors,
+                extend_original,
+            } => {
+                if !selectors
+                    .iter()
+                    .all(|e| is_elementwise_rec(e.node(), expr_arena))
+                {
+                    return None;
+                }
+
+                // Keep the keys that are passed through unchanged.
+                keys.retain_mut(|(_, name)| {
+                    match selectors.iter().find(|e| e.output_name() == name) {
+                        Some(e) => match expr_arena.get(e.node()) {
+                            AExpr::Column(input_name) => {
+                                *name = input_name.clone();
+                                true
+                            },
+                            _ => false,
+                        },
+                        None => *extend_original,
+                    }
+                });
+                stream = *input;
+            },
+            PhysNodeKind::MultiScan {
+                file_reader_builder,
+                output_schema,
+                row_index,
+                hive_parts,
+                include_file_paths,
+                file_schema,
+                ..
+            } => {
+                if !file_reader_builder
+                    .reader_capabilities()
+                    .contains(ReaderCapabilities::PARTIAL_FILTER)
+                {
+                    return None;
+                }
+
+                let keys = keys
+                    .into_iter()
+                    .filter(|(_, name)| {
+                        file_schema.contains(name)
+                            && row_index.as_ref().is_none_or(|ri| ri.name != name)
+                            && include_file_paths.as_ref() != Some(name)
+                            && hive_parts
+                                .as_ref()
+                                .is_none_or(|hp| !hp.df().schema().contains(name))
+                    })
+                    .filter_map(|(i, name)| {
+                        let dtype = output_schema.get(&name)?.clone();
+                        supports_runtime_filter(&dtype).then_some((i, name, dtype))
+                    })
+                    .collect::<Vec<_>>();
+
+                return (!keys.is_empty()).then_some((stream.node, keys));
+            },
+            _ => return None,
+        }
+    }
+}
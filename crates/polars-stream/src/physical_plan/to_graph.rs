@@ -35,6 +35,7 @@ use crate::nodes::io_sources::multi_scan::config::MultiScanConfig;
 use crate::nodes::io_sources::multi_scan::reader_interface::builder::FileReaderBuilder;
 use crate::nodes::io_sources::multi_scan::reader_interface::capabilities::ReaderCapabilities;
 use crate::physical_plan::lower_expr::compute_output_schema;
+use crate::physical_plan::runtime_join_filters::{RuntimeJoinFilters, plan_runtime_join_filters};
 use crate::utils::late_materialized_df::LateMaterializedDataFrame;
 
 fn has_potential_recurring_entrance(node: Node, arena: &Arena<AExpr>) -> bool {
@@ -69,6 +70,7 @@ struct GraphConversionContext<'a> {
     phys_to_graph: SecondaryMap<PhysNodeKey, GraphNodeKey>,
     expr_conversion_state: ExpressionConversionState,
     num_pipelines: usize,
+    runtime_join_filters: RuntimeJoinFilters,
 }
 
 pub fn physical_plan_to_graph(
@@ -78,6 +80,7 @@ pub fn physical_plan_to_graph(
 ) -> PolarsResult<(Graph, SecondaryMap<PhysNodeKey, GraphNodeKey>)> {
     // Get the number of threads from the rayon thread-pool as that respects our config.
     let num_pipelines = POOL.current_num_threads();
+    let runtime_join_filters = plan_runtime_join_filters(phys_sm, expr_arena);
     let mut ctx = GraphConversionContext {
         phys_sm,
         expr_arena,
@@ -85,6 +88,7 @@ pub fn physical_plan_to_graph(
         phys_to_graph: SecondaryMap::with_capacity(phys_sm.len()),
         expr_conversion_state: ExpressionConversionState::new(false),
         num_pipelines,
+        runtime_join_filters,
     };
 
     to_graph_rec(root, &mut ctx)?;
@@ -329,10 +333,11 @@ fn to_graph_rec<'a>(
                     [(input_key, input.port)],
                 ),
                 #[cfg(feature = "json")]
-                FileType::Json(_) => ctx.graph.add_node(
+                FileType::Json(json_writer_options) => ctx.graph.add_node(
                     SinkComputeNode::from(nodes::io_sinks::json::NDJsonSinkNode::new(
                         target.clone(),
                         sink_options,
+                        *json_writer_options,
                         cloud_options.clone(),
                     )),
                     [(input_key, input.port)],
@@ -360,11 +365,23 @@ fn to_graph_rec<'a>(
                     )),
                     [(input_key, input.port)],
                 ),
+                #[cfg(feature = "orc")]
+                FileType::Orc(orc_writer_options) => ctx.graph.add_node(
+                    SinkComputeNode::from(nodes::io_sinks::orc::OrcSinkNode::new(
+                        input_schema,
+                        target.clone(),
+                        sink_options,
+                        *orc_writer_options,
+                        cloud_options.clone(),
+                    )?),
+                    [(input_key, input.port)],
+                ),
                 #[cfg(not(any(
                     feature = "csv",
                     feature = "parquet",
                     feature = "json",
-                    feature = "ipc"
+                    feature = "ipc",
+                    feature = "orc"
                 )))]
                 _ => {
                     panic!("activate source feature")
@@ -486,7 +503,7 @@ fn to_graph_rec<'a>(
             )
         },
 
-        Map { input, map } => {
+        Map { input, map, .. } => {
             let input_key = to_graph_rec(input.node, ctx)?;
             ctx.graph.add_node(
                 nodes::map::MapNode::new(map.clone()),
@@ -663,6 +680,7 @@ fn to_graph_rec<'a>(
             forbid_extra_columns,
             deletion_files,
             table_statistics,
+            scan_metrics,
             file_schema,
         } => {
             let hive_parts = hive_parts.clone();
@@ -685,6 +703,15 @@ fn to_graph_rec<'a>(
                 .transpose()?
                 .map(|p| p.to_io(None, file_schema.clone()));
 
+            let mut predicate = match ctx.runtime_join_filters.scans.get(phys_node_key) {
+                Some(filter) => Some(filter.to_scan_predicate(file_schema.clone(), predicate)),
+                None => predicate,
+            };
+
+            if let Some(predicate) = predicate.as_mut() {
+                predicate.metrics = scan_metrics.clone();
+            }
+
             let sources = scan_sources.clone();
             let file_reader_builder = file_reader_builder.clone();
             let cloud_options = cloud_options.clone();
@@ -900,6 +927,13 @@ fn to_graph_rec<'a>(
             let unique_key_schema =
                 compute_output_schema(&right_input_schema, &unique_left_on, ctx.expr_arena)?;
 
+            let [left_runtime_filter, right_runtime_filter] = ctx
+                .runtime_join_filters
+                .joins
+                .get(phys_node_key)
+                .cloned()
+                .unwrap_or_default();
+
             match node.kind {
                 #[cfg(feature = "semi_anti_join")]
                 SemiAntiJoin { output_bool, .. } => ctx.graph.add_node(
@@ -909,6 +943,7 @@ fn to_graph_rec<'a>(
                         right_key_selectors,
                         args,
                         output_bool,
+                        left_runtime_filter,
                         ctx.num_pipelines,
                     )?,
                     [
@@ -926,6 +961,7 @@ fn to_graph_rec<'a>(
                         left_key_selectors,
                         right_key_selectors,
                         args,
+                        [left_runtime_filter, right_runtime_filter],
                         ctx.num_pipelines,
                     )?,
                     [
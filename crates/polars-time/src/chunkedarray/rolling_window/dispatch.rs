@@ -153,6 +153,36 @@ where
     Series::try_from((ca.name().clone(), out))
 }
 
+/// Prepare a Series for the rolling kernels that only compare values: booleans are represented as
+/// `UInt8` and temporal types by their physical type.
+#[cfg(any(feature = "rolling_window", feature = "rolling_window_by"))]
+fn to_comparable_physical(s: &Series, op: &str) -> PolarsResult<Series> {
+    let s = match s.dtype() {
+        DataType::Boolean => s.cast(&DataType::UInt8)?,
+        dt if dt.is_temporal() => s.to_physical_repr().into_owned(),
+        _ => s.clone(),
+    };
+    polars_ensure!(
+        s.dtype().is_primitive_numeric() && !s.dtype().is_unknown(),
+        op = op,
+        s.dtype()
+    );
+    Ok(s)
+}
+
+/// Cast a Series to the type the rolling product is computed in, like [`Series::product`] does.
+#[cfg(any(feature = "rolling_window", feature = "rolling_window_by"))]
+fn to_product_dtype(s: &Series, op: &str) -> PolarsResult<Series> {
+    use DataType::*;
+    match s.dtype() {
+        Boolean | Int8 | UInt8 | Int16 | UInt16 | Int32 | UInt32 => s.cast(&Int64),
+        Int64 | UInt64 | Float32 | Float64 => Ok(s.clone()),
+        #[cfg(feature = "dtype-i128")]
+        Int128 => Ok(s.clone()),
+        dt => polars_bail!(op = op, dt),
+    }
+}
+
 pub trait SeriesOpsTime: AsSeries {
     /// Apply a rolling mean to a Series based on another Series.
     #[cfg(feature = "rolling_window_by")]
@@ -532,6 +562,268 @@ pub trait SeriesOpsTime: AsSeries {
             s
         })
     }
+    /// Apply a rolling rank to a Series based on another Series.
+    ///
+    /// Gives the rank of every value among the values in its window, divided by the number of
+    /// values in the window. If the window doesn't contain the value's own row, the value is
+    /// ranked as if it did.
+    #[cfg(feature = "rolling_window_by")]
+    fn rolling_rank_by(
+        &self,
+        by: &Series,
+        options: RollingOptionsDynamicWindow,
+    ) -> PolarsResult<Series> {
+        let s = to_comparable_physical(self.as_series(), "rolling_rank_by")?;
+        with_match_physical_numeric_polars_type!(s.dtype(), |$T| {
+            let ca: &ChunkedArray<$T> = s.as_ref().as_ref().as_ref();
+            rolling_agg_by(
+                ca,
+                by,
+                options,
+                &super::rolling_kernels::no_nulls::rolling_rank,
+            )
+        })?
+        .cast(&DataType::Float64)
+    }
+
+    /// Apply a rolling rank to a Series.
+    ///
+    /// Gives the rank of the last value of every window among the non-null values in that window,
+    /// divided by the number of those values.
+    #[cfg(feature = "rolling_window")]
+    fn rolling_rank(&self, options: RollingOptionsFixedWindow) -> PolarsResult<Series> {
+        polars_ensure!(
+            options.weights.is_none(),
+            InvalidOperation: "weights are not supported by `rolling_rank`"
+        );
+        polars_ensure!(
+            !options.center,
+            InvalidOperation: "`rolling_rank` ranks the last value of every window and can't be centered"
+        );
+        let s = to_comparable_physical(self.as_series(), "rolling_rank")?;
+        with_match_physical_numeric_polars_type!(s.dtype(), |$T| {
+            let ca: &ChunkedArray<$T> = s.as_ref().as_ref().as_ref();
+            rolling_agg(
+                ca,
+                options,
+                &rolling::no_nulls::rolling_rank,
+                &rolling::nulls::rolling_rank,
+            )
+        })?
+        .cast(&DataType::Float64)
+    }
+
+    /// Apply a rolling count of distinct values to a Series based on another Series.
+    #[cfg(feature = "rolling_window_by")]
+    fn rolling_n_unique_by(
+        &self,
+        by: &Series,
+        options: RollingOptionsDynamicWindow,
+    ) -> PolarsResult<Series> {
+        let s = to_comparable_physical(self.as_series(), "rolling_n_unique_by")?;
+        with_match_physical_numeric_polars_type!(s.dtype(), |$T| {
+            let ca: &ChunkedArray<$T> = s.as_ref().as_ref().as_ref();
+            rolling_agg_by(
+                ca,
+                by,
+                options,
+                &super::rolling_kernels::no_nulls::rolling_n_unique,
+            )
+        })?
+        .cast(&IDX_DTYPE)
+    }
+
+    /// Apply a rolling count of distinct non-null values to a Series.
+    #[cfg(feature = "rolling_window")]
+    fn rolling_n_unique(&self, options: RollingOptionsFixedWindow) -> PolarsResult<Series> {
+        polars_ensure!(
+            options.weights.is_none(),
+            InvalidOperation: "weights are not supported by `rolling_n_unique`"
+        );
+        let s = to_comparable_physical(self.as_series(), "rolling_n_unique")?;
+        with_match_physical_numeric_polars_type!(s.dtype(), |$T| {
+            let ca: &ChunkedArray<$T> = s.as_ref().as_ref().as_ref();
+            rolling_agg(
+                ca,
+                options,
+                &rolling::no_nulls::rolling_n_unique,
+                &rolling::nulls::rolling_n_unique,
+            )
+        })?
+        .cast(&IDX_DTYPE)
+    }
+
+    /// Apply a rolling arg_min to a Series based on another Series.
+    ///
+    /// Gives the position of the minimum relative to the start of every window.
+    #[cfg(feature = "rolling_window_by")]
+    fn rolling_arg_min_by(
+        &self,
+        by: &Series,
+        options: RollingOptionsDynamicWindow,
+    ) -> PolarsResult<Series> {
+        let s = to_comparable_physical(self.as_series(), "rolling_arg_min_by")?;
+        with_match_physical_numeric_polars_type!(s.dtype(), |$T| {
+            let ca: &ChunkedArray<$T> = s.as_ref().as_ref().as_ref();
+            rolling_agg_by(
+                ca,
+                by,
+                options,
+                &super::rolling_kernels::no_nulls::rolling_arg_min,
+            )
+        })?
+        .cast(&IDX_DTYPE)
+    }
+
+    /// Apply a rolling arg_min to a Series.
+    ///
+    /// Gives the position of the minimum relative to the start of every window.
+    #[cfg(feature = "rolling_window")]
+    fn rolling_arg_min(&self, options: RollingOptionsFixedWindow) -> PolarsResult<Series> {
+        polars_ensure!(
+            options.weights.is_none(),
+            InvalidOperation: "weights are not supported by `rolling_arg_min`"
+        );
+        let s = to_comparable_physical(self.as_series(), "rolling_arg_min")?;
+        with_match_physical_numeric_polars_type!(s.dtype(), |$T| {
+            let ca: &ChunkedArray<$T> = s.as_ref().as_ref().as_ref();
+            rolling_agg(
+                ca,
+                options,
+                &rolling::no_nulls::rolling_arg_min,
+                &rolling::nulls::rolling_arg_min,
+            )
+        })?
+        .cast(&IDX_DTYPE)
+    }
+
+    /// Apply a rolling arg_max to a Series based on another Series.
+    ///
+    /// Gives the position of the maximum relative to the start of every window.
+    #[cfg(feature = "rolling_window_by")]
+    fn rolling_arg_max_by(
+        &self,
+        by: &Series,
+        options: RollingOptionsDynamicWindow,
+    ) -> PolarsResult<Series> {
+        let s = to_comparable_physical(self.as_series(), "rolling_arg_max_by")?;
+        with_match_physical_numeric_polars_type!(s.dtype(), |$T| {
+            let ca: &ChunkedArray<$T> = s.as_ref().as_ref().as_ref();
+            rolling_agg_by(
+                ca,
+                by,
+                options,
+                &super::rolling_kernels::no_nulls::rolling_arg_max,
+            )
+        })?
+        .cast(&IDX_DTYPE)
+    }
+
+    /// Apply a rolling arg_max to a Series.
+    ///
+    /// Gives the position of the maximum relative to the start of every window.
+    #[cfg(feature = "rolling_window")]
+    fn rolling_arg_max(&self, options: RollingOptionsFixedWindow) -> PolarsResult<Series> {
+        polars_ensure!(
+            options.weights.is_none(),
+            InvalidOperation: "weights are not supported by `rolling_arg_max`"
+        );
+        let s = to_comparable_physical(self.as_series(), "rolling_arg_max")?;
+        with_match_physical_numeric_polars_type!(s.dtype(), |$T| {
+            let ca: &ChunkedArray<$T> = s.as_ref().as_ref().as_ref();
+            rolling_agg(
+                ca,
+                options,
+                &rolling::no_nulls::rolling_arg_max,
+                &rolling::nulls::rolling_arg_max,
+            )
+        })?
+        .cast(&IDX_DTYPE)
+    }
+
+    /// Apply a rolling mode to a Series based on another Series.
+    ///
+    /// Ties resolve to the smallest value.
+    #[cfg(feature = "rolling_window_by")]
+    fn rolling_mode_by(
+        &self,
+        by: &Series,
+        options: RollingOptionsDynamicWindow,
+    ) -> PolarsResult<Series> {
+        let dt = self.as_series().dtype();
+        let s = to_comparable_physical(self.as_series(), "rolling_mode_by")?;
+        with_match_physical_numeric_polars_type!(s.dtype(), |$T| {
+            let ca: &ChunkedArray<$T> = s.as_ref().as_ref().as_ref();
+            rolling_agg_by(
+                ca,
+                by,
+                options,
+                &super::rolling_kernels::no_nulls::rolling_mode,
+            )
+        })?
+        .cast(dt)
+    }
+
+    /// Apply a rolling mode to a Series.
+    ///
+    /// Ties resolve to the smallest value.
+    #[cfg(feature = "rolling_window")]
+    fn rolling_mode(&self, options: RollingOptionsFixedWindow) -> PolarsResult<Series> {
+        polars_ensure!(
+            options.weights.is_none(),
+            InvalidOperation: "weights are not supported by `rolling_mode`"
+        );
+        let dt = self.as_series().dtype();
+        let s = to_comparable_physical(self.as_series(), "rolling_mode")?;
+        with_match_physical_numeric_polars_type!(s.dtype(), |$T| {
+            let ca: &ChunkedArray<$T> = s.as_ref().as_ref().as_ref();
+            rolling_agg(
+                ca,
+                options,
+                &rolling::no_nulls::rolling_mode,
+                &rolling::nulls::rolling_mode,
+            )
+        })?
+        .cast(dt)
+    }
+
+    /// Apply a rolling product to a Series based on another Series.
+    #[cfg(feature = "rolling_window_by")]
+    fn rolling_product_by(
+        &self,
+        by: &Series,
+        options: RollingOptionsDynamicWindow,
+    ) -> PolarsResult<Series> {
+        let s = to_product_dtype(self.as_series(), "rolling_product_by")?;
+        with_match_physical_numeric_polars_type!(s.dtype(), |$T| {
+            let ca: &ChunkedArray<$T> = s.as_ref().as_ref().as_ref();
+            rolling_agg_by(
+                ca,
+                by,
+                options,
+                &super::rolling_kernels::no_nulls::rolling_product,
+            )
+        })
+    }
+
+    /// Apply a rolling product to a Series.
+    #[cfg(feature = "rolling_window")]
+    fn rolling_product(&self, options: RollingOptionsFixedWindow) -> PolarsResult<Series> {
+        polars_ensure!(
+            options.weights.is_none(),
+            InvalidOperation: "weights are not supported by `rolling_product`"
+        );
+        let s = to_product_dtype(self.as_series(), "rolling_product")?;
+        with_match_physical_numeric_polars_type!(s.dtype(), |$T| {
+            let ca: &ChunkedArray<$T> = s.as_ref().as_ref().as_ref();
+            rolling_agg(
+                ca,
+                options,
+                &rolling::no_nulls::rolling_product,
+                &rolling::nulls::rolling_product,
+            )
+        })
+    }
 }
 
 impl SeriesOpsTime for Series {}
@@ -4,17 +4,19 @@ use arrow::bitmap::MutableBitmap;
 use bytemuck::allocation::zeroed_vec;
 #[cfg(feature = "timezones")]
 use chrono_tz::Tz;
-use num_traits::{FromPrimitive, ToPrimitive};
+use num_traits::{FromPrimitive, One, ToPrimitive};
+use polars_compute::arithmetic::pl_num::PlNumArithmetic;
 use polars_compute::rolling::RollingFnParams;
 use polars_compute::rolling::no_nulls::{self, RollingAggWindowNoNulls};
 use polars_compute::rolling::nulls::VarianceMoment;
 use polars_compute::rolling::quantile_filter::SealedRolling;
+use polars_utils::total_ord::{TotalEq, TotalHash, TotalOrd};
 
 use super::*;
 
 // Use an aggregation window that maintains the state.
 // Fastpath if values were known to already be sorted by time.
-pub(crate) fn rolling_apply_agg_window_sorted<'a, Agg, T, O>(
+pub(crate) fn rolling_apply_agg_window_sorted<'a, Agg, T, Out, O>(
     values: &'a [T],
     offsets: O,
     min_periods: usize,
@@ -22,14 +24,15 @@ pub(crate) fn rolling_apply_agg_window_sorted<'a, Agg, T, O>(
 ) -> PolarsResult<ArrayRef>
 where
     // items (offset, len) -> so offsets are offset, offset + len
-    Agg: RollingAggWindowNoNulls<'a, T>,
+    Agg: RollingAggWindowNoNulls<'a, T, Out>,
     O: Iterator<Item = PolarsResult<(IdxSize, IdxSize)>> + TrustedLen,
     T: Debug + IsFloat + NativeType,
+    Out: NativeType,
 {
     if values.is_empty() {
-        let out: Vec<T> = vec![];
+        let out: Vec<Out> = vec![];
         return Ok(Box::new(PrimitiveArray::new(
-            T::PRIMITIVE.into(),
+            Out::PRIMITIVE.into(),
             out.into(),
             None,
         )));
@@ -54,7 +57,7 @@ where
                 }
             })
         })
-        .collect::<PolarsResult<PrimitiveArray<T>>>()?;
+        .collect::<PolarsResult<PrimitiveArray<Out>>>()?;
 
     Ok(Box::new(out))
 }
@@ -75,7 +78,7 @@ fn instantiate_bitmap_if_null_and_set_false_at_idx(
 }
 
 // Use an aggregation window that maintains the state
-pub(crate) fn rolling_apply_agg_window<'a, Agg, T, O>(
+pub(crate) fn rolling_apply_agg_window<'a, Agg, T, Out, O>(
     values: &'a [T],
     offsets: O,
     min_periods: usize,
@@ -84,14 +87,15 @@ pub(crate) fn rolling_apply_agg_window<'a, Agg, T, O>(
 ) -> PolarsResult<ArrayRef>
 where
     // items (offset, len) -> so offsets are offset, offset + len
-    Agg: RollingAggWindowNoNulls<'a, T>,
+    Agg: RollingAggWindowNoNulls<'a, T, Out>,
     O: Iterator<Item = PolarsResult<(IdxSize, IdxSize)>> + TrustedLen,
     T: Debug + IsFloat + NativeType,
+    Out: NativeType,
 {
     if values.is_empty() {
-        let out: Vec<T> = vec![];
+        let out: Vec<Out> = vec![];
         return Ok(Box::new(PrimitiveArray::new(
-            T::PRIMITIVE.into(),
+            Out::PRIMITIVE.into(),
             out.into(),
             None,
         )));
@@ -136,7 +140,7 @@ where
         Ok::<(), PolarsError>(())
     })?;
 
-    let out = PrimitiveArray::<T>::from_vec(out).with_validity(validity.map(|x| x.into()));
+    let out = PrimitiveArray::<Out>::from_vec(out).with_validity(validity.map(|x| x.into()));
 
     Ok(Box::new(out))
 }
@@ -162,14 +166,14 @@ where
         _ => group_by_values_iter(period, time, closed_window, tu, None),
     }?;
     if sorting_indices.is_none() {
-        rolling_apply_agg_window_sorted::<no_nulls::MinWindow<_>, _, _>(
+        rolling_apply_agg_window_sorted::<no_nulls::MinWindow<_>, _, _, _>(
             values,
             offset_iter,
             min_periods,
             None,
         )
     } else {
-        rolling_apply_agg_window::<no_nulls::MinWindow<_>, _, _>(
+        rolling_apply_agg_window::<no_nulls::MinWindow<_>, _, _, _>(
             values,
             offset_iter,
             min_periods,
@@ -200,14 +204,14 @@ where
         _ => group_by_values_iter(period, time, closed_window, tu, None),
     }?;
     if sorting_indices.is_none() {
-        rolling_apply_agg_window_sorted::<no_nulls::MaxWindow<_>, _, _>(
+        rolling_apply_agg_window_sorted::<no_nulls::MaxWindow<_>, _, _, _>(
             values,
             offset_iter,
             min_periods,
             None,
         )
     } else {
-        rolling_apply_agg_window::<no_nulls::MaxWindow<_>, _, _>(
+        rolling_apply_agg_window::<no_nulls::MaxWindow<_>, _, _, _>(
             values,
             offset_iter,
             min_periods,
@@ -247,14 +251,14 @@ where
         _ => group_by_values_iter(period, time, closed_window, tu, None),
     }?;
     if sorting_indices.is_none() {
-        rolling_apply_agg_window_sorted::<no_nulls::SumWindow<T, T>, _, _>(
+        rolling_apply_agg_window_sorted::<no_nulls::SumWindow<T, T>, _, _, _>(
             values,
             offset_iter,
             min_periods,
             None,
         )
     } else {
-        rolling_apply_agg_window::<no_nulls::SumWindow<T, T>, _, _>(
+        rolling_apply_agg_window::<no_nulls::SumWindow<T, T>, _, _, _>(
             values,
             offset_iter,
             min_periods,
@@ -285,14 +289,14 @@ where
         _ => group_by_values_iter(period, time, closed_window, tu, None),
     }?;
     if sorting_indices.is_none() {
-        rolling_apply_agg_window_sorted::<no_nulls::MeanWindow<_>, _, _>(
+        rolling_apply_agg_window_sorted::<no_nulls::MeanWindow<_>, _, _, _>(
             values,
             offset_iter,
             min_periods,
             None,
         )
     } else {
-        rolling_apply_agg_window::<no_nulls::MeanWindow<_>, _, _>(
+        rolling_apply_agg_window::<no_nulls::MeanWindow<_>, _, _, _>(
             values,
             offset_iter,
             min_periods,
@@ -323,14 +327,14 @@ where
         _ => group_by_values_iter(period, time, closed_window, tu, None),
     }?;
     if sorting_indices.is_none() {
-        rolling_apply_agg_window_sorted::<no_nulls::MomentWindow<_, VarianceMoment>, _, _>(
+        rolling_apply_agg_window_sorted::<no_nulls::MomentWindow<_, VarianceMoment>, _, _, _>(
             values,
             offset_iter,
             min_periods,
             params,
         )
     } else {
-        rolling_apply_agg_window::<no_nulls::MomentWindow<_, VarianceMoment>, _, _>(
+        rolling_apply_agg_window::<no_nulls::MomentWindow<_, VarianceMoment>, _, _, _>(
             values,
             offset_iter,
             min_periods,
@@ -361,14 +365,14 @@ where
         _ => group_by_values_iter(period, time, closed_window, tu, None),
     }?;
     if sorting_indices.is_none() {
-        rolling_apply_agg_window_sorted::<no_nulls::QuantileWindow<_>, _, _>(
+        rolling_apply_agg_window_sorted::<no_nulls::QuantileWindow<_>, _, _, _>(
             values,
             offset_iter,
             min_periods,
             params,
         )
     } else {
-        rolling_apply_agg_window::<no_nulls::QuantileWindow<_>, _, _>(
+        rolling_apply_agg_window::<no_nulls::QuantileWindow<_>, _, _, _>(
             values,
             offset_iter,
             min_periods,
@@ -377,3 +381,231 @@ where
         )
     }
 }
+
+/// Apply an aggregation window over the periods of `time`.
+#[allow(clippy::too_many_arguments)]
+fn rolling_agg_window_by<'a, Agg, T, Out>(
+    values: &'a [T],
+    period: Duration,
+    time: &[i64],
+    closed_window: ClosedWindow,
+    min_periods: usize,
+    tu: TimeUnit,
+    tz: Option<&TimeZone>,
+    params: Option<RollingFnParams>,
+    sorting_indices: Option<&[IdxSize]>,
+) -> PolarsResult<ArrayRef>
+where
+    Agg: RollingAggWindowNoNulls<'a, T, Out>,
+    T: Debug + IsFloat + NativeType,
+    Out: NativeType,
+{
+    let offset_iter = match tz {
+        #[cfg(feature = "timezones")]
+        Some(tz) => group_by_values_iter(period, time, closed_window, tu, tz.parse::<Tz>().ok()),
+        _ => group_by_values_iter(period, time, closed_window, tu, None),
+    }?;
+    if sorting_indices.is_none() {
+        rolling_apply_agg_window_sorted::<Agg, _, _, _>(values, offset_iter, min_periods, params)
+    } else {
+        rolling_apply_agg_window::<Agg, _, _, _>(
+            values,
+            offset_iter,
+            min_periods,
+            params,
+            sorting_indices,
+        )
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn rolling_rank<T>(
+    values: &[T],
+    period: Duration,
+    time: &[i64],
+    closed_window: ClosedWindow,
+    min_periods: usize,
+    tu: TimeUnit,
+    tz: Option<&TimeZone>,
+    params: Option<RollingFnParams>,
+    sorting_indices: Option<&[IdxSize]>,
+) -> PolarsResult<ArrayRef>
+where
+    T: NativeType + TotalOrd + IsFloat,
+{
+    // Windows can hold several rows with the same time, or none of the rows they belong to, so
+    // every row ranks its own value rather than the last value of its window.
+    let offset_iter = match tz {
+        #[cfg(feature = "timezones")]
+        Some(tz) => group_by_values_iter(period, time, closed_window, tu, tz.parse::<Tz>().ok()),
+        _ => group_by_values_iter(period, time, closed_window, tu, None),
+    }?;
+    let mut agg_window = no_nulls::RankWindow::new(values, 0, 0, params, None);
+
+    let mut out = zeroed_vec(values.len());
+    let mut validity: Option<MutableBitmap> = None;
+    offset_iter.enumerate().try_for_each(|(idx, result)| {
+        let (start, len) = result?;
+        let end = start + len;
+        let out_idx = sorting_indices.map_or(idx, |indices| indices[idx] as usize);
+
+        let res = if len >= (min_periods as IdxSize) {
+            // SAFETY: the window and `idx` are in bounds.
+            unsafe { agg_window.update_rank(start as usize, end as usize, idx) }
+        } else {
+            None
+        };
+        match res {
+            Some(res) => out[out_idx] = res,
+            None => instantiate_bitmap_if_null_and_set_false_at_idx(
+                &mut validity,
+                values.len(),
+                out_idx,
+            ),
+        }
+        Ok::<(), PolarsError>(())
+    })?;
+
+    let out = PrimitiveArray::<f64>::from_vec(out).with_validity(validity.map(|x| x.into()));
+    Ok(Box::new(out))
+}
+
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn rolling_n_unique<T>(
+    values: &[T],
+    period: Duration,
+    time: &[i64],
+    closed_window: ClosedWindow,
+    min_periods: usize,
+    tu: TimeUnit,
+    tz: Option<&TimeZone>,
+    params: Option<RollingFnParams>,
+    sorting_indices: Option<&[IdxSize]>,
+) -> PolarsResult<ArrayRef>
+where
+    T: NativeType + TotalEq + TotalHash + IsFloat,
+{
+    rolling_agg_window_by::<no_nulls::NUniqueWindow<_>, _, _>(
+        values,
+        period,
+        time,
+        closed_window,
+        min_periods,
+        tu,
+        tz,
+        params,
+        sorting_indices,
+    )
+}
+
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn rolling_arg_min<T>(
+    values: &[T],
+    period: Duration,
+    time: &[i64],
+    closed_window: ClosedWindow,
+    min_periods: usize,
+    tu: TimeUnit,
+    tz: Option<&TimeZone>,
+    params: Option<RollingFnParams>,
+    sorting_indices: Option<&[IdxSize]>,
+) -> PolarsResult<ArrayRef>
+where
+    T: NativeType + IsFloat,
+{
+    rolling_agg_window_by::<no_nulls::ArgMinWindow<_>, _, _>(
+        values,
+        period,
+        time,
+        closed_window,
+        min_periods,
+        tu,
+        tz,
+        params,
+        sorting_indices,
+    )
+}
+
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn rolling_arg_max<T>(
+    values: &[T],
+    period: Duration,
+    time: &[i64],
+    closed_window: ClosedWindow,
+    min_periods: usize,
+    tu: TimeUnit,
+    tz: Option<&TimeZone>,
+    params: Option<RollingFnParams>,
+    sorting_indices: Option<&[IdxSize]>,
+) -> PolarsResult<ArrayRef>
+where
+    T: NativeType + IsFloat,
+{
+    rolling_agg_window_by::<no_nulls::ArgMaxWindow<_>, _, _>(
+        values,
+        period,
+        time,
+        closed_window,
+        min_periods,
+        tu,
+        tz,
+        params,
+        sorting_indices,
+    )
+}
+
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn rolling_mode<T>(
+    values: &[T],
+    period: Duration,
+    time: &[i64],
+    closed_window: ClosedWindow,
+    min_periods: usize,
+    tu: TimeUnit,
+    tz: Option<&TimeZone>,
+    params: Option<RollingFnParams>,
+    sorting_indices: Option<&[IdxSize]>,
+) -> PolarsResult<ArrayRef>
+where
+    T: NativeType + TotalOrd + TotalHash + IsFloat,
+{
+    rolling_agg_window_by::<no_nulls::ModeWindow<_>, _, _>(
+        values,
+        period,
+        time,
+        closed_window,
+        min_periods,
+        tu,
+        tz,
+        params,
+        sorting_indices,
+    )
+}
+
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn rolling_product<T>(
+    values: &[T],
+    period: Duration,
+    time: &[i64],
+    closed_window: ClosedWindow,
+    min_periods: usize,
+    tu: TimeUnit,
+    tz: Option<&TimeZone>,
+    params: Option<RollingFnParams>,
+    sorting_indices: Option<&[IdxSize]>,
+) -> PolarsResult<ArrayRef>
+where
+    T: NativeType + One + PlNumArithmetic + IsFloat,
+{
+    rolling_agg_window_by::<no_nulls::ProductWindow<_>, _, _>(
+        values,
+        period,
+        time,
+        closed_window,
+        min_periods,
+        tu,
+        tz,
+        params,
+        sorting_indices,
+    )
+}
@@ -1,5 +1,7 @@
 use arrow::legacy::time_zone::Tz;
 use polars_core::POOL;
+#[cfg(feature = "timezones")]
+use polars_core::datatypes::time_zone::parse_time_zone;
 use polars_core::prelude::*;
 use polars_core::series::IsSorted;
 use polars_core::utils::flatten::flatten_par;
@@ -74,6 +76,34 @@ impl Default for RollingGroupOptions {
     }
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub struct SessionGroupOptions {
+    /// Time or index column.
+    pub index_column: PlSmallStr,
+    /// Start a new session after this much inactivity.
+    pub gap: Duration,
+    /// Label a session with its start, its end or its first data point.
+    pub label: Label,
+    /// Add the session boundaries to the DataFrame.
+    pub include_boundaries: bool,
+    /// Whether a value exactly `gap` after the previous value continues the session.
+    pub closed_window: ClosedWindow,
+}
+
+impl Default for SessionGroupOptions {
+    fn default() -> Self {
+        Self {
+            index_column: "".into(),
+            gap: Duration::new(1),
+            label: Label::Left,
+            include_boundaries: false,
+            closed_window: ClosedWindow::Left,
+        }
+    }
+}
+
 fn check_sortedness_slice(v: &[i64]) -> PolarsResult<()> {
     polars_ensure!(v.is_sorted_ascending(), ComputeError: "input data is not sorted");
     Ok(())
@@ -94,6 +124,12 @@ pub trait PolarsTemporalGroupby {
         group_by: Option<GroupsSlice>,
         options: &DynamicGroupOptions,
     ) -> PolarsResult<(Column, Vec<Column>, GroupPositions)>;
+
+    fn group_by_session(
+        &self,
+        group_by: Option<GroupsSlice>,
+        options: &SessionGroupOptions,
+    ) -> PolarsResult<(Column, Vec<Column>, GroupPositions)>;
 }
 
 impl PolarsTemporalGroupby for DataFrame {
@@ -112,6 +148,14 @@ impl PolarsTemporalGroupby for DataFrame {
     ) -> PolarsResult<(Column, Vec<Column>, GroupPositions)> {
         Wrap(self).group_by_dynamic(group_by, options)
     }
+
+    fn group_by_session(
+        &self,
+        group_by: Option<GroupsSlice>,
+        options: &SessionGroupOptions,
+    ) -> PolarsResult<(Column, Vec<Column>, GroupPositions)> {
+        Wrap(self).group_by_session(group_by, options)
+    }
 }
 
 impl Wrap<&DataFrame> {
@@ -207,6 +251,93 @@ impl Wrap<&DataFrame> {
         ensure_duration_matches_dtype(options.every, time_type, "every")?;
         ensure_duration_matches_dtype(options.offset, time_type, "offset")?;
         ensure_duration_matches_dtype(options.period, time_type, "period")?;
+        polars_ensure!(!options.every.negative, ComputeError: "'every' argument must be positive");
+
+        let w = Window::new(options.every, options.period, options.offset);
+        self.group_by_temporal_windows(
+            time,
+            group_by,
+            options.label,
+            options.include_boundaries,
+            |ts, tu, tz, include_lower_bound, include_upper_bound| {
+                group_by_windows(
+                    w,
+                    ts,
+                    options.closed_window,
+                    tu,
+                    tz,
+                    include_lower_bound,
+                    include_upper_bound,
+                    options.start_by,
+                )
+            },
+        )
+    }
+
+    /// Returns: time_keys, keys, groupsproxy.
+    fn group_by_session(
+        &self,
+        group_by: Option<GroupsSlice>,
+        options: &SessionGroupOptions,
+    ) -> PolarsResult<(Column, Vec<Column>, GroupPositions)> {
+        polars_ensure!(
+            !options.gap.is_zero() && !options.gap.negative,
+            ComputeError: "session gap should be strictly positive",
+        );
+        let time = self.0.column(&options.index_column)?.rechunk();
+        if group_by.is_none() {
+            time.as_materialized_series()
+                .ensure_sorted_arg("group_by_session")?;
+        }
+        let time_type = time.dtype();
+
+        polars_ensure!(time.null_count() == 0, ComputeError: "null values in session group_by not supported, fill nulls.");
+        ensure_duration_matches_dtype(options.gap, time_type, "gap")?;
+
+        self.group_by_temporal_windows(
+            time,
+            group_by,
+            options.label,
+            options.include_boundaries,
+            |ts, tu, tz, include_lower_bound, include_upper_bound| {
+                let tz = match tz {
+                    #[cfg(feature = "timezones")]
+                    Some(tz) => Some(parse_time_zone(tz)?),
+                    _ => None,
+                };
+                group_by_sessions(
+                    options.gap,
+                    ts,
+                    options.closed_window,
+                    tu,
+                    tz,
+                    include_lower_bound,
+                    include_upper_bound,
+                )
+            },
+        )
+    }
+
+    /// Dispatch the index column to a datetime column and group it with the `windows` kernel.
+    fn group_by_temporal_windows<F>(
+        &self,
+        time: Column,
+        group_by: Option<GroupsSlice>,
+        label: Label,
+        include_boundaries: bool,
+        windows: F,
+    ) -> PolarsResult<(Column, Vec<Column>, GroupPositions)>
+    where
+        F: Fn(
+                &[i64],
+                TimeUnit,
+                &Option<TimeZone>,
+                bool,
+                bool,
+            ) -> PolarsResult<(GroupsSlice, Vec<i64>, Vec<i64>)>
+            + Sync,
+    {
+        let time_type = time.dtype();
 
         use DataType::*;
         let (dt, tu) = match time_type {
@@ -218,12 +349,14 @@ impl Wrap<&DataFrame> {
             Int32 => {
                 let time_type = Datetime(TimeUnit::Nanoseconds, None);
                 let dt = time.cast(&Int64).unwrap().cast(&time_type).unwrap();
-                let (out, mut keys, gt) = self.impl_group_by_dynamic(
+                let (out, mut keys, gt) = self.impl_group_by_windows(
                     dt,
                     group_by,
-                    options,
+                    label,
+                    include_boundaries,
                     TimeUnit::Nanoseconds,
                     &time_type,
+                    windows,
                 )?;
                 let out = out.cast(&Int64).unwrap().cast(&Int32).unwrap();
                 for k in &mut keys {
@@ -236,12 +369,14 @@ impl Wrap<&DataFrame> {
             Int64 => {
                 let time_type = Datetime(TimeUnit::Nanoseconds, None);
                 let dt = time.cast(&time_type).unwrap();
-                let (out, mut keys, gt) = self.impl_group_by_dynamic(
+                let (out, mut keys, gt) = self.impl_group_by_windows(
                     dt,
                     group_by,
-                    options,
+                    label,
+                    include_boundaries,
                     TimeUnit::Nanoseconds,
                     &time_type,
+                    windows,
                 )?;
                 let out = out.cast(&Int64).unwrap();
                 for k in &mut keys {
@@ -257,18 +392,38 @@ impl Wrap<&DataFrame> {
                 dt
             ),
         };
-        self.impl_group_by_dynamic(dt, group_by, options, tu, time_type)
+        self.impl_group_by_windows(
+            dt,
+            group_by,
+            label,
+            include_boundaries,
+            tu,
+            time_type,
+            windows,
+        )
     }
 
-    fn impl_group_by_dynamic(
+    #[allow(clippy::too_many_arguments)]
+    fn impl_group_by_windows<F>(
         &self,
         mut dt: Column,
         group_by: Option<GroupsSlice>,
-        options: &DynamicGroupOptions,
+        label: Label,
+        include_boundaries: bool,
         tu: TimeUnit,
         time_type: &DataType,
-    ) -> PolarsResult<(Column, Vec<Column>, GroupPositions)> {
-        polars_ensure!(!options.every.negative, ComputeError: "'every' argument must be positive");
+        windows: F,
+    ) -> PolarsResult<(Column, Vec<Column>, GroupPositions)>
+    where
+        F: Fn(
+                &[i64],
+                TimeUnit,
+                &Option<TimeZone>,
+                bool,
+                bool,
+            ) -> PolarsResult<(GroupsSlice, Vec<i64>, Vec<i64>)>
+            + Sync,
+    {
         if dt.is_empty() {
             return dt.cast(time_type).map(|s| (s, vec![], Default::default()));
         }
@@ -276,7 +431,6 @@ impl Wrap<&DataFrame> {
         // A requirement for the index so we can set this such that downstream code has this info.
         dt.set_sorted_flag(IsSorted::Ascending);
 
-        let w = Window::new(options.every, options.period, options.offset);
         let dt = dt.datetime().unwrap();
         let tz = dt.time_zone();
 
@@ -286,13 +440,13 @@ impl Wrap<&DataFrame> {
         let mut include_lower_bound = false;
         let mut include_upper_bound = false;
 
-        if options.include_boundaries {
+        if include_boundaries {
             include_lower_bound = true;
             include_upper_bound = true;
         }
-        if options.label == Label::Left {
+        if label == Label::Left {
             include_lower_bound = true;
-        } else if options.label == Label::Right {
+        } else if label == Label::Right {
             include_upper_bound = true;
         }
 
@@ -312,16 +466,8 @@ impl Wrap<&DataFrame> {
         let groups = if group_by.is_none() {
             let vals = dt.physical().downcast_iter().next().unwrap();
             let ts = vals.values().as_slice();
-            let (groups, lower, upper) = group_by_windows(
-                w,
-                ts,
-                options.closed_window,
-                tu,
-                tz,
-                include_lower_bound,
-                include_upper_bound,
-                options.start_by,
-            )?;
+            let (groups, lower, upper) =
+                windows(ts, tu, tz, include_lower_bound, include_upper_bound)?;
             update_bounds(lower, upper);
             PolarsResult::Ok(GroupsType::Slice {
                 groups,
@@ -340,16 +486,8 @@ impl Wrap<&DataFrame> {
                 let values = &ts[start..end];
                 check_sortedness_slice(values)?;
 
-                let (groups, lower, upper) = group_by_windows(
-                    w,
-                    values,
-                    options.closed_window,
-                    tu,
-                    tz,
-                    include_lower_bound,
-                    include_upper_bound,
-                    options.start_by,
-                )?;
+                let (groups, lower, upper) =
+                    windows(values, tu, tz, include_lower_bound, include_upper_bound)?;
 
                 PolarsResult::Ok((
                     groups
@@ -390,13 +528,13 @@ impl Wrap<&DataFrame> {
         let upper =
             upper_bound.map(|upper| Int64Chunked::new_vec(PlSmallStr::from_static(UP_NAME), upper));
 
-        if options.label == Label::Left {
+        if label == Label::Left {
             let mut lower = lower.clone().unwrap();
             if group_by.is_none() {
                 lower.set_sorted_flag(IsSorted::Ascending)
             }
             dt = lower.with_name(dt.name().clone());
-        } else if options.label == Label::Right {
+        } else if label == Label::Right {
             let mut upper = upper.clone().unwrap();
             if group_by.is_none() {
                 upper.set_sorted_flag(IsSorted::Ascending)
@@ -405,8 +543,7 @@ impl Wrap<&DataFrame> {
         }
 
         let mut bounds = vec![];
-        if let (true, Some(mut lower), Some(mut upper)) = (options.include_boundaries, lower, upper)
-        {
+        if let (true, Some(mut lower), Some(mut upper)) = (include_boundaries, lower, upper) {
             if group_by.is_none() {
                 lower.set_sorted_flag(IsSorted::Ascending);
                 upper.set_sorted_flag(IsSorted::Ascending);
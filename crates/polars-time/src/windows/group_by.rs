@@ -219,6 +219,65 @@ pub fn group_by_windows(
     Ok((groups, lower_bound, upper_bound))
 }
 
+/// Split the sorted `time` values in sessions. A session ends when the next value is more than
+/// `gap` after the previous one, so every session starts at its first value and ends `gap` after
+/// its last value. A value exactly `gap` after the previous one only continues the session if the
+/// windows are closed on the right.
+///
+/// Returns the groups and the `lower` and `upper` bounds of every session. If the bounds are not
+/// included those vectors will be empty.
+pub fn group_by_sessions(
+    gap: Duration,
+    time: &[i64],
+    closed_window: ClosedWindow,
+    tu: TimeUnit,
+    tz: Option<Tz>,
+    include_lower_bound: bool,
+    include_upper_bound: bool,
+) -> PolarsResult<(GroupsSlice, Vec<i64>, Vec<i64>)> {
+    let add = match tu {
+        TimeUnit::Nanoseconds => Duration::add_ns,
+        TimeUnit::Microseconds => Duration::add_us,
+        TimeUnit::Milliseconds => Duration::add_ms,
+    };
+
+    let mut groups = vec![];
+    let mut lower_bound = vec![];
+    let mut upper_bound = vec![];
+    let Some(&first) = time.first() else {
+        return Ok((groups, lower_bound, upper_bound));
+    };
+
+    let mut push_session = |start: usize, end: usize, lower: i64, upper: i64| {
+        groups.push([start as IdxSize, (end - start) as IdxSize]);
+        if include_lower_bound {
+            lower_bound.push(lower);
+        }
+        if include_upper_bound {
+            upper_bound.push(upper);
+        }
+    };
+
+    let mut start = 0;
+    let mut lower = first;
+    let mut upper = add(&gap, first, tz.as_ref())?;
+    for (i, &t) in time.iter().enumerate().skip(1) {
+        let in_session = match closed_window {
+            ClosedWindow::Right | ClosedWindow::Both => t <= upper,
+            ClosedWindow::Left | ClosedWindow::None => t < upper,
+        };
+        if !in_session {
+            push_session(start, i, lower, upper);
+            start = i;
+            lower = t;
+        }
+        upper = add(&gap, t, tz.as_ref())?;
+    }
+    push_session(start, time.len(), lower, upper);
+
+    Ok((groups, lower_bound, upper_bound))
+}
+
 // t is right at the end of the window
 // ------t---
 // [------]
@@ -942,3 +942,38 @@ fn test_group_by_windows_offsets_3776() {
     .unwrap();
     assert_eq!(groups, [[0, 1], [1, 1], [2, 1]]);
 }
+
+#[test]
+fn test_group_by_sessions() {
+    let dates = [0, 1, 3, 6, 6, 7, 10];
+    let gap = Duration::parse("3ms");
+
+    let (groups, lower, upper) = group_by_sessions(
+        gap,
+        &dates,
+        ClosedWindow::Left,
+        TimeUnit::Milliseconds,
+        None,
+        true,
+        true,
+    )
+    .unwrap();
+    assert_eq!(groups, [[0, 3], [3, 3], [6, 1]]);
+    assert_eq!(lower, [0, 6, 10]);
+    assert_eq!(upper, [6, 10, 13]);
+
+    // A value exactly `gap` after the previous one continues the session.
+    let (groups, lower, upper) = group_by_sessions(
+        gap,
+        &dates,
+        ClosedWindow::Both,
+        TimeUnit::Milliseconds,
+        None,
+        false,
+        true,
+    )
+    .unwrap();
+    assert_eq!(groups, [[0, 7]]);
+    assert!(lower.is_empty());
+    assert_eq!(upper, [13]);
+}
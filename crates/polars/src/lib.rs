@@ -203,11 +203,17 @@
 //!     - `parquet` - Read Apache Parquet format
 //!     - `json` - JSON serialization
 //!     - `ipc` - Arrow's IPC format serialization
+//!     - `fwf` - Read fixed-width text files
+//!     - `orc` - Read and write Apache ORC files
 //!     - `decompress` - Automatically infer compression of csvs and decompress them.
 //!       Supported compressions:
 //!          - gzip
 //!          - zlib
 //!          - zstd
+//!          - bzip2
+//!          - xz
+//!          - lz4 (frame format)
+//!     - `compress` - Compress the output of the CSV and NDJSON sinks.
 //!
 //! [`StringChunked`]: crate::datatypes::StringChunked
 //! [column selection]: polars_lazy::dsl::col
@@ -271,3 +271,152 @@ fn test_pivot_datetime() -> PolarsResult<()> {
 
     Ok(())
 }
+
+#[test]
+#[cfg(feature = "lazy")]
+fn test_lazy_pivot() -> PolarsResult<()> {
+    let df = df![
+        "index" => ["a", "b", "a", "b", "c", "a"],
+        "on" => ["x", "y", "y", "x", "z", "x"],
+        "values" => [1, 2, 3, 4, 5, 6],
+        "values_2" => [1.0, 2.0, 3.0, 4.0, 5.0, 6.0],
+    ]?;
+
+    let eager = pivot_stable(
+        &df,
+        ["on"],
+        Some(["index"]),
+        Some(["values"]),
+        false,
+        Some(PivotAgg(Arc::new(PivotExpr::from_expr(col("").sum())))),
+        None,
+    )?;
+
+    for engine in [Engine::InMemory, Engine::Streaming] {
+        let out = df
+            .clone()
+            .lazy()
+            .pivot(
+                "on",
+                Some(Series::new("".into(), ["x", "y", "z"])),
+                Some(["index"]),
+                Some(["values"]),
+                Some(col("").sum()),
+                None,
+            )?
+            .collect_with_engine(engine)?;
+        assert!(out.equals_missing(&eager));
+    }
+
+    // The output schema is known without materializing and undeclared values are ignored.
+    let mut lf = df.clone().lazy().pivot(
+        "on",
+        Some(Series::new("".into(), ["y", "w"])),
+        Some(["index"]),
+        None::<[&str; 0]>,
+        None,
+        Some("|"),
+    )?;
+    let schema = lf.collect_schema()?;
+    assert_eq!(
+        schema.iter_names().cloned().collect::<Vec<_>>(),
+        ["index", "values|y", "values|w", "values_2|y", "values_2|w"]
+    );
+    let out = lf.filter(col("index").neq(lit("c"))).collect()?;
+    let expected = df![
+        "index" => ["a", "b"],
+        "values|y" => [Some(3), Some(2)],
+        "values|w" => [None::<i32>, None],
+        "values_2|y" => [Some(3.0), Some(2.0)],
+        "values_2|w" => [None::<f64>, None],
+    ]?;
+    assert!(out.equals_missing(&expected));
+
+    // Undeclared `on` values require an Enum column.
+    assert!(
+        df.clone()
+            .lazy()
+            .pivot("on", None, Some(["index"]), Some(["values"]), None, None)
+            .is_err()
+    );
+
+    // Without an aggregation, a cell with several values is an error as in the eager pivot.
+    let eager = pivot_stable(
+        &df,
+        ["on"],
+        Some(["index"]),
+        Some(["values"]),
+        false,
+        None,
+        None,
+    );
+    assert!(eager.is_err());
+    for engine in [Engine::InMemory, Engine::Streaming] {
+        let lazy = df
+            .clone()
+            .lazy()
+            .pivot(
+                "on",
+                Some(Series::new("".into(), ["x", "y", "z"])),
+                Some(["index"]),
+                Some(["values"]),
+                None,
+                None,
+            )?
+            .collect_with_engine(engine);
+        assert!(
+            lazy.unwrap_err()
+                .to_string()
+                .contains("found multiple elements in the same group")
+        );
+    }
+
+    // Duplicate `on` values would give duplicate output columns.
+    assert!(
+        df.clone()
+            .lazy()
+            .pivot(
+                "on",
+                Some(Series::new("".into(), ["x", "y", "x"])),
+                Some(["index"]),
+                Some(["values"]),
+                Some(col("").sum()),
+                None,
+            )
+            .is_err()
+    );
+
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "lazy", feature = "dtype-categorical"))]
+fn test_lazy_pivot_enum() -> PolarsResult<()> {
+    let on_dtype = DataType::from_frozen_categories(FrozenCategories::new(["y", "x"])?);
+    let df = df![
+        "index" => [1, 2, 1],
+        "on" => ["x", "x", "y"],
+        "values" => [10, 20, 30],
+    ]?
+    .lazy()
+    .with_column(col("on").strict_cast(on_dtype));
+
+    let out = df
+        .pivot(
+            "on",
+            None,
+            Some(["index"]),
+            Some(["values"]),
+            Some(col("").count()),
+            None,
+        )?
+        .collect()?;
+    let expected = df![
+        "index" => [1, 2],
+        "y" => [1 as IdxSize, 0],
+        "x" => [1 as IdxSize, 1],
+    ]?;
+    assert!(out.equals(&expected));
+
+    Ok(())
+}
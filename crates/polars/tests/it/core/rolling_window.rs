@@ -280,3 +280,68 @@ fn test_rolling_var() {
         "{out:?} is not approximately equal to {exp_res:?}"
     );
 }
+
+#[test]
+fn test_rolling_counts_and_product() -> PolarsResult<()> {
+    let s = Int32Chunked::new(
+        "foo".into(),
+        &[Some(4), Some(1), Some(4), None, Some(1), Some(1)],
+    )
+    .into_series();
+    let options = RollingOptionsFixedWindow {
+        window_size: 3,
+        min_periods: 1,
+        ..Default::default()
+    };
+
+    let out = s.rolling_n_unique(options.clone())?;
+    assert_eq!(out.dtype(), &IDX_DTYPE);
+    assert_eq!(
+        Vec::from(out.idx()?),
+        &[Some(1), Some(2), Some(2), Some(2), Some(2), Some(1)]
+    );
+
+    let out = s.rolling_mode(options.clone())?;
+    assert_eq!(
+        Vec::from(out.i32()?),
+        &[Some(4), Some(1), Some(4), Some(1), Some(1), Some(1)]
+    );
+
+    let out = s.rolling_arg_min(options.clone())?;
+    assert_eq!(
+        Vec::from(out.idx()?),
+        &[Some(0), Some(1), Some(1), Some(0), Some(2), Some(1)]
+    );
+
+    let out = s.rolling_arg_max(options.clone())?;
+    assert_eq!(
+        Vec::from(out.idx()?),
+        &[Some(0), Some(0), Some(0), Some(1), Some(0), Some(1)]
+    );
+
+    let out = s.rolling_product(options.clone())?;
+    assert_eq!(
+        Vec::from(out.i64()?),
+        &[Some(4), Some(4), Some(16), Some(4), Some(4), Some(1)]
+    );
+
+    let out = s.rolling_rank(options.clone())?;
+    assert_eq!(
+        Vec::from(out.f64()?),
+        &[
+            Some(1.0),
+            Some(0.5),
+            Some(2.5 / 3.0),
+            None,
+            Some(0.5),
+            Some(0.75)
+        ]
+    );
+
+    let centered = RollingOptionsFixedWindow {
+        center: true,
+        ..options
+    };
+    assert!(s.rolling_rank(centered).is_err());
+    Ok(())
+}
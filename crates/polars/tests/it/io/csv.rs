@@ -1405,3 +1405,232 @@ fn test_read_io_reader() {
         .head(Some(df.height()));
     assert_eq!(&df, &expected);
 }
+
+#[test]
+fn test_csv_encoding_roundtrip() -> PolarsResult<()> {
+    let mut df = df![
+        "name" => ["Zoë", "François", "Ærø"],
+        "value" => [1i64, 2, 3],
+    ]?;
+
+    for encoding in [
+        CsvEncoding::Latin1,
+        CsvEncoding::Windows1252,
+        CsvEncoding::Utf16Le,
+        CsvEncoding::Utf16Be,
+    ] {
+        let mut buf: Vec<u8> = Vec::new();
+        CsvWriter::new(&mut buf)
+            .with_encoding(encoding)
+            .finish(&mut df)?;
+        assert!(std::str::from_utf8(&buf).is_err());
+
+        let out = CsvReadOptions::default()
+            .map_parse_options(|parse_options| parse_options.with_encoding(encoding))
+            .into_reader_with_file_handle(Cursor::new(buf))
+            .finish()?;
+        assert!(out.equals(&df));
+    }
+    Ok(())
+}
+
+#[test]
+fn test_csv_encoding_shift_jis() -> PolarsResult<()> {
+    // "名前,値\n日本,1\n" in Shift JIS.
+    let csv = b"\x96\xbc\x91\x4f,\x92\x6c\n\x93\xfa\x96\x7b,1\n";
+    let df = CsvReadOptions::default()
+        .map_parse_options(|parse_options| parse_options.with_encoding(CsvEncoding::ShiftJis))
+        .into_reader_with_file_handle(Cursor::new(csv))
+        .finish()?;
+
+    let expected = df!["名前" => ["日本"], "値" => [1i64]]?;
+    assert!(df.equals(&expected));
+
+    // Characters that cannot be represented in the target encoding are an error.
+    let mut buf: Vec<u8> = Vec::new();
+    assert!(
+        CsvWriter::new(&mut buf)
+            .with_encoding(CsvEncoding::Latin1)
+            .finish(&mut df!["a" => ["日本"]]?)
+            .is_err()
+    );
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "lazy")]
+fn test_scan_csv_encoding_streaming() -> PolarsResult<()> {
+    // Large enough to not fit in the prefix that is read for schema inference.
+    let n = 50_000;
+    let mut df = df![
+        "a" => (0..n).collect::<Vec<i64>>(),
+        "b" => (0..n).map(|i| format!("Zoë {i}")).collect::<Vec<_>>(),
+    ]?;
+
+    for encoding in [CsvEncoding::Latin1, CsvEncoding::Utf16Le] {
+        let path = std::env::temp_dir().join(format!("polars_test_scan_csv_{encoding:?}.csv"));
+        // Latin-1 has no byte order mark, so none is written.
+        CsvWriter::new(std::fs::File::create(&path)?)
+            .with_encoding(encoding)
+            .include_bom(true)
+            .finish(&mut df)?;
+        let bytes = std::fs::read(&path)?;
+        assert!(bytes.len() > 256 * 1024);
+        assert_eq!(bytes.starts_with(b"a,b"), encoding == CsvEncoding::Latin1);
+
+        // The streaming engine transcodes the file in blocks.
+        let out = LazyCsvReader::new(PlPath::new(path.to_str().unwrap()))
+            .with_encoding(encoding)
+            .finish()?
+            .collect_with_engine(Engine::Streaming)?;
+        assert!(out.equals(&df));
+        std::fs::remove_file(&path)?;
+    }
+    Ok(())
+}
+
+#[test]
+fn test_csv_bad_records() -> PolarsResult<()> {
+    let csv = "a,b\n1,x\nfoo,y\n3,z,extra\n4,w\n";
+    let schema = Schema::from_iter([
+        Field::new("a".into(), DataType::Int64),
+        Field::new("b".into(), DataType::String),
+    ]);
+    let bad_records = BadRecords::new();
+
+    let df = CsvReadOptions::default()
+        .with_schema(Some(Arc::new(schema)))
+        .with_row_index(Some(RowIndex {
+            name: "idx".into(),
+            offset: 0,
+        }))
+        .with_bad_records(Some(bad_records.clone()))
+        .into_reader_with_file_handle(Cursor::new(csv))
+        .finish()?;
+
+    let expected = df![
+        "idx" => [0 as IdxSize, 1],
+        "a" => [1i64, 4],
+        "b" => ["x", "w"],
+    ]?;
+    assert!(df.equals(&expected));
+
+    let bad = bad_records.take();
+    assert_eq!(bad.get_column_names(), &["path", "line", "record", "error"]);
+    assert_eq!(bad.column("path")?.null_count(), 2);
+    assert_eq!(Vec::from(bad.column("line")?.u64()?), &[Some(3), Some(4)]);
+    assert_eq!(
+        Vec::from(bad.column("record")?.str()?),
+        &[Some("foo,y"), Some("3,z,extra")]
+    );
+    let errors = bad.column("error")?.str()?;
+    assert!(errors.get(0).unwrap().contains("could not parse `foo`"));
+    assert!(errors.get(1).unwrap().contains("more fields"));
+    assert!(bad_records.is_empty());
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "compress", feature = "decompress", feature = "lazy"))]
+fn test_sink_csv_compressed() -> PolarsResult<()> {
+    let n = 2000;
+    let df = df![
+        "a" => (0..n).collect::<Vec<i64>>(),
+        // Quoted newlines, so that lines span multiple blocks of decompressed data.
+        "b" => (0..n)
+            .map(|i| if i % 7 == 0 { format!("multi\nline {i}") } else { format!("row {i}") })
+            .collect::<Vec<_>>(),
+    ]?;
+
+    for (i, compression) in [
+        ExternalCompression::Gzip { level: None },
+        ExternalCompression::Zstd { level: Some(1) },
+        ExternalCompression::Lz4 { level: None },
+        ExternalCompression::Bzip2 { level: None },
+        ExternalCompression::Xz { level: Some(1) },
+    ]
+    .into_iter()
+    .enumerate()
+    {
+        let path = std::env::temp_dir().join(format!("polars_test_sink_csv_compressed_{i}.csv"));
+        let pl_path = PlPath::new(path.to_str().unwrap());
+
+        // Both engines compress the output of sinks.
+        for engine in [Engine::InMemory, Engine::Streaming] {
+            df.clone()
+                .lazy()
+                .sink_csv(
+                    SinkTarget::Path(pl_path.clone()),
+                    CsvWriterOptions {
+                        compression,
+                        ..Default::default()
+                    },
+                    None,
+                    SinkOptions::default(),
+                )?
+                .collect_with_engine(engine)?;
+            assert!(!std::fs::read(&path)?.starts_with(b"a,b"));
+
+            let out = LazyCsvReader::new(pl_path.clone()).finish()?.collect()?;
+            assert!(out.equals(&df));
+
+            let out = LazyCsvReader::new(pl_path.clone())
+                .with_row_index(Some(RowIndex {
+                    name: "index".into(),
+                    offset: 0,
+                }))
+                .finish()?
+                .slice(1000, 3)
+                .collect()?;
+            assert_eq!(
+                Vec::from(out.column("index")?.idx()?),
+                &[Some(1000), Some(1001), Some(1002)]
+            );
+            assert!(out.drop("index")?.equals(&df.slice(1000, 3)));
+
+            let out = CsvReadOptions::default()
+                .try_into_reader_with_file_path(Some(path.clone()))?
+                .finish()?;
+            assert!(out.equals(&df));
+
+            std::fs::remove_file(&path)?;
+        }
+    }
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "compress", feature = "decompress", feature = "lazy"))]
+fn test_scan_csv_compressed_and_transcoded() -> PolarsResult<()> {
+    let n = 50_000;
+    let df = df![
+        "a" => (0..n).collect::<Vec<i64>>(),
+        "b" => (0..n).map(|i| format!("Zoë {i}")).collect::<Vec<_>>(),
+    ]?;
+    let path = std::env::temp_dir().join("polars_test_scan_csv_compressed_and_transcoded.csv");
+    let pl_path = PlPath::new(path.to_str().unwrap());
+
+    df.clone()
+        .lazy()
+        .sink_csv(
+            SinkTarget::Path(pl_path.clone()),
+            CsvWriterOptions {
+                compression: ExternalCompression::Gzip { level: None },
+                encoding: CsvEncoding::Utf16Le,
+                ..Default::default()
+            },
+            None,
+            SinkOptions::default(),
+        )?
+        .collect_with_engine(Engine::Streaming)?;
+
+    // The streaming engine decompresses and transcodes the file in blocks.
+    let out = LazyCsvReader::new(pl_path)
+        .with_encoding(CsvEncoding::Utf16Le)
+        .finish()?
+        .collect_with_engine(Engine::Streaming)?;
+    assert!(out.equals(&df));
+
+    std::fs::remove_file(&path)?;
+    Ok(())
+}
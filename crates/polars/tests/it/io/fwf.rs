@@ -0,0 +1,114 @@
+use std::io::Cursor;
+
+use polars::io::RowIndex;
+use polars_utils::mmap::MemSlice;
+
+use super::*;
+
+const FWF: &str = "\
+id  name      score
+1   alpha      1.5
+2   beta      -2.0
+3             NA
+42  delta     10.25
+";
+
+fn columns() -> Arc<[FwfColumn]> {
+    Arc::new([
+        FwfColumn::new("id".into(), 0, 4, DataType::Int32),
+        FwfColumn::new("name".into(), 4, 6, DataType::String),
+        FwfColumn::new("score".into(), 14, 6, DataType::Float64),
+    ])
+}
+
+#[test]
+fn test_read_fwf() -> PolarsResult<()> {
+    let df = FwfReadOptions::default()
+        .with_columns(columns())
+        .with_skip_rows(1)
+        .with_null_values(Some(NullValues::AllColumnsSingle("NA".into())))
+        .into_reader_with_file_handle(Cursor::new(FWF))
+        .finish()?;
+
+    let expected = df![
+        "id" => [1, 2, 3, 42],
+        "name" => [Some("alpha"), Some("beta"), None, Some("delta")],
+        "score" => [Some(1.5), Some(-2.0), None, Some(10.25)],
+    ]?;
+    assert!(df.equals_missing(&expected));
+
+    Ok(())
+}
+
+#[test]
+fn test_read_fwf_projection_and_row_index() -> PolarsResult<()> {
+    let df = FwfReadOptions::default()
+        .with_columns(columns())
+        .with_skip_rows(1)
+        .with_n_rows(Some(2))
+        .with_projection(Some(Arc::new(["score".into(), "id".into()])))
+        .with_row_index(Some(RowIndex {
+            name: "index".into(),
+            offset: 10,
+        }))
+        .into_reader_with_file_handle(Cursor::new(FWF))
+        .finish()?;
+
+    let expected = df![
+        "index" => [10 as IdxSize, 11],
+        "score" => [1.5, -2.0],
+        "id" => [1, 2],
+    ]?;
+    assert!(df.equals(&expected));
+
+    Ok(())
+}
+
+#[test]
+fn test_read_fwf_parse_error() {
+    let opts = FwfReadOptions::default()
+        .with_columns(columns())
+        .with_skip_rows(1);
+
+    let err = opts
+        .clone()
+        .into_reader_with_file_handle(Cursor::new(FWF))
+        .finish()
+        .unwrap_err();
+    assert!(err.to_string().contains("NA"));
+
+    let df = opts
+        .with_ignore_errors(true)
+        .into_reader_with_file_handle(Cursor::new(FWF))
+        .finish()
+        .unwrap();
+    assert_eq!(df.column("score").unwrap().null_count(), 1);
+}
+
+#[test]
+#[cfg(feature = "lazy")]
+fn test_scan_fwf() -> PolarsResult<()> {
+    let df = LazyFwfReader::new_with_sources(ScanSources::Buffers(Arc::new([MemSlice::from_vec(
+        FWF.as_bytes().to_vec(),
+    )])))
+    .with_columns(columns())
+    .with_skip_rows(1)
+    .with_null_values(Some(NullValues::AllColumnsSingle("NA".into())))
+    .with_row_index(Some(RowIndex {
+        name: "index".into(),
+        offset: 0,
+    }))
+    .finish()?
+    .filter(col("score").is_not_null())
+    .select([col("index"), col("name")])
+    .slice(1, 2)
+    .collect()?;
+
+    let expected = df![
+        "index" => [1 as IdxSize, 3],
+        "name" => ["beta", "delta"],
+    ]?;
+    assert!(df.equals(&expected));
+
+    Ok(())
+}
@@ -1,6 +1,8 @@
 use std::io::Cursor;
 use std::num::NonZeroUsize;
 
+use polars::io::RowIndex;
+
 use super::*;
 
 #[test]
@@ -185,3 +187,119 @@ fn test_read_ndjson_iss_6148() {
     let df = JsonLineReader::new(cursor).finish();
     assert!(df.is_ok());
 }
+
+#[test]
+fn test_read_ndjson_bad_records() -> PolarsResult<()> {
+    let data = "{\"a\":1}\n{\"a\":\n{\"a\":3}\nnot json\n";
+    let schema = Schema::from_iter([Field::new("a".into(), DataType::Int64)]);
+    let bad_records = BadRecords::new();
+
+    let df = JsonLineReader::new(Cursor::new(data))
+        .with_schema(Arc::new(schema))
+        .with_bad_records(Some(bad_records.clone()))
+        .finish()?;
+    assert!(df.equals(&df!["a" => [1i64, 3]]?));
+
+    let bad = bad_records.take();
+    assert_eq!(Vec::from(bad.column("line")?.u64()?), &[Some(2), Some(4)]);
+    assert_eq!(
+        Vec::from(bad.column("record")?.str()?),
+        &[Some("{\"a\":"), Some("not json")]
+    );
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "lazy")]
+fn test_scan_ndjson_bad_records_len() -> PolarsResult<()> {
+    use polars_utils::mmap::MemSlice;
+
+    let data = b"{\"a\":1}\n{\"a\":\n{\"a\":3}\nnot json\n";
+    let bad_records = BadRecords::new();
+
+    // Bad records don't count towards the number of rows, also if no column is read.
+    for engine in [Engine::InMemory, Engine::Streaming] {
+        let df = LazyJsonLineReader::new_with_sources(ScanSources::Buffers(
+            [MemSlice::from_static(data)].into(),
+        ))
+        .with_schema(Some(Arc::new(Schema::from_iter([Field::new(
+            "a".into(),
+            DataType::Int64,
+        )]))))
+        .with_bad_records(Some(bad_records.clone()))
+        .finish()?
+        .select([len()])
+        .collect_with_engine(engine)?;
+        assert_eq!(df.column("len")?.idx()?.get(0), Some(2));
+        assert_eq!(bad_records.take().height(), 2);
+    }
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "compress", feature = "decompress", feature = "lazy"))]
+fn test_sink_ndjson_compressed() -> PolarsResult<()> {
+    let n = 2000;
+    let df = df![
+        "a" => (0..n).collect::<Vec<i64>>(),
+        "b" => (0..n).map(|i| format!("row {i}")).collect::<Vec<_>>(),
+    ]?;
+
+    for (i, compression) in [
+        ExternalCompression::Gzip { level: None },
+        ExternalCompression::Zstd { level: Some(1) },
+        ExternalCompression::Lz4 { level: None },
+        ExternalCompression::Bzip2 { level: None },
+        ExternalCompression::Xz { level: Some(1) },
+    ]
+    .into_iter()
+    .enumerate()
+    {
+        let path =
+            std::env::temp_dir().join(format!("polars_test_sink_ndjson_compressed_{i}.jsonl"));
+        let pl_path = PlPath::new(path.to_str().unwrap());
+
+        // Both engines compress the output of sinks.
+        for engine in [Engine::InMemory, Engine::Streaming] {
+            df.clone()
+                .lazy()
+                .sink_json(
+                    SinkTarget::Path(pl_path.clone()),
+                    JsonWriterOptions { compression },
+                    None,
+                    SinkOptions::default(),
+                )?
+                .collect_with_engine(engine)?;
+            assert!(!std::fs::read(&path)?.starts_with(b"{"));
+
+            let out = LazyJsonLineReader::new(pl_path.clone())
+                .finish()?
+                .collect()?;
+            assert!(out.equals(&df));
+
+            let out = LazyJsonLineReader::new(pl_path.clone())
+                .with_row_index(Some(RowIndex {
+                    name: "index".into(),
+                    offset: 0,
+                }))
+                .finish()?
+                .slice(1000, 3)
+                .collect()?;
+            assert_eq!(
+                Vec::from(out.column("index")?.idx()?),
+                &[Some(1000), Some(1001), Some(1002)]
+            );
+            assert!(out.drop("index")?.equals(&df.slice(1000, 3)));
+
+            // Negative slices decompress the complete file up front.
+            let out = LazyJsonLineReader::new(pl_path.clone())
+                .finish()?
+                .tail(3)
+                .collect()?;
+            assert!(out.equals(&df.tail(Some(3))));
+
+            std::fs::remove_file(&path)?;
+        }
+    }
+    Ok(())
+}
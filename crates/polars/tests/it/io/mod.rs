@@ -1,12 +1,18 @@
 mod csv;
 
+#[cfg(feature = "fwf")]
+mod fwf;
+
 #[cfg(feature = "json")]
 mod json;
 
+#[cfg(feature = "orc")]
+mod orc;
+
 #[cfg(feature = "parquet")]
 mod parquet;
 
-#[cfg(feature = "avro")]
+#[cfg(any())] // LOCAL
 mod avro;
 
 #[cfg(feature = "ipc")]
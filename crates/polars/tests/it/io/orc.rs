@@ -0,0 +1,145 @@
+use std::io::Cursor;
+
+use polars::io::RowIndex;
+
+use super::*;
+
+#[test]
+fn test_orc_read_write() -> PolarsResult<()> {
+    let mut df = df![
+        "a" => [Some(1i32), None, Some(3)],
+        "b" => [Some("foo"), Some("bar"), None],
+        "c" => [1.5f64, 2.5, 3.5],
+    ]?;
+
+    let mut buf = Cursor::new(Vec::new());
+    OrcWriter::new(&mut buf)
+        .with_compression(OrcCompression::Zlib)
+        .finish(&mut df)?;
+
+    buf.set_position(0);
+    let out = OrcReader::new(buf.clone()).finish()?;
+    assert!(out.equals_missing(&df));
+
+    buf.set_position(0);
+    let out = OrcReader::new(buf)
+        .with_columns(Some(vec!["c".into(), "a".into()]))
+        .with_n_rows(Some(2))
+        .finish()?;
+    assert!(out.equals_missing(&df.select(["c", "a"])?.head(Some(2))));
+
+    Ok(())
+}
+
+/// `hive.orc` is laid out like the files of the Java ORC writer used by Hive: zlib compressed
+/// streams, row index streams, RLE v1 integers, dictionary encoded strings and timestamps relative
+/// to the writer time zone `America/Los_Angeles`. It was assembled by hand, as no external writer
+/// is available in the test environment.
+#[test]
+#[cfg(feature = "timezones")]
+fn test_orc_read_external_layout() -> PolarsResult<()> {
+    let file = std::fs::File::open("../../examples/datasets/hive.orc")?;
+    let out = OrcReader::new(file).finish()?;
+
+    let ts = Int64Chunked::new(
+        "ts".into(),
+        [
+            1_420_070_400_000_000_000i64,
+            1_592_224_200_500_000_000,
+            -310_435_199_750_000_000,
+            1_678_591_800_000_000_000,
+            946_684_800_000_000_000,
+        ],
+    )
+    .into_datetime(TimeUnit::Nanoseconds, None);
+    let price = Int128Chunked::new("price".into(), [150i128, -225, 10_000, 1, 1_234_567_890])
+        .into_decimal_unchecked(10, 2);
+    let expected = DataFrame::new(vec![
+        Column::new("id".into(), [1i32, 2, 3, 4, 5]),
+        Column::new(
+            "name".into(),
+            [Some("b"), Some("a"), Some("b"), None, Some("c")],
+        ),
+        ts.into_column(),
+        price.into_column(),
+    ])?;
+    assert!(out.equals_missing(&expected));
+
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "lazy")]
+fn test_sink_and_scan_orc() -> PolarsResult<()> {
+    let df = df![
+        "a" => (0..10_000i64).collect::<Vec<_>>(),
+        "b" => (0..10_000).map(|i| format!("s{i}")).collect::<Vec<_>>(),
+    ]?;
+
+    for engine in [Engine::InMemory, Engine::Streaming] {
+        let dir = tempfile::tempdir()?;
+        let path = dir.path().join("out.orc");
+        let pl_path = PlPath::new(path.to_str().unwrap());
+
+        df.clone()
+            .lazy()
+            .sink_orc(
+                SinkTarget::Path(pl_path.clone()),
+                OrcWriterOptions {
+                    rows_per_stripe: Some(1000),
+                    ..Default::default()
+                },
+                None,
+                SinkOptions::default(),
+            )?
+            .collect_with_engine(engine)?;
+
+        let mut reader = OrcReader::new(std::fs::File::open(&path)?);
+        assert_eq!(reader.metadata()?.num_stripes(), 10);
+
+        let out = LazyFrame::scan_orc(pl_path.clone(), Default::default())?.collect()?;
+        assert!(out.equals(&df));
+
+        // Stripes that cannot match are skipped based on their statistics.
+        let out = LazyFrame::scan_orc(
+            pl_path.clone(),
+            ScanArgsOrc {
+                row_index: Some(RowIndex {
+                    name: "index".into(),
+                    offset: 5,
+                }),
+                ..Default::default()
+            },
+        )?
+        .filter(
+            col("a")
+                .gt_eq(lit(8_998i64))
+                .and(col("a").lt(lit(9_002i64))),
+        )
+        .select([col("index"), col("b")])
+        .collect_with_engine(Engine::Streaming)?;
+
+        let expected = df![
+            "index" => [9_003 as IdxSize, 9_004, 9_005, 9_006],
+            "b" => ["s8998", "s8999", "s9000", "s9001"],
+        ]?;
+        assert!(out.equals(&expected));
+
+        let out = LazyFrame::scan_orc(pl_path.clone(), Default::default())?
+            .slice(-1_500, 3)
+            .select([col("a")])
+            .collect()?;
+        assert!(out.equals(&df!["a" => [8_500i64, 8_501, 8_502]]?));
+
+        assert_eq!(
+            LazyFrame::scan_orc(pl_path, Default::default())?
+                .select([len()])
+                .collect()?
+                .column("len")?
+                .get(0)?,
+            AnyValue::from(10_000 as IdxSize)
+        );
+    }
+
+    Ok(())
+}
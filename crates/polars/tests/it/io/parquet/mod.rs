@@ -206,3 +206,396 @@ fn test_vstack_empty_3220() -> PolarsResult<()> {
     assert!(stacked.equals(&read_df));
     Ok(())
 }
+
+#[test]
+#[cfg(feature = "lazy")]
+fn test_explain_analyze_scan() -> PolarsResult<()> {
+    let mut df = df![
+        "id" => (0..10_000i64).collect::<Vec<_>>(),
+        "v" => (0..10_000i64).map(|i| i * 2).collect::<Vec<_>>(),
+    ]?;
+    let path = std::env::temp_dir().join("polars_test_explain_analyze.parquet");
+    ParquetWriter::new(std::fs::File::create(&path)?)
+        .with_row_group_size(Some(1_000))
+        .finish(&mut df)?;
+    let pl_path = PlPath::new(path.to_str().unwrap());
+
+    for engine in [Engine::InMemory, Engine::Streaming] {
+        let lf = LazyFrame::scan_parquet(pl_path.clone(), Default::default())?
+            .filter(col("id").lt(lit(1_500i64)))
+            .select([col("v").sum()]);
+
+        let out = lf.clone().explain_analyze_df(engine)?;
+        assert_eq!(out.height(), 2);
+        let rows_in = out.column("rows_in")?.u64()?;
+        let rows_out = out.column("rows_out")?.u64()?;
+        assert_eq!(rows_in.get(0), Some(1_500));
+        assert_eq!(rows_out.get(0), Some(1));
+        assert_eq!(rows_in.get(1), None);
+        assert_eq!(rows_out.get(1), Some(1_500));
+        assert_eq!(out.column("files_skipped")?.u64()?.get(1), Some(0));
+        assert_eq!(out.column("row_groups_skipped")?.u64()?.get(1), Some(8));
+        assert_eq!(out.column("files_skipped")?.u64()?.get(0), None);
+        assert!(out.column("elapsed_us")?.u64()?.get(1).is_some());
+
+        let plan = lf.explain_analyze(engine)?;
+        let first_line = plan.lines().next().unwrap();
+        assert!(first_line.starts_with("SELECT"), "{plan}");
+        assert!(first_line.contains("[rows in: 1500, rows out: 1, elapsed: "));
+        assert!(plan.contains("rows out: 1500, elapsed: "));
+        assert!(plan.contains("skipped files: 0, row groups: 8, pages: 0]"));
+        assert!(plan.ends_with(&format!("[engine: {}]", engine.into_static_str())));
+    }
+
+    let lf = LazyFrame::scan_parquet(pl_path.clone(), Default::default())?;
+    let out = lf.clone().explain_analyze_df(Engine::Auto)?;
+    assert_eq!(out.column("engine")?.str()?.get(0), Some("in-memory"));
+    assert!(lf.explain_analyze(Engine::Gpu).is_err());
+
+    std::fs::remove_file(&path)?;
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "lazy")]
+fn test_sorting_columns_roundtrip() -> PolarsResult<()> {
+    use polars_core::series::IsSorted;
+    use polars_parquet::read::read_metadata;
+    use polars_parquet::write::SortingColumn;
+
+    let mut a = Series::new("a".into(), (0..10_000i64).collect::<Vec<_>>());
+    a.set_sorted_flag(IsSorted::Ascending);
+    let mut d = Series::new("d".into(), (0..10_000i32).rev().collect::<Vec<_>>());
+    d.set_sorted_flag(IsSorted::Descending);
+    let v = Series::new(
+        "v".into(),
+        (0..10_000i64)
+            .map(|i| (i * 7_919) % 10_000)
+            .collect::<Vec<_>>(),
+    );
+    let df = DataFrame::new(vec![a.into(), d.into(), v.into()])?;
+
+    for (i, engine) in [Engine::InMemory, Engine::Streaming]
+        .into_iter()
+        .enumerate()
+    {
+        let path = std::env::temp_dir().join(format!("polars_test_sorting_columns_{i}.parquet"));
+        let pl_path = PlPath::new(path.to_str().unwrap());
+        df.clone()
+            .lazy()
+            .sink_parquet(
+                SinkTarget::Path(pl_path.clone()),
+                ParquetWriteOptions {
+                    row_group_size: Some(1_000),
+                    ..Default::default()
+                },
+                None,
+                SinkOptions::default(),
+            )?
+            .collect_with_engine(engine)?;
+
+        let md = read_metadata(&mut std::fs::File::open(&path)?)?;
+        assert_eq!(md.row_groups.len(), 10);
+        let expected = [
+            SortingColumn {
+                column_idx: 0,
+                descending: false,
+                nulls_first: false,
+            },
+            SortingColumn {
+                column_idx: 1,
+                descending: true,
+                nulls_first: false,
+            },
+        ];
+        for rg in &md.row_groups {
+            assert_eq!(rg.sorting_columns(), Some(expected.as_slice()));
+        }
+
+        let scan = LazyFrame::scan_parquet(pl_path.clone(), Default::default())?;
+        let out = scan.clone().collect()?;
+        assert!(out.equals(&df));
+        // The sorting columns are a lexicographic order, so only the first one is sorted by itself.
+        assert_eq!(out.column("a")?.is_sorted_flag(), IsSorted::Ascending);
+        assert_eq!(out.column("d")?.is_sorted_flag(), IsSorted::Not);
+
+        // Sorts of columns that the file is known to be sorted by are removed.
+        let q = scan
+            .clone()
+            .filter(col("v").lt(lit(5_000i64)))
+            .sort(["a"], Default::default());
+        assert!(!q.clone().explain(true)?.contains("SORT BY"));
+        let expected = df
+            .clone()
+            .lazy()
+            .filter(col("v").lt(lit(5_000i64)))
+            .collect()?;
+        assert!(q.collect()?.equals(&expected));
+        let q = scan.clone().sort(
+            ["a"],
+            SortMultipleOptions::default().with_order_descending(true),
+        );
+        assert!(q.explain(true)?.contains("SORT BY"));
+        let q = scan.clone().sort(["d"], Default::default());
+        assert!(q.explain(true)?.contains("SORT BY"));
+
+        // The row groups are sorted by the leading sorting columns, and their ranges of `a`
+        // don't touch, so the file is sorted by `a` and then `d`.
+        let options = SortMultipleOptions::default().with_order_descending_multi([false, true]);
+        let q = scan.clone().sort(["a", "d"], options.clone());
+        assert!(!q.clone().explain(true)?.contains("SORT BY"));
+        assert!(q.collect()?.equals(&df));
+        let q = scan.clone().sort(["a", "v"], options);
+        assert!(q.explain(true)?.contains("SORT BY"));
+
+        // The sorted flags of the keys let the in-memory engine use a sorted merge join and
+        // the sorted group-by.
+        let keys = scan.clone().select([col("a")]).collect()?;
+        assert_eq!(keys.column("a")?.is_sorted_flag(), IsSorted::Ascending);
+        let out = scan
+            .clone()
+            .join(
+                scan.clone().select([col("a"), col("v").alias("w")]),
+                [col("a")],
+                [col("a")],
+                JoinArgs::new(JoinType::Inner),
+            )
+            .group_by_stable([col("a")])
+            .agg([col("w").sum()])
+            .collect_with_engine(Engine::InMemory)?;
+        assert!(out.column("a")?.equals(df.column("a")?));
+        assert!(
+            out.column("w")?
+                .equals(&df.column("v")?.clone().with_name("w".into()))
+        );
+
+        std::fs::remove_file(&path)?;
+    }
+
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "lazy")]
+fn test_join_runtime_filter_on_scan() -> PolarsResult<()> {
+    use std::sync::Arc;
+
+    use polars::io::predicates::ScanMetrics;
+
+    let mut fact = df![
+        "id" => (0..10_000i64).collect::<Vec<_>>(),
+        "v" => (0..10_000i64).map(|i| i * 2).collect::<Vec<_>>(),
+        "s" => (0..10_000).map(|i| format!("s{}", i % 100)).collect::<Vec<_>>(),
+    ]?;
+    let path = std::env::temp_dir().join("polars_test_join_runtime_filter.parquet");
+    ParquetWriter::new(std::fs::File::create(&path)?)
+        .with_row_group_size(Some(1_000))
+        .finish(&mut fact)?;
+    let pl_path = PlPath::new(path.to_str().unwrap());
+
+    let dim = df![
+        "id" => [Some(1_234i64), Some(5_678), Some(9_999), Some(20_000), None],
+        "s" => ["s34", "s1", "s99", "s0", "s50"],
+        "w" => [1, 2, 3, 4, 5],
+    ]?;
+
+    // The metrics of the scan count the row groups that were skipped.
+    let scan = || {
+        let metrics = Arc::new(ScanMetrics::default());
+        let mut lf = LazyFrame::scan_parquet(pl_path.clone(), Default::default()).unwrap();
+        let DslPlan::Scan {
+            unified_scan_args, ..
+        } = &mut lf.logical_plan
+        else {
+            unreachable!()
+        };
+        unified_scan_args.scan_metrics = Some(metrics.clone());
+        let lf = lf.filter(col("v").gt_eq(lit(0i64))).select([
+            col("id").alias("key"),
+            col("v"),
+            col("s"),
+        ]);
+        (lf, metrics)
+    };
+    let dim = || dim.clone().lazy().rename(["id"], ["key"], true);
+    let join = |left: LazyFrame, right: LazyFrame, on: &[&str], args: JoinArgs| {
+        let on = on.iter().map(|c| col(*c)).collect::<Vec<_>>();
+        left.join(right, on.clone(), on, args)
+    };
+
+    // The build keys lie within 1_234..=20_000, so the first row group of the scan can be
+    // skipped whenever the scan is filtered.
+    let mut queries = vec![];
+    let (s, metrics) = scan();
+    queries.push((
+        join(s, dim(), &["key"], JoinArgs::new(JoinType::Inner)),
+        metrics,
+        true,
+    ));
+    // Multiple keys, of which only some are filtered.
+    let (s, metrics) = scan();
+    queries.push((
+        join(s, dim(), &["key", "s"], JoinArgs::new(JoinType::Inner)),
+        metrics,
+        true,
+    ));
+    let (s, metrics) = scan();
+    queries.push((
+        join(dim(), s, &["key"], JoinArgs::new(JoinType::Left)),
+        metrics,
+        true,
+    ));
+    let (s, metrics) = scan();
+    let args = JoinArgs {
+        nulls_equal: true,
+        ..JoinArgs::new(JoinType::Inner)
+    };
+    queries.push((join(s, dim(), &["key"], args), metrics, true));
+    #[cfg(feature = "semi_anti_join")]
+    {
+        let (s, metrics) = scan();
+        queries.push((
+            join(s, dim(), &["key"], JoinArgs::new(JoinType::Semi)),
+            metrics,
+            true,
+        ));
+        // The rows of an anti join are exactly those without a match.
+        let (s, metrics) = scan();
+        queries.push((
+            join(s, dim(), &["key"], JoinArgs::new(JoinType::Anti)),
+            metrics,
+            false,
+        ));
+    }
+
+    for (q, metrics, skips) in queries {
+        let sort = |df: DataFrame| {
+            let by = df.get_column_names_owned();
+            df.sort(by, SortMultipleOptions::default())
+        };
+        let expected = sort(q.clone().collect_with_engine(Engine::InMemory)?)?;
+        let skipped_before = metrics.row_groups_skipped.load();
+        let out = sort(q.collect_with_engine(Engine::Streaming)?)?;
+        assert!(out.equals_missing(&expected), "{out}\n{expected}");
+        let skipped = metrics.row_groups_skipped.load() - skipped_before;
+        if skips {
+            assert_eq!(skipped, 1);
+        } else {
+            assert_eq!(skipped, 0);
+        }
+    }
+
+    // The counting nodes of explain_analyze don't stand between the join and the scan.
+    let (s, _) = scan();
+    let out = join(s, dim(), &["key"], JoinArgs::new(JoinType::Inner))
+        .explain_analyze_df(Engine::Streaming)?;
+    let plan = out.column("plan")?.str()?;
+    let scan_idx = plan
+        .into_no_null_iter()
+        .position(|line| line.contains("SCAN"))
+        .unwrap();
+    assert_eq!(
+        out.column("row_groups_skipped")?.u64()?.get(scan_idx),
+        Some(1)
+    );
+
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "lazy")]
+fn test_partition_wise_hive_join_and_group_by() -> PolarsResult<()> {
+    use polars_plan::plans::{ArenaLpIter, IR};
+
+    let tmp_dir = std::env::temp_dir().join("polars_test_partition_wise");
+    let write_dataset = |name: &str, n: i64| -> PolarsResult<PlPath> {
+        let root = tmp_dir.join(name);
+        for p in 0..4i64 {
+            let dir = root.join(format!("p={p}"));
+            std::fs::create_dir_all(&dir)?;
+            let mut df = df![
+                "k" => (0..n).map(|i| i % 10).collect::<Vec<_>>(),
+                "v" => (0..n).map(|i| i * (p + 1)).collect::<Vec<_>>(),
+            ]?;
+            ParquetWriter::new(std::fs::File::create(dir.join("0.parquet"))?).finish(&mut df)?;
+            // The first partition consists of several files.
+            if p == 0 {
+                ParquetWriter::new(std::fs::File::create(dir.join("1.parquet"))?)
+                    .finish(&mut df)?;
+            }
+        }
+        Ok(PlPath::new(root.to_str().unwrap()))
+    };
+    let left = write_dataset("left", 100)?;
+    let right = write_dataset("right", 20)?;
+
+    let scan = |path: &PlPath| {
+        LazyFrame::scan_parquet(path.clone(), Default::default())
+            .unwrap()
+            .with_partition_wise(true)
+    };
+    let queries = [
+        scan(&left)
+            .filter(col("v").gt(lit(10i64)))
+            .group_by([col("p"), col("k")])
+            .agg([col("v").sum(), col("v").count().alias("n")]),
+        scan(&left).join(
+            scan(&right).filter(col("p").neq(lit(2i64))),
+            [col("p"), col("k")],
+            [col("p"), col("k")],
+            JoinArgs::new(JoinType::Inner),
+        ),
+    ];
+
+    for q in queries {
+        let sort = |df: DataFrame| {
+            let by = df.get_column_names_owned();
+            df.sort(by, SortMultipleOptions::default())
+        };
+        let expected = sort(q.clone().with_partition_wise(false).collect()?)?;
+        assert!(q.clone().explain(true)?.contains("UNION"));
+        assert!(
+            !q.clone()
+                .with_partition_wise(false)
+                .explain(true)?
+                .contains("UNION")
+        );
+        for engine in [Engine::InMemory, Engine::Streaming] {
+            let out = sort(q.clone().collect_with_engine(engine)?)?;
+            assert!(out.equals(&expected));
+        }
+    }
+
+    // Every partition keeps the per-file row estimate of the scan.
+    let plan = scan(&left)
+        .group_by([col("p"), col("k")])
+        .agg([col("v").sum()])
+        .to_alp_optimized()?;
+    let mut estimates = plan
+        .lp_arena
+        .iter(plan.lp_top)
+        .filter_map(|(_, ir)| match ir {
+            IR::Scan {
+                sources, file_info, ..
+            } => Some((sources.len(), file_info.row_estimation.1)),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    estimates.sort();
+    assert_eq!(estimates, [(1, 100), (1, 100), (1, 100), (2, 100)]);
+
+    // Order-maintaining group-bys are not rewritten.
+    let q = scan(&left)
+        .group_by_stable([col("p")])
+        .agg([col("v").sum()]);
+    assert!(!q.explain(true)?.contains("UNION"));
+
+    // The rewrite is opt-in.
+    let q = LazyFrame::scan_parquet(left.clone(), Default::default())?
+        .group_by([col("p")])
+        .agg([col("v").sum()]);
+    assert!(!q.explain(true)?.contains("UNION"));
+
+    std::fs::remove_dir_all(&tmp_dir)?;
+    Ok(())
+}
@@ -56,3 +56,328 @@ fn join_empty_datasets() -> PolarsResult<()> {
 
     Ok(())
 }
+
+#[test]
+#[cfg(feature = "lazy")]
+fn test_streaming_join_strategies() -> PolarsResult<()> {
+    let sorted = |n: i64, div: i64| (0..n).map(|i| i / div).collect::<Vec<_>>();
+    let unsorted = |n: i64, m: i64| (0..n).map(|i| (i * 7_919) % m).collect::<Vec<_>>();
+
+    let cases = [
+        // Both sides sorted with duplicate keys, joined with a sorted merge.
+        (sorted(1_000, 3), sorted(500, 2)),
+        (sorted(10, 1), sorted(200_000, 7)),
+        // Small build side which is broadcast.
+        (unsorted(50, 97), unsorted(20_000, 1_009)),
+        // Build side too big to be broadcast.
+        (unsorted(200_000, 300_007), unsorted(150_000, 300_007)),
+    ];
+
+    for (left, right) in cases {
+        let left = df![
+            "a" => &left,
+            "b" => left.iter().map(|x| x * 2).collect::<Vec<_>>(),
+        ]?;
+        let right = df![
+            "a" => &right,
+            "b" => right.iter().map(|x| x.to_string()).collect::<Vec<_>>(),
+        ]?;
+
+        for how in [
+            JoinType::Inner,
+            JoinType::Left,
+            JoinType::Right,
+            JoinType::Full,
+        ] {
+            let q = left.clone().lazy().join(
+                right.clone().lazy(),
+                [col("a")],
+                [col("a")],
+                JoinArgs::new(how),
+            );
+            let sort = |df: DataFrame| {
+                let by = df.get_column_names_owned();
+                df.sort(by, SortMultipleOptions::default())
+            };
+            let expected = sort(q.clone().collect_with_engine(Engine::InMemory)?)?;
+            let out = sort(q.collect_with_engine(Engine::Streaming)?)?;
+            assert!(out.equals_missing(&expected));
+        }
+    }
+
+    // Sorted string and multiple keys.
+    let left = df![
+        "a" => ["a", "a", "b", "c"],
+        "b" => [1i32, 2, 1, 1],
+        "c" => [1i32, 2, 3, 4],
+    ]?;
+    let right = df![
+        "a" => ["a", "b", "b", "d"],
+        "b" => [2i32, 1, 1, 1],
+        "d" => [5i32, 6, 7, 8],
+    ]?;
+    let q = left.lazy().join(
+        right.lazy(),
+        [col("a"), col("b")],
+        [col("a"), col("b")],
+        JoinArgs {
+            maintain_order: MaintainOrderJoin::Left,
+            ..JoinArgs::new(JoinType::Left)
+        },
+    );
+    let expected = q.clone().collect_with_engine(Engine::InMemory)?;
+    let out = q.collect_with_engine(Engine::Streaming)?;
+    assert!(out.equals_missing(&expected));
+
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "lazy", feature = "fuzzy_join", feature = "cross_join"))]
+fn test_fuzzy_join_matches_cross_join() -> PolarsResult<()> {
+    let left = df! {
+        "name" => [Some("Jonathan Smith"), Some("Jon Smith"), Some("Maria Garcia"), Some("Zoë Müller"), None, Some("")],
+        "id" => [1, 2, 3, 4, 5, 6],
+    }?;
+    let right = df! {
+        "name" => [Some("Jonathon Smith"), Some("Jon Smyth"), Some("Mario Garcia"), Some("Zoe Muller"), Some("Alice"), None, Some("a")],
+        "account" => [10, 20, 30, 40, 50, 60, 70],
+    }?;
+    let sort = |df: DataFrame| {
+        df.sort(["id", "account"], SortMultipleOptions::default())
+            .unwrap()
+    };
+
+    let cases = [
+        (
+            FuzzyJoinMetric::Levenshtein { max_distance: 2 },
+            col("name").str().levenshtein(col("name_right")).lt_eq(2),
+        ),
+        (
+            FuzzyJoinMetric::DamerauLevenshtein { max_distance: 1 },
+            col("name")
+                .str()
+                .damerau_levenshtein(col("name_right"))
+                .lt_eq(1),
+        ),
+        (
+            FuzzyJoinMetric::Hamming { max_distance: 2 },
+            col("name").str().hamming(col("name_right")).lt_eq(2),
+        ),
+        (
+            FuzzyJoinMetric::JaroWinkler {
+                min_similarity: 0.9,
+            },
+            col("name")
+                .str()
+                .jaro_winkler(col("name_right"))
+                .gt_eq(lit(0.9)),
+        ),
+        (
+            FuzzyJoinMetric::NgramJaccard {
+                n: 2,
+                min_similarity: 0.5,
+            },
+            col("name")
+                .str()
+                .ngram_jaccard(col("name_right"), 2)
+                .gt_eq(lit(0.5)),
+        ),
+    ];
+    for (metric, predicate) in cases {
+        let out = left
+            .clone()
+            .lazy()
+            .fuzzy_join(
+                right.clone().lazy(),
+                col("name"),
+                col("name"),
+                FuzzyJoinOptions::new(metric),
+            )
+            .collect()?;
+        let expected = left
+            .clone()
+            .lazy()
+            .cross_join(right.clone().lazy(), None)
+            .filter(predicate)
+            .collect()?;
+        assert!(out.height() > 0, "{metric:?}");
+        assert_eq!(
+            out.get_column_names(),
+            ["name", "id", "name_right", "account"]
+        );
+        assert!(sort(out).equals(&sort(expected)), "{metric:?}");
+    }
+
+    let mut lf = left.lazy().fuzzy_join(
+        right.lazy(),
+        col("name"),
+        col("name"),
+        FuzzyJoinOptions::new(FuzzyJoinMetric::Levenshtein { max_distance: 1 })
+            .with_score_name(Some("distance".into())),
+    );
+    let schema = lf.collect_schema()?;
+    assert_eq!(schema.get("distance"), Some(&DataType::UInt32));
+    let out = lf.clone().collect()?;
+    assert_eq!(&*schema, &**out.schema());
+    let out = sort(out);
+    assert_eq!(
+        out.column("id")?.i32()?.to_vec(),
+        [Some(1), Some(2), Some(3), Some(6)]
+    );
+    assert_eq!(out.column("distance")?.u32()?.to_vec(), [Some(1); 4]);
+
+    let out = lf
+        .clone()
+        .filter(col("distance").eq(lit(1u32)).and(col("id").gt(lit(1))))
+        .collect()?;
+    assert_eq!(out.height(), 3);
+
+    let out = lf.select([col("id"), col("distance")]).collect()?;
+    assert_eq!(out.get_column_names(), ["id", "distance"]);
+    assert_eq!(out.column("distance")?.u32()?.to_vec(), [Some(1); 4]);
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "lazy", feature = "knn_join"))]
+fn test_knn_join_matches_brute_force() -> PolarsResult<()> {
+    const WIDTH: usize = 3;
+    // Deterministic pseudo-random vectors, with a null and a zero vector on both sides.
+    let mut state = 17u64;
+    let mut vectors = |n: usize| -> Vec<Option<[f64; WIDTH]>> {
+        (0..n)
+            .map(|i| match i {
+                3 => None,
+                5 => Some([0.0; WIDTH]),
+                _ => Some(std::array::from_fn(|_| {
+                    state = state
+                        .wrapping_mul(6364136223846793005)
+                        .wrapping_add(1442695040888963407);
+                    (state >> 11) as f64 / (1u64 << 53) as f64 - 0.5
+                })),
+            })
+            .collect()
+    };
+    let left_vectors = vectors(40);
+    let right_vectors = vectors(300);
+    let to_df = |id: &str, vectors: &[Option<[f64; WIDTH]>]| -> PolarsResult<DataFrame> {
+        let rows = vectors
+            .iter()
+            .map(|v| v.map(|v| Series::new(PlSmallStr::EMPTY, v)))
+            .collect::<Vec<_>>();
+        let v = Series::new("v".into(), rows)
+            .cast(&DataType::Array(Box::new(DataType::Float64), WIDTH))?;
+        DataFrame::new(vec![
+            Series::new(id.into(), (0..vectors.len() as i32).collect::<Vec<_>>()).into(),
+            v.into(),
+        ])
+    };
+    let left = to_df("lid", &left_vectors)?;
+    let right = to_df("rid", &right_vectors)?;
+
+    let dot = |a: &[f64; WIDTH], b: &[f64; WIDTH]| a.iter().zip(b).map(|(x, y)| x * y).sum::<f64>();
+    let k = 4;
+    for metric in [KnnJoinMetric::L2, KnnJoinMetric::Cosine, KnnJoinMetric::Dot] {
+        let score = |a: &[f64; WIDTH], b: &[f64; WIDTH]| match metric {
+            KnnJoinMetric::L2 => a
+                .iter()
+                .zip(b)
+                .map(|(x, y)| (x - y) * (x - y))
+                .sum::<f64>()
+                .sqrt(),
+            KnnJoinMetric::Cosine => dot(a, b) / (dot(a, a) * dot(b, b)).sqrt(),
+            KnnJoinMetric::Dot => dot(a, b),
+        };
+        let mut expected = vec![];
+        for (lid, a) in left_vectors.iter().enumerate() {
+            let Some(a) = a else { continue };
+            let mut candidates = right_vectors
+                .iter()
+                .enumerate()
+                .filter_map(|(rid, b)| Some((rid as i32, score(a, b.as_ref()?))))
+                .filter(|(_, s)| !s.is_nan())
+                .collect::<Vec<_>>();
+            candidates.sort_by(|x, y| match metric {
+                KnnJoinMetric::L2 => x.1.total_cmp(&y.1),
+                _ => y.1.total_cmp(&x.1),
+            });
+            expected.extend(candidates.iter().take(k).map(|(rid, _)| (lid as i32, *rid)));
+        }
+
+        let out = left
+            .clone()
+            .lazy()
+            .knn_join(
+                right.clone().lazy(),
+                col("v"),
+                col("v"),
+                KnnJoinOptions::new(k, metric).with_score_name(Some("score".into())),
+            )
+            .collect()?;
+        assert_eq!(
+            out.get_column_names(),
+            ["lid", "v", "rid", "v_right", "score"]
+        );
+        let pairs = out
+            .column("lid")?
+            .i32()?
+            .into_no_null_iter()
+            .zip(out.column("rid")?.i32()?.into_no_null_iter())
+            .collect::<Vec<_>>();
+        assert_eq!(pairs, expected, "{metric:?}");
+
+        let scores = out.column("score")?.f64()?;
+        for ((lid, rid), s) in pairs.iter().zip(scores.into_no_null_iter()) {
+            let a = left_vectors[*lid as usize].as_ref().unwrap();
+            let b = right_vectors[*rid as usize].as_ref().unwrap();
+            assert!((score(a, b) - s).abs() < 1e-9, "{metric:?}");
+        }
+    }
+
+    // Filters on the right table must not be pushed below the join.
+    let lf = left.clone().lazy().knn_join(
+        right.clone().lazy(),
+        col("v"),
+        col("v"),
+        KnnJoinOptions::new(k, KnnJoinMetric::L2).with_score_name(Some("score".into())),
+    );
+    let all = lf.clone().collect()?;
+    let predicate = col("lid").lt(lit(10)).and(col("rid").gt(lit(150)));
+    let filtered = lf.filter(predicate.clone()).collect()?;
+    let expected = all.lazy().filter(predicate).collect()?;
+    assert!(filtered.height() > 0);
+    assert!(filtered.equals(&expected));
+
+    // A `k` beyond the number of candidates returns all of them.
+    let out = left
+        .head(Some(3))
+        .lazy()
+        .knn_join(
+            right.lazy(),
+            col("v"),
+            col("v"),
+            KnnJoinOptions::new(usize::MAX, KnnJoinMetric::L2),
+        )
+        .collect()?;
+    assert_eq!(out.height(), 3 * 299);
+
+    // Errors name the join rather than an `arr` method.
+    let strings = Series::new("v".into(), ["a", "b", "c"])
+        .implode()?
+        .into_series()
+        .cast(&DataType::Array(Box::new(DataType::String), WIDTH))?;
+    let strings = strings.into_frame().lazy();
+    let err = strings
+        .clone()
+        .knn_join(
+            strings,
+            col("v"),
+            col("v"),
+            KnnJoinOptions::new(1, KnnJoinMetric::L2),
+        )
+        .collect()
+        .unwrap_err();
+    assert!(err.to_string().contains("`knn_join` expects a numeric Array"));
+    Ok(())
+}
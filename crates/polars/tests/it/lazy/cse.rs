@@ -38,3 +38,83 @@ fn test_cse_union_schema_6504() -> PolarsResult<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_result_cache_across_collects() -> PolarsResult<()> {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use polars_core::df;
+
+    static N_SCANS: AtomicUsize = AtomicUsize::new(0);
+    static N_OTHER_SCANS: AtomicUsize = AtomicUsize::new(0);
+
+    let source = df![
+        "a" => [1, 2, 3, 4],
+        "b" => [1, 1, 2, 2],
+    ]?
+    .lazy();
+    let base = source
+        .clone()
+        .map(
+            |df| {
+                N_SCANS.fetch_add(1, Ordering::Relaxed);
+                Ok(df)
+            },
+            AllowedOptimizations::default(),
+            None,
+            Some("count_scans"),
+        )
+        .filter(col("a").gt(lit(1)))
+        .cache();
+
+    let q1 = base
+        .clone()
+        .select([col("a").sum()])
+        .with_result_cache(true);
+    let q2 = base
+        .clone()
+        .group_by([col("b")])
+        .agg([col("a").max()])
+        .sort(["b"], Default::default())
+        .with_result_cache(true);
+
+    let out = q1.clone().collect()?;
+    assert_eq!(out.column("a")?.i32()?.get(0), Some(9));
+    let out = q2.clone().collect()?;
+    let expected = df![
+        "b" => [1, 2],
+        "a" => [2, 4],
+    ]?;
+    assert!(out.equals(&expected));
+    let out = q1.clone().collect_with_engine(Engine::Streaming)?;
+    assert_eq!(out.column("a")?.i32()?.get(0), Some(9));
+    assert_eq!(N_SCANS.load(Ordering::Relaxed), 1);
+
+    // After invalidation the shared subplan is computed again.
+    assert_eq!(ResultCache::global().invalidate(base)?, 1);
+    q2.collect()?;
+    assert_eq!(N_SCANS.load(Ordering::Relaxed), 2);
+
+    // A different function with the same name doesn't reuse the result.
+    let other = source
+        .map(
+            |df| {
+                N_OTHER_SCANS.fetch_add(1, Ordering::Relaxed);
+                Ok(df)
+            },
+            AllowedOptimizations::default(),
+            None,
+            Some("count_scans"),
+        )
+        .filter(col("a").gt(lit(1)))
+        .cache();
+    let out = other
+        .select([col("a").sum()])
+        .with_result_cache(true)
+        .collect()?;
+    assert_eq!(out.column("a")?.i32()?.get(0), Some(9));
+    assert_eq!(N_OTHER_SCANS.load(Ordering::Relaxed), 1);
+    assert_eq!(N_SCANS.load(Ordering::Relaxed), 2);
+
+    Ok(())
+}
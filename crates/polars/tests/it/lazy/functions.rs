@@ -24,3 +24,855 @@ fn test_format_str() {
 
     assert!(out.equals_missing(&expected));
 }
+
+#[test]
+#[cfg(all(feature = "stable_hash", feature = "dtype-struct"))]
+fn test_stable_hash() -> PolarsResult<()> {
+    let df = df![
+        "a" => [Some("Spark"), None, Some("Spark")],
+        "b" => [
+            Series::new("".into(), [123i32]),
+            Series::new("".into(), [1i32, 2]),
+            Series::new("".into(), [123i32]),
+        ],
+        "c" => [2i32, 3, 2],
+    ]?;
+
+    let lf = df.lazy().select([
+        // Equals Spark's `hash(a, b, c)`.
+        as_struct(vec![col("a"), col("b"), col("c")])
+            .stable_hash(HashAlgorithm::Murmur3 {
+                seed: SPARK_HASH_SEED,
+            })
+            .alias("murmur3"),
+        col("a")
+            .stable_hash(HashAlgorithm::XxHash64 {
+                seed: SPARK_HASH_SEED as u64,
+            })
+            .alias("xxhash64"),
+        col("b")
+            .stable_hash(HashAlgorithm::Sha256 { hex: true })
+            .alias("sha256"),
+        col("a")
+            .stable_hash(HashAlgorithm::Md5 { hex: false })
+            .alias("md5"),
+    ]);
+    let schema = lf.clone().collect_schema()?;
+    let out = lf.collect()?;
+    assert_eq!(&*schema, &**out.schema());
+    assert_eq!(
+        out.dtypes(),
+        [
+            DataType::Int32,
+            DataType::UInt64,
+            DataType::String,
+            DataType::Binary
+        ]
+    );
+
+    let murmur3 = out.column("murmur3")?.i32()?;
+    assert_eq!(murmur3.get(0), Some(-1321691492));
+    assert_eq!(murmur3.get(0), murmur3.get(2));
+
+    // Spark hashes a null to the seed, digests propagate it.
+    let xxhash64 = out.column("xxhash64")?.u64()?;
+    assert_eq!(xxhash64.get(1), Some(42));
+    assert_eq!(out.column("md5")?.null_count(), 1);
+
+    let sha256 = out.column("sha256")?.str()?;
+    assert_ne!(sha256.get(0), sha256.get(1));
+    assert_eq!(sha256.get(0), sha256.get(2));
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "stable_hash")]
+fn test_stable_hash_nested_encoding() -> PolarsResult<()> {
+    // The canonical encoding must not collide on differently nested values.
+    let s = Series::new(
+        "a".into(),
+        [
+            Series::new("".into(), ["a", "b"]),
+            Series::new("".into(), ["ab"]),
+            Series::new("".into(), Vec::<&str>::new()),
+            Series::new("".into(), [None::<&str>]),
+            Series::new("".into(), [""]),
+        ],
+    );
+    let df = DataFrame::new(vec![s.into()])?;
+    let out = df
+        .lazy()
+        .select([col("a").stable_hash(HashAlgorithm::SipHash24 { k0: 1, k1: 2 })])
+        .collect()?;
+    let hashes = out.column("a")?.unique()?;
+    assert_eq!(hashes.len(), 5);
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "array_linalg")]
+fn test_array_linalg() -> PolarsResult<()> {
+    let array = |name: &str, rows: &[Option<[i32; 2]>], dtype: DataType| {
+        let rows = rows
+            .iter()
+            .map(|r| r.map(|r| Series::new(PlSmallStr::EMPTY, r)))
+            .collect::<Vec<_>>();
+        Series::new(name.into(), rows)
+            .cast(&DataType::Array(Box::new(dtype), 2))
+            .unwrap()
+    };
+    let a = [Some([3, 4]), Some([1, 0]), None, Some([0, 0])];
+    let b = [Some([6, 8]), Some([0, 1]), Some([1, 1]), Some([1, 1])];
+    let df = DataFrame::new(vec![
+        array("a", &a, DataType::Float32).into(),
+        array("b", &b, DataType::Float32).into(),
+        array("c", &a, DataType::Int64).into(),
+    ])?;
+
+    let lf = df.lazy().select([
+        col("a").arr().dot(col("b")).alias("dot"),
+        col("a").arr().cosine_similarity(col("b")).alias("cosine"),
+        col("a").arr().l2_distance(col("b")).alias("l2"),
+        col("a").arr().norm(1.0).alias("norm_1"),
+        col("a").arr().norm(2.0).alias("norm_2"),
+        col("a").arr().dot(col("b").first()).alias("dot_first"),
+        col("c").arr().norm(f64::INFINITY).alias("norm_inf"),
+        col("a").arr().normalize(2.0).alias("normalized"),
+    ]);
+    let schema = lf.clone().collect_schema()?;
+    let out = lf.collect()?;
+    assert_eq!(&*schema, &**out.schema());
+    assert_eq!(schema.get("dot"), Some(&DataType::Float32));
+    assert_eq!(schema.get("norm_inf"), Some(&DataType::Float64));
+    assert_eq!(
+        schema.get("normalized"),
+        Some(&DataType::Array(Box::new(DataType::Float32), 2))
+    );
+
+    let f32_col = |name: &str| out.column(name).unwrap().f32().unwrap().to_vec();
+    assert_eq!(f32_col("dot"), [Some(50.0), Some(0.0), None, Some(0.0)]);
+    let cosine = f32_col("cosine");
+    assert_eq!(cosine[..3], [Some(1.0), Some(0.0), None]);
+    assert!(cosine[3].unwrap().is_nan());
+    assert_eq!(
+        f32_col("l2"),
+        [Some(5.0), Some(2f32.sqrt()), None, Some(2f32.sqrt())]
+    );
+    assert_eq!(f32_col("norm_1"), [Some(7.0), Some(1.0), None, Some(0.0)]);
+    assert_eq!(f32_col("norm_2"), [Some(5.0), Some(1.0), None, Some(0.0)]);
+    assert_eq!(
+        f32_col("dot_first"),
+        [Some(50.0), Some(6.0), None, Some(0.0)]
+    );
+    assert_eq!(
+        out.column("norm_inf")?.f64()?.to_vec(),
+        [Some(4.0), Some(1.0), None, Some(0.0)]
+    );
+
+    let normalized = out.column("normalized")?.array()?;
+    assert_eq!(normalized.null_count(), 1);
+    let first = normalized.get_as_series(0).unwrap();
+    assert_eq!(first.f32()?.to_vec(), [Some(0.6), Some(0.8)]);
+    let last = normalized.get_as_series(3).unwrap();
+    assert!(last.f32()?.into_no_null_iter().all(|x| x.is_nan()));
+
+    let df = df! { "a" => [1, 2] }?;
+    let err = df.lazy().select([col("a").arr().norm(0.0)]).collect();
+    assert!(err.is_err());
+    Ok(())
+}
+
+#[test]
+#[cfg(all(
+    feature = "list_sets",
+    feature = "list_gather",
+    feature = "list_sample",
+    feature = "list_filter",
+    feature = "diff"
+))]
+fn test_array_list_parity() -> PolarsResult<()> {
+    use polars_core::series::ops::NullBehavior;
+
+    let nested = |name: &str, rows: &[Option<&[i64]>], width: Option<usize>| {
+        let rows = rows
+            .iter()
+            .map(|r| r.map(|r| Series::new(PlSmallStr::EMPTY, r)))
+            .collect::<Vec<_>>();
+        let s = Series::new(name.into(), rows);
+        match width {
+            Some(width) => s
+                .cast(&DataType::Array(Box::new(DataType::Int64), width))
+                .unwrap(),
+            None => s,
+        }
+    };
+    let df = DataFrame::new(vec![
+        nested(
+            "a",
+            &[Some(&[1, 2, 3]), Some(&[3, 2, 1]), None, Some(&[5, 5, 6])],
+            Some(3),
+        )
+        .into(),
+        nested(
+            "b",
+            &[
+                Some(&[2, 3, 4]),
+                Some(&[1, 1, 1]),
+                Some(&[1, 2, 3]),
+                Some(&[7, 8, 9]),
+            ],
+            Some(3),
+        )
+        .into(),
+        nested(
+            "idx",
+            &[Some(&[0, -1]), Some(&[2, 0]), Some(&[0, 0]), Some(&[1, 5])],
+            Some(2),
+        )
+        .into(),
+        nested(
+            "list_idx",
+            &[Some(&[0]), Some(&[1, 2]), Some(&[]), Some(&[-3])],
+            None,
+        )
+        .into(),
+    ])?;
+
+    let lf = df.clone().lazy().select([
+        col("a"),
+        col("a").arr().union(col("b")).alias("union"),
+        col("a")
+            .arr()
+            .set_intersection(col("b"))
+            .alias("intersection"),
+        col("a").arr().gather(col("idx"), true).alias("gather"),
+        col("a")
+            .arr()
+            .gather(col("list_idx"), false)
+            .alias("gather_list"),
+        col("a").arr().diff(1, NullBehavior::Ignore).alias("diff"),
+        col("a")
+            .arr()
+            .diff(1, NullBehavior::Drop)
+            .alias("diff_drop"),
+        col("a")
+            .arr()
+            .sample_n(lit(2), false, true, Some(0))
+            .alias("sample"),
+        col("a")
+            .arr()
+            .sample_fraction(lit(0.5), false, true, Some(0))
+            .alias("sample_fraction"),
+        col("a").arr().contains_any(col("b")).alias("contains_any"),
+        col("a")
+            .arr()
+            .is_sorted(Default::default())
+            .alias("is_sorted"),
+        col("a").arr().eval(col("") * lit(10), false).alias("eval"),
+        col("a").arr().eval(col("").sum(), false).alias("eval_sum"),
+        col("a")
+            .arr()
+            .eval(col("").unique_stable(), true)
+            .alias("eval_list"),
+        col("a").arr().filter(col("").gt(lit(1))).alias("filter"),
+    ]);
+    let schema = lf.clone().collect_schema()?;
+    let out = lf.collect()?;
+    assert_eq!(&*schema, &**out.schema());
+    let array = |width| DataType::Array(Box::new(DataType::Int64), width);
+    let list = DataType::List(Box::new(DataType::Int64));
+    assert_eq!(schema.get("union"), Some(&list));
+    assert_eq!(schema.get("gather"), Some(&array(2)));
+    assert_eq!(schema.get("gather_list"), Some(&list));
+    assert_eq!(schema.get("diff"), Some(&array(3)));
+    assert_eq!(schema.get("diff_drop"), Some(&array(2)));
+    assert_eq!(schema.get("sample"), Some(&array(2)));
+    assert_eq!(schema.get("sample_fraction"), Some(&list));
+    assert_eq!(schema.get("contains_any"), Some(&DataType::Boolean));
+    assert_eq!(schema.get("is_sorted"), Some(&DataType::Boolean));
+    assert_eq!(schema.get("eval"), Some(&array(3)));
+    assert_eq!(schema.get("eval_sum"), Some(&array(1)));
+    assert_eq!(schema.get("eval_list"), Some(&list));
+    assert_eq!(schema.get("filter"), Some(&list));
+
+    let rows = |name: &str| {
+        let c = out.column(name).unwrap().cast(&list).unwrap();
+        c.list()
+            .unwrap()
+            .into_iter()
+            .map(|s| s.map(|s| s.i64().unwrap().to_vec()))
+            .collect::<Vec<_>>()
+    };
+    let row = |r: &[Option<i64>]| Some(r.to_vec());
+    let full = |r: &[i64]| Some(r.iter().copied().map(Some).collect::<Vec<_>>());
+    assert_eq!(
+        rows("union"),
+        [
+            full(&[1, 2, 3, 4]),
+            full(&[3, 2, 1]),
+            None,
+            full(&[5, 6, 7, 8, 9])
+        ]
+    );
+    assert_eq!(
+        rows("intersection"),
+        [full(&[2, 3]), full(&[1]), None, full(&[])]
+    );
+    assert_eq!(
+        rows("gather"),
+        [full(&[1, 3]), full(&[1, 3]), None, row(&[Some(5), None])]
+    );
+    assert_eq!(
+        rows("gather_list"),
+        [full(&[1]), full(&[2, 1]), None, full(&[5])]
+    );
+    assert_eq!(
+        rows("diff"),
+        [
+            row(&[None, Some(1), Some(1)]),
+            row(&[None, Some(-1), Some(-1)]),
+            None,
+            row(&[None, Some(0), Some(1)]),
+        ]
+    );
+    assert_eq!(
+        rows("diff_drop"),
+        [full(&[1, 1]), full(&[-1, -1]), None, full(&[0, 1])]
+    );
+    let a = rows("a");
+    for (sample, a) in rows("sample").into_iter().zip(a) {
+        let (Some(sample), Some(a)) = (sample, a) else {
+            continue;
+        };
+        assert_eq!(sample.len(), 2);
+        assert!(sample.iter().all(|v| a.contains(v)));
+    }
+    assert_eq!(
+        rows("eval"),
+        [
+            full(&[10, 20, 30]),
+            full(&[30, 20, 10]),
+            None,
+            full(&[50, 50, 60])
+        ]
+    );
+    assert_eq!(
+        rows("eval_sum"),
+        [full(&[6]), full(&[6]), None, full(&[16])]
+    );
+    assert_eq!(
+        rows("eval_list"),
+        [full(&[1, 2, 3]), full(&[3, 2, 1]), None, full(&[5, 6])]
+    );
+    assert_eq!(
+        rows("filter"),
+        [full(&[2, 3]), full(&[3, 2]), None, full(&[5, 5, 6])]
+    );
+    assert_eq!(
+        out.column("contains_any")?
+            .bool()?
+            .iter()
+            .collect::<Vec<_>>(),
+        [Some(true), Some(true), None, Some(false)]
+    );
+    assert_eq!(
+        out.column("is_sorted")?.bool()?.iter().collect::<Vec<_>>(),
+        [Some(true), Some(false), None, Some(true)]
+    );
+
+    let err = df
+        .clone()
+        .lazy()
+        .select([col("a").arr().gather(col("idx"), false)])
+        .collect();
+    assert!(err.is_err());
+
+    // An expression that changes the number of elements can't keep the width of the arrays.
+    let err = df
+        .lazy()
+        .select([col("a").arr().eval(col("").unique_stable(), false)])
+        .collect_schema();
+    assert!(err.is_err());
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "regex")]
+fn test_split_regex_and_replace_n() -> PolarsResult<()> {
+    let df = df! {
+        "s" => [Some("a1b22c333"), Some("x-y--z"), None],
+        "pat" => [r"\d+", "-+", "x"],
+        "val" => ["#", "_", "?"],
+    }?;
+
+    let out = df
+        .lazy()
+        .select([
+            col("s").str().split_regex(lit(r"\d+"), None).alias("split"),
+            col("s")
+                .str()
+                .split_regex(col("pat"), Some(2))
+                .alias("split_limit"),
+            col("s")
+                .str()
+                .split_regex_inclusive(lit(r"\d+"), None)
+                .alias("split_inclusive"),
+            col("s")
+                .str()
+                .replace_n(lit(r"\d+"), lit("<$0>"), false, 2)
+                .alias("replace_regex"),
+            col("s")
+                .str()
+                .replace_n(lit(r"\d"), col("val"), false, 2)
+                .alias("replace_multi"),
+            col("s")
+                .str()
+                .replace_n(col("pat"), col("val"), false, 1)
+                .alias("replace_per_row"),
+            col("s")
+                .str()
+                .replace_all(col("pat"), lit("."), false)
+                .alias("replace_all_per_row"),
+        ])
+        .collect()?;
+
+    let rows = |name: &str| {
+        out.column(name)
+            .unwrap()
+            .list()
+            .unwrap()
+            .into_iter()
+            .map(|s| {
+                s.map(|s| {
+                    s.str()
+                        .unwrap()
+                        .into_no_null_iter()
+                        .collect::<Vec<_>>()
+                        .join("|")
+                })
+            })
+            .collect::<Vec<_>>()
+    };
+    let strs = |name: &str| {
+        out.column(name)
+            .unwrap()
+            .str()
+            .unwrap()
+            .into_iter()
+            .map(|s| s.map(str::to_string))
+            .collect::<Vec<_>>()
+    };
+    let some = |s: &str| Some(s.to_string());
+
+    assert_eq!(rows("split"), [some("a|b|c|"), some("x-y--z"), None]);
+    assert_eq!(
+        rows("split_limit"),
+        [some("a|b22c333"), some("x|y--z"), None]
+    );
+    assert_eq!(
+        rows("split_inclusive"),
+        [some("a1|b22|c333"), some("x-y--z"), None]
+    );
+    assert_eq!(
+        strs("replace_regex"),
+        [some("a<1>b<22>c333"), some("x-y--z"), None]
+    );
+    assert_eq!(
+        strs("replace_multi"),
+        [some("a#b#2c333"), some("x-y--z"), None]
+    );
+    assert_eq!(
+        strs("replace_per_row"),
+        [some("a#b22c333"), some("x_y--z"), None]
+    );
+    assert_eq!(
+        strs("replace_all_per_row"),
+        [some("a.b.c."), some("x.y.z"), None]
+    );
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "string_url")]
+fn test_url_parse_and_percent_encoding() -> PolarsResult<()> {
+    let df = df! {
+        "url" => [
+            Some("https://user@example.com:8443/a/b?q=polars%20rs&page=2#top"),
+            Some("http://example.org?page=1&page=3"),
+            Some("not a url"),
+            None,
+        ],
+    }?;
+
+    let out = df
+        .clone()
+        .lazy()
+        .select([
+            col("url").str().url().parse(false).alias("parsed"),
+            col("url")
+                .str()
+                .url()
+                .query_param("page", false)
+                .alias("page"),
+            col("url").str().url().query_param("q", false).alias("q"),
+        ])
+        .unnest(by_name(["parsed"], true))
+        .collect()?;
+
+    let strs = |name: &str| {
+        out.column(name)
+            .unwrap()
+            .str()
+            .unwrap()
+            .into_iter()
+            .map(|s| s.map(str::to_string))
+            .collect::<Vec<_>>()
+    };
+    let some = |s: &str| Some(s.to_string());
+    assert_eq!(strs("scheme"), [some("https"), some("http"), None, None]);
+    assert_eq!(strs("user"), [some("user"), None, None, None]);
+    assert_eq!(
+        strs("host"),
+        [some("example.com"), some("example.org"), None, None]
+    );
+    assert_eq!(
+        out.column("port")?.u16()?.to_vec(),
+        [Some(8443), Some(80), None, None]
+    );
+    assert_eq!(strs("path"), [some("/a/b"), some("/"), None, None]);
+    assert_eq!(
+        strs("query"),
+        [
+            some("q=polars%20rs&page=2"),
+            some("page=1&page=3"),
+            None,
+            None
+        ]
+    );
+    assert_eq!(strs("fragment"), [some("top"), None, None, None]);
+    assert_eq!(strs("page"), [some("2"), some("1"), None, None]);
+    assert_eq!(strs("q"), [some("polars rs"), None, None, None]);
+
+    let err = df
+        .lazy()
+        .select([col("url").str().url().parse(true)])
+        .collect();
+    assert!(err.is_err());
+
+    // An empty query and fragment are null.
+    let out = df! {
+        "url" => ["http://a/?#"],
+    }?
+    .lazy()
+    .select([col("url").str().url().parse(true)])
+    .unnest(by_name(["url"], true))
+    .collect()?;
+    assert_eq!(out.column("query")?.null_count(), 1);
+    assert_eq!(out.column("fragment")?.null_count(), 1);
+
+    let df = df! {
+        "s" => [Some("a b/c?d=é~"), Some("%FF"), None],
+    }?;
+    let out = df
+        .lazy()
+        .select([
+            col("s").str().url().percent_encode().alias("encoded"),
+            col("s")
+                .str()
+                .url()
+                .percent_encode()
+                .str()
+                .url()
+                .percent_decode(true)
+                .alias("roundtrip"),
+            col("s").str().url().percent_decode(false).alias("decoded"),
+        ])
+        .collect()?;
+    assert_eq!(
+        out.column("encoded")?
+            .str()?
+            .into_iter()
+            .collect::<Vec<_>>(),
+        [Some("a%20b%2Fc%3Fd%3D%C3%A9~"), Some("%25FF"), None]
+    );
+    assert_eq!(
+        out.column("roundtrip")?
+            .str()?
+            .into_iter()
+            .collect::<Vec<_>>(),
+        [Some("a b/c?d=é~"), Some("%FF"), None]
+    );
+    assert_eq!(
+        out.column("decoded")?
+            .str()?
+            .into_iter()
+            .collect::<Vec<_>>(),
+        [Some("a b/c?d=é~"), None, None]
+    );
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "binary_compression")]
+fn test_binary_compress_roundtrip() -> PolarsResult<()> {
+    let values: [Option<&[u8]>; 3] = [Some(&[7; 1000]), Some(b""), None];
+    let df = df! {
+        "a" => values,
+    }?;
+
+    for codec in [
+        BinaryCodec::Gzip,
+        BinaryCodec::Zstd,
+        BinaryCodec::Brotli,
+        BinaryCodec::Snappy,
+        BinaryCodec::Lz4,
+    ] {
+        let out = df
+            .clone()
+            .lazy()
+            .select([
+                col("a").binary().compress(codec, None).alias("compressed"),
+                col("a")
+                    .binary()
+                    .compress(codec, None)
+                    .binary()
+                    .decompress(codec, true)
+                    .alias("roundtrip"),
+            ])
+            .collect()?;
+
+        let compressed = out.column("compressed")?.binary()?;
+        assert!(compressed.get(0).unwrap().len() < 1000);
+        assert_eq!(compressed.get(2), None);
+        assert!(out.column("roundtrip")?.equals_missing(df.column("a")?));
+    }
+
+    let garbage = df! {
+        "a" => [Some(b"not compressed".as_slice()), None],
+    }?;
+    let decompress = |strict| {
+        garbage
+            .clone()
+            .lazy()
+            .select([col("a").binary().decompress(BinaryCodec::Gzip, strict)])
+            .collect()
+    };
+    assert!(decompress(true).is_err());
+    assert_eq!(decompress(false)?.column("a")?.null_count(), 2);
+
+    // A size prefix that a block of this length can't decompress to is rejected before allocating.
+    let mut oversized = u32::MAX.to_le_bytes().to_vec();
+    oversized.extend_from_slice(&[0; 8]);
+    let oversized = df! {
+        "a" => [oversized.as_slice()],
+    }?
+    .lazy()
+    .select([col("a").binary().decompress(BinaryCodec::Lz4, true)])
+    .collect();
+    assert!(oversized.is_err());
+    let forged_snappy = df! {
+        "a" => [[0xff, 0xff, 0xff, 0xff, 0x0f, 0x00, 0x00].as_slice()],
+    }?
+    .lazy()
+    .select([col("a").binary().decompress(BinaryCodec::Snappy, false)])
+    .collect()?;
+    assert_eq!(forged_snappy.column("a")?.null_count(), 1);
+
+    // Concatenated gzip members decompress to the concatenation of their contents.
+    let members = df! {
+        "a" => [b"foo".as_slice(), b"bar".as_slice()],
+    }?
+    .lazy()
+    .select([col("a").binary().compress(BinaryCodec::Gzip, None)])
+    .collect()?;
+    let members = members.column("a")?.binary()?;
+    let concatenated = [members.get(0).unwrap(), members.get(1).unwrap()].concat();
+    let out = df! {
+        "a" => [concatenated.as_slice()],
+    }?
+    .lazy()
+    .select([col("a").binary().decompress(BinaryCodec::Gzip, true)])
+    .collect()?;
+    assert_eq!(
+        out.column("a")?.binary()?.get(0),
+        Some(b"foobar".as_slice())
+    );
+
+    let invalid_level = df
+        .lazy()
+        .select([col("a").binary().compress(BinaryCodec::Gzip, Some(10))])
+        .collect();
+    assert!(invalid_level.is_err());
+
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "rolling_window_by")]
+fn test_rolling_counts_and_product_by() -> PolarsResult<()> {
+    let df = df! {
+        "t" => [1i64, 2, 3, 4, 5],
+        "a" => [3i32, 1, 3, 2, 2],
+    }?;
+    let options = RollingOptionsDynamicWindow {
+        window_size: Duration::parse("2i"),
+        min_periods: 1,
+        closed_window: ClosedWindow::Right,
+        fn_params: None,
+    };
+
+    let out = df
+        .lazy()
+        .select([
+            col("a")
+                .rolling_n_unique_by(col("t"), options.clone())
+                .alias("n_unique"),
+            col("a")
+                .rolling_arg_min_by(col("t"), options.clone())
+                .alias("arg_min"),
+            col("a")
+                .rolling_arg_max_by(col("t"), options.clone())
+                .alias("arg_max"),
+            col("a")
+                .rolling_mode_by(col("t"), options.clone())
+                .alias("mode"),
+            col("a")
+                .rolling_product_by(col("t"), options.clone())
+                .alias("product"),
+            col("a")
+                .rolling_rank_by(col("t"), RollingRankMethod::Average, options)
+                .alias("rank"),
+        ])
+        .collect()?;
+
+    let expected = df! {
+        "n_unique" => [1 as IdxSize, 2, 2, 2, 1],
+        "arg_min" => [0 as IdxSize, 1, 0, 1, 0],
+        "arg_max" => [0 as IdxSize, 0, 1, 0, 0],
+        "mode" => [3i32, 1, 1, 2, 2],
+        "product" => [3i64, 3, 3, 6, 4],
+        "rank" => [1.0, 0.5, 1.0, 0.5, 0.75],
+    }?;
+    assert!(out.equals(&expected));
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "rolling_window_by")]
+fn test_rolling_rank_by_ties_and_closed_windows() -> PolarsResult<()> {
+    // Rows with the same time share a window, and windows that are closed on the left don't
+    // contain their own row. Every row still ranks its own value.
+    let sorted = df! {
+        "t" => [1i64, 1, 2, 3],
+        "a" => [4i32, 2, 5, 1],
+    }?;
+    let unsorted = df! {
+        "t" => [3i64, 1, 2, 1],
+        "a" => [1i32, 4, 5, 2],
+    }?;
+    let rank = |df: &DataFrame, closed_window| {
+        let options = RollingOptionsDynamicWindow {
+            window_size: Duration::parse("2i"),
+            min_periods: 1,
+            closed_window,
+            fn_params: None,
+        };
+        df.clone()
+            .lazy()
+            .select([col("a").rolling_rank_by(col("t"), RollingRankMethod::Min, options)])
+            .collect()
+    };
+
+    let out = rank(&sorted, ClosedWindow::Right)?;
+    assert_eq!(
+        Vec::from(out.column("a")?.f64()?),
+        &[Some(1.0), Some(0.5), Some(1.0), Some(0.5)]
+    );
+    let out = rank(&sorted, ClosedWindow::Left)?;
+    assert_eq!(
+        Vec::from(out.column("a")?.f64()?),
+        &[None, None, Some(1.0), Some(0.25)]
+    );
+
+    let out = rank(&unsorted, ClosedWindow::Right)?;
+    assert_eq!(
+        Vec::from(out.column("a")?.f64()?),
+        &[Some(0.5), Some(1.0), Some(1.0), Some(0.5)]
+    );
+    let out = rank(&unsorted, ClosedWindow::None)?;
+    assert_eq!(
+        Vec::from(out.column("a")?.f64()?),
+        &[Some(0.5), None, Some(1.0), None]
+    );
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "interpolate_by")]
+fn test_interpolate_by_methods() -> PolarsResult<()> {
+    // The `by` column is unsorted, the rows are in x order 0, 3, 2, 4, 1.
+    let df = df! {
+        "t" => [0i32, 4, 2, 1, 3],
+        "v" => [Some(0.0), Some(0.0), Some(2.0), None, None],
+    }?;
+    let interpolate = |method, limit_area| {
+        col("v").interpolate_by_with_options(
+            col("t").cast(DataType::Date),
+            InterpolationOptions {
+                method,
+                limit: None,
+                limit_area,
+            },
+        )
+    };
+
+    let out = df
+        .lazy()
+        .select([
+            interpolate(InterpolationMethod::CubicSpline, None).alias("cubic_spline"),
+            interpolate(InterpolationMethod::Pchip, None).alias("pchip"),
+            interpolate(InterpolationMethod::Nearest, None).alias("nearest"),
+            interpolate(InterpolationMethod::Previous, None).alias("previous"),
+            interpolate(
+                InterpolationMethod::Linear,
+                Some(InterpolationLimitArea::Outside),
+            )
+            .alias("linear_outside"),
+        ])
+        .collect()?;
+
+    let expected = df! {
+        "cubic_spline" => [Some(0.0), Some(0.0), Some(2.0), Some(1.375), Some(1.375)],
+        "pchip" => [Some(0.0), Some(0.0), Some(2.0), Some(1.5), Some(1.5)],
+        "nearest" => [Some(0.0), Some(0.0), Some(2.0), Some(2.0), Some(0.0)],
+        "previous" => [Some(0.0), Some(0.0), Some(2.0), Some(0.0), Some(2.0)],
+        "linear_outside" => [Some(0.0), Some(0.0), Some(2.0), None, None],
+    }?;
+    assert!(out.equals_missing(&expected));
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "interpolate_by")]
+fn test_interpolate_by_cubic_duplicate_by() -> PolarsResult<()> {
+    let df = df! {
+        "t" => [0i32, 1, 1, 2],
+        "v" => [Some(0.0), Some(1.0), Some(3.0), None],
+    }?;
+    for method in [InterpolationMethod::CubicSpline, InterpolationMethod::Pchip] {
+        let out = df
+            .clone()
+            .lazy()
+            .select([col("v").interpolate_by_with_options(
+                col("t"),
+                InterpolationOptions {
+                    method,
+                    limit: None,
+                    limit_area: None,
+                },
+            )])
+            .collect();
+        assert!(out.is_err());
+    }
+    Ok(())
+}
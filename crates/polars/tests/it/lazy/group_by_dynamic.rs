@@ -61,3 +61,77 @@ fn test_group_by_dynamic_week_bounds() -> PolarsResult<()> {
     assert_eq!(a.get(1)?, AnyValue::Int32(6));
     Ok(())
 }
+
+#[test]
+#[cfg(feature = "dynamic_group_by")]
+fn test_group_by_session() -> PolarsResult<()> {
+    let df = df![
+        "user" => ["a", "b", "a", "b", "a", "a"],
+        "t" => [0i64, 5, 10, 40, 50, 55],
+        "v" => [1i32, 2, 3, 4, 5, 6],
+    ]?;
+
+    let out = df
+        .clone()
+        .lazy()
+        .group_by_session(
+            col("t"),
+            [col("user")],
+            SessionGroupOptions {
+                gap: Duration::parse("30i"),
+                include_boundaries: true,
+                ..Default::default()
+            },
+        )
+        .agg([col("v").sum()])
+        .collect()?;
+
+    assert_eq!(
+        out.get_column_names_str(),
+        ["user", "_lower_boundary", "_upper_boundary", "t", "v"]
+    );
+    let values = |name: &str| out.column(name).unwrap().i64().unwrap().to_vec();
+    assert_eq!(values("t"), [Some(0), Some(50), Some(5), Some(40)]);
+    assert_eq!(
+        values("_upper_boundary"),
+        [Some(40), Some(85), Some(35), Some(70)]
+    );
+    assert_eq!(
+        out.column("v")?.i32()?.to_vec(),
+        [Some(4), Some(11), Some(2), Some(4)]
+    );
+
+    // A row exactly `gap` after the previous one only continues a right-closed session.
+    let sessions = |closed_window| {
+        df.clone()
+            .lazy()
+            .group_by_session(
+                col("t"),
+                [],
+                SessionGroupOptions {
+                    gap: Duration::parse("5i"),
+                    label: Label::Right,
+                    closed_window,
+                    ..Default::default()
+                },
+            )
+            .agg([col("v").count().alias("n")])
+            .collect()
+    };
+    let out = sessions(ClosedWindow::Left)?;
+    assert_eq!(
+        out.column("n")?.idx()?.to_vec(),
+        [Some(1), Some(1), Some(1), Some(1), Some(1), Some(1)]
+    );
+    let out = sessions(ClosedWindow::Both)?;
+    assert_eq!(
+        out.column("t")?.i64()?.to_vec(),
+        [Some(15), Some(45), Some(60)]
+    );
+    assert_eq!(
+        out.column("n")?.idx()?.to_vec(),
+        [Some(3), Some(1), Some(2)]
+    );
+
+    Ok(())
+}
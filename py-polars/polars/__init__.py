@@ -251,6 +251,12 @@ from polars.meta import (
     thread_pool_size,
     threadpool_size,
 )
+from polars.result_cache import (
+    clear_result_cache,
+    invalidate_result_cache,
+    set_result_cache_capacity,
+    set_result_cache_spill_dir,
+)
 from polars.schema import Schema
 from polars.series import Series
 from polars.sql import SQLContext, sql
@@ -360,6 +366,11 @@ __all__ = [
     "disable_string_cache",
     "enable_string_cache",
     "using_string_cache",
+    # polars.result_cache
+    "clear_result_cache",
+    "invalidate_result_cache",
+    "set_result_cache_capacity",
+    "set_result_cache_spill_dir",
     # polars.config
     "Config",
     # polars.functions.whenthen
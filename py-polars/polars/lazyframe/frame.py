@@ -129,6 +129,7 @@ if TYPE_CHECKING:
         AsofJoinStrategy,
         ClosedInterval,
         ColumnNameOrSelector,
+        CsvEncoding,
         CsvQuoteStyle,
         EngineType,
         ExplainFormat,
@@ -3119,6 +3120,7 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         decimal_comma: bool = False,
         null_value: str | None = None,
         quote_style: CsvQuoteStyle | None = None,
+        encoding: CsvEncoding = "utf8",
         maintain_order: bool = True,
         storage_options: dict[str, Any] | None = None,
         credential_provider: CredentialProviderFunction
@@ -3151,6 +3153,7 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         decimal_comma: bool = False,
         null_value: str | None = None,
         quote_style: CsvQuoteStyle | None = None,
+        encoding: CsvEncoding = "utf8",
         maintain_order: bool = True,
         storage_options: dict[str, Any] | None = None,
         credential_provider: CredentialProviderFunction
@@ -3182,6 +3185,7 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         decimal_comma: bool = False,
         null_value: str | None = None,
         quote_style: CsvQuoteStyle | None = None,
+        encoding: CsvEncoding = "utf8",
         maintain_order: bool = True,
         storage_options: dict[str, Any] | None = None,
         credential_provider: CredentialProviderFunction
@@ -3204,7 +3208,7 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         path
             File path to which the file should be written.
         include_bom
-            Whether to include UTF-8 BOM in the CSV output.
+            Whether to include the byte order mark of `encoding` in the CSV output.
         include_header
             Whether to include header in the CSV output.
         separator
@@ -3257,6 +3261,10 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
               Namely, when writing a field that does not parse as a valid float
               or integer, then quotes will be used even if they aren`t strictly
               necessary.
+        encoding : {'utf8', 'utf8-lossy', 'latin1', 'windows-1252', 'shift-jis', 'utf16-le', 'utf16-be'}
+            Character encoding of the output. Raises if a value cannot be represented
+            in the encoding. `include_bom` writes nothing for encodings without a
+            byte order mark.
         maintain_order
             Maintain the order in which data is processed.
             Setting this to `False` will be slightly faster.
@@ -3382,6 +3390,7 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
             decimal_comma=decimal_comma,
             null_value=null_value,
             quote_style=quote_style,
+            encoding=encoding,
             cloud_options=storage_options,
             credential_provider=credential_provider_builder,
             retries=retries,
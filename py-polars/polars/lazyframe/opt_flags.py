@@ -45,6 +45,7 @@ class QueryOptFlags:
         collapse_joins: None | bool = None,
         check_order_observe: None | bool = None,
         fast_projection: None | bool = None,
+        result_cache: None | bool = None,
     ) -> None:
         self._pyoptflags = PyOptFlags.default()
         self.update(
@@ -58,6 +59,7 @@ class QueryOptFlags:
             collapse_joins=collapse_joins,
             check_order_observe=check_order_observe,
             fast_projection=fast_projection,
+            result_cache=result_cache,
         )
 
     @classmethod
@@ -79,6 +81,7 @@ class QueryOptFlags:
         collapse_joins: None | bool = None,
         check_order_observe: None | bool = None,
         fast_projection: None | bool = None,
+        result_cache: None | bool = None,
     ) -> QueryOptFlags:
         """Create new empty set off optimizations."""
         optflags = QueryOptFlags()
@@ -94,6 +97,7 @@ class QueryOptFlags:
             collapse_joins=collapse_joins,
             check_order_observe=check_order_observe,
             fast_projection=fast_projection,
+            result_cache=result_cache,
         )
 
     def update(
@@ -109,6 +113,7 @@ class QueryOptFlags:
         collapse_joins: None | bool = None,
         check_order_observe: None | bool = None,
         fast_projection: None | bool = None,
+        result_cache: None | bool = None,
     ) -> QueryOptFlags:
         """Update the current optimization flags."""
         if predicate_pushdown is not None:
@@ -137,6 +142,8 @@ class QueryOptFlags:
             self.check_order_observe = check_order_observe
         if fast_projection is not None:
             self.fast_projection = fast_projection
+        if result_cache is not None:
+            self.result_cache = result_cache
 
         return self
 
@@ -240,6 +247,15 @@ class QueryOptFlags:
     def fast_projection(self, value: bool) -> None:
         self._pyoptflags.fast_projection = value
 
+    @property
+    def result_cache(self) -> bool:
+        """Reuse the results of cached subplans of earlier queries from the session-level result cache."""  # noqa: W505
+        return self._pyoptflags.result_cache
+
+    @result_cache.setter
+    def result_cache(self, value: bool) -> None:
+        self._pyoptflags.result_cache = value
+
     def __str__(self) -> str:
         return f"""
 QueryOptFlags {{
@@ -255,6 +271,7 @@ QueryOptFlags {{
     cluster_with_columns: {self.cluster_with_columns}
     check_order_observe: {self.check_order_observe}
     fast_projection: {self.fast_projection}
+    result_cache: {self.result_cache}
 
     eager: {self._pyoptflags.eager}
     streaming: {self._pyoptflags.streaming}
@@ -0,0 +1,103 @@
+from __future__ import annotations
+
+import contextlib
+from pathlib import Path
+from typing import TYPE_CHECKING
+
+with contextlib.suppress(ImportError):  # Module not available when building docs
+    import polars._plr as plr
+
+if TYPE_CHECKING:
+    from polars import LazyFrame
+
+
+__all__ = [
+    "clear_result_cache",
+    "invalidate_result_cache",
+    "set_result_cache_capacity",
+    "set_result_cache_spill_dir",
+]
+
+
+def clear_result_cache() -> None:
+    """
+    Remove all results from the session-level result cache.
+
+    The result cache is used by queries that are collected with
+    `QueryOptFlags(result_cache=True)`. It holds the results of their cached
+    subplans, which are created with :meth:`LazyFrame.cache` or by common subplan
+    elimination, so that later queries which share these subplans don't compute
+    them again.
+
+    .. warning::
+        This functionality is considered **unstable**. It may be changed
+        at any point without it being considered a breaking change.
+
+    See Also
+    --------
+    invalidate_result_cache : Remove the results of the subplans of a query.
+    """
+    plr.clear_result_cache()
+
+
+def invalidate_result_cache(lf: LazyFrame) -> int:
+    """
+    Remove the results of the cached subplans of `lf` from the result cache.
+
+    Cached results are not invalidated when the data they were computed from
+    changes, this must be done explicitly.
+
+    .. warning::
+        This functionality is considered **unstable**. It may be changed
+        at any point without it being considered a breaking change.
+
+    Returns
+    -------
+    int
+        The number of removed results.
+
+    Examples
+    --------
+    >>> base = pl.LazyFrame({"a": [1, 2, 3]}).filter(pl.col("a") > 1).cache()
+    >>> opt = pl.QueryOptFlags(result_cache=True)
+    >>> base.select(pl.col("a").sum()).collect(optimizations=opt)
+    shape: (1, 1)
+    ┌─────┐
+    │ a   │
+    │ --- │
+    │ i64 │
+    ╞═════╡
+    │ 5   │
+    └─────┘
+    >>> pl.invalidate_result_cache(base)
+    1
+    """
+    return plr.invalidate_result_cache(lf._ldf)
+
+
+def set_result_cache_capacity(capacity: int) -> None:
+    """
+    Set the maximum number of results held by the result cache.
+
+    If the cache holds more results, the least recently used results are evicted.
+    The default capacity is 32.
+
+    .. warning::
+        This functionality is considered **unstable**. It may be changed
+        at any point without it being considered a breaking change.
+    """
+    plr.set_result_cache_capacity(capacity)
+
+
+def set_result_cache_spill_dir(path: str | Path | None) -> None:
+    """
+    Set the directory to which new results of the result cache are spilled.
+
+    The results are written as IPC files instead of being kept in memory. Pass
+    `None` to keep new results in memory.
+
+    .. warning::
+        This functionality is considered **unstable**. It may be changed
+        at any point without it being considered a breaking change.
+    """
+    plr.set_result_cache_spill_dir(None if path is None else Path(path))
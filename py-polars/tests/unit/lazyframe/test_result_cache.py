@@ -0,0 +1,68 @@
+from __future__ import annotations
+
+from pathlib import Path
+
+import polars as pl
+from polars.testing import assert_frame_equal
+
+
+def test_result_cache_across_collects(tmp_path: Path) -> None:
+    n_scans = 0
+
+    def count_scans(df: pl.DataFrame) -> pl.DataFrame:
+        nonlocal n_scans
+        n_scans += 1
+        return df
+
+    pl.clear_result_cache()
+    base = (
+        pl.LazyFrame({"a": [1, 2, 3, 4], "b": [1, 1, 2, 2]})
+        .map_batches(count_scans)
+        .filter(pl.col("a") > 1)
+        .cache()
+    )
+    opt = pl.QueryOptFlags(result_cache=True)
+    assert opt.result_cache
+    assert not pl.QueryOptFlags().result_cache
+
+    q1 = base.select(pl.col("a").sum())
+    q2 = base.group_by("b").agg(pl.col("a").max()).sort("b")
+
+    assert q1.collect(optimizations=opt).item() == 9
+    assert_frame_equal(
+        q2.collect(optimizations=opt), pl.DataFrame({"b": [1, 2], "a": [2, 4]})
+    )
+    assert n_scans == 1
+
+    assert pl.invalidate_result_cache(base) == 1
+    q1.collect(optimizations=opt)
+    assert n_scans == 2
+
+    # Spilled results are read back.
+    pl.clear_result_cache()
+    pl.set_result_cache_spill_dir(tmp_path)
+    try:
+        assert q1.collect(optimizations=opt).item() == 9
+        assert q1.collect(optimizations=opt).item() == 9
+        assert n_scans == 3
+        assert len(list(tmp_path.iterdir())) == 1
+    finally:
+        pl.set_result_cache_spill_dir(None)
+        pl.clear_result_cache()
+    assert list(tmp_path.iterdir()) == []
+
+
+def test_result_cache_capacity() -> None:
+    pl.clear_result_cache()
+    pl.set_result_cache_capacity(1)
+    try:
+        opt = pl.QueryOptFlags(result_cache=True)
+        lf1 = pl.LazyFrame({"a": [1]}).cache()
+        lf2 = pl.LazyFrame({"a": [2]}).cache()
+        lf1.collect(optimizations=opt)
+        lf2.collect(optimizations=opt)
+        # Only the most recent result is kept.
+        assert pl.invalidate_result_cache(lf1) == 0
+        assert pl.invalidate_result_cache(lf2) == 1
+    finally:
+        pl.set_result_cache_capacity(32)